@@ -138,7 +138,9 @@ impl<'a> std::ops::BitOr<&'a Bitmap> for MutableBitmap {
 /// Compute bitwise `&` between `lhs` and `rhs`, assigning it to `lhs`
 fn and_assign<T: BitChunk>(lhs: &mut MutableBitmap, rhs: &Bitmap) {
     if rhs.unset_bits() == 0 {
+        assert_eq!(lhs.len(), rhs.len());
         // bitmap remains
+        return;
     }
     if rhs.unset_bits() == rhs.len() {
         assert_eq!(lhs.len(), rhs.len());
@@ -188,3 +190,35 @@ impl<'a> std::ops::BitXor<&'a Bitmap> for MutableBitmap {
         self
     }
 }
+
+impl MutableBitmap {
+    /// Compute bitwise `&` between `self` and `rhs` in place, a word at a time.
+    ///
+    /// `rhs` may be sliced at any offset.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`
+    #[inline]
+    pub fn and_assign(&mut self, rhs: &Bitmap) {
+        and_assign::<u64>(self, rhs)
+    }
+
+    /// Compute bitwise `|` between `self` and `rhs` in place, a word at a time.
+    ///
+    /// `rhs` may be sliced at any offset.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`
+    #[inline]
+    pub fn or_assign(&mut self, rhs: &Bitmap) {
+        or_assign::<u64>(self, rhs)
+    }
+
+    /// Compute bitwise `^` between `self` and `rhs` in place, a word at a time.
+    ///
+    /// `rhs` may be sliced at any offset.
+    /// # Panics
+    /// This function panics iff `self.len() != rhs.len()`
+    #[inline]
+    pub fn xor_assign(&mut self, rhs: &Bitmap) {
+        xor_assign::<u64>(self, rhs)
+    }
+}
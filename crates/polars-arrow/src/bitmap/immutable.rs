@@ -6,7 +6,7 @@ use either::Either;
 use polars_error::{polars_bail, PolarsResult};
 
 use super::utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter};
-use super::{chunk_iter_to_vec, IntoIter, MutableBitmap};
+use super::{chunk_iter_to_vec, IntoIter, MutableBitmap, PrefixPopcount};
 use crate::bitmap::aligned::AlignedBitmapSlice;
 use crate::bitmap::iterator::{
     FastU32BitmapIter, FastU56BitmapIter, FastU64BitmapIter, TrueIdxIter,
@@ -329,6 +329,24 @@ impl Bitmap {
         self
     }
 
+    /// Resizes `self` to `new_len` bits, truncating it or extending it with unset bits.
+    #[must_use]
+    pub fn resize(self, new_len: usize) -> Self {
+        if new_len <= self.length {
+            return self.sliced(0, new_len);
+        }
+
+        let set_bits = self.lazy_set_bits();
+        let mut bitmap = self.make_mut();
+        bitmap.extend_constant(new_len - bitmap.len(), false);
+        let mut out = bitmap.freeze();
+        if let Some(set_bits) = set_bits {
+            // SAFETY: the extension only added unset bits.
+            unsafe { out.update_bit_count(set_bits) };
+        }
+        out
+    }
+
     /// Returns whether the bit at position `i` is set.
     /// # Panics
     /// Panics iff `i >= self.len()`.
@@ -422,6 +440,33 @@ impl Bitmap {
         count_zeros(&self.bytes, self.offset + offset, length)
     }
 
+    /// Precomputes the number of set bits before every 64-bit word, so that the number of set
+    /// bits in any prefix can be queried in `O(1)`.
+    pub fn prefix_popcount(&self) -> PrefixPopcount {
+        PrefixPopcount::new(self)
+    }
+
+    /// Returns whether `self` and `other` have a set bit at the same position within the first
+    /// `prefix_len` bits.
+    ///
+    /// The bits are compared 64 at a time and this returns as soon as an overlap is found.
+    /// `prefix_len` is clamped to the length of the shorter bitmap.
+    pub fn intersects_with_prefix(&self, other: &Bitmap, prefix_len: usize) -> bool {
+        let len = prefix_len.min(self.length).min(other.length);
+        if len == 0 {
+            return false;
+        }
+
+        let mut lhs = FastU64BitmapIter::new(&self.bytes, self.offset, len);
+        let mut rhs = FastU64BitmapIter::new(&other.bytes, other.offset, len);
+        if lhs.by_ref().zip(rhs.by_ref()).any(|(l, r)| l & r != 0) {
+            return true;
+        }
+        let (lhs_rem, _) = lhs.remainder();
+        let (rhs_rem, _) = rhs.remainder();
+        (lhs_rem[0] & rhs_rem[0]) | (lhs_rem[1] & rhs_rem[1]) != 0
+    }
+
     /// Creates a new [`Bitmap`] from a slice and length.
     /// # Panic
     /// Panics iff `length <= bytes.len() * 8`
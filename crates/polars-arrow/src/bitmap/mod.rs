@@ -19,3 +19,6 @@ pub use assign_ops::*;
 pub mod utils;
 
 pub mod bitmask;
+
+mod prefix_popcount;
+pub use prefix_popcount::PrefixPopcount;
@@ -526,6 +526,12 @@ unsafe fn extend_aligned_trusted_iter_unchecked(
 }
 
 impl MutableBitmap {
+    /// Extends `self` with the values of a slice of booleans.
+    #[inline]
+    pub fn extend_from_slice_of_bools(&mut self, values: &[bool]) {
+        self.extend_from_trusted_len_iter(values.iter().copied())
+    }
+
     /// Extends `self` from a [`TrustedLen`] iterator.
     #[inline]
     pub fn extend_from_trusted_len_iter<I: TrustedLen<Item = bool>>(&mut self, iterator: I) {
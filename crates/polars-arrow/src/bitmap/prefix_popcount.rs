@@ -0,0 +1,77 @@
+use super::Bitmap;
+
+/// Answers rank queries on a [`Bitmap`]: the number of set bits before a position.
+///
+/// Construction stores the bits as `u64` words together with the number of set bits before
+/// every word, which takes `O(len / 64)`. After that [`rank`](Self::rank) is `O(1)`, which makes
+/// it cheap to query the set bits of many prefixes, e.g. while advancing a cursor.
+#[derive(Debug, Clone)]
+pub struct PrefixPopcount {
+    words: Vec<u64>,
+    /// `cumulative[i]` is the number of set bits in `words[..i]`.
+    cumulative: Vec<usize>,
+    length: usize,
+}
+
+impl PrefixPopcount {
+    pub fn new(bitmap: &Bitmap) -> Self {
+        let chunks = bitmap.chunks::<u64>();
+        let remainder_len = chunks.remainder_len();
+        let remainder = chunks.remainder();
+        let mut words = chunks.collect::<Vec<_>>();
+        if remainder_len > 0 {
+            words.push(remainder & (u64::MAX >> (64 - remainder_len)));
+        }
+
+        let mut cumulative = Vec::with_capacity(words.len() + 1);
+        let mut count = 0;
+        cumulative.push(count);
+        for word in &words {
+            count += word.count_ones() as usize;
+            cumulative.push(count);
+        }
+
+        Self {
+            words,
+            cumulative,
+            length: bitmap.len(),
+        }
+    }
+
+    /// The number of bits of the underlying [`Bitmap`].
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// The number of set bits in the first `k` bits.
+    ///
+    /// # Panics
+    /// Panics if `k > self.len()`.
+    #[inline]
+    pub fn rank(&self, k: usize) -> usize {
+        assert!(
+            k <= self.length,
+            "rank {k} out of bounds for length {}",
+            self.length
+        );
+        let (word, bit) = (k / 64, k % 64);
+        let mut count = self.cumulative[word];
+        if bit > 0 {
+            count += (self.words[word] & (u64::MAX >> (64 - bit))).count_ones() as usize;
+        }
+        count
+    }
+
+    /// The number of unset bits in the first `k` bits.
+    ///
+    /// # Panics
+    /// Panics if `k > self.len()`.
+    #[inline]
+    pub fn rank_zeros(&self, k: usize) -> usize {
+        k - self.rank(k)
+    }
+}
@@ -174,8 +174,10 @@ fn cast_list_to_fixed_size_list<O: Offset>(
 ) -> PolarsResult<FixedSizeListArray> {
     let null_cnt = list.null_count();
     let new_values = if null_cnt == 0 {
+        // Sliced lists don't start at offset zero, the values are still contiguous.
+        let first = *list.offsets().first();
         let offsets = list.offsets().buffer().iter();
-        let expected = (0..list.len()).map(|ix| O::from_as_usize(ix * size));
+        let expected = (0..=list.len()).map(|ix| first + O::from_as_usize(ix * size));
 
         match offsets
             .zip(expected)
@@ -161,4 +161,49 @@ mod test {
             EPS
         );
     }
+
+    #[test]
+    fn test_ewm_mean_min_periods() {
+        let xs = vec![Some(1.0f64), Some(5.0), Some(7.0), Some(1.0), Some(2.0)];
+        for ignore_nulls in [false, true] {
+            assert_allclose!(
+                ewm_mean(xs.clone(), ALPHA, true, 3, ignore_nulls),
+                PrimitiveArray::from([
+                    None,
+                    None,
+                    Some(5.571_428_571_428_571),
+                    Some(3.133_333_333_333_333_3),
+                    Some(2.548_387_096_774_193_5),
+                ]),
+                EPS
+            );
+        }
+
+        // Nulls don't count towards `min_periods`.
+        let xs = vec![Some(1.0f64), None, Some(3.0), Some(4.0), None, Some(2.0)];
+        assert_allclose!(
+            ewm_mean(xs.clone(), ALPHA, true, 3, true),
+            PrimitiveArray::from([
+                None,
+                None,
+                None,
+                Some(3.285_714_285_714_285_6),
+                Some(3.285_714_285_714_285_6),
+                Some(2.6),
+            ]),
+            EPS
+        );
+        assert_allclose!(
+            ewm_mean(xs, ALPHA, true, 3, false),
+            PrimitiveArray::from([
+                None,
+                None,
+                None,
+                Some(3.461_538_461_538_461_7),
+                Some(3.461_538_461_538_461_7),
+                Some(2.422_222_222_222_222),
+            ]),
+            EPS
+        );
+    }
 }
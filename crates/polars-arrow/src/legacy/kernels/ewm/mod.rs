@@ -15,7 +15,12 @@ pub struct EWMOptions {
     pub alpha: f64,
     pub adjust: bool,
     pub bias: bool,
+    /// Minimum number of non-null observations before a value is emitted, earlier
+    /// rows are null. `0` behaves like `1`.
     pub min_periods: usize,
+    /// Whether nulls are skipped when computing the weights. If `false`, nulls still
+    /// decay the weights of earlier observations. In both cases a null row repeats
+    /// the previous value once `min_periods` is reached.
     pub ignore_nulls: bool,
 }
 
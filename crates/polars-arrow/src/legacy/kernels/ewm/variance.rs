@@ -808,4 +808,84 @@ mod test {
             EPS
         );
     }
+
+    #[test]
+    fn test_ewm_var_std_min_periods() {
+        let xs = vec![Some(1.0f64), Some(5.0), Some(7.0), Some(1.0), Some(2.0)];
+        for ignore_nulls in [false, true] {
+            assert_allclose!(
+                ewm_var(xs.clone(), ALPHA, true, false, 3, ignore_nulls),
+                PrimitiveArray::from([
+                    None,
+                    None,
+                    Some(7.428_571_428_571_429),
+                    Some(11.542_857_142_857_143),
+                    Some(5.883_870_967_741_934_5),
+                ]),
+                EPS
+            );
+            assert_allclose!(
+                ewm_std(xs.clone(), ALPHA, true, false, 3, ignore_nulls),
+                PrimitiveArray::from([
+                    None,
+                    None,
+                    Some(2.725_540_575_476_987_5),
+                    Some(3.397_478_056_273_085_3),
+                    Some(2.425_669_179_369_259),
+                ]),
+                EPS
+            );
+        }
+
+        // Nulls don't count towards `min_periods`.
+        let xs = vec![Some(1.0f64), None, Some(3.0), Some(4.0), None, Some(2.0)];
+        assert_allclose!(
+            ewm_var(xs.clone(), ALPHA, true, false, 3, true),
+            PrimitiveArray::from([
+                None,
+                None,
+                None,
+                Some(1.857_142_857_142_856_8),
+                Some(1.857_142_857_142_856_8),
+                Some(1.457_142_857_142_856_9),
+            ]),
+            EPS
+        );
+        assert_allclose!(
+            ewm_std(xs.clone(), ALPHA, true, false, 3, true),
+            PrimitiveArray::from([
+                None,
+                None,
+                None,
+                Some(1.362_770_287_738_493_7),
+                Some(1.362_770_287_738_493_7),
+                Some(1.207_121_724_244_434_5),
+            ]),
+            EPS
+        );
+        assert_allclose!(
+            ewm_var(xs.clone(), ALPHA, true, false, 3, false),
+            PrimitiveArray::from([
+                None,
+                None,
+                None,
+                Some(1.363_636_363_636_363_5),
+                Some(1.363_636_363_636_363_5),
+                Some(1.417_391_304_347_826_1),
+            ]),
+            EPS
+        );
+        assert_allclose!(
+            ewm_std(xs, ALPHA, true, false, 3, false),
+            PrimitiveArray::from([
+                None,
+                None,
+                None,
+                Some(1.167_748_416_242_284_4),
+                Some(1.167_748_416_242_284_4),
+                Some(1.190_542_441_220_734_6),
+            ]),
+            EPS
+        );
+    }
 }
@@ -82,8 +82,172 @@ pub struct RollingVarParams {
     pub ddof: u8,
 }
 
+/// Parameters for `rolling_sum` and `rolling_mean`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RollingSumParams {
+    /// Accumulate float windows with Neumaier (Kahan-Babuska) compensated summation.
+    ///
+    /// The fast path adds entering and subtracts leaving values from a running sum, so rounding
+    /// errors build up over long inputs with mixed magnitudes. The precise path tracks those
+    /// rounding errors and keeps every window within a few ULPs of a fresh summation, at the cost
+    /// of a few extra float operations per update. Has no effect on integer data.
+    pub precise: bool,
+}
+
+impl RollingSumParams {
+    pub(super) fn is_precise<T: IsFloat>(params: &DynArgs) -> bool {
+        T::is_float()
+            && params
+                .as_ref()
+                .and_then(|p| p.downcast_ref::<RollingSumParams>())
+                .map_or(false, |p| p.precise)
+    }
+}
+
+/// Adds `value` to `sum` and accumulates the rounding error of that addition in `compensation`,
+/// the true sum is `sum + compensation`.
+#[inline]
+fn compensated_add<T>(sum: &mut T, compensation: &mut T, value: T)
+where
+    T: NativeType + Add<Output = T> + Sub<Output = T>,
+{
+    let abs = |v: T| {
+        if v.tot_lt(&T::default()) {
+            T::default() - v
+        } else {
+            v
+        }
+    };
+    let t = *sum + value;
+    if abs(*sum).tot_ge(&abs(value)) {
+        *compensation = *compensation + ((*sum - t) + value);
+    } else {
+        *compensation = *compensation + ((value - t) + *sum);
+    }
+    *sum = t;
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct RollingQuantileParams {
     pub prob: f64,
     pub interpol: QuantileInterpolOptions,
 }
+
+/// Applies `aggregator` to the valid values of every window and the weights that line up with
+/// them. A null value drops its weight as well.
+///
+/// The weights line up with the start of the window, a window that is cut off by the bounds of
+/// the array uses a prefix of the weights. A window is null if it has fewer than `min_periods`
+/// valid values or if `aggregator` returns `None`.
+fn rolling_apply_weighted<T, Fo, Fa>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    window_size: usize,
+    min_periods: usize,
+    det_offsets_fn: Fo,
+    aggregator: Fa,
+    weights: &[T],
+) -> ArrayRef
+where
+    T: NativeType,
+    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
+    Fa: Fn(&[T], &[T]) -> Option<T>,
+{
+    assert_eq!(weights.len(), window_size);
+    let len = values.len();
+    let mut valid_values = Vec::with_capacity(window_size);
+    let mut valid_weights = Vec::with_capacity(window_size);
+
+    let out = (0..len).map(|idx| {
+        let (start, end) = det_offsets_fn(idx, window_size, len);
+        // SAFETY: we are in bounds.
+        let window = unsafe { values.get_unchecked(start..end) };
+        let weights = &weights[..end - start];
+        match validity {
+            None if end - start < min_periods => None,
+            None => aggregator(window, weights),
+            Some(validity) => {
+                valid_values.clear();
+                valid_weights.clear();
+                for (i, (v, w)) in (start..end).zip(window.iter().zip(weights)) {
+                    // SAFETY: we are in bounds.
+                    if unsafe { validity.get_bit_unchecked(i) } {
+                        valid_values.push(*v);
+                        valid_weights.push(*w);
+                    }
+                }
+                if valid_values.len() < min_periods {
+                    None
+                } else {
+                    aggregator(&valid_values, &valid_weights)
+                }
+            },
+        }
+    });
+    Box::new(PrimitiveArray::from_trusted_len_iter(out))
+}
+
+/// The weighted mean, `None` if the weights sum to zero.
+fn compute_mean_weights<T: Float>(values: &[T], weights: &[T]) -> Option<T> {
+    let (wsum, sum) = values
+        .iter()
+        .zip(weights)
+        .fold((T::zero(), T::zero()), |(wsum, sum), (&v, &w)| {
+            (wsum + w, sum + v * w)
+        });
+    (wsum != T::zero()).then(|| sum / wsum)
+}
+
+/// The weighted variance, `None` if it is undefined.
+///
+/// The weights are treated as reliability weights, the variance doesn't change if all weights
+/// are scaled by the same factor. With a `ddof` of 1 this is unbiased, like the unweighted
+/// variance.
+fn compute_var_weights<T: Float>(values: &[T], weights: &[T], ddof: u8) -> Option<T> {
+    let (v1, v2, sum) = values.iter().zip(weights).fold(
+        (T::zero(), T::zero(), T::zero()),
+        |(v1, v2, sum), (&v, &w)| (v1 + w, v2 + w * w, sum + v * w),
+    );
+    if v1 <= T::zero() {
+        return None;
+    }
+    let mean = sum / v1;
+    let ddof: T = NumCast::from(ddof).unwrap();
+    let denom = v1 - ddof * v2 / v1;
+    if denom <= T::zero() {
+        return None;
+    }
+    let ssq = values
+        .iter()
+        .zip(weights)
+        .fold(T::zero(), |ssq, (&v, &w)| ssq + w * (v - mean) * (v - mean));
+    Some(ssq / denom)
+}
+
+fn ddof_from_params(params: &DynArgs) -> u8 {
+    params
+        .as_ref()
+        .map_or(1, |p| p.downcast_ref::<RollingVarParams>().unwrap().ddof)
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    /// Alternates large and small magnitudes, every value is a multiple of 0.5 so the exact
+    /// window sums can be computed in integer arithmetic.
+    pub(crate) fn adversarial_values(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| match i % 4 {
+                0 => 1e16,
+                2 => -1e16,
+                _ => 0.5 * ((i % 7) as f64),
+            })
+            .collect()
+    }
+
+    pub(crate) fn assert_within_ulps(out: &[f64], expected: &[f64], ulps: f64) {
+        for (i, (a, b)) in out.iter().zip(expected).enumerate() {
+            let tol = ulps * f64::EPSILON * b.abs().max(1.0);
+            assert!((a - b).abs() <= tol, "index {i}: got {a}, expected {b}");
+        }
+    }
+}
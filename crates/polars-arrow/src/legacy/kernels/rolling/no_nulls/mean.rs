@@ -8,7 +8,13 @@ pub struct MeanWindow<'a, T> {
 
 impl<
         'a,
-        T: NativeType + IsFloat + std::iter::Sum + AddAssign + SubAssign + Div<Output = T> + NumCast,
+        T: NativeType
+            + IsFloat
+            + std::iter::Sum
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Div<Output = T>
+            + NumCast,
     > RollingAggWindowNoNulls<'a, T> for MeanWindow<'a, T>
 {
     fn new(slice: &'a [T], start: usize, end: usize, params: DynArgs) -> Self {
@@ -29,7 +35,7 @@ pub fn rolling_mean<T>(
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
-    _params: DynArgs,
+    params: DynArgs,
 ) -> PolarsResult<ArrayRef>
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
@@ -44,25 +50,25 @@ where
             window_size,
             min_periods,
             offset_fn,
-            None,
+            params,
         ),
         Some(weights) => {
-            // A weighted mean is a weighted sum with normalized weights
-            let mut wts = no_nulls::coerce_weights(weights);
+            // A weighted mean is a weighted sum with the weights of the window normalized
+            let wts = no_nulls::coerce_weights(weights);
             let wsum = wts.iter().fold(T::zero(), |acc, x| acc + *x);
             polars_ensure!(
                 wsum != T::zero(),
                 ComputeError: "Weighted mean is undefined if weights sum to 0"
             );
-            wts.iter_mut().for_each(|w| *w = *w / wsum);
-            no_nulls::rolling_apply_weights(
+            Ok(rolling_apply_weighted(
                 values,
+                None,
                 window_size,
                 min_periods,
                 offset_fn,
-                no_nulls::compute_sum_weights,
+                compute_mean_weights,
                 &wts,
-            )
+            ))
         },
     }
 }
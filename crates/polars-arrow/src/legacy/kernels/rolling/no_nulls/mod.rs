@@ -112,25 +112,6 @@ where
     )))
 }
 
-fn compute_var_weights<T>(vals: &[T], weights: &[T]) -> T
-where
-    T: Float + std::ops::AddAssign,
-{
-    // Assumes the weights have already been standardized to 1
-    debug_assert!(
-        weights.iter().fold(T::zero(), |acc, x| acc + *x) == T::one(),
-        "Rolling weighted variance Weights don't sum to 1"
-    );
-    let (wssq, wmean) = vals
-        .iter()
-        .zip(weights)
-        .fold((T::zero(), T::zero()), |(wssq, wsum), (&v, &w)| {
-            (wssq + v * v * w, wsum + v * w)
-        });
-
-    wssq - wmean * wmean
-}
-
 pub(crate) fn compute_sum_weights<T>(values: &[T], weights: &[T]) -> T
 where
     T: std::iter::Sum<T> + Copy + std::ops::Mul<Output = T>,
@@ -3,21 +3,62 @@ use super::*;
 pub struct SumWindow<'a, T> {
     slice: &'a [T],
     sum: T,
+    compensation: T,
+    precise: bool,
     last_start: usize,
     last_end: usize,
 }
 
-impl<'a, T: NativeType + IsFloat + std::iter::Sum + AddAssign + SubAssign>
+impl<'a, T: NativeType + IsFloat + std::iter::Sum + Add<Output = T> + Sub<Output = T>>
+    SumWindow<'a, T>
+{
+    unsafe fn compute_sum(&mut self, start: usize, end: usize) {
+        let values = self.slice.get_unchecked(start..end).iter().copied();
+        if self.precise {
+            self.sum = T::default();
+            self.compensation = T::default();
+            for v in values {
+                compensated_add(&mut self.sum, &mut self.compensation, v);
+            }
+        } else {
+            self.sum = values.sum::<T>();
+        }
+    }
+
+    #[inline]
+    fn add(&mut self, value: T) {
+        if self.precise {
+            compensated_add(&mut self.sum, &mut self.compensation, value);
+        } else {
+            self.sum = self.sum + value;
+        }
+    }
+
+    #[inline]
+    fn sub(&mut self, value: T) {
+        if self.precise {
+            compensated_add(&mut self.sum, &mut self.compensation, T::default() - value);
+        } else {
+            self.sum = self.sum - value;
+        }
+    }
+}
+
+impl<'a, T: NativeType + IsFloat + std::iter::Sum + Add<Output = T> + Sub<Output = T>>
     RollingAggWindowNoNulls<'a, T> for SumWindow<'a, T>
 {
-    fn new(slice: &'a [T], start: usize, end: usize, _params: DynArgs) -> Self {
-        let sum = slice[start..end].iter().copied().sum::<T>();
-        Self {
+    fn new(slice: &'a [T], start: usize, end: usize, params: DynArgs) -> Self {
+        let mut out = Self {
             slice,
-            sum,
+            sum: T::default(),
+            compensation: T::default(),
+            precise: RollingSumParams::is_precise::<T>(&params),
             last_start: start,
             last_end: end,
-        }
+        };
+        // SAFETY: the window is in bounds.
+        unsafe { out.compute_sum(start, end) };
+        out
     }
 
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
@@ -38,7 +79,7 @@ impl<'a, T: NativeType + IsFloat + std::iter::Sum + AddAssign + SubAssign>
                     break;
                 }
 
-                self.sum -= *leaving_value;
+                self.sub(*leaving_value);
             }
             recompute_sum
         };
@@ -46,21 +87,20 @@ impl<'a, T: NativeType + IsFloat + std::iter::Sum + AddAssign + SubAssign>
 
         // we traverse all values and compute
         if recompute_sum {
-            self.sum = self
-                .slice
-                .get_unchecked(start..end)
-                .iter()
-                .copied()
-                .sum::<T>();
+            self.compute_sum(start, end);
         }
         // remove leaving values.
         else {
             for idx in self.last_end..end {
-                self.sum += *self.slice.get_unchecked(idx);
+                self.add(*self.slice.get_unchecked(idx));
             }
         }
         self.last_end = end;
-        Some(self.sum)
+        if self.precise {
+            Some(self.sum + self.compensation)
+        } else {
+            Some(self.sum)
+        }
     }
 }
 
@@ -70,7 +110,7 @@ pub fn rolling_sum<T>(
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
-    _params: DynArgs,
+    params: DynArgs,
 ) -> PolarsResult<ArrayRef>
 where
     T: NativeType
@@ -88,14 +128,14 @@ where
             window_size,
             min_periods,
             det_offsets_center,
-            None,
+            params,
         ),
         (false, None) => rolling_apply_agg_window::<SumWindow<_>, _, _>(
             values,
             window_size,
             min_periods,
             det_offsets,
-            None,
+            params,
         ),
         (true, Some(weights)) => {
             let weights = no_nulls::coerce_weights(weights);
@@ -125,6 +165,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::legacy::kernels::rolling::test_utils::{adversarial_values, assert_within_ulps};
     #[test]
     fn test_rolling_sum() {
         let values = &[1.0f64, 2.0, 3.0, 4.0];
@@ -176,4 +217,41 @@ mod test {
             )
         );
     }
+
+    fn exact_window_sums(values: &[f64], window_size: usize) -> Vec<f64> {
+        (0..values.len())
+            .map(|i| {
+                let start = i.saturating_sub(window_size - 1);
+                let sum: i128 = values[start..=i].iter().map(|v| (v * 2.0) as i128).sum();
+                sum as f64 / 2.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rolling_sum_precise() {
+        let values = adversarial_values(100_000);
+        let window_size = 7;
+        let expected = exact_window_sums(&values, window_size);
+        let params: DynArgs = Some(Arc::new(RollingSumParams { precise: true }));
+
+        let out = rolling_sum(&values, window_size, 1, false, None, params.clone()).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert_within_ulps(out.values(), &expected, 4.0);
+
+        // The fast path drifts away from the exact sums.
+        let out = rolling_sum(&values, window_size, 1, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let last = *out.values().last().unwrap();
+        assert!((last - expected.last().unwrap()).abs() > 1.0);
+
+        let out = super::super::rolling_mean(&values, window_size, 1, false, None, params).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let expected_mean = expected
+            .iter()
+            .enumerate()
+            .map(|(i, v)| v / (i + 1).min(window_size) as f64)
+            .collect::<Vec<_>>();
+        assert_within_ulps(out.values(), &expected_mean, 4.0);
+    }
 }
@@ -165,23 +165,22 @@ where
             params,
         ),
         Some(weights) => {
-            // Validate and standardize the weights like we do for the mean. This definition is fine
-            // because frequency weights and unbiasing don't make sense for rolling operations.
-            let mut wts = no_nulls::coerce_weights(weights);
+            let wts = no_nulls::coerce_weights(weights);
             let wsum = wts.iter().fold(T::zero(), |acc, x| acc + *x);
             polars_ensure!(
                 wsum != T::zero(),
                 ComputeError: "Weighted variance is undefined if weights sum to 0"
             );
-            wts.iter_mut().for_each(|w| *w = *w / wsum);
-            super::rolling_apply_weights(
+            let ddof = ddof_from_params(&params);
+            Ok(rolling_apply_weighted(
                 values,
+                None,
                 window_size,
                 min_periods,
                 offset_fn,
-                compute_var_weights,
+                |values, weights| compute_var_weights(values, weights, ddof),
                 &wts,
-            )
+            ))
         },
     }
 }
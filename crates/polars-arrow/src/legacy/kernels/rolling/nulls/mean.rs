@@ -36,7 +36,7 @@ pub fn rolling_mean<T>(
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
-    _params: DynArgs,
+    params: DynArgs,
 ) -> ArrayRef
 where
     T: NativeType
@@ -45,10 +45,25 @@ where
         + Add<Output = T>
         + Sub<Output = T>
         + NumCast
-        + Div<Output = T>,
+        + Div<Output = T>
+        + Float,
 {
-    if weights.is_some() {
-        panic!("weights not yet supported on array with null values")
+    if let Some(weights) = weights {
+        let offset_fn = if center {
+            det_offsets_center
+        } else {
+            det_offsets
+        };
+        let wts = no_nulls::coerce_weights(weights);
+        return rolling_apply_weighted(
+            arr.values().as_slice(),
+            arr.validity(),
+            window_size,
+            min_periods,
+            offset_fn,
+            compute_mean_weights,
+            &wts,
+        );
     }
     if center {
         rolling_apply_agg_window::<MeanWindow<_>, _, _>(
@@ -57,7 +72,7 @@ where
             window_size,
             min_periods,
             det_offsets_center,
-            None,
+            params,
         )
     } else {
         rolling_apply_agg_window::<MeanWindow<_>, _, _>(
@@ -66,7 +81,7 @@ where
             window_size,
             min_periods,
             det_offsets,
-            None,
+            params,
         )
     }
 }
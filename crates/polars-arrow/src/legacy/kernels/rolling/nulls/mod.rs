@@ -94,6 +94,7 @@ mod test {
     use crate::array::{Array, Int32Array};
     use crate::buffer::Buffer;
     use crate::datatypes::ArrowDataType;
+    use crate::legacy::kernels::rolling::test_utils::{adversarial_values, assert_within_ulps};
 
     fn get_null_arr() -> PrimitiveArray<f64> {
         // 1, None, -1, 4
@@ -140,6 +141,31 @@ mod test {
         assert_eq!(out, &[None, None, None, None]);
     }
 
+    #[test]
+    fn test_rolling_sum_nulls_precise() {
+        // The adversarial values with a null every 5th slot.
+        let n = 100_000usize;
+        let window_size = 6;
+        let values = adversarial_values(n);
+        let validity = (0..n).map(|i| i % 5 != 4).collect::<Bitmap>();
+        let expected = (0..n)
+            .map(|i| {
+                let start = (i + 1).saturating_sub(window_size);
+                let sum: i128 = (start..=i)
+                    .filter(|j| validity.get_bit(*j))
+                    .map(|j| (values[j] * 2.0) as i128)
+                    .sum();
+                sum as f64 / 2.0
+            })
+            .collect::<Vec<_>>();
+        let arr = &PrimitiveArray::new(ArrowDataType::Float64, values.into(), Some(validity));
+
+        let params: DynArgs = Some(Arc::new(RollingSumParams { precise: true }));
+        let out = rolling_sum(arr, window_size, 1, false, None, params);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        assert_within_ulps(out.values(), &expected, 4.0);
+    }
+
     #[test]
     fn test_rolling_mean_nulls() {
         let arr = get_null_arr();
@@ -4,6 +4,8 @@ pub struct SumWindow<'a, T> {
     slice: &'a [T],
     validity: &'a Bitmap,
     sum: Option<T>,
+    compensation: T,
+    precise: bool,
     last_start: usize,
     last_end: usize,
     pub(super) null_count: usize,
@@ -12,23 +14,53 @@ pub struct SumWindow<'a, T> {
 impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T>> SumWindow<'a, T> {
     // compute sum from the entire window
     unsafe fn compute_sum_and_null_count(&mut self, start: usize, end: usize) -> Option<T> {
-        let mut sum = None;
-        let mut idx = start;
+        self.sum = None;
+        self.compensation = T::default();
         self.null_count = 0;
-        for value in &self.slice[start..end] {
+        for (idx, value) in (start..end).zip(&self.slice[start..end]) {
             let valid = self.validity.get_bit_unchecked(idx);
             if valid {
-                match sum {
-                    None => sum = Some(*value),
-                    Some(current) => sum = Some(*value + current),
-                }
+                self.add(*value);
             } else {
                 self.null_count += 1;
             }
-            idx += 1;
         }
-        self.sum = sum;
-        sum
+        self.sum()
+    }
+
+    #[inline]
+    fn add(&mut self, value: T) {
+        match self.sum {
+            None => self.sum = Some(value),
+            Some(current) if self.precise => {
+                let mut sum = current;
+                compensated_add(&mut sum, &mut self.compensation, value);
+                self.sum = Some(sum);
+            },
+            Some(current) => self.sum = Some(current + value),
+        }
+    }
+
+    #[inline]
+    fn sub(&mut self, value: T) {
+        if let Some(current) = self.sum {
+            if self.precise {
+                let mut sum = current;
+                compensated_add(&mut sum, &mut self.compensation, T::default() - value);
+                self.sum = Some(sum);
+            } else {
+                self.sum = Some(current - value);
+            }
+        }
+    }
+
+    #[inline]
+    fn sum(&self) -> Option<T> {
+        if self.precise {
+            self.sum.map(|sum| sum + self.compensation)
+        } else {
+            self.sum
+        }
     }
 }
 
@@ -40,12 +72,14 @@ impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T>> RollingAgg
         validity: &'a Bitmap,
         start: usize,
         end: usize,
-        _params: DynArgs,
+        params: DynArgs,
     ) -> Self {
         let mut out = Self {
             slice,
             validity,
             sum: None,
+            compensation: T::default(),
+            precise: RollingSumParams::is_precise::<T>(&params),
             last_start: start,
             last_end: end,
             null_count: 0,
@@ -74,7 +108,7 @@ impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T>> RollingAgg
                         recompute_sum = true;
                         break;
                     }
-                    self.sum = self.sum.map(|v| v - *leaving_value)
+                    self.sub(*leaving_value)
                 } else {
                     // null value leaving the window
                     self.null_count -= 1;
@@ -100,11 +134,7 @@ impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T>> RollingAgg
                 let valid = self.validity.get_bit_unchecked(idx);
 
                 if valid {
-                    let value = *self.slice.get_unchecked(idx);
-                    match self.sum {
-                        None => self.sum = Some(value),
-                        Some(current) => self.sum = Some(current + value),
-                    }
+                    self.add(*self.slice.get_unchecked(idx));
                 } else {
                     // null value entering the window
                     self.null_count += 1;
@@ -112,7 +142,7 @@ impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T>> RollingAgg
             }
         }
         self.last_end = end;
-        self.sum
+        self.sum()
     }
 
     fn is_valid(&self, min_periods: usize) -> bool {
@@ -126,13 +156,34 @@ pub fn rolling_sum<T>(
     min_periods: usize,
     center: bool,
     weights: Option<&[f64]>,
-    _params: DynArgs,
+    params: DynArgs,
 ) -> ArrayRef
 where
-    T: NativeType + IsFloat + PartialOrd + Add<Output = T> + Sub<Output = T>,
+    T: NativeType
+        + IsFloat
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + std::iter::Sum
+        + NumCast,
 {
-    if weights.is_some() {
-        panic!("weights not yet supported on array with null values")
+    if let Some(weights) = weights {
+        let offset_fn = if center {
+            det_offsets_center
+        } else {
+            det_offsets
+        };
+        let wts = no_nulls::coerce_weights(weights);
+        return rolling_apply_weighted(
+            arr.values().as_slice(),
+            arr.validity(),
+            window_size,
+            min_periods,
+            offset_fn,
+            |values, weights| Some(no_nulls::compute_sum_weights(values, weights)),
+            &wts,
+        );
     }
     if center {
         rolling_apply_agg_window::<SumWindow<_>, _, _>(
@@ -141,7 +192,7 @@ where
             window_size,
             min_periods,
             det_offsets_center,
-            None,
+            params,
         )
     } else {
         rolling_apply_agg_window::<SumWindow<_>, _, _>(
@@ -150,7 +201,7 @@ where
             window_size,
             min_periods,
             det_offsets,
-            None,
+            params,
         )
     }
 }
@@ -202,14 +202,24 @@ pub fn rolling_var<T>(
 where
     T: NativeType + std::iter::Sum<T> + Zero + AddAssign + SubAssign + IsFloat + Float,
 {
-    if weights.is_some() {
-        panic!("weights not yet supported on array with null values")
-    }
     let offsets_fn = if center {
         det_offsets_center
     } else {
         det_offsets
     };
+    if let Some(weights) = weights {
+        let wts = no_nulls::coerce_weights(weights);
+        let ddof = ddof_from_params(&params);
+        return rolling_apply_weighted(
+            arr.values().as_slice(),
+            arr.validity(),
+            window_size,
+            min_periods,
+            offsets_fn,
+            |values, weights| compute_var_weights(values, weights, ddof),
+            &wts,
+        );
+    }
     rolling_apply_agg_window::<VarWindow<_>, _, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
@@ -3,7 +3,9 @@ pub use crate::legacy::array::default_arrays::*;
 pub use crate::legacy::array::*;
 pub use crate::legacy::index::*;
 pub use crate::legacy::kernels::rolling::no_nulls::QuantileInterpolOptions;
-pub use crate::legacy::kernels::rolling::{DynArgs, RollingQuantileParams, RollingVarParams};
+pub use crate::legacy::kernels::rolling::{
+    DynArgs, RollingQuantileParams, RollingSumParams, RollingVarParams,
+};
 pub use crate::legacy::kernels::{Ambiguous, NonExistent};
 
 pub type LargeStringArray = Utf8Array<i64>;
@@ -272,7 +272,7 @@ impl StructChunked {
         self.field.set_name(name.into())
     }
 
-    pub(crate) fn try_apply_fields<F>(&self, func: F) -> PolarsResult<Self>
+    pub fn try_apply_fields<F>(&self, func: F) -> PolarsResult<Self>
     where
         F: Fn(&Series) -> PolarsResult<Series>,
     {
@@ -3,8 +3,9 @@ use std::iter::Map;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use ahash::RandomState;
 use arrow::array::*;
-use arrow::bitmap::Bitmap;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -53,6 +54,7 @@ use arrow::legacy::kernels::concatenate::concatenate_owned_unchecked;
 use arrow::legacy::prelude::*;
 use bitflags::bitflags;
 
+use crate::hashing::_boost_hash_combine;
 use crate::series::IsSorted;
 use crate::utils::{first_non_null, last_non_null};
 
@@ -183,6 +185,46 @@ impl Settings {
 }
 
 impl<T: PolarsDataType> ChunkedArray<T> {
+    /// Create a new [`ChunkedArray`] from existing chunks, validating that `field` describes a
+    /// [`ChunkedArray`] of type `T` and that every chunk has the expected physical datatype.
+    ///
+    /// This is a safe alternative to [`ChunkedArray::from_chunks_and_dtype`], e.g. for ingesting
+    /// arrays over FFI. Chunks of an equivalent Arrow datatype, e.g. `LargeUtf8` for a string
+    /// array, are converted. The length and null count are computed from the chunks.
+    pub fn from_chunks_validated(field: Field, chunks: Vec<ArrayRef>) -> PolarsResult<Self> {
+        polars_ensure!(
+            !chunks.is_empty(),
+            ComputeError: "cannot create ChunkedArray '{}' from zero chunks", field.name()
+        );
+        polars_ensure!(
+            std::mem::discriminant(&T::get_dtype()) == std::mem::discriminant(field.data_type()),
+            SchemaMismatch: "cannot create a ChunkedArray of type {} from field '{}' with dtype {}",
+            T::get_dtype(), field.name(), field.data_type()
+        );
+        let expected = field.data_type().to_physical();
+        let expected_arrow = expected.to_arrow(true);
+        let chunks = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, arr)| {
+                if arr.data_type() == &expected_arrow {
+                    return Ok(arr);
+                }
+                let dtype = DataType::from(arr.data_type());
+                polars_ensure!(
+                    dtype.to_physical() == expected,
+                    SchemaMismatch: "chunk {} of '{}' has dtype {}, expected {}",
+                    i, field.name(), dtype, field.data_type()
+                );
+                arrow::compute::cast::cast_unchecked(arr.as_ref(), &expected_arrow)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // SAFETY: the chunk datatypes are validated above.
+        Ok(unsafe {
+            Self::from_chunks_and_dtype_unchecked(field.name(), chunks, field.data_type().clone())
+        })
+    }
+
     pub(crate) fn is_sorted_ascending_flag(&self) -> bool {
         self.bit_settings.contains(Settings::SORTED_ASC)
     }
@@ -300,6 +342,31 @@ impl<T: PolarsDataType> ChunkedArray<T> {
         self.iter_validities().any(|valid| valid.is_some())
     }
 
+    /// Replace the validity with the result of `f` applied to the current validity.
+    ///
+    /// The array is rechunked to a single chunk first, so `f` is called exactly once with the
+    /// validity of the whole array. Returning `None` marks all values as valid.
+    ///
+    /// # Panics
+    /// Panics if the returned bitmap does not have the same length as the array.
+    pub fn map_validity(&self, f: impl FnOnce(Option<&Bitmap>) -> Option<Bitmap>) -> Self {
+        let mut ca = self.rechunk();
+        let arr = &ca.chunks[0];
+        let validity = f(arr.validity());
+        if let Some(validity) = &validity {
+            assert_eq!(
+                validity.len(),
+                arr.len(),
+                "validity must have the same length as the array"
+            );
+        }
+        ca.chunks[0] = arr.with_validity(validity);
+        ca.compute_len();
+        // Nulls may have moved, so the sorted flags can no longer be trusted.
+        ca.set_flags(Settings::empty());
+        ca
+    }
+
     /// Shrink the capacity of this array to fit its length.
     pub fn shrink_to_fit(&mut self) {
         self.chunks = vec![concatenate_owned_unchecked(self.chunks.as_slice()).unwrap()];
@@ -538,13 +605,13 @@ where
     T: PolarsDataType,
 {
     /// Should be used to match the chunk_id of another [`ChunkedArray`].
-    /// # Panics
-    /// It is the callers responsibility to ensure that this [`ChunkedArray`] has a single chunk.
+    ///
+    /// If this [`ChunkedArray`] has multiple chunks, it is rechunked first. The lengths in
+    /// `chunk_id` must add up to the length of this [`ChunkedArray`].
     pub(crate) fn match_chunks<I>(&self, chunk_id: I) -> Self
     where
         I: Iterator<Item = usize>,
     {
-        debug_assert!(self.chunks.len() == 1);
         // Takes a ChunkedArray containing a single chunk.
         let slice = |ca: &Self| {
             let array = &ca.chunks[0];
@@ -683,6 +750,42 @@ where
                 .trust_my_length(self.len())
         }
     }
+
+    /// Get a [`Bitmap`] with a set bit for every non-null value for which `pred` returns
+    /// true. Null values give an unset bit.
+    pub fn mask_where(&self, pred: impl Fn(T::Native) -> bool) -> Bitmap {
+        let mut mask = MutableBitmap::with_capacity(self.len());
+        for arr in self.downcast_iter() {
+            if arr.null_count() == 0 {
+                mask.extend_from_trusted_len_iter(arr.values().iter().map(|v| pred(*v)));
+            } else {
+                mask.extend_from_trusted_len_iter(
+                    arr.iter().map(|opt_v| opt_v.map_or(false, |v| pred(*v))),
+                );
+            }
+        }
+        mask.into()
+    }
+}
+
+impl<T> ChunkedArray<T>
+where
+    T: PolarsDataType,
+    ChunkedArray<T>: VecHash,
+{
+    /// Hash the values and the validity of this [`ChunkedArray`] to a single `u64`.
+    ///
+    /// The hash is computed over the rows and doesn't depend on how the array is chunked, so
+    /// equal arrays have equal fingerprints. The fingerprint is stable within a version of
+    /// polars, but may change between versions.
+    pub fn fingerprint(&self, seed: u64) -> u64 {
+        let random_state = RandomState::with_seeds(seed, seed, seed, seed);
+        let mut hashes = Vec::with_capacity(self.len());
+        self.vec_hash(random_state.clone(), &mut hashes).unwrap();
+        hashes
+            .into_iter()
+            .fold(random_state.hash_one(self.len()), _boost_hash_combine)
+    }
 }
 
 impl<T: PolarsDataType> Clone for ChunkedArray<T> {
@@ -765,7 +868,10 @@ impl<T: PolarsDataType> Default for ChunkedArray<T> {
 
 #[cfg(test)]
 pub(crate) mod test {
+    use arrow::bitmap::Bitmap;
+
     use crate::prelude::*;
+    use crate::series::IsSorted;
 
     pub(crate) fn get_chunked_array() -> Int32Chunked {
         ChunkedArray::new("a", &[1, 2, 3])
@@ -840,6 +946,23 @@ pub(crate) mod test {
         assert_eq!(new.len(), 2)
     }
 
+    #[test]
+    fn take_opt() {
+        let a = get_chunked_array();
+        let idx = IdxCa::new("idx", &[0 as IdxSize, 99, 2]);
+        let new = a.take_opt(&idx);
+        assert_eq!(Vec::from(&new), &[Some(1), None, Some(3)]);
+
+        let idx = IdxCa::new("idx", &[None, Some(1 as IdxSize)]);
+        let new = a.take_opt(&idx);
+        assert_eq!(Vec::from(&new), &[None, Some(2)]);
+
+        let a = StringChunked::new("a", &["x", "y", "z"]);
+        let idx = IdxCa::new("idx", &[0 as IdxSize, 99, 2]);
+        let new = a.take_opt(&idx);
+        assert_eq!(Vec::from(&new), &[Some("x"), None, Some("z")]);
+    }
+
     #[test]
     fn cast() {
         let a = get_chunked_array();
@@ -952,4 +1075,127 @@ pub(crate) mod test {
             .sum::<usize>();
         assert!(before > after);
     }
+
+    #[test]
+    fn test_from_chunks_validated() {
+        let ok = Int32Chunked::new("", &[Some(1), None, Some(3)]);
+        let ca = Int32Chunked::from_chunks_validated(
+            Field::new("a", DataType::Int32),
+            ok.chunks().clone(),
+        )
+        .unwrap();
+        assert_eq!(ca.name(), "a");
+        assert_eq!(ca.len(), 3);
+        assert_eq!(ca.null_count(), 1);
+
+        let wrong = Int64Chunked::new("", &[1, 2, 3]);
+        let err = Int32Chunked::from_chunks_validated(
+            Field::new("a", DataType::Int32),
+            wrong.chunks().clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+
+        // Equivalent Arrow types are accepted.
+        let large = arrow::array::Utf8Array::<i64>::from([Some("x"), None]).boxed();
+        let ca =
+            StringChunked::from_chunks_validated(Field::new("s", DataType::String), vec![large])
+                .unwrap();
+        assert_eq!(Vec::from(&ca), &[Some("x"), None]);
+
+        let err = Int32Chunked::from_chunks_validated(Field::new("a", DataType::Int64), vec![])
+            .unwrap_err();
+        assert!(matches!(err, PolarsError::ComputeError(_)));
+        let err = Int32Chunked::from_chunks_validated(
+            Field::new("a", DataType::Int64),
+            ok.chunks().clone(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+    }
+
+    #[test]
+    fn test_map_validity() {
+        let ca = Int32Chunked::new("a", &[Some(1), None, Some(3), Some(4)]);
+        let mut other = Int32Chunked::new("a", &[Some(5)]);
+        other.append(&ca.slice(0, 3));
+        assert_eq!(other.chunks().len(), 2);
+
+        let mask = Bitmap::from([true, true, true, false]);
+        let out = other.map_validity(|validity| {
+            Some(match validity {
+                Some(validity) => validity & &mask,
+                None => mask.clone(),
+            })
+        });
+        assert_eq!(out.chunks().len(), 1);
+        assert_eq!(out.null_count(), 2);
+        assert_eq!(Vec::from(&out), &[Some(5), Some(1), None, None]);
+
+        let out = out.map_validity(|_| None);
+        assert_eq!(out.null_count(), 0);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let mut ca = Int32Chunked::new("a", &[Some(3), None, Some(-1), Some(7)]);
+        ca.append(&Int32Chunked::new("a", &[None, Some(2)]));
+        assert_eq!(ca.min_max(), Some((-1, 7)));
+        assert_eq!(ca.min_max(), ca.min().zip(ca.max()));
+
+        let ca = Float64Chunked::new("a", &[f64::NAN, 2.5, -1.0, f64::NAN]);
+        assert_eq!(ca.min_max(), Some((-1.0, 2.5)));
+        assert_eq!(ca.min_max(), ca.min().zip(ca.max()));
+
+        let ca = Int32Chunked::new("a", &[None, None]);
+        assert_eq!(ca.min_max(), None);
+
+        // A sorted flag is trusted, only the endpoints are looked at.
+        let mut ca = Int32Chunked::new("a", &[None, Some(5), Some(1), Some(9)]);
+        ca.set_sorted_flag(IsSorted::Ascending);
+        assert_eq!(ca.min_max(), Some((5, 9)));
+        ca.set_sorted_flag(IsSorted::Descending);
+        assert_eq!(ca.min_max(), Some((9, 5)));
+    }
+
+    #[test]
+    fn test_mask_where() {
+        let mut ca = Int32Chunked::new("a", &[Some(3), None, Some(-1), Some(7)]);
+        ca.append(&Int32Chunked::new("a", &[Some(8), Some(2)]));
+
+        let mask = ca.mask_where(|v| v > 2);
+        let expected = ca.gt(2);
+        assert_eq!(mask.len(), ca.len());
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            expected
+                .into_iter()
+                .map(|opt_v| opt_v.unwrap_or(false))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(mask.set_bits(), 3);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let mut ca = Int32Chunked::new("a", &[Some(3), None, Some(-1), Some(7)]);
+        ca.append(&Int32Chunked::new("a", &[Some(8), None]));
+        let fingerprint = ca.fingerprint(0);
+
+        assert_eq!(ca.rechunk().fingerprint(0), fingerprint);
+        let other = Int32Chunked::new("b", &[Some(3), None, Some(-1), Some(7), Some(8), None]);
+        assert_eq!(other.fingerprint(0), fingerprint);
+        assert_ne!(ca.fingerprint(1), fingerprint);
+
+        let changed = Int32Chunked::new("a", &[Some(3), None, Some(-1), Some(7), Some(9), None]);
+        assert_ne!(changed.fingerprint(0), fingerprint);
+        let validity =
+            Int32Chunked::new("a", &[Some(3), None, Some(-1), Some(7), Some(8), Some(0)]);
+        assert_ne!(validity.fingerprint(0), fingerprint);
+
+        let ca = StringChunked::new("a", &[Some("a"), None, Some("bc")]);
+        let mut chunked = StringChunked::new("a", &[Some("a")]);
+        chunked.append(&StringChunked::new("a", &[None, Some("bc")]));
+        assert_eq!(chunked.fingerprint(0), ca.fingerprint(0));
+    }
 }
@@ -173,15 +173,25 @@ where
 
                 min.zip(max)
             },
-            IsSorted::Not => self
-                .downcast_iter()
-                .filter_map(MinMaxKernel::min_max_ignore_nan_kernel)
-                .reduce(|(min1, max1), (min2, max2)| {
-                    (
-                        MinMax::min_ignore_nan(min1, min2),
-                        MinMax::max_ignore_nan(max1, max2),
-                    )
-                }),
+            // Both are updated in a single pass over the values.
+            IsSorted::Not => {
+                let update = |acc: Option<(T::Native, T::Native)>, v: T::Native| {
+                    Some(match acc {
+                        None => (v, v),
+                        Some((min, max)) => (
+                            MinMax::min_ignore_nan(min, v),
+                            MinMax::max_ignore_nan(max, v),
+                        ),
+                    })
+                };
+                match self.cont_slice() {
+                    Ok(values) => values.iter().copied().fold(None, update),
+                    Err(_) => self
+                        .downcast_iter()
+                        .flat_map(|arr| arr.non_null_values_iter())
+                        .fold(None, update),
+                }
+            },
         }
     }
 
@@ -196,6 +196,18 @@ impl<T: PolarsNumericType> ChunkedArray<T> {
         self.compute_len();
         self.set_sorted_flag(IsSorted::Not);
     }
+
+    /// Apply a function to the values, without allocating new buffers if possible.
+    ///
+    /// The values of a chunk are mutated in place if its buffer isn't shared, e.g. with a clone
+    /// of this array. Otherwise they are written to a new buffer. The validity is untouched.
+    pub fn apply_values_in_place<F>(mut self, f: F) -> Self
+    where
+        F: Fn(T::Native) -> T::Native + Copy,
+    {
+        self.apply_mut(f);
+        self
+    }
 }
 
 impl<'a, T> ChunkApply<'a, T::Native> for ChunkedArray<T>
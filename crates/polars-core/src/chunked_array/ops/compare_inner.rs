@@ -168,6 +168,23 @@ pub(crate) trait IntoTotalOrdInner<'a> {
     fn into_total_ord_inner(self) -> Box<dyn TotalOrdInner + 'a>;
 }
 
+impl TotalOrdInner for &NullChunked {
+    unsafe fn cmp_element_unchecked(
+        &self,
+        _idx_a: usize,
+        _idx_b: usize,
+        _nulls_last: bool,
+    ) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<'a> IntoTotalOrdInner<'a> for &'a NullChunked {
+    fn into_total_ord_inner(self) -> Box<dyn TotalOrdInner + 'a> {
+        Box::new(self)
+    }
+}
+
 impl<'a, T> IntoTotalOrdInner<'a> for &'a ChunkedArray<T>
 where
     T: PolarsDataType,
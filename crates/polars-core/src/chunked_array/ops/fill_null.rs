@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-struct")]
+use arrow::bitmap::Bitmap;
 use arrow::legacy::kernels::set::set_at_nulls;
 use arrow::legacy::trusted_len::FromIteratorReversed;
 use arrow::legacy::utils::FromTrustedLenIterator;
@@ -63,6 +65,12 @@ impl Series {
     /// example();
     /// ```
     pub fn fill_null(&self, strategy: FillNullStrategy) -> PolarsResult<Series> {
+        // Interpolation is implemented in `polars-ops`.
+        polars_ensure!(
+            !matches!(strategy, FillNullStrategy::Interpolate(_)),
+            InvalidOperation: "fill-null strategy {:?} is only supported in expressions, use `interpolate` instead",
+            strategy
+        );
         let logical_type = self.dtype();
         let s = self.to_physical_repr();
 
@@ -82,6 +90,13 @@ impl Series {
                 let ca = s.list().unwrap();
                 fill_null_list(ca, strategy).map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-struct")]
+            Struct(_) => {
+                let ca = self.struct_().unwrap();
+                return ca
+                    .fill_null_fields(strategy, false)
+                    .map(|ca| ca.into_series());
+            },
             dt if dt.is_numeric() => {
                 with_match_physical_numeric_polars_type!(dt, |$T| {
                     let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -94,6 +109,107 @@ impl Series {
     }
 }
 
+#[cfg(feature = "dtype-struct")]
+impl StructChunked {
+    /// Replace the null values in every field that supports `strategy`. Fields with a dtype the
+    /// strategy doesn't apply to are left untouched and nested structs are filled recursively.
+    ///
+    /// Rows where the struct itself is null stay null, unless `fill_null_rows` is set.
+    pub fn fill_null_fields(
+        &self,
+        strategy: FillNullStrategy,
+        fill_null_rows: bool,
+    ) -> PolarsResult<StructChunked> {
+        fill_null_struct(self, strategy, fill_null_rows, None)
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+fn fill_null_struct(
+    ca: &StructChunked,
+    strategy: FillNullStrategy,
+    fill_null_rows: bool,
+    parent_validity: Option<&Bitmap>,
+) -> PolarsResult<StructChunked> {
+    if ca.total_null_count() == 0 {
+        return Ok(ca.clone());
+    }
+    let validity = if !fill_null_rows && ca.null_count() > 0 {
+        let is_valid = ca.clone().into_series().is_not_null().rechunk();
+        let is_valid = is_valid.downcast_iter().next().unwrap().values().clone();
+        Some(match parent_validity {
+            Some(parent) => parent & &is_valid,
+            None => is_valid,
+        })
+    } else {
+        parent_validity.cloned()
+    };
+
+    let fields = ca
+        .fields()
+        .iter()
+        .map(|s| match s.dtype() {
+            DataType::Struct(_) => fill_null_struct(
+                s.struct_().unwrap(),
+                strategy,
+                fill_null_rows,
+                validity.as_ref(),
+            )
+            .map(|ca| ca.into_series()),
+            dt if s.null_count() > 0 && fill_null_supports(strategy, dt) => {
+                let out = s.fill_null(strategy)?;
+                Ok(match &validity {
+                    Some(validity) => mask_rows(&out, validity),
+                    None => out,
+                })
+            },
+            _ => Ok(s.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    StructChunked::new(ca.name(), &fields)
+}
+
+/// Whether [`Series::fill_null`] can apply `strategy` to data of type `dtype`.
+#[cfg(feature = "dtype-struct")]
+fn fill_null_supports(strategy: FillNullStrategy, dtype: &DataType) -> bool {
+    use DataType::*;
+    let directional = matches!(
+        strategy,
+        FillNullStrategy::Forward(_) | FillNullStrategy::Backward(_)
+    );
+    match dtype {
+        Null => false,
+        Boolean => strategy != FillNullStrategy::Mean,
+        String | Binary => {
+            directional
+                || matches!(
+                    strategy,
+                    FillNullStrategy::Min | FillNullStrategy::Max | FillNullStrategy::Zero
+                )
+        },
+        List(_) => directional,
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => directional,
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => false,
+        dt => dt.to_physical().is_numeric(),
+    }
+}
+
+/// Set the rows that are unset in `validity` to null.
+#[cfg(feature = "dtype-struct")]
+fn mask_rows(s: &Series, validity: &Bitmap) -> Series {
+    let s = s.rechunk();
+    let arr = &s.chunks()[0];
+    let validity = match arr.validity() {
+        Some(v) => v & validity,
+        None => validity.clone(),
+    };
+    let arr = arr.with_validity(Some(validity));
+    // SAFETY: only the validity changed.
+    unsafe { Series::from_chunks_and_dtype_unchecked(s.name(), vec![arr], s.dtype()) }
+}
+
 // Utility trait to make generics work
 trait LocalCopy {
     fn cheap_clone(&self) -> Self;
@@ -312,6 +428,7 @@ where
         FillNullStrategy::Zero => return ca.fill_null_with_values(Zero::zero()),
         FillNullStrategy::MinBound => return ca.fill_null_with_values(Bounded::min_value()),
         FillNullStrategy::MaxBound => return ca.fill_null_with_values(Bounded::max_value()),
+        FillNullStrategy::Interpolate(_) => unreachable!(),
     };
     out.rename(ca.name());
     Ok(out)
@@ -352,6 +469,7 @@ fn fill_null_bool(ca: &BooleanChunked, strategy: FillNullStrategy) -> PolarsResu
         FillNullStrategy::Zero | FillNullStrategy::MinBound => {
             ca.fill_null_with_values(false).map(|ca| ca.into_series())
         },
+        FillNullStrategy::Interpolate(_) => unreachable!(),
     }
 }
 
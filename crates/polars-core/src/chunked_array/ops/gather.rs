@@ -1,6 +1,7 @@
 use arrow::bitmap::bitmask::BitMask;
 use arrow::bitmap::Bitmap;
 use arrow::compute::take::take_unchecked;
+use arrow::compute::utils::combine_validities_and;
 use polars_error::polars_ensure;
 use polars_utils::index::check_bounds;
 
@@ -62,6 +63,40 @@ where
     }
 }
 
+impl<T: PolarsDataType> ChunkedArray<T>
+where
+    ChunkedArray<T>: ChunkTakeUnchecked<IdxCa>,
+{
+    /// Gather values from ChunkedArray by index, where out-of-bounds and null indices
+    /// produce a null instead of an error.
+    pub fn take_opt(&self, indices: &IdxCa) -> Self {
+        if self.is_empty() {
+            return Self::full_null_like(self, indices.len());
+        }
+
+        let len = self.len() as IdxSize;
+        let chunks = indices.downcast_iter().map(|arr| {
+            let in_bounds: Bitmap = arr.values().iter().map(|i| *i < len).collect_trusted();
+            if in_bounds.unset_bits() == 0 {
+                return arr.clone();
+            }
+            // Out-of-bounds indices are masked and point to an existing element, so the
+            // gather kernels never read outside of the array.
+            let values: Vec<IdxSize> = arr
+                .values()
+                .iter()
+                .map(|i| if *i < len { *i } else { 0 })
+                .collect_trusted();
+            let validity = combine_validities_and(arr.validity(), Some(&in_bounds));
+            IdxArr::from_vec(values).with_validity_typed(validity)
+        });
+        let indices = IdxCa::from_chunk_iter(indices.name(), chunks);
+
+        // SAFETY: all non-null indices are in bounds.
+        unsafe { self.take_unchecked(&indices) }
+    }
+}
+
 /// Computes cumulative lengths for efficient branchless binary search
 /// lookup. The first element is always 0, and the last length of arrs
 /// is always ignored (as we already checked that all indices are
@@ -369,6 +369,16 @@ pub trait ChunkSort<T: PolarsDataType> {
     /// Retrieve the indexes needed to sort this array.
     fn arg_sort(&self, options: SortOptions) -> IdxCa;
 
+    /// Write the indexes needed to sort this array into `buf`.
+    ///
+    /// The buffer is cleared first, so it can be reused across calls to avoid allocating the
+    /// output every time.
+    fn sort_indices_into(&self, options: SortOptions, buf: &mut Vec<IdxSize>) {
+        let idx = self.arg_sort(options);
+        buf.clear();
+        buf.extend(idx.into_no_null_iter());
+    }
+
     /// Retrieve the indexes need to sort this and the other arrays.
     #[allow(unused_variables)]
     fn arg_sort_multiple(
@@ -382,7 +392,7 @@ pub trait ChunkSort<T: PolarsDataType> {
 
 pub type FillNullLimit = Option<IdxSize>;
 
-#[derive(Copy, Clone, Debug, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub enum FillNullStrategy {
     /// previous value in array
@@ -403,7 +413,22 @@ pub enum FillNullStrategy {
     MaxBound,
     /// replace with the minimal value of that data type
     MinBound,
+    /// interpolate between the non-null values, only supported in expressions
+    Interpolate(InterpolationMethod),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
+pub enum InterpolationMethod {
+    Linear,
+    Nearest,
+    /// Piecewise cubic Hermite interpolation with monotonicity preserving slopes (PCHIP).
+    ///
+    /// The interpolated values never overshoot the neighboring known points. This needs at least
+    /// two known points and is equal to linear interpolation when there are exactly two.
+    Pchip,
 }
+
 /// Replace None values with a value
 pub trait ChunkFillNullValue<T> {
     /// Replace None values with a give value `T`.
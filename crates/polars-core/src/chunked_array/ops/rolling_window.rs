@@ -11,6 +11,8 @@ pub struct RollingOptionsFixedWindow {
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
     pub center: bool,
+    /// Sum float windows with compensated summation in `rolling_sum` and `rolling_mean`.
+    pub precise: bool,
     pub fn_params: DynArgs,
 }
 
@@ -21,6 +23,7 @@ impl Default for RollingOptionsFixedWindow {
             min_periods: 1,
             weights: None,
             center: false,
+            precise: false,
             fn_params: None,
         }
     }
@@ -64,6 +67,63 @@ mod inner_mod {
         (start, end - start)
     }
 
+    /// Calls `f` with the index of the first element and the values of every window of `ca`.
+    /// Windows with less than `min_periods` non-null values are null.
+    fn apply_windows<T, F>(
+        ca: &ChunkedArray<T>,
+        window_size: usize,
+        min_periods: usize,
+        center: bool,
+        mut f: F,
+    ) -> PolarsResult<ChunkedArray<T>>
+    where
+        T: PolarsNumericType,
+        ChunkedArray<T>: IntoSeries,
+        F: FnMut(usize, &Series) -> PolarsResult<Option<T::Native>>,
+    {
+        let len = ca.len();
+        let window_size = std::cmp::min(len, window_size);
+        let rechunked = ca.rechunk();
+        let arr = rechunked.downcast_iter().next().unwrap();
+        let mut container = ChunkedArray::<T>::from_slice("", &[T::Native::zero()]);
+        let ptr = container.chunks[0].as_mut() as *mut dyn Array as *mut PrimitiveArray<T::Native>;
+        let mut series_container = container.into_series();
+
+        let mut builder = PrimitiveChunkedBuilder::<T>::new(ca.name(), len);
+        for idx in 0..len {
+            let (start, size) = window_edges(idx, len, window_size, center);
+
+            if size < min_periods {
+                builder.append_null();
+                continue;
+            }
+            // SAFETY:
+            // we are in bounds
+            let arr_window = unsafe { arr.slice_typed_unchecked(start, size) };
+
+            // ensure we still meet window size criteria after removing null values
+            if size - arr_window.null_count() < min_periods {
+                builder.append_null();
+                continue;
+            }
+
+            // SAFETY.
+            // ptr is not dropped as we are in scope
+            // We are also the only owner of the contents of the Arc
+            // we do this to reduce heap allocs.
+            unsafe {
+                *ptr = arr_window;
+            }
+            // reset flags as we reuse this container
+            series_container.clear_settings();
+            // ensure the length is correct
+            series_container._get_inner_mut().compute_len();
+            builder.append_option(f(start, &series_container)?);
+        }
+
+        Ok(builder.finish())
+    }
+
     impl<T> ChunkRollApply for ChunkedArray<T>
     where
         T: PolarsNumericType,
@@ -73,11 +133,10 @@ mod inner_mod {
         fn rolling_map(
             &self,
             f: &dyn Fn(&Series) -> Series,
-            mut options: RollingOptionsFixedWindow,
+            options: RollingOptionsFixedWindow,
         ) -> PolarsResult<Series> {
             check_input(options.window_size, options.min_periods)?;
 
-            let ca = self.rechunk();
             if options.weights.is_some()
                 && !matches!(self.dtype(), DataType::Float64 | DataType::Float32)
             {
@@ -85,110 +144,62 @@ mod inner_mod {
                 return s.rolling_map(f, options);
             }
 
-            options.window_size = std::cmp::min(self.len(), options.window_size);
-
-            let len = self.len();
-            let arr = ca.downcast_iter().next().unwrap();
-            let mut ca = ChunkedArray::<T>::from_slice("", &[T::Native::zero()]);
-            let ptr = ca.chunks[0].as_mut() as *mut dyn Array as *mut PrimitiveArray<T::Native>;
-            let mut series_container = ca.into_series();
-
-            let mut builder = PrimitiveChunkedBuilder::<T>::new(self.name(), self.len());
-
-            if let Some(weights) = options.weights {
-                let weights_series = Float64Chunked::new("weights", &weights).into_series();
-
-                let weights_series = weights_series.cast(self.dtype()).unwrap();
-
-                for idx in 0..len {
-                    let (start, size) = window_edges(idx, len, options.window_size, options.center);
-
-                    if size < options.min_periods {
-                        builder.append_null();
-                    } else {
-                        // SAFETY:
-                        // we are in bounds
-                        let arr_window = unsafe { arr.slice_typed_unchecked(start, size) };
-
-                        // ensure we still meet window size criteria after removing null values
-                        if size - arr_window.null_count() < options.min_periods {
-                            builder.append_null();
-                            continue;
-                        }
-
-                        // SAFETY.
-                        // ptr is not dropped as we are in scope
-                        // We are also the only owner of the contents of the Arc
-                        // we do this to reduce heap allocs.
-                        unsafe {
-                            *ptr = arr_window;
-                        }
-                        // reset flags as we reuse this container
-                        series_container.clear_settings();
-                        // ensure the length is correct
-                        series_container._get_inner_mut().compute_len();
-                        let s = if size == options.window_size {
-                            f(&series_container.multiply(&weights_series).unwrap())
-                        } else {
-                            let weights_cutoff: Series = match self.dtype() {
-                                DataType::Float64 => weights_series
-                                    .f64()
-                                    .unwrap()
-                                    .into_iter()
-                                    .take(series_container.len())
-                                    .collect(),
-                                _ => weights_series // Float32 case
-                                    .f32()
-                                    .unwrap()
-                                    .into_iter()
-                                    .take(series_container.len())
-                                    .collect(),
-                            };
-                            f(&series_container.multiply(&weights_cutoff).unwrap())
-                        };
-
-                        let out = self.unpack_series_matching_type(&s)?;
-                        builder.append_option(out.get(0));
-                    }
-                }
-
-                Ok(builder.finish().into_series())
-            } else {
-                for idx in 0..len {
-                    let (start, size) = window_edges(idx, len, options.window_size, options.center);
-
-                    if size < options.min_periods {
-                        builder.append_null();
-                    } else {
-                        // SAFETY:
-                        // we are in bounds
-                        let arr_window = unsafe { arr.slice_typed_unchecked(start, size) };
-
-                        // ensure we still meet window size criteria after removing null values
-                        if size - arr_window.null_count() < options.min_periods {
-                            builder.append_null();
-                            continue;
-                        }
+            let weights = options.weights.as_ref().map(|weights| {
+                Float64Chunked::new("weights", weights)
+                    .into_series()
+                    .cast(self.dtype())
+                    .unwrap()
+            });
+
+            let out = apply_windows(
+                self,
+                options.window_size,
+                options.min_periods,
+                options.center,
+                |_, window| {
+                    let s = match &weights {
+                        // The windows at the edges are shorter than the weights.
+                        Some(weights) => f(&window.multiply(&weights.slice(0, window.len()))?),
+                        None => f(window),
+                    };
+                    Ok(self.unpack_series_matching_type(&s)?.get(0))
+                },
+            )?;
+            Ok(out.into_series())
+        }
+    }
 
-                        // SAFETY.
-                        // ptr is not dropped as we are in scope
-                        // We are also the only owner of the contents of the Arc
-                        // we do this to reduce heap allocs.
-                        unsafe {
-                            *ptr = arr_window;
-                        }
-                        // reset flags as we reuse this container
-                        series_container.clear_settings();
-                        // ensure the length is correct
-                        series_container._get_inner_mut().compute_len();
-                        let s = f(&series_container);
-                        let out = self.unpack_series_matching_type(&s)?;
-                        builder.append_option(out.get(0));
-                    }
-                }
+    impl<T> ChunkedArray<T>
+    where
+        T: PolarsNumericType,
+        Self: IntoSeries,
+    {
+        /// Apply a rolling custom function that also gets the index of the first element of
+        /// the window. This is pretty slow because of dynamic dispatch.
+        ///
+        /// The windows and the handling of `min_periods` and nulls are the same as in
+        /// [`ChunkRollApply::rolling_map`]. Weights are not supported.
+        pub fn rolling_apply_indexed<F>(
+            &self,
+            f: F,
+            options: RollingOptionsFixedWindow,
+        ) -> PolarsResult<Self>
+        where
+            F: Fn(usize, &Series) -> Option<T::Native>,
+        {
+            check_input(options.window_size, options.min_periods)?;
+            polars_ensure!(
+                options.weights.is_none(),
+                InvalidOperation: "`rolling_apply_indexed` does not support weights"
+            );
 
-                Ok(builder.finish().into_series())
-            }
+            apply_windows(
+                self,
+                options.window_size,
+                options.min_periods,
+                options.center,
+                |start, window| Ok(f(start, window)),
+            )
         }
     }
 
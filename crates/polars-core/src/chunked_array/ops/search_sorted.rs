@@ -109,6 +109,94 @@ where
     left
 }
 
+/// Find the first index from `start` on for which `is_before` is false. The step is doubled until
+/// that index is passed, after which it is found with a binary search.
+fn gallop<'a, A, F>(arr: &'a A, start: usize, is_before: F) -> usize
+where
+    A: StaticArray,
+    F: Fn(A::ValueT<'a>) -> bool,
+{
+    let len = arr.len();
+    // All values before `lo` are before, the index is in `lo..=hi`.
+    let mut lo = start;
+    let mut hi = start;
+    let mut step = 1;
+    // SAFETY: `hi < len`.
+    while hi < len && is_before(unsafe { arr.value_unchecked(hi) }) {
+        lo = hi + 1;
+        hi += step;
+        step *= 2;
+    }
+    let mut hi = hi.min(len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        // SAFETY: `mid < hi <= len`.
+        if is_before(unsafe { arr.value_unchecked(mid) }) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Search all `search_values` in `arr` at once. The search values are sorted and merged with `arr`
+/// by galloping through it, which is faster than a binary search per value if there are many.
+///
+/// The results are the same as those of [`binary_search_array`] for every value, null values get
+/// index 0. `arr` must not contain nulls.
+pub fn batched_search_array<'a, A>(
+    side: SearchSortedSide,
+    arr: &'a A,
+    search_values: Vec<Option<A::ValueT<'a>>>,
+    descending: bool,
+) -> Vec<IdxSize>
+where
+    A: StaticArray,
+    A::ValueT<'a>: TotalOrd + Debug + Copy,
+{
+    debug_assert_eq!(arr.null_count(), 0);
+    let cmp = |value: &A::ValueT<'a>, search_value: &A::ValueT<'a>| {
+        if descending {
+            search_value.tot_cmp(value)
+        } else {
+            value.tot_cmp(search_value)
+        }
+    };
+
+    let mut out = vec![0; search_values.len()];
+    let mut sorted: Vec<_> = search_values
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, opt_v)| Some((i, opt_v?)))
+        .collect();
+    // Sort in the order of `arr`, so that the search only moves forward.
+    sorted.sort_unstable_by(|a, b| cmp(&a.1, &b.1));
+
+    let mut start = 0;
+    for (i, search_value) in sorted {
+        let lo = gallop(arr, start, |v| cmp(&v, &search_value) == Ordering::Less);
+        let idx = match side {
+            SearchSortedSide::Left => lo,
+            SearchSortedSide::Right => {
+                gallop(arr, lo, |v| cmp(&v, &search_value) != Ordering::Greater)
+            },
+            SearchSortedSide::Any => {
+                let hi = gallop(arr, lo, |v| cmp(&v, &search_value) != Ordering::Greater);
+                // Which of the equal values a binary search finds depends on its path.
+                if hi - lo > 1 {
+                    binary_search_array(side, arr, search_value, descending) as usize
+                } else {
+                    lo
+                }
+            },
+        };
+        out[i] = idx as IdxSize;
+        start = lo;
+    }
+    out
+}
+
 /// Get a slice of the non-null values of a sorted array. The returned array
 /// will have a single chunk.
 /// # Safety
@@ -1,3 +1,5 @@
+use std::collections::BinaryHeap;
+
 use polars_utils::iter::EnumerateIdxTrait;
 
 use super::*;
@@ -54,15 +56,25 @@ pub fn _arg_bottom_k(
         }
         &rows
     } else if sort_options.maintain_order {
-        // todo: maybe there is some more efficient method, comparable to select_nth_unstable
-        if sort_options.multithreaded {
-            POOL.install(|| {
-                rows.par_sort();
-            })
-        } else {
-            rows.sort();
+        // Keep the `k` smallest rows in a bounded max-heap. Ties are broken on the row index, so
+        // the earlier rows win and the selection is stable.
+        let mut heap = BinaryHeap::with_capacity(k);
+        for row in &rows {
+            let key = (row.bytes, row.idx);
+            if heap.len() < k {
+                heap.push(key);
+            } else if let Some(mut max) = heap.peek_mut() {
+                if key < *max {
+                    *max = key;
+                }
+            }
         }
-        &rows[..k]
+        let idx: NoNull<IdxCa> = heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(_, idx)| idx)
+            .collect();
+        return Ok(idx);
     } else {
         // todo: possible multi threaded `select_nth_unstable`?
         let (lower, _el, _upper) = rows.select_nth_unstable(k);
@@ -11,14 +11,31 @@ where
     I: IntoIterator<Item = J>,
     J: IntoIterator<Item = Option<T>>,
     T: TotalOrd + Send + Sync,
+{
+    let mut idx = Vec::new();
+    arg_sort_into(iters, options, null_count, len, &mut idx);
+    ChunkedArray::with_chunk(name, IdxArr::from_data_default(Buffer::from(idx), None))
+}
+
+pub(super) fn arg_sort_into<I, J, T>(
+    iters: I,
+    options: SortOptions,
+    null_count: usize,
+    len: usize,
+    buf: &mut Vec<IdxSize>,
+) where
+    I: IntoIterator<Item = J>,
+    J: IntoIterator<Item = Option<T>>,
+    T: TotalOrd + Send + Sync,
 {
     let descending = options.descending;
     let nulls_last = options.nulls_last;
 
     let mut vals = Vec::with_capacity(len - null_count);
 
-    let null_cap = if nulls_last { null_count } else { len };
-    let mut nulls_idx = Vec::with_capacity(null_cap);
+    // The null indexes are collected at the front of `buf`.
+    buf.clear();
+    buf.reserve(len);
     let mut count: IdxSize = 0;
 
     for arr_iter in iters {
@@ -28,8 +45,8 @@ where
             match v {
                 Some(v) => Some((i, v)),
                 None => {
-                    // SAFETY: we allocated enough.
-                    unsafe { nulls_idx.push_unchecked(i) };
+                    // SAFETY: we reserved enough.
+                    unsafe { buf.push_unchecked(i) };
                     None
                 },
             }
@@ -44,26 +61,11 @@ where
         options.multithreaded,
     );
 
-    let iter = vals.into_iter().map(|(idx, _v)| idx);
-    let idx = if nulls_last {
-        let mut idx = Vec::with_capacity(len);
-        idx.extend(iter);
-        if descending {
-            idx.extend(nulls_idx.into_iter().rev());
-        } else {
-            idx.extend(nulls_idx);
-        }
-        idx
-    } else {
-        let ptr = nulls_idx.as_ptr() as usize;
-        if descending {
-            nulls_idx.reverse();
-        }
-        nulls_idx.extend(iter);
-        // We had a realloc.
-        debug_assert_eq!(nulls_idx.as_ptr() as usize, ptr);
-        nulls_idx
-    };
-
-    ChunkedArray::with_chunk(name, IdxArr::from_data_default(Buffer::from(idx), None))
+    if descending {
+        buf.reverse();
+    }
+    buf.extend(vals.into_iter().map(|(idx, _v)| idx));
+    if nulls_last {
+        buf.rotate_left(null_count);
+    }
 }
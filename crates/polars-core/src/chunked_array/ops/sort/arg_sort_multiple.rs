@@ -1,3 +1,4 @@
+use arrow::bitmap::Bitmap;
 use arrow::compute::utils::combine_validities_and_many;
 use compare_inner::NullOrderCmp;
 use polars_row::{convert_columns, EncodingField, RowsEncoded};
@@ -140,7 +141,11 @@ pub fn encode_rows_vertical_par_unordered_broadcast_nulls(
                 s.chunks()
                     .to_vec()
                     .into_iter()
-                    .map(|arr| arr.validity().cloned())
+                    .map(|arr| match arr.data_type() {
+                        // A null array has no validity, but none of its values are valid.
+                        ArrowDataType::Null => Some(Bitmap::new_zeroed(arr.len())),
+                        _ => arr.validity().cloned(),
+                    })
             })
             .collect::<Vec<_>>();
 
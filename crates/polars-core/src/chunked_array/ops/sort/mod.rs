@@ -173,6 +173,15 @@ where
 }
 
 fn arg_sort_numeric<T>(ca: &ChunkedArray<T>, options: SortOptions) -> IdxCa
+where
+    T: PolarsNumericType,
+{
+    let mut idx = Vec::new();
+    arg_sort_numeric_into(ca, options, &mut idx);
+    IdxCa::from_vec(ca.name(), idx)
+}
+
+fn arg_sort_numeric_into<T>(ca: &ChunkedArray<T>, options: SortOptions, buf: &mut Vec<IdxSize>)
 where
     T: PolarsNumericType,
 {
@@ -197,15 +206,13 @@ where
             options.multithreaded,
         );
 
-        let out: NoNull<IdxCa> = vals.into_iter().map(|(idx, _v)| idx).collect_trusted();
-        let mut out = out.into_inner();
-        out.rename(ca.name());
-        out
+        buf.clear();
+        buf.extend_trusted_len(vals.into_iter().map(|(idx, _v)| idx));
     } else {
         let iter = ca
             .downcast_iter()
             .map(|arr| arr.iter().map(|opt| opt.copied()));
-        arg_sort::arg_sort(ca.name(), iter, options, ca.null_count(), ca.len())
+        arg_sort::arg_sort_into(iter, options, ca.null_count(), ca.len(), buf)
     }
 }
 
@@ -261,6 +268,10 @@ where
         arg_sort_numeric(self, options)
     }
 
+    fn sort_indices_into(&self, options: SortOptions, buf: &mut Vec<IdxSize>) {
+        arg_sort_numeric_into(self, options, buf)
+    }
+
     /// # Panics
     ///
     /// This function is very opinionated.
@@ -313,6 +324,10 @@ impl ChunkSort<StringType> for StringChunked {
         self.as_binary().arg_sort(options)
     }
 
+    fn sort_indices_into(&self, options: SortOptions, buf: &mut Vec<IdxSize>) {
+        self.as_binary().sort_indices_into(options, buf)
+    }
+
     /// # Panics
     ///
     /// This function is very opinionated. On the implementation of `ChunkedArray<T>` for numeric types,
@@ -390,6 +405,16 @@ impl ChunkSort<BinaryType> for BinaryChunked {
         )
     }
 
+    fn sort_indices_into(&self, options: SortOptions, buf: &mut Vec<IdxSize>) {
+        arg_sort::arg_sort_into(
+            self.downcast_iter().map(|arr| arr.iter()),
+            options,
+            self.null_count(),
+            self.len(),
+            buf,
+        )
+    }
+
     fn arg_sort_multiple(
         &self,
         by: &[Series],
@@ -643,6 +668,8 @@ pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series>
         Categorical(_, _) | Enum(_, _) => s.rechunk(),
         Binary | Boolean => s.clone(),
         BinaryOffset => s.clone(),
+        // All values are null, these don't affect the order.
+        Null => s.clone(),
         String => s.cast(&Binary).unwrap(),
         #[cfg(feature = "dtype-struct")]
         Struct(_) => {
@@ -734,6 +761,36 @@ mod test {
         assert_eq!(idx, expected);
     }
 
+    #[test]
+    fn test_sort_indices_into() {
+        let a = Int32Chunked::new("a", &[Some(1), Some(5), None, Some(1), None, Some(4)]);
+        let b = Int32Chunked::new("b", &[3, 1, 2]);
+        let c = StringChunked::new("c", &[Some("b"), None, Some("a"), Some("c")]);
+
+        // Reuse one buffer across arrays of different lengths and options.
+        let mut buf = vec![];
+        for descending in [false, true] {
+            for nulls_last in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_last,
+                    multithreaded: false,
+                    ..Default::default()
+                };
+
+                a.sort_indices_into(options, &mut buf);
+                assert_eq!(buf, a.arg_sort(options).cont_slice().unwrap());
+                b.sort_indices_into(options, &mut buf);
+                assert_eq!(buf, b.arg_sort(options).cont_slice().unwrap());
+                c.sort_indices_into(options, &mut buf);
+                assert_eq!(buf, c.arg_sort(options).cont_slice().unwrap());
+            }
+        }
+
+        a.sort_indices_into(SortOptions::default(), &mut buf);
+        assert_eq!(buf, &[2, 4, 0, 3, 5, 1]);
+    }
+
     #[test]
     fn test_sort() {
         let a = Int32Chunked::new(
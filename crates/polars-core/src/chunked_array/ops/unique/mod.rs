@@ -1,3 +1,5 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::hash::Hash;
 
 use arrow::bitmap::MutableBitmap;
@@ -70,6 +72,60 @@ where
     unique
 }
 
+/// Count the values and collect the `k` most frequent ones into a `value_counts` frame.
+///
+/// The counts are kept in a min-heap of size `k`. Values with the same count are ranked by
+/// their first occurrence, so of two equally frequent values the one that occurs first is
+/// kept. If `sort` is set the output is ordered by descending count, otherwise by first
+/// occurrence.
+pub(crate) fn value_counts_top_k<T>(
+    s: &dyn SeriesTrait,
+    values: impl Iterator<Item = T>,
+    k: usize,
+    sort: bool,
+) -> PolarsResult<DataFrame>
+where
+    T: ToTotalOrd,
+    <T as ToTotalOrd>::TotalOrdItem: Hash + Eq,
+{
+    polars_ensure!(
+        s.name() != "count",
+        Duplicate: "using `value_counts_top_k` on a column named 'count' would lead to duplicate column names; change the name of the column"
+    );
+
+    let mut counts = PlHashMap::new();
+    for (idx, val) in values.enumerate() {
+        counts
+            .entry(val.to_total_ord())
+            .or_insert((idx as IdxSize, 0 as IdxSize))
+            .1 += 1;
+    }
+
+    // `Reverse` turns the max-heap into a min-heap, so the least frequent value is popped.
+    let mut heap = BinaryHeap::with_capacity(std::cmp::min(k, counts.len()) + 1);
+    for (first, count) in counts.into_values() {
+        heap.push(Reverse((count, Reverse(first))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    let mut top = heap
+        .into_iter()
+        .map(|Reverse((count, Reverse(first)))| (first, count))
+        .collect::<Vec<_>>();
+    if sort {
+        top.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    } else {
+        top.sort_unstable_by_key(|(first, _)| *first);
+    }
+    let (idx, counts): (Vec<_>, Vec<_>) = top.into_iter().unzip();
+
+    // SAFETY: the indices are positions in `values`, which has the length of `s`.
+    let values = unsafe { s.take_slice_unchecked(&idx) };
+    let counts = IdxCa::from_vec("count", counts).into_series();
+    Ok(unsafe { DataFrame::new_no_checks(vec![values, counts]) })
+}
+
 macro_rules! arg_unique_ca {
     ($ca:expr) => {{
         match $ca.has_validity() {
@@ -290,4 +346,44 @@ mod test {
             vec![Some(0), Some(1), Some(4)]
         );
     }
+
+    #[test]
+    fn value_counts_top_k() -> PolarsResult<()> {
+        // 10_000 distinct values, the multiples of 1000 occur 3 times and 7 once more.
+        let mut values = (0..10_000i32).collect::<Vec<_>>();
+        values.extend((0..10_000).step_by(1000));
+        values.extend((0..10_000).step_by(1000));
+        values.push(7);
+        values.push(7);
+        values.push(7);
+        let s = Series::new("a", values);
+
+        let out = s.value_counts_top_k(3, true)?;
+        assert_eq!(out.get_column_names(), &["a", "count"]);
+        // 0 and 1000 win the tie with the other multiples of 1000 as they occur first.
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(7), Some(0), Some(1000)]
+        );
+        assert_eq!(
+            Vec::from(out.column("count")?.idx()?),
+            &[Some(4), Some(3), Some(3)]
+        );
+
+        let out = s.value_counts_top_k(3, false)?;
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(0), Some(7), Some(1000)]
+        );
+        assert_eq!(s.value_counts_top_k(0, true)?.height(), 0);
+        assert_eq!(s.value_counts_top_k(usize::MAX, true)?.height(), 10_000);
+
+        let s = Series::new("s", &[Some("b"), None, Some("a"), None, Some("b")]);
+        let out = s.value_counts_top_k(2, true)?;
+        assert_eq!(Vec::from(out.column("s")?.str()?), &[Some("b"), None]);
+        assert!(Series::new("count", &[1i32])
+            .value_counts_top_k(1, true)
+            .is_err());
+        Ok(())
+    }
 }
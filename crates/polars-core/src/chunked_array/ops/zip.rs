@@ -160,6 +160,71 @@ where
     }
 }
 
+fn chunk_ends(chunk_lengths: impl Iterator<Item = usize>) -> impl Iterator<Item = usize> {
+    chunk_lengths.scan(0, |end, len| {
+        *end += len;
+        Some(*end)
+    })
+}
+
+/// Zip two nested [`Series`] of the same length segment by segment.
+///
+/// The inputs are split at the chunk boundaries of `mask`, `if_true` and `if_false`, so every
+/// segment lies within a single chunk of each input. Segments in which the mask is all `true`
+/// or all `false` are zero-copy slices of the selected side, the others are passed to
+/// `zip_segment` together with the mask of the segment, in which nulls are `false`.
+pub(crate) fn zip_with_segments<F>(
+    if_true: &Series,
+    mask: &BooleanChunked,
+    if_false: &Series,
+    mut zip_segment: F,
+) -> PolarsResult<Series>
+where
+    F: FnMut(&Series, &Bitmap, &Series) -> PolarsResult<Series>,
+{
+    polars_ensure!(
+        mask.len() == if_true.len() && mask.len() == if_false.len(),
+        ShapeMismatch: SHAPE_MISMATCH_STR
+    );
+    let mut bounds = chunk_ends(mask.chunk_id())
+        .chain(chunk_ends(if_true.chunk_lengths()))
+        .chain(chunk_ends(if_false.chunk_lengths()))
+        .collect::<Vec<_>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let mut out: Option<Series> = None;
+    let mut start = 0;
+    for end in bounds {
+        if end == start {
+            continue;
+        }
+        let (offset, len) = (start as i64, end - start);
+        let m = mask.slice(offset, len).rechunk();
+        let bm = bool_null_to_false(m.downcast_iter().next().unwrap());
+        let segment = match bm.set_bits() {
+            0 => if_false.slice(offset, len),
+            n if n == len => if_true.slice(offset, len),
+            _ => zip_segment(
+                &if_true.slice(offset, len),
+                &bm,
+                &if_false.slice(offset, len),
+            )?,
+        };
+        match out.as_mut() {
+            Some(out) => {
+                out.append(&segment)?;
+            },
+            None => out = Some(segment),
+        };
+        start = end;
+    }
+
+    let mut out = out.unwrap_or_else(|| if_true.clear());
+    out.rename(if_true.name());
+    Ok(out)
+}
+
 // Basic implementation for ObjectArray.
 #[cfg(feature = "object")]
 impl<T: PolarsObject> IfThenElseKernel for ObjectArray<T> {
@@ -206,3 +271,109 @@ impl<T: PolarsObject> IfThenElseKernel for ObjectArray<T> {
             .collect_arr()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chunked(chunks: &[Series]) -> Series {
+        let mut out = chunks[0].clone();
+        for s in &chunks[1..] {
+            out.append(s).unwrap();
+        }
+        out
+    }
+
+    fn list(values: &[Option<&[i32]>]) -> Series {
+        let values = values
+            .iter()
+            .map(|v| v.map(|v| Series::new("", v)))
+            .collect::<Vec<_>>();
+        Series::new("", values)
+    }
+
+    fn mask(chunks: &[&[Option<bool>]]) -> BooleanChunked {
+        let mut out = BooleanChunked::new("", chunks[0]);
+        for m in &chunks[1..] {
+            out.append(&BooleanChunked::new("", *m));
+        }
+        out
+    }
+
+    #[test]
+    fn test_zip_with_list_chunked() -> PolarsResult<()> {
+        let if_true = chunked(&[
+            list(&[Some(&[1]), Some(&[2, 3])]),
+            list(&[None, Some(&[4])]),
+        ]);
+        let if_false = list(&[Some(&[10]), None, Some(&[]), Some(&[20, 30])]);
+
+        // Constant per chunk, the second chunk is false or null.
+        let m = mask(&[&[Some(true), Some(true)], &[Some(false), None]]);
+        let out = if_true.zip_with(&m, &if_false)?;
+        let expected = list(&[Some(&[1]), Some(&[2, 3]), Some(&[]), Some(&[20, 30])]);
+        assert!(out.equals_missing(&expected));
+        assert_eq!(out.n_chunks(), 2);
+
+        // Mixed within the chunks of `if_true`, with a null in the mask.
+        let m = mask(&[&[Some(true), Some(false), None, Some(true)]]);
+        let out = if_true.zip_with(&m, &if_false)?;
+        let expected = list(&[Some(&[1]), None, Some(&[]), Some(&[4])]);
+        assert!(out.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_zip_with_struct_chunked() -> PolarsResult<()> {
+        let fields = |a: &[Option<i32>], b: &[Option<&[i32]>]| {
+            let b = list(b).with_name("b");
+            StructChunked::new("s", &[Series::new("a", a), b])
+                .unwrap()
+                .into_series()
+        };
+        let if_true = chunked(&[
+            fields(&[Some(1), None], &[Some(&[1]), None]),
+            fields(&[Some(3), Some(4)], &[Some(&[3]), Some(&[4, 4])]),
+        ]);
+        let if_false = fields(
+            &[Some(10), Some(20), None, Some(40)],
+            &[None, Some(&[20]), None, Some(&[])],
+        );
+
+        // Constant per chunk, the selected chunks are shared.
+        let m = mask(&[&[Some(false), None], &[Some(true), Some(true)]]);
+        let out = if_true.zip_with(&m, &if_false)?;
+        let expected = fields(
+            &[Some(10), Some(20), Some(3), Some(4)],
+            &[None, Some(&[20]), Some(&[3]), Some(&[4, 4])],
+        );
+        assert_eq!(out.n_chunks(), 2);
+        for (l, r) in out
+            .struct_()?
+            .fields()
+            .iter()
+            .zip(expected.struct_()?.fields())
+        {
+            assert!(l.equals_missing(r));
+        }
+
+        // Mixed, with a null in the mask and a row that is null in both fields.
+        let m = mask(&[&[None, Some(true), Some(false), Some(true)]]);
+        let out = if_true.zip_with(&m, &if_false)?;
+        let expected = fields(
+            &[Some(10), None, None, Some(4)],
+            &[None, None, None, Some(&[4, 4])],
+        );
+        for (l, r) in out
+            .struct_()?
+            .fields()
+            .iter()
+            .zip(expected.struct_()?.fields())
+        {
+            assert!(l.equals_missing(r));
+        }
+        assert_eq!(out.null_count(), expected.null_count());
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -60,3 +62,15 @@ pub fn force_async() -> bool {
         .map(|value| value == "1")
         .unwrap_or_default()
 }
+
+/// The maximum number of nodes a single expression may have when it is converted to the IR.
+///
+/// This is checked for every expression, so `POLARS_MAX_EXPR_NODES` is only read once.
+pub fn get_max_expr_nodes() -> usize {
+    static MAX_EXPR_NODES: OnceLock<usize> = OnceLock::new();
+    *MAX_EXPR_NODES.get_or_init(|| {
+        std::env::var("POLARS_MAX_EXPR_NODES")
+            .map(|s| s.parse::<usize>().expect("integer"))
+            .unwrap_or(usize::MAX)
+    })
+}
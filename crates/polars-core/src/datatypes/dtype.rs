@@ -179,6 +179,106 @@ impl DataType {
         }
     }
 
+    /// Feed this data type into `state` in a stable way.
+    ///
+    /// Unlike the [`Hash`] implementation, which only hashes the variant, this
+    /// covers time units, time zones, nested types, struct fields and enum
+    /// categories. Every variant is identified by a fixed tag, so the output
+    /// doesn't depend on the enabled features. Combine with
+    /// [`StableHasher`](polars_utils::hashing::StableHasher) to get a hash that can
+    /// be persisted.
+    pub fn hash_stable<H: Hasher>(&self, state: &mut H) {
+        use DataType::*;
+        fn hash_time_unit<H: Hasher>(tu: &TimeUnit, state: &mut H) {
+            state.write_u8(match tu {
+                TimeUnit::Nanoseconds => 0,
+                TimeUnit::Microseconds => 1,
+                TimeUnit::Milliseconds => 2,
+            })
+        }
+
+        match self {
+            Boolean => state.write_u8(0),
+            UInt8 => state.write_u8(1),
+            UInt16 => state.write_u8(2),
+            UInt32 => state.write_u8(3),
+            UInt64 => state.write_u8(4),
+            Int8 => state.write_u8(5),
+            Int16 => state.write_u8(6),
+            Int32 => state.write_u8(7),
+            Int64 => state.write_u8(8),
+            Float32 => state.write_u8(9),
+            Float64 => state.write_u8(10),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(precision, scale) => {
+                state.write_u8(11);
+                precision.hash(state);
+                scale.hash(state);
+            },
+            String => state.write_u8(12),
+            Binary => state.write_u8(13),
+            BinaryOffset => state.write_u8(14),
+            Date => state.write_u8(15),
+            Datetime(tu, tz) => {
+                state.write_u8(16);
+                hash_time_unit(tu, state);
+                match tz {
+                    Some(tz) => {
+                        state.write_u8(1);
+                        tz.hash(state);
+                    },
+                    None => state.write_u8(0),
+                }
+            },
+            Duration(tu) => {
+                state.write_u8(17);
+                hash_time_unit(tu, state);
+            },
+            Time => state.write_u8(18),
+            #[cfg(feature = "dtype-array")]
+            Array(inner, width) => {
+                state.write_u8(19);
+                inner.hash_stable(state);
+                width.hash(state);
+            },
+            List(inner) => {
+                state.write_u8(20);
+                inner.hash_stable(state);
+            },
+            #[cfg(feature = "object")]
+            Object(name, _) => {
+                state.write_u8(21);
+                name.hash(state);
+            },
+            Null => state.write_u8(22),
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(_, ordering) => {
+                state.write_u8(23);
+                state.write_u8(matches!(ordering, CategoricalOrdering::Lexical) as u8);
+            },
+            #[cfg(feature = "dtype-categorical")]
+            Enum(rev_map, ordering) => {
+                state.write_u8(24);
+                state.write_u8(matches!(ordering, CategoricalOrdering::Lexical) as u8);
+                if let Some(rev_map) = rev_map {
+                    let categories = rev_map.get_categories();
+                    categories.len().hash(state);
+                    categories.values_iter().for_each(|cat| cat.hash(state));
+                }
+            },
+            #[cfg(feature = "dtype-struct")]
+            Struct(fields) => {
+                state.write_u8(25);
+                fields.len().hash(state);
+                for fld in fields {
+                    fld.name().as_str().hash(state);
+                    fld.data_type().hash_stable(state);
+                }
+            },
+            Unknown(_) => state.write_u8(26),
+        }
+    }
+
     /// Convert to the physical data type
     #[must_use]
     pub fn to_physical(&self) -> DataType {
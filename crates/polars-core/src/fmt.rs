@@ -1,6 +1,8 @@
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter, Write};
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+use std::ops::Range;
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use std::sync::RwLock;
 use std::{fmt, str};
@@ -25,6 +27,8 @@ use num_traits::{Num, NumCast};
 
 use crate::config::*;
 use crate::prelude::*;
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+use crate::series::any_value_to_str_value;
 
 // Note: see https://github.com/pola-rs/polars/pull/13699 for the rationale
 // behind choosing 10 as the default value for default number of rows displayed
@@ -445,6 +449,23 @@ fn field_to_str(f: &Field, str_truncate: usize) -> (String, usize) {
     (s, s_len + 2)
 }
 
+#[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
+/// Get the formatted values of the rows in `range`. The values are extracted column by column,
+/// so every column is only downcast once per chunk.
+fn str_value_rows(columns: &[Series], range: Range<usize>) -> Vec<Vec<Cow<'_, str>>> {
+    let mut columns = columns
+        .iter()
+        .map(|s| {
+            s.extract_any_values(range.clone())
+                .into_iter()
+                .map(any_value_to_str_value)
+        })
+        .collect::<Vec<_>>();
+    range
+        .map(|_| columns.iter_mut().map(|c| c.next().unwrap()).collect())
+        .collect()
+}
+
 #[cfg(any(feature = "fmt", feature = "fmt_no_tty"))]
 fn prepare_row(
     row: Vec<Cow<'_, str>>,
@@ -596,13 +617,7 @@ impl Display for DataFrame {
                     let half = max_n_rows / 2;
                     let rest = max_n_rows % 2;
 
-                    for i in 0..(half + rest) {
-                        let row = self
-                            .columns
-                            .iter()
-                            .map(|s| s.str_value(i).unwrap())
-                            .collect();
-
+                    for row in str_value_rows(&self.columns, 0..(half + rest)) {
                         let row_strings =
                             prepare_row(row, n_first, n_last, str_truncate, &mut max_elem_lengths);
 
@@ -610,38 +625,17 @@ impl Display for DataFrame {
                     }
                     let dots = rows[0].iter().map(|_| "…".to_string()).collect();
                     rows.push(dots);
-                    for i in (height - half)..height {
-                        let row = self
-                            .columns
-                            .iter()
-                            .map(|s| s.str_value(i).unwrap())
-                            .collect();
-
+                    for row in str_value_rows(&self.columns, (height - half)..height) {
                         let row_strings =
                             prepare_row(row, n_first, n_last, str_truncate, &mut max_elem_lengths);
                         rows.push(row_strings);
                     }
                     table.add_rows(rows);
-                } else {
-                    for i in 0..height {
-                        if self.width() > 0 {
-                            let row = self
-                                .columns
-                                .iter()
-                                .map(|s| s.str_value(i).unwrap())
-                                .collect();
-
-                            let row_strings = prepare_row(
-                                row,
-                                n_first,
-                                n_last,
-                                str_truncate,
-                                &mut max_elem_lengths,
-                            );
-                            table.add_row(row_strings);
-                        } else {
-                            break;
-                        }
+                } else if self.width() > 0 {
+                    for row in str_value_rows(&self.columns, 0..height) {
+                        let row_strings =
+                            prepare_row(row, n_first, n_last, str_truncate, &mut max_elem_lengths);
+                        table.add_row(row_strings);
                     }
                 }
             } else if height > 0 {
@@ -56,4 +56,126 @@ impl DataFrame {
             split.into_iter().map(split_fn).collect()
         }
     }
+
+    /// Iterate over zero-copy slices of at most `n` rows, only the last slice can be shorter.
+    ///
+    /// If `contiguous` is set, every slice is rechunked to a single chunk, so only the rows of
+    /// one slice are copied at a time. If `exact` is set, the last slice is dropped if it has
+    /// fewer than `n` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn iter_slices(&self, n: usize, contiguous: bool, exact: bool) -> SliceIter<'_> {
+        assert!(
+            n > 0,
+            "the number of rows per slice should be greater than 0"
+        );
+        let height = self.height();
+        let end = if exact { height - height % n } else { height };
+        SliceIter {
+            columns: &self.columns,
+            n,
+            offset: 0,
+            end,
+            contiguous,
+        }
+    }
+}
+
+pub struct SliceIter<'a> {
+    columns: &'a [Series],
+    n: usize,
+    offset: usize,
+    end: usize,
+    contiguous: bool,
+}
+
+impl Iterator for SliceIter<'_> {
+    type Item = DataFrame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.end {
+            return None;
+        }
+        let len = std::cmp::min(self.n, self.end - self.offset);
+        let columns = self
+            .columns
+            .iter()
+            .map(|s| {
+                let s = s.slice(self.offset as i64, len);
+                if self.contiguous {
+                    s.rechunk()
+                } else {
+                    s
+                }
+            })
+            .collect();
+        self.offset += len;
+
+        Some(unsafe { DataFrame::new_no_checks(columns) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (self.end - self.offset).div_ceil(self.n);
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for SliceIter<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn multi_chunk_frame() -> PolarsResult<DataFrame> {
+        let mut df = df!["a" => [0i32, 1, 2], "b" => [Some("0"), None, Some("2")]]?;
+        for offset in [3i32, 7] {
+            let a = (offset..offset + 4).collect::<Vec<_>>();
+            let b = a.iter().map(|v| Some(v.to_string())).collect::<Vec<_>>();
+            df.vstack_mut(&df!["a" => a, "b" => b]?)?;
+        }
+        Ok(df)
+    }
+
+    #[test]
+    fn test_iter_slices() -> PolarsResult<()> {
+        let df = multi_chunk_frame()?;
+        assert_eq!((df.height(), df.n_chunks()), (11, 3));
+
+        for contiguous in [false, true] {
+            let slices = df.iter_slices(4, contiguous, false).collect::<Vec<_>>();
+            let heights = slices.iter().map(|df| df.height()).collect::<Vec<_>>();
+            assert_eq!(heights, [4, 4, 3]);
+            if contiguous {
+                assert!(slices.iter().all(|df| df.n_chunks() == 1));
+                assert!(slices[1].column("a")?.i32()?.cont_slice().is_ok());
+            } else {
+                assert_eq!(slices[0].n_chunks(), 2);
+            }
+
+            let mut out = slices[0].clone();
+            for slice in &slices[1..] {
+                out.vstack_mut(slice)?;
+            }
+            assert!(out.equals_missing(&df));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_slices_exact() -> PolarsResult<()> {
+        let df = multi_chunk_frame()?;
+
+        let iter = df.iter_slices(4, true, true);
+        assert_eq!(iter.len(), 2);
+        let slices = iter.collect::<Vec<_>>();
+        assert!(slices[1].equals_missing(&df.slice(4, 4)));
+
+        assert_eq!(df.iter_slices(11, false, true).len(), 1);
+        assert_eq!(df.iter_slices(12, false, true).len(), 0);
+        assert_eq!(df.iter_slices(12, false, false).len(), 1);
+        assert_eq!(df.clear().iter_slices(4, false, false).len(), 0);
+        Ok(())
+    }
 }
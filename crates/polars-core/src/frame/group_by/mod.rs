@@ -225,6 +225,10 @@ impl<'df> GroupBy<'df> {
     /// The Vec returned contains:
     ///     (first_idx, [`Vec<indexes>`])
     ///     Where second value in the tuple is a vector with all matching indexes.
+    ///
+    /// This is an advanced API and the representation may change between releases.
+    /// Sorted keys can produce [`GroupsProxy::Slice`] groups instead of index groups; use
+    /// [`GroupsProxy::to_offsets_and_indices`] to get a flat layout for both.
     pub fn get_groups(&self) -> &GroupsProxy {
         &self.groups
     }
@@ -1185,4 +1189,33 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_group_by_offsets_and_indices() -> PolarsResult<()> {
+        let df = df![
+            "g" => ["a", "b", "a", "c", "b", "a"],
+        ]?;
+        let gb = df.group_by_stable(["g"])?;
+        let expected = gb.groups()?;
+        let expected = expected.column("groups")?.list()?;
+
+        let (offsets, indices) = gb.get_groups().to_offsets_and_indices();
+        assert_eq!(offsets, [0, 3, 5, 6]);
+        assert_eq!(indices, [0, 2, 5, 1, 4, 3]);
+        for (i, group) in expected.into_iter().enumerate() {
+            let group = group.unwrap();
+            let idx = &indices[offsets[i] as usize..offsets[i + 1] as usize];
+            assert!(group.equals(&Series::new("", idx)));
+        }
+
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 2], [2, 0], [2, 3]],
+            rolling: false,
+        };
+        let (offsets, indices) = groups.to_offsets_and_indices();
+        assert_eq!(offsets, [0, 2, 2, 5]);
+        assert_eq!(indices, [0, 1, 2, 3, 4]);
+        Ok(())
+    }
 }
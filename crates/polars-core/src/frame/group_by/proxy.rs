@@ -504,23 +504,42 @@ impl GroupsProxy {
             },
         }
     }
+    /// The row indices of every group, built from the buffers of
+    /// [`GroupsProxy::to_offsets_and_indices`].
     pub fn as_list_chunked(&self) -> ListChunked {
+        let (offsets, indices) = self.to_offsets_and_indices();
+        let offsets = offsets.into_iter().map(|o| o as i64).collect::<Vec<_>>();
+        // SAFETY: the offsets start at 0 and are monotonically increasing.
+        let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+        let values = IdxCa::from_vec("", indices).chunks()[0].clone();
+        let dtype = LargeListArray::default_datatype(values.data_type().clone());
+        ListChunked::with_chunk("", LargeListArray::new(dtype, offsets, values, None))
+    }
+
+    /// Flatten the groups into `(offsets, indices)` buffers, e.g. to pass them over FFI.
+    ///
+    /// The row indices of group `i` are `indices[offsets[i]..offsets[i + 1]]`, so `offsets`
+    /// has one more element than there are groups. These are the buffers of
+    /// [`GroupsProxy::as_list_chunked`] and of the `agg_groups` expression.
+    pub fn to_offsets_and_indices(&self) -> (Vec<IdxSize>, Vec<IdxSize>) {
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0);
+        let mut indices = Vec::new();
         match self {
-            GroupsProxy::Idx(groups) => groups
-                .iter()
-                .map(|(_first, idx)| {
-                    let ca: NoNull<IdxCa> = idx.iter().map(|&v| v as IdxSize).collect();
-                    ca.into_inner().into_series()
-                })
-                .collect_trusted(),
-            GroupsProxy::Slice { groups, .. } => groups
-                .iter()
-                .map(|&[first, len]| {
-                    let ca: NoNull<IdxCa> = (first..first + len).collect_trusted();
-                    ca.into_inner().into_series()
-                })
-                .collect_trusted(),
+            GroupsProxy::Idx(groups) => {
+                for (_first, idx) in groups.iter() {
+                    indices.extend_from_slice(idx);
+                    offsets.push(indices.len() as IdxSize);
+                }
+            },
+            GroupsProxy::Slice { groups, .. } => {
+                for &[first, len] in groups {
+                    indices.extend(first..first + len);
+                    offsets.push(indices.len() as IdxSize);
+                }
+            },
         }
+        (offsets, indices)
     }
 
     pub fn unroll(self) -> GroupsProxy {
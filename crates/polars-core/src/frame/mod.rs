@@ -525,6 +525,13 @@ impl DataFrame {
         self.columns.as_slice().into()
     }
 
+    /// A stable fingerprint of the schema of this [`DataFrame`].
+    ///
+    /// See [`Schema::stable_hash`].
+    pub fn schema_hash(&self) -> u64 {
+        self.schema().stable_hash()
+    }
+
     /// Get a reference to the [`DataFrame`] columns.
     ///
     /// # Example
@@ -3283,4 +3290,35 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_schema_hash() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i64, 2],
+            "b" => ["x", "y"]
+        )?;
+        // Golden value, this must only change if the hashing of data types changes.
+        assert_eq!(df.schema_hash(), 0xe84dbbb79fabce3e);
+
+        // Order sensitive.
+        let swapped = df.select(["b", "a"])?;
+        assert_ne!(df.schema_hash(), swapped.schema_hash());
+
+        // Data type aware beyond the variant.
+        let ms = Schema::from_iter([Field::new(
+            "t",
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+        )]);
+        let us = Schema::from_iter([Field::new(
+            "t",
+            DataType::Datetime(TimeUnit::Microseconds, None),
+        )]);
+        assert_ne!(ms.stable_hash(), us.stable_hash());
+        let list_int =
+            Schema::from_iter([Field::new("l", DataType::List(Box::new(DataType::Int64)))]);
+        let list_str =
+            Schema::from_iter([Field::new("l", DataType::List(Box::new(DataType::String)))]);
+        assert_ne!(list_int.stable_hash(), list_str.stable_hash());
+        Ok(())
+    }
 }
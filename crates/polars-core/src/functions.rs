@@ -3,8 +3,9 @@
 //! Functions that might be useful.
 //!
 use crate::prelude::*;
-#[cfg(feature = "diagonal_concat")]
 use crate::utils::concat_df;
+#[cfg(feature = "diagonal_concat")]
+use crate::utils::try_get_column_supertype;
 
 /// Concat [`DataFrame`]s horizontally.
 /// Concat horizontally and extend with null values if lengths don't match
@@ -81,3 +82,61 @@ pub fn concat_df_diagonal(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
 
     concat_df(&dfs)
 }
+
+/// Cast the columns of `df` to the data types in `schema` and fill the columns that are
+/// missing with nulls.
+fn cast_to_schema(df: &DataFrame, schema: &Schema) -> PolarsResult<DataFrame> {
+    let height = df.height();
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| match df.column(name).ok() {
+            Some(s) if s.dtype() == dtype => Ok(s.clone()),
+            Some(s) => s.cast(dtype),
+            None => Ok(Series::full_null(name, height, dtype)),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(unsafe { DataFrame::new_no_checks(columns) })
+}
+
+/// Concat [`DataFrame`]s vertically and cast every column to the supertype of its data types.
+///
+/// The [`DataFrame`]s must have the same column names in the same order. Structs are merged
+/// by field name, recursively.
+pub fn concat_df_vertical_relaxed(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    let first = dfs
+        .first()
+        .ok_or_else(|| polars_err!(NoData: "cannot concat empty dataframes"))?;
+    let mut schema = first.schema();
+    for df in &dfs[1..] {
+        schema.to_supertype(&df.schema())?;
+    }
+    let dfs = dfs
+        .iter()
+        .map(|df| cast_to_schema(df, &schema))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    concat_df(&dfs)
+}
+
+/// Concat [`DataFrame`]s diagonally and cast every column to the supertype of its data types.
+///
+/// Like [`concat_df_diagonal`], columns that are missing in a [`DataFrame`] are filled with
+/// nulls. Structs are merged by field name, recursively.
+#[cfg(feature = "diagonal_concat")]
+pub fn concat_df_diagonal_relaxed(dfs: &[DataFrame]) -> PolarsResult<DataFrame> {
+    polars_ensure!(!dfs.is_empty(), NoData: "cannot concat empty dataframes");
+    let mut schema = Schema::new();
+    for df in dfs {
+        for s in df.get_columns() {
+            let dtype = match schema.get(s.name()) {
+                Some(dtype) => try_get_column_supertype(s.name(), dtype, s.dtype())?,
+                None => s.dtype().clone(),
+            };
+            schema.with_column(s.name().into(), dtype);
+        }
+    }
+    let dfs = dfs
+        .iter()
+        .map(|df| cast_to_schema(df, &schema))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    concat_df(&dfs)
+}
@@ -4,12 +4,13 @@ use std::hash::{Hash, Hasher};
 use arrow::datatypes::ArrowSchemaRef;
 use indexmap::map::MutableKeys;
 use indexmap::IndexMap;
+use polars_utils::hashing::StableHasher;
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
-use crate::utils::try_get_supertype;
+use crate::utils::try_get_column_supertype;
 
 /// A map from field/column name ([`String`](smartstring::alias::String)) to the type of that field/column ([`DataType`])
 #[derive(Eq, Clone, Default)]
@@ -399,12 +400,30 @@ impl Schema {
         for ((k, dt), (other_k, other_dt)) in self.inner.iter_mut().zip(other.iter()) {
             polars_ensure!(k == other_k, ComputeError: "schema names differ: got {}, expected {}", k, other_k);
 
-            let st = try_get_supertype(dt, other_dt)?;
+            let st = try_get_column_supertype(k, dt, other_dt)?;
             changed |= (&st != dt) || (&st != other_dt);
             *dt = st
         }
         Ok(changed)
     }
+
+    /// A fingerprint of the names and data types in this schema.
+    ///
+    /// The fingerprint is order-sensitive and, unlike the [`Hash`] implementation,
+    /// covers the full data types, including time units, time zones and nested
+    /// fields. It is computed with a
+    /// [`StableHasher`](polars_utils::hashing::StableHasher), so it can be
+    /// persisted and compared across processes and machines. It only changes if
+    /// the hashing of data types changes, which is considered a breaking change.
+    pub fn stable_hash(&self) -> u64 {
+        let mut state = StableHasher::default();
+        self.len().hash(&mut state);
+        for (name, dtype) in self.iter() {
+            name.as_str().hash(&mut state);
+            dtype.hash_stable(&mut state);
+        }
+        state.finish()
+    }
 }
 
 pub type SchemaRef = Arc<Schema>;
@@ -120,6 +120,10 @@ impl SeriesTrait for SeriesWrap<ArrayChunked> {
         self.0.rechunk().into_series()
     }
 
+    unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+        self.0.match_chunks(chunk_lengths).into_series()
+    }
+
     fn new_from_index(&self, index: usize, length: usize) -> Series {
         ChunkExpandAtIndex::new_from_index(&self.0, index, length).into_series()
     }
@@ -212,6 +212,12 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
         self.with_state(true, |ca| ca.rechunk()).into_series()
     }
 
+    unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+        let chunk_lengths = chunk_lengths.collect::<Vec<_>>();
+        self.with_state(true, |ca| ca.match_chunks(chunk_lengths.iter().copied()))
+            .into_series()
+    }
+
     fn new_from_index(&self, index: usize, length: usize) -> Series {
         self.with_state(true, |cats| cats.new_from_index(index, length))
             .into_series()
@@ -224,6 +224,13 @@ macro_rules! impl_dyn_series {
                 self.0.rechunk().$into_logical().into_series()
             }
 
+            unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+                self.0
+                    .match_chunks(chunk_lengths)
+                    .$into_logical()
+                    .into_series()
+            }
+
             fn new_from_index(&self, index: usize, length: usize) -> Series {
                 self.0
                     .new_from_index(index, length)
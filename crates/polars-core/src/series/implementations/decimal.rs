@@ -252,6 +252,12 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
             .into_series()
     }
 
+    unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+        let ca = self.0.match_chunks(chunk_lengths);
+        ca.into_decimal_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
     fn new_from_index(&self, index: usize, length: usize) -> Series {
         self.0
             .new_from_index(index, length)
@@ -216,6 +216,10 @@ macro_rules! impl_dyn_series {
                 self.0.rechunk().into_series()
             }
 
+            unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+                self.0.match_chunks(chunk_lengths).into_series()
+            }
+
             fn new_from_index(&self, index: usize, length: usize) -> Series {
                 ChunkExpandAtIndex::new_from_index(&self.0, index, length).into_series()
             }
@@ -264,6 +268,11 @@ macro_rules! impl_dyn_series {
                 ChunkUnique::arg_unique(&self.0)
             }
 
+            #[cfg(feature = "algorithm_group_by")]
+            fn value_counts_top_k(&self, k: usize, sort: bool) -> PolarsResult<DataFrame> {
+                crate::chunked_array::ops::unique::value_counts_top_k(self, self.0.iter(), k, sort)
+            }
+
             fn is_null(&self) -> BooleanChunked {
                 self.0.is_null()
             }
@@ -1,5 +1,10 @@
+#[cfg(feature = "zip_with")]
+use polars_compute::if_then_else::IfThenElseKernel;
+
 use super::*;
 use crate::chunked_array::comparison::*;
+#[cfg(feature = "zip_with")]
+use crate::chunked_array::ops::zip::zip_with_segments;
 #[cfg(feature = "algorithm_group_by")]
 use crate::frame::group_by::*;
 use crate::prelude::*;
@@ -31,7 +36,22 @@ impl private::PrivateSeries for SeriesWrap<ListChunked> {
 
     #[cfg(feature = "zip_with")]
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
-        ChunkZip::zip_with(&self.0, mask, other.as_ref().as_ref()).map(|ca| ca.into_series())
+        if mask.len() != self.0.len() || other.len() != self.0.len() {
+            // Broadcasting.
+            return ChunkZip::zip_with(&self.0, mask, other.as_ref().as_ref())
+                .map(|ca| ca.into_series());
+        }
+        // Only select the offsets and value ranges of the chunks that are actually mixed.
+        zip_with_segments(&self.0.clone().into_series(), mask, other, |t, m, f| {
+            let t = t.list().unwrap().rechunk();
+            let f = f.list().unwrap().rechunk();
+            let arr = IfThenElseKernel::if_then_else(
+                m,
+                t.downcast_iter().next().unwrap(),
+                f.downcast_iter().next().unwrap(),
+            );
+            Ok(ListChunked::from_chunk_iter_like(&t, [arr]).into_series())
+        })
     }
 
     #[cfg(feature = "algorithm_group_by")]
@@ -113,6 +133,10 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
         self.0.rechunk().into_series()
     }
 
+    unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+        self.0.match_chunks(chunk_lengths).into_series()
+    }
+
     fn new_from_index(&self, index: usize, length: usize) -> Series {
         ChunkExpandAtIndex::new_from_index(&self.0, index, length).into_series()
     }
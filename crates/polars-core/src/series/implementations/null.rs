@@ -2,7 +2,9 @@ use std::any::Any;
 
 use polars_error::constants::LENGTH_LIMIT_MSG;
 
-use crate::prelude::compare_inner::{IntoTotalEqInner, TotalEqInner};
+use crate::prelude::compare_inner::{
+    IntoTotalEqInner, IntoTotalOrdInner, TotalEqInner, TotalOrdInner,
+};
 use crate::prelude::explode::ExplodeByOffsets;
 use crate::prelude::*;
 use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
@@ -35,7 +37,11 @@ impl NullChunked {
         }
     }
 }
-impl PrivateSeriesNumeric for NullChunked {}
+impl PrivateSeriesNumeric for NullChunked {
+    fn bit_repr_small(&self) -> UInt32Chunked {
+        UInt32Chunked::full_null(self.name(), self.len())
+    }
+}
 
 impl PrivateSeries for NullChunked {
     fn compute_len(&mut self) {
@@ -128,6 +134,28 @@ impl PrivateSeries for NullChunked {
     fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
         IntoTotalEqInner::into_total_eq_inner(self)
     }
+
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        IntoTotalOrdInner::into_total_ord_inner(self)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Series],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        // All values are equal, so only the other columns determine the order.
+        match by.split_first() {
+            None => Ok(self.arg_sort(SortOptions::default())),
+            Some((first, other)) => {
+                let mut options = options.clone();
+                if options.descending.len() > 1 {
+                    options.descending.remove(0);
+                }
+                first.arg_sort_multiple(other, &options)
+            },
+        }
+    }
 }
 
 fn null_arithmetic(lhs: &NullChunked, rhs: &Series, op: &str) -> PolarsResult<Series> {
@@ -247,6 +275,20 @@ impl SeriesTrait for NullChunked {
         self.clone().into_series()
     }
 
+    fn sort_with(&self, _options: SortOptions) -> PolarsResult<Series> {
+        Ok(self.clone().into_series())
+    }
+
+    fn arg_sort(&self, _options: SortOptions) -> IdxCa {
+        IdxCa::from_vec(self.name(), (0..self.length).collect())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        let idx = if self.is_empty() { vec![] } else { vec![0] };
+        Ok(IdxCa::from_vec(self.name(), idx))
+    }
+
     fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
         let len = filter.sum().unwrap_or(0);
         Ok(NullChunked::new(self.name.clone(), len as usize).into_series())
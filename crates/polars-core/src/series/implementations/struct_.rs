@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "zip_with")]
+use crate::chunked_array::ops::zip::zip_with_segments;
 use crate::hashing::series_to_hashes;
 use crate::prelude::*;
 use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
@@ -11,6 +13,21 @@ unsafe impl IntoSeries for StructChunked {
 
 impl PrivateSeriesNumeric for SeriesWrap<StructChunked> {}
 
+#[cfg(feature = "zip_with")]
+fn zip_fields(
+    if_true: &StructChunked,
+    mask: &BooleanChunked,
+    if_false: &StructChunked,
+) -> PolarsResult<Series> {
+    let fields = if_true
+        .fields()
+        .iter()
+        .zip(if_false.fields())
+        .map(|(lhs, rhs)| lhs.zip_with_same_type(mask, rhs))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(StructChunked::new_unchecked(if_true.name(), &fields).into_series())
+}
+
 impl private::PrivateSeries for SeriesWrap<StructChunked> {
     fn compute_len(&mut self) {
         for s in self.0.fields_mut() {
@@ -45,15 +62,17 @@ impl private::PrivateSeries for SeriesWrap<StructChunked> {
 
     #[cfg(feature = "zip_with")]
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
-        let other = other.struct_()?;
-        let fields = self
-            .0
-            .fields()
-            .iter()
-            .zip(other.fields())
-            .map(|(lhs, rhs)| lhs.zip_with_same_type(mask, rhs))
-            .collect::<PolarsResult<Vec<_>>>()?;
-        Ok(StructChunked::new_unchecked(self.0.name(), &fields).into_series())
+        if mask.len() != self.0.len() || other.len() != self.0.len() {
+            // Broadcasting.
+            return zip_fields(&self.0, mask, other.struct_()?);
+        }
+        // Chunks in which the mask is constant share the fields of the selected side, only the
+        // mixed chunks are zipped per field.
+        zip_with_segments(&self.0.clone().into_series(), mask, other, |t, m, f| {
+            let mask =
+                BooleanChunked::with_chunk("", BooleanArray::from_data_default(m.clone(), None));
+            zip_fields(t.struct_()?, &mask, f.struct_()?)
+        })
     }
 
     #[cfg(feature = "algorithm_group_by")]
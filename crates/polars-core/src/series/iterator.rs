@@ -1,3 +1,8 @@
+use std::ops::Range;
+
+#[cfg(feature = "dtype-categorical")]
+use polars_utils::sync::SyncPtr;
+
 use crate::prelude::any_value::arr_to_any_value;
 use crate::prelude::*;
 use crate::utils::NoNull;
@@ -151,6 +156,126 @@ impl Series {
     }
 }
 
+/// Call `f` with the index and [`AnyValue`] of every element of `s` in `range`, see
+/// [`SeriesTrait::for_each_any_value`].
+pub(crate) fn for_each_any_value_in_range<'a, S: SeriesTrait + ?Sized>(
+    s: &'a S,
+    range: Range<usize>,
+    f: &mut dyn FnMut(usize, AnyValue<'a>),
+) {
+    assert!(range.end <= s.len(), "range out of bounds");
+    let dtype = s.dtype();
+
+    let mut offset = 0;
+    for arr in s.chunks() {
+        let arr = &**arr;
+        let chunk_start = offset;
+        offset += arr.len();
+        if offset <= range.start || chunk_start >= range.end {
+            continue;
+        }
+        let start = range.start.saturating_sub(chunk_start);
+        let end = std::cmp::min(range.end, offset) - chunk_start;
+
+        macro_rules! for_each_downcast {
+            ($casttype:ty, |$v:ident| $av:expr) => {{
+                let arr = arr.as_any().downcast_ref::<$casttype>().unwrap();
+                for i in start..end {
+                    let av = if arr.is_null(i) {
+                        AnyValue::Null
+                    } else {
+                        // SAFETY: `i` is within the bounds of this chunk.
+                        let $v = unsafe { arr.value_unchecked(i) };
+                        $av
+                    };
+                    f(chunk_start + i, av)
+                }
+            }};
+        }
+
+        match dtype {
+            DataType::String => for_each_downcast!(Utf8ViewArray, |v| AnyValue::String(v)),
+            DataType::Binary => for_each_downcast!(BinaryViewArray, |v| AnyValue::Binary(v)),
+            DataType::Boolean => for_each_downcast!(BooleanArray, |v| AnyValue::Boolean(v)),
+            DataType::UInt8 => for_each_downcast!(UInt8Array, |v| AnyValue::UInt8(v)),
+            DataType::UInt16 => for_each_downcast!(UInt16Array, |v| AnyValue::UInt16(v)),
+            DataType::UInt32 => for_each_downcast!(UInt32Array, |v| AnyValue::UInt32(v)),
+            DataType::UInt64 => for_each_downcast!(UInt64Array, |v| AnyValue::UInt64(v)),
+            DataType::Int8 => for_each_downcast!(Int8Array, |v| AnyValue::Int8(v)),
+            DataType::Int16 => for_each_downcast!(Int16Array, |v| AnyValue::Int16(v)),
+            DataType::Int32 => for_each_downcast!(Int32Array, |v| AnyValue::Int32(v)),
+            DataType::Int64 => for_each_downcast!(Int64Array, |v| AnyValue::Int64(v)),
+            DataType::Float32 => for_each_downcast!(Float32Array, |v| AnyValue::Float32(v)),
+            DataType::Float64 => for_each_downcast!(Float64Array, |v| AnyValue::Float64(v)),
+            #[cfg(feature = "dtype-date")]
+            DataType::Date => for_each_downcast!(Int32Array, |v| AnyValue::Date(v)),
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(tu, tz) => {
+                for_each_downcast!(Int64Array, |v| AnyValue::Datetime(v, *tu, tz))
+            },
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(tu) => {
+                for_each_downcast!(Int64Array, |v| AnyValue::Duration(v, *tu))
+            },
+            #[cfg(feature = "dtype-time")]
+            DataType::Time => for_each_downcast!(Int64Array, |v| AnyValue::Time(v)),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(rev_map, _) => {
+                let rev_map = rev_map.as_ref().unwrap().as_ref();
+                for_each_downcast!(UInt32Array, |v| AnyValue::Categorical(
+                    v,
+                    rev_map,
+                    SyncPtr::new_null()
+                ))
+            },
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(rev_map, _) => {
+                let rev_map = rev_map.as_ref().unwrap().as_ref();
+                for_each_downcast!(UInt32Array, |v| AnyValue::Enum(
+                    v,
+                    rev_map,
+                    SyncPtr::new_null()
+                ))
+            },
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(flds) => {
+                // Struct values are views into the fields, so we only have to hand out the
+                // position in the downcasted array.
+                let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                for i in start..end {
+                    f(chunk_start + i, AnyValue::Struct(i, arr, flds))
+                }
+            },
+            DataType::List(_) => {
+                for i in start..end {
+                    // SAFETY: `i` is within the bounds of this chunk.
+                    f(chunk_start + i, unsafe { arr_to_any_value(arr, i, dtype) })
+                }
+            },
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(_, _) => {
+                for i in start..end {
+                    // SAFETY: `i` is within the bounds of this chunk.
+                    f(chunk_start + i, unsafe { arr_to_any_value(arr, i, dtype) })
+                }
+            },
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => {
+                for i in start..end {
+                    // SAFETY: `i` is within the bounds of this chunk.
+                    f(chunk_start + i, unsafe { arr_to_any_value(arr, i, dtype) })
+                }
+            },
+            _ => {
+                for i in start..end {
+                    // SAFETY: `i` is within the bounds of the Series.
+                    f(chunk_start + i, unsafe { s.get_unchecked(chunk_start + i) })
+                }
+            },
+        }
+    }
+}
+
 pub struct SeriesIter<'a> {
     arr: &'a dyn Array,
     dtype: &'a DataType,
@@ -184,6 +309,43 @@ impl ExactSizeIterator for SeriesIter<'_> {}
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    #[cfg(all(feature = "dtype-date", feature = "dtype-struct"))]
+    fn test_for_each_any_value() -> PolarsResult<()> {
+        let mut s = Series::new("a", [Some(1), None, Some(3)].as_ref());
+        s.append(&Series::new("a", [Some(4), Some(5)].as_ref()))?;
+        let mut strings = Series::new("b", [Some("x"), None, Some("z")].as_ref());
+        strings.append(&Series::new("b", [None, Some("w")].as_ref()))?;
+        let dates = s.cast(&DataType::Date)?;
+        let strukt = StructChunked::new("c", &[s.clone(), strings.clone()])?.into_series();
+
+        // Two borrowed struct values never compare equal, so the values are compared owned.
+        let owned = |values: Vec<AnyValue>| {
+            values
+                .into_iter()
+                .map(|av| av.into_static().unwrap())
+                .collect::<Vec<_>>()
+        };
+        for s in [&s, &strings, &dates, &strukt] {
+            let expected = owned(
+                (0..s.len())
+                    .map(|i| unsafe { s.get_unchecked(i) })
+                    .collect(),
+            );
+
+            let mut out = Vec::new();
+            s.for_each_any_value(&mut |i, av| {
+                assert_eq!(i, out.len());
+                out.push(av)
+            });
+            assert_eq!(owned(out), expected);
+            assert_eq!(owned(s.extract_any_values(2..4)), &expected[2..4]);
+            assert_eq!(owned(s.extract_any_values(0..5)), expected);
+            assert!(s.extract_any_values(3..3).is_empty());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_iter() {
         let a = Series::new("age", [23, 71, 9].as_ref());
@@ -393,6 +393,21 @@ impl Series {
         }
     }
 
+    /// Apply a function to the values of a Series with numeric data type `T`.
+    ///
+    /// The values are mutated in place if neither this Series nor its buffers are shared.
+    /// Otherwise the result is written to new buffers.
+    pub fn apply_values_in_place<T, F>(mut self, f: F) -> PolarsResult<Series>
+    where
+        T: PolarsNumericType,
+        F: Fn(T::Native) -> T::Native + Copy,
+    {
+        polars_ensure!(&T::get_dtype() == self.dtype(), unpack);
+        let ca: &mut ChunkedArray<T> = self._get_inner_mut().as_mut();
+        ca.apply_mut(f);
+        Ok(self)
+    }
+
     /// Check if float value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
@@ -733,20 +748,7 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
-        let out = match self.0.get(index)? {
-            AnyValue::String(s) => Cow::Borrowed(s),
-            AnyValue::Null => Cow::Borrowed("null"),
-            #[cfg(feature = "dtype-categorical")]
-            AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
-                if arr.is_null() {
-                    Cow::Borrowed(rev.get(idx))
-                } else {
-                    unsafe { Cow::Borrowed(arr.deref_unchecked().value(idx as usize)) }
-                }
-            },
-            av => Cow::Owned(format!("{av}")),
-        };
-        Ok(out)
+        Ok(any_value_to_str_value(self.0.get(index)?))
     }
     /// Get the head of the Series.
     pub fn head(&self, length: Option<usize>) -> Series {
@@ -864,6 +866,23 @@ impl Series {
     }
 }
 
+// used for formatting
+pub(crate) fn any_value_to_str_value(av: AnyValue) -> Cow<str> {
+    match av {
+        AnyValue::String(s) => Cow::Borrowed(s),
+        AnyValue::Null => Cow::Borrowed("null"),
+        #[cfg(feature = "dtype-categorical")]
+        AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
+            if arr.is_null() {
+                Cow::Borrowed(rev.get(idx))
+            } else {
+                unsafe { Cow::Borrowed(arr.deref_unchecked().value(idx as usize)) }
+            }
+        },
+        av => Cow::Owned(format!("{av}")),
+    }
+}
+
 impl Deref for Series {
     type Target = dyn SeriesTrait;
 
@@ -1035,4 +1054,98 @@ mod test {
         let _ = series.slice(-6, 2);
         let _ = series.slice(4, 2);
     }
+
+    #[test]
+    fn values_equal_at() {
+        let a = Series::new("a", &[Some(1i64), None, Some(3)]);
+        let b = Series::new("b", &[Some(3i64), Some(1), None]);
+
+        assert!(a.values_equal_at(0, &b, 1).unwrap());
+        assert!(a.values_equal_at(2, &b, 0).unwrap());
+        assert!(a.values_equal_at(1, &b, 2).unwrap());
+        assert!(!a.values_equal_at(0, &b, 0).unwrap());
+        assert!(!a.values_equal_at(1, &b, 1).unwrap());
+
+        // Logical types compare on their physical values.
+        #[cfg(feature = "dtype-date")]
+        {
+            let a_date = a
+                .cast(&DataType::Int32)
+                .unwrap()
+                .cast(&DataType::Date)
+                .unwrap();
+            let b_date = b
+                .cast(&DataType::Int32)
+                .unwrap()
+                .cast(&DataType::Date)
+                .unwrap();
+            assert!(a_date.values_equal_at(0, &b_date, 1).unwrap());
+            assert!(!a_date.values_equal_at(0, &b_date, 0).unwrap());
+            assert!(matches!(
+                a_date.values_equal_at(0, &a.cast(&DataType::Int32).unwrap(), 0),
+                Err(PolarsError::SchemaMismatch(_))
+            ));
+        }
+
+        assert!(matches!(
+            a.values_equal_at(3, &b, 0),
+            Err(PolarsError::OutOfBounds(_))
+        ));
+        assert!(matches!(
+            a.values_equal_at(0, &b, 3),
+            Err(PolarsError::OutOfBounds(_))
+        ));
+        let c = Series::new("c", &[1i32, 2, 3]);
+        assert!(matches!(
+            a.values_equal_at(0, &c, 0),
+            Err(PolarsError::SchemaMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn series_slice_checked() {
+        let s = Series::new("a", &[1i32, 2, 3, 4]);
+
+        let out = s.slice_checked(1, 2).unwrap();
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(2), Some(3)]);
+        let out = s.slice_checked(-2, 2).unwrap();
+        assert_eq!(Vec::from(out.i32().unwrap()), &[Some(3), Some(4)]);
+        assert_eq!(s.slice_checked(4, 0).unwrap().len(), 0);
+
+        for (offset, length) in [(5, 0), (-5, 1), (2, 3), (-1, 2)] {
+            assert!(matches!(
+                s.slice_checked(offset, length),
+                Err(PolarsError::OutOfBounds(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn series_align_chunks_with() {
+        let chunked = |name: &str, chunks: &[&[i32]]| {
+            let mut out = Series::new(name, chunks[0]);
+            for chunk in &chunks[1..] {
+                out.append(&Series::new(name, *chunk)).unwrap();
+            }
+            out
+        };
+        let a = chunked("a", &[&[1, 2], &[3, 4, 5], &[6]]);
+        let b = chunked("b", &[&[1], &[2, 3, 4, 5, 6]]);
+        let other = chunked("other", &[&[0, 0, 0, 0], &[0, 0]]);
+
+        for s in [a.clone(), b, a.cast(&DataType::String).unwrap()] {
+            let out = s.align_chunks_with(&other).unwrap();
+            assert!(out.chunk_lengths().eq(other.chunk_lengths()));
+            assert!(out.equals_missing(&s));
+        }
+
+        // Types without a typed implementation are sliced and appended.
+        let s = Series::full_null("n", 6, &DataType::Null);
+        let out = s.align_chunks_with(&other).unwrap();
+        assert!(out.chunk_lengths().eq(other.chunk_lengths()));
+        assert_eq!(out.dtype(), &DataType::Null);
+        assert_eq!(out.null_count(), 6);
+
+        assert!(a.align_chunks_with(&a.head(Some(3))).is_err());
+    }
 }
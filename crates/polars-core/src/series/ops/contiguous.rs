@@ -0,0 +1,186 @@
+use std::fmt::{Display, Formatter};
+
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+
+use crate::prelude::*;
+
+/// The reason a [`Series`] can't be viewed as a contiguous slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotContiguous {
+    /// The physical type of the [`Series`] is not the requested type.
+    DtypeMismatch,
+    /// The data is spread over more than one chunk.
+    MultipleChunks,
+    /// The data contains null values.
+    HasNulls,
+}
+
+impl Display for NotContiguous {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            NotContiguous::DtypeMismatch => "physical dtype doesn't match the requested type",
+            NotContiguous::MultipleChunks => "data is spread over multiple chunks",
+            NotContiguous::HasNulls => "data contains null values",
+        };
+        write!(f, "series is not contiguous: {reason}")
+    }
+}
+
+impl std::error::Error for NotContiguous {}
+
+/// The values of a numeric [`Series`] in a single contiguous buffer.
+///
+/// The values are aligned to `align_of::<T>()`, which is what the allocator guarantees for
+/// the buffers polars allocates itself. No stronger (e.g. SIMD) alignment is guaranteed.
+#[derive(Debug, Clone)]
+pub struct ContiguousBuffer<T> {
+    /// The values. The slot of a null value holds an unspecified value.
+    pub values: Buffer<T>,
+    /// Bit `i` is unset if value `i` is null. `None` if there are no null values.
+    pub validity: Option<Bitmap>,
+    /// Whether the values had to be copied to make them contiguous.
+    pub copied: bool,
+}
+
+impl Series {
+    /// View the values as a contiguous slice of `T`, without copying.
+    ///
+    /// This only succeeds if the physical type of the [`Series`] is `T`, e.g. `Int32` for a
+    /// `Date` column, if the data is in a single chunk and if it has no null values.
+    pub fn try_as_slice<T: PolarsNumericType>(&self) -> Result<&[T::Native], NotContiguous> {
+        if self.dtype().to_physical() != T::get_dtype() {
+            return Err(NotContiguous::DtypeMismatch);
+        }
+        match self.chunks().as_slice() {
+            [] => Ok(&[]),
+            [arr] => {
+                if arr.null_count() > 0 {
+                    return Err(NotContiguous::HasNulls);
+                }
+                let arr = arr
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<T::Native>>()
+                    .unwrap();
+                Ok(arr.values().as_slice())
+            },
+            _ => Err(NotContiguous::MultipleChunks),
+        }
+    }
+
+    /// Get the values as a single contiguous buffer of `T` and a separate validity.
+    ///
+    /// This is zero-copy if the data is in a single chunk, otherwise the chunks are copied
+    /// into a new buffer. Null values don't prevent the zero-copy path, they are reported
+    /// in the validity instead. The physical type of the [`Series`] must be `T`.
+    pub fn to_contiguous_buffer<T: PolarsNumericType>(
+        &self,
+    ) -> PolarsResult<ContiguousBuffer<T::Native>> {
+        polars_ensure!(
+            self.dtype().to_physical() == T::get_dtype(),
+            SchemaMismatch: "cannot get a contiguous buffer of {} from a series of dtype {}",
+            T::get_dtype(), self.dtype()
+        );
+        let copied = self.chunks().len() > 1;
+        let s = self.rechunk();
+        let arr = s.chunks()[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<T::Native>>()
+            .unwrap();
+        Ok(ContiguousBuffer {
+            values: arr.values().clone(),
+            validity: arr.validity().filter(|v| v.unset_bits() > 0).cloned(),
+            copied,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_as_slice() -> PolarsResult<()> {
+        let s = Series::new("a", &[1i32, 2, 3]);
+        assert_eq!(s.try_as_slice::<Int32Type>(), Ok([1, 2, 3].as_slice()));
+        assert_eq!(
+            s.try_as_slice::<Int64Type>(),
+            Err(NotContiguous::DtypeMismatch)
+        );
+
+        let mut chunked = s.clone();
+        chunked.append(&s)?;
+        assert_eq!(
+            chunked.try_as_slice::<Int32Type>(),
+            Err(NotContiguous::MultipleChunks)
+        );
+
+        let s = Series::new("a", &[Some(1i32), None]);
+        assert_eq!(s.try_as_slice::<Int32Type>(), Err(NotContiguous::HasNulls));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_try_as_slice_logical() -> PolarsResult<()> {
+        let s = Series::new("a", &[0i32, 19000]).cast(&DataType::Date)?;
+        assert_eq!(s.try_as_slice::<Int32Type>(), Ok([0, 19000].as_slice()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_contiguous_buffer() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1.0f64), None, Some(3.0)]);
+        let buf = s.to_contiguous_buffer::<Float64Type>()?;
+        assert!(!buf.copied);
+        assert_eq!(buf.values.len(), 3);
+        assert_eq!(buf.values[0], 1.0);
+        assert_eq!(buf.values[2], 3.0);
+        let validity = buf.validity.unwrap();
+        assert_eq!(validity.iter().collect::<Vec<_>>(), [true, false, true]);
+
+        // Multiple chunks are copied into a single buffer.
+        let mut chunked = s.clone();
+        chunked.append(&Series::new("a", &[4.0f64]))?;
+        let buf = chunked.to_contiguous_buffer::<Float64Type>()?;
+        assert!(buf.copied);
+        assert_eq!(buf.values.len(), 4);
+        assert_eq!(buf.values[3], 4.0);
+        assert_eq!(buf.validity.unwrap().unset_bits(), 1);
+
+        assert!(s.to_contiguous_buffer::<Int64Type>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-datetime")]
+    fn test_to_contiguous_buffer_logical() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1i64), None])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+        let buf = s.to_contiguous_buffer::<Int64Type>()?;
+        assert!(!buf.copied);
+        assert_eq!(buf.values[0], 1);
+        assert_eq!(buf.validity.unwrap().unset_bits(), 1);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_contiguous_ndarray_interop() -> PolarsResult<()> {
+        use ndarray::{aview1, ArrayView1};
+
+        let s = Series::new("a", &[1.0f64, 2.0, 3.0]);
+        let view = aview1(s.try_as_slice::<Float64Type>().unwrap());
+        assert_eq!(view, ArrayView1::from(&[1.0, 2.0, 3.0]));
+
+        // Fall back to a copy if the data is chunked.
+        let mut chunked = s.clone();
+        chunked.append(&s)?;
+        assert!(chunked.try_as_slice::<Float64Type>().is_err());
+        let buf = chunked.to_contiguous_buffer::<Float64Type>()?;
+        assert!(buf.copied && buf.validity.is_none());
+        let view = aview1(buf.values.as_slice());
+        assert_eq!(view.sum(), 12.0);
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+mod contiguous;
 mod downcast;
 mod extend;
 mod null;
 mod to_list;
+pub use contiguous::{ContiguousBuffer, NotContiguous};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
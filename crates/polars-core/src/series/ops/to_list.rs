@@ -54,6 +54,14 @@ impl Series {
         if dimensions.is_empty() {
             polars_bail!(ComputeError: "reshape `dimensions` cannot be empty")
         }
+        polars_ensure!(
+            dimensions.iter().all(|d| *d >= -1),
+            ComputeError: "invalid reshape `dimensions` {:?}: dimensions must be non-negative or -1 (inferred)", dimensions,
+        );
+        polars_ensure!(
+            dimensions.iter().filter(|d| **d == -1).count() <= 1,
+            ComputeError: "invalid reshape `dimensions` {:?}: only one dimension can be inferred (-1)", dimensions,
+        );
         let s = if let DataType::List(_) = self.dtype() {
             Cow::Owned(self.explode()?)
         } else {
@@ -70,14 +78,31 @@ impl Series {
 
         let mut dimensions = dimensions.to_vec();
         if let Some(idx) = dimensions.iter().position(|i| *i == -1) {
-            let mut product = 1;
-
-            for (cnt, dim) in dimensions.iter().enumerate() {
-                if cnt != idx {
-                    product *= *dim
-                }
+            let len = s_ref.len() as i64;
+            let product = dimensions
+                .iter()
+                .enumerate()
+                .filter(|(cnt, _)| *cnt != idx)
+                .map(|(_, dim)| *dim)
+                .product::<i64>();
+
+            if product == 0 {
+                // A zero-sized fixed dimension leaves the inferred one undetermined,
+                // unless there is nothing to reshape at all.
+                polars_ensure!(
+                    len == 0,
+                    ComputeError: "cannot reshape len {} into shape {:?}: the fixed dimensions have a product of 0, so dimension {} cannot be inferred",
+                    len, dimensions, idx,
+                );
+                dimensions[idx] = 0;
+            } else {
+                polars_ensure!(
+                    len % product == 0,
+                    ComputeError: "cannot reshape len {} into shape {:?}: the product of the fixed dimensions ({}) does not divide the length, so dimension {} cannot be inferred",
+                    len, dimensions, product, idx,
+                );
+                dimensions[idx] = len / product;
             }
-            dimensions[idx] = s_ref.len() as i64 / product;
         }
 
         let prod = dimensions.iter().product::<i64>() as usize;
@@ -160,4 +185,29 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reshape_inferred_dimension() -> PolarsResult<()> {
+        let s = Series::new("a", &[1, 2, 3, 4, 5, 6]);
+
+        let out = s.reshape(&[-1, 3])?;
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.list()?.get_as_series(1).unwrap().len(), 3);
+
+        let err = s.reshape(&[-1, 4]).unwrap_err().to_string();
+        assert!(err.contains("cannot reshape len 6"));
+        assert!(err.contains("(4) does not divide the length"));
+
+        let err = s.reshape(&[-1, -1]).unwrap_err().to_string();
+        assert!(err.contains("only one dimension can be inferred"));
+
+        let empty = Series::new_empty("a", &DataType::Int32);
+        let out = empty.reshape(&[-1, 3])?;
+        assert_eq!(out.len(), 0);
+        assert!(matches!(out.dtype(), DataType::List(_)));
+        assert!(empty.reshape(&[-1, 0]).is_ok());
+        assert!(s.reshape(&[-1, 0]).is_err());
+
+        Ok(())
+    }
 }
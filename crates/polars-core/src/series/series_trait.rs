@@ -1,5 +1,6 @@
 use std::any::Any;
 use std::borrow::Cow;
+use std::ops::Range;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -7,6 +8,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "object")]
 use crate::chunked_array::object::PolarsObjectSafe;
 use crate::prelude::*;
+use crate::series::iterator::for_each_any_value_in_range;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -238,6 +240,29 @@ pub trait SeriesTrait:
     /// end of the array
     fn slice(&self, _offset: i64, _length: usize) -> Series;
 
+    /// Get a zero copy view of the data, like [`slice`](SeriesTrait::slice), but
+    /// return an error instead of clamping when the range is out of bounds.
+    fn slice_checked(&self, offset: i64, length: usize) -> PolarsResult<Series> {
+        let len = self.len();
+        let start = if offset < 0 {
+            offset.checked_add_unsigned(len as u64)
+        } else {
+            Some(offset)
+        };
+        let in_bounds = start.is_some_and(|start| {
+            start >= 0
+                && (start as u64)
+                    .checked_add(length as u64)
+                    .is_some_and(|stop| stop <= len as u64)
+        });
+        polars_ensure!(
+            in_bounds,
+            OutOfBounds: "slice with offset {} and length {} is out of bounds for series of length {}",
+            offset, length, len
+        );
+        Ok(self.slice(offset, length))
+    }
+
     #[doc(hidden)]
     fn append(&mut self, _other: &Series) -> PolarsResult<()>;
 
@@ -276,6 +301,46 @@ pub trait SeriesTrait:
     /// Aggregate all chunks to a contiguous array of memory.
     fn rechunk(&self) -> Series;
 
+    /// Split into chunks of the given lengths.
+    ///
+    /// # Safety
+    /// The lengths must add up to the length of the Series, the typed implementations slice
+    /// without bound checks.
+    #[doc(hidden)]
+    unsafe fn match_chunks(&self, chunk_lengths: ChunkIdIter) -> Series {
+        let s = self.rechunk();
+        let mut offset = 0;
+        let mut chunks = chunk_lengths.map(|len| {
+            let chunk = s.slice(offset as i64, len);
+            offset += len;
+            chunk
+        });
+        let Some(mut out) = chunks.next() else {
+            return s;
+        };
+        for chunk in chunks {
+            out.append(&chunk).unwrap();
+        }
+        out
+    }
+
+    /// Get a version of the Series with the same chunk lengths as `other`, so that the chunks of
+    /// both can be processed pairwise.
+    ///
+    /// Errors if the lengths of the Series differ.
+    fn align_chunks_with(&self, other: &Series) -> PolarsResult<Series> {
+        polars_ensure!(
+            self.len() == other.len(),
+            ShapeMismatch: "cannot align the chunks of a Series of length {} with a Series of length {}",
+            self.len(), other.len()
+        );
+        if self.chunk_lengths().eq(other.chunk_lengths()) {
+            return Ok(Series(self.clone_inner()));
+        }
+        // SAFETY: the lengths are equal, so the chunk lengths of `other` add up to our length.
+        Ok(unsafe { self.match_chunks(other.chunk_lengths()) })
+    }
+
     /// Drop all null values and return a new Series.
     fn drop_nulls(&self) -> Series {
         if self.null_count() == 0 {
@@ -338,6 +403,65 @@ pub trait SeriesTrait:
         invalid_operation_panic!(get_unchecked, self)
     }
 
+    /// Call `f` with the index and [`AnyValue`] of every element.
+    ///
+    /// This iterates chunk by chunk, and the dtype dispatch and downcast happen once per
+    /// chunk instead of once per element.
+    fn for_each_any_value<'a>(&'a self, f: &mut dyn FnMut(usize, AnyValue<'a>)) {
+        for_each_any_value_in_range(self, 0..self.len(), f)
+    }
+
+    /// Get the [`AnyValue`]s of the elements in `range`.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds.
+    fn extract_any_values(&self, range: Range<usize>) -> Vec<AnyValue<'_>> {
+        let mut out = Vec::with_capacity(range.len());
+        for_each_any_value_in_range(self, range, &mut |_, av| out.push(av));
+        out
+    }
+
+    /// Check if the value at `idx_self` is equal to the value at `idx_other` in `other`.
+    ///
+    /// Nulls compare equal to nulls and NaN compares equal to NaN. This is the bounds and dtype
+    /// checked version of the element comparison used by the engine; both series must have the
+    /// same data type.
+    fn values_equal_at(
+        &self,
+        idx_self: usize,
+        other: &Series,
+        idx_other: usize,
+    ) -> PolarsResult<bool> {
+        polars_ensure!(
+            self.dtype() == other.dtype(),
+            SchemaMismatch: "cannot compare values of dtype {} with values of dtype {}",
+            self.dtype(), other.dtype()
+        );
+        #[cfg(feature = "dtype-categorical")]
+        if let (DataType::Categorical(Some(l), _), DataType::Categorical(Some(r), _)) =
+            (self.dtype(), other.dtype())
+        {
+            polars_ensure!(l.same_src(r), string_cache_mismatch);
+        }
+        polars_ensure!(idx_self < self.len(), oob = idx_self, self.len());
+        polars_ensure!(idx_other < other.len(), oob = idx_other, other.len());
+
+        match self.dtype() {
+            DataType::Null => Ok(true),
+            #[cfg(feature = "object")]
+            DataType::Object(_, _) => polars_bail!(opq = values_equal_at, self.dtype()),
+            _ => {
+                // Not every logical type implements the element comparison, so go through the
+                // physical representation on both sides.
+                let lhs = Series(self.clone_inner());
+                let lhs = lhs.to_physical_repr();
+                let rhs = other.to_physical_repr();
+                // SAFETY: both indices are in bounds and the dtypes are equal.
+                Ok(unsafe { lhs.equal_element(idx_self, idx_other, &rhs) })
+            },
+        }
+    }
+
     fn sort_with(&self, _options: SortOptions) -> PolarsResult<Series> {
         polars_bail!(opq = sort_with, self._dtype());
     }
@@ -370,6 +494,15 @@ pub trait SeriesTrait:
         polars_bail!(opq = arg_unique, self._dtype());
     }
 
+    /// Count the occurrences of the `k` most frequent values.
+    ///
+    /// Returns the same columns as `value_counts`. Values with the same count are ranked by
+    /// their first occurrence. If `sort` is set the values are ordered by descending count,
+    /// otherwise by first occurrence.
+    fn value_counts_top_k(&self, _k: usize, _sort: bool) -> PolarsResult<DataFrame> {
+        polars_bail!(opq = value_counts_top_k, self._dtype());
+    }
+
     /// Get a mask of the null values.
     fn is_null(&self) -> BooleanChunked;
 
@@ -11,6 +11,13 @@ pub fn try_get_supertype(l: &DataType, r: &DataType) -> PolarsResult<DataType> {
     )
 }
 
+/// Like [`try_get_supertype`], but the error names the column the data types belong to.
+pub fn try_get_column_supertype(name: &str, l: &DataType, r: &DataType) -> PolarsResult<DataType> {
+    get_supertype(l, r).ok_or_else(
+        || polars_err!(ComputeError: "failed to determine supertype of {} and {} for column '{}'", l, r, name),
+    )
+}
+
 /// Given two data types, determine the data type that both types can safely be cast to.
 ///
 /// Returns [`None`] if no such data type exists.
@@ -0,0 +1,233 @@
+//! The subset of the [ADBC 1.1.0 C API](https://arrow.apache.org/adbc/current/format/specification.html)
+//! that is needed to execute a query and fetch its results.
+//!
+//! The layout of these structs must match `adbc.h` exactly. A driver that is initialized with
+//! version 1.0.0 only fills in the part of the function table that precedes the 1.1.0 slots.
+#![allow(non_snake_case)]
+
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::ptr;
+
+use arrow::ffi::{ArrowArrayStream, ArrowSchema};
+use polars_error::{polars_bail, PolarsResult};
+
+pub(super) type AdbcStatusCode = u8;
+
+pub(super) const ADBC_STATUS_OK: AdbcStatusCode = 0;
+pub(super) const ADBC_STATUS_NOT_IMPLEMENTED: AdbcStatusCode = 2;
+pub(super) const ADBC_VERSION_1_0_0: c_int = 1_000_000;
+pub(super) const ADBC_VERSION_1_1_0: c_int = 1_001_000;
+
+#[repr(C)]
+#[allow(dead_code)] // Only `message` and `release` are read, the rest is there for the layout.
+pub(super) struct AdbcError {
+    message: *mut c_char,
+    vendor_code: i32,
+    sqlstate: [c_char; 5],
+    release: Option<unsafe extern "C" fn(*mut AdbcError)>,
+    // Added in 1.1.0.
+    private_data: *mut c_void,
+    private_driver: *mut AdbcDriver,
+}
+
+impl AdbcError {
+    pub(super) fn empty() -> Self {
+        Self {
+            message: ptr::null_mut(),
+            vendor_code: 0,
+            sqlstate: [0; 5],
+            release: None,
+            private_data: ptr::null_mut(),
+            private_driver: ptr::null_mut(),
+        }
+    }
+
+    /// Release the message set by the driver, if any.
+    pub(super) fn clear(&mut self) {
+        if let Some(release) = self.release.take() {
+            // SAFETY: the error was populated by the driver that provided `release`.
+            unsafe { release(self) };
+        }
+        self.message = ptr::null_mut();
+    }
+
+    /// Turn a status code into a [`PolarsResult`], consuming the message set by the driver.
+    pub(super) fn check(&mut self, status: AdbcStatusCode, context: &str) -> PolarsResult<()> {
+        if status == ADBC_STATUS_OK {
+            return Ok(());
+        }
+        let message = if self.message.is_null() {
+            "no error message".to_string()
+        } else {
+            // SAFETY: the driver sets a nul-terminated message.
+            unsafe { CStr::from_ptr(self.message) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        self.clear();
+        polars_bail!(ComputeError: "ADBC {} failed with status {}: {}", context, status, message)
+    }
+}
+
+#[repr(C)]
+pub(super) struct AdbcDatabase {
+    pub(super) private_data: *mut c_void,
+    pub(super) private_driver: *mut AdbcDriver,
+}
+
+#[repr(C)]
+pub(super) struct AdbcConnection {
+    pub(super) private_data: *mut c_void,
+    pub(super) private_driver: *mut AdbcDriver,
+}
+
+#[repr(C)]
+pub(super) struct AdbcStatement {
+    pub(super) private_data: *mut c_void,
+    pub(super) private_driver: *mut AdbcDriver,
+}
+
+macro_rules! impl_empty {
+    ($($t:ty),*) => {
+        $(impl $t {
+            pub(super) fn empty() -> Self {
+                Self {
+                    private_data: ptr::null_mut(),
+                    private_driver: ptr::null_mut(),
+                }
+            }
+        })*
+    };
+}
+
+impl_empty!(AdbcDatabase, AdbcConnection, AdbcStatement);
+
+type Fn0<T> = Option<unsafe extern "C" fn(*mut T, *mut AdbcError) -> AdbcStatusCode>;
+type SetOption<T> = Option<
+    unsafe extern "C" fn(*mut T, *const c_char, *const c_char, *mut AdbcError) -> AdbcStatusCode,
+>;
+/// A slot in the function table that isn't used here. Only its size matters.
+type Unused = Option<unsafe extern "C" fn()>;
+
+/// The function table a driver fills in on initialization.
+#[repr(C)]
+#[allow(dead_code)] // The unused slots are only there for the layout.
+pub(super) struct AdbcDriver {
+    private_data: *mut c_void,
+    private_manager: *mut c_void,
+    pub(super) release: Fn0<AdbcDriver>,
+
+    pub(super) DatabaseInit: Fn0<AdbcDatabase>,
+    pub(super) DatabaseNew: Fn0<AdbcDatabase>,
+    pub(super) DatabaseSetOption: SetOption<AdbcDatabase>,
+    pub(super) DatabaseRelease: Fn0<AdbcDatabase>,
+
+    ConnectionCommit: Unused,
+    ConnectionGetInfo: Unused,
+    ConnectionGetObjects: Unused,
+    ConnectionGetTableSchema: Unused,
+    ConnectionGetTableTypes: Unused,
+    pub(super) ConnectionInit: Option<
+        unsafe extern "C" fn(
+            *mut AdbcConnection,
+            *mut AdbcDatabase,
+            *mut AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub(super) ConnectionNew: Fn0<AdbcConnection>,
+    pub(super) ConnectionSetOption: SetOption<AdbcConnection>,
+    ConnectionReadPartition: Unused,
+    pub(super) ConnectionRelease: Fn0<AdbcConnection>,
+    ConnectionRollback: Unused,
+
+    StatementBind: Unused,
+    StatementBindStream: Unused,
+    pub(super) StatementExecuteQuery: Option<
+        unsafe extern "C" fn(
+            *mut AdbcStatement,
+            *mut ArrowArrayStream,
+            *mut i64,
+            *mut AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    StatementExecutePartitions: Unused,
+    StatementGetParameterSchema: Unused,
+    pub(super) StatementNew: Option<
+        unsafe extern "C" fn(
+            *mut AdbcConnection,
+            *mut AdbcStatement,
+            *mut AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    StatementPrepare: Unused,
+    pub(super) StatementRelease: Fn0<AdbcStatement>,
+    StatementSetOption: Unused,
+    pub(super) StatementSetSqlQuery: Option<
+        unsafe extern "C" fn(*mut AdbcStatement, *const c_char, *mut AdbcError) -> AdbcStatusCode,
+    >,
+    StatementSetSubstraitPlan: Unused,
+
+    // Added in 1.1.0.
+    ErrorGetDetailCount: Unused,
+    ErrorGetDetail: Unused,
+    ErrorFromArrayStream: Unused,
+
+    DatabaseGetOption: Unused,
+    DatabaseGetOptionBytes: Unused,
+    DatabaseGetOptionDouble: Unused,
+    DatabaseGetOptionInt: Unused,
+    DatabaseSetOptionBytes: Unused,
+    DatabaseSetOptionDouble: Unused,
+    DatabaseSetOptionInt: Unused,
+
+    ConnectionCancel: Unused,
+    ConnectionGetOption: Unused,
+    ConnectionGetOptionBytes: Unused,
+    ConnectionGetOptionDouble: Unused,
+    ConnectionGetOptionInt: Unused,
+    ConnectionGetStatistics: Unused,
+    ConnectionGetStatisticNames: Unused,
+    ConnectionSetOptionBytes: Unused,
+    ConnectionSetOptionDouble: Unused,
+    ConnectionSetOptionInt: Unused,
+
+    StatementCancel: Unused,
+    pub(super) StatementExecuteSchema: Option<
+        unsafe extern "C" fn(
+            *mut AdbcStatement,
+            *mut ArrowSchema,
+            *mut AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    StatementGetOption: Unused,
+    StatementGetOptionBytes: Unused,
+    StatementGetOptionDouble: Unused,
+    StatementGetOptionInt: Unused,
+    StatementSetOptionBytes: Unused,
+    StatementSetOptionDouble: Unused,
+    StatementSetOptionInt: Unused,
+}
+
+impl AdbcDriver {
+    pub(super) fn empty() -> Self {
+        // SAFETY: all fields are raw pointers or nullable function pointers.
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+pub(super) type AdbcDriverInitFunc =
+    unsafe extern "C" fn(c_int, *mut c_void, *mut AdbcError) -> AdbcStatusCode;
+
+/// Get a function from the table, drivers may leave functions they don't implement unset.
+macro_rules! driver_fn {
+    ($driver:expr, $name:ident) => {
+        match $driver.$name {
+            Some(f) => f,
+            None => polars_error::polars_bail!(
+                ComputeError: "ADBC driver doesn't implement {}", stringify!($name)
+            ),
+        }
+    };
+}
+
+pub(super) use driver_fn;
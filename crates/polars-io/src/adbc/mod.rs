@@ -0,0 +1,542 @@
+//! Execute queries through an [ADBC](https://arrow.apache.org/adbc/) driver and read the results.
+//!
+//! The driver is a shared library that is loaded at runtime, e.g. `libadbc_driver_sqlite.so` or
+//! `libadbc_driver_postgresql.so`, so no database client is linked into polars. The results are
+//! imported over the Arrow C stream interface, without copying wherever the Arrow layout is also
+//! the polars layout. Strings and binary data are converted to their view representation.
+//!
+//! # Unsupported types
+//! Columns of which the Arrow type has no polars equivalent (e.g. unions, maps, intervals,
+//! fixed-size lists and dictionaries of non-string values) are handled according to the
+//! [`UnsupportedTypePolicy`]: by default the query fails, but those columns can also be dropped
+//! or read as all-null columns.
+//!
+//! # Example
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::adbc::{AdbcOptions, AdbcReader};
+//!
+//! # fn example() -> PolarsResult<()> {
+//! let options = AdbcOptions::new("libadbc_driver_sqlite.so")
+//!     .with_database_option("uri", "file:data.db");
+//! let reader = AdbcReader::new(&options)?;
+//! let df = reader.read("SELECT * FROM weather")?;
+//! # Ok(())
+//! # }
+//! ```
+mod ffi;
+
+use std::ffi::CString;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use arrow::array::{Array, StructArray};
+use arrow::datatypes::{ArrowDataType, Field as ArrowField};
+use arrow::ffi::{import_field_from_c, ArrowArrayStream, ArrowArrayStreamReader, ArrowSchema};
+use ffi::driver_fn;
+use libloading::Library;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+/// What to do with columns of which the Arrow type can't be represented in polars.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UnsupportedTypePolicy {
+    /// Fail with an error that names the column and its type.
+    #[default]
+    Raise,
+    /// Leave the column out of the result.
+    Drop,
+    /// Read the column as a column of `Null` dtype.
+    Null,
+}
+
+/// How to load the driver and connect to the database.
+#[derive(Clone, Debug)]
+pub struct AdbcOptions {
+    /// Path to the shared library of the driver.
+    pub driver: PathBuf,
+    /// The symbol that initializes the driver. Defaults to `AdbcDriverInit`.
+    pub entrypoint: Option<String>,
+    /// Options that are set on the database before it is initialized, e.g. `uri`.
+    pub database_options: Vec<(String, String)>,
+    /// Options that are set on the connection before it is initialized.
+    pub connection_options: Vec<(String, String)>,
+    pub unsupported_types: UnsupportedTypePolicy,
+}
+
+impl AdbcOptions {
+    pub fn new(driver: impl Into<PathBuf>) -> Self {
+        Self {
+            driver: driver.into(),
+            entrypoint: None,
+            database_options: vec![],
+            connection_options: vec![],
+            unsupported_types: UnsupportedTypePolicy::default(),
+        }
+    }
+
+    pub fn with_entrypoint(mut self, entrypoint: impl Into<String>) -> Self {
+        self.entrypoint = Some(entrypoint.into());
+        self
+    }
+
+    pub fn with_database_option(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.database_options.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn with_connection_option(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.connection_options.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn with_unsupported_types(mut self, policy: UnsupportedTypePolicy) -> Self {
+        self.unsupported_types = policy;
+        self
+    }
+}
+
+fn to_cstring(s: &str) -> PolarsResult<CString> {
+    CString::new(s)
+        .map_err(|_| polars_err!(ComputeError: "ADBC option {:?} contains a nul byte", s))
+}
+
+struct Driver {
+    driver: ffi::AdbcDriver,
+    // Must outlive the function table.
+    _library: Library,
+}
+
+impl Drop for Driver {
+    fn drop(&mut self) {
+        if let Some(release) = self.driver.release {
+            let mut error = ffi::AdbcError::empty();
+            unsafe { release(&mut self.driver, &mut error) };
+        }
+    }
+}
+
+// SAFETY: the function table is only written by the driver's init function, after that it is
+// read-only. Whether the functions may be called concurrently is up to the handles they are
+// called with, which synchronize themselves.
+unsafe impl Send for Driver {}
+unsafe impl Sync for Driver {}
+
+struct Database {
+    database: Box<ffi::AdbcDatabase>,
+    driver: Arc<Driver>,
+}
+
+impl Drop for Database {
+    fn drop(&mut self) {
+        if self.database.private_data.is_null() {
+            return;
+        }
+        if let Some(release) = self.driver.driver.DatabaseRelease {
+            let mut error = ffi::AdbcError::empty();
+            unsafe { release(&mut *self.database, &mut error) };
+        }
+    }
+}
+
+struct Connection {
+    // ADBC connections may not be used from multiple threads at the same time.
+    connection: Mutex<Box<ffi::AdbcConnection>>,
+    database: Database,
+}
+
+impl Connection {
+    fn driver(&self) -> &ffi::AdbcDriver {
+        &self.database.driver.driver
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        let release = self.driver().ConnectionRelease;
+        let connection = self.connection.get_mut().unwrap();
+        if connection.private_data.is_null() {
+            return;
+        }
+        if let Some(release) = release {
+            let mut error = ffi::AdbcError::empty();
+            unsafe { release(&mut **connection, &mut error) };
+        }
+    }
+}
+
+// SAFETY: the handles are owned, and the connection is only used behind a lock.
+unsafe impl Send for Connection {}
+unsafe impl Sync for Connection {}
+
+/// A connection to a database through an ADBC driver.
+pub struct AdbcReader {
+    connection: Arc<Connection>,
+    unsupported_types: UnsupportedTypePolicy,
+}
+
+impl AdbcReader {
+    /// Load the driver and connect to the database.
+    pub fn new(options: &AdbcOptions) -> PolarsResult<Self> {
+        let library = unsafe { Library::new(&options.driver) }.map_err(|e| {
+            polars_err!(
+                ComputeError: "error loading ADBC driver {}: {}", options.driver.display(), e
+            )
+        })?;
+        let entrypoint = options.entrypoint.as_deref().unwrap_or("AdbcDriverInit");
+        let mut error = ffi::AdbcError::empty();
+        let mut driver = ffi::AdbcDriver::empty();
+        unsafe {
+            let init = library
+                .get::<ffi::AdbcDriverInitFunc>(entrypoint.as_bytes())
+                .map_err(|e| polars_err!(ComputeError: "error loading {}: {}", entrypoint, e))?;
+            let mut status = init(
+                ffi::ADBC_VERSION_1_1_0,
+                &mut driver as *mut ffi::AdbcDriver as *mut _,
+                &mut error,
+            );
+            // Drivers that predate 1.1.0 refuse that version, they can still run queries.
+            if status == ffi::ADBC_STATUS_NOT_IMPLEMENTED {
+                error.clear();
+                driver = ffi::AdbcDriver::empty();
+                status = init(
+                    ffi::ADBC_VERSION_1_0_0,
+                    &mut driver as *mut ffi::AdbcDriver as *mut _,
+                    &mut error,
+                );
+            }
+            error.check(status, "driver initialization")?;
+        }
+        let driver = Arc::new(Driver {
+            driver,
+            _library: library,
+        });
+
+        let mut database = Database {
+            database: Box::new(ffi::AdbcDatabase::empty()),
+            driver,
+        };
+        let d = &database.driver.driver;
+        unsafe {
+            let status = driver_fn!(d, DatabaseNew)(&mut *database.database, &mut error);
+            error.check(status, "creating the database")?;
+            for (key, value) in &options.database_options {
+                let (key, value) = (to_cstring(key)?, to_cstring(value)?);
+                let status = driver_fn!(d, DatabaseSetOption)(
+                    &mut *database.database,
+                    key.as_ptr(),
+                    value.as_ptr(),
+                    &mut error,
+                );
+                error.check(status, "setting a database option")?;
+            }
+            let status = driver_fn!(d, DatabaseInit)(&mut *database.database, &mut error);
+            error.check(status, "initializing the database")?;
+        }
+
+        let mut connection = Box::new(ffi::AdbcConnection::empty());
+        unsafe {
+            let status = driver_fn!(d, ConnectionNew)(&mut *connection, &mut error);
+            error.check(status, "creating the connection")?;
+            // From here on the connection is released on drop, also if initialization fails.
+            let mut connection = Connection {
+                connection: Mutex::new(connection),
+                database,
+            };
+            {
+                let mut c = connection.connection.lock().unwrap();
+                let d = &connection.database.driver.driver;
+                for (key, value) in &options.connection_options {
+                    let (key, value) = (to_cstring(key)?, to_cstring(value)?);
+                    let status = driver_fn!(d, ConnectionSetOption)(
+                        &mut **c,
+                        key.as_ptr(),
+                        value.as_ptr(),
+                        &mut error,
+                    );
+                    error.check(status, "setting a connection option")?;
+                }
+                let status = driver_fn!(d, ConnectionInit)(
+                    &mut **c,
+                    &mut *connection.database.database as *mut _,
+                    &mut error,
+                );
+                error.check(status, "initializing the connection")?;
+            }
+            Ok(Self {
+                connection: Arc::new(connection),
+                unsupported_types: options.unsupported_types,
+            })
+        }
+    }
+
+    fn statement(&self, query: &str) -> PolarsResult<Statement> {
+        let query = to_cstring(query)?;
+        let d = self.connection.driver();
+        let mut error = ffi::AdbcError::empty();
+        let mut statement = Statement {
+            statement: Box::new(ffi::AdbcStatement::empty()),
+            connection: self.connection.clone(),
+        };
+        unsafe {
+            let mut c = self.connection.connection.lock().unwrap();
+            let status =
+                driver_fn!(d, StatementNew)(&mut **c, &mut *statement.statement, &mut error);
+            error.check(status, "creating a statement")?;
+            let status = driver_fn!(d, StatementSetSqlQuery)(
+                &mut *statement.statement,
+                query.as_ptr(),
+                &mut error,
+            );
+            error.check(status, "setting the query")?;
+        }
+        Ok(statement)
+    }
+
+    /// The schema of the [`DataFrame`]s a query produces, without executing the query.
+    ///
+    /// Returns `None` if the driver can't tell without executing the query, which is the case
+    /// for ADBC 1.0 drivers and for drivers that don't implement `StatementExecuteSchema`.
+    pub fn execute_schema(&self, query: &str) -> PolarsResult<Option<SchemaRef>> {
+        let Some(execute_schema) = self.connection.driver().StatementExecuteSchema else {
+            return Ok(None);
+        };
+        let mut statement = self.statement(query)?;
+        let mut error = ffi::AdbcError::empty();
+        let mut schema = ArrowSchema::empty();
+        let field = unsafe {
+            let _guard = self.connection.connection.lock().unwrap();
+            let status = execute_schema(&mut *statement.statement, &mut schema, &mut error);
+            if status == ffi::ADBC_STATUS_NOT_IMPLEMENTED {
+                error.clear();
+                return Ok(None);
+            }
+            error.check(status, "getting the schema of the query")?;
+            import_field_from_c(&schema)?
+        };
+        let ArrowDataType::Struct(fields) = &field.data_type else {
+            polars_bail!(
+                ComputeError: "expected the ADBC driver to return a record batch schema, got {:?}",
+                field.data_type
+            )
+        };
+        let (_, schema) = resolve_columns(fields, self.unsupported_types)?;
+        Ok(Some(Arc::new(schema)))
+    }
+
+    /// Execute a query and read its results in batches, as they are sent by the driver.
+    pub fn execute(&self, query: &str) -> PolarsResult<AdbcBatchedReader> {
+        let d = self.connection.driver();
+        let mut error = ffi::AdbcError::empty();
+        let mut statement = self.statement(query)?;
+        let mut stream = Box::new(ArrowArrayStream::empty());
+        unsafe {
+            let _guard = self.connection.connection.lock().unwrap();
+            let mut rows_affected = -1;
+            let status = driver_fn!(d, StatementExecuteQuery)(
+                &mut *statement.statement,
+                &mut *stream,
+                &mut rows_affected,
+                &mut error,
+            );
+            error.check(status, "executing the query")?;
+        }
+        let stream = unsafe { ArrowArrayStreamReader::try_new(stream)? };
+        AdbcBatchedReader::new(stream, statement, self.unsupported_types)
+    }
+
+    /// Execute a query and collect its results in a single [`DataFrame`].
+    pub fn read(&self, query: &str) -> PolarsResult<DataFrame> {
+        let mut reader = self.execute(query)?;
+        let schema = reader.schema().clone();
+        let dfs = reader.by_ref().collect::<PolarsResult<Vec<_>>>()?;
+        if dfs.is_empty() {
+            return Ok(DataFrame::from(schema.as_ref()));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+}
+
+struct Statement {
+    statement: Box<ffi::AdbcStatement>,
+    connection: Arc<Connection>,
+}
+
+impl Drop for Statement {
+    fn drop(&mut self) {
+        if self.statement.private_data.is_null() {
+            return;
+        }
+        if let Some(release) = self.connection.driver().StatementRelease {
+            let mut error = ffi::AdbcError::empty();
+            let _guard = self.connection.connection.lock().unwrap();
+            unsafe { release(&mut *self.statement, &mut error) };
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Column {
+    Keep,
+    Drop,
+    Null,
+}
+
+fn is_supported(dtype: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+    match dtype {
+        D::Null
+        | D::Boolean
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::UInt8
+        | D::UInt16
+        | D::UInt32
+        | D::UInt64
+        | D::Float32
+        | D::Float64
+        | D::Utf8
+        | D::LargeUtf8
+        | D::Utf8View
+        | D::Binary
+        | D::LargeBinary
+        | D::BinaryView
+        | D::FixedSizeBinary(_)
+        | D::Date32
+        | D::Date64
+        | D::Time32(_)
+        | D::Time64(_)
+        | D::Timestamp(_, _)
+        | D::Duration(_) => true,
+        #[cfg(feature = "dtype-decimal")]
+        D::Decimal(_, _) => true,
+        #[cfg(feature = "dtype-categorical")]
+        D::Dictionary(_, values, _) => {
+            matches!(values.as_ref(), D::Utf8 | D::LargeUtf8 | D::Utf8View)
+        },
+        #[cfg(feature = "dtype-struct")]
+        D::Struct(fields) => fields.iter().all(|f| is_supported(f.data_type())),
+        D::List(field) | D::LargeList(field) => is_supported(field.data_type()),
+        _ => false,
+    }
+}
+
+/// Decide what to do with every column, and the schema that results from that.
+fn resolve_columns(
+    fields: &[ArrowField],
+    policy: UnsupportedTypePolicy,
+) -> PolarsResult<(Vec<Column>, Schema)> {
+    let mut columns = Vec::with_capacity(fields.len());
+    let mut schema = Schema::with_capacity(fields.len());
+    for fld in fields {
+        let column = match (is_supported(fld.data_type()), policy) {
+            (true, _) => Column::Keep,
+            (false, UnsupportedTypePolicy::Raise) => polars_bail!(
+                ComputeError: "column {:?} has Arrow type {:?}, which is not supported",
+                fld.name, fld.data_type()
+            ),
+            (false, UnsupportedTypePolicy::Drop) => Column::Drop,
+            (false, UnsupportedTypePolicy::Null) => Column::Null,
+        };
+        match column {
+            Column::Keep => schema.with_column(fld.name.as_str().into(), fld.data_type().into()),
+            Column::Drop => None,
+            Column::Null => schema.with_column(fld.name.as_str().into(), DataType::Null),
+        };
+        columns.push(column);
+    }
+    Ok((columns, schema))
+}
+
+/// Reads the results of a query batch by batch, every record batch becomes a [`DataFrame`].
+pub struct AdbcBatchedReader {
+    // Declared before the statement, so it is released first.
+    stream: ArrowArrayStreamReader<Box<ArrowArrayStream>>,
+    _statement: Statement,
+    columns: Vec<Column>,
+    schema: SchemaRef,
+    finished: bool,
+}
+
+// SAFETY: the stream and statement are owned and only used through `&mut self`. A shared
+// reference can't reach them, it only gives access to the schema and the column policy, so
+// sharing the reader between threads is sound as well.
+unsafe impl Send for AdbcBatchedReader {}
+unsafe impl Sync for AdbcBatchedReader {}
+
+impl AdbcBatchedReader {
+    fn new(
+        stream: ArrowArrayStreamReader<Box<ArrowArrayStream>>,
+        statement: Statement,
+        policy: UnsupportedTypePolicy,
+    ) -> PolarsResult<Self> {
+        let ArrowDataType::Struct(fields) = &stream.field().data_type else {
+            polars_bail!(
+                ComputeError: "expected the ADBC driver to return record batches, got {:?}",
+                stream.field().data_type
+            )
+        };
+        let (columns, schema) = resolve_columns(fields, policy)?;
+        Ok(Self {
+            stream,
+            _statement: statement,
+            columns,
+            schema: Arc::new(schema),
+            finished: false,
+        })
+    }
+
+    /// The schema of the [`DataFrame`]s this reader produces.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn to_df(&self, batch: Box<dyn Array>) -> PolarsResult<DataFrame> {
+        let batch = batch.as_any().downcast_ref::<StructArray>().unwrap();
+        let ArrowDataType::Struct(fields) = batch.data_type() else {
+            unreachable!()
+        };
+        let columns = fields
+            .iter()
+            .zip(batch.values())
+            .zip(&self.columns)
+            .filter_map(|((fld, arr), column)| match column {
+                Column::Keep => Some(Series::try_from((fld.name.as_str(), arr.clone()))),
+                Column::Drop => None,
+                Column::Null => Some(Ok(Series::full_null(&fld.name, arr.len(), &DataType::Null))),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+}
+
+impl Iterator for AdbcBatchedReader {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match unsafe { self.stream.next() } {
+            Some(Ok(batch)) => Some(self.to_df(batch)),
+            Some(Err(e)) => {
+                self.finished = true;
+                Some(Err(e))
+            },
+            None => {
+                self.finished = true;
+                None
+            },
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use object_store::aws::AwsCredential;
+use object_store::path::Path;
+use object_store::{
+    CredentialProvider, GetOptions, GetResult, ListResult, MultipartId, ObjectMeta, ObjectStore,
+    PutOptions, PutResult,
+};
+use polars_error::PolarsResult;
+use tokio::io::AsyncWrite;
+use tokio::sync::Mutex;
+
+/// Credentials are refreshed when they expire within this margin.
+const REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// AWS credentials as returned by a [`CredentialProviderFunction`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AwsCredentials {
+    pub key_id: String,
+    pub secret_key: String,
+    pub session_token: Option<String>,
+    /// Point in time at which the credentials expire. `None` if they don't expire.
+    pub expiry: Option<SystemTime>,
+}
+
+type CredentialFn = dyn Fn() -> PolarsResult<AwsCredentials> + Send + Sync;
+
+/// A user supplied function that fetches fresh credentials, e.g. from STS.
+///
+/// The function is called when the object store first needs credentials and again
+/// whenever the previously returned credentials are about to expire. This keeps
+/// long running queries working after the initial credentials expire.
+#[derive(Clone)]
+pub struct CredentialProviderFunction(Arc<CredentialFn>);
+
+impl CredentialProviderFunction {
+    pub fn new(f: impl Fn() -> PolarsResult<AwsCredentials> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Identity of the function, two providers are equal if they share the same function.
+    pub(crate) fn addr(&self) -> usize {
+        Arc::as_ptr(&self.0) as *const () as usize
+    }
+}
+
+impl Debug for CredentialProviderFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CredentialProviderFunction({:#x})", self.addr())
+    }
+}
+
+impl PartialEq for CredentialProviderFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.addr() == other.addr()
+    }
+}
+
+impl Eq for CredentialProviderFunction {}
+
+impl Hash for CredentialProviderFunction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state)
+    }
+}
+
+/// Credentials returned by the user function, with the time at which they were fetched.
+#[derive(Debug)]
+struct CachedCredential {
+    credential: Arc<AwsCredential>,
+    expiry: Option<SystemTime>,
+    fetched_at: Instant,
+}
+
+/// Caches the credentials of a [`CredentialProviderFunction`] and refreshes them
+/// shortly before they expire, or when a request was rejected with them.
+#[derive(Debug)]
+pub(crate) struct RefreshingAwsCredentialProvider {
+    func: CredentialProviderFunction,
+    /// Held while the function runs, so concurrent requests wait for a single refresh.
+    cached: Mutex<Option<CachedCredential>>,
+}
+
+impl RefreshingAwsCredentialProvider {
+    pub(crate) fn new(func: CredentialProviderFunction) -> Self {
+        Self {
+            func,
+            cached: Default::default(),
+        }
+    }
+
+    /// Drop the cached credentials if they were fetched before the request failed at
+    /// `failed_at`. Requests that were rejected concurrently only cause a single refresh this way.
+    async fn invalidate(&self, failed_at: Instant) {
+        let mut cached = self.cached.lock().await;
+        if cached
+            .as_ref()
+            .is_some_and(|cached| cached.fetched_at <= failed_at)
+        {
+            *cached = None;
+        }
+    }
+}
+
+fn needs_refresh(expiry: Option<SystemTime>) -> bool {
+    expiry.is_some_and(|expiry| SystemTime::now() + REFRESH_MARGIN >= expiry)
+}
+
+fn to_object_store_err(e: impl std::error::Error + Send + Sync + 'static) -> object_store::Error {
+    object_store::Error::Generic {
+        store: "S3",
+        source: Box::new(e),
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for RefreshingAwsCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<AwsCredential>> {
+        let mut cached = self.cached.lock().await;
+        if let Some(cached) = cached.as_ref() {
+            if !needs_refresh(cached.expiry) {
+                return Ok(cached.credential.clone());
+            }
+        }
+
+        // The function may block, e.g. on the GIL or on a request to STS, so it must not run
+        // on a worker of the async runtime.
+        let func = self.func.clone();
+        let fetched_at = Instant::now();
+        let fresh = tokio::task::spawn_blocking(move || (func.0)())
+            .await
+            .map_err(to_object_store_err)?
+            .map_err(to_object_store_err)?;
+        let credential = Arc::new(AwsCredential {
+            key_id: fresh.key_id,
+            secret_key: fresh.secret_key,
+            token: fresh.session_token,
+        });
+        *cached = Some(CachedCredential {
+            credential: credential.clone(),
+            expiry: fresh.expiry,
+            fetched_at,
+        });
+        Ok(credential)
+    }
+}
+
+/// Whether the request was rejected with 401 or 403. object_store only exposes the status
+/// in the message of the error.
+fn is_auth_error(e: &object_store::Error) -> bool {
+    let mut source: Option<&dyn std::error::Error> = Some(e);
+    while let Some(e) = source {
+        let msg = e.to_string();
+        if msg.contains("Client error with status 401")
+            || msg.contains("Client error with status 403")
+        {
+            return true;
+        }
+        source = e.source();
+    }
+    false
+}
+
+/// Run the request and, if it was rejected with 401 or 403, run it once more with fresh
+/// credentials. The credentials may have been revoked or rotated before they expired.
+async fn with_credential_refresh<T, F, Fut>(
+    provider: Option<&RefreshingAwsCredentialProvider>,
+    request: F,
+) -> object_store::Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = object_store::Result<T>>,
+{
+    match (request().await, provider) {
+        (Err(e), Some(provider)) if is_auth_error(&e) => {
+            provider.invalidate(Instant::now()).await;
+            request().await
+        },
+        (out, _) => out,
+    }
+}
+
+/// An S3 store that retries requests rejected with 401 or 403 once after refreshing the
+/// credentials of its [`RefreshingAwsCredentialProvider`], if it has one.
+#[derive(Debug)]
+pub(crate) struct CredentialRefreshingStore<S> {
+    inner: S,
+    provider: Option<Arc<RefreshingAwsCredentialProvider>>,
+}
+
+impl<S> CredentialRefreshingStore<S> {
+    pub(crate) fn new(inner: S, provider: Option<Arc<RefreshingAwsCredentialProvider>>) -> Self {
+        Self { inner, provider }
+    }
+
+    async fn retry<T, F, Fut>(&self, request: F) -> object_store::Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = object_store::Result<T>>,
+    {
+        with_credential_refresh(self.provider.as_deref(), request).await
+    }
+}
+
+impl<S: Display> Display for CredentialRefreshingStore<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.inner, f)
+    }
+}
+
+#[async_trait]
+impl<S: ObjectStore> ObjectStore for CredentialRefreshingStore<S> {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        bytes: Bytes,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        self.retry(|| self.inner.put_opts(location, bytes.clone(), opts.clone()))
+            .await
+    }
+
+    async fn put_multipart(
+        &self,
+        location: &Path,
+    ) -> object_store::Result<(MultipartId, Box<dyn AsyncWrite + Unpin + Send>)> {
+        self.retry(|| self.inner.put_multipart(location)).await
+    }
+
+    async fn abort_multipart(
+        &self,
+        location: &Path,
+        multipart_id: &MultipartId,
+    ) -> object_store::Result<()> {
+        self.retry(|| self.inner.abort_multipart(location, multipart_id))
+            .await
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        // `GetOptions` isn't `Clone` in this version of object_store.
+        let options = || GetOptions {
+            if_match: options.if_match.clone(),
+            if_none_match: options.if_none_match.clone(),
+            if_modified_since: options.if_modified_since,
+            if_unmodified_since: options.if_unmodified_since,
+            range: options.range.clone(),
+            version: options.version.clone(),
+            head: options.head,
+        };
+        self.retry(|| self.inner.get_opts(location, options()))
+            .await
+    }
+
+    async fn get_range(&self, location: &Path, range: Range<usize>) -> object_store::Result<Bytes> {
+        self.retry(|| self.inner.get_range(location, range.clone()))
+            .await
+    }
+
+    async fn get_ranges(
+        &self,
+        location: &Path,
+        ranges: &[Range<usize>],
+    ) -> object_store::Result<Vec<Bytes>> {
+        self.retry(|| self.inner.get_ranges(location, ranges)).await
+    }
+
+    async fn head(&self, location: &Path) -> object_store::Result<ObjectMeta> {
+        self.retry(|| self.inner.head(location)).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.retry(|| self.inner.delete(location)).await
+    }
+
+    // Listings are streamed, the pages can't be requested again.
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        self.inner.list(prefix)
+    }
+
+    fn list_with_offset(
+        &self,
+        prefix: Option<&Path>,
+        offset: &Path,
+    ) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+        self.inner.list_with_offset(prefix, offset)
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        self.retry(|| self.inner.list_with_delimiter(prefix)).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.retry(|| self.inner.copy(from, to)).await
+    }
+
+    async fn rename(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.retry(|| self.inner.rename(from, to)).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.retry(|| self.inner.copy_if_not_exists(from, to)).await
+    }
+
+    async fn rename_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.retry(|| self.inner.rename_if_not_exists(from, to))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn counting_provider(
+        valid_for: Duration,
+    ) -> (RefreshingAwsCredentialProvider, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let func = {
+            let calls = calls.clone();
+            CredentialProviderFunction::new(move || {
+                let n = calls.fetch_add(1, Ordering::Relaxed);
+                Ok(AwsCredentials {
+                    key_id: format!("key_{n}"),
+                    secret_key: "secret".into(),
+                    session_token: Some("token".into()),
+                    expiry: Some(SystemTime::now() + valid_for),
+                })
+            })
+        };
+        (RefreshingAwsCredentialProvider::new(func), calls)
+    }
+
+    #[test]
+    fn test_credential_provider_refresh() {
+        let rt = crate::pl_async::get_runtime();
+
+        // Credentials that expire within the refresh margin are fetched on every request.
+        let (provider, calls) = counting_provider(Duration::from_secs(1));
+        for i in 0..3 {
+            let credential = rt.block_on(provider.get_credential()).unwrap();
+            assert_eq!(credential.key_id, format!("key_{i}"));
+            assert_eq!(credential.token.as_deref(), Some("token"));
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+
+        // Long lived credentials are fetched once.
+        let (provider, calls) = counting_provider(Duration::from_secs(3600));
+        for _ in 0..3 {
+            let credential = rt.block_on(provider.get_credential()).unwrap();
+            assert_eq!(credential.key_id, "key_0");
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_credential_provider_refresh_on_auth_error() {
+        let rt = crate::pl_async::get_runtime();
+        let (provider, calls) = counting_provider(Duration::from_secs(3600));
+        let requests = AtomicUsize::new(0);
+
+        // The first request is rejected, the retry uses fresh credentials.
+        let key_id = rt
+            .block_on(with_credential_refresh(Some(&provider), || async {
+                let credential = provider.get_credential().await?;
+                if requests.fetch_add(1, Ordering::Relaxed) == 0 {
+                    return Err(object_store::Error::Generic {
+                        store: "S3",
+                        source: "Client error with status 403 Forbidden: No Body".into(),
+                    });
+                }
+                Ok(credential.key_id.clone())
+            }))
+            .unwrap();
+        assert_eq!(key_id, "key_1");
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        // Other errors are not retried.
+        let out = rt.block_on(with_credential_refresh(Some(&provider), || async {
+            requests.fetch_add(1, Ordering::Relaxed);
+            Err::<(), _>(object_store::Error::NotFound {
+                path: "a".into(),
+                source: "Client error with status 404 Not Found: No Body".into(),
+            })
+        }));
+        assert!(out.is_err());
+        assert_eq!(requests.load(Ordering::Relaxed), 3);
+
+        // A request that failed before the last refresh doesn't refresh again.
+        let failed_at = Instant::now() - Duration::from_secs(60);
+        rt.block_on(provider.invalidate(failed_at));
+        let credential = rt.block_on(provider.get_credential()).unwrap();
+        assert_eq!(credential.key_id, "key_1");
+    }
+}
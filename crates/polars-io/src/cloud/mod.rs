@@ -2,6 +2,8 @@
 
 #[cfg(feature = "cloud")]
 mod adaptors;
+#[cfg(feature = "aws")]
+mod credential_provider;
 #[cfg(feature = "cloud")]
 mod glob;
 #[cfg(feature = "cloud")]
@@ -12,6 +14,8 @@ mod polars_object_store;
 
 #[cfg(feature = "cloud")]
 pub use adaptors::*;
+#[cfg(feature = "aws")]
+pub use credential_provider::{AwsCredentials, CredentialProviderFunction};
 #[cfg(feature = "cloud")]
 pub use glob::*;
 #[cfg(feature = "cloud")]
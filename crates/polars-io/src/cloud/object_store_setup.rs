@@ -32,6 +32,12 @@ fn err_missing_feature(feature: &str, scheme: &str) -> BuildResult {
 fn url_and_creds_to_key(url: &Url, options: Option<&CloudOptions>) -> String {
     // We include credentials as they can expire, so users will send new credentials for the same url.
     let creds = serde_json::to_string(&options).unwrap_or_else(|_| "".into());
+    // The credential provider isn't serialized, so tell providers apart by identity.
+    #[cfg(feature = "aws")]
+    let creds = match options.and_then(|options| options.credential_provider.as_ref()) {
+        Some(provider) => format!("{creds}<\\provider\\>{:#x}", provider.addr()),
+        None => creds,
+    };
     format!(
         "{}://{}<\\creds\\>{}",
         url.scheme(),
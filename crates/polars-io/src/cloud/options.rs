@@ -3,6 +3,8 @@ use std::io::Read;
 #[cfg(feature = "aws")]
 use std::path::Path;
 use std::str::FromStr;
+#[cfg(feature = "aws")]
+use std::sync::Arc;
 
 #[cfg(feature = "aws")]
 use object_store::aws::AmazonS3Builder;
@@ -34,6 +36,10 @@ use smartstring::alias::String as SmartString;
 #[cfg(feature = "cloud")]
 use url::Url;
 
+#[cfg(feature = "aws")]
+use super::credential_provider::{
+    CredentialProviderFunction, CredentialRefreshingStore, RefreshingAwsCredentialProvider,
+};
 #[cfg(feature = "aws")]
 use crate::pl_async::with_concurrency_budget;
 #[cfg(feature = "aws")]
@@ -58,6 +64,9 @@ type Configs<T> = Vec<(T, String)>;
 pub struct CloudOptions {
     #[cfg(feature = "aws")]
     aws: Option<Configs<AmazonS3ConfigKey>>,
+    #[cfg(feature = "aws")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) credential_provider: Option<CredentialProviderFunction>,
     #[cfg(feature = "azure")]
     azure: Option<Configs<AzureConfigKey>>,
     #[cfg(feature = "gcp")]
@@ -71,6 +80,8 @@ impl Default for CloudOptions {
             max_retries: 2,
             #[cfg(feature = "aws")]
             aws: Default::default(),
+            #[cfg(feature = "aws")]
+            credential_provider: Default::default(),
             #[cfg(feature = "azure")]
             azure: Default::default(),
             #[cfg(feature = "gcp")]
@@ -224,6 +235,15 @@ impl CloudOptions {
         self
     }
 
+    /// Set a function that provides AWS credentials. It is called again whenever the
+    /// credentials it returned are about to expire, so that long running queries keep
+    /// working. These credentials take precedence over the ones in the configuration.
+    #[cfg(feature = "aws")]
+    pub fn with_credential_provider(mut self, provider: CredentialProviderFunction) -> Self {
+        self.credential_provider = Some(provider);
+        self
+    }
+
     /// Build the [`object_store::ObjectStore`] implementation for AWS.
     #[cfg(feature = "aws")]
     pub async fn build_aws(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
@@ -305,11 +325,20 @@ impl CloudOptions {
             };
         };
 
-        builder
+        let provider = self
+            .credential_provider
+            .as_ref()
+            .map(|func| Arc::new(RefreshingAwsCredentialProvider::new(func.clone())));
+        if let Some(provider) = &provider {
+            builder = builder.with_credentials(provider.clone());
+        }
+
+        let store = builder
             .with_client_options(get_client_options())
             .with_retry(get_retry_config(self.max_retries))
             .build()
-            .map_err(to_compute_err)
+            .map_err(to_compute_err)?;
+        Ok(CredentialRefreshingStore::new(store, provider))
     }
 
     /// Set the configuration for Azure connections. This is the preferred API from rust.
@@ -11,6 +11,8 @@ pub struct CsvParserOptions {
     pub comment_prefix: Option<CommentPrefix>,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
+    /// Bytes that separate records. Takes precedence over `eol_char` if set.
+    pub record_separator: Option<Vec<u8>>,
     pub encoding: CsvEncoding,
     pub skip_rows: usize,
     pub skip_rows_after_header: usize,
@@ -35,6 +37,7 @@ impl Default for CsvParserOptions {
             comment_prefix: None,
             quote_char: Some(b'"'),
             eol_char: b'\n',
+            record_separator: None,
             encoding: CsvEncoding::default(),
             skip_rows: 0,
             skip_rows_after_header: 0,
@@ -22,8 +22,10 @@ pub fn count_rows(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    record_separator: Option<&[u8]>,
     has_header: bool,
 ) -> PolarsResult<usize> {
+    let record_separator = RecordSeparator::new(eol_char, record_separator, separator, quote_char)?;
     let mut reader = polars_utils::open_file(path)?;
     let reader_bytes = get_reader_bytes(&mut reader)?;
     const MIN_ROWS_PER_THREAD: usize = 1024;
@@ -33,7 +35,7 @@ pub fn count_rows(
     let n_threads = get_line_stats(
         &reader_bytes,
         MIN_ROWS_PER_THREAD,
-        eol_char,
+        record_separator,
         None,
         separator,
         quote_char,
@@ -50,12 +52,13 @@ pub fn count_rows(
         None,
         separator,
         quote_char,
-        eol_char,
+        record_separator,
     );
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let local_bytes = &reader_bytes[start..stop];
-        let row_iterator = SplitLines::new(local_bytes, quote_char.unwrap_or(b'"'), eol_char);
+        let row_iterator =
+            SplitLines::new(local_bytes, quote_char.unwrap_or(b'"'), record_separator);
         if comment_prefix.is_some() {
             Ok(row_iterator
                 .filter(|line| !line.is_empty() && !is_comment_line(line, comment_prefix))
@@ -94,10 +97,106 @@ pub(super) fn is_comment_line(line: &[u8], comment_prefix: Option<&CommentPrefix
     }
 }
 
+/// The bytes that end a record: the end of line char, or a record separator of up to 4 bytes.
+///
+/// Records are split on the last byte. If the separator is longer, that byte only ends a record
+/// when the rest of the separator is in front of it, in the same way as a `\r` in front of a
+/// `\n` belongs to the line ending.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct RecordSeparator {
+    bytes: [u8; 4],
+    len: u8,
+}
+
+impl RecordSeparator {
+    pub(super) fn new(
+        eol_char: u8,
+        record_separator: Option<&[u8]>,
+        separator: u8,
+        quote_char: Option<u8>,
+    ) -> PolarsResult<Self> {
+        let record_separator = match record_separator {
+            None => std::slice::from_ref(&eol_char),
+            Some(record_separator) => {
+                polars_ensure!(
+                    (1..=4).contains(&record_separator.len()),
+                    InvalidOperation: "record separator must be 1 to 4 bytes long, got {}",
+                    record_separator.len()
+                );
+                polars_ensure!(
+                    record_separator.len() == 1
+                        || !record_separator.contains(&separator)
+                            && quote_char.map_or(true, |q| !record_separator.contains(&q)),
+                    InvalidOperation: "record separator {:?} cannot contain the separator or quote char",
+                    record_separator
+                );
+                record_separator
+            },
+        };
+        let mut bytes = [0; 4];
+        bytes[..record_separator.len()].copy_from_slice(record_separator);
+        Ok(Self {
+            bytes,
+            len: record_separator.len() as u8,
+        })
+    }
+
+    /// The last byte of the separator, which is searched for to find the end of a record.
+    #[inline]
+    pub(super) fn eol_char(&self) -> u8 {
+        self.bytes[self.len as usize - 1]
+    }
+
+    #[inline]
+    pub(super) fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    #[inline]
+    fn prefix(&self) -> &[u8] {
+        &self.bytes[..self.len as usize - 1]
+    }
+
+    #[inline]
+    pub(super) fn is_multi_byte(&self) -> bool {
+        self.len > 1
+    }
+
+    /// Whether the `eol_char` at `bytes[pos]` ends a record.
+    #[inline]
+    pub(super) fn ends_record(&self, bytes: &[u8], pos: usize) -> bool {
+        !self.is_multi_byte() || bytes[..pos].ends_with(self.prefix())
+    }
+
+    /// Remove the rest of the separator from a field that ended at the `eol_char`.
+    #[inline]
+    pub(super) fn trim_end<'a>(&self, field: &'a [u8]) -> &'a [u8] {
+        if self.is_multi_byte() {
+            field.strip_suffix(self.prefix()).unwrap_or(field)
+        } else {
+            field
+        }
+    }
+
+    /// Find the position after the first record separator. Doesn't look at quotes.
+    #[inline]
+    fn find_end(&self, input: &[u8]) -> Option<usize> {
+        let pos = if self.is_multi_byte() {
+            memchr::memchr_iter(self.eol_char(), input).find(|pos| self.ends_record(input, *pos))
+        } else {
+            memchr::memchr(self.eol_char(), input)
+        };
+        pos.map(|pos| pos + 1)
+    }
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
-pub(super) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usize> {
-    let pos = memchr::memchr(eol_char, input)? + 1;
+pub(super) fn next_line_position_naive(
+    input: &[u8],
+    record_separator: RecordSeparator,
+) -> Option<usize> {
+    let pos = record_separator.find_end(input)?;
     if input.len() - pos == 0 {
         return None;
     }
@@ -110,21 +209,31 @@ pub(super) fn next_line_position(
     mut expected_fields: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
         separator: u8,
-        eol_char: u8,
+        record_separator: RecordSeparator,
         quote_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
-        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char) {
-            if memchr2_iter(separator, eol_char, field).count() >= expected_fields {
+        let mut end = 0;
+        for (field, _) in SplitFields::new(line, separator, quote_char, record_separator) {
+            let n_separators = memchr2_iter(separator, record_separator.eol_char(), field)
+                .filter(|&i| field[i] == separator || record_separator.ends_record(field, i))
+                .count();
+            if n_separators >= expected_fields {
                 return false;
             }
             count += 1;
+            end = field.as_ptr() as usize + field.len() - line.as_ptr() as usize;
+        }
+        // The fields ended at a record separator in the middle of the line, so the quotes were
+        // read the wrong way around.
+        if end + 1 < line.len() {
+            return false;
         }
 
         // if the latest field is missing
@@ -161,22 +270,34 @@ pub(super) fn next_line_position(
                 expected_fields = Some(ef.saturating_sub(1))
             }
         };
-        let pos = memchr::memchr(eol_char, input)? + 1;
+        let pos = record_separator.find_end(input)?;
         if input.len() - pos == 0 {
             return None;
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char.unwrap_or(b'"'), eol_char);
+        let mut lines = SplitLines::new(new_input, quote_char.unwrap_or(b'"'), record_separator);
         let line = lines.next();
 
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
             (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                if accept_line(
+                    line,
+                    expected_fields,
+                    separator,
+                    record_separator,
+                    quote_char,
+                ) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                        if !accept_line(
+                            line,
+                            expected_fields,
+                            separator,
+                            record_separator,
+                            quote_char,
+                        ) {
                             valid = false;
                             break;
                         }
@@ -240,15 +361,24 @@ pub(super) fn skip_whitespace_exclude(input: &[u8], exclude: u8) -> &[u8] {
 }
 
 #[inline]
-pub(super) fn skip_line_ending(input: &[u8], eol_char: u8) -> &[u8] {
-    skip_condition(input, |b| is_line_ending(b, eol_char))
+pub(super) fn skip_line_ending(mut input: &[u8], record_separator: RecordSeparator) -> &[u8] {
+    if !record_separator.is_multi_byte() {
+        return skip_condition(input, |b| is_line_ending(b, record_separator.eol_char()));
+    }
+    loop {
+        input = skip_condition(input, |b| b == b'\r');
+        match input.strip_prefix(record_separator.as_bytes()) {
+            Some(rest) => input = rest,
+            None => return input,
+        }
+    }
 }
 
 /// Get the mean and standard deviation of length of lines in bytes
 pub(super) fn get_line_stats(
     bytes: &[u8],
     n_lines: usize,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     expected_fields: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
@@ -268,12 +398,12 @@ pub(super) fn get_line_stats(
             expected_fields,
             separator,
             quote_char,
-            eol_char,
+            record_separator,
         )?;
         bytes_trunc = &bytes_trunc[pos + 1..];
 
         for _ in offset..(offset + n_lines_per_iter) {
-            let pos = next_line_position_naive(bytes_trunc, eol_char)? + 1;
+            let pos = next_line_position_naive(bytes_trunc, record_separator)? + 1;
             n_read += pos;
             lengths.push(pos);
             bytes_trunc = &bytes_trunc[pos..];
@@ -304,14 +434,16 @@ pub(super) struct SplitLines<'a> {
     v: &'a [u8],
     quote_char: u8,
     end_line_char: u8,
+    record_separator: RecordSeparator,
 }
 
 impl<'a> SplitLines<'a> {
-    pub(super) fn new(slice: &'a [u8], quote_char: u8, end_line_char: u8) -> Self {
+    pub(super) fn new(slice: &'a [u8], quote_char: u8, record_separator: RecordSeparator) -> Self {
         Self {
             v: slice,
             quote_char,
-            end_line_char,
+            end_line_char: record_separator.eol_char(),
+            record_separator,
         }
     }
 }
@@ -341,7 +473,10 @@ impl<'a> Iterator for SplitLines<'a> {
                         in_field = !in_field;
                     }
                     // if we are not in a string and we encounter '\n' we can stop at this position.
-                    else if c == self.end_line_char && !in_field {
+                    else if c == self.end_line_char
+                        && !in_field
+                        && self.record_separator.ends_record(self.v, pos as usize - 1)
+                    {
                         break;
                     }
                 },
@@ -356,7 +491,8 @@ impl<'a> Iterator for SplitLines<'a> {
         unsafe {
             debug_assert!((pos as usize) <= self.v.len());
             // return line up to this position
-            let ret = Some(self.v.get_unchecked(..(pos - 1) as usize));
+            let line = self.v.get_unchecked(..(pos - 1) as usize);
+            let ret = Some(self.record_separator.trim_end(line));
             // skip the '\n' token and update slice.
             self.v = self.v.get_unchecked(pos as usize..);
             ret
@@ -365,7 +501,8 @@ impl<'a> Iterator for SplitLines<'a> {
 }
 
 #[inline]
-fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
+fn find_quoted(bytes: &[u8], quote_char: u8, record_separator: RecordSeparator) -> Option<usize> {
+    let needle = record_separator.eol_char();
     let mut in_field = false;
 
     let mut idx = 0u32;
@@ -379,8 +516,8 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
             in_field = !in_field;
         }
 
-        if !in_field && c == needle {
-            return Some(idx as usize);
+        if !in_field && c == needle && record_separator.ends_record(bytes, idx as usize) {
+            return Some(idx as usize + 1);
         }
         idx += 1;
     }
@@ -388,15 +525,17 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
 }
 
 #[inline]
-pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
+pub(super) fn skip_this_line(
+    bytes: &[u8],
+    quote: Option<u8>,
+    record_separator: RecordSeparator,
+) -> &[u8] {
     let pos = match quote {
-        Some(quote) => find_quoted(bytes, quote, eol_char),
-        None => bytes.iter().position(|x| *x == eol_char),
+        Some(quote) => find_quoted(bytes, quote, record_separator),
+        None => record_separator.find_end(bytes),
     };
-    match pos {
-        None => &[],
-        Some(pos) => &bytes[pos + 1..],
-    }
+    // Keep the pointer at the end of the input, the parser tracks the bytes read with it.
+    &bytes[pos.unwrap_or(bytes.len())..]
 }
 
 /// Parse CSV.
@@ -415,7 +554,7 @@ pub(super) fn parse_lines(
     separator: u8,
     comment_prefix: Option<&CommentPrefix>,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     missing_is_null: bool,
     ignore_errors: bool,
     mut truncate_ragged_lines: bool,
@@ -442,6 +581,7 @@ pub(super) fn parse_lines(
     let start = bytes.as_ptr() as usize;
     let original_bytes_len = bytes.len();
     let n_lines = n_lines as u32;
+    let eol_char = record_separator.eol_char();
 
     let mut line_count = 0u32;
     loop {
@@ -454,7 +594,7 @@ pub(super) fn parse_lines(
             return Ok(original_bytes_len);
         } else if is_comment_line(bytes, comment_prefix) {
             // deal with comments
-            let bytes_rem = skip_this_line(bytes, quote_char, eol_char);
+            let bytes_rem = skip_this_line(bytes, quote_char, record_separator);
             bytes = bytes_rem;
             continue;
         }
@@ -466,7 +606,7 @@ pub(super) fn parse_lines(
         let mut next_projected = unsafe { projection_iter.next().unwrap_unchecked() };
         let mut processed_fields = 0;
 
-        let mut iter = SplitFields::new(bytes, separator, quote_char, eol_char);
+        let mut iter = SplitFields::new(bytes, separator, quote_char, record_separator);
         let mut idx = 0u32;
         let mut read_sol = 0;
         loop {
@@ -486,7 +626,13 @@ pub(super) fn parse_lines(
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
-                        // this could be preceded by a '\r'
+                        // this could be preceded by the rest of a record separator or a '\r'
+                        if record_separator.is_multi_byte()
+                            && bytes.get(read_sol - 1) == Some(&eol_char)
+                        {
+                            field = record_separator.trim_end(field);
+                        }
+                        let field_len = field.len();
                         unsafe {
                             if field_len > 0 && *field.get_unchecked_release(field_len - 1) == b'\r'
                             {
@@ -559,7 +705,7 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked_release(read_sol - 1..) },
                                         quote_char,
-                                        eol_char,
+                                        record_separator,
                                     );
                                     bytes = bytes_rem;
                                 }
@@ -590,20 +736,43 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
 
 #[cfg(test)]
 mod test {
-    use super::SplitLines;
+    use super::{skip_this_line, RecordSeparator, SplitLines};
 
     #[test]
     fn test_splitlines() {
+        let eol = RecordSeparator::new(b'\n', None, b',', Some(b'"')).unwrap();
         let input = "1,\"foo\n\"\n2,\"foo\n\"\n";
-        let mut lines = SplitLines::new(input.as_bytes(), b'"', b'\n');
+        let mut lines = SplitLines::new(input.as_bytes(), b'"', eol);
         assert_eq!(lines.next(), Some("1,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), Some("2,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), None);
 
         let input2 = "1,'foo\n'\n2,'foo\n'\n";
-        let mut lines2 = SplitLines::new(input2.as_bytes(), b'\'', b'\n');
+        let mut lines2 = SplitLines::new(input2.as_bytes(), b'\'', eol);
         assert_eq!(lines2.next(), Some("1,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
     }
+
+    #[test]
+    fn test_splitlines_record_separator() {
+        let eol = RecordSeparator::new(b'\n', Some(b"\x1e\x1d"), b',', Some(b'"')).unwrap();
+        // Neither a part of the separator nor a quoted separator ends the record.
+        let input = b"a,b\x1e\x1d1,\"x\x1e\x1dy\"\x1e\x1d\x1d2,z\x1e\nw\x1e\x1d";
+        let mut lines = SplitLines::new(input, b'"', eol);
+        assert_eq!(lines.next(), Some(b"a,b".as_slice()));
+        assert_eq!(lines.next(), Some(b"1,\"x\x1e\x1dy\"".as_slice()));
+        assert_eq!(lines.next(), Some(b"\x1d2,z\x1e\nw".as_slice()));
+        assert_eq!(lines.next(), None);
+
+        assert_eq!(
+            skip_this_line(b"1,\"x\x1e\x1d\"\x1e\x1d2", Some(b'"'), eol),
+            b"2"
+        );
+        assert_eq!(skip_this_line(b"1,x\x1d\x1e\x1d2", None, eol), b"2");
+
+        assert!(RecordSeparator::new(b'\n', Some(b"12345"), b',', None).is_err());
+        assert!(RecordSeparator::new(b'\n', Some(b",\n"), b',', None).is_err());
+        assert!(RecordSeparator::new(b'\n', Some(b"\"\n"), b',', Some(b'"')).is_err());
+    }
 }
@@ -16,7 +16,7 @@ use super::buffer::init_buffers;
 use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled};
 use super::parser::{
     get_line_stats, is_comment_line, next_line_position, next_line_position_naive, parse_lines,
-    skip_bom, skip_line_ending, skip_this_line, skip_whitespace_exclude,
+    skip_bom, skip_line_ending, skip_this_line, skip_whitespace_exclude, RecordSeparator,
 };
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::utils::decompress;
@@ -112,7 +112,7 @@ pub(crate) struct CoreReader<'a> {
     decimal_comma: bool,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
@@ -154,6 +154,7 @@ impl<'a> CoreReader<'a> {
         comment_prefix: Option<CommentPrefix>,
         quote_char: Option<u8>,
         eol_char: u8,
+        record_separator: Option<Vec<u8>>,
         null_values: Option<NullValues>,
         missing_is_null: bool,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
@@ -179,6 +180,8 @@ impl<'a> CoreReader<'a> {
 
         // check if schema should be inferred
         let separator = separator.unwrap_or(b',');
+        let eol =
+            RecordSeparator::new(eol_char, record_separator.as_deref(), separator, quote_char)?;
 
         // We keep track of the inferred schema bool
         // In case the file is compressed this schema inference is wrong and has to be done
@@ -187,9 +190,7 @@ impl<'a> CoreReader<'a> {
         {
             let total_n_rows =
                 n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
-            if let Some(b) =
-                decompress(&reader_bytes, total_n_rows, separator, quote_char, eol_char)
-            {
+            if let Some(b) = decompress(&reader_bytes, total_n_rows, separator, quote_char, eol) {
                 reader_bytes = ReaderBytes::Owned(b);
             }
         }
@@ -208,6 +209,7 @@ impl<'a> CoreReader<'a> {
                     comment_prefix.as_ref(),
                     quote_char,
                     eol_char,
+                    record_separator.as_deref(),
                     null_values.as_ref(),
                     try_parse_dates,
                     raise_if_empty,
@@ -260,7 +262,7 @@ impl<'a> CoreReader<'a> {
             low_memory,
             comment_prefix,
             quote_char,
-            eol_char,
+            record_separator: eol,
             null_values,
             missing_is_null,
             predicate,
@@ -275,7 +277,7 @@ impl<'a> CoreReader<'a> {
         &self,
         mut bytes: &'b [u8],
         quote_char: Option<u8>,
-        eol_char: u8,
+        record_separator: RecordSeparator,
     ) -> PolarsResult<(&'b [u8], Option<usize>)> {
         let starting_point_offset = bytes.as_ptr() as usize;
 
@@ -284,13 +286,13 @@ impl<'a> CoreReader<'a> {
         // \n\n can be a empty string row of a single column
         // in other cases we skip it.
         if self.schema.len() > 1 {
-            bytes = skip_line_ending(bytes, eol_char)
+            bytes = skip_line_ending(bytes, record_separator)
         }
 
         // skip 'n' leading rows
         if self.skip_rows_before_header > 0 {
             for _ in 0..self.skip_rows_before_header {
-                let pos = next_line_position_naive(bytes, eol_char)
+                let pos = next_line_position_naive(bytes, record_separator)
                     .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
                 bytes = &bytes[pos..];
             }
@@ -298,23 +300,29 @@ impl<'a> CoreReader<'a> {
 
         // skip lines that are comments
         while is_comment_line(bytes, self.comment_prefix.as_ref()) {
-            bytes = skip_this_line(bytes, quote_char, eol_char);
+            bytes = skip_this_line(bytes, quote_char, record_separator);
         }
 
         // skip header row
         if self.has_header {
-            bytes = skip_this_line(bytes, quote_char, eol_char);
+            bytes = skip_this_line(bytes, quote_char, record_separator);
         }
         // skip 'n' rows following the header
         if self.skip_rows_after_header > 0 {
             for _ in 0..self.skip_rows_after_header {
                 let pos = if is_comment_line(bytes, self.comment_prefix.as_ref()) {
-                    next_line_position_naive(bytes, eol_char)
+                    next_line_position_naive(bytes, record_separator)
                 } else {
                     // we don't pass expected fields
                     // as we want to skip all rows
                     // no matter the no. of fields
-                    next_line_position(bytes, None, self.separator, self.quote_char, eol_char)
+                    next_line_position(
+                        bytes,
+                        None,
+                        self.separator,
+                        self.quote_char,
+                        record_separator,
+                    )
                 }
                 .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
 
@@ -349,7 +357,7 @@ impl<'a> CoreReader<'a> {
         if let Some((mean, std)) = get_line_stats(
             bytes,
             self.sample_size,
-            self.eol_char,
+            self.record_separator,
             Some(self.schema.len()),
             self.separator,
             self.quote_char,
@@ -377,7 +385,7 @@ impl<'a> CoreReader<'a> {
                         Some(self.schema.len()),
                         self.separator,
                         self.quote_char,
-                        self.eol_char,
+                        self.record_separator,
                     ) {
                         if set_upper_bound {
                             (bytes, remaining_bytes) =
@@ -409,7 +417,7 @@ impl<'a> CoreReader<'a> {
     )> {
         // Make the variable mutable so that we can reassign the sliced file to this variable.
         let (bytes, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+            self.find_starting_point(bytes, self.quote_char, self.record_separator)?;
 
         let (bytes, total_rows, remaining_bytes) =
             self.estimate_rows_and_set_upper_bound(bytes, logging, true);
@@ -433,7 +441,7 @@ impl<'a> CoreReader<'a> {
             Some(self.schema.len()),
             self.separator,
             self.quote_char,
-            self.eol_char,
+            self.record_separator,
         );
 
         if logging {
@@ -527,7 +535,7 @@ impl<'a> CoreReader<'a> {
                                 self.separator,
                                 self.comment_prefix.as_ref(),
                                 self.quote_char,
-                                self.eol_char,
+                                self.record_separator,
                                 self.missing_is_null,
                                 ignore_errors,
                                 self.truncate_ragged_lines,
@@ -589,7 +597,7 @@ impl<'a> CoreReader<'a> {
                             &projection,
                             bytes_offset_thread,
                             self.quote_char,
-                            self.eol_char,
+                            self.record_separator,
                             self.comment_prefix.as_ref(),
                             capacity,
                             self.encoding,
@@ -632,7 +640,7 @@ impl<'a> CoreReader<'a> {
                                 self.separator,
                                 self.comment_prefix.as_ref(),
                                 self.quote_char,
-                                self.eol_char,
+                                self.record_separator,
                                 self.missing_is_null,
                                 self.ignore_errors,
                                 self.truncate_ragged_lines,
@@ -696,7 +704,7 @@ fn read_chunk(
     projection: &[usize],
     bytes_offset_thread: usize,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     comment_prefix: Option<&CommentPrefix>,
     capacity: usize,
     encoding: CsvEncoding,
@@ -738,7 +746,7 @@ fn read_chunk(
             separator,
             comment_prefix,
             quote_char,
-            eol_char,
+            record_separator,
             missing_is_null,
             ignore_errors,
             truncate_ragged_lines,
@@ -11,7 +11,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use super::{cast_columns, read_chunk, CoreReader};
 use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
-use crate::csv::read::parser::next_line_position;
+use crate::csv::read::parser::{next_line_position, RecordSeparator};
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::update_row_counts2;
@@ -27,7 +27,7 @@ pub(crate) fn get_file_chunks_iterator(
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 ) {
     for _ in 0..n_chunks {
         let search_pos = *last_pos + chunk_size;
@@ -41,7 +41,7 @@ pub(crate) fn get_file_chunks_iterator(
             Some(expected_fields),
             separator,
             quote_char,
-            eol_char,
+            record_separator,
         ) {
             Some(pos) => search_pos + pos,
             None => {
@@ -63,7 +63,7 @@ struct ChunkOffsetIter<'a> {
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 }
 
 impl<'a> Iterator for ChunkOffsetIter<'a> {
@@ -82,7 +82,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                         Some(self.expected_fields),
                         self.separator,
                         self.quote_char,
-                        self.eol_char,
+                        self.record_separator,
                     )
                     .unwrap_or(1);
                     bytes_first_row + 2
@@ -98,7 +98,7 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.expected_fields,
                     self.separator,
                     self.quote_char,
-                    self.eol_char,
+                    self.record_separator,
                 );
                 match self.offsets.pop_front() {
                     Some(offsets) => Some(offsets),
@@ -120,7 +120,7 @@ impl<'a> CoreReader<'a> {
         let reader_bytes = self.reader_bytes.take().unwrap();
         let bytes = reader_bytes.as_ref();
         let (bytes, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+            self.find_starting_point(bytes, self.quote_char, self.record_separator)?;
 
         // this is arbitrarily chosen.
         // we don't want this to depend on the thread pool size
@@ -138,7 +138,7 @@ impl<'a> CoreReader<'a> {
             expected_fields: self.schema.len(),
             separator: self.separator,
             quote_char: self.quote_char,
-            eol_char: self.eol_char,
+            record_separator: self.record_separator,
         };
 
         let projection = self.get_projection()?;
@@ -164,7 +164,7 @@ impl<'a> CoreReader<'a> {
             row_index: self.row_index,
             comment_prefix: self.comment_prefix,
             quote_char: self.quote_char,
-            eol_char: self.eol_char,
+            record_separator: self.record_separator,
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
@@ -191,7 +191,7 @@ pub struct BatchedCsvReaderMmap<'a> {
     row_index: Option<RowIndex>,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     truncate_ragged_lines: bool,
@@ -247,7 +247,7 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                         &self.projection,
                         bytes_offset_thread,
                         self.quote_char,
-                        self.eol_char,
+                        self.record_separator,
                         self.comment_prefix.as_ref(),
                         self.chunk_size,
                         self.encoding,
@@ -12,7 +12,7 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use super::{cast_columns, read_chunk, CoreReader};
 use crate::csv::read::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
-use crate::csv::read::parser::next_line_position;
+use crate::csv::read::parser::{next_line_position, RecordSeparator};
 use crate::csv::read::CsvReader;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::update_row_counts2;
@@ -27,7 +27,7 @@ pub(crate) fn get_offsets(
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 ) {
     let mut start = 0;
     for i in 1..(n_chunks + 1) {
@@ -42,7 +42,7 @@ pub(crate) fn get_offsets(
             Some(expected_fields),
             separator,
             quote_char,
-            eol_char,
+            record_separator,
         ) {
             Some(pos) => search_pos + pos,
             None => {
@@ -70,7 +70,7 @@ struct ChunkReader<'a> {
     expected_fields: usize,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 }
 
 impl<'a> ChunkReader<'a> {
@@ -80,7 +80,7 @@ impl<'a> ChunkReader<'a> {
         expected_fields: usize,
         separator: u8,
         quote_char: Option<u8>,
-        eol_char: u8,
+        record_separator: RecordSeparator,
         page_size: u64,
     ) -> Self {
         Self {
@@ -98,7 +98,7 @@ impl<'a> ChunkReader<'a> {
             expected_fields,
             separator,
             quote_char,
-            eol_char,
+            record_separator,
         }
     }
 
@@ -145,7 +145,7 @@ impl<'a> ChunkReader<'a> {
                     Some(self.expected_fields),
                     self.separator,
                     self.quote_char,
-                    self.eol_char,
+                    self.record_separator,
                 );
 
                 if bytes_first_row.is_some() {
@@ -192,7 +192,7 @@ impl<'a> ChunkReader<'a> {
             self.expected_fields,
             self.separator,
             self.quote_char,
-            self.eol_char,
+            self.record_separator,
         );
         !self.offsets.is_empty()
     }
@@ -207,7 +207,7 @@ impl<'a> CoreReader<'a> {
             unreachable!()
         };
         let (_, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+            self.find_starting_point(bytes, self.quote_char, self.record_separator)?;
         if let Some(starting_point_offset) = starting_point_offset {
             file.seek(SeekFrom::Current(starting_point_offset as i64))
                 .unwrap();
@@ -219,7 +219,7 @@ impl<'a> CoreReader<'a> {
             self.schema.len(),
             self.separator,
             self.quote_char,
-            self.eol_char,
+            self.record_separator,
             4096,
         );
 
@@ -246,7 +246,7 @@ impl<'a> CoreReader<'a> {
             row_index: self.row_index,
             comment_prefix: self.comment_prefix,
             quote_char: self.quote_char,
-            eol_char: self.eol_char,
+            record_separator: self.record_separator,
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
             to_cast: self.to_cast,
@@ -273,7 +273,7 @@ pub struct BatchedCsvReaderRead<'a> {
     row_index: Option<RowIndex>,
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     to_cast: Vec<Field>,
@@ -343,7 +343,7 @@ impl<'a> BatchedCsvReaderRead<'a> {
                         &self.projection,
                         0,
                         self.quote_char,
-                        self.eol_char,
+                        self.record_separator,
                         self.comment_prefix.as_ref(),
                         self.chunk_size,
                         self.encoding,
@@ -78,6 +78,7 @@ where
     has_header: bool,
     ignore_errors: bool,
     eol_char: u8,
+    record_separator: Option<Vec<u8>>,
     decimal_comma: bool,
 }
 
@@ -178,6 +179,14 @@ where
         self
     }
 
+    /// Set the bytes that separate records, e.g. `b"\x1e"` or `b"\r\n\x1e"`. Takes precedence
+    /// over the end of line char. Separators of 2 to 4 bytes are supported; they don't split a
+    /// record when they occur inside a quoted field.
+    pub fn with_record_separator(mut self, record_separator: Option<Vec<u8>>) -> Self {
+        self.record_separator = record_separator;
+        self
+    }
+
     /// Set values that will be interpreted as missing/ null. Note that any value you set as null value
     /// will not be escaped, so if quotation marks are part of the null value you should include them.
     pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
@@ -336,6 +345,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             std::mem::take(&mut self.comment_prefix),
             self.quote_char,
             self.eol_char,
+            std::mem::take(&mut self.record_separator),
             std::mem::take(&mut self.null_values),
             self.missing_is_null,
             std::mem::take(&mut self.predicate),
@@ -449,6 +459,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.comment_prefix.as_ref(),
                     self.quote_char,
                     self.eol_char,
+                    self.record_separator.as_deref(),
                     self.null_values.as_ref(),
                     self.try_parse_dates,
                     self.raise_if_empty,
@@ -480,6 +491,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.comment_prefix.as_ref(),
                     self.quote_char,
                     self.eol_char,
+                    self.record_separator.as_deref(),
                     self.null_values.as_ref(),
                     self.try_parse_dates,
                     self.raise_if_empty,
@@ -521,6 +533,7 @@ where
             low_memory: false,
             comment_prefix: None,
             eol_char: b'\n',
+            record_separator: None,
             null_values: None,
             missing_is_null: true,
             predicate: None,
@@ -1,5 +1,7 @@
 #[cfg(not(feature = "simd"))]
 mod inner {
+    use crate::csv::read::parser::RecordSeparator;
+
     /// An adapted version of std::iter::Split.
     /// This exists solely because we cannot split the lines naively as
     pub(crate) struct SplitFields<'a> {
@@ -9,6 +11,7 @@ mod inner {
         quote_char: u8,
         quoting: bool,
         eol_char: u8,
+        record_separator: RecordSeparator,
     }
 
     impl<'a> SplitFields<'a> {
@@ -16,7 +19,7 @@ mod inner {
             slice: &'a [u8],
             separator: u8,
             quote_char: Option<u8>,
-            eol_char: u8,
+            record_separator: RecordSeparator,
         ) -> Self {
             Self {
                 v: slice,
@@ -24,7 +27,8 @@ mod inner {
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
-                eol_char,
+                eol_char: record_separator.eol_char(),
+                record_separator,
             }
         }
 
@@ -89,15 +93,20 @@ mod inner {
                     }
 
                     if !in_field && self.eof_oel(c) {
-                        if c == self.eol_char {
+                        if c != self.eol_char {
+                            idx = current_idx;
+                            break;
+                        }
+                        if self
+                            .record_separator
+                            .ends_record(self.v, current_idx as usize)
+                        {
                             // SAFETY:
                             // we are in bounds
                             return unsafe {
                                 self.finish_eol(needs_escaping, current_idx as usize)
                             };
                         }
-                        idx = current_idx;
-                        break;
                     }
                     current_idx += 1;
                 }
@@ -108,17 +117,22 @@ mod inner {
 
                 idx as usize
             } else {
-                match self.v.iter().position(|&c| self.eof_oel(c)) {
-                    None => return self.finish(needs_escaping),
-                    Some(idx) => unsafe {
-                        // SAFETY:
-                        // idx was just found
-                        if *self.v.get_unchecked(idx) == self.eol_char {
-                            return self.finish_eol(needs_escaping, idx);
-                        } else {
-                            idx
-                        }
-                    },
+                let mut total_idx = 0;
+                loop {
+                    match self.v[total_idx..].iter().position(|&c| self.eof_oel(c)) {
+                        None => return self.finish(needs_escaping),
+                        Some(idx) => unsafe {
+                            total_idx += idx;
+                            // SAFETY:
+                            // idx was just found
+                            if *self.v.get_unchecked(total_idx) != self.eol_char {
+                                break total_idx;
+                            } else if self.record_separator.ends_record(self.v, total_idx) {
+                                return self.finish_eol(needs_escaping, total_idx);
+                            }
+                            total_idx += 1;
+                        },
+                    }
                 }
             };
 
@@ -142,6 +156,8 @@ mod inner {
     use polars_utils::slice::GetSaferUnchecked;
     use polars_utils::unwrap::UnwrapUncheckedRelease;
 
+    use crate::csv::read::parser::RecordSeparator;
+
     const SIMD_SIZE: usize = 16;
     type SimdVec = u8x16;
 
@@ -164,6 +180,7 @@ mod inner {
         quote_char: u8,
         quoting: bool,
         eol_char: u8,
+        record_separator: RecordSeparator,
         simd_separator: SimdVec,
         simd_eol_char: SimdVec,
     }
@@ -173,8 +190,9 @@ mod inner {
             slice: &'a [u8],
             separator: u8,
             quote_char: Option<u8>,
-            eol_char: u8,
+            record_separator: RecordSeparator,
         ) -> Self {
+            let eol_char = record_separator.eol_char();
             let simd_separator = SimdVec::splat(separator);
             let simd_eol_char = SimdVec::splat(eol_char);
 
@@ -185,6 +203,7 @@ mod inner {
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
                 eol_char,
+                record_separator,
                 simd_separator,
                 simd_eol_char,
             }
@@ -251,15 +270,20 @@ mod inner {
                     }
 
                     if !in_field && self.eof_oel(c) {
-                        if c == self.eol_char {
+                        if c != self.eol_char {
+                            idx = current_idx;
+                            break;
+                        }
+                        if self
+                            .record_separator
+                            .ends_record(self.v, current_idx as usize)
+                        {
                             // SAFETY:
                             // we are in bounds
                             return unsafe {
                                 self.finish_eol(needs_escaping, current_idx as usize)
                             };
                         }
-                        idx = current_idx;
-                        break;
                     }
                     current_idx += 1;
                 }
@@ -272,47 +296,51 @@ mod inner {
             } else {
                 let mut total_idx = 0;
 
-                loop {
-                    let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
-
-                    if bytes.len() > SIMD_SIZE {
-                        unsafe {
-                            let lane: [u8; SIMD_SIZE] = bytes
-                                .get_unchecked(0..SIMD_SIZE)
-                                .try_into()
-                                .unwrap_unchecked_release();
-                            let simd_bytes = SimdVec::from(lane);
-                            let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
-                            let has_separator = simd_bytes.simd_eq(self.simd_separator);
-                            let has_any = has_separator.bitor(has_eol_char);
-                            if has_any.any() {
-                                // soundness we can transmute because we have the same alignment
-                                let has_any = std::mem::transmute::<
-                                    Mask<_, SIMD_SIZE>,
-                                    [bool; SIMD_SIZE],
-                                >(has_any);
-                                total_idx += simple_argmax(&has_any);
-                                break;
-                            } else {
-                                total_idx += SIMD_SIZE;
+                'search: loop {
+                    loop {
+                        let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
+
+                        if bytes.len() > SIMD_SIZE {
+                            unsafe {
+                                let lane: [u8; SIMD_SIZE] = bytes
+                                    .get_unchecked(0..SIMD_SIZE)
+                                    .try_into()
+                                    .unwrap_unchecked_release();
+                                let simd_bytes = SimdVec::from(lane);
+                                let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
+                                let has_separator = simd_bytes.simd_eq(self.simd_separator);
+                                let has_any = has_separator.bitor(has_eol_char);
+                                if has_any.any() {
+                                    // soundness we can transmute because we have the same alignment
+                                    let has_any = std::mem::transmute::<
+                                        Mask<_, SIMD_SIZE>,
+                                        [bool; SIMD_SIZE],
+                                    >(has_any);
+                                    total_idx += simple_argmax(&has_any);
+                                    break;
+                                } else {
+                                    total_idx += SIMD_SIZE;
+                                }
+                            }
+                        } else {
+                            match bytes.iter().position(|&c| self.eof_oel(c)) {
+                                None => return self.finish(needs_escaping),
+                                Some(idx) => {
+                                    total_idx += idx;
+                                    break;
+                                },
                             }
-                        }
-                    } else {
-                        match bytes.iter().position(|&c| self.eof_oel(c)) {
-                            None => return self.finish(needs_escaping),
-                            Some(idx) => {
-                                total_idx += idx;
-                                break;
-                            },
                         }
                     }
-                }
-                unsafe {
-                    if *self.v.get_unchecked_release(total_idx) == self.eol_char {
-                        return self.finish_eol(needs_escaping, total_idx);
-                    } else {
-                        total_idx
+                    unsafe {
+                        if *self.v.get_unchecked_release(total_idx) != self.eol_char {
+                            break 'search total_idx;
+                        } else if self.record_separator.ends_record(self.v, total_idx) {
+                            return self.finish_eol(needs_escaping, total_idx);
+                        }
                     }
+                    // Only a part of the record separator, search on.
+                    total_idx += 1;
                 }
             };
 
@@ -333,22 +361,47 @@ pub(crate) use inner::SplitFields;
 #[cfg(test)]
 mod test {
     use super::SplitFields;
+    use crate::csv::read::parser::RecordSeparator;
 
     #[test]
     fn test_splitfields() {
+        let eol = RecordSeparator::new(b'\n', None, b',', Some(b'"')).unwrap();
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), eol);
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), eol);
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_record_separator() {
+        let eol = RecordSeparator::new(b'\n', Some(b"||"), b',', Some(b'"')).unwrap();
+        // Long enough fields to take the simd path, a lone `|` is data.
+        let input = b"a|bcdefghijklmnopqrstuvwxyz,\"x||\"||next";
+        let mut fields = SplitFields::new(input, b',', Some(b'"'), eol);
+        assert_eq!(
+            fields.next(),
+            Some((b"a|bcdefghijklmnopqrstuvwxyz".as_slice(), false))
+        );
+        // The field that ends the record keeps the rest of the separator.
+        assert_eq!(fields.next(), Some((b"\"x||\"|".as_slice(), true)));
+        assert_eq!(fields.next(), None);
+
+        let mut fields = SplitFields::new(b"a,0123456789abcdef|ghi||j", b',', None, eol);
+        assert_eq!(fields.next(), Some((b"a".as_slice(), false)));
+        assert_eq!(
+            fields.next(),
+            Some((b"0123456789abcdef|ghi|".as_slice(), false))
+        );
+        assert_eq!(fields.next(), None);
+    }
 }
@@ -14,7 +14,9 @@ use polars_utils::slice::GetSaferUnchecked;
 use super::options::{CommentPrefix, CsvEncoding, NullValues};
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::parser::next_line_position_naive;
-use super::parser::{is_comment_line, next_line_position, skip_bom, skip_line_ending, SplitLines};
+use super::parser::{
+    is_comment_line, next_line_position, skip_bom, skip_line_ending, RecordSeparator, SplitLines,
+};
 use super::splitfields::SplitFields;
 use crate::mmap::ReaderBytes;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
@@ -25,7 +27,7 @@ pub(crate) fn get_file_chunks(
     expected_fields: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
     let total_len = bytes.len();
@@ -43,7 +45,7 @@ pub(crate) fn get_file_chunks(
             expected_fields,
             separator,
             quote_char,
-            eol_char,
+            record_separator,
         ) {
             Some(pos) => search_pos + pos,
             None => {
@@ -134,7 +136,7 @@ pub(crate) fn parse_bytes_with_encoding(
 }
 
 #[allow(clippy::too_many_arguments)]
-pub fn infer_file_schema_inner(
+pub(super) fn infer_file_schema_inner(
     reader_bytes: &ReaderBytes,
     separator: u8,
     max_read_rows: Option<usize>,
@@ -146,7 +148,7 @@ pub fn infer_file_schema_inner(
     skip_rows_after_header: usize,
     comment_prefix: Option<&CommentPrefix>,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
     recursion_count: u8,
@@ -161,11 +163,12 @@ pub fn infer_file_schema_inner(
     // It may later.
     let encoding = CsvEncoding::LossyUtf8;
 
-    let bytes = skip_line_ending(skip_bom(reader_bytes), eol_char);
+    let bytes = skip_line_ending(skip_bom(reader_bytes), record_separator);
     if raise_if_empty {
         polars_ensure!(!bytes.is_empty(), NoData: "empty CSV");
     };
-    let mut lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+    let mut lines =
+        SplitLines::new(bytes, quote_char.unwrap_or(b'"'), record_separator).skip(*skip_rows);
 
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
@@ -196,7 +199,7 @@ pub fn infer_file_schema_inner(
             }
         }
 
-        let byterecord = SplitFields::new(header_line, separator, quote_char, eol_char);
+        let byterecord = SplitFields::new(header_line, separator, quote_char, record_separator);
         if has_header {
             let headers = byterecord
                 .map(|(slice, needs_escaping)| {
@@ -233,9 +236,9 @@ pub fn infer_file_schema_inner(
     } else if has_header && !bytes.is_empty() && recursion_count == 0 {
         // there was no new line char. So we copy the whole buf and add one
         // this is likely to be cheap as there are no rows.
-        let mut buf = Vec::with_capacity(bytes.len() + 2);
+        let mut buf = Vec::with_capacity(bytes.len() + 5);
         buf.extend_from_slice(bytes);
-        buf.push(eol_char);
+        buf.extend_from_slice(record_separator.as_bytes());
 
         return infer_file_schema_inner(
             &ReaderBytes::Owned(buf),
@@ -247,7 +250,7 @@ pub fn infer_file_schema_inner(
             skip_rows_after_header,
             comment_prefix,
             quote_char,
-            eol_char,
+            record_separator,
             null_values,
             try_parse_dates,
             recursion_count + 1,
@@ -262,7 +265,8 @@ pub fn infer_file_schema_inner(
     };
     if !has_header {
         // re-init lines so that the header is included in type inference.
-        lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+        lines =
+            SplitLines::new(bytes, quote_char.unwrap_or(b'"'), record_separator).skip(*skip_rows);
     }
 
     let header_length = headers.len();
@@ -317,7 +321,7 @@ pub fn infer_file_schema_inner(
             }
         }
 
-        let mut record = SplitFields::new(line, separator, quote_char, eol_char);
+        let mut record = SplitFields::new(line, separator, quote_char, record_separator);
 
         for i in 0..header_length {
             if let Some((slice, needs_escaping)) = record.next() {
@@ -373,7 +377,12 @@ pub fn infer_file_schema_inner(
                             // new line characters in an escaped field. So we set a (somewhat arbitrary)
                             // upper bound to the number of escaped lines we accept.
                             // On the chunking side we also have logic to make this more robust.
-                            if slice.iter().filter(|b| **b == eol_char).count() > 8 {
+                            if slice
+                                .iter()
+                                .filter(|b| **b == record_separator.eol_char())
+                                .count()
+                                > 8
+                            {
                                 if verbose() {
                                     eprintln!("falling back to single core reading because of many escaped new line chars.")
                                 }
@@ -435,12 +444,12 @@ pub fn infer_file_schema_inner(
     // so that the inference is consistent with and without eol char
     if rows_count == 0
         && !reader_bytes.is_empty()
-        && reader_bytes[reader_bytes.len() - 1] != eol_char
+        && !reader_bytes.ends_with(record_separator.as_bytes())
         && recursion_count == 0
     {
-        let mut rb = Vec::with_capacity(reader_bytes.len() + 1);
+        let mut rb = Vec::with_capacity(reader_bytes.len() + 4);
         rb.extend_from_slice(reader_bytes);
-        rb.push(eol_char);
+        rb.extend_from_slice(record_separator.as_bytes());
         return infer_file_schema_inner(
             &ReaderBytes::Owned(rb),
             separator,
@@ -451,7 +460,7 @@ pub fn infer_file_schema_inner(
             skip_rows_after_header,
             comment_prefix,
             quote_char,
-            eol_char,
+            record_separator,
             null_values,
             try_parse_dates,
             recursion_count + 1,
@@ -494,6 +503,7 @@ pub fn infer_file_schema(
     comment_prefix: Option<&CommentPrefix>,
     quote_char: Option<u8>,
     eol_char: u8,
+    record_separator: Option<&[u8]>,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
     raise_if_empty: bool,
@@ -501,6 +511,7 @@ pub fn infer_file_schema(
     decimal_comma: bool,
 ) -> PolarsResult<(Schema, usize, usize)> {
     check_decimal_comma(decimal_comma, separator)?;
+    let record_separator = RecordSeparator::new(eol_char, record_separator, separator, quote_char)?;
     infer_file_schema_inner(
         reader_bytes,
         separator,
@@ -511,7 +522,7 @@ pub fn infer_file_schema(
         skip_rows_after_header,
         comment_prefix,
         quote_char,
-        eol_char,
+        record_separator,
         null_values,
         try_parse_dates,
         0,
@@ -543,7 +554,7 @@ fn decompress_impl<R: Read>(
     n_rows: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 ) -> Option<Vec<u8>> {
     let chunk_size = 4096;
     Some(match n_rows {
@@ -565,7 +576,7 @@ fn decompress_impl<R: Read>(
                 if read == 0 {
                     break;
                 }
-                if next_line_position_naive(&out, eol_char).is_some() {
+                if next_line_position_naive(&out, record_separator).is_some() {
                     // an extra shot
                     let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
                     if read == 0 {
@@ -573,7 +584,7 @@ fn decompress_impl<R: Read>(
                     }
                     // now that we have enough, we compute the number of fields (also takes embedding into account)
                     expected_fields =
-                        SplitFields::new(&out, separator, quote_char, eol_char).count();
+                        SplitFields::new(&out, separator, quote_char, record_separator).count();
                     break;
                 }
             }
@@ -588,7 +599,7 @@ fn decompress_impl<R: Read>(
                     Some(expected_fields),
                     separator,
                     quote_char,
-                    eol_char,
+                    record_separator,
                 ) {
                     Some(pos) => {
                         line_count += 1;
@@ -616,17 +627,35 @@ pub(crate) fn decompress(
     n_rows: Option<usize>,
     separator: u8,
     quote_char: Option<u8>,
-    eol_char: u8,
+    record_separator: RecordSeparator,
 ) -> Option<Vec<u8>> {
     if bytes.starts_with(&GZIP) {
         let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            separator,
+            quote_char,
+            record_separator,
+        )
     } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
         let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            separator,
+            quote_char,
+            record_separator,
+        )
     } else if bytes.starts_with(&ZSTD) {
         let mut decoder = zstd::Decoder::new(bytes).ok()?;
-        decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            separator,
+            quote_char,
+            record_separator,
+        )
     } else {
         None
     }
@@ -668,18 +697,36 @@ pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUnini
 #[cfg(test)]
 mod test {
     use super::get_file_chunks;
+    use crate::csv::read::parser::RecordSeparator;
 
     #[test]
     fn test_get_file_chunks() {
         let path = "../../examples/datasets/foods1.csv";
         let s = std::fs::read_to_string(path).unwrap();
         let bytes = s.as_bytes();
+        let eol = RecordSeparator::new(b'\n', None, b',', None).unwrap();
         // can be within -1 / +1 bounds.
         assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
-        );
-        assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
+            (get_file_chunks(bytes, 10, Some(4), b',', None, eol).len() as i32 - 10).abs() <= 1
         );
+        assert!((get_file_chunks(bytes, 8, Some(4), b',', None, eol).len() as i32 - 8).abs() <= 1);
+    }
+
+    #[test]
+    fn test_get_file_chunks_record_separator() {
+        let path = "../../examples/datasets/foods1.csv";
+        let s = std::fs::read_to_string(path)
+            .unwrap()
+            .replace('\n', "\x1e\x1d");
+        let bytes = s.as_bytes();
+        let eol = RecordSeparator::new(b'\n', Some(b"\x1e\x1d"), b',', None).unwrap();
+        for n_chunks in [3, 8, 10] {
+            let chunks = get_file_chunks(bytes, n_chunks, Some(4), b',', None, eol);
+            assert!((chunks.len() as i32 - n_chunks as i32).abs() <= 1);
+            // Every chunk ends right after a whole separator.
+            for (_, end) in &chunks[..chunks.len() - 1] {
+                assert_eq!(&bytes[end - 2..*end], b"\x1e\x1d");
+            }
+        }
     }
 }
@@ -181,6 +181,90 @@ impl<R: MmapBytesReader> IpcReader<R> {
     }
 }
 
+impl<R: MmapBytesReader> IpcReader<R> {
+    /// Read the file one record batch at a time.
+    ///
+    /// Every record batch becomes a [`DataFrame`] without copying or concatenating. Batches
+    /// with more than `max_batch_size` rows are split into zero-copy slices.
+    pub fn batched(mut self, max_batch_size: usize) -> PolarsResult<BatchedIpcReader<R>> {
+        let metadata = read::read_file_metadata(&mut self.reader)?;
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &metadata.schema)?);
+        }
+
+        let schema = if let Some(projection) = &self.projection {
+            Arc::new(apply_projection(&metadata.schema, projection))
+        } else {
+            metadata.schema.clone()
+        };
+
+        let reader = read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
+        Ok(BatchedIpcReader {
+            reader,
+            schema,
+            row_index: self.row_index,
+            max_batch_size: max_batch_size.max(1),
+            rows_read: 0,
+            remainder: None,
+        })
+    }
+}
+
+/// Iterator over the record batches of an IPC file, see [`IpcReader::batched`].
+pub struct BatchedIpcReader<R: MmapBytesReader> {
+    reader: read::FileReader<R>,
+    schema: ArrowSchemaRef,
+    row_index: Option<RowIndex>,
+    max_batch_size: usize,
+    rows_read: IdxSize,
+    /// The part of the last record batch that didn't fit in `max_batch_size`.
+    remainder: Option<DataFrame>,
+}
+
+impl<R: MmapBytesReader> BatchedIpcReader<R> {
+    fn next_record_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        loop {
+            let Some(batch) = self.reader.next().transpose()? else {
+                return Ok(None);
+            };
+            // Empty batches would only add overhead downstream.
+            if batch.is_empty() {
+                continue;
+            }
+
+            let mut df = DataFrame::try_from((batch, self.schema.fields.as_slice()))?;
+            if let Some(rc) = &self.row_index {
+                df.with_row_index_mut(&rc.name, Some(rc.offset + self.rows_read));
+            }
+            self.rows_read += df.height() as IdxSize;
+            return Ok(Some(df));
+        }
+    }
+}
+
+impl<R: MmapBytesReader> Iterator for BatchedIpcReader<R> {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let df = match self.remainder.take() {
+            Some(df) => df,
+            None => match self.next_record_batch() {
+                Ok(Some(df)) => df,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            },
+        };
+
+        if df.height() > self.max_batch_size {
+            let len = df.height() - self.max_batch_size;
+            self.remainder = Some(df.slice(self.max_batch_size as i64, len));
+            Some(Ok(df.slice(0, self.max_batch_size)))
+        } else {
+            Some(Ok(df))
+        }
+    }
+}
+
 impl<R: MmapBytesReader> ArrowReader for read::FileReader<R>
 where
     R: Read + Seek,
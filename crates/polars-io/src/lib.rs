@@ -2,6 +2,8 @@
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![allow(ambiguous_glob_reexports)]
 
+#[cfg(feature = "adbc")]
+pub mod adbc;
 #[cfg(feature = "avro")]
 pub mod avro;
 pub mod cloud;
@@ -25,6 +27,8 @@ pub mod predicates;
 pub mod prelude;
 mod shared;
 pub mod utils;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
 
 #[cfg(feature = "cloud")]
 pub use cloud::glob as async_glob;
@@ -7,6 +7,8 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Read columns with the `UUID` logical type as strings instead of binary.
+    pub uuid_as_string: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
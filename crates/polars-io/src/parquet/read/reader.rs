@@ -39,6 +39,7 @@ pub struct ParquetReader<R: Read + Seek> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     hive_partition_columns: Option<Vec<Series>>,
     use_statistics: bool,
+    uuid_as_string: bool,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -93,12 +94,24 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         match &self.schema {
             Some(schema) => Ok(schema.clone()),
             None => {
+                let options = schema_inference_options(self.uuid_as_string);
                 let metadata = self.get_metadata()?;
-                Ok(Arc::new(read::infer_schema(metadata)?))
+                Ok(Arc::new(read::schema::infer_schema_with_options(
+                    metadata, &options,
+                )?))
             },
         }
     }
 
+    /// Read columns with the parquet `UUID` logical type as strings in their canonical
+    /// hyphenated form, instead of as binary values.
+    ///
+    /// This only has an effect if the schema is inferred by this reader.
+    pub fn uuid_as_string(mut self, toggle: bool) -> Self {
+        self.uuid_as_string = toggle;
+        self
+    }
+
     /// Use statistics in the parquet to determine if pages
     /// can be skipped from reading.
     pub fn use_statistics(mut self, toggle: bool) -> Self {
@@ -169,6 +182,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             use_statistics: true,
             hive_partition_columns: None,
+            uuid_as_string: false,
         }
     }
 
@@ -220,6 +234,7 @@ pub struct ParquetAsyncReader {
     hive_partition_columns: Option<Vec<Series>>,
     schema: Option<ArrowSchemaRef>,
     parallel: ParallelStrategy,
+    uuid_as_string: bool,
 }
 
 #[cfg(feature = "cloud")]
@@ -241,6 +256,7 @@ impl ParquetAsyncReader {
             hive_partition_columns: None,
             schema,
             parallel: Default::default(),
+            uuid_as_string: false,
         })
     }
 
@@ -248,8 +264,9 @@ impl ParquetAsyncReader {
         Ok(match self.schema.as_ref() {
             Some(schema) => Arc::clone(schema),
             None => {
+                let options = schema_inference_options(self.uuid_as_string);
                 let metadata = self.reader.get_metadata().await?;
-                let arrow_schema = polars_parquet::arrow::read::infer_schema(metadata)?;
+                let arrow_schema = read::schema::infer_schema_with_options(metadata, &options)?;
                 Arc::new(arrow_schema)
             },
         })
@@ -301,6 +318,13 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Read columns with the parquet `UUID` logical type as strings in their canonical
+    /// hyphenated form, instead of as binary values.
+    pub fn uuid_as_string(mut self, toggle: bool) -> Self {
+        self.uuid_as_string = toggle;
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
@@ -369,3 +393,10 @@ impl ParquetAsyncReader {
         Ok(df)
     }
 }
+
+fn schema_inference_options(uuid_as_string: bool) -> Option<read::schema::SchemaInferenceOptions> {
+    Some(read::schema::SchemaInferenceOptions {
+        uuid_as_string,
+        ..Default::default()
+    })
+}
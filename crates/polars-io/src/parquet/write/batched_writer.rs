@@ -8,9 +8,9 @@ use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    array_to_columns, compress, CompressedPage, Compressor, DynIter, DynStreamingIterator,
-    Encoding, FallibleStreamingIterator, FileWriter, ParquetType, RowGroupIter, SchemaDescriptor,
-    WriteOptions,
+    array_to_columns, compress, row_group_to_bloom_filters, CompressedPage, Compressor, DynIter,
+    DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, ParquetType,
+    RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 use rayon::prelude::*;
 
@@ -22,17 +22,28 @@ pub struct BatchedWriter<W: Write> {
     pub(super) encodings: Vec<Vec<Encoding>>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
+    /// The false positive probability of the bloom filter of every column, if it gets one.
+    pub(super) bloom_filter_fpp: Vec<Option<f64>>,
+}
+
+/// A row group that is encoded and compressed, together with the bloom filters of its column
+/// chunks.
+pub struct EncodedRowGroup {
+    columns: RowGroupIter<'static, PolarsError>,
+    bloom_filters: Vec<Option<Vec<u8>>>,
 }
 
 impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIter<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(true);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
+                let bloom_filters =
+                    row_group_to_bloom_filters(&batch, &self.encodings, &self.bloom_filter_fpp);
                 let row_group = create_eager_serializer(
                     batch,
                     self.parquet_schema.fields(),
@@ -40,7 +51,10 @@ impl<W: Write> BatchedWriter<W> {
                     self.options,
                 );
 
-                Some(row_group)
+                Some(row_group.map(|columns| EncodedRowGroup {
+                    columns,
+                    bloom_filters,
+                }))
             },
         })
     }
@@ -54,13 +68,15 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.bloom_filter_fpp,
             self.options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let group = group?;
+            writer.write_with_bloom_filters(group.columns, group.bloom_filters)?;
         }
         Ok(())
     }
@@ -69,14 +85,11 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIter<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
         for group in rgs {
-            writer.write(group)?;
+            writer.write_with_bloom_filters(group.columns, group.bloom_filters)?;
         }
         Ok(())
     }
@@ -94,17 +107,22 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    bloom_filter_fpp: &'a [Option<f64>],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIter<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(true);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
+            let bloom_filters = row_group_to_bloom_filters(&batch, encodings, bloom_filter_fpp);
             let row_group =
                 create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
 
-            Some(row_group)
+            Some(row_group.map(|columns| EncodedRowGroup {
+                columns,
+                bloom_filters,
+            }))
         },
     })
 }
@@ -1,3 +1,5 @@
+use std::hash::{Hash, Hasher};
+
 use polars_error::PolarsResult;
 use polars_parquet::write::{
     BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
@@ -6,7 +8,7 @@ use polars_parquet::write::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -19,6 +21,40 @@ pub struct ParquetWriteOptions {
     pub data_pagesize_limit: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Write bloom filters for the row groups of these columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+/// The columns to write split block bloom filters for, one per row group and column.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BloomFilterOptions {
+    /// Names of the columns, they must have an integer, float, date, string or binary type.
+    pub columns: Vec<String>,
+    /// The false positive probability the filters are sized for, between 0 and 1.
+    pub fpp: f64,
+}
+
+impl BloomFilterOptions {
+    pub fn new(columns: Vec<String>, fpp: f64) -> Self {
+        Self { columns, fpp }
+    }
+}
+
+impl PartialEq for BloomFilterOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.columns == other.columns && self.fpp.to_bits() == other.fpp.to_bits()
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl Hash for BloomFilterOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.columns.hash(state);
+        self.fpp.to_bits().hash(state);
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
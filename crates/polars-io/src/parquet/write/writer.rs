@@ -8,7 +8,7 @@ use polars_parquet::write::{
 };
 
 use super::batched_writer::BatchedWriter;
-use super::options::ParquetCompression;
+use super::options::{BloomFilterOptions, ParquetCompression};
 use crate::prelude::chunk_df_for_writing;
 
 /// Write a DataFrame to Parquet format.
@@ -25,6 +25,8 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Write bloom filters for these columns
+    bloom_filter: Option<BloomFilterOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -43,6 +45,7 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            bloom_filter: None,
         }
     }
 
@@ -74,6 +77,13 @@ where
         self
     }
 
+    /// Write a split block bloom filter for every row group of the given columns. Readers can
+    /// use them to skip row groups that don't contain a value.
+    pub fn with_bloom_filter(mut self, bloom_filter: Option<BloomFilterOptions>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -81,6 +91,7 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let bloom_filter_fpp = get_bloom_filter_fpp(schema, self.bloom_filter.as_ref())?;
         let fields = schema.to_arrow(true).fields;
         let schema = ArrowSchema::from(fields);
 
@@ -95,6 +106,7 @@ where
             encodings,
             options,
             parallel: self.parallel,
+            bloom_filter_fpp,
         })
     }
 
@@ -116,6 +128,35 @@ where
     }
 }
 
+/// The false positive probability of the bloom filter of every column, `None` if the column
+/// doesn't get one.
+fn get_bloom_filter_fpp(
+    schema: &Schema,
+    bloom_filter: Option<&BloomFilterOptions>,
+) -> PolarsResult<Vec<Option<f64>>> {
+    let mut bloom_filter_fpp = vec![None; schema.len()];
+    let Some(bloom_filter) = bloom_filter else {
+        return Ok(bloom_filter_fpp);
+    };
+    polars_ensure!(
+        bloom_filter.fpp > 0.0 && bloom_filter.fpp < 1.0,
+        InvalidOperation: "the false positive probability of a bloom filter must be between 0 and 1, got {}",
+        bloom_filter.fpp
+    );
+    for name in &bloom_filter.columns {
+        let (idx, _, dtype) = schema.try_get_full(name)?;
+        polars_ensure!(
+            dtype.is_integer()
+                || dtype.is_float()
+                || matches!(dtype, DataType::Date | DataType::String | DataType::Binary),
+            InvalidOperation: "bloom filters are not supported for column '{}' of type {}",
+            name, dtype
+        );
+        bloom_filter_fpp[idx] = Some(bloom_filter.fpp);
+    }
+    Ok(bloom_filter_fpp)
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
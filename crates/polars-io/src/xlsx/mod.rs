@@ -0,0 +1,7 @@
+//! Write [`DataFrame`](polars_core::frame::DataFrame)s to Excel `.xlsx` files.
+//!
+//! Only a single worksheet is written. Rows are streamed into a deflate-compressed zip entry,
+//! so the sheet XML is never held in memory as a whole.
+mod write;
+
+pub use write::*;
@@ -0,0 +1,694 @@
+use std::fmt::Write as _;
+use std::io::{BufWriter, Write};
+
+use ::zip::write::{SimpleFileOptions, StreamWriter};
+use ::zip::{CompressionMethod, ZipWriter};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_core::POOL;
+
+use crate::shared::SerWriter;
+
+/// Maximum number of rows in an Excel worksheet.
+const MAX_ROWS: usize = 1_048_576;
+/// Maximum number of columns in an Excel worksheet.
+const MAX_COLUMNS: usize = 16_384;
+/// Days between the Excel epoch (1899-12-30) and the unix epoch.
+const EXCEL_UNIX_EPOCH_DAYS: f64 = 25_569.0;
+const NANOSECONDS_IN_DAY: f64 = 86_400_000_000_000.0;
+/// Upper bound for automatically sized column widths, in characters.
+const MAX_COLUMN_WIDTH: usize = 100;
+
+const DEFAULT_DATE_FORMAT: &str = "yyyy-mm-dd";
+const DEFAULT_DATETIME_FORMAT: &str = "yyyy-mm-dd hh:mm:ss";
+const DEFAULT_TIME_FORMAT: &str = "hh:mm:ss";
+
+/// Style index of the bold header cells, see `styles_xml`.
+const HEADER_STYLE: usize = 1;
+/// Style index of the first custom number format.
+const FIRST_NUMBER_FORMAT_STYLE: usize = 2;
+/// Excel reserves the number format ids below 164 for builtin formats.
+const FIRST_CUSTOM_NUMBER_FORMAT_ID: usize = 164;
+
+const SPREADSHEET_NS: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+/// Write a [`DataFrame`] to a single-sheet Excel `.xlsx` file.
+///
+/// Numeric and boolean columns become numeric and boolean cells. Dates, datetimes and times
+/// are written as Excel serial numbers with a date format; datetimes with a time zone are
+/// written in UTC. Decimals are written as floats and all other data types as strings.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::xlsx::XlsxWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("file.xlsx").expect("could not create file");
+///
+///     XlsxWriter::new(&mut file)
+///         .with_bold_header(true)
+///         .with_number_format("price", "#,##0.00")
+///         .with_autofit(Some(100))
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct XlsxWriter<W> {
+    writer: W,
+    sheet_name: String,
+    include_header: bool,
+    bold_header: bool,
+    number_formats: Vec<(String, String)>,
+    autofit: Option<usize>,
+}
+
+impl<W> XlsxWriter<W>
+where
+    W: Write,
+{
+    /// Set the name of the worksheet. Defaults to `"Sheet1"`.
+    pub fn with_sheet_name(mut self, sheet_name: String) -> Self {
+        self.sheet_name = sheet_name;
+        self
+    }
+
+    /// Write the column names as the first row. Defaults to `true`.
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+
+    /// Write the header in bold. Defaults to `false`.
+    pub fn with_bold_header(mut self, bold_header: bool) -> Self {
+        self.bold_header = bold_header;
+        self
+    }
+
+    /// Set the Excel number format of a column, e.g. `"0.00%"` or `"dd/mm/yyyy"`.
+    ///
+    /// Dates, datetimes and times get an ISO 8601 format by default.
+    pub fn with_number_format(mut self, column: &str, format: &str) -> Self {
+        self.number_formats
+            .retain(|(existing, _)| existing.as_str() != column);
+        self.number_formats
+            .push((column.to_string(), format.to_string()));
+        self
+    }
+
+    /// Size the columns to fit the header and the first `sample_size` rows. Defaults to
+    /// `None`, which leaves the default Excel column width.
+    pub fn with_autofit(mut self, sample_size: Option<usize>) -> Self {
+        self.autofit = sample_size;
+        self
+    }
+}
+
+impl<W> SerWriter<W> for XlsxWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            sheet_name: "Sheet1".to_string(),
+            include_header: true,
+            bold_header: false,
+            number_formats: vec![],
+            autofit: None,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        validate_sheet_name(&self.sheet_name)?;
+        let n_header_rows = self.include_header as usize;
+        polars_ensure!(
+            df.height() + n_header_rows <= MAX_ROWS,
+            ComputeError: "cannot write {} rows to an xlsx sheet, the maximum is {}",
+            df.height() + n_header_rows, MAX_ROWS
+        );
+        polars_ensure!(
+            df.width() <= MAX_COLUMNS,
+            ComputeError: "cannot write {} columns to an xlsx sheet, the maximum is {}",
+            df.width(), MAX_COLUMNS
+        );
+        for (column, _) in &self.number_formats {
+            df.try_get_column_index(column)?;
+        }
+        df.as_single_chunk_par();
+
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| self.prepare_column(s))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut number_formats: Vec<&str> = vec![];
+        let styles = columns
+            .iter()
+            .map(|column| {
+                column.number_format.as_deref().map(|format| {
+                    let idx = number_formats
+                        .iter()
+                        .position(|existing| *existing == format)
+                        .unwrap_or_else(|| {
+                            number_formats.push(format);
+                            number_formats.len() - 1
+                        });
+                    FIRST_NUMBER_FORMAT_STYLE + idx
+                })
+            })
+            .collect::<Vec<_>>();
+        let widths = self
+            .autofit
+            .map(|sample_size| column_widths(&columns, sample_size, self.include_header));
+
+        let mut zip = ZipWriter::new_stream(&mut self.writer);
+        write_entry(&mut zip, "[Content_Types].xml", |w| {
+            w.write_all(CONTENT_TYPES_XML.as_bytes())?;
+            Ok(())
+        })?;
+        write_entry(&mut zip, "_rels/.rels", |w| {
+            w.write_all(ROOT_RELS_XML.as_bytes())?;
+            Ok(())
+        })?;
+        write_entry(&mut zip, "xl/workbook.xml", |w| {
+            write!(
+                w,
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="{SPREADSHEET_NS}" xmlns:r="{RELATIONSHIPS_NS}"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+                escape_xml(&self.sheet_name)
+            )?;
+            Ok(())
+        })?;
+        write_entry(&mut zip, "xl/_rels/workbook.xml.rels", |w| {
+            w.write_all(WORKBOOK_RELS_XML.as_bytes())?;
+            Ok(())
+        })?;
+        write_entry(&mut zip, "xl/styles.xml", |w| {
+            w.write_all(styles_xml(&number_formats).as_bytes())?;
+            Ok(())
+        })?;
+        write_entry(&mut zip, "xl/worksheets/sheet1.xml", |w| {
+            write_sheet(
+                w,
+                &columns,
+                &styles,
+                widths.as_deref(),
+                self.include_header,
+                self.bold_header,
+            )
+        })?;
+        zip.finish().map_err(to_compute_err)?.flush()?;
+        Ok(())
+    }
+}
+
+/// Add a deflated entry named `name` to the archive, with the data written by `f`.
+fn write_entry<W, F>(zip: &mut ZipWriter<StreamWriter<W>>, name: &str, f: F) -> PolarsResult<()>
+where
+    W: Write,
+    F: FnOnce(&mut dyn Write) -> PolarsResult<()>,
+{
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(name, options).map_err(to_compute_err)?;
+    let mut buffered = BufWriter::new(zip);
+    f(&mut buffered)?;
+    buffered.flush()?;
+    Ok(())
+}
+
+impl<W> XlsxWriter<W> {
+    fn prepare_column(&self, s: &Series) -> PolarsResult<XlsxColumn> {
+        let number_format = self
+            .number_formats
+            .iter()
+            .find(|(column, _)| column.as_str() == s.name())
+            .map(|(_, format)| format.clone());
+        let default_format = |format: &str| number_format.clone().or(Some(format.to_string()));
+
+        use DataType::*;
+        let (series, number_format) = match s.dtype() {
+            Boolean | UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32
+            | Float64 | String => (s.clone(), number_format),
+            #[cfg(feature = "dtype-date")]
+            Date => (s.clone(), default_format(DEFAULT_DATE_FORMAT)),
+            #[cfg(feature = "dtype-datetime")]
+            Datetime(_, _) => (s.clone(), default_format(DEFAULT_DATETIME_FORMAT)),
+            #[cfg(feature = "dtype-time")]
+            Time => (s.clone(), default_format(DEFAULT_TIME_FORMAT)),
+            #[cfg(feature = "dtype-decimal")]
+            Decimal(_, _) => (s.cast(&Float64)?, number_format),
+            _ => (s.cast(&String)?, number_format),
+        };
+        Ok(XlsxColumn {
+            // Cells are read with `Series::iter`, which needs a single chunk.
+            series: series.rechunk(),
+            number_format,
+        })
+    }
+}
+
+struct XlsxColumn {
+    series: Series,
+    number_format: Option<String>,
+}
+
+/// The content of a single cell.
+enum Cell<'a> {
+    Empty,
+    Number(f64),
+    Integer(AnyValue<'a>),
+    Boolean(bool),
+    String(&'a str),
+}
+
+fn to_cell(value: AnyValue) -> Cell {
+    match value {
+        AnyValue::Null => Cell::Empty,
+        AnyValue::Boolean(v) => Cell::Boolean(v),
+        AnyValue::String(v) => Cell::String(v),
+        AnyValue::Float32(v) if v.is_finite() => Cell::Number(v as f64),
+        AnyValue::Float64(v) if v.is_finite() => Cell::Number(v),
+        // Excel has no representation for NaN and infinity.
+        AnyValue::Float32(_) | AnyValue::Float64(_) => Cell::Empty,
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => Cell::Number(v as f64 + EXCEL_UNIX_EPOCH_DAYS),
+        #[cfg(feature = "dtype-datetime")]
+        AnyValue::Datetime(v, tu, _) => {
+            let units_per_day = match tu {
+                TimeUnit::Nanoseconds => NANOSECONDS_IN_DAY,
+                TimeUnit::Microseconds => NANOSECONDS_IN_DAY / 1e3,
+                TimeUnit::Milliseconds => NANOSECONDS_IN_DAY / 1e6,
+            };
+            Cell::Number(v as f64 / units_per_day + EXCEL_UNIX_EPOCH_DAYS)
+        },
+        #[cfg(feature = "dtype-time")]
+        AnyValue::Time(v) => Cell::Number(v as f64 / NANOSECONDS_IN_DAY),
+        v => Cell::Integer(v),
+    }
+}
+
+/// Write the worksheet XML, row by row.
+fn write_sheet(
+    w: &mut dyn Write,
+    columns: &[XlsxColumn],
+    styles: &[Option<usize>],
+    widths: Option<&[usize]>,
+    include_header: bool,
+    bold_header: bool,
+) -> PolarsResult<()> {
+    write!(
+        w,
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="{SPREADSHEET_NS}" xmlns:r="{RELATIONSHIPS_NS}">"#
+    )?;
+    if let Some(widths) = widths {
+        w.write_all(b"<cols>")?;
+        for (i, width) in widths.iter().enumerate() {
+            write!(
+                w,
+                r#"<col min="{0}" max="{0}" width="{width}" customWidth="1"/>"#,
+                i + 1
+            )?;
+        }
+        w.write_all(b"</cols>")?;
+    }
+    w.write_all(b"<sheetData>")?;
+
+    // Used to amortize the allocations of the rows and cell references.
+    let mut buf = String::new();
+    let column_names = (0..columns.len()).map(column_name).collect::<Vec<_>>();
+    let mut row_number = 0;
+
+    if include_header {
+        row_number += 1;
+        write!(buf, r#"<row r="{row_number}">"#).unwrap();
+        for (column, name) in columns.iter().zip(&column_names) {
+            write!(buf, r#"<c r="{name}{row_number}" t="inlineStr""#).unwrap();
+            if bold_header {
+                write!(buf, r#" s="{HEADER_STYLE}""#).unwrap();
+            }
+            write!(
+                buf,
+                r#"><is><t xml:space="preserve">{}</t></is></c>"#,
+                escape_xml_text(column.series.name())
+            )
+            .unwrap();
+        }
+        buf.push_str("</row>");
+        w.write_all(buf.as_bytes())?;
+    }
+
+    let height = columns.first().map_or(0, |column| column.series.len());
+    let mut values = columns
+        .iter()
+        .map(|column| column.series.iter())
+        .collect::<Vec<_>>();
+    for _ in 0..height {
+        buf.clear();
+        row_number += 1;
+        write!(buf, r#"<row r="{row_number}">"#).unwrap();
+        for ((values, name), style) in values.iter_mut().zip(&column_names).zip(styles) {
+            let cell = to_cell(values.next().unwrap());
+            if matches!(cell, Cell::Empty) {
+                continue;
+            }
+            write!(buf, r#"<c r="{name}{row_number}""#).unwrap();
+            if let Some(style) = style {
+                write!(buf, r#" s="{style}""#).unwrap();
+            }
+            match cell {
+                Cell::Empty => unreachable!(),
+                Cell::Number(v) => write!(buf, "><v>{v}</v></c>"),
+                Cell::Integer(v) => write!(buf, "><v>{v}</v></c>"),
+                Cell::Boolean(v) => write!(buf, r#" t="b"><v>{}</v></c>"#, v as u8),
+                Cell::String(v) => write!(
+                    buf,
+                    r#" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                    escape_xml_text(v)
+                ),
+            }
+            .unwrap();
+        }
+        buf.push_str("</row>");
+        w.write_all(buf.as_bytes())?;
+    }
+
+    w.write_all(b"</sheetData></worksheet>")?;
+    Ok(())
+}
+
+/// Estimate the width of every column from its header and the first `sample_size` values.
+fn column_widths(columns: &[XlsxColumn], sample_size: usize, include_header: bool) -> Vec<usize> {
+    POOL.install(|| {
+        use rayon::prelude::*;
+        columns
+            .par_iter()
+            .map(|column| {
+                let s = &column.series;
+                let header_width = if include_header {
+                    s.name().chars().count()
+                } else {
+                    0
+                };
+                let values_width = s
+                    .iter()
+                    .take(sample_size)
+                    .map(|value| match to_cell(value) {
+                        Cell::Empty => 0,
+                        Cell::String(v) => v.chars().count(),
+                        Cell::Boolean(v) => {
+                            if v {
+                                4
+                            } else {
+                                5
+                            }
+                        },
+                        // Formatted numbers are roughly as wide as their format.
+                        Cell::Number(_) if column.number_format.is_some() => {
+                            column.number_format.as_ref().unwrap().chars().count()
+                        },
+                        Cell::Number(v) => v.to_string().len(),
+                        Cell::Integer(v) => v.to_string().len(),
+                    })
+                    .max()
+                    .unwrap_or(0);
+                // Leave some room for the cell padding.
+                (header_width.max(values_width) + 2).min(MAX_COLUMN_WIDTH)
+            })
+            .collect()
+    })
+}
+
+/// The name of the column at `idx`, e.g. `A`, `Z`, `AA`.
+fn column_name(mut idx: usize) -> String {
+    let mut name = vec![];
+    loop {
+        name.push(b'A' + (idx % 26) as u8);
+        if idx < 26 {
+            break;
+        }
+        idx = idx / 26 - 1;
+    }
+    name.reverse();
+    // SAFETY: only ascii letters were pushed.
+    unsafe { String::from_utf8_unchecked(name) }
+}
+
+fn validate_sheet_name(name: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        !name.is_empty()
+            && name.chars().count() <= 31
+            && !name.contains(['[', ']', ':', '*', '?', '/', '\\']),
+        ComputeError: "invalid xlsx sheet name '{}': sheet names must be 1 to 31 characters \
+        long and cannot contain any of []:*?/\\",
+        name
+    );
+    Ok(())
+}
+
+/// Escape `value` for use in XML text and attribute values.
+///
+/// Characters that XML 1.0 can't represent at all, such as most control characters, are
+/// dropped, as Excel refuses to open a file that contains them.
+fn escape_xml(value: &str) -> std::borrow::Cow<'_, str> {
+    if !value.contains(|c| matches!(c, '&' | '<' | '>' | '"') || !is_xml_char(c)) {
+        return value.into();
+    }
+    let mut out = String::with_capacity(value.len() + 8);
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c if !is_xml_char(c) => {},
+            c => out.push(c),
+        }
+    }
+    out.into()
+}
+
+/// Escape `value` for use as the text of a cell.
+///
+/// Unlike [`escape_xml`], characters that XML 1.0 can't represent are kept by writing them as
+/// `_xHHHH_`, which Excel decodes when it reads the cell. A literal `_xHHHH_` in `value` gets
+/// its underscore escaped as `_x005F_` so it is read back as is.
+fn escape_xml_text(value: &str) -> std::borrow::Cow<'_, str> {
+    let is_escape_at = |i: usize| {
+        let b = &value.as_bytes()[i..];
+        b.len() >= 7 && b[1] == b'x' && b[2..6].iter().all(u8::is_ascii_hexdigit) && b[6] == b'_'
+    };
+    if !value.char_indices().any(|(i, c)| {
+        matches!(c, '&' | '<' | '>' | '"') || !is_xml_char(c) || (c == '_' && is_escape_at(i))
+    }) {
+        return value.into();
+    }
+    let mut out = String::with_capacity(value.len() + 8);
+    for (i, c) in value.char_indices() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '_' if is_escape_at(i) => out.push_str("_x005F_"),
+            c if !is_xml_char(c) => write!(out, "_x{:04X}_", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.into()
+}
+
+/// Whether `c` matches the `Char` production of XML 1.0.
+fn is_xml_char(c: char) -> bool {
+    matches!(
+        c,
+        '\t' | '\n' | '\r' | '\u{20}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..
+    )
+}
+
+fn styles_xml(number_formats: &[&str]) -> String {
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="{SPREADSHEET_NS}">"#
+    );
+    if !number_formats.is_empty() {
+        write!(xml, r#"<numFmts count="{}">"#, number_formats.len()).unwrap();
+        for (i, format) in number_formats.iter().enumerate() {
+            write!(
+                xml,
+                r#"<numFmt numFmtId="{}" formatCode="{}"/>"#,
+                FIRST_CUSTOM_NUMBER_FORMAT_ID + i,
+                escape_xml(format)
+            )
+            .unwrap();
+        }
+        xml.push_str("</numFmts>");
+    }
+    xml.push_str(concat!(
+        r#"<fonts count="2">"#,
+        r#"<font><sz val="11"/><name val="Calibri"/></font>"#,
+        r#"<font><b/><sz val="11"/><name val="Calibri"/></font>"#,
+        r#"</fonts>"#,
+        r#"<fills count="2">"#,
+        r#"<fill><patternFill patternType="none"/></fill>"#,
+        r#"<fill><patternFill patternType="gray125"/></fill>"#,
+        r#"</fills>"#,
+        r#"<borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders>"#,
+        r#"<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>"#,
+    ));
+    write!(
+        xml,
+        r#"<cellXfs count="{}">"#,
+        FIRST_NUMBER_FORMAT_STYLE + number_formats.len()
+    )
+    .unwrap();
+    xml.push_str(r#"<xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>"#);
+    xml.push_str(r#"<xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/>"#);
+    for i in 0..number_formats.len() {
+        write!(
+            xml,
+            r#"<xf numFmtId="{}" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+            FIRST_CUSTOM_NUMBER_FORMAT_ID + i
+        )
+        .unwrap();
+    }
+    xml.push_str(concat!(
+        r#"</cellXfs>"#,
+        r#"<cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles>"#,
+        r#"</styleSheet>"#,
+    ));
+    xml
+}
+
+const CONTENT_TYPES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/></Types>"#;
+
+const ROOT_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, Read};
+
+    use ::zip::ZipArchive;
+    use polars_core::df;
+
+    use super::*;
+
+    /// Extract the names and contents of the entries of a zip archive.
+    fn unzip(buf: &[u8]) -> Vec<(String, String)> {
+        let mut archive = ZipArchive::new(Cursor::new(buf)).unwrap();
+        (0..archive.len())
+            .map(|i| {
+                let mut entry = archive.by_index(i).unwrap();
+                let mut content = String::new();
+                entry.read_to_string(&mut content).unwrap();
+                (entry.name().to_string(), content)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_column_name() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(701), "ZZ");
+        assert_eq!(column_name(702), "AAA");
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_write_xlsx() -> PolarsResult<()> {
+        let mut df = df![
+            "int" => [Some(1i64), None],
+            "float" => [1.5, f64::NAN],
+            "bool" => [true, false],
+            "str" => ["a <b>", "  c & d"],
+        ]?;
+        df.with_column(Series::new("date", [0i32, 19_000]).cast(&DataType::Date)?)?;
+
+        let mut buf = vec![];
+        XlsxWriter::new(&mut buf)
+            .with_sheet_name("data".to_string())
+            .with_bold_header(true)
+            .with_number_format("float", "0.00")
+            .with_autofit(Some(10))
+            .finish(&mut df)?;
+
+        let entries = unzip(&buf);
+        let names = entries
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "[Content_Types].xml",
+                "_rels/.rels",
+                "xl/workbook.xml",
+                "xl/_rels/workbook.xml.rels",
+                "xl/styles.xml",
+                "xl/worksheets/sheet1.xml",
+            ]
+        );
+        assert!(entries[2].1.contains(r#"<sheet name="data""#));
+
+        let styles = &entries[4].1;
+        assert!(styles.contains(r#"<numFmt numFmtId="164" formatCode="0.00"/>"#));
+        assert!(styles.contains(r#"<numFmt numFmtId="165" formatCode="yyyy-mm-dd"/>"#));
+
+        let sheet = &entries[5].1;
+        assert!(sheet.contains(r#"<col min="4" max="4" width="9" customWidth="1"/>"#));
+        assert!(sheet.contains(
+            r#"<c r="A1" t="inlineStr" s="1"><is><t xml:space="preserve">int</t></is></c>"#
+        ));
+        assert!(sheet.contains(r#"<c r="A2"><v>1</v></c>"#));
+        assert!(!sheet.contains(r#"r="A3""#));
+        assert!(sheet.contains(r#"<c r="B2" s="2"><v>1.5</v></c>"#));
+        assert!(!sheet.contains(r#"r="B3""#));
+        assert!(sheet.contains(r#"<c r="C3" t="b"><v>0</v></c>"#));
+        assert!(sheet.contains(
+            r#"<c r="D2" t="inlineStr"><is><t xml:space="preserve">a &lt;b&gt;</t></is></c>"#
+        ));
+        assert!(sheet.contains(r#"<c r="E2" s="3"><v>25569</v></c>"#));
+        assert!(sheet.contains(r#"<c r="E3" s="3"><v>44569</v></c>"#));
+        Ok(())
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a & b"), "a &amp; b");
+        assert_eq!(escape_xml("tab\tnew\nline"), "tab\tnew\nline");
+        assert_eq!(escape_xml("a\u{0}b\u{1b}c\u{FFFE}<"), "abc&lt;");
+    }
+
+    #[test]
+    fn test_escape_xml_text() {
+        assert_eq!(escape_xml_text("a & b"), "a &amp; b");
+        assert_eq!(escape_xml_text("tab\tnew\nline"), "tab\tnew\nline");
+        assert_eq!(
+            escape_xml_text("a\u{0}b\u{1b}c\u{FFFE}<"),
+            "a_x0000_b_x001B_c_xFFFE_&lt;"
+        );
+        assert_eq!(escape_xml_text("_x0041_ _x41_ _"), "_x005F_x0041_ _x41_ _");
+    }
+
+    #[test]
+    fn test_invalid_sheet_name() {
+        let mut df = df!["a" => [1]].unwrap();
+        let result = XlsxWriter::new(vec![])
+            .with_sheet_name("a/b".to_string())
+            .finish(&mut df);
+        assert!(result.is_err());
+    }
+}
@@ -9,11 +9,14 @@ use arrow::offset::Offset;
 use arrow::temporal_conversions::parse_offset_tz;
 use arrow::temporal_conversions::{
     date32_to_date, duration_ms_to_duration, duration_ns_to_duration, duration_s_to_duration,
-    duration_us_to_duration, parse_offset, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
-    timestamp_s_to_datetime, timestamp_to_datetime, timestamp_us_to_datetime,
+    duration_us_to_duration, parse_offset, time64ns_to_time, time64us_to_time,
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_s_to_datetime,
+    timestamp_to_datetime, timestamp_us_to_datetime,
 };
 use arrow::types::NativeType;
-use chrono::{Duration, NaiveDate, NaiveDateTime};
+use base64::engine::general_purpose;
+use base64::Engine as _;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use streaming_iterator::StreamingIterator;
 
 use super::utf8;
@@ -158,6 +161,30 @@ fn utf8view_serializer<'a>(
     materialize_serializer(f, array.iter(), offset, take)
 }
 
+/// Binary values are written as base64 encoded strings.
+fn binview_serializer<'a>(
+    array: &'a BinaryViewArray,
+    offset: usize,
+    take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+    let f = |x: Option<&[u8]>, buf: &mut Vec<u8>| {
+        if let Some(x) = x {
+            // Base64 never needs escaping, so it is encoded straight into the buffer.
+            let start = buf.len() + 1;
+            let len = base64::encoded_len(x.len(), true).unwrap();
+            buf.push(b'"');
+            buf.resize(start + len, 0);
+            general_purpose::STANDARD
+                .encode_slice(x, &mut buf[start..])
+                .unwrap();
+            buf.push(b'"');
+        } else {
+            buf.extend_from_slice(b"null")
+        }
+    };
+    materialize_serializer(f, array.iter(), offset, take)
+}
+
 fn struct_serializer<'a>(
     array: &'a StructArray,
     offset: usize,
@@ -293,6 +320,27 @@ where
     materialize_serializer(f, array.iter(), offset, take)
 }
 
+fn time_serializer<'a, F>(
+    array: &'a PrimitiveArray<i64>,
+    convert: F,
+    offset: usize,
+    take: usize,
+) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>
+where
+    F: Fn(i64) -> NaiveTime + 'static + Send + Sync,
+{
+    let f = move |x: Option<&i64>, buf: &mut Vec<u8>| {
+        if let Some(x) = x {
+            let nt = convert(*x);
+            write!(buf, "\"{nt}\"").unwrap();
+        } else {
+            buf.extend_from_slice(b"null")
+        }
+    };
+
+    materialize_serializer(f, array.iter(), offset, take)
+}
+
 fn duration_serializer<'a, T, F>(
     array: &'a PrimitiveArray<T>,
     convert: F,
@@ -425,6 +473,9 @@ pub(crate) fn new_serializer<'a>(
         ArrowDataType::Utf8View => {
             utf8view_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
+        ArrowDataType::BinaryView => {
+            binview_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
+        },
         ArrowDataType::Struct(_) => {
             struct_serializer(array.as_any().downcast_ref().unwrap(), offset, take)
         },
@@ -474,6 +525,20 @@ pub(crate) fn new_serializer<'a>(
             offset,
             take,
         ),
+        ArrowDataType::Time64(tu) => {
+            let convert = match tu {
+                TimeUnit::Nanosecond => time64ns_to_time,
+                TimeUnit::Microsecond => time64us_to_time,
+                // Not produced by polars
+                _ => unreachable!(),
+            };
+            time_serializer(
+                array.as_any().downcast_ref().unwrap(),
+                convert,
+                offset,
+                take,
+            )
+        },
         ArrowDataType::Duration(tu) => {
             let convert = match tu {
                 TimeUnit::Nanosecond => duration_ns_to_duration,
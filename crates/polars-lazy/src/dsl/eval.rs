@@ -1,4 +1,5 @@
 use polars_core::prelude::*;
+use polars_plan::constants::ELEMENT_INDEX_NAME;
 use rayon::prelude::*;
 
 use super::*;
@@ -14,7 +15,11 @@ pub(crate) fn eval_field_to_dtype(f: &Field, expr: &Expr, list: bool) -> Field {
         .cloned()
         .unwrap_or_else(|| f.data_type().clone());
 
-    let df = Series::new_empty("", &dtype).into_frame();
+    let mut df = Series::new_empty("", &dtype).into_frame();
+    if list {
+        df.with_column(Series::new_empty(ELEMENT_INDEX_NAME, &IDX_DTYPE))
+            .unwrap();
+    }
 
     #[cfg(feature = "python")]
     let out = {
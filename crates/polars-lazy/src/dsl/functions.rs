@@ -135,12 +135,21 @@ pub fn concat_lf_diagonal<L: AsRef<[LazyFrame]>>(
     let mut total_schema = Vec::with_capacity(upper_bound_width);
 
     for sch in schemas.iter() {
-        sch.iter().for_each(|(name, dtype)| {
-            if !column_names.contains(name) {
-                column_names.push(name.clone());
-                total_schema.push((name.clone(), dtype.clone()));
+        for (name, dtype) in sch.iter() {
+            match column_names.iter().position(|n| n == name) {
+                None => {
+                    column_names.push(name.clone());
+                    total_schema.push((name.clone(), dtype.clone()));
+                },
+                // Fill missing columns with the dtype the union casts to, so they
+                // don't need another cast later on.
+                Some(idx) if args.to_supertypes => {
+                    let current = &mut total_schema[idx].1;
+                    *current = polars_core::utils::try_get_column_supertype(name, current, dtype)?;
+                },
+                Some(_) => {},
             }
-        });
+        }
     }
     let lfs_with_all_columns = lfs
         .iter()
@@ -220,6 +229,8 @@ pub fn concat_lf_horizontal<L: AsRef<[LazyFrame]>>(
 pub struct UnionArgs {
     pub parallel: bool,
     pub rechunk: bool,
+    /// Cast the columns to the supertype of their data types in all inputs, instead of
+    /// requiring them to match. Structs are merged by field name, recursively.
     pub to_supertypes: bool,
 }
 
@@ -3,7 +3,7 @@ use std::sync::Mutex;
 use arrow::array::ValueSize;
 use arrow::legacy::utils::CustomIterTools;
 use polars_core::prelude::*;
-use polars_plan::constants::MAP_LIST_NAME;
+use polars_plan::constants::{ELEMENT_INDEX_NAME, MAP_LIST_NAME};
 use polars_plan::dsl::*;
 use rayon::prelude::*;
 
@@ -43,14 +43,38 @@ fn offsets_to_groups(offsets: &[i64]) -> Option<GroupsProxy> {
     })
 }
 
+/// The position of every value within its sublist, for the values of a list array with the
+/// given `offsets`. Values that are not part of any sublist get index 0.
+fn offsets_to_element_index(offsets: &[i64], len: usize) -> Series {
+    let mut idx = vec![0 as IdxSize; len];
+    for window in offsets.windows(2) {
+        let (start, end) = (window[0] as usize, window[1] as usize);
+        for (i, v) in idx[start..end].iter_mut().enumerate() {
+            *v = i as IdxSize;
+        }
+    }
+    IdxCa::from_vec(ELEMENT_INDEX_NAME, idx).into_series()
+}
+
+fn sublist_element_index(len: usize) -> Series {
+    IdxCa::from_vec(ELEMENT_INDEX_NAME, (0..len as IdxSize).collect()).into_series()
+}
+
 fn run_per_sublist(
     s: Series,
     lst: &ListChunked,
     expr: &Expr,
     parallel: bool,
     output_field: Field,
+    element_index: bool,
 ) -> PolarsResult<Option<Series>> {
-    let phys_expr = prepare_expression_for_context("", expr, &lst.inner_dtype(), Context::Default)?;
+    let phys_expr = prepare_expression_for_context(
+        "",
+        expr,
+        &lst.inner_dtype(),
+        Context::Default,
+        element_index,
+    )?;
 
     let state = ExecutionState::new();
 
@@ -61,7 +85,12 @@ fn run_per_sublist(
             .par_iter()
             .map(|opt_s| {
                 opt_s.and_then(|s| {
-                    let df = s.into_frame();
+                    let mut df = s.into_frame();
+                    if element_index {
+                        let idx = sublist_element_index(df.height());
+                        // SAFETY: the index has the length of the sublist and a reserved name.
+                        unsafe { df.get_columns_mut().push(idx) };
+                    }
                     let out = phys_expr.evaluate(&df, &state);
                     match out {
                         Ok(s) => Some(s),
@@ -81,7 +110,13 @@ fn run_per_sublist(
         lst.into_iter()
             .map(|s| {
                 s.and_then(|s| unsafe {
+                    let len = s.len();
                     df_container.get_columns_mut().push(s);
+                    if element_index {
+                        df_container
+                            .get_columns_mut()
+                            .push(sublist_element_index(len));
+                    }
                     let out = phys_expr.evaluate(&df_container, &state);
                     df_container.get_columns_mut().clear();
                     match out {
@@ -112,6 +147,7 @@ fn run_on_group_by_engine(
     name: &str,
     lst: &ListChunked,
     expr: &Expr,
+    element_index: bool,
 ) -> PolarsResult<Option<Series>> {
     let lst = lst.rechunk();
     let arr = lst.downcast_iter().next().unwrap();
@@ -124,8 +160,18 @@ fn run_on_group_by_engine(
     // Invariant in List means values physicals can be cast to inner dtype
     let values = unsafe { values.cast_unchecked(&inner_dtype).unwrap() };
 
-    let df_context = values.into_frame();
-    let phys_expr = prepare_expression_for_context("", expr, &inner_dtype, Context::Aggregation)?;
+    let mut df_context = values.into_frame();
+    if element_index {
+        let idx = offsets_to_element_index(arr.offsets(), df_context.height());
+        df_context.with_column(idx)?;
+    }
+    let phys_expr = prepare_expression_for_context(
+        "",
+        expr,
+        &inner_dtype,
+        Context::Aggregation,
+        element_index,
+    )?;
 
     let state = ExecutionState::new();
     let mut ac = phys_expr.evaluate_on_groups(&df_context, &groups, &state)?;
@@ -145,6 +191,10 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
         let this = self.into_list_name_space();
 
         let expr2 = expr.clone();
+        // Only materialize the element index if the expression uses it.
+        let element_index = expr
+            .into_iter()
+            .any(|e| matches!(e, Expr::Column(name) if name.as_ref() == ELEMENT_INDEX_NAME));
         let func = move |s: Series| {
             for e in expr.into_iter() {
                 match e {
@@ -159,7 +209,7 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
                     },
                     Expr::Column(name) => {
                         polars_ensure!(
-                            name.is_empty(),
+                            name.is_empty() || name.as_ref() == ELEMENT_INDEX_NAME,
                             ComputeError:
                             "named columns are not allowed in `list.eval`; consider using `element` or `col(\"\")`"
                         );
@@ -187,9 +237,9 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
             };
 
             if fits_idx_size && s.null_count() == 0 && !is_user_apply() {
-                run_on_group_by_engine(s.name(), &lst, &expr)
+                run_on_group_by_engine(s.name(), &lst, &expr, element_index)
             } else {
-                run_per_sublist(s, &lst, &expr, parallel, output_field)
+                run_per_sublist(s, &lst, &expr, parallel, output_field, element_index)
             }
         };
 
@@ -7,13 +7,8 @@ mod err;
 mod exitable;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod split;
 
-#[cfg(any(
-    feature = "parquet",
-    feature = "ipc",
-    feature = "csv",
-    feature = "json"
-))]
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -123,6 +118,7 @@ impl LazyFrame {
 
     /// Turn off all optimizations.
     pub fn without_optimizations(self) -> Self {
+        let streaming_memory_limit = self.opt_state.streaming_memory_limit;
         self.with_optimizations(OptState {
             projection_pushdown: false,
             predicate_pushdown: false,
@@ -139,6 +135,8 @@ impl LazyFrame {
             eager: false,
             fast_projection: false,
             row_estimate: false,
+            // not an optimization
+            streaming_memory_limit,
         })
     }
 
@@ -192,6 +190,15 @@ impl LazyFrame {
         self
     }
 
+    /// Set the memory budget in bytes of the nodes that run on the streaming engine. Nodes that
+    /// can spill to disk do so when the budget is exceeded, the others raise an error.
+    ///
+    /// This takes precedence over the `POLARS_STREAMING_MEMORY_LIMIT` env var.
+    pub fn with_streaming_memory_limit(mut self, limit: Option<usize>) -> Self {
+        self.opt_state.streaming_memory_limit = limit;
+        self
+    }
+
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub fn with_row_estimate(mut self, toggle: bool) -> Self {
         self.opt_state.row_estimate = toggle;
@@ -252,6 +259,27 @@ impl LazyFrame {
         }
     }
 
+    /// Compute a stable fingerprint of the optimized logical plan.
+    ///
+    /// Unlike hashing the output of [`explain`](LazyFrame::explain), this doesn't
+    /// depend on how plans are formatted. Equal plans have equal fingerprints across
+    /// processes and machines, which makes them suitable as a key for caching query
+    /// results. See [`plan_fingerprint`](polars_plan::logical_plan::visitor::plan_fingerprint)
+    /// for what is and isn't part of the fingerprint.
+    ///
+    /// Fingerprints only change when
+    /// [`PLAN_FINGERPRINT_VERSION`](polars_plan::logical_plan::visitor::PLAN_FINGERPRINT_VERSION)
+    /// is bumped.
+    #[cfg(feature = "cse")]
+    pub fn plan_fingerprint(&self) -> PolarsResult<u64> {
+        let (root, lp_arena, expr_arena) = self.clone().to_alp_optimized()?;
+        Ok(polars_plan::logical_plan::visitor::plan_fingerprint(
+            root,
+            &lp_arena,
+            &expr_arena,
+        ))
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -579,6 +607,7 @@ impl LazyFrame {
                     _fmt,
                     true,
                     opt_state.row_estimate,
+                    opt_state.streaming_memory_limit,
                 )?;
             }
             #[cfg(not(feature = "streaming"))]
@@ -634,6 +663,20 @@ impl LazyFrame {
         physical_plan.execute(&mut state)
     }
 
+    /// Execute all the lazy operations and collect them into a [`DataFrame`], together with the
+    /// files that were skipped.
+    ///
+    /// Scans created with [`ScanErrorPolicy::SkipFile`] don't abort the query when a file can't be
+    /// read. Instead the file is left out of the result and its path and error are returned here.
+    /// Any other error still aborts the query.
+    pub fn collect_with_scan_errors(
+        self,
+    ) -> PolarsResult<(DataFrame, Vec<(PathBuf, PolarsError)>)> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        let out = physical_plan.execute(&mut state)?;
+        Ok((out, state.take_scan_errors()))
+    }
+
     /// Profile a LazyFrame.
     ///
     /// This will run the query and return a tuple
@@ -649,6 +692,23 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    /// Profile the query like [`profile`](Self::profile) and additionally count, for every
+    /// `when/then/otherwise` expression, how many rows took the truthy and the falsy branch.
+    ///
+    /// This returns the materialized DataFrame, the node timings and a DataFrame with the
+    /// columns `expr` (output name), `snippet` (the rendered expression), `truthy` and
+    /// `falsy`. The branches of chained `when/then` expressions are nested ternaries, which
+    /// each evaluate their predicate on all rows. Rows with a null predicate count as falsy.
+    pub fn profile_with_branch_counts(self) -> PolarsResult<(DataFrame, DataFrame, DataFrame)> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        state.time_nodes();
+        state.count_branches();
+        let out = physical_plan.execute(&mut state)?;
+        let branches_df = state.finish_branch_counter()?;
+        let timer_df = state.finish_timer()?;
+        Ok((out, timer_df, branches_df))
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -834,6 +894,7 @@ impl LazyFrame {
             ProjectionOptions {
                 run_parallel: true,
                 duplicate_check: true,
+                sequential_resolution: false,
             },
         )
     }
@@ -845,6 +906,7 @@ impl LazyFrame {
             ProjectionOptions {
                 run_parallel: false,
                 duplicate_check: true,
+                sequential_resolution: false,
             },
         )
     }
@@ -990,6 +1052,10 @@ impl LazyFrame {
     }
 
     /// Similar to [`group_by`][`Self::group_by`], but order of the DataFrame is maintained.
+    ///
+    /// The groups are returned in the order in which their keys are first seen. The streaming
+    /// engine supports this by sorting the groups once they are all aggregated, which requires
+    /// all groups to fit in memory.
     pub fn group_by_stable<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupBy {
         let keys = by
             .as_ref()
@@ -1200,6 +1266,9 @@ impl LazyFrame {
         if let Some(suffix) = args.suffix {
             builder = builder.suffix(suffix);
         }
+        if let Some(hint) = args.strategy_hint {
+            builder = builder.strategy_hint(hint);
+        }
 
         // Note: args.slice is set by the optimizer
 
@@ -1241,6 +1310,7 @@ impl LazyFrame {
                 ProjectionOptions {
                     run_parallel: false,
                     duplicate_check: true,
+                    sequential_resolution: false,
                 },
             )
             .build();
@@ -1268,6 +1338,7 @@ impl LazyFrame {
             ProjectionOptions {
                 run_parallel: true,
                 duplicate_check: true,
+                sequential_resolution: false,
             },
         )
     }
@@ -1280,6 +1351,37 @@ impl LazyFrame {
             ProjectionOptions {
                 run_parallel: false,
                 duplicate_check: true,
+                sequential_resolution: false,
+            },
+        )
+    }
+
+    /// Add or replace multiple columns to a DataFrame, where every expression can refer to the
+    /// columns created by the expressions before it.
+    ///
+    /// Expressions that don't depend on each other are still evaluated together. If multiple
+    /// expressions output the same name, the last one wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn add_columns(df: DataFrame) -> LazyFrame {
+    ///     df.lazy()
+    ///         .with_columns_chained(
+    ///             vec![lit(10).alias("foo"), (col("foo") * lit(2)).alias("bar")]
+    ///          )
+    /// }
+    /// ```
+    pub fn with_columns_chained<E: AsRef<[Expr]>>(self, exprs: E) -> LazyFrame {
+        let exprs = exprs.as_ref().to_vec();
+        self.with_columns_impl(
+            exprs,
+            ProjectionOptions {
+                run_parallel: true,
+                duplicate_check: true,
+                sequential_resolution: true,
             },
         )
     }
@@ -1597,6 +1699,19 @@ impl LazyFrame {
         }
     }
 
+    /// Return a single column `name` with the row numbers where `predicate` evaluates
+    /// to `true`.
+    ///
+    /// The row numbers are global and follow the row order of the frame. Only the
+    /// columns `predicate` needs are read.
+    ///
+    /// This yields the same indices as `arg_where(predicate)` in a `select`.
+    pub fn filtered_indices(self, name: &str, predicate: Expr) -> LazyFrame {
+        self.with_row_index(name, None)
+            .filter(predicate)
+            .select([col(name)])
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col("*").count()])
@@ -1613,6 +1728,31 @@ impl LazyFrame {
 
     #[cfg(feature = "merge_sorted")]
     pub fn merge_sorted(self, other: LazyFrame, key: &str) -> PolarsResult<LazyFrame> {
+        self.merge_sorted_by(other, [key], [false])
+    }
+
+    /// Merge two frames that are both sorted by the `keys` columns, where every key can be
+    /// sorted in `descending` order. Equal rows are taken from `self` first.
+    #[cfg(feature = "merge_sorted")]
+    pub fn merge_sorted_by<I, S, D>(
+        self,
+        other: LazyFrame,
+        keys: I,
+        descending: D,
+    ) -> PolarsResult<LazyFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        D: IntoIterator<Item = bool>,
+    {
+        let columns: Arc<[Arc<str>]> = keys.into_iter().map(|s| Arc::from(s.as_ref())).collect();
+        let descending: Arc<[bool]> = descending.into_iter().collect();
+        polars_ensure!(
+            !columns.is_empty() && columns.len() == descending.len(),
+            InvalidOperation: "`merge_sorted` expected one `descending` flag per key, got {} keys and {} flags",
+            columns.len(), descending.len()
+        );
+
         // The two DataFrames are temporary concatenated
         // this indicates until which chunk the data is from the left df
         // this trick allows us to reuse the `Union` architecture to get map over
@@ -1628,7 +1768,8 @@ impl LazyFrame {
         )?;
         Ok(
             q.map_private(DslFunction::FunctionNode(FunctionNode::MergeSorted {
-                column: Arc::from(key),
+                columns,
+                descending,
             })),
         )
     }
@@ -1765,6 +1906,7 @@ pub struct JoinBuilder {
     suffix: Option<String>,
     validation: JoinValidation,
     join_nulls: bool,
+    strategy_hint: Option<JoinStrategyHint>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1780,6 +1922,7 @@ impl JoinBuilder {
             join_nulls: false,
             suffix: None,
             validation: Default::default(),
+            strategy_hint: None,
         }
     }
 
@@ -1851,6 +1994,13 @@ impl JoinBuilder {
         self
     }
 
+    /// Force the join algorithm. The hint is ignored if it can't be honored, run in verbose
+    /// mode to see whether it was.
+    pub fn strategy_hint(mut self, hint: JoinStrategyHint) -> Self {
+        self.strategy_hint = Some(hint);
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -1865,6 +2015,7 @@ impl JoinBuilder {
             suffix: self.suffix,
             slice: None,
             join_nulls: self.join_nulls,
+            strategy_hint: self.strategy_hint,
         };
 
         let lp = self
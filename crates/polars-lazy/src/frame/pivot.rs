@@ -20,7 +20,8 @@ impl PhysicalAggExpr for PivotExpr {
     fn evaluate(&self, df: &DataFrame, groups: &GroupsProxy) -> PolarsResult<Series> {
         let state = ExecutionState::new();
         let dtype = df.get_columns()[0].dtype();
-        let phys_expr = prepare_expression_for_context("", &self.0, dtype, Context::Aggregation)?;
+        let phys_expr =
+            prepare_expression_for_context("", &self.0, dtype, Context::Aggregation, false)?;
         phys_expr
             .evaluate_on_groups(df, groups, &state)
             .map(|mut ac| ac.aggregated())
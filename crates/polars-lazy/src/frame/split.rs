@@ -0,0 +1,59 @@
+use super::*;
+
+const SPLIT_PREDICATE: &str = "__POLARS_SPLIT_PREDICATE";
+
+impl LazyFrame {
+    /// Collect the query and split its rows into the rows that match `predicate` and the rows
+    /// that don't.
+    ///
+    /// Unlike collecting `filter(predicate)` and `filter(predicate.not())`, this executes the
+    /// query once and evaluates the predicate once per row. Every row ends up in at most one of
+    /// the outputs, and both outputs keep the order of the rows. The whole result is collected,
+    /// use [`LazyFrame::sink_split`] if it doesn't fit into memory.
+    pub fn split_at_expr(
+        self,
+        predicate: Expr,
+        nulls: SplitNulls,
+    ) -> PolarsResult<(DataFrame, DataFrame)> {
+        let mut df = self
+            .with_column(predicate.alias(SPLIT_PREDICATE))
+            .collect()?;
+        let mask = df.drop_in_place(SPLIT_PREDICATE)?;
+        let mask = mask.bool()?;
+
+        let is_true = mask.fill_null_with_values(nulls == SplitNulls::True)?;
+        let is_false = !&mask.fill_null_with_values(nulls != SplitNulls::False)?;
+        Ok((df.filter(&is_true)?, df.filter(&is_false)?))
+    }
+
+    /// Stream a query result into two files: the rows that match `predicate` go to `on_true`,
+    /// the other rows to `on_false`.
+    ///
+    /// The query is executed once in the streaming engine and the predicate is evaluated once
+    /// per morsel, so the result doesn't have to fit into memory. Every row ends up in at most
+    /// one of the files. This method will return an error if the query cannot be completely done
+    /// in a streaming fashion.
+    #[cfg(any(
+        feature = "ipc",
+        feature = "parquet",
+        feature = "csv",
+        feature = "json"
+    ))]
+    pub fn sink_split(
+        self,
+        predicate: Expr,
+        nulls: SplitNulls,
+        on_true: FileSinkOptions,
+        on_false: FileSinkOptions,
+    ) -> PolarsResult<()> {
+        self.with_column(predicate.alias(SPLIT_PREDICATE)).sink(
+            SinkType::Split {
+                predicate: Arc::from(SPLIT_PREDICATE),
+                nulls,
+                on_true: Box::new(on_true),
+                on_false: Box::new(on_false),
+            },
+            "split_at_expr()",
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+
+type Key = (Arc<str>, String);
+type Counts = (u64, u64);
+
+/// Counts how many rows took the truthy and the falsy branch of every ternary expression,
+/// keyed by the output name and the rendered expression.
+#[derive(Clone, Default)]
+pub(super) struct BranchCounter {
+    data: Arc<Mutex<PlHashMap<Key, Counts>>>,
+}
+
+impl BranchCounter {
+    pub(super) fn store(&self, name: Arc<str>, snippet: String, truthy: u64, falsy: u64) {
+        let mut data = self.data.lock().unwrap();
+        let counts = data.entry((name, snippet)).or_default();
+        counts.0 += truthy;
+        counts.1 += falsy;
+    }
+
+    pub(super) fn finish(self) -> PolarsResult<DataFrame> {
+        let data = std::mem::take(&mut *self.data.lock().unwrap());
+        let mut names = Vec::with_capacity(data.len());
+        let mut snippets = Vec::with_capacity(data.len());
+        let mut truthy = Vec::with_capacity(data.len());
+        let mut falsy = Vec::with_capacity(data.len());
+        for ((name, snippet), (t, f)) in data {
+            names.push(name.to_string());
+            snippets.push(snippet);
+            truthy.push(t);
+            falsy.push(f);
+        }
+
+        let columns = vec![
+            Series::new("expr", names),
+            Series::new("snippet", snippets),
+            Series::new("truthy", truthy),
+            Series::new("falsy", falsy),
+        ];
+        let df = unsafe { DataFrame::new_no_checks(columns) };
+        df.sort(vec!["expr", "snippet"], SortMultipleOptions::default())
+    }
+}
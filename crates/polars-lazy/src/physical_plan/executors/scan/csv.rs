@@ -39,6 +39,7 @@ impl CsvExec {
             ._with_comment_prefix(std::mem::take(&mut self.options.comment_prefix))
             .with_quote_char(self.options.quote_char)
             .with_end_of_line_char(self.options.eol_char)
+            .with_record_separator(std::mem::take(&mut self.options.record_separator))
             .with_encoding(self.options.encoding)
             .with_rechunk(self.file_options.rechunk)
             .with_row_index(std::mem::take(&mut self.file_options.row_index))
@@ -45,7 +45,7 @@ impl ParquetExec {
         }
     }
 
-    fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
+    fn read_par(&mut self, state: &ExecutionState) -> PolarsResult<Vec<DataFrame>> {
         let parallel = match self.options.parallel {
             ParallelStrategy::Auto if self.paths.len() > POOL.current_num_threads() => {
                 ParallelStrategy::RowGroups
@@ -56,6 +56,7 @@ impl ParquetExec {
         let mut result = vec![];
 
         let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let per_file_limit = self.file_options.per_file_limit.unwrap_or(usize::MAX);
         let mut base_row_index = self.file_options.row_index.take();
 
         // Limit no. of files at a time to prevent open file limits.
@@ -70,9 +71,8 @@ impl ParquetExec {
             // First initialize the readers, predicates and metadata.
             // This will be used to determine the slices. That way we can actually read all the
             // files in parallel even if we add row index columns or slices.
-            let readers_and_metadata = paths
-                .iter()
-                .map(|path| {
+            let readers_and_metadata = paths.iter().map(|path| {
+                let open = || {
                     let mut file_info = self.file_info.clone();
                     file_info.update_hive_partitions(path)?;
 
@@ -95,18 +95,22 @@ impl ParquetExec {
                         .read_parallel(parallel)
                         .set_low_memory(self.options.low_memory)
                         .use_statistics(self.options.use_statistics)
+                        .uuid_as_string(self.options.uuid_as_string)
                         .set_rechunk(false)
                         .with_hive_partition_columns(hive_partitions);
 
                     reader
                         .num_rows()
-                        .map(|num_rows| (reader, num_rows, predicate, projection))
-                })
-                .collect::<PolarsResult<Vec<_>>>()?;
+                        .map(|num_rows| (path, reader, num_rows, predicate, projection))
+                };
+                (path, open())
+            });
+            let readers_and_metadata =
+                collect_file_results(readers_and_metadata, self.file_options.on_error, state)?;
 
             let iter = readers_and_metadata
                 .iter()
-                .map(|(_, num_rows, _, _)| *num_rows);
+                .map(|(_, _, num_rows, _, _)| (*num_rows).min(per_file_limit));
 
             let rows_statistics = get_sequential_row_statistics(iter, remaining_rows_to_read);
 
@@ -116,10 +120,11 @@ impl ParquetExec {
                     .zip(rows_statistics.par_iter())
                     .map(
                         |(
-                            (reader, num_rows_this_file, predicate, projection),
+                            (path, reader, num_rows_this_file, predicate, projection),
                             (remaining_rows_to_read, cumulative_read),
                         )| {
-                            let remaining_rows_to_read = *remaining_rows_to_read;
+                            let remaining_rows_to_read =
+                                (*remaining_rows_to_read).min(per_file_limit);
                             let remaining_rows_to_read =
                                 if num_rows_this_file < remaining_rows_to_read {
                                     None
@@ -131,16 +136,18 @@ impl ParquetExec {
                                 offset: rc.offset + *cumulative_read as IdxSize,
                             });
 
-                            reader
+                            let df = reader
                                 .with_n_rows(remaining_rows_to_read)
                                 .with_row_index(row_index)
                                 .with_predicate(predicate.clone())
                                 .with_projection(projection.clone())
-                                .finish()
+                                .finish();
+                            (path, df)
                         },
                     )
-                    .collect::<PolarsResult<Vec<_>>>()
-            })?;
+                    .collect::<Vec<_>>()
+            });
+            let out = collect_file_results(out, self.file_options.on_error, state)?;
 
             let n_read = out.iter().map(|df| df.height()).sum();
             remaining_rows_to_read = remaining_rows_to_read.saturating_sub(n_read);
@@ -157,7 +164,7 @@ impl ParquetExec {
     }
 
     #[cfg(feature = "cloud")]
-    async fn read_async(&mut self) -> PolarsResult<Vec<DataFrame>> {
+    async fn read_async(&mut self, state: &ExecutionState) -> PolarsResult<Vec<DataFrame>> {
         let verbose = verbose();
         let first_schema = self
             .file_info
@@ -180,6 +187,9 @@ impl ParquetExec {
         }
 
         let mut remaining_rows_to_read = self.file_options.n_rows.unwrap_or(usize::MAX);
+        let per_file_limit = self.file_options.per_file_limit.unwrap_or(usize::MAX);
+        let on_error = self.file_options.on_error;
+        let uuid_as_string = self.options.uuid_as_string;
         let mut base_row_index = self.file_options.row_index.take();
         let mut processed = 0;
         for (batch_idx, paths) in self.paths.chunks(batch_size).enumerate() {
@@ -197,43 +207,47 @@ impl ParquetExec {
 
             // First initialize the readers and get the metadata concurrently.
             let iter = paths.iter().enumerate().map(|(i, path)| async move {
-                let first_file = batch_idx == 0 && i == 0;
-                // use the cached one as this saves a cloud call
-                let (metadata, schema) = if first_file {
-                    (first_metadata.clone(), Some((*first_schema).clone()))
-                } else {
-                    (None, None)
+                let open = async {
+                    let first_file = batch_idx == 0 && i == 0;
+                    // use the cached one as this saves a cloud call
+                    let (metadata, schema) = if first_file {
+                        (first_metadata.clone(), Some((*first_schema).clone()))
+                    } else {
+                        (None, None)
+                    };
+                    let mut reader = ParquetAsyncReader::from_uri(
+                        &path.to_string_lossy(),
+                        cloud_options,
+                        // Schema must be the same for all files. The hive partitions are included in this schema.
+                        schema,
+                        metadata,
+                    )
+                    .await?
+                    .uuid_as_string(uuid_as_string);
+
+                    if !first_file {
+                        let schema = reader.schema().await?;
+                        check_projected_arrow_schema(
+                            first_schema.as_ref(),
+                            schema.as_ref(),
+                            with_columns,
+                            "schema of all files in a single scan_parquet must be equal",
+                        )?
+                    }
+
+                    let num_rows = reader.num_rows().await?;
+                    PolarsResult::Ok((num_rows, reader, path))
                 };
-                let mut reader = ParquetAsyncReader::from_uri(
-                    &path.to_string_lossy(),
-                    cloud_options,
-                    // Schema must be the same for all files. The hive partitions are included in this schema.
-                    schema,
-                    metadata,
-                )
-                .await?;
-
-                if !first_file {
-                    let schema = reader.schema().await?;
-                    check_projected_arrow_schema(
-                        first_schema.as_ref(),
-                        schema.as_ref(),
-                        with_columns,
-                        "schema of all files in a single scan_parquet must be equal",
-                    )?
-                }
-
-                let num_rows = reader.num_rows().await?;
-                PolarsResult::Ok((num_rows, reader))
+                (path, open.await)
             });
-            let readers_and_metadata = futures::future::try_join_all(iter).await?;
+            let readers_and_metadata = futures::future::join_all(iter).await;
+            let readers_and_metadata = collect_file_results(readers_and_metadata, on_error, state)?;
 
             // Then compute `n_rows` to be taken per file up front, so we can actually read concurrently
             // after this.
             let iter = readers_and_metadata
                 .iter()
-                .map(|(num_rows, _)| num_rows)
-                .copied();
+                .map(|(num_rows, _, _)| (*num_rows).min(per_file_limit));
 
             let rows_statistics = get_sequential_row_statistics(iter, remaining_rows_to_read);
 
@@ -251,55 +265,59 @@ impl ParquetExec {
             let iter = readers_and_metadata
                 .into_iter()
                 .zip(rows_statistics.iter())
-                .zip(paths.as_ref().iter())
                 .map(
                     |(
-                        ((num_rows_this_file, reader), (remaining_rows_to_read, cumulative_read)),
-                        path,
+                        (num_rows_this_file, reader, path),
+                        (remaining_rows_to_read, cumulative_read),
                     )| async move {
-                        let mut file_info = file_info.clone();
-                        let remaining_rows_to_read = *remaining_rows_to_read;
-                        let remaining_rows_to_read = if num_rows_this_file < remaining_rows_to_read
-                        {
-                            None
-                        } else {
-                            Some(remaining_rows_to_read)
+                        let read = async {
+                            let mut file_info = file_info.clone();
+                            let remaining_rows_to_read =
+                                (*remaining_rows_to_read).min(per_file_limit);
+                            let remaining_rows_to_read =
+                                if num_rows_this_file < remaining_rows_to_read {
+                                    None
+                                } else {
+                                    Some(remaining_rows_to_read)
+                                };
+                            let row_index = base_row_index_ref.as_ref().map(|rc| RowIndex {
+                                name: rc.name.clone(),
+                                offset: rc.offset + *cumulative_read as IdxSize,
+                            });
+
+                            file_info.update_hive_partitions(path)?;
+
+                            let hive_partitions = file_info
+                                .hive_parts
+                                .as_ref()
+                                .map(|hive| hive.materialize_partition_columns());
+
+                            let (projection, predicate) = prepare_scan_args(
+                                predicate.clone(),
+                                &mut file_options.with_columns.clone(),
+                                &mut file_info.schema.clone(),
+                                row_index.is_some(),
+                                hive_partitions.as_deref(),
+                            );
+
+                            reader
+                                .with_n_rows(remaining_rows_to_read)
+                                .with_row_index(row_index)
+                                .with_projection(projection)
+                                .use_statistics(use_statistics)
+                                .with_predicate(predicate)
+                                .set_rechunk(false)
+                                .with_hive_partition_columns(hive_partitions)
+                                .finish()
+                                .await
+                                .map(Some)
                         };
-                        let row_index = base_row_index_ref.as_ref().map(|rc| RowIndex {
-                            name: rc.name.clone(),
-                            offset: rc.offset + *cumulative_read as IdxSize,
-                        });
-
-                        file_info.update_hive_partitions(path)?;
-
-                        let hive_partitions = file_info
-                            .hive_parts
-                            .as_ref()
-                            .map(|hive| hive.materialize_partition_columns());
-
-                        let (projection, predicate) = prepare_scan_args(
-                            predicate.clone(),
-                            &mut file_options.with_columns.clone(),
-                            &mut file_info.schema.clone(),
-                            row_index.is_some(),
-                            hive_partitions.as_deref(),
-                        );
-
-                        reader
-                            .with_n_rows(remaining_rows_to_read)
-                            .with_row_index(row_index)
-                            .with_projection(projection)
-                            .use_statistics(use_statistics)
-                            .with_predicate(predicate)
-                            .set_rechunk(false)
-                            .with_hive_partition_columns(hive_partitions)
-                            .finish()
-                            .await
-                            .map(Some)
+                        (path, read.await)
                     },
                 );
 
-            let dfs = futures::future::try_join_all(iter).await?;
+            let dfs = futures::future::join_all(iter).await;
+            let dfs = collect_file_results(dfs, on_error, state)?;
             let n_read = dfs
                 .iter()
                 .map(|opt_df| opt_df.as_ref().map(|df| df.height()).unwrap_or(0))
@@ -314,17 +332,20 @@ impl ParquetExec {
         Ok(result)
     }
 
-    fn read(&mut self) -> PolarsResult<DataFrame> {
+    fn read(&mut self, state: &ExecutionState) -> PolarsResult<DataFrame> {
         // FIXME: The row index implementation is incorrect when a predicate is
         // applied. This code mitigates that by applying the predicate after the
         // collection of the entire dataframe if a row index is requested. This is
         // inefficient.
-        let post_predicate = self
-            .file_options
-            .row_index
-            .as_ref()
-            .and_then(|_| self.predicate.take())
-            .map(phys_expr_to_io_expr);
+        // If files may be skipped, the predicate is applied afterwards as well, so
+        // that an error while evaluating it is never mistaken for a broken file.
+        let post_predicate = if self.file_options.row_index.is_some()
+            || self.file_options.on_error == ScanErrorPolicy::SkipFile
+        {
+            self.predicate.take().map(phys_expr_to_io_expr)
+        } else {
+            None
+        };
 
         let is_cloud = match self.paths.first() {
             Some(p) => is_cloud_url(p.as_path()),
@@ -363,10 +384,11 @@ impl ParquetExec {
                     eprintln!("ASYNC READING FORCED");
                 }
 
-                polars_io::pl_async::get_runtime().block_on_potential_spawn(self.read_async())?
+                polars_io::pl_async::get_runtime()
+                    .block_on_potential_spawn(self.read_async(state))?
             }
         } else {
-            self.read_par()?
+            self.read_par(state)?
         };
 
         let mut out = accumulate_dataframes_vertical(out)?;
@@ -380,6 +402,33 @@ impl ParquetExec {
     }
 }
 
+/// Unwrap the results of reading the individual files of a scan.
+///
+/// With [`ScanErrorPolicy::SkipFile`] the files that failed are dropped and their errors are
+/// recorded in the [`ExecutionState`], otherwise the first error is returned.
+fn collect_file_results<'a, T>(
+    results: impl IntoIterator<Item = (&'a PathBuf, PolarsResult<T>)>,
+    on_error: ScanErrorPolicy,
+    state: &ExecutionState,
+) -> PolarsResult<Vec<T>> {
+    let results = results.into_iter();
+    match on_error {
+        ScanErrorPolicy::Raise => results.map(|(_, result)| result).collect(),
+        ScanErrorPolicy::SkipFile => Ok(results
+            .filter_map(|(path, result)| match result {
+                Ok(v) => Some(v),
+                Err(err) => {
+                    if state.verbose() {
+                        eprintln!("skipping file {}: {}", path.display(), err);
+                    }
+                    state.push_scan_error(path.clone(), err);
+                    None
+                },
+            })
+            .collect()),
+    }
+}
+
 impl Executor for ParquetExec {
     fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
         let profile_name = if state.has_node_timer() {
@@ -393,6 +442,6 @@ impl Executor for ParquetExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| self.read(), profile_name)
+        state.record(|| self.read(state), profile_name)
     }
 }
@@ -1,4 +1,5 @@
 use polars_core::prelude::*;
+use polars_plan::constants::ELEMENT_INDEX_NAME;
 
 use crate::physical_plan::planner::create_physical_expr;
 use crate::prelude::*;
@@ -17,15 +18,18 @@ pub(crate) fn prepare_expression_for_context(
     expr: &Expr,
     dtype: &DataType,
     ctxt: Context,
+    element_index: bool,
 ) -> PolarsResult<Arc<dyn PhysicalExpr>> {
     let mut lp_arena = Arena::with_capacity(8);
     let mut expr_arena = Arena::with_capacity(10);
 
     // create a dummy lazyframe and run a very simple optimization run so that
     // type coercion and simplify expression optimizations run.
-    let column = Series::full_null(name, 0, dtype);
-    let lf = column
-        .into_frame()
+    let mut df = Series::full_null(name, 0, dtype).into_frame();
+    if element_index {
+        df.with_column(Series::new_empty(ELEMENT_INDEX_NAME, &IDX_DTYPE))?;
+    }
+    let lf = df
         .lazy()
         .without_optimizations()
         .with_simplify_expr(true)
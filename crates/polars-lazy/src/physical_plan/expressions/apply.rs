@@ -1,6 +1,8 @@
 use std::borrow::Cow;
 
+use arrow::legacy::utils::CustomIterTools;
 use polars_core::prelude::*;
+use polars_core::utils::NoNull;
 use polars_core::POOL;
 #[cfg(feature = "parquet")]
 use polars_io::predicates::{BatchStats, StatsEvaluator};
@@ -151,31 +153,23 @@ impl ApplyExpr {
             return self.finish_apply_groups(ac, ca);
         }
 
-        let f = |opt_s: Option<Series>| match opt_s {
+        let f = |(i, opt_s): (usize, Option<Series>)| match opt_s {
             None => Ok(None),
             Some(mut s) => {
                 if self.pass_name_to_apply {
                     s.rename(&name);
                 }
-                self.function.call_udf(&mut [s])
+                self.function.call_udf(&mut [s]).map_err(|err| (i, err))
             },
         };
 
-        let ca: ListChunked = if self.allow_threading {
-            POOL.install(|| {
-                agg.list()
-                    .unwrap()
-                    .par_iter()
-                    .map(f)
-                    .collect::<PolarsResult<_>>()
-            })?
+        let mut agg = agg.list().unwrap().clone();
+        let ca: Result<ListChunked, _> = if self.allow_threading {
+            POOL.install(|| agg.par_iter_indexed().enumerate().map(f).collect())
         } else {
-            agg.list()
-                .unwrap()
-                .into_iter()
-                .map(f)
-                .collect::<PolarsResult<_>>()?
+            agg.into_iter().enumerate().map(f).collect()
         };
+        let ca = ca.map_err(|(i, err)| group_error_context(err, &mut ac, i))?;
 
         self.finish_apply_groups(ac, ca.with_name(&name))
     }
@@ -252,7 +246,7 @@ impl ApplyExpr {
         }
 
         let ca = (0..len)
-            .map(|_| {
+            .map(|i| {
                 container.clear();
                 for iter in &mut iters {
                     match iter.next().unwrap() {
@@ -260,19 +254,30 @@ impl ApplyExpr {
                         Some(s) => container.push(s.deep_clone()),
                     }
                 }
-                self.function.call_udf(&mut container)
+                self.function
+                    .call_udf(&mut container)
+                    .map_err(|err| (i, err))
             })
-            .collect::<PolarsResult<ListChunked>>()?
-            .with_name(&field.name);
+            .collect::<Result<ListChunked, _>>();
 
         drop(iters);
 
+        let ca = ca
+            .map_err(|(i, err)| group_error_context(err, &mut acs[0], i))?
+            .with_name(&field.name);
+
         // Take the first aggregation context that as that is the input series.
         let ac = acs.swap_remove(0);
         self.finish_apply_groups(ac, ca)
     }
 }
 
+/// Name the group a function failed on, as the function only sees its values.
+fn group_error_context(err: PolarsError, ac: &mut AggregationContext, i: usize) -> PolarsError {
+    let first = ac.groups().get(i).first();
+    err.context(format!("in group {i}, which starts at row {first}").into())
+}
+
 fn all_unit_length(ca: &ListChunked) -> bool {
     assert_eq!(ca.chunks().len(), 1);
     let list_arr = ca.downcast_iter().next().unwrap();
@@ -336,7 +341,45 @@ impl PhysicalExpr for ApplyExpr {
                     ac.with_series(s, true, Some(&self.expr))?;
                     Ok(ac)
                 },
-                ApplyOptions::GroupWise => self.apply_single_group_aware(ac),
+                ApplyOptions::GroupWise => {
+                    if matches!(
+                        self.expr,
+                        Expr::Function {
+                            function: FunctionExpr::NullCount,
+                            ..
+                        }
+                    ) && matches!(ac.agg_state(), AggState::NotAggregated(_))
+                    {
+                        let s = ac.series().rechunk();
+                        if let Some(mut counts) = group_null_counts(&s, ac.groups()) {
+                            counts.rename(s.name());
+                            ac.with_agg_state(AggState::AggregatedScalar(counts.into_series()));
+                            ac.with_update_groups(UpdateGroups::No);
+                            return Ok(ac);
+                        }
+                    }
+                    // The groups are gathered at once and wrapped as arrays, instead of
+                    // imploding every group on its own.
+                    #[cfg(feature = "dtype-array")]
+                    if let Expr::Function {
+                        function: FunctionExpr::ImplodeToArray(width),
+                        ..
+                    } = self.expr
+                    {
+                        if matches!(
+                            ac.agg_state(),
+                            AggState::NotAggregated(_) | AggState::AggregatedList(_)
+                        ) {
+                            let lists = ac.aggregated();
+                            let out =
+                                polars_ops::chunked_array::lists_to_array(lists.list()?, width)?;
+                            ac.with_agg_state(AggState::AggregatedScalar(out));
+                            ac.with_update_groups(UpdateGroups::No);
+                            return Ok(ac);
+                        }
+                    }
+                    self.apply_single_group_aware(ac)
+                },
                 ApplyOptions::ElementWise => self.apply_single_elementwise(ac),
             }
         } else {
@@ -642,3 +685,42 @@ impl PartitionedAggregation for ApplyExpr {
         Ok(partitioned)
     }
 }
+
+/// Count the nulls of every group straight from the validity of a single-chunk `s`,
+/// without gathering the groups. Returns `None` if `s` is not supported.
+fn group_null_counts(s: &Series, groups: &GroupsProxy) -> Option<IdxCa> {
+    #[cfg(feature = "dtype-struct")]
+    if matches!(s.dtype(), DataType::Struct(_)) {
+        return None;
+    }
+    if s.chunks().len() != 1 {
+        return None;
+    }
+
+    let null_count = s.null_count();
+    if null_count == s.len() {
+        return Some(groups.group_count());
+    }
+    let validity = match s.chunks()[0].validity() {
+        Some(validity) if null_count > 0 => validity,
+        _ => return Some(IdxCa::from_vec("", vec![0; groups.len()])),
+    };
+
+    let counts: NoNull<IdxCa> = match groups {
+        GroupsProxy::Slice { groups, .. } => groups
+            .iter()
+            .map(|&[first, len]| validity.null_count_range(first as usize, len as usize) as IdxSize)
+            .collect_trusted(),
+        GroupsProxy::Idx(groups) => groups
+            .all()
+            .iter()
+            .map(|idx| {
+                idx.iter()
+                    // SAFETY: the group indices are in bounds of `s`.
+                    .filter(|&&i| unsafe { !validity.get_bit_unchecked(i as usize) })
+                    .count() as IdxSize
+            })
+            .collect_trusted(),
+    };
+    Some(counts.into_inner())
+}
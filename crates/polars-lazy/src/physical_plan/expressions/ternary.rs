@@ -1,6 +1,7 @@
 use polars_core::prelude::*;
 use polars_core::POOL;
 
+use crate::physical_plan::branch_counter::BranchCounter;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
 
@@ -29,6 +30,27 @@ impl TernaryExpr {
             run_par,
         }
     }
+
+    /// Record how many of the `len` output rows took the truthy and the falsy branch. Rows
+    /// with a null predicate take the falsy branch.
+    fn count_branches(&self, counter: &BranchCounter, mask: &BooleanChunked, len: usize) {
+        let truthy = if mask.len() == 1 {
+            if mask.get(0) == Some(true) {
+                len
+            } else {
+                0
+            }
+        } else {
+            mask.sum().unwrap_or(0) as usize
+        };
+        let name = expr_output_name(&self.expr).unwrap_or_else(|_| Arc::from(""));
+        counter.store(
+            name,
+            self.expr.to_string(),
+            truthy as u64,
+            (len - truthy) as u64,
+        );
+    }
 }
 
 fn finish_as_iters<'a>(
@@ -95,7 +117,11 @@ impl PhysicalExpr for TernaryExpr {
         let truthy = truthy?;
         let falsy = falsy?;
 
-        truthy.zip_with(&mask, &falsy)
+        let out = truthy.zip_with(&mask, &falsy)?;
+        if let Some(counter) = state.branch_counter() {
+            self.count_branches(counter, &mask, out.len());
+        }
+        Ok(out)
     }
 
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
@@ -124,6 +150,19 @@ impl PhysicalExpr for TernaryExpr {
 
         use AggState::*;
 
+        if let Some(counter) = state.branch_counter() {
+            let mask = match ac_mask.agg_state() {
+                NotAggregated(s) | AggregatedScalar(s) => Some(s.clone()),
+                AggregatedList(s) => Some(s.list()?.get_inner()),
+                // The length of a literal says nothing about the number of rows.
+                Literal(_) => None,
+            };
+            if let Some(mask) = mask {
+                let mask = mask.bool()?;
+                self.count_branches(counter, mask, mask.len());
+            }
+        }
+
         // Check if there are any:
         // - non-unit literals
         // - AggregatedScalar or AggregatedList
@@ -342,7 +381,11 @@ impl PartitionedAggregation for TernaryExpr {
         let mask = mask.evaluate_partitioned(df, groups, state)?;
         let mask = mask.bool()?.clone();
 
-        truthy.zip_with(&mask, &falsy)
+        let out = truthy.zip_with(&mask, &falsy)?;
+        if let Some(counter) = state.branch_counter() {
+            self.count_branches(counter, &mask, out.len());
+        }
+        Ok(out)
     }
 
     fn finalize(
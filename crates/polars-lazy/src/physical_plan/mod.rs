@@ -1,3 +1,4 @@
+mod branch_counter;
 pub mod executors;
 #[cfg(any(feature = "list_eval", feature = "pivot"))]
 pub(crate) mod exotic;
@@ -473,6 +473,40 @@ fn create_physical_expr_inner(
                             create_physical_expr_inner(quantile, ctxt, expr_arena, schema, state)?;
                         return Ok(Arc::new(AggQuantileExpr::new(input, quantile, interpol)));
                     }
+                    // `is_null().sum()` per group is `null_count()`, which counts straight from
+                    // the validity instead of materializing the boolean mask.
+                    if let AAggExpr::Sum(sum_input) = agg {
+                        if let AExpr::Function {
+                            input,
+                            function: FunctionExpr::Boolean(BooleanFunction::IsNull),
+                            ..
+                        } = expr_arena.get(sum_input)
+                        {
+                            if let [input] = input.as_slice() {
+                                let expr = node_to_expr(input.node(), expr_arena).null_count();
+                                let input = create_physical_expr_inner(
+                                    input.node(),
+                                    ctxt,
+                                    expr_arena,
+                                    schema,
+                                    state,
+                                )?;
+                                let options = FunctionOptions {
+                                    collect_groups: ApplyOptions::GroupWise,
+                                    returns_scalar: true,
+                                    ..Default::default()
+                                };
+                                return Ok(Arc::new(ApplyExpr::new(
+                                    vec![input],
+                                    FunctionExpr::NullCount.into(),
+                                    expr,
+                                    options,
+                                    !state.has_cache,
+                                    schema.cloned(),
+                                )));
+                            }
+                        }
+                    }
                     let field = schema
                         .map(|schema| {
                             expr_arena.get(expression).to_field(
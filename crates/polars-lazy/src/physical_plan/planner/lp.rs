@@ -150,6 +150,9 @@ pub fn create_physical_plan(
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
             },
+            SinkType::Split { .. } => {
+                polars_bail!(InvalidOperation: "split sink not supported in standard engine.")
+            },
         },
         Union { inputs, options } => {
             let inputs = inputs
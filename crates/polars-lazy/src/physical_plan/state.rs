@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering};
 use std::sync::{Mutex, RwLock};
 
@@ -8,10 +9,12 @@ use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 
+use crate::physical_plan::branch_counter::BranchCounter;
 use crate::physical_plan::node_timer::NodeTimer;
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
+pub type ScanErrors = Arc<Mutex<Vec<(PathBuf, PolarsError)>>>;
 
 bitflags! {
     #[repr(transparent)]
@@ -71,7 +74,13 @@ pub struct ExecutionState {
     pub(super) flags: AtomicU8,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    /// Only set when profiling the branches of ternary expressions.
+    branch_counter: Option<BranchCounter>,
     stop: Arc<AtomicBool>,
+    /// Files that were skipped by scans with `ScanErrorPolicy::SkipFile`.
+    scan_errors: ScanErrors,
+    /// Memory budget in bytes of the streaming engine, see `OptState::streaming_memory_limit`.
+    pub(crate) streaming_memory_limit: Option<usize>,
 }
 
 impl ExecutionState {
@@ -89,7 +98,10 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            branch_counter: None,
             stop: Arc::new(AtomicBool::new(false)),
+            scan_errors: Default::default(),
+            streaming_memory_limit: None,
         }
     }
 
@@ -105,6 +117,18 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
+    /// Toggle this to count the rows that take each branch of ternary expressions.
+    pub(crate) fn count_branches(&mut self) {
+        self.branch_counter = Some(BranchCounter::default())
+    }
+    pub(super) fn branch_counter(&self) -> Option<&BranchCounter> {
+        self.branch_counter.as_ref()
+    }
+
+    pub(crate) fn finish_branch_counter(&mut self) -> PolarsResult<DataFrame> {
+        self.branch_counter.take().unwrap().finish()
+    }
+
     // This is wrong when the U64 overflows which will never happen.
     pub(super) fn should_stop(&self) -> PolarsResult<()> {
         polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
@@ -141,7 +165,10 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            branch_counter: self.branch_counter.clone(),
             stop: self.stop.clone(),
+            scan_errors: self.scan_errors.clone(),
+            streaming_memory_limit: self.streaming_memory_limit,
         }
     }
 
@@ -156,10 +183,26 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            branch_counter: self.branch_counter.clone(),
             stop: self.stop.clone(),
+            scan_errors: self.scan_errors.clone(),
+            streaming_memory_limit: self.streaming_memory_limit,
         }
     }
 
+    /// Record the error of a file that a scan skipped.
+    #[cfg(feature = "parquet")]
+    pub(crate) fn push_scan_error(&self, path: PathBuf, err: PolarsError) {
+        let mut lock = self.scan_errors.lock().unwrap();
+        lock.push((path, err));
+    }
+
+    /// Take the errors of all files that were skipped so far.
+    pub(crate) fn take_scan_errors(&self) -> Vec<(PathBuf, PolarsError)> {
+        let mut lock = self.scan_errors.lock().unwrap();
+        std::mem::take(&mut *lock)
+    }
+
     pub(crate) fn set_schema(&self, schema: SchemaRef) {
         let mut lock = self.schema_cache.write().unwrap();
         *lock = Some(schema);
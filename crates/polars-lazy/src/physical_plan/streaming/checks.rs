@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use polars_core::chunked_array::ops::SortMultipleOptions;
 use polars_ops::prelude::*;
 use polars_plan::logical_plan::expr_ir::ExprIR;
@@ -24,6 +26,35 @@ pub(super) fn all_column(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
         .all(|e| matches!(expr_arena.get(e.node()), AExpr::Column(_)))
 }
 
+#[allow(unused_variables)]
+pub(super) fn streamable_scan(scan_type: &FileScan, paths: &[PathBuf]) -> bool {
+    match scan_type {
+        // The streaming IPC source only reads local files.
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { .. } => !paths.iter().any(polars_io::utils::is_cloud_url),
+        _ => scan_type.streamable(),
+    }
+}
+
+/// Check if the projection is a single `is_first_distinct` or `is_last_distinct` of a
+/// streamable key, returns whether it flags the last occurrence.
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+pub(super) fn streamable_distinct_flag(
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> Option<bool> {
+    polars_pipe::pipeline::distinct_flag_key(exprs, expr_arena)
+        .filter(|(key, _)| is_streamable(key.node(), expr_arena, Context::Default))
+        .map(|(_, keep_last)| keep_last)
+}
+
+/// Check if the projection is a single `arg_where` of a streamable condition.
+#[cfg(feature = "arg_where")]
+pub(super) fn streamable_arg_where(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> bool {
+    polars_pipe::pipeline::arg_where_condition(exprs, expr_arena)
+        .is_some_and(|condition| is_streamable(condition.node(), expr_arena, Context::Default))
+}
+
 pub(super) fn streamable_join(args: &JoinArgs) -> bool {
     let supported = match args.how {
         #[cfg(feature = "cross_join")]
@@ -104,6 +104,7 @@ pub(super) fn construct(
     lp_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     fmt: bool,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<Option<Node>> {
     use IR::*;
 
@@ -234,7 +235,7 @@ pub(super) fn construct(
         .get(insertion_location)
         .schema(lp_arena)
         .into_owned();
-    let pipeline_node = get_pipeline_node(lp_arena, pipelines, schema, original_lp);
+    let pipeline_node = get_pipeline_node(lp_arena, pipelines, schema, original_lp, memory_limit);
     lp_arena.replace(insertion_location, pipeline_node);
 
     Ok(Some(final_sink))
@@ -248,6 +249,10 @@ impl SExecutionContext for ExecutionState {
     fn should_stop(&self) -> PolarsResult<()> {
         ExecutionState::should_stop(self)
     }
+
+    fn memory_limit(&self) -> Option<usize> {
+        self.streaming_memory_limit
+    }
 }
 
 fn get_pipeline_node(
@@ -255,6 +260,7 @@ fn get_pipeline_node(
     mut pipelines: Vec<PipeLine>,
     schema: SchemaRef,
     original_lp: Option<DslPlan>,
+    memory_limit: Option<usize>,
 ) -> IR {
     // create a dummy input as the map function will call the input
     // so we just create a scan that returns an empty df
@@ -275,6 +281,7 @@ fn get_pipeline_node(
                     eprintln!("{:?}", &pipelines)
                 }
                 state.set_in_streaming_engine();
+                state.streaming_memory_limit = memory_limit;
                 let state = Box::new(state) as Box<dyn SExecutionContext>;
                 execute_pipeline(state, std::mem::take(&mut pipelines))
             }),
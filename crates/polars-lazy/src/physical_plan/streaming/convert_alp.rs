@@ -96,6 +96,7 @@ fn insert_slice(
     state.operators_sinks.push(PipelineNode::Sink(node));
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn insert_streaming_nodes(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -106,6 +107,7 @@ pub(crate) fn insert_streaming_nodes(
     // to streaming
     allow_partial: bool,
     row_estimate: bool,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<bool> {
     scratch.clear();
 
@@ -189,7 +191,20 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push(PipelineNode::Operator(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
-            Slice { input, offset, .. } if *offset >= 0 => {
+            #[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+            HStack { input, exprs, .. }
+                if streamable_distinct_flag(exprs, expr_arena).is_some() =>
+            {
+                state.streamable = true;
+                // The last occurrences are only known once the whole input is seen.
+                if streamable_distinct_flag(exprs, expr_arena) == Some(true) {
+                    state.operators_sinks.push(PipelineNode::Sink(root));
+                } else {
+                    state.operators_sinks.push(PipelineNode::Operator(root));
+                }
+                stack.push(StackFrame::new(*input, state, current_idx))
+            },
+            Slice { input, offset, len } if *offset >= 0 || *len as i64 >= -*offset => {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Sink(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
@@ -214,6 +229,13 @@ pub(crate) fn insert_streaming_nodes(
                 state.operators_sinks.push(PipelineNode::Operator(root));
                 stack.push(StackFrame::new(*input, state, current_idx))
             },
+            #[cfg(feature = "arg_where")]
+            Select { input, expr, .. } if streamable_arg_where(expr, expr_arena) => {
+                state.streamable = true;
+                // The global indices are only known once the heights of all chunks are seen.
+                state.operators_sinks.push(PipelineNode::Sink(root));
+                stack.push(StackFrame::new(*input, state, current_idx))
+            },
             SimpleProjection { input, .. } => {
                 state.streamable = true;
                 state.operators_sinks.push(PipelineNode::Operator(root));
@@ -244,11 +266,15 @@ pub(crate) fn insert_streaming_nodes(
                     )
                 }
             },
+            // The streaming sources can't skip unreadable files, so those scans run in memory.
             Scan {
+                paths,
                 file_options: options,
                 scan_type,
                 ..
-            } if scan_type.streamable() => {
+            } if streamable_scan(scan_type, paths)
+                && options.on_error == ScanErrorPolicy::Raise =>
+            {
                 if state.streamable {
                     #[cfg(feature = "csv")]
                     if matches!(scan_type, FileScan::Csv { .. }) {
@@ -284,7 +310,8 @@ pub(crate) fn insert_streaming_nodes(
                 // *except* for a left join. In a left join we use the right
                 // table as build table and we stream the left table. This way
                 // we maintain order in the left join.
-                let (input_left, input_right) = if swap_join_order(options) {
+                let right_schema = lp_arena.get(input_right).schema(lp_arena);
+                let (input_left, input_right) = if swap_join_order(options, &right_schema) {
                     (input_right, input_left)
                 } else {
                     (input_left, input_right)
@@ -383,7 +410,6 @@ pub(crate) fn insert_streaming_nodes(
                 input,
                 keys,
                 aggs,
-                maintain_order: false,
                 apply: None,
                 schema: output_schema,
                 options,
@@ -485,7 +511,8 @@ pub(crate) fn insert_streaming_nodes(
     let mut inserted = false;
     for tree in pipeline_trees {
         if is_valid_tree(&tree)
-            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt)?.is_some()
+            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt, memory_limit)?
+                .is_some()
         {
             inserted = true;
         }
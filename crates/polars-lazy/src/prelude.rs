@@ -6,14 +6,17 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "round_series")]
+pub use polars_ops::prelude::RoundMode;
+pub use polars_ops::prelude::{JoinArgs, JoinStrategyHint, JoinType, JoinValidation};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
 pub use polars_plan::logical_plan::{
-    AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
-    NULL,
+    AnonymousScan, AnonymousScanArgs, AnonymousScanBatches, AnonymousScanOptions, DslPlan, Literal,
+    LiteralValue, Null, NULL,
 };
 pub(crate) use polars_plan::prelude::*;
+pub use polars_plan::prelude::{FileSinkOptions, FileType, ScanErrorPolicy, SplitNulls};
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
 #[cfg(feature = "dynamic_group_by")]
@@ -0,0 +1,97 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::adbc::{AdbcBatchedReader, AdbcOptions, AdbcReader};
+
+use crate::prelude::*;
+
+struct AdbcScan {
+    reader: AdbcReader,
+    query: String,
+}
+
+/// Select the projected columns and stop after `n_rows`.
+fn prune_batches(batches: AdbcBatchedReader, args: AnonymousScanArgs) -> AnonymousScanBatches {
+    let with_columns = args.with_columns;
+    Box::new(
+        batches.scan(args.n_rows.unwrap_or(usize::MAX), move |remaining, df| {
+            if *remaining == 0 {
+                return None;
+            }
+            Some(df.and_then(|mut df| {
+                if let Some(columns) = &with_columns {
+                    df = df.select(columns.iter())?;
+                }
+                if df.height() > *remaining {
+                    df = df.head(Some(*remaining));
+                }
+                *remaining -= df.height();
+                Ok(df)
+            }))
+        }),
+    )
+}
+
+impl AnonymousScan for AdbcScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let schema = scan_opts
+            .output_schema
+            .clone()
+            .unwrap_or_else(|| scan_opts.schema.clone());
+        let dfs = self
+            .scan_batched(scan_opts)?
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if dfs.is_empty() {
+            return Ok(DataFrame::from(schema.as_ref()));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        if let Some(schema) = self.reader.execute_schema(&self.query)? {
+            return Ok(schema);
+        }
+        // The results are dropped without being read, which closes the statement.
+        let batches = self.reader.execute(&self.query)?;
+        Ok(batches.schema().clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+
+    fn scan_batched(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<AnonymousScanBatches> {
+        let batches = self.reader.execute(&self.query)?;
+        Ok(prune_batches(batches, scan_opts))
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the results of a query that is executed through an ADBC driver.
+    ///
+    /// The query is executed when the LazyFrame is collected. If `args` has no schema, it is
+    /// asked from the driver. Drivers that can't describe a query without executing it (e.g.
+    /// ADBC 1.0 drivers) also execute it when the LazyFrame is created, and close it without
+    /// reading the results. In the streaming engine every record batch sent by the driver is
+    /// a morsel.
+    pub fn scan_adbc(
+        query: &str,
+        options: &AdbcOptions,
+        args: ScanArgsAnonymous,
+    ) -> PolarsResult<Self> {
+        let function = Arc::new(AdbcScan {
+            reader: AdbcReader::new(options)?,
+            query: query.to_string(),
+        });
+        LazyFrame::anonymous_scan(function, args)
+    }
+}
@@ -24,6 +24,7 @@ pub struct LazyCsvReader {
     comment_prefix: Option<CommentPrefix>,
     quote_char: Option<u8>,
     eol_char: u8,
+    record_separator: Option<Vec<u8>>,
     null_values: Option<NullValues>,
     missing_is_null: bool,
     truncate_ragged_lines: bool,
@@ -60,6 +61,7 @@ impl LazyCsvReader {
             comment_prefix: None,
             quote_char: Some(b'"'),
             eol_char: b'\n',
+            record_separator: None,
             null_values: None,
             missing_is_null: true,
             infer_schema_length: Some(100),
@@ -176,6 +178,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Set the bytes that separate records, e.g. `b"\x1e"`. Takes precedence over the end of
+    /// line char. Separators of 2 to 4 bytes are supported.
+    #[must_use]
+    pub fn with_record_separator(mut self, record_separator: Option<Vec<u8>>) -> Self {
+        self.record_separator = record_separator;
+        self
+    }
+
     /// Set values that will be interpreted as missing/ null.
     #[must_use]
     pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
@@ -269,6 +279,7 @@ impl LazyCsvReader {
             self.comment_prefix.as_ref(),
             self.quote_char,
             self.eol_char,
+            self.record_separator.as_deref(),
             None,
             self.try_parse_dates,
             self.raise_if_empty,
@@ -304,6 +315,7 @@ impl LazyFileListReader for LazyCsvReader {
             self.comment_prefix,
             self.quote_char,
             self.eol_char,
+            self.record_separator,
             self.null_values,
             self.infer_schema_length,
             self.rechunk,
@@ -1,3 +1,5 @@
+#[cfg(feature = "adbc")]
+pub(super) mod adbc;
 pub(super) mod anonymous_scan;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
@@ -17,7 +17,12 @@ pub struct ScanArgsParquet {
     pub low_memory: bool,
     pub cloud_options: Option<CloudOptions>,
     pub use_statistics: bool,
+    /// Read columns with the parquet `UUID` logical type as strings instead of binary.
+    pub uuid_as_string: bool,
     pub hive_options: HiveOptions,
+    pub on_error: ScanErrorPolicy,
+    /// Read at most this many rows from each file. `n_rows` still limits the total.
+    pub per_file_limit: Option<usize>,
 }
 
 impl Default for ScanArgsParquet {
@@ -31,7 +36,10 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cloud_options: None,
             use_statistics: true,
+            uuid_as_string: false,
             hive_options: Default::default(),
+            on_error: Default::default(),
+            per_file_limit: None,
         }
     }
 }
@@ -83,7 +91,10 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.uuid_as_string,
             self.args.hive_options,
+            self.args.on_error,
+            self.args.per_file_limit,
         )?
         .build()
         .into();
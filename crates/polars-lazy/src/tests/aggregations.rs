@@ -1,3 +1,4 @@
+use polars_core::series::IsSorted;
 use polars_ops::prelude::ListNameSpaceImpl;
 use polars_utils::unitvec;
 
@@ -571,3 +572,181 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "random")]
+fn test_sample_n_per_group() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 2, 2, 2, 3, 3, 3, 3, 3],
+        "x" => [1, 2, 3, 4, 5, 6, 7, 8, 9],
+    ]?;
+
+    let sample_lens = |e: Expr| -> PolarsResult<Vec<Option<u32>>> {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("g")])
+            .agg([e.alias("x")])
+            .select([col("x").list().len()])
+            .collect()?;
+        Ok(Vec::from(out.column("x")?.cast(&DataType::UInt32)?.u32()?))
+    };
+
+    // `n` is evaluated per group.
+    let n = min_horizontal([len(), lit(2)])?;
+    let lens = sample_lens(col("x").sample_n(n, false, false, Some(0)))?;
+    assert_eq!(lens, &[Some(1), Some(2), Some(2)]);
+
+    // Clamped to the group length.
+    let lens = sample_lens(col("x").sample_n_clamped(lit(3), false, false, Some(0)))?;
+    assert_eq!(lens, &[Some(1), Some(3), Some(3)]);
+    assert!(sample_lens(col("x").sample_n(lit(3), false, false, Some(0))).is_err());
+
+    // Negative sizes are rejected.
+    assert!(sample_lens(col("x").sample_n(lit(-1), false, false, Some(0))).is_err());
+
+    // Float sizes must be integer-valued.
+    let n = min_horizontal([len(), lit(2)])?.cast(DataType::Float64);
+    let lens = sample_lens(col("x").sample_n(n, false, false, Some(0)))?;
+    assert_eq!(lens, &[Some(1), Some(2), Some(2)]);
+    assert!(sample_lens(col("x").sample_n(lit(1.5), false, false, Some(0))).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_sample_n_groups_seeded_independently() -> PolarsResult<()> {
+    // Two groups of the same length with the position within the group as value.
+    let df = df![
+        "g" => (0..40).map(|i| i / 20).collect::<Vec<i32>>(),
+        "pos" => (0..40).map(|i| i % 20).collect::<Vec<i32>>(),
+    ]?;
+    let sample = || -> PolarsResult<Vec<Series>> {
+        let out = df
+            .clone()
+            .lazy()
+            .group_by_stable([col("g")])
+            .agg([col("pos").sample_n(lit(5), false, true, Some(0))])
+            .collect()?;
+        Ok(out
+            .column("pos")?
+            .list()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>())
+    };
+
+    let groups = sample()?;
+    assert_eq!(groups.len(), 2);
+    assert!(!groups[0].equals(&groups[1]));
+    // The samples are still determined by the seed.
+    let again = sample()?;
+    assert!(groups.iter().zip(&again).all(|(a, b)| a.equals(b)));
+    Ok(())
+}
+
+#[test]
+fn test_group_by_null_count() -> PolarsResult<()> {
+    let mut df = df![
+        "g" => [1, 1, 1, 2, 2, 3, 3, 3],
+        "x" => [Some(1), None, None, Some(2), Some(3), None, None, None],
+        "y" => [Some(1.0), Some(2.0), Some(3.0), None, Some(4.0), Some(5.0), Some(6.0), Some(7.0)],
+        "z" => [Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), Some(7), Some(8)],
+    ]?;
+    // Sorted keys give slice groups, shuffled keys give index groups.
+    df.apply("g", |s| {
+        let mut s = s.clone();
+        s.set_sorted_flag(IsSorted::Ascending);
+        s
+    })?;
+    let shuffled = df.take(&IdxCa::new("", [5, 0, 3, 6, 1, 4, 7, 2]))?;
+    assert!(matches!(
+        df.group_by(["g"])?.get_groups(),
+        GroupsProxy::Slice { .. }
+    ));
+    assert!(matches!(
+        shuffled.group_by(["g"])?.get_groups(),
+        GroupsProxy::Idx(_)
+    ));
+
+    for df in [df, shuffled] {
+        // The window and the plain select contexts must agree as well.
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                col("x").is_null().sum().over([col("g")]).alias("over"),
+                (col("x").len() - col("x").count())
+                    .over([col("g")])
+                    .alias("over_expected"),
+                col("x").is_null().sum().alias("select"),
+            ])
+            .collect()?;
+        assert!(out
+            .column("over")?
+            .equals(&out.column("over_expected")?.clone().with_name("over")));
+        assert_eq!(out.column("select")?.idx()?.get(0), Some(5));
+
+        let out = df
+            .lazy()
+            .group_by_stable([col("g")])
+            .agg([
+                cols(["x", "y", "z"])
+                    .null_count()
+                    .name()
+                    .suffix("_null_count"),
+                cols(["x", "y", "z"])
+                    .is_null()
+                    .sum()
+                    .name()
+                    .suffix("_is_null_sum"),
+                (cols(["x", "y", "z"]).len() - cols(["x", "y", "z"]).count())
+                    .cast(IDX_DTYPE)
+                    .name()
+                    .suffix("_expected"),
+            ])
+            .sort(["g"], Default::default())
+            .collect()?;
+
+        for (name, expected) in [("x", [2, 0, 3]), ("y", [0, 1, 0]), ("z", [0, 0, 0])] {
+            let expected = Series::new("", expected).cast(&IDX_DTYPE)?;
+            for suffix in ["_null_count", "_is_null_sum", "_expected"] {
+                let s = out.column(&format!("{name}{suffix}"))?;
+                assert!(
+                    s.equals(&expected.clone().with_name(s.name())),
+                    "{name}{suffix}: {s:?}"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_agg_groups_offsets_and_indices() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a", "c", "b", "a"],
+    ]?;
+    let (offsets, indices) = df
+        .group_by_stable(["g"])?
+        .get_groups()
+        .to_offsets_and_indices();
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("g").agg_groups().alias("groups")])
+        .collect()?;
+    let groups = out.column("groups")?.list()?;
+    let arr = groups.downcast_iter().next().unwrap();
+    let arr_offsets = arr
+        .offsets()
+        .iter()
+        .map(|o| *o as IdxSize)
+        .collect::<Vec<_>>();
+    assert_eq!(arr_offsets, offsets);
+    let values = arr.values().as_any().downcast_ref::<IdxArr>().unwrap();
+    assert_eq!(values.values().as_slice(), indices);
+    Ok(())
+}
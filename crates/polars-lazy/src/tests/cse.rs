@@ -351,3 +351,59 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_plan_fingerprint() -> PolarsResult<()> {
+    let df = || {
+        df![
+            "a" => [1i64, 2],
+            "b" => ["x", "y"]
+        ]
+        .unwrap()
+    };
+
+    // Golden values, these must only change together with `PLAN_FINGERPRINT_VERSION` and
+    // don't depend on the enabled features.
+    assert_eq!(df().lazy().plan_fingerprint()?, 0x604701c7caafd7c1);
+    let with_exprs = df()
+        .lazy()
+        .filter(col("b").eq(lit("x")))
+        .select([col("a").cast(DataType::Float64).alias("c")])
+        .sort(["c"], Default::default());
+    assert_eq!(with_exprs.plan_fingerprint()?, 0xb8fae9a2e83b7f43);
+
+    let q = |df: DataFrame, value: i64| {
+        df.lazy()
+            .filter(col("a").gt(lit(value)))
+            .select([col("a").cast(DataType::Float64).alias("c")])
+    };
+    let fingerprint = q(df(), 1).plan_fingerprint()?;
+
+    // In-memory frames are identified by their shape, not by their address.
+    assert_eq!(q(df(), 1).plan_fingerprint()?, fingerprint);
+    // Literal values are part of the fingerprint.
+    assert_ne!(q(df(), 2).plan_fingerprint()?, fingerprint);
+    // So are cast targets.
+    let other_cast = df()
+        .lazy()
+        .filter(col("a").gt(lit(1i64)))
+        .select([col("a").cast(DataType::Float32).alias("c")]);
+    assert_ne!(other_cast.plan_fingerprint()?, fingerprint);
+
+    // Scans are identified by their source.
+    let foods = scan_foods_ipc().select([col("calories")]);
+    assert_eq!(
+        foods.plan_fingerprint()?,
+        scan_foods_ipc()
+            .select([col("calories")])
+            .plan_fingerprint()?
+    );
+    assert_ne!(
+        foods.plan_fingerprint()?,
+        scan_foods_ipc()
+            .select([col("fats_g")])
+            .plan_fingerprint()?
+    );
+
+    Ok(())
+}
@@ -380,6 +380,121 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_skip_corrupt_file() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_parquet_skip_corrupt_file");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    for (name, values) in [
+        ("a.parquet", [1i64, 2, 3]),
+        ("c.parquet", [4i64, 5, 3_000_000_000]),
+    ] {
+        let mut df = df![
+            "a" => values,
+        ]?;
+        let f = std::fs::File::create(dir.join(name))?;
+        ParquetWriter::new(f).finish(&mut df)?;
+    }
+    std::fs::write(dir.join("b.parquet"), b"PAR1 this is not a parquet file")?;
+
+    let glob = format!("{}/*.parquet", dir.display());
+    let scan = |on_error| {
+        LazyFrame::scan_parquet(
+            &glob,
+            ScanArgsParquet {
+                on_error,
+                ..Default::default()
+            },
+        )
+    };
+
+    assert!(scan(ScanErrorPolicy::Raise)?.collect().is_err());
+
+    let (df, errors) = scan(ScanErrorPolicy::SkipFile)?
+        .filter(col("a").lt(lit(5i64)))
+        .collect_with_scan_errors()?;
+    assert_eq!(
+        Vec::from(df.column("a")?.i64()?),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, dir.join("b.parquet"));
+
+    // The streaming engine runs such a scan in memory.
+    let df = scan(ScanErrorPolicy::SkipFile)?
+        .with_streaming(true)
+        .collect()?;
+    assert_eq!(df.height(), 6);
+
+    // Errors that don't come from reading a file still abort the query.
+    let out = scan(ScanErrorPolicy::SkipFile)?
+        .filter(col("a").strict_cast(DataType::Int32).gt(lit(0i32)))
+        .collect_with_scan_errors();
+    assert!(out.is_err());
+
+    // The schema is taken from the first file that can be read.
+    std::fs::write(dir.join("0.parquet"), b"PAR1 this is not a parquet file")?;
+    assert!(scan(ScanErrorPolicy::Raise)?.collect().is_err());
+    let (df, errors) = scan(ScanErrorPolicy::SkipFile)?.collect_with_scan_errors()?;
+    assert_eq!(df.height(), 6);
+    assert_eq!(
+        df.schema(),
+        Schema::from_iter([Field::new("a", DataType::Int64)])
+    );
+    let mut paths = errors.into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, &[dir.join("0.parquet"), dir.join("b.parquet")]);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_parquet_per_file_limit() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_parquet_per_file_limit");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    for (name, values) in [("a.parquet", [1i64, 2, 3]), ("b.parquet", [4i64, 5, 6])] {
+        let mut df = df![
+            "a" => values,
+        ]?;
+        let f = std::fs::File::create(dir.join(name))?;
+        ParquetWriter::new(f).finish(&mut df)?;
+    }
+
+    let glob = format!("{}/*.parquet", dir.display());
+    let scan = |n_rows, streaming| -> PolarsResult<Vec<Option<i64>>> {
+        let df = LazyFrame::scan_parquet(
+            &glob,
+            ScanArgsParquet {
+                n_rows,
+                per_file_limit: Some(2),
+                ..Default::default()
+            },
+        )?
+        .with_streaming(streaming)
+        .collect()?;
+        Ok(Vec::from(df.column("a")?.i64()?))
+    };
+
+    for streaming in [false, true] {
+        // Both files contribute at most two rows.
+        assert_eq!(
+            scan(None, streaming)?,
+            &[Some(1), Some(2), Some(4), Some(5)]
+        );
+        // The global limit still applies on top of the per-file limit.
+        assert_eq!(scan(Some(3), streaming)?, &[Some(1), Some(2), Some(4)]);
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -428,6 +543,67 @@ fn test_ipc_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_ipc_streaming_record_batches() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_ipc_streaming_record_batches");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    // Every chunk is written as a record batch. The large batch exceeds the chunk size of the
+    // streaming engine, so it is split. The small batches are concatenated, also across files.
+    let mut total = 0;
+    for (name, batch_sizes) in [
+        ("a.ipc", &[1usize, 700_000, 5, 20_000][..]),
+        ("b.ipc", &[3, 1]),
+    ] {
+        let mut df = DataFrame::default();
+        for len in batch_sizes {
+            let values = (total..total + *len as i64).collect::<Vec<_>>();
+            df.vstack_mut(&df!["a" => values]?)?;
+            total += *len as i64;
+        }
+        let f = std::fs::File::create(dir.join(name))?;
+        IpcWriter::new(f).finish(&mut df)?;
+    }
+
+    let glob = format!("{}/*.ipc", dir.display());
+    let total = total as usize;
+    for n_rows in [None, Some(2), Some(700_003), Some(total - 2)] {
+        let lf = LazyFrame::scan_ipc(
+            &glob,
+            ScanArgsIpc {
+                n_rows,
+                row_index: Some(RowIndex {
+                    name: "index".into(),
+                    offset: 10,
+                }),
+                ..Default::default()
+            },
+        )?;
+        let out = lf.clone().with_streaming(true).collect()?;
+        assert!(out.equals(&lf.clone().collect()?));
+
+        let len = n_rows.unwrap_or(total);
+        let index = out.column("index")?.idx()?;
+        assert!(index.into_no_null_iter().eq(10..10 + len as IdxSize));
+        let a = out.column("a")?.i64()?;
+        assert!(a.into_no_null_iter().eq(0..len as i64));
+
+        let q = lf
+            .filter(col("a").gt_eq(lit(699_999i64)))
+            .select([col("index")]);
+        assert!(q
+            .clone()
+            .with_streaming(true)
+            .collect()?
+            .equals(&q.collect()?));
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 fn slice_at_union(lp_arena: &Arena<IR>, lp: Node) -> bool {
     (&lp_arena).iter(lp).all(|(_, lp)| {
         if let IR::Union { options, .. } = lp {
@@ -1917,3 +1917,943 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_filtered_indices() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 5, 2, 7],
+    ]?;
+    df.vstack_mut(&df![
+        "a" => [9, 0, 6],
+    ]?)?;
+    assert_eq!(df.n_chunks(), 2);
+
+    let out = df
+        .clone()
+        .lazy()
+        .filtered_indices("idx", col("a").gt(lit(4)))
+        .collect()?;
+    assert_eq!(out.width(), 1);
+    assert_eq!(out.column("idx")?.dtype(), &IDX_DTYPE);
+    let idx: Vec<_> = out.column("idx")?.idx()?.into_no_null_iter().collect();
+    assert_eq!(idx, &[1, 3, 4, 6]);
+
+    let out = df
+        .lazy()
+        .filtered_indices("idx", col("a").gt(lit(100)))
+        .collect()?;
+    assert_eq!(out.shape(), (0, 1));
+    assert_eq!(out.column("idx")?.dtype(), &IDX_DTYPE);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "arg_where")]
+fn test_arg_where_groups() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "a" => [1, 5, 7, 9, 0],
+    ]?;
+
+    // In an aggregation the indices are relative to each group.
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([arg_where(col("a").gt(lit(4)))])
+        .collect()?;
+    let a = out.column("a")?.explode()?;
+    let a: Vec<_> = a.idx()?.into_no_null_iter().collect();
+    assert_eq!(a, &[1, 2, 0]);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "row_hash")]
+fn test_hash_hex() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, Some(3)],
+        "b" => ["x", "y", "z"],
+    ]?;
+
+    for (k0, k1, k2, k3) in [(0, 0, 0, 0), (1, 2, 3, 4)] {
+        let out = df
+            .clone()
+            .lazy()
+            .select([
+                col("a").hash(k0, k1, k2, k3).alias("a_hash"),
+                col("a").hash_hex(k0, k1, k2, k3).alias("a_hex"),
+                col("b").hash(k0, k1, k2, k3).alias("b_hash"),
+                col("b").hash_hex(k0, k1, k2, k3).alias("b_hex"),
+            ])
+            .collect()?;
+
+        for name in ["a", "b"] {
+            let hashes = out.column(&format!("{name}_hash"))?.u64()?;
+            let hex = out.column(&format!("{name}_hex"))?.str()?;
+            for (hash, hex) in hashes.into_no_null_iter().zip(hex.into_no_null_iter()) {
+                assert_eq!(hex.len(), 16);
+                assert_eq!(hex, hex.to_lowercase());
+                assert_eq!(u64::from_str_radix(hex, 16).unwrap(), hash);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_with_columns_chained() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+
+    let exprs = [
+        (col("a") + lit(1)).alias("b"),
+        (col("b") * lit(2)).alias("c"),
+        (col("a") * lit(10)).alias("d"),
+        (col("c") + col("d")).alias("b"),
+    ];
+
+    // Without sequential resolution `b` doesn't exist yet.
+    assert!(df.clone().lazy().with_columns(&exprs).collect().is_err());
+
+    let out = df.lazy().with_columns_chained(&exprs).collect()?;
+    assert!(out.equals(&df![
+        "a" => [1, 2, 3],
+        "b" => [14, 26, 38],
+        "c" => [4, 6, 8],
+        "d" => [10, 20, 30],
+    ]?));
+
+    Ok(())
+}
+
+#[test]
+fn test_repeat_literal() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([repeat(lit(1), lit(1_000_000))])
+        .collect()?;
+    let s = out.column("repeat")?;
+    assert_eq!(s.len(), 1_000_000);
+    assert_eq!(s.dtype(), &DataType::Int32);
+    assert_eq!(s.i32()?.sum(), Some(1_000_000));
+
+    // Column inputs take the general path.
+    let out = df.lazy().select([repeat(col("a"), lit(2))]).collect()?;
+    assert!(out.column("repeat")?.equals(&Series::new("repeat", [1, 1])));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "merge_sorted")]
+fn test_merge_sorted_multiple_keys() -> PolarsResult<()> {
+    let left = df![
+        "a" => [1, 1, 1, 2],
+        "b" => [3, 2, 2, 5],
+        "side" => ["l", "l", "l", "l"],
+    ]?;
+    // Ties on the first key span the chunk boundary of the right frame.
+    let mut right = df![
+        "a" => [1, 1],
+        "b" => [4, 2],
+        "side" => ["r", "r"],
+    ]?;
+    right.vstack_mut(&df![
+        "a" => [1, 2],
+        "b" => [1, 5],
+        "side" => ["r", "r"],
+    ]?)?;
+
+    let out = left
+        .lazy()
+        .merge_sorted_by(right.lazy(), ["a", "b"], [false, true])?
+        .collect()?;
+    let expected = df![
+        "a" => [1, 1, 1, 1, 1, 1, 2, 2],
+        "b" => [4, 3, 2, 2, 2, 1, 5, 5],
+        "side" => ["r", "l", "l", "l", "r", "r", "l", "r"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_fill_null_with_strategy_limit() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, None, None, Some(5)],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .fill_null_with_strategy(FillNullStrategy::Forward(Some(1)))
+                .alias("forward"),
+            col("a")
+                .fill_null_with_strategy(FillNullStrategy::Backward(Some(1)))
+                .alias("backward"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("forward")?.i32()?),
+        &[Some(1), Some(1), None, None, Some(5)]
+    );
+    assert_eq!(
+        Vec::from(out.column("backward")?.i32()?),
+        &[Some(1), None, None, Some(5), Some(5)]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interpolate")]
+fn test_fill_null_with_strategy_interpolate() -> PolarsResult<()> {
+    let df = df![
+        "a" => [None, Some(1), None, None, Some(7), None],
+    ]?;
+
+    let lf = df.clone().lazy().select([
+        col("a")
+            .fill_null_with_strategy(FillNullStrategy::Interpolate(InterpolationMethod::Linear))
+            .alias("strategy"),
+        col("a")
+            .interpolate(InterpolationMethod::Linear)
+            .alias("interpolate"),
+    ]);
+    let schema = lf.schema()?;
+    let out = lf.collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("strategy")?.f64()?),
+        &[None, Some(1.0), Some(3.0), Some(5.0), Some(7.0), None]
+    );
+    assert!(out
+        .column("strategy")?
+        .equals_missing(&out.column("interpolate")?.clone().with_name("strategy")));
+    assert_eq!(schema.get("strategy"), Some(&DataType::Float64));
+
+    // Interpolation needs the expression engine.
+    assert!(df
+        .column("a")?
+        .fill_null(FillNullStrategy::Interpolate(InterpolationMethod::Linear))
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_type_coercion_error_names_expression() -> PolarsResult<()> {
+    let df = df![
+        "revenue" => ["1.0", "2.5"],
+        "fx" => [1.1, 0.9],
+    ]?;
+
+    let err = df
+        .lazy()
+        .with_columns([
+            col("fx").alias("fx_copy"),
+            (col("revenue") * col("fx")).alias("revenue_eur"),
+        ])
+        .collect()
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("arithmetic on string and numeric not allowed"));
+    assert!(err.contains("in expression 'revenue_eur'"));
+    assert!(err.contains(r#"col("revenue")"#));
+    assert!(!err.contains("fx_copy"));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "strings"))]
+fn test_strptime_formats() -> PolarsResult<()> {
+    let df = df![
+        "date" => [Some("2021-03-15"), Some("03/16/2021"), Some("17 Mar 2021"), None, Some("foo")]
+    ]?;
+    let formats = vec![
+        "%Y-%m-%d".to_string(),
+        "%m/%d/%Y".to_string(),
+        "%d %b %Y".to_string(),
+    ];
+    let parse = |strict| {
+        df.clone()
+            .lazy()
+            .select([col("date").str().strptime_formats(
+                DataType::Date,
+                formats.clone(),
+                StrptimeOptions {
+                    strict,
+                    ..Default::default()
+                },
+                lit("raise"),
+            )])
+            .collect()
+    };
+
+    let out = parse(false)?;
+    let expected = Series::new(
+        "date",
+        [
+            NaiveDate::from_ymd_opt(2021, 3, 15),
+            NaiveDate::from_ymd_opt(2021, 3, 16),
+            NaiveDate::from_ymd_opt(2021, 3, 17),
+            None,
+            None,
+        ],
+    );
+    assert!(out.column("date")?.equals_missing(&expected));
+
+    let err = parse(true).unwrap_err().to_string();
+    assert!(err.contains("1 out of 5 values"));
+    assert!(err.contains("foo"));
+
+    Ok(())
+}
+
+#[test]
+fn test_negate_boolean_and_numeric() -> PolarsResult<()> {
+    let df = df![
+        "bool" => [Some(true), Some(false), None],
+        "int" => [Some(1i32), Some(-2), None],
+        "float" => [Some(1.5), Some(-0.5), None],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            (-col("bool")).alias("neg_bool"),
+            col("bool").not().alias("not_bool"),
+            -col("int"),
+            -col("float"),
+            (-lit(true)).alias("neg_lit"),
+        ])
+        .collect()?;
+
+    let expected = |name: &str| Series::new(name, [Some(false), Some(true), None]);
+    assert!(out
+        .column("neg_bool")?
+        .equals_missing(&expected("neg_bool")));
+    assert!(out
+        .column("not_bool")?
+        .equals_missing(&expected("not_bool")));
+    assert!(out
+        .column("int")?
+        .equals_missing(&Series::new("int", [Some(-1i32), Some(2), None])));
+    assert!(out
+        .column("float")?
+        .equals_missing(&Series::new("float", [Some(-1.5), Some(0.5), None])));
+    assert_eq!(out.column("neg_lit")?.bool()?.get(0), Some(false));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "top_k")]
+fn test_top_k_by_maintain_order_and_indices() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2, 2],
+        "key" => [3, 1, 3, 2, 5],
+        "tie" => [1, 1, 1, 1, 1],
+        "val" => [0, 1, 2, 3, 4],
+    ]?;
+    let stable = SortMultipleOptions::default().with_maintain_order(true);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("val")
+                .top_k_by(lit(2), [col("tie")], stable.clone(), false)
+                .alias("all_tied"),
+            col("val")
+                .top_k_by(lit(2), [col("key")], stable.clone(), false)
+                .alias("values"),
+            col("val")
+                .top_k_by(lit(2), [col("key")], stable.clone(), true)
+                .alias("indices"),
+        ])
+        .collect()?;
+    // The earlier rows win ties.
+    assert_eq!(
+        Vec::from(out.column("all_tied")?.i32()?),
+        &[Some(0), Some(1)]
+    );
+    assert_eq!(Vec::from(out.column("values")?.i32()?), &[Some(4), Some(0)]);
+    assert_eq!(
+        Vec::from(out.column("indices")?.idx()?),
+        &[Some(4), Some(0)]
+    );
+
+    // `k` exceeds the size of the groups.
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("val")
+                .top_k_by(lit(5), [col("tie")], stable.clone(), false)
+                .alias("values"),
+            col("val")
+                .top_k_by(lit(5), [col("key")], stable, true)
+                .alias("indices"),
+        ])
+        .collect()?;
+    let values = out.column("values")?.list()?;
+    assert_eq!(
+        Vec::from(values.get_as_series(0).unwrap().i32()?),
+        &[Some(0), Some(1), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(values.get_as_series(1).unwrap().i32()?),
+        &[Some(3), Some(4)]
+    );
+    let indices = out.column("indices")?.list()?;
+    assert_eq!(
+        Vec::from(indices.get_as_series(0).unwrap().idx()?),
+        &[Some(0), Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(indices.get_as_series(1).unwrap().idx()?),
+        &[Some(1), Some(0)]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "dtype-time", feature = "timezones"))]
+fn test_dt_combine_time_zone() -> PolarsResult<()> {
+    // 2024-01-01 and 01:02:03.000001.
+    let date = Series::new("date", [Some(19723i32), Some(19723), None]).cast(&DataType::Date)?;
+    let time =
+        Series::new("time", [Some(3_723_000_001_000i64), None, Some(0)]).cast(&DataType::Time)?;
+    let df = DataFrame::new(vec![date, time])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("date")
+                .dt()
+                .combine(col("time"), TimeUnit::Microseconds, None)
+                .alias("naive"),
+            col("date")
+                .dt()
+                .combine(
+                    col("time"),
+                    TimeUnit::Microseconds,
+                    Some("Europe/Amsterdam".into()),
+                )
+                .alias("aware"),
+        ])
+        .collect()?;
+
+    let naive = out.column("naive")?;
+    assert_eq!(
+        naive.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    let naive = naive.to_physical_repr();
+    assert_eq!(
+        Vec::from(naive.i64()?),
+        &[Some(1_704_070_923_000_001), None, None]
+    );
+
+    // The wall clock time is interpreted in the time zone, one hour ahead of UTC in winter.
+    let aware = out.column("aware")?;
+    assert_eq!(
+        aware.dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, Some("Europe/Amsterdam".into()))
+    );
+    let aware = aware.to_physical_repr();
+    assert_eq!(
+        Vec::from(aware.i64()?),
+        &[Some(1_704_067_323_000_001), None, None]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_implode_to_array_round_trip() -> PolarsResult<()> {
+    let array_dtype = DataType::Array(Box::new(DataType::Float64), 2);
+    let a = Series::new(
+        "a",
+        [
+            Series::new("", &[1.0, 2.0]),
+            Series::new("", &[3.0, 4.0]),
+            Series::new("", &[5.0, 6.0]),
+        ],
+    )
+    .cast(&array_dtype)?;
+    let df = df!["id" => [0u32, 1, 2], "a" => a]?;
+
+    // Explode, apply a per element transform and go back to fixed size arrays.
+    let out = df
+        .clone()
+        .lazy()
+        .explode([col("a")])
+        .with_column(col("a") * lit(2.0))
+        .group_by_stable([col("id")])
+        .agg([col("a").implode_to_array(2)])
+        .collect()?;
+    let a = out.column("a")?;
+    assert_eq!(a.dtype(), &array_dtype);
+    let values = a.explode()?;
+    let values = values.f64()?.into_no_null_iter().collect::<Vec<_>>();
+    assert_eq!(values, &[2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+
+    // Without groups the whole column is imploded.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").explode().implode_to_array(6)])
+        .collect()?;
+    assert_eq!(out.shape(), (1, 1));
+    assert_eq!(
+        out.column("a")?.dtype(),
+        &DataType::Array(Box::new(DataType::Float64), 6)
+    );
+
+    // The width must match every group.
+    let err = df
+        .lazy()
+        .explode([col("a")])
+        .filter(col("a").neq(lit(3.0)))
+        .group_by_stable([col("id")])
+        .agg([col("a").implode_to_array(2)])
+        .collect()
+        .unwrap_err();
+    let err = err.to_string();
+    assert!(err.contains("got length 1"), "{err}");
+    assert!(err.contains("in group 1"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "list_drop_nulls")]
+fn test_concat_to_list() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1i32), None, Some(3)],
+        "b" => [Some(4i64), Some(5), None],
+        "c" => [Some(7i32), None, Some(9)]
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([concat_to_list([col("a"), col("b"), col("c")], false)?])
+        .collect()?;
+    let out = out.column("a")?;
+    assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+    let expected = [
+        vec![Some(1i64), Some(4), Some(7)],
+        vec![None, Some(5), None],
+        vec![Some(3), None, Some(9)],
+    ];
+    for (row, expected) in out.list()?.into_no_null_iter().zip(expected) {
+        assert_eq!(row.i64()?.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    // Skipping the nulls leaves lists of different lengths.
+    let out = df
+        .lazy()
+        .select([concat_to_list([col("a"), col("b"), col("c")], true)?])
+        .collect()?;
+    let out = out.column("a")?;
+    let lengths = out
+        .list()?
+        .into_no_null_iter()
+        .map(|s| s.len())
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, &[3, 1, 2]);
+    let values = out.explode()?;
+    assert_eq!(
+        Vec::from(values.i64()?),
+        &[Some(1), Some(4), Some(7), Some(5), Some(3), Some(9)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_profile_branch_counts() -> PolarsResult<()> {
+    let df = df![
+        "g" => [0, 0, 0, 0, 0, 1, 1, 1, 1, 1],
+        "a" => (0..10).collect::<Vec<i32>>()
+    ]?;
+    let get_counts = |branches: &DataFrame, expr: &str| -> PolarsResult<(u64, u64)> {
+        let row = branches.filter(&branches.column("expr")?.equal(expr)?)?;
+        assert_eq!(row.height(), 1);
+        Ok((
+            row.column("truthy")?.u64()?.get(0).unwrap(),
+            row.column("falsy")?.u64()?.get(0).unwrap(),
+        ))
+    };
+
+    let (out, _, branches) = df
+        .clone()
+        .lazy()
+        .select([when(col("a").gt(lit(6)))
+            .then(col("a"))
+            .otherwise(lit(-1))
+            .alias("big")])
+        .profile_with_branch_counts()?;
+    assert_eq!(out.height(), 10);
+    assert_eq!(branches.height(), 1);
+    // The ternary is keyed by its own output name, the alias is applied on top of it.
+    assert_eq!(get_counts(&branches, "a")?, (3, 7));
+
+    // In a group-by context all groups add up.
+    let (_, _, branches) = df
+        .lazy()
+        .group_by([col("g")])
+        .agg([when(col("a").lt(lit(2)))
+            .then(col("a"))
+            .otherwise(lit(0))
+            .sum()])
+        .profile_with_branch_counts()?;
+    assert_eq!(get_counts(&branches, "a")?, (2, 8));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_splitn_list() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("a,b,c,d"), Some("x"), None, Some("")]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("s").str().splitn_list(lit(","), 2, false).alias("n2"),
+            col("s")
+                .str()
+                .splitn_list(lit(","), 2, true)
+                .alias("n2_inclusive"),
+            col("s").str().splitn_list(lit(","), 10, false).alias("n10"),
+        ])
+        .collect()?;
+
+    let to_vecs = |name: &str| -> PolarsResult<Vec<Option<Vec<Option<String>>>>> {
+        Ok(out
+            .column(name)?
+            .list()?
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    s.str()
+                        .unwrap()
+                        .into_iter()
+                        .map(|v| v.map(str::to_string))
+                        .collect()
+                })
+            })
+            .collect())
+    };
+    let strings = |values: &[&str]| -> Option<Vec<Option<String>>> {
+        Some(values.iter().map(|v| Some(v.to_string())).collect())
+    };
+
+    // The remainder is kept intact in the last element.
+    assert_eq!(
+        to_vecs("n2")?,
+        [
+            strings(&["a", "b,c,d"]),
+            strings(&["x"]),
+            None,
+            strings(&[""])
+        ]
+    );
+    assert_eq!(
+        to_vecs("n2_inclusive")?,
+        [
+            strings(&["a,", "b,c,d"]),
+            strings(&["x"]),
+            None,
+            strings(&[])
+        ]
+    );
+    assert_eq!(
+        to_vecs("n10")?,
+        [
+            strings(&["a", "b", "c", "d"]),
+            strings(&["x"]),
+            None,
+            strings(&[""])
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_split_at_expr() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let df = df![
+        "a" => [Some(1), Some(2), None, Some(4), Some(5), Some(6)],
+        "b" => ["u", "v", "w", "x", "y", "z"],
+    ]?;
+    let scans = Arc::new(AtomicUsize::new(0));
+    let counting_scan = {
+        let df = df.clone();
+        let scans = scans.clone();
+        move |_: AnonymousScanArgs| -> PolarsResult<DataFrame> {
+            scans.fetch_add(1, Ordering::Relaxed);
+            Ok(df.clone())
+        }
+    };
+    let args = ScanArgsAnonymous {
+        schema: Some(Arc::new(df.schema())),
+        ..Default::default()
+    };
+    let lf = LazyFrame::anonymous_scan(Arc::new(counting_scan), args)?;
+
+    let predicate = (col("a") % lit(2)).eq(lit(0));
+    let (matching, other) = lf
+        .clone()
+        .split_at_expr(predicate.clone(), SplitNulls::False)?;
+    assert_eq!(scans.load(Ordering::Relaxed), 1);
+    assert_eq!(matching.get_column_names(), &["a", "b"]);
+    assert!(matching.equals(&df![
+        "a" => [2, 4, 6],
+        "b" => ["v", "x", "z"],
+    ]?));
+    assert!(other.equals_missing(&df![
+        "a" => [Some(1), None, Some(5)],
+        "b" => ["u", "w", "y"],
+    ]?));
+
+    let (matching, other) = lf
+        .clone()
+        .split_at_expr(predicate.clone(), SplitNulls::True)?;
+    assert_eq!(matching.height(), 4);
+    assert_eq!(other.height(), 2);
+
+    let (matching, other) = lf.split_at_expr(predicate, SplitNulls::Drop)?;
+    assert_eq!(matching.height(), 3);
+    assert_eq!(other.height(), 2);
+    assert_eq!(scans.load(Ordering::Relaxed), 3);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cutqcut")]
+fn test_cut_index_only() -> PolarsResult<()> {
+    let values = [-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, f64::NAN];
+    let df = df![
+        "a" => values.into_iter().map(Some).chain([None]).collect::<Vec<_>>(),
+    ]?;
+    let breaks = vec![2.0, 0.0, 1.0];
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .cut(breaks.clone(), None, false, false, true)
+                .alias("right_closed"),
+            col("a")
+                .cut(breaks.clone(), None, true, false, true)
+                .alias("left_closed"),
+            col("a")
+                .cut(breaks, None, false, true, true)
+                .alias("with_breaks"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("right_closed")?.dtype(), &DataType::UInt32);
+
+    // Values below the first break are in bin 0, values on a break are in the bin that is
+    // closed at it.
+    assert_eq!(
+        Vec::from(out.column("right_closed")?.u32()?),
+        &[
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(3),
+            None,
+            None
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("left_closed")?.u32()?),
+        &[
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(3),
+            None,
+            None
+        ]
+    );
+
+    let with_breaks = out.column("with_breaks")?.struct_()?;
+    assert_eq!(
+        Vec::from(with_breaks.field_by_name("brk")?.f64()?),
+        &[
+            Some(0.0),
+            Some(0.0),
+            Some(1.0),
+            Some(1.0),
+            Some(2.0),
+            Some(f64::INFINITY),
+            None,
+            None
+        ]
+    );
+    assert_eq!(
+        with_breaks.field_by_name("a_bin")?.dtype(),
+        &DataType::UInt32
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cutqcut")]
+fn test_qcut_index_only() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..8).map(|v| v as f64).collect::<Vec<_>>(),
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("a").qcut_uniform(4, None, false, false, false, true)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.u32()?),
+        &[
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(2),
+            Some(2),
+            Some(3),
+            Some(3)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "map_elements")]
+fn test_map_elements() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "b", "a"],
+        "a" => [Some(1), None, Some(3)],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a")
+                .map_elements(
+                    |av| Ok(AnyValue::StringOwned(format!("v{av}").into())),
+                    None,
+                )
+                .alias("inferred"),
+            col("a")
+                .map_elements(
+                    |av| Ok(AnyValue::StringOwned(format!("v{av}").into())),
+                    Some(DataType::String),
+                )
+                .alias("given"),
+        ])
+        .collect()?;
+    for name in ["inferred", "given"] {
+        assert_eq!(
+            Vec::from(out.column(name)?.str()?),
+            &[Some("v1"), None, Some("v3")]
+        );
+    }
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("a").map_elements(
+            |av| Ok(AnyValue::Int64(av.extract::<i64>().unwrap() * 10)),
+            Some(DataType::Int64),
+        )])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.explode()?.i64()?),
+        &[Some(10), Some(30), None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "map_elements")]
+fn test_map_elements_inconsistent_dtype() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..200).collect::<Vec<i32>>(),
+    ]?;
+    // The data type is inferred from the first 100 outputs.
+    let out = df
+        .lazy()
+        .select([col("a").map_elements(
+            |av| match av.extract::<i32>().unwrap() {
+                v if v < 150 => Ok(AnyValue::Int32(v)),
+                _ => Ok(AnyValue::StringOwned("many".into())),
+            },
+            None,
+        )])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "map_elements", feature = "dtype-struct"))]
+fn test_map_elements_to_struct() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("ab"), None, Some("cde")],
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("s").map_elements(
+            |av| {
+                let s = av.get_str().unwrap();
+                Ok(AnyValue::StructOwned(Box::new((
+                    vec![
+                        AnyValue::UInt32(s.len() as u32),
+                        AnyValue::StringOwned(s.to_uppercase().into()),
+                    ],
+                    vec![
+                        Field::new("len", DataType::UInt32),
+                        Field::new("upper", DataType::String),
+                    ],
+                ))))
+            },
+            None,
+        )])
+        .collect()?;
+    let s = out.column("s")?.struct_()?;
+    assert_eq!(
+        Vec::from(s.field_by_name("len")?.u32()?),
+        &[Some(2), None, Some(3)]
+    );
+    assert_eq!(
+        Vec::from(s.field_by_name("upper")?.str()?),
+        &[Some("AB"), None, Some("CDE")]
+    );
+    Ok(())
+}
@@ -59,6 +59,29 @@ fn test_streaming_csv() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_csv_record_separator() -> PolarsResult<()> {
+    let csv = std::fs::read_to_string("../../examples/datasets/foods1.csv")?;
+    let path = std::env::temp_dir().join("polars_test_streaming_csv_record_separator.csv");
+    std::fs::write(&path, csv.replace('\n', "\r\n\x1e"))?;
+
+    let expected = get_csv_file().collect()?;
+    for low_memory in [false, true] {
+        let q = LazyCsvReader::new(&path)
+            .with_record_separator(Some(b"\r\n\x1e".to_vec()))
+            .low_memory(low_memory)
+            .finish()?;
+        assert!(optimization_checks::is_pipeline(
+            q.clone().with_streaming(true)
+        ));
+        assert_eq!(q.clone().with_streaming(true).collect()?, expected);
+        assert_eq!(q.collect()?, expected);
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
 #[test]
 fn test_streaming_glob() -> PolarsResult<()> {
     let q = get_csv_glob();
@@ -78,6 +101,42 @@ fn test_streaming_union_order() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "diagonal_concat")]
+fn test_streaming_union_diagonal() -> PolarsResult<()> {
+    let left = get_csv_file().select([col("category"), col("calories")]);
+    let right = get_parquet_file().select([col("calories").cast(DataType::Float64), col("fats_g")]);
+    let left_height = left.clone().collect()?.height();
+    let right_height = right.clone().collect()?.height();
+
+    let q = concat_lf_diagonal(
+        [left, right],
+        UnionArgs {
+            to_supertypes: true,
+            ..Default::default()
+        },
+    )?;
+    assert_streaming_with_default(q.clone(), true, false);
+
+    let out = q.with_streaming(true).collect()?;
+    assert_eq!(out.get_column_names(), &["category", "calories", "fats_g"]);
+    assert_eq!(out.height(), left_height + right_height);
+    assert_eq!(out.column("calories")?.dtype(), &DataType::Float64);
+    assert_eq!(out.column("fats_g")?.dtype(), &DataType::Float64);
+
+    let category = out.column("category")?;
+    assert_eq!(category.slice(0, left_height).null_count(), 0);
+    assert_eq!(
+        category
+            .slice(left_height as i64, right_height)
+            .null_count(),
+        right_height
+    );
+    let fats = out.column("fats_g")?;
+    assert_eq!(fats.slice(0, left_height).null_count(), left_height);
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_streaming_union_join() -> PolarsResult<()> {
@@ -234,6 +293,79 @@ fn test_streaming_inner_join2() -> PolarsResult<()> {
     assert_streaming_with_default(q, true, false);
     Ok(())
 }
+
+#[test]
+fn test_streaming_memory_limit() -> PolarsResult<()> {
+    let lf_left = df![
+        "a" => [0, 1, 2, 3],
+        "b" => [0, 1, 2, 3],
+    ]?
+    .lazy();
+    let lf_right = df![
+        "a" => [1, 2, 3, 4],
+        "c" => [0, 1, 2, 3],
+    ]?
+    .lazy();
+    let q = lf_left
+        .inner_join(lf_right, col("a"), col("a"))
+        .with_streaming(true);
+
+    let err = q
+        .clone()
+        .with_streaming_memory_limit(Some(1))
+        .collect()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("streaming memory limit of 1 bytes"));
+
+    let out = q.with_streaming_memory_limit(Some(1 << 30)).collect()?;
+    assert_eq!(out.shape(), (3, 3));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_join_strategy_hint() -> PolarsResult<()> {
+    let lf_left = df![
+        "a" => [4, 1, 3, 0, 2, 4, 1, 3, 0, 2],
+        "b" => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+    ]?
+    .lazy();
+    let lf_right = df![
+        "a" => [0, 0, 0, 3, 0, 1, 3, 3, 3, 1, 4, 4, 2, 1, 1, 3, 1, 4, 2, 2],
+        "b" => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]
+    ]?
+    .lazy();
+    let is_ascending = |s: &Series| {
+        let values = s.i32().unwrap().into_no_null_iter().collect::<Vec<_>>();
+        values.windows(2).all(|w| w[0] <= w[1])
+    };
+
+    for (hint, build_right) in [
+        (JoinStrategyHint::Hash, false),
+        (JoinStrategyHint::SortMerge, false),
+        (JoinStrategyHint::Broadcast, true),
+    ] {
+        let q = lf_left
+            .clone()
+            .join_builder()
+            .with(lf_right.clone())
+            .on([col("a")])
+            .strategy_hint(hint)
+            .finish();
+        assert!(q.explain(true)?.contains(&format!("STRATEGY HINT: {hint}")));
+        assert_streaming_with_default(q.clone(), true, false);
+
+        // The output follows the order of the probe side, so it shows which side the hash
+        // table was built on. By default that is the smaller left side.
+        let out = q.with_streaming(true).collect()?;
+        assert_eq!(out.height(), 40);
+        assert_eq!(is_ascending(out.column("b")?), build_right);
+        assert_eq!(is_ascending(out.column("b_right")?), !build_right);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_left_join() -> PolarsResult<()> {
     let lf_left = df![
@@ -396,6 +528,44 @@ fn test_sort_maintain_order_streaming() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_sort_head_matches_full_sort() -> PolarsResult<()> {
+    // Large enough for the sort sink to prune its accumulated chunks to the head.
+    let n = 300_000;
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let values = (0..n)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            // Few distinct values, so there are many ties.
+            (state % 1000) as i64
+        })
+        .collect::<Vec<_>>();
+    let df = df![
+        "a" => values,
+        "b" => (0..n as i64).collect::<Vec<_>>(),
+    ]?;
+
+    for descending in [false, true] {
+        for k in [1, 10, 1000] {
+            let options = SortMultipleOptions::default().with_order_descending(descending);
+            let out = df
+                .clone()
+                .lazy()
+                .sort(["a"], options.clone())
+                .slice(0, k as IdxSize)
+                .with_streaming(true)
+                .collect()?;
+            let expected = df.sort(["a"], options)?.slice(0, k);
+
+            assert_eq!(out.height(), k);
+            assert!(out.column("a")?.equals(expected.column("a")?));
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_outer_join() -> PolarsResult<()> {
     let lf_left = df![
@@ -421,3 +591,184 @@ fn test_streaming_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_streaming_filtered_indices() -> PolarsResult<()> {
+    let q = get_csv_glob().filtered_indices("idx", col("sugars_g").gt(lit(10)));
+
+    assert_streaming_with_default(q, false, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "arg_where")]
+fn test_streaming_arg_true() -> PolarsResult<()> {
+    let condition = col("sugars_g").gt(lit(10));
+    for expr in [condition.clone().arg_true(), condition.arg_false()] {
+        let q = get_csv_glob().select([expr.alias("idx")]);
+        assert!(q.clone().collect()?.height() > 0);
+        assert_streaming_with_default(q, true, false);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_group_by_maintain_order() -> PolarsResult<()> {
+    let q = get_csv_glob();
+    let aggs = [col("fats_g").sum(), col("sugars_g").first()];
+
+    // Integer, string and multiple keys all use a different sink.
+    for keys in [
+        vec![col("calories")],
+        vec![col("category")],
+        vec![col("category"), col("calories")],
+    ] {
+        let q = q.clone().group_by_stable(keys).agg(aggs.clone());
+        assert_streaming_with_default(q.clone(), true, false);
+
+        let first = q.clone().with_streaming(true).collect()?;
+        let second = q.clone().with_streaming(true).collect()?;
+        assert_eq!(first, second);
+
+        assert_streaming_with_default(q.slice(2, 5), true, false);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_streaming_tail() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1_000_000i64).collect::<Vec<_>>(),
+    ]?;
+    // The filter keeps the slice out of the scan, so the tail is taken over many morsels.
+    let q = df
+        .lazy()
+        .filter((col("a") % lit(7i64)).neq(lit(0i64)))
+        .tail(10);
+    assert_streaming_with_default(q.clone(), true, false);
+
+    let out = q.with_streaming(true).collect()?;
+    let a = out.column("a")?.i64()?;
+    let expected = (999_980..1_000_000i64)
+        .filter(|a| a % 7 != 0)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        a.into_no_null_iter().collect::<Vec<_>>(),
+        expected[expected.len() - 10..]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "is_first_distinct", feature = "is_last_distinct"))]
+fn test_streaming_is_first_last_distinct() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..100_000i64).collect::<Vec<_>>(),
+        "k" => (0..100_000i64).map(|i| (i * 7_919) % 1_013).collect::<Vec<_>>(),
+        "j" => (0..100_000i64).map(|i| i % 3).collect::<Vec<_>>(),
+    ]?;
+    let keys = vec![
+        col("k"),
+        #[cfg(feature = "dtype-struct")]
+        as_struct(vec![col("k"), col("j")]),
+    ];
+
+    for key in keys {
+        // The filter runs in the pipeline, so the duplicates are spread over many morsels.
+        let q = df
+            .clone()
+            .lazy()
+            .filter((col("a") % lit(5i64)).neq(lit(0i64)));
+        let first = q
+            .clone()
+            .with_column(key.clone().is_first_distinct().alias("flag"));
+        assert_streaming_with_default(first, true, false);
+        let last = q.with_column(key.is_last_distinct().alias("flag"));
+        assert_streaming_with_default(last, true, false);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "ipc")]
+fn test_streaming_sink_split() -> PolarsResult<()> {
+    use std::any::Any;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Emits a frame in batches and counts how often it is scanned.
+    struct CountingScan {
+        df: DataFrame,
+        scans: AtomicUsize,
+    }
+
+    impl AnonymousScan for CountingScan {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            unimplemented!()
+        }
+
+        fn allows_streaming(&self) -> bool {
+            true
+        }
+
+        fn scan_batched(
+            &self,
+            _scan_opts: AnonymousScanArgs,
+        ) -> PolarsResult<AnonymousScanBatches> {
+            self.scans.fetch_add(1, Ordering::Relaxed);
+            let df = self.df.clone();
+            Ok(Box::new(
+                (0..df.height() as i64)
+                    .step_by(1000)
+                    .map(move |offset| Ok(df.slice(offset, 1000))),
+            ))
+        }
+    }
+
+    let df = df![
+        "a" => (0..10_000i32).map(|i| (i % 7 != 0).then_some(i)).collect::<Vec<_>>(),
+        "b" => (0..10_000i32).collect::<Vec<_>>(),
+    ]?;
+    let scan = Arc::new(CountingScan {
+        df: df.clone(),
+        scans: AtomicUsize::new(0),
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(Arc::new(df.schema())),
+        ..Default::default()
+    };
+    let predicate = (col("a") % lit(2)).eq(lit(0));
+
+    let dir = std::env::temp_dir().join("polars_test_streaming_sink_split");
+    std::fs::create_dir_all(&dir)?;
+    let output = |name: &str| FileSinkOptions {
+        path: Arc::new(dir.join(name)),
+        file_type: FileType::Ipc(IpcWriterOptions {
+            maintain_order: true,
+            ..Default::default()
+        }),
+    };
+    for nulls in [SplitNulls::False, SplitNulls::True, SplitNulls::Drop] {
+        let scans = scan.scans.load(Ordering::Relaxed);
+        LazyFrame::anonymous_scan(scan.clone(), args.clone())?.sink_split(
+            predicate.clone(),
+            nulls,
+            output("true.ipc"),
+            output("false.ipc"),
+        )?;
+        assert_eq!(scan.scans.load(Ordering::Relaxed), scans + 1);
+
+        let (expected_true, expected_false) =
+            df.clone().lazy().split_at_expr(predicate.clone(), nulls)?;
+        let on_true = LazyFrame::scan_ipc(dir.join("true.ipc"), Default::default())?.collect()?;
+        let on_false = LazyFrame::scan_ipc(dir.join("false.ipc"), Default::default())?.collect()?;
+        assert!(on_true.equals_missing(&expected_true));
+        assert!(on_false.equals_missing(&expected_false));
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
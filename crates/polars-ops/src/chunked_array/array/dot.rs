@@ -0,0 +1,290 @@
+use std::borrow::Cow;
+
+use arrow::array::{FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
+use arrow::legacy::utils::CustomIterTools;
+use arrow::types::NativeType;
+use polars_core::export::num::Float;
+
+use super::*;
+
+#[inline]
+fn dot_slice<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b)
+        .fold(T::zero(), |acc, (&x, &y)| x.mul_add(y, acc))
+}
+
+#[inline]
+fn cosine_similarity_slice<T: Float>(a: &[T], b: &[T]) -> T {
+    let (dot, norm_a, norm_b) = a.iter().zip(b).fold(
+        (T::zero(), T::zero(), T::zero()),
+        |(dot, norm_a, norm_b), (&x, &y)| {
+            (
+                x.mul_add(y, dot),
+                x.mul_add(x, norm_a),
+                y.mul_add(y, norm_b),
+            )
+        },
+    );
+    dot / (norm_a * norm_b).sqrt()
+}
+
+/// The flat values of a fixed size list array together with the validity of its rows.
+///
+/// Null elements inside a sub-array are either zeroed out or mark the whole row as null.
+struct Rows<'a, T: Clone> {
+    values: Cow<'a, [T]>,
+    validity: Option<Bitmap>,
+}
+
+fn prepare_rows<T>(arr: &FixedSizeListArray, nulls_as_zero: bool) -> Rows<'_, T>
+where
+    T: NativeType + Float,
+{
+    let width = arr.size();
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap();
+
+    match values.validity() {
+        Some(inner) if inner.unset_bits() > 0 => {
+            if nulls_as_zero {
+                let values = values
+                    .values()
+                    .iter()
+                    .zip(inner.iter())
+                    .map(|(&v, valid)| if valid { v } else { T::zero() })
+                    .collect_trusted::<Vec<_>>();
+                Rows {
+                    values: Cow::Owned(values),
+                    validity: arr.validity().cloned(),
+                }
+            } else {
+                let rows_valid = (0..arr.len())
+                    .map(|row| {
+                        let start = row * width;
+                        // SAFETY: the child array has exactly `len * width` values.
+                        (start..start + width).all(|i| unsafe { inner.get_bit_unchecked(i) })
+                    })
+                    .collect::<Bitmap>();
+                Rows {
+                    values: Cow::Borrowed(values.values().as_slice()),
+                    validity: combine_validities_and(arr.validity(), Some(&rows_valid)),
+                }
+            }
+        },
+        _ => Rows {
+            values: Cow::Borrowed(values.values().as_slice()),
+            validity: arr.validity().cloned(),
+        },
+    }
+}
+
+fn row_wise_binary<T, F>(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    nulls_as_zero: bool,
+    op: F,
+) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+    F: Fn(&[T::Native], &[T::Native]) -> T::Native,
+{
+    let width = lhs.width();
+    let len = lhs.len();
+    let broadcast = rhs.len() == 1 && len != 1;
+
+    let lhs_ca = lhs.rechunk();
+    let rhs_ca = rhs.rechunk();
+    let lhs_rows = prepare_rows::<T::Native>(lhs_ca.downcast_iter().next().unwrap(), nulls_as_zero);
+    let rhs_rows = prepare_rows::<T::Native>(rhs_ca.downcast_iter().next().unwrap(), nulls_as_zero);
+
+    let values = (0..len)
+        .map(|row| {
+            let rhs_row = if broadcast { 0 } else { row };
+            let a = &lhs_rows.values[row * width..(row + 1) * width];
+            let b = &rhs_rows.values[rhs_row * width..(rhs_row + 1) * width];
+            op(a, b)
+        })
+        .collect_trusted::<Vec<_>>();
+
+    let validity = if broadcast {
+        match &rhs_rows.validity {
+            Some(v) if !v.get_bit(0) => Some(Bitmap::new_zeroed(len)),
+            _ => lhs_rows.validity,
+        }
+    } else {
+        combine_validities_and(lhs_rows.validity.as_ref(), rhs_rows.validity.as_ref())
+    };
+
+    let arr = PrimitiveArray::from_data_default(values.into(), validity);
+    ChunkedArray::with_chunk(lhs.name(), arr)
+}
+
+fn array_similarity(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    nulls_as_zero: bool,
+    cosine: bool,
+) -> PolarsResult<Series> {
+    let fn_name = if cosine {
+        "arr.cosine_similarity"
+    } else {
+        "arr.dot"
+    };
+    let width = lhs.width();
+    polars_ensure!(
+        width == rhs.width(),
+        ShapeMismatch: "`{}` got arrays of width {} and {}", fn_name, width, rhs.width()
+    );
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ShapeMismatch: "`{}` got inputs of length {} and {}", fn_name, lhs.len(), rhs.len()
+    );
+    let (lhs_inner, rhs_inner) = (lhs.inner_dtype(), rhs.inner_dtype());
+    polars_ensure!(
+        lhs_inner.is_numeric() && rhs_inner.is_numeric(),
+        InvalidOperation: "`{}` operation not supported for arrays of dtype `{}` and `{}`",
+        fn_name, lhs_inner, rhs_inner
+    );
+
+    // Both operations are commutative, so broadcast a unit-length left side by swapping.
+    let name = lhs.name();
+    let (lhs, rhs) = if lhs.len() == 1 && rhs.len() != 1 {
+        (rhs, lhs)
+    } else {
+        (lhs, rhs)
+    };
+
+    let float_dtype = match (&lhs_inner, &rhs_inner) {
+        (DataType::Float32, DataType::Float32) => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let array_dtype = DataType::Array(Box::new(float_dtype.clone()), width);
+    let lhs = lhs.cast(&array_dtype)?;
+    let rhs = rhs.cast(&array_dtype)?;
+    let (lhs, rhs) = (lhs.array()?, rhs.array()?);
+
+    let out = match float_dtype {
+        DataType::Float32 => {
+            let out: Float32Chunked = if cosine {
+                row_wise_binary(lhs, rhs, nulls_as_zero, cosine_similarity_slice)
+            } else {
+                row_wise_binary(lhs, rhs, nulls_as_zero, dot_slice)
+            };
+            out.into_series()
+        },
+        _ => {
+            let out: Float64Chunked = if cosine {
+                row_wise_binary(lhs, rhs, nulls_as_zero, cosine_similarity_slice)
+            } else {
+                row_wise_binary(lhs, rhs, nulls_as_zero, dot_slice)
+            };
+            out.into_series()
+        },
+    };
+    Ok(out.with_name(name))
+}
+
+/// Compute the dot product of every pair of sub-arrays.
+///
+/// If `rhs` has a single row it is broadcast against every row of `lhs`. A null element in a
+/// sub-array makes the result of that row null, unless `nulls_as_zero` is set.
+pub fn array_dot(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    nulls_as_zero: bool,
+) -> PolarsResult<Series> {
+    array_similarity(lhs, rhs, nulls_as_zero, false)
+}
+
+/// Compute the cosine similarity of every pair of sub-arrays.
+///
+/// Follows the same broadcasting and null semantics as [`array_dot`].
+pub fn array_cosine_similarity(
+    lhs: &ArrayChunked,
+    rhs: &ArrayChunked,
+    nulls_as_zero: bool,
+) -> PolarsResult<Series> {
+    array_similarity(lhs, rhs, nulls_as_zero, true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_array(rows: &[Option<Vec<Option<f64>>>], width: usize) -> ArrayChunked {
+        let rows = rows
+            .iter()
+            .map(|row| row.as_ref().map(|v| Series::new("", v.as_slice())))
+            .collect::<Vec<_>>();
+        Series::new("a", rows)
+            .cast(&DataType::Array(Box::new(DataType::Float64), width))
+            .unwrap()
+            .array()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_array_dot() -> PolarsResult<()> {
+        let lhs = to_array(
+            &[
+                Some(vec![Some(1.0), Some(2.0), Some(3.0)]),
+                Some(vec![Some(1.0), None, Some(1.0)]),
+                None,
+            ],
+            3,
+        );
+        let rhs = to_array(
+            &[
+                Some(vec![Some(4.0), Some(5.0), Some(6.0)]),
+                Some(vec![Some(2.0), Some(2.0), Some(2.0)]),
+                Some(vec![Some(1.0), Some(1.0), Some(1.0)]),
+            ],
+            3,
+        );
+
+        let out = array_dot(&lhs, &rhs, false)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.f64()?), &[Some(32.0), None, None]);
+
+        let out = array_dot(&lhs, &rhs, true)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(32.0), Some(4.0), None]);
+
+        // Broadcast a single vector against every row.
+        let single = to_array(&[Some(vec![Some(1.0), Some(0.0), Some(0.0)])], 3);
+        let out = array_dot(&lhs, &single, true)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), Some(1.0), None]);
+        let out = array_dot(&single, &lhs, true)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), Some(1.0), None]);
+
+        let wide = to_array(&[Some(vec![Some(1.0), Some(0.0)])], 2);
+        assert!(array_dot(&lhs, &wide, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_cosine_similarity() -> PolarsResult<()> {
+        let lhs = to_array(
+            &[
+                Some(vec![Some(1.0), Some(0.0)]),
+                Some(vec![Some(3.0), Some(4.0)]),
+            ],
+            2,
+        );
+        let rhs = to_array(&[Some(vec![Some(0.0), Some(2.0)])], 2);
+
+        let out = array_cosine_similarity(&lhs, &rhs, false)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(0.0), Some(0.8)]);
+
+        let out = array_cosine_similarity(&lhs, &lhs, false)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.0), Some(1.0)]);
+        Ok(())
+    }
+}
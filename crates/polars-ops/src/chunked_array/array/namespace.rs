@@ -3,6 +3,7 @@ use super::*;
 #[cfg(feature = "array_count")]
 use crate::chunked_array::array::count::array_count_matches;
 use crate::chunked_array::array::count::count_boolean_bits;
+use crate::chunked_array::array::dot::{array_cosine_similarity, array_dot};
 use crate::chunked_array::array::sum_mean::sum_with_nulls;
 #[cfg(feature = "array_any_all")]
 use crate::prelude::array::any_all::{array_all, array_any};
@@ -132,6 +133,20 @@ pub trait ArrayNameSpace: AsArray {
         array_join(ca, separator, ignore_nulls).map(|ok| ok.into_series())
     }
 
+    fn array_dot(&self, other: &ArrayChunked, nulls_as_zero: bool) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_dot(ca, other, nulls_as_zero)
+    }
+
+    fn array_cosine_similarity(
+        &self,
+        other: &ArrayChunked,
+        nulls_as_zero: bool,
+    ) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        array_cosine_similarity(ca, other, nulls_as_zero)
+    }
+
     #[cfg(feature = "array_count")]
     fn array_count_matches(&self, element: AnyValue) -> PolarsResult<Series> {
         let ca = self.as_array();
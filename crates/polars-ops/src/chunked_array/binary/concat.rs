@@ -0,0 +1,59 @@
+use super::*;
+
+/// Horizontally concatenate the values of all binary arrays. The result is null if any of the
+/// values is null.
+pub fn hor_bin_concat(cas: &[&BinaryChunked]) -> PolarsResult<BinaryChunked> {
+    if cas.is_empty() {
+        return Ok(BinaryChunked::full_null("", 0));
+    }
+    let lengths = cas.iter().map(|ca| ca.len()).collect::<Vec<_>>();
+    let Some(len) = broadcast_len(&lengths) else {
+        polars_bail!(
+            ComputeError: "all series in `hor_bin_concat` should have equal or unit length"
+        );
+    };
+
+    let columns: Vec<Vec<Option<&[u8]>>> = cas.iter().map(|ca| ca.iter().collect()).collect();
+    build_binary(
+        cas[0].name(),
+        len,
+        |i| {
+            columns
+                .iter()
+                .map(|values| get_broadcast(values, i).map(<[u8]>::len))
+                .sum()
+        },
+        |i, buf| {
+            for values in &columns {
+                buf.extend_from_slice(get_broadcast(values, i).unwrap())
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hor_bin_concat() {
+        let long = b"a value that is too long to be inlined".as_slice();
+        let a = BinaryChunked::new("a", &[Some(b"ab".as_slice()), Some(long), None]);
+        let b = BinaryChunked::new("b", &[Some(b"cd".as_slice()), Some(b"!"), Some(b"ef")]);
+
+        let out = hor_bin_concat(&[&a, &b]).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(out.get(0), Some(b"abcd".as_slice()));
+        assert_eq!(out.get(1).unwrap(), [long, b"!".as_slice()].concat());
+        assert_eq!(out.get(2), None);
+
+        let c = BinaryChunked::new("c", &[long]);
+        let out = hor_bin_concat(&[&a, &c]).unwrap();
+        assert_eq!(out.get(0).unwrap(), [b"ab".as_slice(), long].concat());
+        assert_eq!(out.get(1).unwrap(), [long, long].concat());
+        assert_eq!(out.get(2), None);
+
+        let d = BinaryChunked::new("d", &[b"x".as_slice(), b"y"]);
+        assert!(hor_bin_concat(&[&a, &d]).is_err());
+    }
+}
@@ -1,5 +1,12 @@
+mod concat;
 mod namespace;
+mod slice;
 
+use std::sync::Arc;
+
+use arrow::array::{BinaryViewArray, View, INLINE_VIEW_SIZE};
+use arrow::bitmap::Bitmap;
+pub use concat::*;
 pub use namespace::*;
 use polars_core::prelude::*;
 
@@ -12,3 +19,91 @@ impl AsBinary for BinaryChunked {
         self
     }
 }
+
+/// Build a [`BinaryChunked`] of `len` values whose data buffer is allocated once.
+///
+/// `value_len(i)` returns the length of value `i`, or `None` if it is null. `write_value(i, buf)`
+/// must append exactly that many bytes to `buf`.
+fn build_binary(
+    name: &str,
+    len: usize,
+    value_len: impl Fn(usize) -> Option<usize>,
+    mut write_value: impl FnMut(usize, &mut Vec<u8>),
+) -> PolarsResult<BinaryChunked> {
+    let lengths: Vec<Option<usize>> = (0..len).map(value_len).collect();
+    let total_bytes_len: usize = lengths.iter().flatten().sum();
+    // Only the values that don't fit in their view are stored in the buffer.
+    let total_buffer_len: usize = lengths
+        .iter()
+        .flatten()
+        .filter(|l| **l > INLINE_VIEW_SIZE as usize)
+        .sum();
+    polars_ensure!(
+        total_buffer_len <= u32::MAX as usize,
+        ComputeError: "binary values of {} bytes don't fit in a single buffer", total_buffer_len
+    );
+
+    let mut views = Vec::with_capacity(len);
+    let mut buffer = Vec::with_capacity(total_buffer_len);
+    let mut inline = Vec::with_capacity(INLINE_VIEW_SIZE as usize);
+    for (i, length) in lengths.iter().enumerate() {
+        let view = match length {
+            None => View::default(),
+            Some(length) if *length <= INLINE_VIEW_SIZE as usize => {
+                inline.clear();
+                write_value(i, &mut inline);
+                View::new_from_bytes(&inline, 0, 0)
+            },
+            Some(_) => {
+                let offset = buffer.len();
+                write_value(i, &mut buffer);
+                View::new_from_bytes(&buffer[offset..], 0, offset as u32)
+            },
+        };
+        views.push(view);
+    }
+    debug_assert_eq!(buffer.len(), total_buffer_len);
+
+    let validity = lengths
+        .iter()
+        .any(Option::is_none)
+        .then(|| lengths.iter().map(Option::is_some).collect::<Bitmap>());
+    let buffers: Arc<[_]> = if buffer.is_empty() {
+        Arc::new([])
+    } else {
+        Arc::new([buffer.into()])
+    };
+    // SAFETY: the views point into the single buffer and the lengths are computed above.
+    let arr = unsafe {
+        BinaryViewArray::new_unchecked(
+            ArrowDataType::BinaryView,
+            views.into(),
+            buffers,
+            validity,
+            total_bytes_len,
+            total_buffer_len,
+        )
+    };
+    Ok(BinaryChunked::with_chunk(name, arr))
+}
+
+/// The post-broadcast length of `lengths`, if they are all equal or unit length.
+fn broadcast_len(lengths: &[usize]) -> Option<usize> {
+    let len = lengths
+        .iter()
+        .copied()
+        .filter(|l| *l != 1)
+        .max()
+        .unwrap_or(1);
+    lengths.iter().all(|l| *l == 1 || *l == len).then_some(len)
+}
+
+/// Get value `i` of `values`, a unit length `values` is broadcast.
+#[inline]
+fn get_broadcast<T: Copy>(values: &[T], i: usize) -> T {
+    if values.len() == 1 {
+        values[0]
+    } else {
+        values[i]
+    }
+}
@@ -69,6 +69,25 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         }
     }
 
+    /// Get the size of the binary values in bytes.
+    fn size_bytes(&self) -> UInt32Chunked {
+        let ca = self.as_binary();
+        ca.apply_values_generic(|s| s.len() as u32)
+    }
+
+    /// Slice the binary values.
+    ///
+    /// A negative `offset` counts from the end of the value and a null `length` slices until the
+    /// end. Slices that are out of bounds are clamped, like in `str_slice`.
+    fn bin_slice(&self, offset: &Series, length: &Series) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        let offset = offset.cast(&DataType::Int64)?;
+        // We strict cast, otherwise negative value will be treated as a valid length.
+        let length = length.strict_cast(&DataType::UInt64)?;
+
+        super::slice::slice(ca, offset.i64()?, length.u64()?)
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
@@ -0,0 +1,87 @@
+use super::*;
+
+/// Slice `value` like `str.slice` slices a string. A negative `offset` counts from the end, a
+/// `length` of `None` slices until the end and a slice that is out of bounds is clamped.
+fn slice_bytes(value: &[u8], offset: i64, length: Option<u64>) -> &[u8] {
+    let len = value.len() as u64;
+    let (start, length) = if offset >= 0 {
+        ((offset as u64).min(len), length)
+    } else {
+        let from_end = offset.unsigned_abs();
+        if from_end <= len {
+            (len - from_end, length)
+        } else {
+            // The slice starts before the value, so the part before it is cut from the length.
+            (0, length.map(|l| l.saturating_sub(from_end - len)))
+        }
+    };
+    let end = length.map_or(len, |l| start.saturating_add(l).min(len));
+    &value[start as usize..end as usize]
+}
+
+pub(super) fn slice(
+    ca: &BinaryChunked,
+    offset: &Int64Chunked,
+    length: &UInt64Chunked,
+) -> PolarsResult<BinaryChunked> {
+    let Some(len) = broadcast_len(&[ca.len(), offset.len(), length.len()]) else {
+        polars_bail!(ComputeError: "all series in `bin.slice` should have equal or unit length");
+    };
+
+    let values: Vec<Option<&[u8]>> = ca.iter().collect();
+    let offset: Vec<Option<i64>> = offset.iter().collect();
+    let length: Vec<Option<u64>> = length.iter().collect();
+    let sliced = |i| {
+        let value = get_broadcast(&values, i)?;
+        let offset = get_broadcast(&offset, i)?;
+        Some(slice_bytes(value, offset, get_broadcast(&length, i)))
+    };
+    build_binary(
+        ca.name(),
+        len,
+        |i| sliced(i).map(<[u8]>::len),
+        |i, buf| buf.extend_from_slice(sliced(i).unwrap()),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slice_bytes() {
+        let value = b"abcdef".as_slice();
+        assert_eq!(slice_bytes(value, 1, Some(2)), b"bc");
+        assert_eq!(slice_bytes(value, 1, None), b"bcdef");
+        assert_eq!(slice_bytes(value, 4, Some(10)), b"ef");
+        assert_eq!(slice_bytes(value, 10, Some(2)), b"");
+        assert_eq!(slice_bytes(value, -2, None), b"ef");
+        assert_eq!(slice_bytes(value, -3, Some(1)), b"d");
+        assert_eq!(slice_bytes(value, -8, Some(3)), b"a");
+        assert_eq!(slice_bytes(value, -8, Some(1)), b"");
+        assert_eq!(slice_bytes(value, i64::MIN, None), b"abcdef");
+        assert_eq!(slice_bytes(value, 0, Some(u64::MAX)), b"abcdef");
+    }
+
+    #[test]
+    fn test_slice() {
+        let long = b"0123456789abcdefghij".as_slice();
+        let ca = BinaryChunked::new("a", &[Some(b"abc".as_slice()), Some(long), None]);
+
+        let offset = Int64Chunked::new("", &[1]);
+        let length = UInt64Chunked::new("", &[Some(2), None, Some(1)]);
+        let out = slice(&ca, &offset, &length).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(b"bc".as_slice()), Some(&long[1..]), None]
+        );
+
+        let offset = Int64Chunked::new("", &[Some(-15), None]);
+        let length = UInt64Chunked::new("", &[14]);
+        let out = slice(&ca.slice(1, 1), &offset, &length).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(&long[5..19]), None]);
+
+        assert!(slice(&ca, &offset, &length).is_err());
+    }
+}
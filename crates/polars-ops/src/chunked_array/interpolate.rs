@@ -3,10 +3,9 @@ use std::ops::{Add, Div, Mul, Sub};
 use arrow::array::PrimitiveArray;
 use arrow::bitmap::MutableBitmap;
 use polars_core::downcast_as_macro_arg_physical;
-use polars_core::export::num::{NumCast, Zero};
+use polars_core::export::num::{Float, NumCast, One, Zero};
+pub use polars_core::prelude::InterpolationMethod;
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
@@ -223,17 +222,141 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
+/// Slope at the first (or, mirrored, the last) known point: a non-centered three point
+/// estimate that is clamped so it does not introduce an extremum at the boundary.
+fn pchip_edge_slope<T: Float>(h0: T, h1: T, delta0: T, delta1: T) -> T {
+    let two = T::one() + T::one();
+    let three = two + T::one();
+    let d = ((two * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+    if d * delta0 <= T::zero() {
+        T::zero()
+    } else if delta0 * delta1 < T::zero() && d.abs() > three * delta0.abs() {
+        three * delta0
+    } else {
+        d
+    }
+}
+
+/// Fritsch-Carlson slopes at the known points. `h` holds the distances between consecutive known
+/// points and `delta` the secant slopes between them.
+fn pchip_slopes<T: Float>(h: &[T], delta: &[T]) -> Vec<T> {
+    let n = h.len() + 1;
+    if n == 2 {
+        return vec![delta[0]; 2];
+    }
+    let two = T::one() + T::one();
+    let mut d = vec![T::zero(); n];
+    for k in 1..n - 1 {
+        // A local extremum or a flat segment gets a zero slope, this is what prevents overshoot.
+        if delta[k - 1] * delta[k] > T::zero() {
+            let w1 = two * h[k] + h[k - 1];
+            let w2 = h[k] + two * h[k - 1];
+            d[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+        }
+    }
+    d[0] = pchip_edge_slope(h[0], h[1], delta[0], delta[1]);
+    d[n - 1] = pchip_edge_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    d
+}
+
+fn pchip_impl<T>(chunked_arr: &ChunkedArray<T>) -> ChunkedArray<T>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    if !chunked_arr.has_validity() || chunked_arr.null_count() == chunked_arr.len() {
+        return chunked_arr.clone();
+    }
+    let (xs, ys): (Vec<usize>, Vec<T::Native>) = chunked_arr
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (i, v)))
+        .unzip();
+    // A single known point has no gaps to fill.
+    if xs.len() < 2 {
+        return chunked_arr.clone();
+    }
+    let n = xs.len();
+    let h: Vec<T::Native> = xs
+        .windows(2)
+        .map(|w| NumCast::from(w[1] - w[0]).unwrap())
+        .collect();
+    let delta: Vec<T::Native> = (0..n - 1).map(|k| (ys[k + 1] - ys[k]) / h[k]).collect();
+    let d = pchip_slopes(&h, &delta);
+
+    let two = T::Native::one() + T::Native::one();
+    let three = two + T::Native::one();
+    let first = xs[0];
+    let last = xs[n - 1] + 1;
+
+    let mut av = Vec::with_capacity(chunked_arr.len());
+    av.extend(std::iter::repeat(T::Native::zero()).take(first));
+    for k in 0..n - 1 {
+        av.push(ys[k]);
+        for step in 1..xs[k + 1] - xs[k] {
+            // Cubic Hermite basis on the unit interval.
+            let t: T::Native = <T::Native as NumCast>::from(step).unwrap() / h[k];
+            let t2 = t * t;
+            let t3 = t2 * t;
+            let h00 = two * t3 - three * t2 + T::Native::one();
+            let h10 = t3 - two * t2 + t;
+            let h01 = three * t2 - two * t3;
+            let h11 = t3 - t2;
+            av.push(h00 * ys[k] + h10 * h[k] * d[k] + h01 * ys[k + 1] + h11 * h[k] * d[k + 1]);
+        }
+    }
+    av.push(ys[n - 1]);
+
+    if first != 0 || last != chunked_arr.len() {
+        let mut validity = MutableBitmap::with_capacity(chunked_arr.len());
+        validity.extend_constant(first, false);
+        validity.extend_constant(last - first, true);
+        validity.extend_constant(chunked_arr.len() - last, false);
+        av.extend(std::iter::repeat(T::Native::zero()).take(chunked_arr.len() - last));
+
+        let array = PrimitiveArray::new(
+            T::get_dtype().to_arrow(true),
+            av.into(),
+            Some(validity.into()),
+        );
+        ChunkedArray::with_chunk(chunked_arr.name(), array)
+    } else {
+        ChunkedArray::from_vec(chunked_arr.name(), av)
+    }
+}
+
+fn interpolate_pchip(s: &Series) -> Series {
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.clone(),
+        DataType::Binary => s.clone(),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => s.clone(),
+        DataType::List(_) => s.clone(),
+        DataType::Float32 => pchip_impl(s.f32().unwrap()).into_series(),
+        DataType::Float64 => pchip_impl(s.f64().unwrap()).into_series(),
+        dt if dt.is_numeric() => {
+            pchip_impl(s.cast(&DataType::Float64).unwrap().f64().unwrap()).into_series()
+        },
+        DataType::Date | DataType::Datetime(_, _) | DataType::Duration(_) | DataType::Time => {
+            let logical = s.dtype();
+            let physical = s.to_physical_repr();
+            let out = pchip_impl(physical.cast(&DataType::Float64).unwrap().f64().unwrap());
+            out.into_series()
+                .cast(physical.dtype())
+                .unwrap()
+                .cast(logical)
+                .unwrap()
+        },
+        _ => s.clone(),
+    }
 }
 
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     match method {
         InterpolationMethod::Linear => interpolate_linear(s),
         InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::Pchip => interpolate_pchip(s),
     }
 }
 
@@ -336,4 +459,54 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_interpolate_pchip_no_overshoot() {
+        // A monotone staircase. A cubic Hermite with centered finite difference slopes gives
+        // x=3 a slope of (1 - 0) / 4 and dips below zero halfway between x=1 and x=3:
+        // 0.5 * 0 + 0.125 * 2 * 0 + 0.5 * 0 - 0.125 * 2 * 0.25 = -0.0625.
+        let ca = Float64Chunked::new(
+            "",
+            &[
+                None,
+                Some(0.0),
+                None,
+                Some(0.0),
+                None,
+                Some(1.0),
+                None,
+                Some(1.0),
+                None,
+                Some(2.0),
+                None,
+            ],
+        );
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Pchip);
+        let out = Vec::from(out.f64().unwrap());
+
+        // Boundary nulls are kept.
+        assert_eq!(out[0], None);
+        assert_eq!(out[10], None);
+        // Flat segments stay flat, there is no dip below 0.0.
+        assert_eq!(out[2], Some(0.0));
+        assert_eq!(out[6], Some(1.0));
+        // Rising segments stay strictly within their neighbors.
+        let v = out[4].unwrap();
+        assert!(v > 0.0 && v < 1.0);
+        let v = out[8].unwrap();
+        assert!(v > 1.0 && v < 2.0);
+        let values = out.iter().flatten().copied().collect::<Vec<_>>();
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_interpolate_pchip_two_points_is_linear() {
+        let ca = Int32Chunked::new("", &[Some(1), None, None, Some(4)]);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Pchip);
+        let out = out.f64().unwrap();
+        assert_eq!(
+            Vec::from(out),
+            &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+        );
+    }
 }
@@ -8,9 +8,12 @@ mod dispersion;
 pub(crate) mod hash;
 mod min_max;
 mod namespace;
+mod pad;
 #[cfg(feature = "list_sets")]
 mod sets;
 mod sum_mean;
+#[cfg(feature = "dtype-array")]
+mod to_array;
 #[cfg(feature = "list_to_struct")]
 mod to_struct;
 
@@ -19,8 +22,11 @@ pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
 pub use namespace::*;
+pub use pad::*;
 #[cfg(feature = "list_sets")]
 pub use sets::*;
+#[cfg(feature = "dtype-array")]
+pub use to_array::*;
 #[cfg(feature = "list_to_struct")]
 pub use to_struct::*;
 
@@ -0,0 +1,172 @@
+use std::fmt::{Display, Formatter};
+
+use arrow::array::Array;
+#[cfg(feature = "dtype-array")]
+use arrow::array::FixedSizeListArray;
+use arrow::legacy::prelude::LargeListArray;
+use arrow::offset::{Offsets, OffsetsBuffer};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The side of a list at which elements are added or removed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PadSide {
+    Left,
+    #[default]
+    Right,
+}
+
+impl Display for PadSide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            PadSide::Left => "left",
+            PadSide::Right => "right",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Pad every sublist with `fill` until it has `length` elements.
+///
+/// Sublists that already have `length` or more elements are left untouched. If `as_array` is set,
+/// the output is an `Array(inner, length)` and sublists longer than `length` raise an error.
+/// Null sublists stay null.
+pub fn list_pad_to_length(
+    ca: &ListChunked,
+    length: usize,
+    side: PadSide,
+    fill: &Series,
+    as_array: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        fill.len() == 1,
+        ComputeError: "fill value of `list.pad_to_length` must be a scalar, got length {}", fill.len()
+    );
+    let fill = fill.cast(&ca.inner_dtype())?;
+    reshape_sublists(ca, length, side, &fill, as_array, true)
+}
+
+/// Truncate every sublist to at most `length` elements.
+///
+/// Elements are removed from the given `side` of the sublist. If `as_array` is set, the output is
+/// an `Array(inner, length)` and sublists shorter than `length` raise an error. Null sublists stay
+/// null.
+pub fn list_truncate(
+    ca: &ListChunked,
+    length: usize,
+    side: PadSide,
+    as_array: bool,
+) -> PolarsResult<Series> {
+    let null_fill = Series::full_null("", 1, &ca.inner_dtype());
+    reshape_sublists(ca, length, side, &null_fill, as_array, false)
+}
+
+/// Computes a single gather over the flat values of `ca`, extended with one `pad_value` element,
+/// and rebuilds the list (or fixed size list) array from the resulting offsets.
+fn reshape_sublists(
+    ca: &ListChunked,
+    length: usize,
+    side: PadSide,
+    pad_value: &Series,
+    as_array: bool,
+    pad: bool,
+) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let inner_dtype = ca.inner_dtype();
+
+    // SAFETY: the inner dtype is passed correctly.
+    let mut values = unsafe {
+        Series::from_chunks_and_dtype_unchecked("", vec![arr.values().clone()], &inner_dtype)
+    };
+    let pad_idx = values.len() as IdxSize;
+    values.append(pad_value)?;
+
+    let offsets = arr.offsets().as_slice();
+    let mut idx: Vec<IdxSize> = Vec::with_capacity(arr.len() * length);
+    let mut new_offsets: Vec<i64> = Vec::with_capacity(arr.len() + 1);
+    new_offsets.push(0);
+
+    for i in 0..arr.len() {
+        let start = offsets[i] as IdxSize;
+        let end = offsets[i + 1] as IdxSize;
+        let sub_len = (end - start) as usize;
+
+        if !arr.is_valid(i) {
+            // Fixed size lists still need `length` slots for a null row.
+            if as_array {
+                idx.extend(std::iter::repeat(pad_idx).take(length));
+            }
+        } else if pad {
+            polars_ensure!(
+                !as_array || sub_len <= length,
+                ComputeError: "cannot pad sublist of length {} at row {} to an array of width {}; \
+                use `list.truncate` first", sub_len, i, length
+            );
+            let n_pad = length.saturating_sub(sub_len);
+            match side {
+                PadSide::Left => {
+                    idx.extend(std::iter::repeat(pad_idx).take(n_pad));
+                    idx.extend(start..end);
+                },
+                PadSide::Right => {
+                    idx.extend(start..end);
+                    idx.extend(std::iter::repeat(pad_idx).take(n_pad));
+                },
+            }
+        } else {
+            polars_ensure!(
+                !as_array || sub_len >= length,
+                ComputeError: "cannot truncate sublist of length {} at row {} to an array of width {}; \
+                use `list.pad_to_length` first", sub_len, i, length
+            );
+            let keep = std::cmp::min(sub_len, length) as IdxSize;
+            match side {
+                PadSide::Left => idx.extend((end - keep)..end),
+                PadSide::Right => idx.extend(start..(start + keep)),
+            }
+        }
+        new_offsets.push(idx.len() as i64);
+    }
+
+    let idx = IdxCa::from_vec("", idx);
+    // SAFETY: all indices are within the bounds of `values`.
+    let new_values = unsafe { values.take_unchecked(&idx) };
+    let new_values = new_values.rechunk();
+    let new_values = new_values.chunks()[0].clone();
+    let validity = arr.validity().cloned();
+
+    if as_array {
+        #[cfg(feature = "dtype-array")]
+        {
+            let data_type =
+                FixedSizeListArray::default_datatype(new_values.data_type().clone(), length);
+            let new_arr = FixedSizeListArray::new(data_type, new_values, validity);
+            // SAFETY: the inner dtype is derived from the input list.
+            return Ok(unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    ca.name(),
+                    vec![Box::new(new_arr)],
+                    &DataType::Array(Box::new(inner_dtype), length),
+                )
+            });
+        }
+        #[cfg(not(feature = "dtype-array"))]
+        polars_bail!(InvalidOperation: "activate 'dtype-array' feature to output fixed size arrays")
+    }
+
+    // SAFETY: offsets are monotonically increasing and end at the length of the values.
+    let new_offsets: OffsetsBuffer<i64> = unsafe { Offsets::new_unchecked(new_offsets) }.into();
+    let data_type = LargeListArray::default_datatype(new_values.data_type().clone());
+    let new_arr = LargeListArray::new(data_type, new_offsets, new_values, validity);
+    // SAFETY: the inner dtype is derived from the input list.
+    Ok(unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            ca.name(),
+            vec![Box::new(new_arr)],
+            &DataType::List(Box::new(inner_dtype)),
+        )
+    })
+}
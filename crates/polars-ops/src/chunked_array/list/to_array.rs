@@ -0,0 +1,27 @@
+use arrow::array::FixedSizeListArray;
+use polars_core::prelude::*;
+
+/// Convert every list, e.g. the values of a group, to an array of `width` values without
+/// copying them. Every list must have exactly `width` values.
+pub fn lists_to_array(ca: &ListChunked, width: usize) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let offsets = arr.offsets();
+    for (i, w) in offsets.windows(2).enumerate() {
+        let len = (w[1] - w[0]) as usize;
+        polars_ensure!(
+            len == width,
+            ShapeMismatch: "`implode_to_array` expected groups of length {}, got length {} in group {}",
+            width, len, i
+        );
+    }
+    // The values of the lists are contiguous, so the arrays wrap them.
+    let values = arr
+        .values()
+        .sliced(*offsets.first() as usize, offsets.range() as usize);
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+    let arr = FixedSizeListArray::new(data_type, values, arr.validity().cloned());
+    let dtype = DataType::Array(Box::new(ca.inner_dtype()), width);
+    // SAFETY: the values have the physical type of the inner dtype.
+    Ok(unsafe { Series::from_chunks_and_dtype_unchecked(ca.name(), vec![arr.boxed()], &dtype) })
+}
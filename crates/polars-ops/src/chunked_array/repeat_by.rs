@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-array")]
+use arrow::array::FixedSizeListArray;
 use arrow::array::ListArray;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
@@ -134,3 +136,88 @@ pub fn repeat_by(s: &Series, by: &IdxCa) -> PolarsResult<ListChunked> {
         ca.apply_to_inner(&|s| unsafe { s.cast_unchecked(logical_type) })
     })
 }
+
+/// Repeat every value `n` times into an array of width `n`.
+///
+/// Unlike [`repeat_by`] the count is the same for every row, so the result is built with a
+/// single tiled gather of the values instead of offsets. Null values are repeated into an array
+/// of nulls.
+#[cfg(feature = "dtype-array")]
+pub fn repeat_fixed(s: &Series, n: usize) -> PolarsResult<ArrayChunked> {
+    polars_ensure!(
+        n > 0,
+        InvalidOperation: "`repeat_fixed` cannot create arrays of width 0; use `repeat_by` to create empty lists"
+    );
+    let s_phys = s.to_physical_repr();
+    let mut idx = Vec::with_capacity(s.len() * n);
+    for i in 0..s.len() as IdxSize {
+        idx.extend(std::iter::repeat(i).take(n));
+    }
+    let idx = IdxCa::from_vec("", idx);
+    // SAFETY: all indices are within the bounds of `s`.
+    let values = unsafe { s_phys.take_unchecked(&idx) }.rechunk();
+    let values = values.chunks()[0].clone();
+
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), n);
+    let arr = FixedSizeListArray::new(data_type, values, None);
+    // SAFETY: the inner dtype is the dtype of `s_phys`.
+    let out = unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            s.name(),
+            vec![Box::new(arr)],
+            &DataType::Array(Box::new(s_phys.dtype().clone()), n),
+        )
+    };
+    let logical_type = s.dtype();
+    out.array()?
+        .apply_to_inner(&|s| unsafe { s.cast_unchecked(logical_type) })
+}
+
+#[cfg(test)]
+#[cfg(feature = "dtype-array")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_repeat_fixed() -> PolarsResult<()> {
+        let n = 3;
+        let int = Series::new("a", [Some(1i32), None, Some(3)]);
+        let str = Series::new("a", [Some("x"), Some("y"), None]);
+        let bool = Series::new("a", [None, Some(true), Some(false)]);
+
+        #[cfg(feature = "dtype-date")]
+        let date = int.cast(&DataType::Date)?;
+        #[cfg(feature = "dtype-categorical")]
+        let cat = {
+            let _sc = polars_core::StringCacheHolder::hold();
+            str.cast(&DataType::Categorical(None, Default::default()))?
+        };
+
+        for s in [
+            int,
+            str,
+            bool,
+            #[cfg(feature = "dtype-date")]
+            date,
+            #[cfg(feature = "dtype-categorical")]
+            cat,
+        ] {
+            let fixed = repeat_fixed(&s, n)?;
+            assert_eq!(
+                fixed.dtype(),
+                &DataType::Array(Box::new(s.dtype().clone()), n)
+            );
+            assert_eq!(fixed.null_count(), 0);
+
+            let by = IdxCa::new("", [n as IdxSize]);
+            let list = repeat_by(&s, &by)?;
+            for i in 0..s.len() {
+                let (fixed, list) = (fixed.get_as_series(i), list.get_as_series(i));
+                assert!(fixed.unwrap().equals_missing(&list.unwrap()));
+            }
+        }
+
+        assert!(repeat_fixed(&Series::new("a", [1i32]), 0).is_err());
+        Ok(())
+    }
+}
@@ -486,6 +486,21 @@ pub trait StringNameSpaceImpl: AsString {
         split_helper(ca, by, str::split_inclusive)
     }
 
+    /// Split into at most `n` parts. The last part holds the remainder of the string.
+    fn splitn_list(&self, by: &StringChunked, n: usize) -> ListChunked {
+        let ca = self.as_string();
+
+        split_helper(ca, by, |s, by| s.splitn(n, by))
+    }
+
+    /// Split into at most `n` parts and keep the substring. The last part holds the remainder
+    /// of the string.
+    fn splitn_list_inclusive(&self, by: &StringChunked, n: usize) -> ListChunked {
+        let ca = self.as_string();
+
+        split_helper(ca, by, |s, by| SplitNInclusive::new(s, by, n))
+    }
+
     /// Extract each successive non-overlapping regex match in an individual string as an array.
     fn extract_all_many(&self, pat: &StringChunked) -> PolarsResult<ListChunked> {
         let ca = self.as_string();
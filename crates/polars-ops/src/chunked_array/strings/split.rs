@@ -112,3 +112,42 @@ where
         builder.finish()
     }
 }
+
+/// Like [`str::splitn`], but the pieces keep the separator they were split at, like
+/// [`str::split_inclusive`] does.
+pub struct SplitNInclusive<'a> {
+    s: &'a str,
+    by: &'a str,
+    n: usize,
+}
+
+impl<'a> SplitNInclusive<'a> {
+    pub fn new(s: &'a str, by: &'a str, n: usize) -> Self {
+        Self { s, by, n }
+    }
+}
+
+impl<'a> Iterator for SplitNInclusive<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 || self.s.is_empty() {
+            return None;
+        }
+        self.n -= 1;
+        let mut end = if self.n == 0 {
+            self.s.len()
+        } else {
+            self.s
+                .find(self.by)
+                .map_or(self.s.len(), |idx| idx + self.by.len())
+        };
+        // An empty separator splits off one character at a time.
+        if end == 0 {
+            end = self.s.chars().next().map_or(0, char::len_utf8);
+        }
+        let (piece, rest) = self.s.split_at(end);
+        self.s = rest;
+        Some(piece)
+    }
+}
@@ -207,22 +207,42 @@ pub fn top_k_by(
     by: &[Series],
     sort_options: SortMultipleOptions,
 ) -> PolarsResult<Series> {
-    let (k, src) = extract_target_and_k(s)?;
-
-    if src.is_empty() {
-        return Ok(src.clone());
-    }
-
     let multithreaded = sort_options.multithreaded;
 
-    let idx = _arg_bottom_k(k, by, &mut sort_options.with_order_reversed())?;
+    let idx = arg_top_k_by(s, by, sort_options)?;
+    let src = &s[0];
 
     let result = unsafe {
         if multithreaded {
-            src.take_unchecked_threaded(&idx.into_inner(), false)
+            src.take_unchecked_threaded(&idx, false)
         } else {
-            src.take_unchecked(&idx.into_inner())
+            src.take_unchecked(&idx)
         }
     };
     Ok(result)
 }
+
+/// Get the indices of the `k` rows of `s[0]` with the largest values in `by`, in order. `k` is
+/// taken from `s[1]`.
+///
+/// With `maintain_order` set, ties are resolved in favor of the earlier rows.
+pub fn arg_top_k_by(
+    s: &[Series],
+    by: &[Series],
+    sort_options: SortMultipleOptions,
+) -> PolarsResult<IdxCa> {
+    let (k, src) = extract_target_and_k(s)?;
+
+    polars_ensure!(!by.is_empty(), ComputeError: "`top_k_by` needs at least one `by` column");
+    polars_ensure!(
+        by.iter().all(|s| s.len() == src.len()),
+        ShapeMismatch: "`by` columns of `top_k_by` must have the same length as the input"
+    );
+    if src.is_empty() {
+        return Ok(IdxCa::from_vec(src.name(), vec![]));
+    }
+
+    let mut idx = _arg_bottom_k(k, by, &mut sort_options.with_order_reversed())?.into_inner();
+    idx.rename(src.name());
+    Ok(idx)
+}
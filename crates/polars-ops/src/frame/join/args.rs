@@ -26,6 +26,8 @@ pub struct JoinArgs {
     pub suffix: Option<String>,
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strategy_hint: Option<JoinStrategyHint>,
 }
 
 impl Default for JoinArgs {
@@ -36,6 +38,7 @@ impl Default for JoinArgs {
             suffix: None,
             slice: None,
             join_nulls: false,
+            strategy_hint: None,
         }
     }
 }
@@ -48,6 +51,7 @@ impl JoinArgs {
             suffix: None,
             slice: None,
             join_nulls: false,
+            strategy_hint: None,
         }
     }
 
@@ -85,6 +89,33 @@ impl JoinType {
     }
 }
 
+/// Force the algorithm of an equi-join. The hint is honored when it is feasible, otherwise the
+/// default strategy is used and a message is printed in verbose mode.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinStrategyHint {
+    /// Always use a hash join, even if the keys are sorted.
+    Hash,
+    /// Use a sorted merge join. Requires numeric keys without nulls, of which at least one
+    /// side is sorted.
+    SortMerge,
+    /// Build the hash table on the right side, which is expected to be small. The streaming
+    /// engine only honors this if the estimated size of the right side is below
+    /// `POLARS_JOIN_BROADCAST_MAX_BYTES`.
+    Broadcast,
+}
+
+impl Display for JoinStrategyHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            JoinStrategyHint::Hash => "hash",
+            JoinStrategyHint::SortMerge => "sort_merge",
+            JoinStrategyHint::Broadcast => "broadcast",
+        };
+        write!(f, "{val}")
+    }
+}
+
 impl From<JoinType> for JoinArgs {
     fn from(value: JoinType) -> Self {
         JoinArgs::new(value)
@@ -199,7 +199,14 @@ pub trait JoinDispatch: IntoDf {
             s_right = s_right.rechunk();
         }
 
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose, args.validation, args.join_nulls)?;
+        let ids = sort_or_hash_left(
+            &s_left,
+            &s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            args.strategy_hint,
+        )?;
         let right = if let Some(drop_names) = drop_names {
             right.drop_many(drop_names)
         } else {
@@ -123,6 +123,8 @@ pub trait SeriesJoin: SeriesSealed + Sized {
         other: &Series,
         validate: JoinValidation,
         join_nulls: bool,
+        // If false, the hash table is always built on `other`.
+        build_shortest_table: bool,
     ) -> PolarsResult<(InnerJoinIds, bool)> {
         let s_self = self.as_series();
         let (lhs, rhs) = (s_self.to_physical_repr(), other.to_physical_repr());
@@ -135,7 +137,8 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = rhs.cast(&Binary).unwrap();
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryType>(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) =
+                    prepare_binary::<BinaryType>(lhs, rhs, build_shortest_table);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -147,7 +150,8 @@ pub trait SeriesJoin: SeriesSealed + Sized {
             BinaryOffset => {
                 let lhs = lhs.binary_offset().unwrap();
                 let rhs = rhs.binary_offset()?;
-                let (lhs, rhs, swapped, _) = prepare_binary::<BinaryOffsetType>(lhs, rhs, true);
+                let (lhs, rhs, swapped, _) =
+                    prepare_binary::<BinaryOffsetType>(lhs, rhs, build_shortest_table);
                 // Take slices so that vecs are not copied
                 let lhs = lhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
                 let rhs = rhs.iter().map(|k| k.as_slice()).collect::<Vec<_>>();
@@ -161,16 +165,28 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                     with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                         let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
                         let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
-                        group_join_inner::<$T>(lhs, rhs, validate, join_nulls)
+                        group_join_inner::<$T>(lhs, rhs, validate, join_nulls, build_shortest_table)
                     })
                 } else if s_self.bit_repr_is_large() {
                     let lhs = s_self.bit_repr_large();
                     let rhs = other.bit_repr_large();
-                    group_join_inner::<UInt64Type>(&lhs, &rhs, validate, join_nulls)
+                    group_join_inner::<UInt64Type>(
+                        &lhs,
+                        &rhs,
+                        validate,
+                        join_nulls,
+                        build_shortest_table,
+                    )
                 } else {
                     let lhs = s_self.bit_repr_small();
                     let rhs = other.bit_repr_small();
-                    group_join_inner::<UInt32Type>(&lhs, &rhs, validate, join_nulls)
+                    group_join_inner::<UInt32Type>(
+                        &lhs,
+                        &rhs,
+                        validate,
+                        join_nulls,
+                        build_shortest_table,
+                    )
                 }
             },
         }
@@ -250,6 +266,7 @@ fn group_join_inner<T>(
     right: &ChunkedArray<T>,
     validate: JoinValidation,
     join_nulls: bool,
+    build_shortest_table: bool,
 ) -> PolarsResult<(InnerJoinIds, bool)>
 where
     T: PolarsDataType,
@@ -260,7 +277,11 @@ where
         Send + Sync + Copy + Hash + Eq + DirtyHash + IsNull,
 {
     let n_threads = POOL.current_num_threads();
-    let (a, b, swapped) = det_hash_prone_order!(left, right);
+    let (a, b, swapped) = if build_shortest_table {
+        det_hash_prone_order!(left, right)
+    } else {
+        (left, right, false)
+    };
     let splitted_a = split_ca(a, n_threads).unwrap();
     let splitted_b = split_ca(b, n_threads).unwrap();
     let splitted_a = get_arrays(&splitted_a);
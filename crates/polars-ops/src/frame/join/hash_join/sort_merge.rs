@@ -171,6 +171,33 @@ fn create_reverse_map_from_arg_sort(mut arg_sort: IdxCa) -> Vec<IdxSize> {
     primitive_to_vec::<IdxSize>(arr).unwrap()
 }
 
+/// The maximum relative size of the side we are willing to sort for a sorted merge join.
+/// A sort-merge hint accepts sorting a side of any size.
+#[cfg(feature = "performant")]
+fn sort_size_factor_acceptable(hint: Option<JoinStrategyHint>) -> f32 {
+    if hint == Some(JoinStrategyHint::SortMerge) {
+        return f32::INFINITY;
+    }
+    std::env::var("POLARS_JOIN_SORT_FACTOR")
+        .map(|s| s.parse::<f32>().unwrap())
+        .unwrap_or(1.0)
+}
+
+#[cfg(feature = "performant")]
+fn warn_sort_merge_hint_ignored(hint: Option<JoinStrategyHint>, verbose: bool, how: &str) {
+    if verbose && hint == Some(JoinStrategyHint::SortMerge) {
+        eprintln!(
+            "{how} join: cannot honor the 'sort_merge' hint, it requires numeric keys without \
+            nulls of which one is sorted and no join validation: use hash join"
+        )
+    }
+}
+
+/// A broadcast hint builds the hash table on the right side instead of on the shortest one.
+fn build_shortest_table(hint: Option<JoinStrategyHint>) -> bool {
+    hint != Some(JoinStrategyHint::Broadcast)
+}
+
 #[cfg(not(feature = "performant"))]
 pub(crate) fn _sort_or_hash_inner(
     s_left: &Series,
@@ -178,8 +205,9 @@ pub(crate) fn _sort_or_hash_inner(
     _verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hint: Option<JoinStrategyHint>,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
-    s_left.hash_join_inner(s_right, validate, join_nulls)
+    s_left.hash_join_inner(s_right, validate, join_nulls, build_shortest_table(hint))
 }
 
 #[cfg(feature = "performant")]
@@ -189,6 +217,7 @@ pub(crate) fn _sort_or_hash_inner(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hint: Option<JoinStrategyHint>,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
@@ -196,13 +225,20 @@ pub(crate) fn _sort_or_hash_inner(
     // the `arg_sort` indices to revert the sort once the join keys are determined.
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
     let size_factor_lhs = s_left.len() as f32 / s_right.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = sort_size_factor_acceptable(hint);
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
-    if validate.needs_checks() {
-        return s_left.hash_join_inner(s_right, validate, join_nulls);
+    if validate.needs_checks()
+        || matches!(
+            hint,
+            Some(JoinStrategyHint::Hash | JoinStrategyHint::Broadcast)
+        )
+    {
+        warn_sort_merge_hint_ignored(hint, verbose, "inner");
+        if verbose && hint == Some(JoinStrategyHint::Broadcast) {
+            eprintln!("inner join: broadcast hint: build the hash table on the right side");
+        }
+        return s_left.hash_join_inner(s_right, validate, join_nulls, build_shortest_table(hint));
     }
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
@@ -268,7 +304,10 @@ pub(crate) fn _sort_or_hash_inner(
             // set sorted to `false` as we descending sorted the left key.
             Ok(((left, right), false))
         },
-        _ => s_left.hash_join_inner(s_right, validate, join_nulls),
+        _ => {
+            warn_sort_merge_hint_ignored(hint, verbose, "inner");
+            s_left.hash_join_inner(s_right, validate, join_nulls, true)
+        },
     }
 }
 
@@ -279,6 +318,7 @@ pub(crate) fn sort_or_hash_left(
     _verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    _hint: Option<JoinStrategyHint>,
 ) -> PolarsResult<LeftJoinIds> {
     s_left.hash_join_left(s_right, validate, join_nulls)
 }
@@ -290,15 +330,21 @@ pub(crate) fn sort_or_hash_left(
     verbose: bool,
     validate: JoinValidation,
     join_nulls: bool,
+    hint: Option<JoinStrategyHint>,
 ) -> PolarsResult<LeftJoinIds> {
-    if validate.needs_checks() {
+    // The left join always builds the hash table on the right side, as a broadcast hint asks.
+    if validate.needs_checks()
+        || matches!(
+            hint,
+            Some(JoinStrategyHint::Hash | JoinStrategyHint::Broadcast)
+        )
+    {
+        warn_sort_merge_hint_ignored(hint, verbose, "left");
         return s_left.hash_join_left(s_right, validate, join_nulls);
     }
 
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = sort_size_factor_acceptable(hint);
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
@@ -343,6 +389,9 @@ pub(crate) fn sort_or_hash_left(
             Ok(to_left_join_ids(left, right))
         },
         // don't reverse sort a left join key yet. Have to figure out how to set sorted flag
-        _ => s_left.hash_join_left(s_right, validate, join_nulls),
+        _ => {
+            warn_sort_merge_hint_ignored(hint, verbose, "left");
+            s_left.hash_join_left(s_right, validate, join_nulls)
+        },
     }
 }
@@ -1,4 +1,5 @@
 use arrow::legacy::utils::{CustomIterTools, FromTrustedLenIterator};
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 
@@ -28,6 +29,63 @@ pub fn _merge_sorted_dfs(
     }
 
     let merge_indicator = series_to_merge_indicator(left_s, right_s);
+    Ok(merge_dfs(left, right, &merge_indicator))
+}
+
+/// Merge two [`DataFrame`]s that are both sorted by the `left_keys`/`right_keys` columns.
+///
+/// Rows are compared lexicographically on the keys, where every key can be sorted in
+/// `descending` order. Nulls are ordered first, as in the default sort. Equal rows are taken
+/// from `left` first.
+pub fn _merge_sorted_dfs_by(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_keys: &[Series],
+    right_keys: &[Series],
+    descending: &[bool],
+    check_schema: bool,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        !left_keys.is_empty() && left_keys.len() == right_keys.len(),
+        ComputeError: "merge-sort expected the same non-zero number of keys on both sides, got {} and {}",
+        left_keys.len(), right_keys.len()
+    );
+    polars_ensure!(
+        descending.len() == left_keys.len(),
+        ComputeError: "merge-sort expected {} `descending` flags, got {}",
+        left_keys.len(), descending.len()
+    );
+
+    // A single ascending key doesn't need the row encoding.
+    if left_keys.len() == 1 && !descending[0] {
+        return _merge_sorted_dfs(left, right, &left_keys[0], &right_keys[0], check_schema);
+    }
+
+    if check_schema {
+        left.schema_equal(right)?;
+    }
+    for (lhs, rhs) in left_keys.iter().zip(right_keys) {
+        polars_ensure!(
+            lhs.dtype() == rhs.dtype(),
+            ComputeError: "merge-sort datatype mismatch: {} != {}", lhs.dtype(), rhs.dtype()
+        );
+    }
+
+    // If one frame is empty, we can return the other immediately.
+    if right.height() == 0 {
+        return Ok(left.clone());
+    } else if left.height() == 0 {
+        return Ok(right.clone());
+    }
+
+    // The row encoding orders lexicographically, so we can compare the rows bytewise.
+    let lhs = _get_rows_encoded_ca("", left_keys, descending, false)?;
+    let rhs = _get_rows_encoded_ca("", right_keys, descending, false)?;
+    let merge_indicator = get_merge_indicator(lhs.into_no_null_iter(), rhs.into_no_null_iter());
+    Ok(merge_dfs(left, right, &merge_indicator))
+}
+
+fn merge_dfs(left: &DataFrame, right: &DataFrame, merge_indicator: &[bool]) -> DataFrame {
     let new_columns = left
         .get_columns()
         .iter()
@@ -36,14 +94,14 @@ pub fn _merge_sorted_dfs(
             let lhs_phys = lhs.to_physical_repr();
             let rhs_phys = rhs.to_physical_repr();
 
-            let out = merge_series(&lhs_phys, &rhs_phys, &merge_indicator);
+            let out = merge_series(&lhs_phys, &rhs_phys, merge_indicator);
             let mut out = out.cast(lhs.dtype()).unwrap();
             out.rename(lhs.name());
             out
         })
         .collect();
 
-    Ok(unsafe { DataFrame::new_no_checks(new_columns) })
+    unsafe { DataFrame::new_no_checks(new_columns) }
 }
 
 fn merge_series(lhs: &Series, rhs: &Series, merge_indicator: &[bool]) -> Series {
@@ -253,3 +311,58 @@ fn test_merge_sorted() {
     let expected = [true, true, true, false, false, false, false];
     assert_eq!(out, expected);
 }
+
+#[test]
+fn test_merge_sorted_by_multiple_keys() -> PolarsResult<()> {
+    let left = df![
+        "exchange" => [Some("a"), Some("a"), Some("a"), Some("b")],
+        "ts" => [1, 3, 3, 2],
+        "side" => ["l", "l", "l", "l"],
+    ]?;
+    let right = df![
+        "exchange" => [None, Some("a"), Some("a"), Some("b"), Some("b")],
+        "ts" => [9, 2, 3, 1, 2],
+        "side" => ["r", "r", "r", "r", "r"],
+    ]?;
+    let keys = |df: &DataFrame| df.get_columns()[..2].to_vec();
+
+    let out = _merge_sorted_dfs_by(
+        &left,
+        &right,
+        &keys(&left),
+        &keys(&right),
+        &[false, false],
+        true,
+    )?;
+    let expected = df![
+        "exchange" => [None, Some("a"), Some("a"), Some("a"), Some("a"), Some("a"), Some("b"), Some("b"), Some("b")],
+        "ts" => [9, 1, 2, 3, 3, 3, 1, 2, 2],
+        "side" => ["r", "l", "r", "l", "l", "r", "r", "l", "r"],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Descending on the second key.
+    let left = df![
+        "k" => [1, 1, 2],
+        "ts" => [5, 1, 3],
+    ]?;
+    let right = df![
+        "k" => [1, 2, 2],
+        "ts" => [3, 4, 3],
+    ]?;
+    let out = _merge_sorted_dfs_by(
+        &left,
+        &right,
+        &keys(&left),
+        &keys(&right),
+        &[false, true],
+        true,
+    )?;
+    let expected = df![
+        "k" => [1, 1, 1, 2, 2, 2],
+        "ts" => [5, 3, 1, 4, 3, 3],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
@@ -29,7 +29,7 @@ pub use general::{_coalesce_outer_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
 #[cfg(feature = "merge_sorted")]
-pub use merge_sorted::_merge_sorted_dfs;
+pub use merge_sorted::{_merge_sorted_dfs, _merge_sorted_dfs_by};
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
 #[allow(unused_imports)]
 use polars_core::prelude::sort::arg_sort_multiple::{
@@ -433,8 +433,14 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         let left_df = self.to_df();
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
-        let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.join_nulls)?;
+        let ((join_tuples_left, join_tuples_right), sorted) = _sort_or_hash_inner(
+            s_left,
+            s_right,
+            verbose,
+            args.validation,
+            args.join_nulls,
+            args.strategy_hint,
+        )?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
@@ -489,5 +495,5 @@ pub fn private_left_join_multiple_keys(
 ) -> PolarsResult<LeftJoinIds> {
     let a = prepare_keys_multiple(a.get_columns(), join_nulls)?.into_series();
     let b = prepare_keys_multiple(b.get_columns(), join_nulls)?.into_series();
-    sort_or_hash_left(&a, &b, false, JoinValidation::ManyToMany, join_nulls)
+    sort_or_hash_left(&a, &b, false, JoinValidation::ManyToMany, join_nulls, None)
 }
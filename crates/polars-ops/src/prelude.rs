@@ -2,8 +2,8 @@
 pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 
 pub use crate::chunked_array::*;
-#[cfg(feature = "merge_sorted")]
-pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
+#[cfg(feature = "merge_sorted")]
+pub use crate::frame::{_merge_sorted_dfs, _merge_sorted_dfs_by};
 pub use crate::series::*;
@@ -1,15 +1,36 @@
 use polars_core::prelude::*;
 
+use super::negate::ensure_no_signed_min;
+
 /// Convert numerical values to their absolute value.
+///
+/// Errors instead of wrapping around if a signed integer column contains the minimum value of
+/// its type.
 pub fn abs(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         #[cfg(feature = "dtype-i8")]
-        Int8 => s.i8().unwrap().wrapping_abs().into_series(),
+        Int8 => {
+            let ca = s.i8().unwrap();
+            ensure_no_signed_min(ca, "abs")?;
+            ca.wrapping_abs().into_series()
+        },
         #[cfg(feature = "dtype-i16")]
-        Int16 => s.i16().unwrap().wrapping_abs().into_series(),
-        Int32 => s.i32().unwrap().wrapping_abs().into_series(),
-        Int64 => s.i64().unwrap().wrapping_abs().into_series(),
+        Int16 => {
+            let ca = s.i16().unwrap();
+            ensure_no_signed_min(ca, "abs")?;
+            ca.wrapping_abs().into_series()
+        },
+        Int32 => {
+            let ca = s.i32().unwrap();
+            ensure_no_signed_min(ca, "abs")?;
+            ca.wrapping_abs().into_series()
+        },
+        Int64 => {
+            let ca = s.i64().unwrap();
+            ensure_no_signed_min(ca, "abs")?;
+            ca.wrapping_abs().into_series()
+        },
         Float32 => s.f32().unwrap().wrapping_abs().into_series(),
         Float64 => s.f64().unwrap().wrapping_abs().into_series(),
         #[cfg(feature = "dtype-decimal")]
@@ -22,20 +22,35 @@ use std::marker::PhantomData;
 
 use polars_core::export::ahash::RandomState;
 
-/// The greater is P, the smaller the error.
-const HLL_P: usize = 14_usize;
-/// The number of bits of the hash value used determining the number of leading zeros
-const HLL_Q: usize = 64_usize - HLL_P;
-const NUM_REGISTERS: usize = 1_usize << HLL_P;
-/// Mask to obtain index into the registers
-const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
+/// The number of hash bits used to index the registers (P), used by [`HyperLogLog::new`].
+///
+/// The greater is P, the smaller the error. The relative standard error of the estimate is about
+/// `1.04 / sqrt(2^P)` and the sketch uses `2^P` bytes, so every increment of P doubles the memory
+/// and reduces the error by a factor `sqrt(2)`:
+///
+/// | P  | registers | standard error |
+/// |----|-----------|----------------|
+/// | 4  | 16        | 26%            |
+/// | 8  | 256       | 6.5%           |
+/// | 12 | 4096      | 1.6%           |
+/// | 14 | 16384     | 0.81%          |
+/// | 16 | 65536     | 0.41%          |
+/// | 18 | 262144    | 0.20%          |
+pub const HLL_DEFAULT_PRECISION: u8 = 14;
+/// The smallest supported precision.
+pub const HLL_MIN_PRECISION: u8 = 4;
+/// The largest supported precision.
+pub const HLL_MAX_PRECISION: u8 = 18;
+/// The size of the register histogram, large enough for the smallest precision.
+const HISTOGRAM_LEN: usize = 64 - HLL_MIN_PRECISION as usize + 2;
 
 #[derive(Clone, Debug)]
 pub struct HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    registers: [u8; NUM_REGISTERS],
+    registers: Box<[u8]>,
+    precision: u8,
     phantom: PhantomData<T>,
 }
 
@@ -65,22 +80,46 @@ impl<T> HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
-    /// Creates a new, empty HyperLogLog.
+    /// Creates a new, empty HyperLogLog with [`HLL_DEFAULT_PRECISION`].
     pub fn new() -> Self {
-        let registers = [0; NUM_REGISTERS];
-        Self::new_with_registers(registers)
+        Self::with_precision(HLL_DEFAULT_PRECISION)
+    }
+
+    /// Creates a new, empty HyperLogLog that uses `2^precision` registers.
+    ///
+    /// See [`HLL_DEFAULT_PRECISION`] for the expected error per precision.
+    ///
+    /// # Panics
+    /// Panics if `precision` is not in `HLL_MIN_PRECISION..=HLL_MAX_PRECISION`.
+    pub fn with_precision(precision: u8) -> Self {
+        assert!(
+            (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+            "precision must be between {} and {}, got {}",
+            HLL_MIN_PRECISION,
+            HLL_MAX_PRECISION,
+            precision
+        );
+        let registers = vec![0; 1 << precision].into_boxed_slice();
+        Self::new_with_registers(registers, precision)
     }
 
     /// Creates a HyperLogLog from already populated registers
     /// note that this method should not be invoked in untrusted environment
     /// because the internal structure of registers are not examined.
-    pub(crate) fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
+    pub(crate) fn new_with_registers(registers: Box<[u8]>, precision: u8) -> Self {
+        debug_assert_eq!(registers.len(), 1 << precision);
         Self {
             registers,
+            precision,
             phantom: PhantomData,
         }
     }
 
+    /// The number of hash bits used to index the registers.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
     /// choice of hash function: ahash is already an dependency
     /// and it fits the requirements of being a 64bit hash with
     /// reasonable performance.
@@ -92,18 +131,25 @@ where
     /// Adds an element to the HyperLogLog.
     pub fn add(&mut self, obj: &T) {
         let hash = self.hash_value(obj);
-        let index = (hash & HLL_P_MASK) as usize;
-        let p = ((hash >> HLL_P) | (1_u64 << HLL_Q)).trailing_zeros() + 1;
+        let mask = (self.registers.len() as u64) - 1;
+        let index = (hash & mask) as usize;
+        let p = ((hash >> self.precision) | (1_u64 << self.q())).trailing_zeros() + 1;
         self.registers[index] = self.registers[index].max(p as u8);
     }
 
+    /// The number of bits of the hash value used determining the number of leading zeros
+    #[inline]
+    fn q(&self) -> usize {
+        64 - self.precision as usize
+    }
+
     /// Get the register histogram (each value in register index into
-    /// the histogram; u32 is enough because we only have 2**14=16384 registers
+    /// the histogram; u32 is enough because we have at most 2**18 registers
     #[inline]
-    fn get_histogram(&self) -> [u32; HLL_Q + 2] {
-        let mut histogram = [0; HLL_Q + 2];
+    fn get_histogram(&self) -> [u32; HISTOGRAM_LEN] {
+        let mut histogram = [0; HISTOGRAM_LEN];
         // hopefully this can be unrolled
-        for r in self.registers {
+        for &r in self.registers.iter() {
             histogram[r as usize] += 1;
         }
         histogram
@@ -112,7 +158,7 @@ where
     /// Merge the other [`HyperLogLog`] into this one
     pub fn merge(&mut self, other: &HyperLogLog<T>) {
         assert!(
-            self.registers.len() == other.registers.len(),
+            self.precision == other.precision,
             "unexpected got unequal register size, expect {}, got {}",
             self.registers.len(),
             other.registers.len()
@@ -125,9 +171,10 @@ where
     /// Guess the number of unique elements seen by the HyperLogLog.
     pub fn count(&self) -> usize {
         let histogram = self.get_histogram();
-        let m = NUM_REGISTERS as f64;
-        let mut z = m * hll_tau((m - histogram[HLL_Q + 1] as f64) / m);
-        for i in histogram[1..=HLL_Q].iter().rev() {
+        let q = self.q();
+        let m = self.registers.len() as f64;
+        let mut z = m * hll_tau((m - histogram[q + 1] as f64) / m);
+        for i in histogram[1..=q].iter().rev() {
             z += *i as f64;
             z *= 0.5;
         }
@@ -217,16 +264,20 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{HyperLogLog, NUM_REGISTERS};
+    use super::{HyperLogLog, HLL_DEFAULT_PRECISION, HLL_MAX_PRECISION, HLL_MIN_PRECISION};
 
     fn compare_with_delta(got: usize, expected: usize) {
+        compare_with_delta_for_precision(got, expected, HLL_DEFAULT_PRECISION)
+    }
+
+    fn compare_with_delta_for_precision(got: usize, expected: usize, precision: u8) {
         let expected = expected as f64;
         let diff = (got as f64) - expected;
         let diff = diff.abs() / expected;
         // times 6 because we want the tests to be stable
         // so we allow a rather large margin of error
         // this is adopted from redis's unit test version as well
-        let margin = 1.04 / ((NUM_REGISTERS as f64).sqrt()) * 6.0;
+        let margin = 1.04 / (((1 << precision) as f64).sqrt()) * 6.0;
         assert!(
             diff <= margin,
             "{} is not near {} percent of {} which is ({}, {})",
@@ -350,4 +401,37 @@ mod tests {
         }
         compare_with_delta(hll.count(), 1000);
     }
+
+    #[test]
+    fn test_default_precision() {
+        let mut hll = HyperLogLog::<u64>::new();
+        let mut explicit = HyperLogLog::<u64>::with_precision(HLL_DEFAULT_PRECISION);
+        hll.extend(0..10_000);
+        explicit.extend(0..10_000);
+        assert_eq!(hll.precision(), 14);
+        assert_eq!(hll.as_ref().len(), 1 << 14);
+        assert_eq!(hll.count(), explicit.count());
+    }
+
+    #[test]
+    fn test_precision() {
+        let n = 100_000;
+        let relative_error = |precision| {
+            let mut hll = HyperLogLog::<u64>::with_precision(precision);
+            hll.extend(0..n as u64);
+            compare_with_delta_for_precision(hll.count(), n, precision);
+            (hll.count() as f64 - n as f64).abs() / n as f64
+        };
+        for precision in HLL_MIN_PRECISION..=HLL_MAX_PRECISION {
+            relative_error(precision);
+        }
+        assert!(relative_error(HLL_MAX_PRECISION) < relative_error(HLL_MIN_PRECISION));
+        assert!(relative_error(16) < relative_error(8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_precision_out_of_range() {
+        HyperLogLog::<u64>::with_precision(HLL_MAX_PRECISION + 1);
+    }
 }
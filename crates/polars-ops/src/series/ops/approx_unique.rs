@@ -5,41 +5,45 @@ use polars_core::with_match_physical_integer_polars_type;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
 
 #[cfg(feature = "approx_unique")]
-use crate::series::ops::approx_algo::HyperLogLog;
+use crate::series::ops::approx_algo::{
+    HyperLogLog, HLL_DEFAULT_PRECISION, HLL_MAX_PRECISION, HLL_MIN_PRECISION,
+};
 
-fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>) -> PolarsResult<Series>
+fn approx_n_unique_ca<'a, T>(ca: &'a ChunkedArray<T>, precision: u8) -> PolarsResult<Series>
 where
     T: PolarsDataType,
     T::Physical<'a>: TotalHash + TotalEq + Copy + ToTotalOrd,
     <Option<T::Physical<'a>> as ToTotalOrd>::TotalOrdItem: Hash + Eq,
 {
-    let mut hllp = HyperLogLog::new();
+    let mut hllp = HyperLogLog::with_precision(precision);
     ca.iter().for_each(|item| hllp.add(&item.to_total_ord()));
     let c = hllp.count() as IdxSize;
 
     Ok(Series::new(ca.name(), &[c]))
 }
 
-fn dispatcher(s: &Series) -> PolarsResult<Series> {
+fn dispatcher(s: &Series, precision: u8) -> PolarsResult<Series> {
     let s = s.to_physical_repr();
     use DataType::*;
     match s.dtype() {
-        Boolean => s.bool().and_then(approx_n_unique_ca),
-        Binary => s.binary().and_then(approx_n_unique_ca),
+        Boolean => approx_n_unique_ca(s.bool()?, precision),
+        Binary => approx_n_unique_ca(s.binary()?, precision),
         String => {
             let ca = s.str().unwrap().as_binary();
-            approx_n_unique_ca(&ca)
+            approx_n_unique_ca(&ca, precision)
         },
-        Float32 => approx_n_unique_ca(AsRef::<ChunkedArray<Float32Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
-        Float64 => approx_n_unique_ca(AsRef::<ChunkedArray<Float64Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
+        Float32 => approx_n_unique_ca(
+            AsRef::<ChunkedArray<Float32Type>>::as_ref(s.as_ref().as_ref()),
+            precision,
+        ),
+        Float64 => approx_n_unique_ca(
+            AsRef::<ChunkedArray<Float64Type>>::as_ref(s.as_ref().as_ref()),
+            precision,
+        ),
         dt if dt.is_numeric() => {
             with_match_physical_integer_polars_type!(s.dtype(), |$T| {
                 let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                approx_n_unique_ca(ca)
+                approx_n_unique_ca(ca, precision)
             })
         },
         dt => polars_bail!(opq = approx_n_unique, dt),
@@ -74,5 +78,18 @@ fn dispatcher(s: &Series) -> PolarsResult<Series> {
 /// ]
 /// ```
 pub fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    dispatcher(s)
+    dispatcher(s, HLL_DEFAULT_PRECISION)
+}
+
+/// Approx count unique values with a HyperLogLog sketch of `2^precision` registers.
+///
+/// A higher precision uses more memory but gives a more accurate estimate, see
+/// [`HLL_DEFAULT_PRECISION`] for the expected error per precision.
+pub fn approx_n_unique_with_precision(s: &Series, precision: u8) -> PolarsResult<Series> {
+    polars_ensure!(
+        (HLL_MIN_PRECISION..=HLL_MAX_PRECISION).contains(&precision),
+        InvalidOperation: "`approx_n_unique` precision must be between {} and {}, got {}",
+        HLL_MIN_PRECISION, HLL_MAX_PRECISION, precision
+    );
+    dispatcher(s, precision)
 }
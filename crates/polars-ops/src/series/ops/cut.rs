@@ -6,15 +6,10 @@ fn map_cats(
     sorted_breaks: &[f64],
     left_closed: bool,
     include_breaks: bool,
+    index_only: bool,
 ) -> PolarsResult<Series> {
     let out_name = format!("{}_bin", s.name());
 
-    // Create new categorical and pre-register labels for consistent categorical indexes.
-    let mut bld = CategoricalChunkedBuilder::new(&out_name, s.len(), Default::default());
-    for label in labels {
-        bld.register_value(label);
-    }
-
     let s2 = s.cast(&DataType::Float64)?;
     // It would be nice to parallelize this
     let s_iter = s2.f64()?.into_iter();
@@ -25,6 +20,33 @@ fn map_cats(
         PartialOrd::gt
     };
 
+    if index_only {
+        let mut idx: UInt32Chunked = s_iter
+            .map(|opt| {
+                opt.filter(|x| !x.is_nan())
+                    .map(|x| sorted_breaks.partition_point(|v| op(&x, v)) as u32)
+            })
+            .collect();
+        idx.rename(&out_name);
+        if include_breaks {
+            let right_ends = [sorted_breaks, &[f64::INFINITY]].concat();
+            let mut brk_vals: Float64Chunked = idx
+                .into_iter()
+                .map(|idx| idx.map(|idx| right_ends[idx as usize]))
+                .collect();
+            brk_vals.rename("brk");
+            let outvals = vec![brk_vals.into_series(), idx.into_series()];
+            return Ok(StructChunked::new(&out_name, &outvals)?.into_series());
+        }
+        return Ok(idx.into_series());
+    }
+
+    // Create new categorical and pre-register labels for consistent categorical indexes.
+    let mut bld = CategoricalChunkedBuilder::new(&out_name, s.len(), Default::default());
+    for label in labels {
+        bld.register_value(label);
+    }
+
     if include_breaks {
         // This is to replicate the behavior of the old buggy version that only worked on series and
         // returned a dataframe. That included a column of the right endpoint of the interval. So we
@@ -79,12 +101,19 @@ pub fn compute_labels(breaks: &[f64], left_closed: bool) -> PolarsResult<Vec<Str
     Ok(ret)
 }
 
+/// Bin the values of `s` into the intervals between `breaks`.
+///
+/// The first interval is unbounded on the left, so values below the first break are in the
+/// first bin. With `index_only` the zero-based index of the bin is returned as
+/// [`DataType::UInt32`] instead of a categorical label, and `labels` are ignored. Null and NaN
+/// values map to null.
 pub fn cut(
     s: &Series,
     mut breaks: Vec<f64>,
     labels: Option<Vec<String>>,
     left_closed: bool,
     include_breaks: bool,
+    index_only: bool,
 ) -> PolarsResult<Series> {
     // Breaks must be sorted to cut inputs properly.
     polars_ensure!(!breaks.iter().any(|x| x.is_nan()), ComputeError: "breaks cannot be NaN");
@@ -102,9 +131,19 @@ pub fn cut(
     } else {
         compute_labels(&breaks, left_closed)?
     };
-    map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
+    map_cats(
+        s,
+        &cut_labels,
+        &breaks,
+        left_closed,
+        include_breaks,
+        index_only,
+    )
 }
 
+/// Bin the values of `s` into intervals between the quantiles `probs`.
+///
+/// See [`cut`] for how the values are assigned to bins and for `index_only`.
 pub fn qcut(
     s: &Series,
     probs: Vec<f64>,
@@ -112,6 +151,7 @@ pub fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    index_only: bool,
 ) -> PolarsResult<Series> {
     polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
 
@@ -121,7 +161,7 @@ pub fn qcut(
 
     if ca.null_count() == ca.len() {
         // If we only have nulls we don't have any breakpoints.
-        return cut(&s, vec![], labels, left_closed, include_breaks);
+        return cut(&s, vec![], labels, left_closed, include_breaks, index_only);
     }
 
     let f = |&p| {
@@ -143,5 +183,12 @@ pub fn qcut(
         compute_labels(&qbreaks, left_closed)?
     };
 
-    map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
+    map_cats(
+        &s,
+        &cut_labels,
+        &qbreaks,
+        left_closed,
+        include_breaks,
+        index_only,
+    )
 }
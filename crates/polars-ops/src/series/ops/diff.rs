@@ -20,3 +20,29 @@ pub fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsResult<Ser
         },
     }
 }
+
+/// Divide the difference between consecutive values of `s` by the difference between the
+/// consecutive values of `by`.
+///
+/// This is the discrete derivative of `s` with respect to `by`, e.g. the rate of change of an
+/// irregularly sampled series. Temporal columns are differenced in their physical unit. The
+/// first value is null, and a zero difference in `by` gives an infinite value, or NaN if the
+/// difference in `s` is zero as well.
+pub fn diff_by(s: &Series, by: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == by.len(),
+        ShapeMismatch: "'diff_by' expected `by` to have length {}, got {}",
+        s.len(), by.len()
+    );
+    for dt in [s.dtype(), by.dtype()] {
+        polars_ensure!(
+            dt.is_numeric() || dt.is_temporal(),
+            InvalidOperation: "'diff_by' is not supported for dtype {}", dt
+        );
+    }
+    // Take the differences before the cast, so large integers don't lose precision.
+    let diff_f64 =
+        |s: &Series| diff(&s.to_physical_repr(), 1, NullBehavior::Ignore)?.cast(&DataType::Float64);
+    let out = diff_f64(s)?.divide(&diff_f64(by)?)?;
+    Ok(out.with_name(s.name()))
+}
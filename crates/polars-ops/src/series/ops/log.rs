@@ -15,6 +15,10 @@ fn exp<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
     ca.cast_and_apply_in_place(|v: f64| v.exp())
 }
 
+fn expm1<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Float64Chunked {
+    ca.cast_and_apply_in_place(|v: f64| v.exp_m1())
+}
+
 pub trait LogSeries: SeriesSealed {
     /// Compute the logarithm to a given base
     fn log(&self, base: f64) -> Series {
@@ -77,6 +81,27 @@ pub trait LogSeries: SeriesSealed {
         }
     }
 
+    /// Calculate the exponential of all elements minus one in the input array.
+    ///
+    /// This is accurate even if the elements are close to zero, unlike `exp() - 1`.
+    fn expm1(&self) -> Series {
+        let s = self.as_series().to_physical_repr();
+        let s = s.as_ref();
+
+        use DataType::*;
+        match s.dtype() {
+            dt if dt.is_integer() => {
+                with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    expm1(ca).into_series()
+                })
+            },
+            Float32 => s.f32().unwrap().apply_values(|v| v.exp_m1()).into_series(),
+            Float64 => s.f64().unwrap().apply_values(|v| v.exp_m1()).into_series(),
+            _ => s.cast(&DataType::Float64).unwrap().expm1(),
+        }
+    }
+
     /// Compute the entropy as `-sum(pk * log(pk)`.
     /// where `pk` are discrete probabilities.
     fn entropy(&self, base: f64, normalize: bool) -> PolarsResult<f64> {
@@ -1,14 +1,81 @@
+use num_traits::Bounded;
 use polars_core::prelude::*;
 
+/// The dtype [`negate`] produces for `dtype`.
+///
+/// Unsigned integers are upcast to the next wider signed integer, so that negative results
+/// can be represented.
+pub fn negate_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        #[cfg(feature = "dtype-i16")]
+        UInt8 => Int16,
+        #[cfg(not(feature = "dtype-i16"))]
+        UInt8 => Int32,
+        UInt16 => Int32,
+        UInt32 | UInt64 => Int64,
+        dt => dt.clone(),
+    }
+}
+
+/// Error if `ca` contains the minimum value of its signed integer type, as that value can't be
+/// negated without overflowing.
+pub(super) fn ensure_no_signed_min<T>(ca: &ChunkedArray<T>, op: &str) -> PolarsResult<()>
+where
+    T: PolarsIntegerType,
+    T::Native: Bounded,
+    ChunkedArray<T>: ChunkAgg<T::Native>,
+{
+    polars_ensure!(
+        ca.min() != Some(T::Native::min_value()),
+        ComputeError: "`{}` overflowed: {} contains its minimum value {}, which has no positive counterpart",
+        op, ca.dtype(), T::Native::min_value()
+    );
+    Ok(())
+}
+
+/// Arithmetic negation.
+///
+/// Unsigned integers are upcast to the output of [`negate_dtype`]. Errors instead of wrapping
+/// around if the result doesn't fit the output type.
 pub fn negate(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
         #[cfg(feature = "dtype-i8")]
-        Int8 => s.i8().unwrap().wrapping_neg().into_series(),
+        Int8 => {
+            let ca = s.i8().unwrap();
+            ensure_no_signed_min(ca, "neg")?;
+            ca.wrapping_neg().into_series()
+        },
         #[cfg(feature = "dtype-i16")]
-        Int16 => s.i16().unwrap().wrapping_neg().into_series(),
-        Int32 => s.i32().unwrap().wrapping_neg().into_series(),
-        Int64 => s.i64().unwrap().wrapping_neg().into_series(),
+        Int16 => {
+            let ca = s.i16().unwrap();
+            ensure_no_signed_min(ca, "neg")?;
+            ca.wrapping_neg().into_series()
+        },
+        Int32 => {
+            let ca = s.i32().unwrap();
+            ensure_no_signed_min(ca, "neg")?;
+            ca.wrapping_neg().into_series()
+        },
+        Int64 => {
+            let ca = s.i64().unwrap();
+            ensure_no_signed_min(ca, "neg")?;
+            ca.wrapping_neg().into_series()
+        },
+        UInt64 => {
+            // Only values up to `i64::MAX + 1` have a negative counterpart in `Int64`.
+            let ca = s.u64().unwrap();
+            let max = ca.max();
+            polars_ensure!(
+                max.map_or(true, |max| max <= i64::MAX as u64 + 1),
+                ComputeError: "`neg` overflowed: {} can't be represented as {}",
+                max.unwrap(), Int64
+            );
+            let out: Int64Chunked = ca.apply_values_generic(|v| (v as i64).wrapping_neg());
+            out.into_series()
+        },
+        dt if dt.is_unsigned_integer() => negate(&s.cast(&negate_dtype(dt))?)?,
         Float32 => s.f32().unwrap().wrapping_neg().into_series(),
         Float64 => s.f64().unwrap().wrapping_neg().into_series(),
         #[cfg(feature = "dtype-decimal")]
@@ -1,3 +1,4 @@
+use arrow::legacy::utils::CustomIterTools;
 use polars_core::prelude::*;
 #[cfg(feature = "moment")]
 use {
@@ -34,6 +35,189 @@ where
     })
 }
 
+/// Write the average rank (1-based, ties get the mean of their ranks) of every value to `out`.
+fn average_ranks(values: &[f64], order: &mut Vec<usize>, out: &mut Vec<f64>) {
+    order.clear();
+    order.extend(0..values.len());
+    order.sort_unstable_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    out.clear();
+    out.resize(values.len(), 0.0);
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        // Ranks `start + 1..=end` are shared by this group of ties.
+        let rank = (start + end + 1) as f64 / 2.0;
+        for &idx in &order[start..end] {
+            out[idx] = rank;
+        }
+        start = end;
+    }
+}
+
+fn corr_cov(x: &[f64], y: &[f64], ddof: u8, is_corr: bool) -> Option<f64> {
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(y) {
+        let dx = x - mean_x;
+        let dy = y - mean_y;
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+
+    if is_corr {
+        // The `ddof` correction cancels out in the correlation.
+        Some(sxy / (sxx * syy).sqrt())
+    } else {
+        let divisor = n - ddof as f64;
+        (divisor > 0.0).then(|| sxy / divisor)
+    }
+}
+
+/// Running sums over the valid pairs of a window, updated as rows enter and leave it.
+#[derive(Default)]
+struct CovWindow {
+    n: usize,
+    /// Pairs with a NaN or infinite value, these are kept out of the sums as they could never
+    /// be subtracted again.
+    n_non_finite: usize,
+    sum_x: f64,
+    sum_y: f64,
+    sum_xx: f64,
+    sum_yy: f64,
+    sum_xy: f64,
+}
+
+impl CovWindow {
+    fn update(&mut self, x: f64, y: f64, sign: f64) {
+        if x.is_finite() && y.is_finite() {
+            self.sum_x += sign * x;
+            self.sum_y += sign * y;
+            self.sum_xx += sign * x * x;
+            self.sum_yy += sign * y * y;
+            self.sum_xy += sign * x * y;
+        } else if sign > 0.0 {
+            self.n_non_finite += 1;
+        } else {
+            self.n_non_finite -= 1;
+        }
+    }
+
+    fn push(&mut self, x: f64, y: f64) {
+        self.n += 1;
+        self.update(x, y, 1.0);
+    }
+
+    fn remove(&mut self, x: f64, y: f64) {
+        self.n -= 1;
+        self.update(x, y, -1.0);
+    }
+
+    fn corr_cov(&self, ddof: u8, is_corr: bool) -> Option<f64> {
+        let n = self.n as f64;
+        if self.n_non_finite > 0 {
+            return (is_corr || n - ddof as f64 > 0.0).then_some(f64::NAN);
+        }
+        // Centered sums of products, clamped as cancellation may make them slightly negative.
+        let sxy = self.sum_xy - self.sum_x * self.sum_y / n;
+        let sxx = (self.sum_xx - self.sum_x * self.sum_x / n).max(0.0);
+        let syy = (self.sum_yy - self.sum_y * self.sum_y / n).max(0.0);
+
+        if is_corr {
+            // The `ddof` correction cancels out in the correlation.
+            Some(sxy / (sxx * syy).sqrt())
+        } else {
+            let divisor = n - ddof as f64;
+            (divisor > 0.0).then(|| sxy / divisor)
+        }
+    }
+}
+
+/// Compute the covariance (or correlation if `is_corr`) of `x` and `y` over every window of
+/// `window_size` rows ending at the current row.
+///
+/// Only rows in which both `x` and `y` are valid are used, and windows with fewer than
+/// `min_periods` of those pairs produce a null. If `spearman` is set, the values are ranked
+/// within every window before the correlation is computed, which costs a sort per window.
+/// Otherwise running sums are updated as the window slides.
+pub fn rolling_corr_cov(
+    x: &Series,
+    y: &Series,
+    window_size: usize,
+    min_periods: usize,
+    ddof: u8,
+    spearman: bool,
+    is_corr: bool,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "rolling covariance/correlation got inputs of length {} and {}",
+        x.len(), y.len()
+    );
+    polars_ensure!(window_size > 0, ComputeError: "window size should be strictly positive");
+    let name = x.name().to_string();
+    let x = x.cast(&DataType::Float64)?;
+    let y = y.cast(&DataType::Float64)?;
+    let x = x.f64()?.into_iter().collect::<Vec<_>>();
+    let y = y.f64()?.into_iter().collect::<Vec<_>>();
+    let min_periods = min_periods.max(1);
+
+    let out: Float64Chunked = if spearman {
+        let mut window_x = Vec::with_capacity(window_size);
+        let mut window_y = Vec::with_capacity(window_size);
+        let mut order = vec![];
+        let mut ranks_x = vec![];
+        let mut ranks_y = vec![];
+
+        (0..x.len())
+            .map(|i| {
+                let start = (i + 1).saturating_sub(window_size);
+                window_x.clear();
+                window_y.clear();
+                for (x, y) in x[start..=i].iter().zip(&y[start..=i]) {
+                    if let (Some(x), Some(y)) = (x, y) {
+                        window_x.push(*x);
+                        window_y.push(*y);
+                    }
+                }
+                if window_x.len() < min_periods {
+                    return None;
+                }
+                average_ranks(&window_x, &mut order, &mut ranks_x);
+                average_ranks(&window_y, &mut order, &mut ranks_y);
+                corr_cov(&ranks_x, &ranks_y, ddof, is_corr)
+            })
+            .collect_trusted()
+    } else {
+        let mut window = CovWindow::default();
+        (0..x.len())
+            .map(|i| {
+                if let (Some(x), Some(y)) = (x[i], y[i]) {
+                    window.push(x, y);
+                }
+                if i >= window_size {
+                    if let (Some(x), Some(y)) = (x[i - window_size], y[i - window_size]) {
+                        window.remove(x, y);
+                    }
+                }
+                if window.n < min_periods {
+                    return None;
+                }
+                window.corr_cov(ddof, is_corr)
+            })
+            .collect_trusted()
+    };
+
+    Ok(out.with_name(&name).into_series())
+}
+
 pub trait RollingSeries: SeriesSealed {
     #[cfg(feature = "moment")]
     fn rolling_skew(&self, window_size: usize, bias: bool) -> PolarsResult<Series> {
@@ -58,3 +242,77 @@ pub trait RollingSeries: SeriesSealed {
 }
 
 impl RollingSeries for Series {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_average_ranks() {
+        let mut order = vec![];
+        let mut out = vec![];
+        average_ranks(&[3.0, 1.0, 3.0, 2.0], &mut order, &mut out);
+        assert_eq!(out, &[3.5, 1.0, 3.5, 2.0]);
+    }
+
+    #[test]
+    fn test_rolling_corr_cov_running_sums() -> PolarsResult<()> {
+        let x = Series::new(
+            "x",
+            &[
+                Some(1.0),
+                Some(4.0),
+                None,
+                Some(2.0),
+                Some(8.0),
+                Some(f64::NAN),
+                Some(3.0),
+                Some(5.0),
+                Some(6.0),
+                Some(1.0),
+            ],
+        );
+        let y = Series::new(
+            "y",
+            &[
+                Some(2.0),
+                Some(3.0),
+                Some(1.0),
+                None,
+                Some(9.0),
+                Some(4.0),
+                Some(7.0),
+                Some(6.0),
+                Some(2.0),
+                Some(5.0),
+            ],
+        );
+        let xs = x.f64()?.into_iter().collect::<Vec<_>>();
+        let ys = y.f64()?.into_iter().collect::<Vec<_>>();
+
+        for is_corr in [false, true] {
+            let out = rolling_corr_cov(&x, &y, 3, 2, 1, false, is_corr)?;
+            let out = out.f64()?.into_iter().collect::<Vec<_>>();
+            for (i, out) in out.into_iter().enumerate() {
+                // Compare with a two pass computation over the valid pairs of the window.
+                let start = (i + 1).saturating_sub(3);
+                let (wx, wy): (Vec<_>, Vec<_>) = xs[start..=i]
+                    .iter()
+                    .zip(&ys[start..=i])
+                    .filter_map(|(x, y)| Some(((*x)?, (*y)?)))
+                    .unzip();
+                let expected = if wx.len() < 2 {
+                    None
+                } else {
+                    corr_cov(&wx, &wy, 1, is_corr)
+                };
+                match (out, expected) {
+                    (Some(out), Some(expected)) if expected.is_nan() => assert!(out.is_nan()),
+                    (Some(out), Some(expected)) => assert!((out - expected).abs() < 1e-12),
+                    (out, expected) => assert_eq!(out, expected),
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,9 +1,31 @@
 use num_traits::pow::Pow;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::series::ops::SeriesSealed;
 
+/// How to break ties when a value lies exactly halfway between two candidates.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RoundMode {
+    /// Round half-way cases away from zero, like [`f64::round`].
+    #[default]
+    HalfAwayFromZero,
+    /// Round half-way cases to the nearest even candidate, also known as banker's rounding.
+    HalfToEven,
+}
+
+impl RoundMode {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            RoundMode::HalfAwayFromZero => value.round(),
+            RoundMode::HalfToEven => value.round_ties_even(),
+        }
+    }
+}
+
 pub trait RoundSeries: SeriesSealed {
     /// Round underlying floating point array to given decimal.
     fn round(&self, decimals: u32) -> PolarsResult<Series> {
@@ -56,6 +78,30 @@ pub trait RoundSeries: SeriesSealed {
         });
     }
 
+    /// Round to the nearest multiple of `step`. Integers are rounded as `Float64`.
+    fn round_to_multiple(&self, step: f64, mode: RoundMode) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(
+            step > 0.0 && step.is_finite(),
+            InvalidOperation: "step must be a positive finite number, got {}", step
+        );
+
+        if let Ok(ca) = s.f32() {
+            // Compute on f64 floats to not lose precision, like `round`.
+            let s = ca
+                .apply_values(|val| (mode.apply(val as f64 / step) * step) as f32)
+                .into_series();
+            return Ok(s);
+        }
+        polars_ensure!(s.dtype().is_numeric(), opq = round_to_multiple, s.dtype());
+        let s = s.cast(&DataType::Float64)?;
+        let s = s
+            .f64()?
+            .apply_values(|val| mode.apply(val / step) * step)
+            .into_series();
+        Ok(s)
+    }
+
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
     fn floor(&self) -> PolarsResult<Series> {
         let s = self.as_series();
@@ -100,4 +146,53 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    fn test_round_to_multiple() {
+        let s = Series::new("a", &[0.1, 0.125, -0.125, 0.375, -0.8, 1.0]);
+        let out = s
+            .round_to_multiple(0.25, RoundMode::HalfAwayFromZero)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(0.0),
+                Some(0.25),
+                Some(-0.25),
+                Some(0.5),
+                Some(-0.75),
+                Some(1.0)
+            ]
+        );
+        let out = s.round_to_multiple(0.25, RoundMode::HalfToEven).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(0.0),
+                Some(0.0),
+                Some(-0.0),
+                Some(0.5),
+                Some(-0.75),
+                Some(1.0)
+            ]
+        );
+
+        // Integers are rounded as floats.
+        let s = Series::new("a", &[Some(14i32), Some(15), Some(25), Some(-25), None]);
+        let out = s
+            .round_to_multiple(10.0, RoundMode::HalfAwayFromZero)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(10.0), Some(20.0), Some(30.0), Some(-30.0), None]
+        );
+        let out = s.round_to_multiple(10.0, RoundMode::HalfToEven).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(10.0), Some(20.0), Some(20.0), Some(-20.0), None]
+        );
+
+        assert!(s.round_to_multiple(0.0, RoundMode::HalfToEven).is_err());
+        assert!(s.round_to_multiple(-5.0, RoundMode::HalfToEven).is_err());
+    }
 }
@@ -1,4 +1,6 @@
-use arrow::array::{Array, DictionaryKey, MutablePrimitiveArray, PrimitiveArray};
+use arrow::array::{
+    Array, DictionaryKey, MutableBinaryViewArray, MutablePrimitiveArray, PrimitiveArray,
+};
 use arrow::datatypes::{ArrowDataType, IntervalUnit, TimeUnit};
 use arrow::match_integer_type;
 use arrow::types::{days_ms, i256, NativeType};
@@ -340,6 +342,28 @@ pub fn page_iter_to_arrays<'a, I: PagesIter + 'a>(
         (PhysicalType::ByteArray, BinaryView | Utf8View) => Box::new(
             binview::BinaryViewArrayIter::new(pages, data_type, chunk_size, num_rows),
         ),
+        // A `UUID` that is read as its string representation.
+        (PhysicalType::FixedLenByteArray(16), Utf8View) => {
+            let pages = fixed_size_binary::Iter::new(
+                pages,
+                ArrowDataType::FixedSizeBinary(16),
+                num_rows,
+                chunk_size,
+            );
+
+            let pages = pages.map(move |maybe_array| {
+                let array = maybe_array?;
+                let mut buf = [0u8; 36];
+                let mut values = MutableBinaryViewArray::<str>::with_capacity(array.len());
+                for value in array.values().chunks_exact(16) {
+                    values.push_value_ignore_validity(super::super::convert_uuid(value, &mut buf));
+                }
+                let array = values.freeze().with_validity(array.validity().cloned());
+                PolarsResult::Ok(array.boxed())
+            });
+
+            Box::new(pages) as _
+        },
 
         (_, Dictionary(key_type, _, _)) => {
             return match_integer_type!(key_type, |$K| {
@@ -88,6 +88,24 @@ fn convert_i128(value: &[u8], n: usize) -> i128 {
     i128::from_be_bytes(bytes) >> (8 * (16 - n))
 }
 
+/// Formats the 16 bytes of a parquet `UUID` into `buf` as its canonical, hyphenated lowercase
+/// string.
+fn convert_uuid<'a>(value: &[u8], buf: &'a mut [u8; 36]) -> &'a str {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut i = 0;
+    for (pos, byte) in value.iter().enumerate() {
+        if matches!(pos, 4 | 6 | 8 | 10) {
+            buf[i] = b'-';
+            i += 1;
+        }
+        buf[i] = HEX[(byte >> 4) as usize];
+        buf[i + 1] = HEX[(byte & 0xf) as usize];
+        i += 2;
+    }
+    // SAFETY: only ASCII was written.
+    unsafe { std::str::from_utf8_unchecked(buf) }
+}
+
 fn convert_i256(value: &[u8]) -> i256 {
     if value[0] >= 128 {
         let mut neg_bytes = [255u8; 32];
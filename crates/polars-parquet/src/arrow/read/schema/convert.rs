@@ -1174,6 +1174,7 @@ mod tests {
                 parquet_schema.fields(),
                 &Some(SchemaInferenceOptions {
                     int96_coerce_to_timeunit: tu,
+                    ..Default::default()
                 }),
             );
             assert_eq!(arrow_fields, fields);
@@ -1,5 +1,5 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use arrow::datatypes::{ArrowSchema, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, TimeUnit};
 
 mod convert;
 mod metadata;
@@ -12,6 +12,7 @@ use polars_error::PolarsResult;
 use self::metadata::parse_key_value_metadata;
 pub use crate::parquet::metadata::{FileMetaData, KeyValue, SchemaDescriptor};
 pub use crate::parquet::schema::types::ParquetType;
+use crate::parquet::schema::types::PrimitiveLogicalType;
 
 /// Options when inferring schemas from Parquet
 pub struct SchemaInferenceOptions {
@@ -23,12 +24,18 @@ pub struct SchemaInferenceOptions {
     /// (e.g. TimeUnit::Milliseconds) will result in loss of precision, but support a larger range of dates
     /// without overflowing when parsing the data.
     pub int96_coerce_to_timeunit: TimeUnit,
+    /// Read top-level columns with the `UUID` logical type as their canonical string
+    /// representation (`Utf8View`) instead of as 16 byte `FixedSizeBinary` values.
+    ///
+    /// This defaults to `false`.
+    pub uuid_as_string: bool,
 }
 
 impl Default for SchemaInferenceOptions {
     fn default() -> Self {
         SchemaInferenceOptions {
             int96_coerce_to_timeunit: TimeUnit::Nanosecond,
+            uuid_as_string: false,
         }
     }
 }
@@ -51,8 +58,30 @@ pub fn infer_schema_with_options(
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
 
     let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
+    let mut schema = schema.unwrap_or_else(|| {
         let fields = parquet_to_arrow_schema_with_options(file_metadata.schema().fields(), options);
         ArrowSchema { fields, metadata }
-    }))
+    });
+
+    if options
+        .as_ref()
+        .map_or(false, |options| options.uuid_as_string)
+    {
+        for parquet_field in file_metadata.schema().fields() {
+            let ParquetType::PrimitiveType(primitive) = parquet_field else {
+                continue;
+            };
+            if primitive.logical_type != Some(PrimitiveLogicalType::Uuid) {
+                continue;
+            }
+            if let Some(field) = schema
+                .fields
+                .iter_mut()
+                .find(|field| field.name == primitive.field_info.name)
+            {
+                field.data_type = ArrowDataType::Utf8View;
+            }
+        }
+    }
+    Ok(schema)
 }
@@ -3,7 +3,7 @@ use arrow::types::{days_ms, i256};
 use ethnum::I256;
 use polars_error::PolarsResult;
 
-use super::super::{convert_days_ms, convert_i128};
+use super::super::{convert_days_ms, convert_i128, convert_uuid};
 use crate::arrow::read::convert_i256;
 use crate::parquet::statistics::{FixedLenStatistics, Statistics as ParquetStatistics};
 
@@ -29,6 +29,34 @@ pub(super) fn push_i128(
     Ok(())
 }
 
+/// The canonical string representation of a `UUID` sorts like its bytes, so the statistics
+/// remain valid bounds.
+pub(super) fn push_uuid(
+    from: Option<&dyn ParquetStatistics>,
+    min: &mut dyn MutableArray,
+    max: &mut dyn MutableArray,
+) -> PolarsResult<()> {
+    let min = min
+        .as_mut_any()
+        .downcast_mut::<MutableBinaryViewArray<str>>()
+        .unwrap();
+    let max = max
+        .as_mut_any()
+        .downcast_mut::<MutableBinaryViewArray<str>>()
+        .unwrap();
+    let from = from.map(|s| s.as_any().downcast_ref::<FixedLenStatistics>().unwrap());
+
+    let mut buf = [0u8; 36];
+    let mut push = |target: &mut MutableBinaryViewArray<str>, value: Option<&[u8]>| match value {
+        Some(value) => target.push_value(convert_uuid(value, &mut buf)),
+        None => target.push_null(),
+    };
+    push(min, from.and_then(|s| s.min_value.as_deref()));
+    push(max, from.and_then(|s| s.max_value.as_deref()));
+
+    Ok(())
+}
+
 pub(super) fn push_i256_with_i128(
     from: Option<&dyn ParquetStatistics>,
     n: usize,
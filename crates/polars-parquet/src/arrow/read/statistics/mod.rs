@@ -525,7 +525,10 @@ fn push(
         Utf8 => utf8::push::<i32>(from, min, max),
         LargeUtf8 => utf8::push::<i64>(from, min, max),
         BinaryView => binview::push::<[u8]>(from, min, max),
-        Utf8View => binview::push::<str>(from, min, max),
+        Utf8View => match physical_type {
+            ParquetPhysicalType::FixedLenByteArray(16) => fixlen::push_uuid(from, min, max),
+            _ => binview::push::<str>(from, min, max),
+        },
         FixedSizeBinary(_) => fixlen::push(from, min, max),
 
         Null => null::push(min, max),
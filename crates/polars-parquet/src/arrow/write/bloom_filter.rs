@@ -0,0 +1,81 @@
+use arrow::array::*;
+use arrow::datatypes::ArrowDataType;
+use arrow::record_batch::RecordBatch;
+use arrow::types::NativeType;
+
+use super::Encoding;
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_of_bytes};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+fn hash_primitive<T, P>(array: &dyn Array) -> Vec<u64>
+where
+    T: NativeType + num_traits::AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .non_null_values_iter()
+        .map(|x| hash_native::<P>(x.as_()))
+        .collect()
+}
+
+fn hash_binary<A: AsRef<[u8]>>(values: impl Iterator<Item = Option<A>>) -> Vec<u64> {
+    values.flatten().map(hash_byte).collect()
+}
+
+/// Builds the split block bloom filter of the non-null values of `array`, sized so that its false
+/// positive probability is at most `fpp`.
+///
+/// The values are hashed as the parquet physical type they are written as, so that readers can
+/// probe the filter. Returns `None` if bloom filters aren't supported for the type of `array`.
+pub fn array_to_bloom_filter(array: &dyn Array, fpp: f64) -> Option<Vec<u8>> {
+    use ArrowDataType as D;
+    // casts below MUST match the casts done when the array is written.
+    let mut hashes = match array.data_type().to_logical_type() {
+        D::UInt8 => hash_primitive::<u8, i32>(array),
+        D::UInt16 => hash_primitive::<u16, i32>(array),
+        D::UInt32 => hash_primitive::<u32, i32>(array),
+        D::UInt64 => hash_primitive::<u64, i64>(array),
+        D::Int8 => hash_primitive::<i8, i32>(array),
+        D::Int16 => hash_primitive::<i16, i32>(array),
+        D::Int32 | D::Date32 | D::Time32(_) => hash_primitive::<i32, i32>(array),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            hash_primitive::<i64, i64>(array)
+        },
+        D::Float32 => hash_primitive::<f32, f32>(array),
+        D::Float64 => hash_primitive::<f64, f64>(array),
+        D::BinaryView => hash_binary(array.as_any().downcast_ref::<BinaryViewArray>()?.iter()),
+        D::Utf8View => hash_binary(array.as_any().downcast_ref::<Utf8ViewArray>()?.iter()),
+        D::LargeBinary => hash_binary(array.as_any().downcast_ref::<BinaryArray<i64>>()?.iter()),
+        D::LargeUtf8 => hash_binary(array.as_any().downcast_ref::<Utf8Array<i64>>()?.iter()),
+        _ => return None,
+    };
+    hashes.sort_unstable();
+    hashes.dedup();
+
+    let mut bitset = vec![0; optimal_num_of_bytes(hashes.len(), fpp)];
+    for hash in hashes {
+        insert(&mut bitset, hash);
+    }
+    Some(bitset)
+}
+
+/// Builds the bloom filters of the leaf columns of `batch`, in the order they are written.
+///
+/// `fpp` holds the false positive probability of every column of `batch`, `None` if the column
+/// doesn't get a bloom filter. Only columns that consist of a single leaf column get one.
+pub fn row_group_to_bloom_filters(
+    batch: &RecordBatch<Box<dyn Array>>,
+    encodings: &[Vec<Encoding>],
+    fpp: &[Option<f64>],
+) -> Vec<Option<Vec<u8>>> {
+    if fpp.iter().all(Option::is_none) {
+        return vec![];
+    }
+    let mut bloom_filters = Vec::with_capacity(encodings.iter().map(Vec::len).sum());
+    for ((array, encodings), fpp) in batch.columns().iter().zip(encodings).zip(fpp) {
+        bloom_filters.push(fpp.and_then(|fpp| array_to_bloom_filter(array.as_ref(), fpp)));
+        bloom_filters.extend(std::iter::repeat(None).take(encodings.len().saturating_sub(1)));
+    }
+    bloom_filters
+}
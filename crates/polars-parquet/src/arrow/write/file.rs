@@ -75,6 +75,18 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file, together with the bloom filters of its column chunks.
+    /// See [`crate::parquet::write::FileWriter::write_with_bloom_filters`].
+    pub fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIter<'_, PolarsError>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(row_group, bloom_filters)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
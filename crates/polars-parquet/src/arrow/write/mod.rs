@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -30,6 +32,8 @@ mod utils;
 use arrow::array::*;
 use arrow::datatypes::*;
 use arrow::types::{days_ms, i256, NativeType};
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{array_to_bloom_filter, row_group_to_bloom_filters};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 pub use utils::write_def_levels;
@@ -25,6 +25,9 @@ pub struct FileSink<'a, W: AsyncWrite + Send + Unpin> {
     encodings: Vec<Vec<Encoding>>,
     schema: ArrowSchema,
     parquet_schema: SchemaDescriptor,
+    /// The false positive probability of the bloom filter of every field, if it gets one.
+    #[cfg(feature = "bloom_filter")]
+    bloom_filter_fpp: Vec<Option<f64>>,
     /// Key-value metadata that will be written to the file on close.
     pub metadata: AHashMap<String, Option<String>>,
 }
@@ -69,10 +72,28 @@ where
             schema,
             encodings,
             parquet_schema,
+            #[cfg(feature = "bloom_filter")]
+            bloom_filter_fpp: vec![],
             metadata: AHashMap::default(),
         })
     }
 
+    /// Write a split block bloom filter for every row group of the fields that have a false
+    /// positive probability in `fpp`, which holds one entry per field of the schema.
+    ///
+    /// # Error
+    /// Iff the length of `fpp` is different from the number of fields in schema
+    #[cfg(feature = "bloom_filter")]
+    pub fn with_bloom_filters(mut self, fpp: Vec<Option<f64>>) -> PolarsResult<Self> {
+        if fpp.len() != self.schema.fields.len() {
+            polars_bail!(InvalidOperation:
+                "The number of bloom filter probabilities must equal the number of fields"
+            )
+        }
+        self.bloom_filter_fpp = fpp;
+        Ok(self)
+    }
+
     /// The Arrow [`ArrowSchema`] for the file.
     pub fn schema(&self) -> &ArrowSchema {
         &self.schema
@@ -127,6 +148,14 @@ where
         }
         let this = self.get_mut();
         if let Some(mut writer) = this.writer.take() {
+            #[cfg(feature = "bloom_filter")]
+            let bloom_filters = crate::arrow::write::row_group_to_bloom_filters(
+                &item,
+                &this.encodings,
+                &this.bloom_filter_fpp,
+            );
+            #[cfg(not(feature = "bloom_filter"))]
+            let bloom_filters = vec![];
             let rows = crate::arrow::write::row_group_iter(
                 item,
                 this.encodings.clone(),
@@ -134,7 +163,7 @@ where
                 this.options,
             );
             this.task = Some(Box::pin(async move {
-                writer.write(rows).await?;
+                writer.write_with_bloom_filters(rows, bloom_filters).await?;
                 Ok(Some(writer))
             }));
             Ok(())
@@ -5,7 +5,7 @@ mod split_block;
 
 pub use hash::{hash_byte, hash_native};
 pub use read::read;
-pub use split_block::{insert, is_in_set};
+pub use split_block::{insert, is_in_set, optimal_num_of_bytes};
 
 #[cfg(test)]
 mod tests {
@@ -50,6 +50,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn num_of_bytes() {
+        assert_eq!(optimal_num_of_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_of_bytes(usize::MAX, 0.01), 128 * 1024 * 1024);
+        let num_bytes = optimal_num_of_bytes(1_000, 0.01);
+        assert!(num_bytes.is_power_of_two());
+        assert!(optimal_num_of_bytes(1_000, 0.001) > num_bytes);
+        assert!(optimal_num_of_bytes(10_000, 0.01) > num_bytes);
+    }
+
     #[test]
     fn binary() {
         let mut bitset = vec![0; 32];
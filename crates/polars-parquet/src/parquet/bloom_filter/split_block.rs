@@ -3,6 +3,21 @@ const SALT: [u32; 8] = [
     1203114875, 1150766481, 2284105051, 2729912477, 1884591559, 770785867, 2667333959, 1550580529,
 ];
 
+/// The smallest and largest bitset sizes produced by [`optimal_num_of_bytes`].
+const MIN_NUM_BYTES: usize = 32;
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a bitset that holds `ndv` distinct values with a false positive
+/// probability of at most `fpp`. The size is a power of two between 32 bytes and 128 MiB.
+pub fn optimal_num_of_bytes(ndv: usize, fpp: f64) -> usize {
+    // Every value sets one bit in each of the 8 words of its block.
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
 fn hash_to_block_index(hash: u64, len: usize) -> usize {
     let number_of_blocks = len as u64 / 32;
     let low_hash = hash >> 32;
@@ -0,0 +1,47 @@
+use std::io::Write;
+
+#[cfg(feature = "async")]
+use futures::{AsyncWrite, AsyncWriteExt};
+use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
+#[cfg(feature = "async")]
+use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
+use parquet_format_safe::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::Result;
+
+/// Writes the split block bloom filter `bitset`, preceded by its header. Returns the number of
+/// bytes written.
+pub fn write_bloom_filter<W: Write>(writer: &mut W, bitset: &[u8]) -> Result<u64> {
+    let header = bloom_filter_header(bitset)?;
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+    Ok(header_len + bitset.len() as u64)
+}
+
+/// Asynchronously writes the split block bloom filter `bitset`, preceded by its header. Returns
+/// the number of bytes written.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub async fn write_bloom_filter_async<W: AsyncWrite + Unpin + Send>(
+    writer: &mut W,
+    bitset: &[u8],
+) -> Result<u64> {
+    let header = bloom_filter_header(bitset)?;
+    let mut protocol = TCompactOutputStreamProtocol::new(&mut *writer);
+    let header_len = header.write_to_out_stream_protocol(&mut protocol).await? as u64;
+    writer.write_all(bitset).await?;
+    Ok(header_len + bitset.len() as u64)
+}
+
+fn bloom_filter_header(bitset: &[u8]) -> Result<BloomFilterHeader> {
+    Ok(BloomFilterHeader {
+        num_bytes: bitset.len().try_into()?,
+        algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        hash: BloomFilterHash::XXHASH(XxHash {}),
+        compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    })
+}
@@ -3,6 +3,7 @@ use std::io::Write;
 use parquet_format_safe::thrift::protocol::TCompactOutputProtocol;
 use parquet_format_safe::RowGroup;
 
+use super::bloom_filter::write_bloom_filter;
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
@@ -151,11 +152,34 @@ impl<W: Write> FileWriter<W> {
         Error: From<E>,
         E: std::error::Error,
     {
+        self.write_with_bloom_filters(row_group, vec![])
+    }
+
+    /// Writes a row group to the file, together with the split block bloom filters of its
+    /// column chunks.
+    ///
+    /// `bloom_filters` holds the bitset of every column chunk in the order of the leaf columns,
+    /// it may be shorter than the number of columns. The filters are written right after the
+    /// row group, so they don't need to be kept in memory until the file ends.
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIter<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> Result<()>
+    where
+        Error: From<E>,
+        E: std::error::Error,
+    {
+        if bloom_filters.len() > self.schema.columns().len() {
+            return Err(Error::InvalidParameter(
+                "There are more bloom filters than columns".to_string(),
+            ));
+        }
         if self.offset == 0 {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
@@ -163,6 +187,16 @@ impl<W: Write> FileWriter<W> {
             ordinal,
         )?;
         self.offset += size;
+
+        // write bloom filters
+        for (column, bitset) in group.columns.iter_mut().zip(bloom_filters.iter()) {
+            if let Some(bitset) = bitset {
+                let meta_data = column.meta_data.as_mut().unwrap();
+                meta_data.bloom_filter_offset = Some(self.offset as i64);
+                self.offset += write_bloom_filter(&mut self.writer, bitset)?;
+            }
+        }
+
         self.row_groups.push(group);
         self.page_specs.push(specs);
         Ok(())
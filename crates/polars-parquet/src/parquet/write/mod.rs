@@ -1,3 +1,4 @@
+mod bloom_filter;
 mod column_chunk;
 mod compression;
 mod file;
@@ -4,6 +4,7 @@ use futures::{AsyncWrite, AsyncWriteExt};
 use parquet_format_safe::thrift::protocol::TCompactOutputStreamProtocol;
 use parquet_format_safe::{FileMetaData, RowGroup};
 
+use super::bloom_filter::write_bloom_filter_async;
 use super::row_group::write_row_group_async;
 use super::{RowGroupIter, WriteOptions};
 use crate::parquet::error::{Error, Result};
@@ -112,12 +113,33 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
         Error: From<E>,
         E: std::error::Error,
     {
+        self.write_with_bloom_filters(row_group, vec![]).await
+    }
+
+    /// Writes a row group to the file, together with the split block bloom filters of its
+    /// column chunks.
+    ///
+    /// See [`crate::parquet::write::FileWriter::write_with_bloom_filters`].
+    pub async fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIter<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> Result<()>
+    where
+        Error: From<E>,
+        E: std::error::Error,
+    {
+        if bloom_filters.len() > self.schema.columns().len() {
+            return Err(Error::InvalidParameter(
+                "There are more bloom filters than columns".to_string(),
+            ));
+        }
         if self.offset == 0 {
             self.start().await?;
         }
 
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group_async(
+        let (mut group, specs, size) = write_row_group_async(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
@@ -126,6 +148,16 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
         )
         .await?;
         self.offset += size;
+
+        // write bloom filters
+        for (column, bitset) in group.columns.iter_mut().zip(bloom_filters.iter()) {
+            if let Some(bitset) = bitset {
+                let meta_data = column.meta_data.as_mut().unwrap();
+                meta_data.bloom_filter_offset = Some(self.offset as i64);
+                self.offset += write_bloom_filter_async(&mut self.writer, bitset).await?;
+            }
+        }
+
         self.row_groups.push(group);
         self.page_specs.push(specs);
         Ok(())
@@ -0,0 +1,225 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_utils::hashing::hash_to_partition;
+use smartstring::alias::String as SmartString;
+
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+use crate::pipeline::PARTITION_SIZE;
+
+type SeenPartition = Mutex<PlHashMap<Vec<u8>, u64>>;
+
+/// Adds a column that flags the first occurrence of every key, the streaming
+/// `is_first_distinct`.
+///
+/// All threads share a seen-set that maps every row encoded key to the position of its first
+/// row, so a chunk that is processed before a chunk with a lower index can't claim a key.
+/// The seen-set is partitioned by the hash of the key, so that the threads rarely wait on
+/// each other.
+/// The chunks of a batch are processed concurrently, but a batch is only fetched once the
+/// previous one is done and has higher chunk indices. So the flags of a chunk are final once
+/// its thread receives a chunk with a higher index, until then the chunk is held back.
+pub(crate) struct FirstDistinctOperator {
+    /// The keys, a struct key is distinct by all its fields.
+    key: Arc<dyn PhysicalPipedExpr>,
+    name: SmartString,
+    /// The position of the first row of every key seen by any thread, partitioned by hash.
+    seen: Arc<[SeenPartition]>,
+    hb: RandomState,
+    /// The chunks of this thread whose flags may still change, with their encoded keys.
+    pending: Vec<(DataChunk, BinaryOffsetChunked)>,
+    /// Flagged chunks that still need to be emitted.
+    out: Vec<DataChunk>,
+    output_schema: SchemaRef,
+}
+
+impl FirstDistinctOperator {
+    pub(crate) fn new(
+        key: Arc<dyn PhysicalPipedExpr>,
+        name: SmartString,
+        output_schema: SchemaRef,
+    ) -> Self {
+        Self {
+            key,
+            name,
+            seen: (0..PARTITION_SIZE).map(|_| Default::default()).collect(),
+            hb: Default::default(),
+            pending: vec![],
+            out: vec![],
+            output_schema,
+        }
+    }
+
+    fn partition(&self, key: &[u8]) -> usize {
+        hash_to_partition(self.hb.hash_one(key), self.seen.len())
+    }
+
+    /// Flag the pending chunks for which `is_final` holds and move them to the output.
+    fn finish_pending(&mut self, is_final: impl Fn(&DataChunk) -> bool) -> PolarsResult<()> {
+        let (done, pending) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(chunk, _)| is_final(chunk));
+        self.pending = pending;
+        if done.is_empty() {
+            return Ok(());
+        }
+
+        // The partitions are always locked in the same order, so this can't deadlock.
+        let seen = self
+            .seen
+            .iter()
+            .map(|partition| partition.lock().unwrap())
+            .collect::<Vec<_>>();
+        for (chunk, keys) in done {
+            let offset = chunk.row_position_offset();
+            let flags = keys
+                .into_no_null_iter()
+                .enumerate()
+                .map(|(row, key)| seen[self.partition(key)][key] == offset + row as u64);
+            let flags = BooleanChunked::from_iter_values(&self.name, flags);
+
+            let mut df = chunk.data;
+            df.with_column(flags.into_series())?;
+            self.out.push(DataChunk::new(chunk.chunk_index, df));
+        }
+        // Emit the lowest chunk index last.
+        self.out
+            .sort_unstable_by_key(|chunk| std::cmp::Reverse(chunk.chunk_index));
+        Ok(())
+    }
+
+    fn pop_output(&mut self) -> OperatorResult {
+        match self.out.pop() {
+            None => OperatorResult::NeedsNewData,
+            Some(chunk) if self.out.is_empty() => OperatorResult::Finished(chunk),
+            Some(chunk) => OperatorResult::HaveMoreOutPut(chunk),
+        }
+    }
+}
+
+impl Operator for FirstDistinctOperator {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        // We are called again with the same chunk until the output is emitted.
+        if !self.out.is_empty() {
+            return Ok(self.pop_output());
+        }
+
+        let key = self.key.evaluate(chunk, context.execution_state.as_any())?;
+        let keys = _get_rows_encoded_ca_unordered("", &[key])?;
+        let offset = chunk.row_position_offset();
+        let mut partitioned = vec![vec![]; self.seen.len()];
+        for (row, key) in keys.into_no_null_iter().enumerate() {
+            partitioned[self.partition(key)].push((offset + row as u64, key));
+        }
+        for (seen, rows) in self.seen.iter().zip(partitioned) {
+            if rows.is_empty() {
+                continue;
+            }
+            let mut seen = seen.lock().unwrap();
+            for (position, key) in rows {
+                match seen.get_mut(key) {
+                    Some(first) => *first = std::cmp::min(*first, position),
+                    None => {
+                        seen.insert(key.to_vec(), position);
+                    },
+                }
+            }
+        }
+
+        self.finish_pending(|pending| pending.chunk_index < chunk.chunk_index)?;
+        self.pending.push((chunk.clone(), keys));
+        Ok(self.pop_output())
+    }
+
+    fn flush(&mut self) -> PolarsResult<OperatorResult> {
+        self.finish_pending(|_| true)?;
+        match self.pop_output() {
+            OperatorResult::NeedsNewData => Ok(OperatorResult::Finished(DataChunk::new(
+                0,
+                DataFrame::from(self.output_schema.as_ref()),
+            ))),
+            out => Ok(out),
+        }
+    }
+
+    fn must_flush(&self) -> bool {
+        true
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(Self {
+            key: self.key.clone(),
+            name: self.name.clone(),
+            seen: self.seen.clone(),
+            hb: self.hb.clone(),
+            pending: vec![],
+            out: vec![],
+            output_schema: self.output_schema.clone(),
+        })
+    }
+
+    fn fmt(&self) -> &str {
+        "first_distinct"
+    }
+}
+
+#[cfg(all(test, feature = "is_first_distinct"))]
+mod test {
+    use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+    use polars_ops::prelude::is_first_distinct;
+
+    use super::*;
+    use crate::operators::testing::{interleaved_chunks, TestColumn, TestContext};
+
+    #[test]
+    fn test_first_distinct_out_of_order() -> PolarsResult<()> {
+        // Every key, including null, occurs in many chunks.
+        let key = (0..10_000i32)
+            .map(|i| Some((i * 7) % 31).filter(|v| *v != 3))
+            .collect::<Vec<_>>();
+        let df = df![
+            "key" => key,
+            "value" => (0..10_000i32).collect::<Vec<_>>(),
+        ]?;
+        let context = PExecutionContext::new(Box::new(TestContext), false);
+        let op = FirstDistinctOperator::new(
+            Arc::new(TestColumn("key")),
+            "flag".into(),
+            Arc::new(df.schema()),
+        );
+        let mut ops = [op.split(0), op.split(1)];
+
+        let mut chunks = vec![];
+        let emit = |chunks: &mut Vec<DataChunk>, result| {
+            if let OperatorResult::Finished(chunk) | OperatorResult::HaveMoreOutPut(chunk) = result
+            {
+                chunks.push(chunk)
+            }
+        };
+        for (thread, chunk) in interleaved_chunks(&df, 100, false) {
+            emit(&mut chunks, ops[thread].execute(&context, &chunk)?);
+        }
+        // Only the chunks of the last batch are held back until the flush.
+        assert_eq!(chunks.len(), 98);
+        for op in &mut ops {
+            emit(&mut chunks, op.flush()?);
+        }
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+
+        let out = accumulate_dataframes_vertical_unchecked(chunks.into_iter().map(|c| c.data));
+        let expected = is_first_distinct(df.column("key")?)?;
+        assert_eq!(out.get_column_names(), &["key", "value", "flag"]);
+        assert!(out.column("value")?.equals(df.column("value")?));
+        assert!(out
+            .column("flag")?
+            .equals(&expected.with_name("flag").into_series()));
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 mod filter;
+#[cfg(feature = "is_first_distinct")]
+mod first_distinct;
 mod function;
 mod pass;
 mod placeholder;
@@ -6,6 +8,8 @@ mod projection;
 mod reproject;
 
 pub(crate) use filter::*;
+#[cfg(feature = "is_first_distinct")]
+pub(crate) use first_distinct::*;
 pub(crate) use function::*;
 pub(crate) use pass::Pass;
 pub(crate) use placeholder::PlaceHolder;
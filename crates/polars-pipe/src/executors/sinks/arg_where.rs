@@ -0,0 +1,115 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use smartstring::alias::String as SmartString;
+
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// The streaming `arg_where`, the indices where a boolean expression is `true`.
+///
+/// The indices are global, so they can only be offset once the heights of all preceding
+/// chunks are known. Every thread keeps the local indices and the height of the chunks it
+/// saw, which are ordered and offset on `finalize`.
+#[derive(Clone)]
+pub struct ArgWhereSink {
+    condition: Arc<dyn PhysicalPipedExpr>,
+    name: SmartString,
+    /// The chunk index, the height and the local indices of every chunk.
+    chunks: Vec<(IdxSize, IdxSize, Vec<IdxSize>)>,
+}
+
+impl ArgWhereSink {
+    pub(crate) fn new(condition: Arc<dyn PhysicalPipedExpr>, name: SmartString) -> Self {
+        ArgWhereSink {
+            condition,
+            name,
+            chunks: vec![],
+        }
+    }
+}
+
+impl Sink for ArgWhereSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let condition = self
+            .condition
+            .evaluate(&chunk, context.execution_state.as_any())?;
+        let condition = condition.bool()?;
+        let indices = condition
+            .downcast_iter()
+            .flat_map(|arr| arr.iter())
+            .enumerate()
+            .filter_map(|(idx, value)| (value == Some(true)).then_some(idx as IdxSize))
+            .collect();
+        self.chunks
+            .push((chunk.chunk_index, chunk.data.height() as IdxSize, indices));
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.chunks.append(&mut other.chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(self.condition.clone(), self.name.clone()))
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.sort_by_key(|(chunk_index, _, _)| *chunk_index);
+
+        let mut offset = 0 as IdxSize;
+        let mut out = Vec::with_capacity(chunks.iter().map(|(_, _, idx)| idx.len()).sum());
+        for (_, height, indices) in chunks {
+            out.extend(indices.into_iter().map(|idx| idx + offset));
+            offset += height;
+        }
+        let out = IdxCa::from_vec(&self.name, out);
+        Ok(FinalizedSink::Finished(DataFrame::new(vec![
+            out.into_series()
+        ])?))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "arg_where"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::operators::testing::{interleaved_chunks, TestColumn, TestContext};
+
+    #[test]
+    fn test_arg_where_out_of_order() -> PolarsResult<()> {
+        let condition = (0..10_000i32)
+            .map(|i| (i % 3 != 0).then_some(i % 7 < 3))
+            .collect::<BooleanChunked>();
+        let df = DataFrame::new(vec![condition.with_name("condition").into_series()])?;
+        let context = PExecutionContext::new(Box::new(TestContext), false);
+        let sink = ArgWhereSink::new(Arc::new(TestColumn("condition")), "idx".into());
+        let mut sinks = [sink.split(0), sink.split(1)];
+        for (thread, chunk) in interleaved_chunks(&df, 100, true) {
+            sinks[thread].sink(&context, chunk)?;
+        }
+        let [mut a, mut b] = sinks;
+        a.combine(b.as_mut());
+
+        let FinalizedSink::Finished(out) = a.finalize(&context)? else {
+            unreachable!()
+        };
+        let expected = (0..10_000 as IdxSize)
+            .filter(|i| i % 3 != 0 && i % 7 < 3)
+            .collect::<Vec<_>>();
+        let expected = IdxCa::from_vec("idx", expected);
+        assert_eq!(out.get_column_names(), &["idx"]);
+        assert!(out.column("idx")?.equals(&expected.into_series()));
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use polars_utils::unwrap::UnwrapUncheckedRelease;
 
 use super::*;
 
-pub(super) fn new_min<K: NumericNative>() -> MinMaxAgg<K, fn(K, K) -> K> {
+pub(crate) fn new_min<K: NumericNative>() -> MinMaxAgg<K, fn(K, K) -> K> {
     MinMaxAgg::new(MinMax::min_ignore_nan, true)
 }
 
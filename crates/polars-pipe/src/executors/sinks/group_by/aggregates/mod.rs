@@ -10,4 +10,5 @@ mod sum;
 
 pub use convert::*;
 pub(crate) use interface::{AggregateFn, AggregateFunction};
+pub(crate) use min_max::new_min;
 pub(crate) use sum::SumAgg;
@@ -60,6 +60,13 @@ impl<const FIXED: bool> AggHashTable<FIXED> {
         self.inner_map.is_empty()
     }
 
+    /// The size of the keys and the running aggregations of the groups in the table.
+    pub(super) fn estimated_size(&self) -> usize {
+        self.inner_map.len() * std::mem::size_of::<(Key, u32)>()
+            + self.keys.len()
+            + self.running_aggregations.len() * std::mem::size_of::<AggregateFunction>()
+    }
+
     fn get_entry(&mut self, hash: u64, row: &[u8]) -> RawEntryMut<Key, u32, IdBuildHasher> {
         let keys = self.keys.as_ptr();
 
@@ -14,6 +14,8 @@ pub(super) struct OocState {
     pub(super) ooc: bool,
     // when ooc, we write to disk using an IO thread
     pub(super) io_thread: IOThreadRef,
+    // bytes reserved in the memory budget of the query
+    reserved: usize,
     count: u16,
     to_disk_threshold: f64,
 }
@@ -30,6 +32,7 @@ impl Default for OocState {
             mem_track: MemTracker::new(morsels_per_sink()),
             ooc: false,
             io_thread: Default::default(),
+            reserved: 0,
             count: 0,
             to_disk_threshold,
         }
@@ -50,11 +53,13 @@ pub(super) enum SpillAction {
 }
 
 impl OocState {
-    fn init_ooc(&mut self, spill_schema: Schema) -> PolarsResult<()> {
+    fn init_ooc(&mut self, context: &PExecutionContext, spill_schema: Schema) -> PolarsResult<()> {
         if verbose() {
             eprintln!("OOC group_by started");
         }
         self.ooc = true;
+        // From now on the state is spilled to disk.
+        self.release(context);
 
         // start IO thread
         let mut iot = self.io_thread.lock().unwrap();
@@ -66,17 +71,21 @@ impl OocState {
 
     pub(super) fn check_memory_usage(
         &mut self,
+        context: &PExecutionContext,
+        state_bytes: usize,
         spill_schema: &dyn Fn() -> Option<Schema>,
     ) -> PolarsResult<SpillAction> {
         if self.ooc {
             return Ok(SpillAction::Dump);
         }
+        self.reserved += state_bytes;
+        let within_budget = context.memory_budget.reserve(state_bytes);
         let free_frac = self.mem_track.free_memory_fraction_since_start();
         self.count += 1;
 
-        if free_frac < self.to_disk_threshold {
+        if free_frac < self.to_disk_threshold || !within_budget {
             if let Some(schema) = spill_schema() {
-                self.init_ooc(schema)?;
+                self.init_ooc(context, schema)?;
                 Ok(SpillAction::Dump)
             } else {
                 Ok(SpillAction::None)
@@ -91,6 +100,17 @@ impl OocState {
         }
     }
 
+    pub(super) fn combine(&mut self, other: &mut Self) {
+        self.ooc |= other.ooc;
+        self.reserved += std::mem::take(&mut other.reserved);
+    }
+
+    pub(super) fn release(&mut self, context: &PExecutionContext) {
+        context
+            .memory_budget
+            .release(std::mem::take(&mut self.reserved));
+    }
+
     pub(super) fn dump(&self, partition_no: usize, df: DataFrame) {
         let iot = self.io_thread.lock().unwrap();
         let iot = iot.as_ref().unwrap();
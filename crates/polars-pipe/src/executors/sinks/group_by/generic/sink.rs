@@ -73,18 +73,24 @@ impl Sink for GenericGroupby2 {
         let mut aggs = unsafe { self.eval.get_aggs_iters() };
 
         let chunk_idx = chunk.chunk_index;
-        unsafe {
+        // The size the aggregation state grows by: the new groups in the local table and the
+        // overflow that is handed to the global table.
+        let state_bytes = unsafe {
             // SAFETY: the mutable borrows are not aliasing
             let table = &mut *self.thread_local_table.get();
+            let table_bytes = table.estimated_size();
+            let mut spilled_bytes = 0;
 
             for (hash, row) in self.eval.hashes().iter().zip(keys.values_iter()) {
                 if let Some((partition, spill_payload)) =
                     table.insert(*hash, row, &mut aggs, chunk_idx)
                 {
+                    spilled_bytes += spill_payload.estimated_size();
                     self.global_table.spill(partition, spill_payload)
                 }
             }
-        }
+            table.estimated_size().saturating_sub(table_bytes) + spilled_bytes
+        };
 
         // clear memory
         unsafe {
@@ -97,8 +103,9 @@ impl Sink for GenericGroupby2 {
         // other scenario could be that we must spill to disk
         match self
             .ooc_state
-            .check_memory_usage(&|| self.global_table.get_ooc_dump_schema())?
-        {
+            .check_memory_usage(context, state_bytes, &|| {
+                self.global_table.get_ooc_dump_schema()
+            })? {
             SpillAction::None => {},
             SpillAction::EarlyMerge => self.global_table.early_merge(),
             SpillAction::Dump => {
@@ -119,6 +126,7 @@ impl Sink for GenericGroupby2 {
             let other_map = &mut *other.thread_local_table.get();
             map.combine(other_map);
         }
+        self.ooc_state.combine(&mut other.ooc_state);
     }
 
     fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
@@ -134,6 +142,7 @@ impl Sink for GenericGroupby2 {
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.ooc_state.release(context);
         let map = unsafe { &mut *self.thread_local_table.get() };
 
         // only succeeds if it hasn't spilled to global
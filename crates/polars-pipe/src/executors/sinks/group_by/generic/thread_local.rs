@@ -256,6 +256,10 @@ impl ThreadLocalTable {
         }
     }
 
+    pub(super) fn estimated_size(&self) -> usize {
+        self.inner_map.estimated_size()
+    }
+
     pub(super) fn get_inner_map_mut(&mut self) -> &mut AggHashTable<true> {
         &mut self.inner_map
     }
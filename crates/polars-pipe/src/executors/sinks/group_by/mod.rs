@@ -2,11 +2,13 @@ pub(crate) mod aggregates;
 mod generic;
 mod ooc;
 mod ooc_state;
+mod ordered;
 mod primitive;
 mod string;
 mod utils;
 
 pub(crate) use generic::GenericGroupby2;
+pub(crate) use ordered::*;
 use polars_core::prelude::*;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::using_string_cache;
@@ -0,0 +1,125 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+use polars_io::predicates::PhysicalIoExpr;
+use polars_plan::dsl::{col, Expr};
+
+use super::aggregates::{new_min, AggregateFunction};
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{
+    DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult, SourceResult,
+};
+
+const FIRST_SEEN: &str = "__POLARS_GB_FIRST_SEEN";
+
+/// The position of every row in the stream, see [`DataChunk::row_position_offset`].
+struct RowPosition {}
+
+impl PhysicalIoExpr for RowPosition {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+impl PhysicalPipedExpr for RowPosition {
+    fn evaluate(&self, chunk: &DataChunk, _lazy_state: &dyn Any) -> PolarsResult<Series> {
+        let offset = chunk.row_position_offset();
+        let height = chunk.data.height() as u64;
+        Ok(UInt64Chunked::from_vec(FIRST_SEEN, (offset..offset + height).collect()).into_series())
+    }
+
+    fn field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        Ok(Field::new(FIRST_SEEN, DataType::UInt64))
+    }
+
+    fn expression(&self) -> Expr {
+        col(FIRST_SEEN)
+    }
+}
+
+/// The extra aggregation a group-by sink needs to maintain the order of the groups. It computes
+/// the position of the first row of every group.
+pub(crate) fn first_seen_aggregation() -> (DataType, Arc<dyn PhysicalPipedExpr>, AggregateFunction)
+{
+    (
+        DataType::UInt64,
+        Arc::new(RowPosition {}),
+        AggregateFunction::MinMaxU64(new_min()),
+    )
+}
+
+/// The output schema of a group-by sink that computes [`first_seen_aggregation`] last.
+pub(crate) fn first_seen_schema(output_schema: &Schema) -> SchemaRef {
+    let mut schema = output_schema.clone();
+    schema.with_column(FIRST_SEEN.into(), DataType::UInt64);
+    Arc::new(schema)
+}
+
+/// Emits the groups of a group-by sink in the order in which their keys were first seen.
+///
+/// The inner sink must compute [`first_seen_aggregation`] as its last aggregation. All groups
+/// are materialized and sorted by the position of their first row, so unlike the other
+/// group-by sinks the output must fit in memory.
+pub(crate) struct OrderedGroupbySink {
+    sink: Box<dyn Sink>,
+    /// The output schema of the inner sink.
+    schema: SchemaRef,
+    slice: Option<(i64, usize)>,
+}
+
+impl OrderedGroupbySink {
+    pub(crate) fn new(sink: Box<dyn Sink>, schema: SchemaRef, slice: Option<(i64, usize)>) -> Self {
+        Self {
+            sink,
+            schema,
+            slice,
+        }
+    }
+}
+
+impl Sink for OrderedGroupbySink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.sink.sink(context, chunk)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.sink.combine(other.sink.as_mut())
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            sink: self.sink.split(thread_no),
+            schema: self.schema.clone(),
+            slice: self.slice,
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let df = match self.sink.finalize(context)? {
+            FinalizedSink::Finished(df) => df,
+            FinalizedSink::Source(mut source) => {
+                let mut frames = vec![];
+                while let SourceResult::GotMoreData(batch) = source.get_batches(context)? {
+                    frames.extend(batch.into_iter().map(|chunk| chunk.data))
+                }
+                accumulate_dataframes_vertical_unchecked_optional(frames)
+                    .unwrap_or_else(|| DataFrame::from(self.schema.as_ref()))
+            },
+            _ => unreachable!(),
+        };
+        let by = vec![df.column(FIRST_SEEN)?.clone()];
+        let mut df = df.sort_impl(by, SortMultipleOptions::default(), self.slice)?;
+        let _ = df.drop_in_place(FIRST_SEEN)?;
+        Ok(FinalizedSink::Finished(df))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "ordered_group_by"
+    }
+}
@@ -1,6 +1,7 @@
 use std::any::Any;
 use std::iter::StepBy;
 use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::vec;
 
@@ -12,8 +13,8 @@ use smartstring::alias::String as SmartString;
 
 use crate::executors::operators::PlaceHolder;
 use crate::operators::{
-    chunks_to_df_unchecked, DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext,
-    Sink, SinkResult,
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, MemoryReservation, Operator, OperatorResult,
+    PExecutionContext, Sink, SinkResult,
 };
 
 #[derive(Default)]
@@ -23,6 +24,8 @@ pub struct CrossJoin {
     swapped: bool,
     node: Node,
     placeholder: PlaceHolder,
+    // bytes reserved in the memory budget of the query, shared by the threads
+    reserved: Arc<AtomicUsize>,
 }
 
 impl CrossJoin {
@@ -38,6 +41,7 @@ impl CrossJoin {
             swapped,
             node,
             placeholder,
+            reserved: Default::default(),
         }
     }
 }
@@ -50,7 +54,12 @@ impl Sink for CrossJoin {
         true
     }
 
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let chunk_bytes = chunk.data.estimated_size();
+        let node_bytes = self.reserved.fetch_add(chunk_bytes, Ordering::Relaxed) + chunk_bytes;
+        context
+            .memory_budget
+            .try_reserve("cross_join", chunk_bytes, node_bytes)?;
         self.chunks.push(chunk);
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -66,11 +75,12 @@ impl Sink for CrossJoin {
             suffix: self.suffix.clone(),
             swapped: self.swapped,
             placeholder: self.placeholder.clone(),
+            reserved: self.reserved.clone(),
             ..Default::default()
         })
     }
 
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         let op = Box::new(CrossJoinProbe {
             df: Arc::new(chunks_to_df_unchecked(std::mem::take(&mut self.chunks))),
             suffix: Arc::from(self.suffix.as_ref()),
@@ -79,6 +89,10 @@ impl Sink for CrossJoin {
             in_process_left_df: Default::default(),
             output_names: None,
             swapped: self.swapped,
+            _reservation: Arc::new(MemoryReservation::new(
+                context,
+                self.reserved.swap(0, Ordering::Relaxed),
+            )),
         });
         self.placeholder.replace(op);
 
@@ -103,6 +117,8 @@ pub struct CrossJoinProbe {
     in_process_left_df: DataFrame,
     output_names: Option<Vec<SmartString>>,
     swapped: bool,
+    /// bytes of the build side in the memory budget, released when the last thread is done
+    _reservation: Arc<MemoryReservation>,
 }
 
 impl Operator for CrossJoinProbe {
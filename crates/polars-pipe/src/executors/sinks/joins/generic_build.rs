@@ -1,4 +1,5 @@
 use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use arrow::array::BinaryArray;
 use hashbrown::hash_map::RawEntryMut;
@@ -14,10 +15,13 @@ use super::*;
 use crate::executors::operators::PlaceHolder;
 use crate::executors::sinks::joins::generic_probe_inner_left::GenericJoinProbe;
 use crate::executors::sinks::joins::generic_probe_outer::GenericOuterJoinProbe;
+use crate::executors::sinks::joins::row_values::keys_validity;
 use crate::executors::sinks::utils::{hash_rows, load_vec};
 use crate::executors::sinks::HASHMAP_INIT_SIZE;
 use crate::expressions::PhysicalPipedExpr;
-use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+use crate::operators::{
+    DataChunk, FinalizedSink, MemoryReservation, PExecutionContext, Sink, SinkResult,
+};
 
 pub(super) type ChunkIdx = IdxSize;
 pub(super) type DfIdx = IdxSize;
@@ -53,6 +57,8 @@ pub struct GenericBuild<K: ExtraPayload> {
     key_names_left: Arc<[SmartString]>,
     key_names_right: Arc<[SmartString]>,
     placeholder: PlaceHolder,
+    // bytes reserved in the memory budget of the query, shared by the threads
+    reserved: Arc<AtomicUsize>,
 }
 
 impl<K: ExtraPayload> GenericBuild<K> {
@@ -91,6 +97,7 @@ impl<K: ExtraPayload> GenericBuild<K> {
             key_names_left,
             key_names_right,
             placeholder,
+            reserved: Default::default(),
         }
     }
 }
@@ -175,16 +182,37 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
             }
             return Ok(SinkResult::CanHaveMoreInput);
         }
+        // The hash table has to fit in memory.
+        let chunk_bytes = chunk.data.estimated_size();
+        let node_bytes = self.reserved.fetch_add(chunk_bytes, Ordering::Relaxed) + chunk_bytes;
+        context
+            .memory_budget
+            .try_reserve("join", chunk_bytes, node_bytes)?;
+
         let mut hashes = std::mem::take(&mut self.hashes);
         let rows = self.set_join_series(context, &chunk)?.clone();
         hash_rows(&rows, &mut hashes, &self.hb);
         self.hashes = hashes;
 
+        // Without `join_nulls` a key containing a null never matches. Only an outer join
+        // needs those rows in the table, so that it can emit them as unmatched.
+        let validity = if self.join_nulls || matches!(self.join_type, JoinType::Outer { .. }) {
+            None
+        } else {
+            keys_validity(&self.join_columns)
+        };
+
         let current_chunk_offset = self.chunks.len() as ChunkIdx;
 
         // row offset in the chunk belonging to the hash
         let mut current_df_idx = 0 as IdxSize;
         for (row, h) in rows.values_iter().zip(&self.hashes) {
+            if let Some(validity) = &validity {
+                if !unsafe { validity.get_bit_unchecked(current_df_idx as usize) } {
+                    current_df_idx += 1;
+                    continue;
+                }
+            }
             let entry = self.hash_tables.raw_entry_mut(*h).from_hash(*h, |key| {
                 compare_fn(key, *h, &self.materialized_join_cols, row)
             });
@@ -289,6 +317,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
             self.placeholder.clone(),
         );
         new.hb = self.hb.clone();
+        new.reserved = self.reserved.clone();
         Box::new(new)
     }
 
@@ -316,6 +345,11 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         // take the buffers, this saves one allocation
         let mut hashes = std::mem::take(&mut self.hashes);
         hashes.clear();
+        // the probe holds the build side, so it holds its reservation
+        let reservation = Arc::new(MemoryReservation::new(
+            context,
+            self.reserved.swap(0, Ordering::Relaxed),
+        ));
 
         match self.join_type {
             JoinType::Inner | JoinType::Left => {
@@ -332,6 +366,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                     context,
                     self.join_type.clone(),
                     self.join_nulls,
+                    reservation,
                 );
                 self.placeholder.replace(Box::new(probe_operator));
                 Ok(FinalizedSink::Operator)
@@ -350,6 +385,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
                     coalesce,
                     self.key_names_left.clone(),
                     self.key_names_right.clone(),
+                    reservation,
                 );
                 self.placeholder.replace(Box::new(probe_operator));
                 Ok(FinalizedSink::Operator)
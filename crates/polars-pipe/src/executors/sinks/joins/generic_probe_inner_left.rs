@@ -15,7 +15,7 @@ use crate::executors::sinks::joins::row_values::RowValues;
 use crate::executors::sinks::joins::{ExtraPayload, PartitionedMap, ToRow};
 use crate::executors::sinks::utils::hash_rows;
 use crate::expressions::PhysicalPipedExpr;
-use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+use crate::operators::{DataChunk, MemoryReservation, Operator, OperatorResult, PExecutionContext};
 
 #[derive(Clone)]
 pub struct GenericJoinProbe<K: ExtraPayload> {
@@ -51,6 +51,8 @@ pub struct GenericJoinProbe<K: ExtraPayload> {
     how: JoinType,
     join_nulls: bool,
     row_values: RowValues,
+    /// bytes of the build side in the memory budget, released when the last thread is done
+    _reservation: Arc<MemoryReservation>,
 }
 
 impl<K: ExtraPayload> GenericJoinProbe<K> {
@@ -69,6 +71,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         context: &PExecutionContext,
         how: JoinType,
         join_nulls: bool,
+        reservation: Arc<MemoryReservation>,
     ) -> Self {
         if swapped_or_left {
             let tmp = DataChunk {
@@ -103,6 +106,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             how,
             join_nulls,
             row_values: RowValues::new(join_columns_right, !swapped_or_left),
+            _reservation: reservation,
         }
     }
 
@@ -15,7 +15,7 @@ use crate::executors::sinks::joins::PartitionedMap;
 use crate::executors::sinks::utils::hash_rows;
 use crate::executors::sinks::ExtraPayload;
 use crate::expressions::PhysicalPipedExpr;
-use crate::operators::{DataChunk, Operator, OperatorResult, PExecutionContext};
+use crate::operators::{DataChunk, MemoryReservation, Operator, OperatorResult, PExecutionContext};
 
 #[derive(Clone)]
 pub struct GenericOuterJoinProbe<K: ExtraPayload> {
@@ -56,6 +56,8 @@ pub struct GenericOuterJoinProbe<K: ExtraPayload> {
     row_values: RowValues,
     key_names_left: Arc<[SmartString]>,
     key_names_right: Arc<[SmartString]>,
+    /// bytes of the build side in the memory budget, released when the last thread is done
+    _reservation: Arc<MemoryReservation>,
 }
 
 impl<K: ExtraPayload> GenericOuterJoinProbe<K> {
@@ -74,6 +76,7 @@ impl<K: ExtraPayload> GenericOuterJoinProbe<K> {
         coalesce: bool,
         key_names_left: Arc<[SmartString]>,
         key_names_right: Arc<[SmartString]>,
+        reservation: Arc<MemoryReservation>,
     ) -> Self {
         GenericOuterJoinProbe {
             df_a: Arc::new(df_a),
@@ -93,6 +96,7 @@ impl<K: ExtraPayload> GenericOuterJoinProbe<K> {
             row_values: RowValues::new(join_columns_right, false),
             key_names_left,
             key_names_right,
+            _reservation: reservation,
         }
     }
 
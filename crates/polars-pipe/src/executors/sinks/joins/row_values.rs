@@ -1,13 +1,28 @@
 use std::sync::Arc;
 
 use arrow::array::{ArrayRef, BinaryArray, StaticArray};
+use arrow::bitmap::Bitmap;
 use arrow::compute::utils::combine_validities_and_many;
+use arrow::datatypes::ArrowDataType;
 use polars_core::error::PolarsResult;
 use polars_row::RowsEncoded;
 
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::{DataChunk, PExecutionContext};
 
+/// The combined validity of the join keys. A row is only valid if none of its keys are null.
+pub(super) fn keys_validity(join_columns: &[ArrayRef]) -> Option<Bitmap> {
+    let validities = join_columns
+        .iter()
+        .map(|arr| match arr.data_type() {
+            // A null array has no validity, but none of its values are valid.
+            ArrowDataType::Null => Some(Bitmap::new_zeroed(arr.len())),
+            _ => arr.validity().cloned(),
+        })
+        .collect::<Vec<_>>();
+    combine_validities_and_many(&validities)
+}
+
 #[derive(Clone)]
 pub(super) struct RowValues {
     current_rows: RowsEncoded,
@@ -80,13 +95,7 @@ impl RowValues {
         Ok(if join_nulls {
             array
         } else {
-            let validities = self
-                .join_columns_material
-                .iter()
-                .map(|arr| arr.validity())
-                .collect::<Vec<_>>();
-            let validity = combine_validities_and_many(&validities);
-            array.with_validity_typed(validity)
+            array.with_validity_typed(keys_validity(&self.join_columns_material))
         })
     }
 }
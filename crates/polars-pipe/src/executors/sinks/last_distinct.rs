@@ -0,0 +1,411 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use polars_core::config::verbose;
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use polars_io::ipc::IpcReader;
+use polars_io::SerReader;
+use rayon::prelude::*;
+use smartstring::alias::String as SmartString;
+
+use crate::executors::sinks::io::IOThread;
+use crate::executors::sinks::memory::MemTracker;
+use crate::executors::sources::get_source_index;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{
+    DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult, Source, SourceResult,
+};
+use crate::pipeline::{morsels_per_sink, FORCE_OOC};
+
+/// Adds a column that flags the last occurrence of every key, the streaming
+/// `is_last_distinct`.
+///
+/// Whether a row is the last of its key is only known once the whole stream is seen. Every
+/// thread keeps the position of the last row of every key it saw, which are merged on
+/// `combine`, and the chunks, which are flagged on `finalize`. If the chunks don't fit in
+/// memory or the memory budget of the query, they are spilled to disk instead and the flags are
+/// joined back when the [`LastDistinctSource`] reads them again.
+pub struct LastDistinctSink {
+    /// The keys, a struct key is distinct by all its fields.
+    key: Arc<dyn PhysicalPipedExpr>,
+    name: SmartString,
+    /// The position of the last row of every row encoded key, see
+    /// [`DataChunk::row_position_offset`].
+    positions: PlHashMap<Vec<u8>, u64>,
+    /// The chunks that are held in memory.
+    chunks: Vec<DataChunk>,
+    // Stores available memory in the system at the start of this sink.
+    // and stores the memory used by this this sink.
+    mem_track: MemTracker,
+    // keep the chunks in memory or spill them to disk
+    ooc: bool,
+    // bytes reserved in the memory budget of the query, only while the chunks are in memory
+    reserved: usize,
+    // Shared by all threads, started once the first thread spills.
+    io_thread: Arc<RwLock<Option<IOThread>>>,
+    input_schema: SchemaRef,
+    output_schema: SchemaRef,
+}
+
+impl LastDistinctSink {
+    pub(crate) fn new(
+        key: Arc<dyn PhysicalPipedExpr>,
+        name: SmartString,
+        input_schema: SchemaRef,
+        output_schema: SchemaRef,
+    ) -> Self {
+        LastDistinctSink {
+            key,
+            name,
+            positions: Default::default(),
+            chunks: vec![],
+            mem_track: MemTracker::new(morsels_per_sink()),
+            // for testing purposes
+            ooc: std::env::var(FORCE_OOC).is_ok(),
+            reserved: 0,
+            io_thread: Default::default(),
+            input_schema,
+            output_schema,
+        }
+    }
+
+    fn init_ooc(&mut self) -> PolarsResult<()> {
+        if verbose() {
+            eprintln!("OOC last_distinct started");
+        }
+        self.ooc = true;
+
+        let mut iot = self.io_thread.write().unwrap();
+        if iot.is_none() {
+            *iot = Some(IOThread::try_new(
+                self.input_schema.clone(),
+                "last_distinct",
+            )?);
+        }
+        Ok(())
+    }
+
+    /// Spill the chunks held in memory. Every chunk gets its own directory, so the source can
+    /// read them in order.
+    fn dump(&mut self) -> PolarsResult<()> {
+        if self.io_thread.read().unwrap().is_none() {
+            self.init_ooc()?;
+        }
+        let iot = self.io_thread.read().unwrap();
+        let iot = iot.as_ref().unwrap();
+        for chunk in self.chunks.drain(..) {
+            iot.dump_partition_local(chunk.chunk_index, chunk.data);
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, key: &[u8], position: u64) {
+        match self.positions.get_mut(key) {
+            Some(last) => *last = std::cmp::max(*last, position),
+            None => {
+                self.positions.insert(key.to_vec(), position);
+            },
+        }
+    }
+}
+
+fn encode_keys(
+    key: &dyn PhysicalPipedExpr,
+    context: &PExecutionContext,
+    chunk: &DataChunk,
+) -> PolarsResult<BinaryOffsetChunked> {
+    let key = key.evaluate(chunk, context.execution_state.as_any())?;
+    _get_rows_encoded_ca_unordered("", &[key])
+}
+
+/// Add the flags column to a chunk, given the position of the last row of every key.
+fn flag_last(
+    key: &dyn PhysicalPipedExpr,
+    name: &str,
+    positions: &PlHashMap<Vec<u8>, u64>,
+    context: &PExecutionContext,
+    chunk: DataChunk,
+) -> PolarsResult<DataFrame> {
+    let keys = encode_keys(key, context, &chunk)?;
+    let offset = chunk.row_position_offset();
+    let flags = keys
+        .into_no_null_iter()
+        .enumerate()
+        .map(|(row, key)| positions[key] == offset + row as u64);
+    let flags = BooleanChunked::from_iter_values(name, flags);
+    let mut df = chunk.data;
+    df.with_column(flags.into_series())?;
+    Ok(df)
+}
+
+impl Sink for LastDistinctSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        if chunk.is_empty() {
+            return Ok(SinkResult::CanHaveMoreInput);
+        }
+        let keys = encode_keys(self.key.as_ref(), context, &chunk)?;
+        let offset = chunk.row_position_offset();
+        for (row, key) in keys.into_no_null_iter().enumerate() {
+            self.insert(key, offset + row as u64);
+        }
+
+        if !self.ooc {
+            let chunk_bytes = chunk.data.estimated_size();
+            let used = self.mem_track.fetch_add(chunk_bytes);
+            let free = self.mem_track.get_available();
+            self.reserved += chunk_bytes;
+            let within_budget = context.memory_budget.reserve(chunk_bytes);
+
+            // Keep some free memory to flag the chunks.
+            if used * 2 > free || !within_budget {
+                if !within_budget && context.verbose {
+                    eprintln!("memory budget of the query exceeded");
+                }
+                self.init_ooc()?;
+                self.dump()?;
+                context
+                    .memory_budget
+                    .release(std::mem::take(&mut self.reserved));
+            }
+        }
+        self.chunks.push(chunk);
+        if self.ooc {
+            self.dump()?;
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        for (key, position) in std::mem::take(&mut other.positions) {
+            self.insert(&key, position);
+        }
+        self.chunks.append(&mut other.chunks);
+        self.ooc |= other.ooc;
+        self.reserved += std::mem::take(&mut other.reserved);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            key: self.key.clone(),
+            name: self.name.clone(),
+            positions: Default::default(),
+            chunks: vec![],
+            mem_track: self.mem_track.clone(),
+            ooc: self.ooc,
+            reserved: 0,
+            io_thread: self.io_thread.clone(),
+            input_schema: self.input_schema.clone(),
+            output_schema: self.output_schema.clone(),
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // The chunks are either spilled or handed over to the next node.
+        context
+            .memory_budget
+            .release(std::mem::take(&mut self.reserved));
+        if self.io_thread.read().unwrap().is_none() {
+            if self.chunks.is_empty() {
+                return Ok(FinalizedSink::Finished(DataFrame::from(
+                    self.output_schema.as_ref(),
+                )));
+            }
+            let mut chunks = std::mem::take(&mut self.chunks);
+            chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+            let dfs = POOL.install(|| {
+                chunks
+                    .into_par_iter()
+                    .map(|chunk| {
+                        flag_last(
+                            self.key.as_ref(),
+                            &self.name,
+                            &self.positions,
+                            context,
+                            chunk,
+                        )
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?;
+            return Ok(FinalizedSink::Finished(
+                accumulate_dataframes_vertical_unchecked(dfs),
+            ));
+        }
+
+        // Some threads spilled, so the chunks that are still in memory are spilled as well.
+        self.dump()?;
+        let io_thread = self.io_thread.write().unwrap().take().unwrap();
+        let mut chunks = std::fs::read_dir(&io_thread.dir)?
+            .map(|entry| {
+                let path = entry?.path();
+                let chunk_index = path
+                    .file_name()
+                    .and_then(|name| name.to_str()?.parse::<IdxSize>().ok());
+                Ok(chunk_index.map(|chunk_index| (chunk_index, path)))
+            })
+            .filter_map(|entry: std::io::Result<_>| entry.transpose())
+            .collect::<std::io::Result<Vec<_>>>()?;
+        chunks.sort_unstable_by_key(|(chunk_index, _)| *chunk_index);
+
+        Ok(FinalizedSink::Source(Box::new(LastDistinctSource {
+            key: self.key.clone(),
+            name: self.name.clone(),
+            positions: std::mem::take(&mut self.positions),
+            chunks: chunks.into_iter(),
+            io_thread,
+            n_threads: POOL.current_num_threads(),
+        })))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "last_distinct"
+    }
+}
+
+/// Reads the chunks spilled by a [`LastDistinctSink`] in order and flags the last row of
+/// every key.
+pub struct LastDistinctSource {
+    key: Arc<dyn PhysicalPipedExpr>,
+    name: SmartString,
+    positions: PlHashMap<Vec<u8>, u64>,
+    /// The directories of the spilled chunks, ordered by chunk index.
+    chunks: std::vec::IntoIter<(IdxSize, PathBuf)>,
+    // Holding this keeps the lockfile in place
+    io_thread: IOThread,
+    n_threads: usize,
+}
+
+impl LastDistinctSource {
+    /// Read the spilled parts of one chunk and flag their rows.
+    fn read_chunk(
+        &self,
+        context: &PExecutionContext,
+        chunk_index: IdxSize,
+        path: &Path,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        let mut files = std::fs::read_dir(path)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        files.sort_unstable();
+
+        files
+            .iter()
+            .map(|file| {
+                let file = polars_utils::open_file(file)?;
+                let df = IpcReader::new(file).finish()?;
+                let chunk = DataChunk::new(chunk_index, df);
+                flag_last(
+                    self.key.as_ref(),
+                    &self.name,
+                    &self.positions,
+                    context,
+                    chunk,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Source for LastDistinctSource {
+    fn get_batches(&mut self, context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batch = self
+            .chunks
+            .by_ref()
+            .take(self.n_threads)
+            .collect::<Vec<_>>();
+        if batch.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+        let dfs = POOL.install(|| {
+            batch
+                .par_iter()
+                .map(|(chunk_index, path)| self.read_chunk(context, *chunk_index, path))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        for (_, path) in batch {
+            self.io_thread.clean(path);
+        }
+
+        let dfs = dfs.into_iter().flatten().collect::<Vec<_>>();
+        let chunk_offset = get_source_index(dfs.len() as u32) as IdxSize;
+        let chunks = dfs
+            .into_iter()
+            .enumerate()
+            .map(|(i, df)| DataChunk::new(chunk_offset + i as IdxSize, df))
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "last_distinct_source"
+    }
+}
+
+#[cfg(all(test, feature = "is_last_distinct"))]
+mod test {
+    use polars_ops::prelude::is_last_distinct;
+
+    use super::*;
+    use crate::operators::testing::{interleaved_chunks, TestColumn, TestContext};
+
+    #[test]
+    fn test_last_distinct_out_of_order() -> PolarsResult<()> {
+        // Every key, including null, occurs in many chunks.
+        let key = (0..10_000i32)
+            .map(|i| Some((i * 7) % 31).filter(|v| *v != 3))
+            .collect::<Vec<_>>();
+        let df = df![
+            "key" => key,
+            "value" => (0..10_000i32).collect::<Vec<_>>(),
+        ]?;
+        let mut output_schema = df.schema();
+        output_schema.with_column("flag".into(), DataType::Boolean);
+        let expected = is_last_distinct(df.column("key")?)?;
+
+        // The chunks are kept in memory, or spilled if they exceed the memory budget.
+        for spill in [false, true] {
+            let mut context = PExecutionContext::new(Box::new(TestContext), false);
+            if spill {
+                context = context.with_memory_limit(1 << 10);
+            }
+            let sink = LastDistinctSink::new(
+                Arc::new(TestColumn("key")),
+                "flag".into(),
+                Arc::new(df.schema()),
+                Arc::new(output_schema.clone()),
+            );
+            let mut sinks = [sink.split(0), sink.split(1)];
+            for (thread, chunk) in interleaved_chunks(&df, 100, true) {
+                sinks[thread].sink(&context, chunk)?;
+            }
+            let [mut a, mut b] = sinks;
+            a.combine(b.as_mut());
+
+            let out = match a.finalize(&context)? {
+                FinalizedSink::Finished(out) if !spill => out,
+                FinalizedSink::Source(mut src) if spill => {
+                    let mut chunks = vec![];
+                    while let SourceResult::GotMoreData(batch) = src.get_batches(&context)? {
+                        chunks.extend(batch.into_iter().map(|chunk| chunk.data));
+                    }
+                    accumulate_dataframes_vertical_unchecked(chunks)
+                },
+                _ => panic!("expected the chunks to be spilled: {spill}"),
+            };
+            assert_eq!(out.get_column_names(), &["key", "value", "flag"]);
+            assert!(out.column("value")?.equals(df.column("value")?));
+            assert!(out
+                .column("flag")?
+                .equals(&expected.clone().with_name("flag").into_series()));
+        }
+        Ok(())
+    }
+}
@@ -75,4 +75,9 @@ impl MemTracker {
     pub(super) fn fetch_add(&self, add: usize) -> usize {
         self.used_by_node.fetch_add(add, Ordering::Relaxed)
     }
+
+    /// Decrement the used memory and return the previous value.
+    pub(super) fn fetch_sub(&self, sub: usize) -> usize {
+        self.used_by_node.fetch_sub(sub, Ordering::Relaxed)
+    }
 }
@@ -1,17 +1,26 @@
+#[cfg(feature = "arg_where")]
+mod arg_where;
 pub(crate) mod group_by;
 mod io;
 mod joins;
+#[cfg(feature = "is_last_distinct")]
+mod last_distinct;
 mod memory;
 mod ordered;
 mod output;
 mod reproject;
 mod slice;
 mod sort;
+mod tail;
 mod utils;
 
 use std::sync::OnceLock;
 
+#[cfg(feature = "arg_where")]
+pub(crate) use arg_where::*;
 pub(crate) use joins::*;
+#[cfg(feature = "is_last_distinct")]
+pub(crate) use last_distinct::*;
 pub(crate) use ordered::*;
 #[cfg(any(
     feature = "parquet",
@@ -23,6 +32,7 @@ pub(crate) use output::*;
 pub(crate) use reproject::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
+pub(crate) use tail::*;
 
 // We must strike a balance between cache coherence and resizing costs.
 // Overallocation seems a lot more expensive than resizing so we start reasonable small.
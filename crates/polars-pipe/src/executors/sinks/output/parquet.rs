@@ -4,13 +4,15 @@ use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars_core::prelude::*;
-use polars_io::parquet::write::{BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIter};
+use polars_io::parquet::write::{
+    BatchedWriter, EncodedRowGroup, ParquetWriteOptions, ParquetWriter,
+};
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-type RowGroups = Vec<RowGroupIter<'static, PolarsError>>;
+type RowGroups = Vec<EncodedRowGroup>;
 
 pub(super) fn init_row_group_writer_thread(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
@@ -64,6 +66,7 @@ impl ParquetSink {
             .with_data_page_size(options.data_pagesize_limit)
             .with_statistics(options.statistics)
             .with_row_group_size(options.row_group_size)
+            .with_bloom_filter(options.bloom_filter)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -91,7 +94,7 @@ impl ParquetSink {
 
 impl Sink for ParquetSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
-        // Encode and compress row-groups on every thread.
+        // Encode and compress row-groups and build their bloom filters on every thread.
         let row_groups = self
             .writer
             .encode_and_compress(&chunk.data)
@@ -155,6 +158,7 @@ impl ParquetCloudSink {
             .with_data_page_size(parquet_options.data_pagesize_limit)
             .with_statistics(parquet_options.statistics)
             .with_row_group_size(parquet_options.row_group_size)
+            .with_bloom_filter(parquet_options.bloom_filter)
             // This is important! Otherwise we will deadlock
             // See: #7074
             .set_parallel(false)
@@ -0,0 +1,79 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_plan::prelude::SplitNulls;
+use smartstring::alias::String as SmartString;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// Routes every row of a chunk to one of two sinks by a boolean column, which is dropped.
+///
+/// Both parts of a chunk keep its chunk index, so the sinks can restore the order of the rows
+/// like they do for a single output.
+pub struct SplitSink {
+    predicate: SmartString,
+    nulls: SplitNulls,
+    on_true: Box<dyn Sink>,
+    on_false: Box<dyn Sink>,
+}
+
+impl SplitSink {
+    pub fn new(
+        predicate: &str,
+        nulls: SplitNulls,
+        on_true: Box<dyn Sink>,
+        on_false: Box<dyn Sink>,
+    ) -> Self {
+        Self {
+            predicate: predicate.into(),
+            nulls,
+            on_true,
+            on_false,
+        }
+    }
+}
+
+impl Sink for SplitSink {
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        let mut df = chunk.data;
+        let mask = df.drop_in_place(&self.predicate)?;
+        let mask = mask.bool()?;
+
+        let is_true = mask.fill_null_with_values(self.nulls == SplitNulls::True)?;
+        let is_false = !&mask.fill_null_with_values(self.nulls != SplitNulls::False)?;
+        let on_true = DataChunk::new(chunk.chunk_index, df.filter(&is_true)?);
+        let on_false = DataChunk::new(chunk.chunk_index, df.filter(&is_false)?);
+        self.on_true.sink(context, on_true)?;
+        self.on_false.sink(context, on_false)?;
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.on_true.combine(other.on_true.as_mut());
+        self.on_false.combine(other.on_false.as_mut());
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            predicate: self.predicate.clone(),
+            nulls: self.nulls,
+            on_true: self.on_true.split(thread_no),
+            on_false: self.on_false.split(thread_no),
+        })
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        self.on_true.finalize(context)?;
+        self.on_false.finalize(context)?;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "split_sink"
+    }
+}
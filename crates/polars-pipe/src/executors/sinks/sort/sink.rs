@@ -15,6 +15,9 @@ use crate::executors::sinks::sort::ooc::sort_ooc;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::{morsels_per_sink, FORCE_OOC};
 
+// Don't prune the in-memory chunks of a `sort().head(k)` before we have at least this many rows.
+const MIN_ROWS_BEFORE_PRUNE: usize = 1 << 16;
+
 pub struct SortSink {
     schema: SchemaRef,
     chunks: Vec<DataFrame>,
@@ -37,6 +40,8 @@ pub struct SortSink {
     current_chunk_rows: usize,
     // total bytes of tables in current chunks
     current_chunks_size: usize,
+    // bytes reserved in the memory budget of the query, only while we sort in-memory
+    reserved: usize,
     // Start time of OOC phase.
     ooc_start: Option<Instant>,
 }
@@ -64,6 +69,7 @@ impl SortSink {
             dist_sample: vec![],
             current_chunk_rows: 0,
             current_chunks_size: 0,
+            reserved: 0,
             ooc_start: None,
         };
         if ooc {
@@ -90,17 +96,25 @@ impl SortSink {
         Ok(())
     }
 
-    fn store_chunk(&mut self, chunk: DataChunk) -> PolarsResult<()> {
+    fn store_chunk(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<()> {
         let chunk_bytes = chunk.data.estimated_size();
         if !self.ooc {
             let used = self.mem_track.fetch_add(chunk_bytes);
             let free = self.mem_track.get_available();
+            self.reserved += chunk_bytes;
+            let within_budget = context.memory_budget.reserve(chunk_bytes);
 
             // we need some free memory to be able to sort
             // so we keep 3x the sort data size before we go out of core
-            if used * 3 > free {
+            if used * 3 > free || !within_budget {
+                if !within_budget && context.verbose {
+                    eprintln!("memory budget of the query exceeded");
+                }
                 self.init_ooc()?;
                 self.dump(true)?;
+                context
+                    .memory_budget
+                    .release(std::mem::take(&mut self.reserved));
             }
         };
         // don't add empty dataframes
@@ -109,6 +123,37 @@ impl SortSink {
             self.current_chunk_rows += chunk.data.height();
             self.chunks.push(chunk.data);
         }
+        if !self.ooc {
+            self.prune_to_head()?;
+        }
+        Ok(())
+    }
+
+    /// If we only need the first `k` rows of the sorted output, the accumulated chunks can
+    /// be reduced to their own top-k without changing the final result. We do that once we
+    /// hold more than `2 * k` rows, so every prune discards at least `k` rows.
+    fn prune_to_head(&mut self) -> PolarsResult<()> {
+        let Some((0, k)) = self.slice else {
+            return Ok(());
+        };
+        if self.current_chunk_rows <= std::cmp::max(2 * k, MIN_ROWS_BEFORE_PRUNE) {
+            return Ok(());
+        }
+
+        let df = accumulate_dataframes_vertical_unchecked(self.chunks.drain(..));
+        let df = sort_accumulated(
+            df,
+            self.sort_idx,
+            self.slice,
+            SortOptions::from(&self.sort_options),
+        )?;
+
+        let pruned_size = df.estimated_size();
+        self.mem_track
+            .fetch_sub(self.current_chunks_size.saturating_sub(pruned_size));
+        self.current_chunk_rows = df.height();
+        self.current_chunks_size = pruned_size;
+        self.chunks.push(df);
         Ok(())
     }
 
@@ -142,11 +187,13 @@ impl SortSink {
 }
 
 impl Sink for SortSink {
-    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
-        self.store_chunk(chunk)?;
+    fn sink(&mut self, context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.store_chunk(context, chunk)?;
 
         if self.ooc {
-            self.dump(false)?;
+            // Spill early if the chunks we hold don't fit in the memory budget.
+            let force = !context.memory_budget.fits(self.current_chunks_size);
+            self.dump(force)?;
         }
         Ok(SinkResult::CanHaveMoreInput)
     }
@@ -158,6 +205,7 @@ impl Sink for SortSink {
         }
         self.chunks.extend(std::mem::take(&mut other.chunks));
         self.ooc |= other.ooc;
+        self.reserved += std::mem::take(&mut other.reserved);
         self.dist_sample
             .extend(std::mem::take(&mut other.dist_sample));
 
@@ -179,11 +227,16 @@ impl Sink for SortSink {
             dist_sample: vec![],
             current_chunk_rows: 0,
             current_chunks_size: 0,
+            reserved: 0,
             ooc_start: self.ooc_start,
         })
     }
 
     fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // The data is either spilled or handed over to the next node.
+        context
+            .memory_budget
+            .release(std::mem::take(&mut self.reserved));
         if self.ooc {
             // spill everything
             self.dump(true).unwrap();
@@ -250,3 +303,52 @@ pub(super) fn sort_accumulated(
         slice,
     )
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::operators::{SExecutionContext, SourceResult};
+
+    struct TestContext;
+
+    impl SExecutionContext for TestContext {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn should_stop(&self) -> PolarsResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_sort_spills_if_memory_budget_is_exceeded() -> PolarsResult<()> {
+        // A permutation of the keys, about 1.6 MB of data.
+        let df = df![
+            "a" => (0..100_000i64).map(|i| (i * 7_919) % 100_000).collect::<Vec<_>>(),
+            "b" => (0..100_000i64).collect::<Vec<_>>(),
+        ]?;
+        let context =
+            PExecutionContext::new(Box::new(TestContext), false).with_memory_limit(1 << 16);
+        let mut sink = SortSink::new(0, None, Default::default(), Arc::new(df.schema()));
+
+        for i in 0..100 {
+            let chunk = DataChunk::new(i as IdxSize, df.slice(i * 1000, 1000));
+            sink.sink(&context, chunk)?;
+        }
+        assert!(sink.ooc);
+
+        let FinalizedSink::Source(mut src) = sink.finalize(&context)? else {
+            panic!("expected an out-of-core sort")
+        };
+        let mut chunks = vec![];
+        while let SourceResult::GotMoreData(batch) = src.get_batches(&context)? {
+            chunks.extend(batch.into_iter().map(|chunk| chunk.data));
+        }
+        let out = accumulate_dataframes_vertical_unchecked(chunks);
+        assert!(out.equals(&df.sort(["a"], Default::default())?));
+        Ok(())
+    }
+}
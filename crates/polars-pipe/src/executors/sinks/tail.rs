@@ -0,0 +1,131 @@
+use std::any::Any;
+use std::collections::VecDeque;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+
+use crate::operators::{
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult,
+};
+
+/// Keeps the last `n` rows of the stream.
+///
+/// Every thread only holds on to the chunks that can contain one of its last `n` rows, so
+/// the memory is bounded by `n` rows plus a chunk per thread instead of by the input.
+#[derive(Clone)]
+pub struct TailSink {
+    n: usize,
+    /// Ordered by chunk index.
+    chunks: VecDeque<DataChunk>,
+    /// The number of rows in `chunks`.
+    height: usize,
+    schema: SchemaRef,
+}
+
+impl TailSink {
+    pub fn new(n: usize, schema: SchemaRef) -> Self {
+        TailSink {
+            n,
+            chunks: VecDeque::new(),
+            height: 0,
+            schema,
+        }
+    }
+
+    fn push(&mut self, chunk: DataChunk) {
+        let height = chunk.data.height();
+        if height == 0 {
+            return;
+        }
+        // The chunks mostly come in order, so search from the back.
+        let idx = self
+            .chunks
+            .iter()
+            .rposition(|c| c.chunk_index < chunk.chunk_index)
+            .map_or(0, |i| i + 1);
+        self.chunks.insert(idx, chunk);
+        self.height += height;
+
+        // Drop the oldest chunks as long as the newer chunks hold the tail.
+        while let Some(first) = self.chunks.front() {
+            let first_height = first.data.height();
+            if self.height - first_height < self.n {
+                break;
+            }
+            self.height -= first_height;
+            self.chunks.pop_front();
+        }
+    }
+}
+
+impl Sink for TailSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.push(chunk);
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<TailSink>().unwrap();
+        for chunk in std::mem::take(&mut other.chunks) {
+            self.push(chunk);
+        }
+        other.height = 0;
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(self.n, self.schema.clone()))
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        if self.chunks.is_empty() {
+            return Ok(FinalizedSink::Finished(DataFrame::from(
+                self.schema.as_ref(),
+            )));
+        }
+        let chunks = std::mem::take(&mut self.chunks);
+        self.height = 0;
+        let df = chunks_to_df_unchecked(chunks.into());
+        Ok(FinalizedSink::Finished(df.tail(Some(self.n))))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "tail_sink"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_tail_sink_is_bounded() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..10_000i32).collect::<Vec<_>>(),
+        ]?;
+        let mut sinks = [
+            TailSink::new(10, Arc::new(df.schema())),
+            TailSink::new(10, Arc::new(df.schema())),
+        ];
+        // Two threads that receive interleaved, out of order chunks.
+        for i in (0..100i64).rev() {
+            let chunk = DataChunk::new(i as IdxSize, df.slice(i * 100, 100));
+            let sink = &mut sinks[i as usize % 2];
+            sink.push(chunk);
+            assert!(sink.height <= 110);
+        }
+        let [mut a, mut b] = sinks;
+        a.combine(&mut b);
+        assert_eq!(a.chunks.len(), 1);
+
+        let out = chunks_to_df_unchecked(a.chunks.into()).tail(Some(10));
+        assert!(out.equals(&df.tail(Some(10))));
+        Ok(())
+    }
+}
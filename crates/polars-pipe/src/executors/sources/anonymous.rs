@@ -0,0 +1,48 @@
+use polars_core::error::PolarsResult;
+use polars_core::POOL;
+use polars_plan::prelude::AnonymousScanBatches;
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// A source that pulls the batches of an anonymous scan, every batch becomes a morsel.
+pub struct AnonymousSource {
+    batches: AnonymousScanBatches,
+    n_threads: usize,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(batches: AnonymousScanBatches) -> Self {
+        Self {
+            batches,
+            n_threads: POOL.current_num_threads(),
+        }
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let batches = (&mut self.batches)
+            .take(self.n_threads)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if batches.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+
+        let idx_offset = get_source_index(batches.len() as u32);
+        let chunks = batches
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (idx_offset + i as u32) as IdxSize,
+                data,
+            })
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
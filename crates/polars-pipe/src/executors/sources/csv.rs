@@ -75,6 +75,7 @@ impl CsvSource {
             ._with_comment_prefix(options.comment_prefix)
             .with_quote_char(options.quote_char)
             .with_end_of_line_char(options.eol_char)
+            .with_record_separator(options.record_separator.clone())
             .with_encoding(options.encoding)
             // never rechunk in streaming
             .with_rechunk(false)
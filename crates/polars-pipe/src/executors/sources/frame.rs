@@ -9,6 +9,7 @@ use polars_utils::IdxSize;
 
 use crate::executors::sources::get_source_index;
 use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+use crate::pipeline::determine_chunk_size;
 
 pub struct DataFrameSource {
     dfs: Enumerate<IntoIter<DataFrame>>,
@@ -16,9 +17,19 @@ pub struct DataFrameSource {
 }
 
 impl DataFrameSource {
-    pub(crate) fn from_df(mut df: DataFrame) -> Self {
+    pub(crate) fn from_df(df: DataFrame) -> Self {
         let n_threads = POOL.current_num_threads();
-        let dfs = split_df(&mut df, n_threads).unwrap();
+        // An invalid `POLARS_STREAMING_CHUNK_SIZE` is reported by the operators; here we fall back
+        // to one morsel per thread.
+        let morsel_size = determine_chunk_size(df.width(), n_threads).unwrap_or(usize::MAX);
+        Self::from_df_with_morsel_size(df, n_threads, morsel_size)
+    }
+
+    /// Split the [`DataFrame`] in zero-copy slices of at most `morsel_size` rows (and at least
+    /// `n_threads` slices), so that downstream operators don't receive one giant morsel.
+    fn from_df_with_morsel_size(mut df: DataFrame, n_threads: usize, morsel_size: usize) -> Self {
+        let n_morsels = std::cmp::max(df.height().div_ceil(morsel_size.max(1)), n_threads);
+        let dfs = split_df(&mut df, n_morsels).unwrap();
         let dfs = dfs.into_iter().enumerate();
         Self { dfs, n_threads }
     }
@@ -46,3 +57,51 @@ impl Source for DataFrameSource {
         "df"
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::any::Any;
+
+    use polars_core::prelude::*;
+
+    use super::*;
+    use crate::operators::SExecutionContext;
+
+    struct TestContext;
+
+    impl SExecutionContext for TestContext {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn should_stop(&self) -> PolarsResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_df_source_emits_bounded_morsels() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..10_000i64).collect::<Vec<_>>(),
+        ]?;
+        let context = PExecutionContext::new(Box::new(TestContext), false);
+        let mut src = DataFrameSource::from_df_with_morsel_size(df.clone(), 2, 1000);
+
+        let mut chunks = vec![];
+        while let SourceResult::GotMoreData(batch) = src.get_batches(&context)? {
+            chunks.extend(batch);
+        }
+        assert!(chunks.len() >= 10);
+        assert!(chunks.iter().all(|chunk| chunk.data.height() <= 1000));
+        assert!(chunks
+            .windows(2)
+            .all(|w| w[0].chunk_index < w[1].chunk_index));
+
+        let mut out = chunks[0].data.clone();
+        for chunk in &chunks[1..] {
+            out.vstack_mut(&chunk.data)?;
+        }
+        assert!(out.equals(&df));
+        Ok(())
+    }
+}
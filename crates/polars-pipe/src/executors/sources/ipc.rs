@@ -0,0 +1,155 @@
+use std::fs::File;
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::POOL;
+use polars_io::ipc::{BatchedIpcReader, IpcReader};
+use polars_io::prelude::materialize_projection;
+use polars_io::{RowIndex, SerReader};
+use polars_plan::prelude::FileScanOptions;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+use crate::pipeline::determine_chunk_size;
+
+/// Reads IPC files one record batch at a time.
+///
+/// Batches that are longer than the chunk size of the pipeline are split. Consecutive smaller
+/// batches, also across files, are concatenated until they have at least the chunk size, so
+/// files with many tiny batches don't produce as many tiny morsels.
+pub struct IpcSource {
+    paths: Arc<[PathBuf]>,
+    path_index: usize,
+    reader: Option<BatchedIpcReader<File>>,
+    /// Batches that were read, but are still too small to be a morsel.
+    pending: Option<DataFrame>,
+    file_options: FileScanOptions,
+    projection: Option<Vec<usize>>,
+    chunk_size: usize,
+    /// The number of rows produced so far, over all files.
+    rows_read: usize,
+    n_threads: usize,
+}
+
+impl IpcSource {
+    pub(crate) fn new(
+        paths: Arc<[PathBuf]>,
+        schema: SchemaRef,
+        file_options: FileScanOptions,
+        verbose: bool,
+    ) -> PolarsResult<Self> {
+        let projection = materialize_projection(
+            file_options
+                .with_columns
+                .as_deref()
+                .map(|cols| cols.deref()),
+            &schema,
+            None,
+            file_options.row_index.is_some(),
+        );
+
+        let n_threads = POOL.current_num_threads();
+        let n_cols = projection.as_ref().map(|v| v.len()).unwrap_or(schema.len());
+        let chunk_size = determine_chunk_size(n_cols, n_threads)?;
+        if verbose {
+            eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
+        }
+
+        Ok(IpcSource {
+            paths,
+            path_index: 0,
+            reader: None,
+            pending: None,
+            file_options,
+            projection,
+            chunk_size,
+            rows_read: 0,
+            n_threads,
+        })
+    }
+
+    /// Open the next file, returns `false` if all files are read or `n_rows` is reached.
+    fn init_next_reader(&mut self) -> PolarsResult<bool> {
+        let n_rows = self
+            .file_options
+            .n_rows
+            .map(|n| n.saturating_sub(self.rows_read));
+        if self.path_index == self.paths.len() || n_rows == Some(0) {
+            return Ok(false);
+        }
+
+        let file = polars_utils::open_file(&self.paths[self.path_index])?;
+        self.path_index += 1;
+
+        // The row index continues where the previous file stopped.
+        let row_index = self.file_options.row_index.as_ref().map(|rc| RowIndex {
+            name: rc.name.clone(),
+            offset: rc.offset + self.rows_read as IdxSize,
+        });
+        let reader = IpcReader::new(file)
+            .with_n_rows(n_rows)
+            .with_row_index(row_index)
+            .with_projection(self.projection.clone())
+            .batched(self.chunk_size)?;
+        self.reader = Some(reader);
+        Ok(true)
+    }
+}
+
+impl Source for IpcSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let mut batches = Vec::with_capacity(self.n_threads);
+        while batches.len() < self.n_threads {
+            if self.reader.is_none() && !self.init_next_reader()? {
+                break;
+            }
+            match self.reader.as_mut().unwrap().next() {
+                Some(df) => {
+                    let df = df?;
+                    self.rows_read += df.height();
+                    let mut df = match self.pending.take() {
+                        Some(mut pending) => {
+                            pending.vstack_mut(&df)?;
+                            pending
+                        },
+                        None => df,
+                    };
+                    if df.height() < self.chunk_size {
+                        self.pending = Some(df);
+                    } else {
+                        df.as_single_chunk();
+                        batches.push(df);
+                    }
+                },
+                None => self.reader = None,
+            }
+        }
+        // All files are read, the remaining rows become the last morsel.
+        if batches.len() < self.n_threads {
+            if let Some(mut df) = self.pending.take() {
+                df.as_single_chunk();
+                batches.push(df);
+            }
+        }
+        if batches.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+
+        let idx_offset = get_source_index(batches.len() as u32);
+        let chunks = batches
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (idx_offset + i as u32) as IdxSize,
+                data,
+            })
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "ipc"
+    }
+}
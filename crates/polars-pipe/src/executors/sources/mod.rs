@@ -1,6 +1,9 @@
+mod anonymous;
 #[cfg(feature = "csv")]
 mod csv;
 mod frame;
+#[cfg(feature = "ipc")]
+mod ipc;
 mod ipc_one_shot;
 #[cfg(feature = "parquet")]
 mod parquet;
@@ -9,9 +12,12 @@ mod union;
 
 use std::sync::atomic::{AtomicU32, Ordering};
 
+pub(crate) use anonymous::*;
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvSource;
 pub(crate) use frame::*;
+#[cfg(feature = "ipc")]
+pub(crate) use ipc::IpcSource;
 pub(crate) use ipc_one_shot::*;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::*;
@@ -75,7 +75,10 @@ impl ParquetSource {
     )> {
         let path = &self.paths[index];
         let options = self.options;
-        let file_options = self.file_options.clone();
+        let mut file_options = self.file_options.clone();
+        if let Some(limit) = file_options.per_file_limit {
+            file_options.n_rows = Some(file_options.n_rows.map_or(limit, |n| n.min(limit)));
+        }
         let schema = self.file_info.schema.clone();
 
         let mut file_info = self.file_info.clone();
@@ -135,6 +138,7 @@ impl ParquetSource {
                 .with_predicate(predicate.clone())
                 .with_projection(projection)
                 .use_statistics(options.use_statistics)
+                .uuid_as_string(options.uuid_as_string)
                 .with_hive_partition_columns(hive_partitions)
                 .batched(chunk_size)?
         };
@@ -178,6 +182,7 @@ impl ParquetSource {
                 .with_projection(projection)
                 .with_predicate(predicate.clone())
                 .use_statistics(options.use_statistics)
+                .uuid_as_string(options.uuid_as_string)
                 .with_hive_partition_columns(hive_partitions)
                 .batched(chunk_size)
                 .await?
@@ -275,6 +280,10 @@ impl ParquetSource {
 
 impl Source for ParquetSource {
     fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        // The global limit is exhausted, the readers left over can't contribute.
+        if self.processed_paths > 0 && self.file_options.n_rows == Some(0) {
+            return Ok(SourceResult::Finished);
+        }
         self.prefetch_files()?;
 
         let Some(mut reader) = self.batched_readers.pop_front() else {
@@ -287,7 +296,8 @@ impl Source for ParquetSource {
 
         Ok(match batches {
             None => {
-                if reader.limit_reached() {
+                // A reader that only hit the per-file limit doesn't end the scan.
+                if reader.limit_reached() && self.file_options.n_rows == Some(0) {
                     return Ok(SourceResult::Finished);
                 }
 
@@ -300,10 +310,14 @@ impl Source for ParquetSource {
                 let out = batches
                     .into_iter()
                     .enumerate_u32()
-                    .map(|(i, data)| {
+                    .map(|(i, mut data)| {
                         // Keep the row limit updated so the next reader will have a correct limit.
                         if let Some(n_rows) = &mut self.file_options.n_rows {
-                            *n_rows = n_rows.saturating_sub(data.height())
+                            // Readers prefetched before the limit was updated may read past it.
+                            if data.height() > *n_rows {
+                                data = data.slice(0, *n_rows);
+                            }
+                            *n_rows -= data.height();
                         }
 
                         DataChunk {
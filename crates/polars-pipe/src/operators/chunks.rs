@@ -25,6 +25,12 @@ impl DataChunk {
     pub(crate) fn is_empty(&self) -> bool {
         self.data.height() == 0
     }
+    /// The position of the first row of this chunk in the stream. A position holds the chunk
+    /// index in the upper 32 bits and the row in the chunk in the lower 32 bits.
+    #[allow(clippy::unnecessary_cast)]
+    pub(crate) fn row_position_offset(&self) -> u64 {
+        (self.chunk_index as u64) << 32
+    }
 }
 
 pub(crate) fn chunks_to_df_unchecked(chunks: Vec<DataChunk>) -> DataFrame {
@@ -1,24 +1,132 @@
 use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use polars_core::prelude::*;
 
+use crate::pipeline::MEMORY_LIMIT;
+
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
 
     fn should_stop(&self) -> PolarsResult<()>;
+
+    /// Memory budget in bytes of the query, if it overrides `POLARS_STREAMING_MEMORY_LIMIT`.
+    fn memory_limit(&self) -> Option<usize> {
+        None
+    }
 }
 
 pub struct PExecutionContext {
     // injected upstream in polars-lazy
     pub(crate) execution_state: Box<dyn SExecutionContext>,
     pub(crate) verbose: bool,
+    pub(crate) memory_budget: Arc<MemoryBudget>,
 }
 
 impl PExecutionContext {
     pub(crate) fn new(state: Box<dyn SExecutionContext>, verbose: bool) -> Self {
+        let memory_limit = state.memory_limit().or_else(|| {
+            std::env::var(MEMORY_LIMIT)
+                .ok()
+                .and_then(|val| val.parse().ok())
+        });
         PExecutionContext {
             execution_state: state,
             verbose,
+            memory_budget: Arc::new(MemoryBudget::new(memory_limit)),
         }
     }
+
+    #[cfg(test)]
+    pub(crate) fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.memory_budget = Arc::new(MemoryBudget::new(Some(limit)));
+        self
+    }
+}
+
+/// The memory budget of a streaming query, shared by the nodes that hold on to their input.
+///
+/// The accounting is approximate: the nodes reserve the estimated size of the data they keep in
+/// memory and release it once it is spilled to disk or handed over.
+pub(crate) struct MemoryBudget {
+    limit: Option<usize>,
+    used: AtomicUsize,
+}
+
+impl MemoryBudget {
+    pub(crate) fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Reserve `bytes`, returns `false` if the reservations exceed the limit.
+    ///
+    /// The bytes are reserved either way, the caller should release them if it spills.
+    pub(crate) fn reserve(&self, bytes: usize) -> bool {
+        let used = self.used.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.limit.map_or(true, |limit| used <= limit)
+    }
+
+    pub(crate) fn release(&self, bytes: usize) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                Some(used.saturating_sub(bytes))
+            });
+    }
+
+    /// Whether `bytes` more fit in the budget.
+    pub(crate) fn fits(&self, bytes: usize) -> bool {
+        self.limit.map_or(true, |limit| {
+            self.used.load(Ordering::Relaxed) + bytes <= limit
+        })
+    }
+
+    /// Reserve `bytes` for a node that can't spill to disk and errors if they don't fit.
+    ///
+    /// `node_bytes` are all the bytes the node holds on to, including `bytes`.
+    pub(crate) fn try_reserve(
+        &self,
+        node: &str,
+        bytes: usize,
+        node_bytes: usize,
+    ) -> PolarsResult<()> {
+        if self.reserve(bytes) {
+            return Ok(());
+        }
+        polars_bail!(
+            ComputeError:
+            "the streaming `{}` node needs an estimated {} bytes in memory, which exceeds the \
+            streaming memory limit of {} bytes",
+            node, node_bytes, self.limit.unwrap()
+        )
+    }
+}
+
+/// Bytes a node reserved in the [`MemoryBudget`] of the query, released when this is dropped.
+///
+/// Nodes that hand their data to an operator, like the join build sinks, move their
+/// reservation into it so that it is held until the operator is done.
+pub(crate) struct MemoryReservation {
+    budget: Arc<MemoryBudget>,
+    bytes: usize,
+}
+
+impl MemoryReservation {
+    /// Take over `bytes` that were already reserved in the budget of `context`.
+    pub(crate) fn new(context: &PExecutionContext, bytes: usize) -> Self {
+        Self {
+            budget: context.memory_budget.clone(),
+            bytes,
+        }
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.budget.release(self.bytes)
+    }
 }
@@ -3,6 +3,15 @@ mod context;
 mod operator;
 mod sink;
 mod source;
+#[cfg(all(
+    test,
+    any(
+        feature = "arg_where",
+        feature = "is_first_distinct",
+        feature = "is_last_distinct"
+    )
+))]
+pub(crate) mod testing;
 
 pub(crate) use chunks::*;
 pub use context::*;
@@ -0,0 +1,73 @@
+//! Helpers to drive sinks and operators in unit tests like a pipeline does.
+use std::any::Any;
+
+use polars_io::predicates::PhysicalIoExpr;
+use polars_plan::dsl::{col, Expr};
+
+use super::*;
+use crate::expressions::PhysicalPipedExpr;
+
+/// An execution state that never stops the query.
+pub(crate) struct TestContext;
+
+impl SExecutionContext for TestContext {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn should_stop(&self) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+/// An expression that selects a column.
+pub(crate) struct TestColumn(pub(crate) &'static str);
+
+impl PhysicalIoExpr for TestColumn {
+    fn evaluate_io(&self, _df: &DataFrame) -> PolarsResult<Series> {
+        unimplemented!()
+    }
+}
+
+impl PhysicalPipedExpr for TestColumn {
+    fn evaluate(&self, chunk: &DataChunk, _lazy_state: &dyn Any) -> PolarsResult<Series> {
+        chunk.data.column(self.0).cloned()
+    }
+
+    fn field(&self, _input_schema: &Schema) -> PolarsResult<Field> {
+        unimplemented!()
+    }
+
+    fn expression(&self) -> Expr {
+        col(self.0)
+    }
+}
+
+/// Splits `df` in `n_chunks` chunks and assigns them to two threads, in the order in which the
+/// threads may receive them. Every batch of a source has one chunk per thread and the threads
+/// run concurrently, so the second chunk of a batch can arrive first. With `reverse_batches`
+/// the batches arrive in reverse order as well, which only sinks that don't depend on the
+/// order of the batches can handle.
+pub(crate) fn interleaved_chunks(
+    df: &DataFrame,
+    n_chunks: usize,
+    reverse_batches: bool,
+) -> Vec<(usize, DataChunk)> {
+    let chunk_size = df.height().div_ceil(n_chunks);
+    let mut batches = (0..n_chunks)
+        .step_by(2)
+        .map(|first| {
+            (first..std::cmp::min(first + 2, n_chunks))
+                .rev()
+                .map(|i| {
+                    let data = df.slice((i * chunk_size) as i64, chunk_size);
+                    (i % 2, DataChunk::new(i as IdxSize, data))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    if reverse_batches {
+        batches.reverse();
+    }
+    batches.into_iter().flatten().collect()
+}
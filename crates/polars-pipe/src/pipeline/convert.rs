@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::path::Path;
 use std::rc::Rc;
 
 use hashbrown::hash_map::Entry;
@@ -6,7 +7,7 @@ use polars_core::prelude::*;
 use polars_core::with_match_physical_integer_polars_type;
 #[cfg(feature = "parquet")]
 use polars_io::predicates::{PhysicalIoExpr, StatsEvaluator};
-use polars_ops::prelude::JoinType;
+use polars_ops::prelude::{JoinStrategyHint, JoinType};
 use polars_plan::prelude::expr_ir::{ExprIR, OutputName};
 use polars_plan::prelude::*;
 
@@ -152,6 +153,24 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                #[cfg(feature = "ipc")]
+                FileScan::Ipc { .. } => {
+                    let src =
+                        sources::IpcSource::new(paths, file_info.schema, file_options, verbose)?;
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                FileScan::Anonymous { function, .. } => {
+                    let args = AnonymousScanArgs {
+                        n_rows: file_options.n_rows,
+                        with_columns: file_options.with_columns,
+                        schema: file_info.schema,
+                        output_schema,
+                        predicate: None,
+                    };
+                    let src = sources::AnonymousSource::new(function.scan_batched(args)?);
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -159,6 +178,68 @@ where
     }
 }
 
+/// If `exprs` is a single `is_first_distinct` or `is_last_distinct`, returns its key and
+/// whether the last occurrence is flagged. The first occurrences are flagged by an operator,
+/// the last ones by a sink.
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+pub fn distinct_flag_key(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Option<(ExprIR, bool)> {
+    let [e] = exprs else { return None };
+    let AExpr::Function {
+        input,
+        function: FunctionExpr::Boolean(function),
+        ..
+    } = expr_arena.get(e.node())
+    else {
+        return None;
+    };
+    let keep_last = match function {
+        #[cfg(feature = "is_first_distinct")]
+        BooleanFunction::IsFirstDistinct => false,
+        #[cfg(feature = "is_last_distinct")]
+        BooleanFunction::IsLastDistinct => true,
+        _ => return None,
+    };
+    Some((input[0].clone(), keep_last))
+}
+
+/// If `exprs` is a single `arg_where`, returns its condition. The indices are global, so
+/// they are computed by a sink.
+#[cfg(feature = "arg_where")]
+pub fn arg_where_condition(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Option<ExprIR> {
+    let [e] = exprs else { return None };
+    let AExpr::Function {
+        input,
+        function: FunctionExpr::ArgWhere,
+        ..
+    } = expr_arena.get(e.node())
+    else {
+        return None;
+    };
+    Some(input[0].clone())
+}
+
+#[allow(unused_variables)]
+fn get_file_sink(
+    path: &Path,
+    file_type: &FileType,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkTrait>> {
+    match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(options) => {
+            Ok(Box::new(ParquetSink::new(path, options.clone(), schema)?))
+        },
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(options) => Ok(Box::new(IpcSink::new(path, *options, schema)?)),
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => Ok(Box::new(CsvSink::new(path, options.clone(), schema)?)),
+        #[cfg(feature = "json")]
+        FileType::Json(options) => Ok(Box::new(JsonSink::new(path, *options, schema)?)),
+        #[allow(unreachable_patterns)]
+        _ => unreachable!(),
+    }
+}
+
 pub fn get_sink<F>(
     node: Node,
     lp_arena: &Arena<IR>,
@@ -177,36 +258,38 @@ where
                 SinkType::Memory => {
                     Box::new(OrderedSink::new(input_schema.into_owned())) as Box<dyn SinkTrait>
                 },
-                #[allow(unused_variables)]
                 SinkType::File {
                     path, file_type, ..
+                } => get_file_sink(path, file_type, input_schema.as_ref())?,
+                #[cfg(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                ))]
+                SinkType::Split {
+                    predicate,
+                    nulls,
+                    on_true,
+                    on_false,
                 } => {
-                    let path = path.as_ref().as_path();
-                    match &file_type {
-                        #[cfg(feature = "parquet")]
-                        FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "ipc")]
-                        FileType::Ipc(options) => {
-                            Box::new(IpcSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "csv")]
-                        FileType::Csv(options) => {
-                            Box::new(CsvSink::new(path, options.clone(), input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[cfg(feature = "json")]
-                        FileType::Json(options) => {
-                            Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
-                                as Box<dyn SinkTrait>
-                        },
-                        #[allow(unreachable_patterns)]
-                        _ => unreachable!(),
-                    }
+                    let mut schema = (**input_schema).clone();
+                    schema.shift_remove(predicate);
+                    Box::new(SplitSink::new(
+                        predicate,
+                        *nulls,
+                        get_file_sink(&on_true.path, &on_true.file_type, &schema)?,
+                        get_file_sink(&on_false.path, &on_false.file_type, &schema)?,
+                    )) as Box<dyn SinkTrait>
                 },
+                // Without file types there is nothing to split into.
+                #[cfg(not(any(
+                    feature = "parquet",
+                    feature = "ipc",
+                    feature = "csv",
+                    feature = "json"
+                )))]
+                SinkType::Split { .. } => unreachable!(),
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud {
                     #[cfg(any(feature = "parquet", feature = "ipc"))]
@@ -221,7 +304,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -249,7 +332,16 @@ where
         } => {
             // slice pushdown optimization should not set this one in a streaming query.
             assert!(options.args.slice.is_none());
-            let swapped = swap_join_order(options);
+            let input_schema_right = lp_arena.get(*input_right).schema(lp_arena);
+            let swapped = swap_join_order(options, &input_schema_right);
+            if polars_core::config::verbose() {
+                if let Some(reason) = join_hint_fallback_reason(options, &input_schema_right) {
+                    let hint = options.args.strategy_hint.unwrap();
+                    eprintln!(
+                        "join: cannot honor the '{hint}' hint, {reason}: use default hash join"
+                    );
+                }
+            }
             let placeholder = callbacks.get(&node).unwrap().clone();
 
             match &options.args.how {
@@ -268,7 +360,6 @@ where
                         to_physical,
                         Some(input_schema_left.as_ref()),
                     )?);
-                    let input_schema_right = lp_arena.get(*input_right).schema(lp_arena);
                     let join_columns_right = Arc::new(exprs_to_physical(
                         right_on,
                         expr_arena,
@@ -334,9 +425,15 @@ where
             }
         },
         Slice { input, offset, len } => {
-            let input_schema = lp_arena.get(*input).schema(lp_arena);
-            let slice = SliceSink::new(*offset as u64, *len as usize, input_schema.into_owned());
-            Box::new(slice) as Box<dyn SinkTrait>
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+            // Negative offsets are only streamed if the slice takes the whole tail.
+            if *offset < 0 {
+                let tail = TailSink::new(offset.unsigned_abs() as usize, input_schema);
+                Box::new(tail) as Box<dyn SinkTrait>
+            } else {
+                let slice = SliceSink::new(*offset as u64, *len as usize, input_schema);
+                Box::new(slice) as Box<dyn SinkTrait>
+            }
         },
         Sort {
             input,
@@ -469,6 +566,7 @@ where
             aggs,
             schema: output_schema,
             options,
+            maintain_order,
             ..
         } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena).as_ref().clone();
@@ -490,17 +588,27 @@ where
                 agg_fns.push(agg_fn);
                 input_agg_dtypes.push(input_dtype);
             }
+            // The groups are sorted by the position of their first row afterwards.
+            let (sink_schema, slice) = if *maintain_order {
+                let (input_dtype, index, agg_fn) = group_by::first_seen_aggregation();
+                aggregation_columns.push(index);
+                agg_fns.push(agg_fn);
+                input_agg_dtypes.push(input_dtype);
+                (group_by::first_seen_schema(output_schema), None)
+            } else {
+                (output_schema.clone(), options.slice)
+            };
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
+            let group_by_sink = if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
                 Box::new(GenericGroupby2::new(
                     key_columns,
                     aggregation_columns,
                     Arc::from(agg_fns),
-                    output_schema.clone(),
+                    sink_schema.clone(),
                     input_agg_dtypes,
-                    options.slice,
-                ))
+                    slice,
+                )) as Box<dyn SinkTrait>
             } else {
                 match (
                     output_schema.get_at_index(0).unwrap().1.to_physical(),
@@ -513,8 +621,8 @@ where
                                 aggregation_columns,
                                 agg_fns,
                                 input_schema,
-                                output_schema.clone(),
-                                options.slice,
+                                sink_schema.clone(),
+                                slice,
                             )) as Box<dyn SinkTrait>
                         })
                     },
@@ -523,20 +631,55 @@ where
                         aggregation_columns,
                         agg_fns,
                         input_schema,
-                        output_schema.clone(),
-                        options.slice,
+                        sink_schema.clone(),
+                        slice,
                     )) as Box<dyn SinkTrait>,
                     _ => Box::new(GenericGroupby2::new(
                         key_columns,
                         aggregation_columns,
                         Arc::from(agg_fns),
-                        output_schema.clone(),
+                        sink_schema.clone(),
                         input_agg_dtypes,
-                        options.slice,
+                        slice,
                     )),
                 }
+            };
+            if *maintain_order {
+                Box::new(group_by::OrderedGroupbySink::new(
+                    group_by_sink,
+                    sink_schema,
+                    options.slice,
+                ))
+            } else {
+                group_by_sink
             }
         },
+        #[cfg(feature = "is_last_distinct")]
+        HStack {
+            input,
+            exprs,
+            schema,
+            ..
+        } => {
+            let (key, _) = distinct_flag_key(exprs, expr_arena).unwrap();
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let key = to_physical(&key, expr_arena, Some(input_schema.as_ref()))?;
+            let name = exprs[0].output_name().into();
+            Box::new(LastDistinctSink::new(
+                key,
+                name,
+                input_schema.into_owned(),
+                schema.clone(),
+            )) as Box<dyn SinkTrait>
+        },
+        #[cfg(feature = "arg_where")]
+        Select { input, expr, .. } => {
+            let condition = arg_where_condition(expr, expr_arena).unwrap();
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let condition = to_physical(&condition, expr_arena, Some(input_schema.as_ref()))?;
+            let name = expr[0].output_name().into();
+            Box::new(ArgWhereSink::new(condition, name)) as Box<dyn SinkTrait>
+        },
         lp => {
             panic!("{lp:?} not implemented")
         },
@@ -612,6 +755,20 @@ where
             };
             Box::new(op) as Box<dyn Operator>
         },
+        #[cfg(feature = "is_first_distinct")]
+        HStack {
+            exprs,
+            input,
+            schema,
+            ..
+        } if distinct_flag_key(exprs, expr_arena).is_some_and(|(_, keep_last)| !keep_last) => {
+            let (key, _) = distinct_flag_key(exprs, expr_arena).unwrap();
+            let input_schema = lp_arena.get(*input).schema(lp_arena);
+            let key = to_physical(&key, expr_arena, Some(input_schema.as_ref()))?;
+            let name = exprs[0].output_name().into();
+            let op = operators::FirstDistinctOperator::new(key, name, schema.clone());
+            Box::new(op) as Box<dyn Operator>
+        },
         HStack { exprs, input, .. } => {
             let input_schema = lp_arena.get(*input).schema(lp_arena);
 
@@ -766,10 +923,92 @@ where
     ))
 }
 
-pub fn swap_join_order(options: &JoinOptions) -> bool {
+/// Maximum estimated size of the right side of a join with a broadcast hint.
+const DEFAULT_BROADCAST_MAX_BYTES: usize = 1 << 30;
+
+/// Rough estimate of the size of a row, only used to check the broadcast hint.
+fn estimated_row_size(schema: &Schema) -> usize {
+    schema
+        .iter_dtypes()
+        .map(|dtype| {
+            if dtype.to_physical().is_numeric() {
+                8
+            } else {
+                32
+            }
+        })
+        .sum()
+}
+
+/// Returns why the streaming engine can't honor the strategy hint of the join, if it can't.
+fn join_hint_fallback_reason(options: &JoinOptions, right_schema: &Schema) -> Option<String> {
+    match options.args.strategy_hint? {
+        JoinStrategyHint::Hash => None,
+        JoinStrategyHint::SortMerge => {
+            Some("the streaming engine has no sorted merge join".to_string())
+        },
+        JoinStrategyHint::Broadcast => {
+            let max_bytes = std::env::var("POLARS_JOIN_BROADCAST_MAX_BYTES")
+                .map(|s| s.parse::<usize>().unwrap())
+                .unwrap_or(DEFAULT_BROADCAST_MAX_BYTES);
+            let (known, estimated) = options.rows_right;
+            let bytes = known
+                .unwrap_or(estimated)
+                .saturating_mul(estimated_row_size(right_schema));
+            (bytes > max_bytes).then(|| {
+                format!(
+                    "the right side is estimated at {bytes} bytes, more than \
+                    POLARS_JOIN_BROADCAST_MAX_BYTES ({max_bytes})"
+                )
+            })
+        },
+    }
+}
+
+/// Whether the right side of the join is the build side of the hash join.
+pub fn swap_join_order(options: &JoinOptions, right_schema: &Schema) -> bool {
+    if options.args.strategy_hint == Some(JoinStrategyHint::Broadcast)
+        && join_hint_fallback_reason(options, right_schema).is_none()
+    {
+        return true;
+    }
     matches!(options.args.how, JoinType::Left)
         || match (options.rows_left, options.rows_right) {
             ((Some(left), _), (Some(right), _)) => left > right,
             ((_, left), (_, right)) => left > right,
         }
 }
+
+#[cfg(test)]
+mod test {
+    use polars_ops::prelude::JoinArgs;
+
+    use super::*;
+
+    #[test]
+    fn test_join_strategy_hint_build_side() {
+        let schema = Schema::from_iter([Field::new("a", DataType::Int64)]);
+        // The left side is smaller, so by default it is the build side.
+        let mut options = JoinOptions {
+            args: JoinArgs::new(JoinType::Inner),
+            rows_left: (Some(10), 10),
+            rows_right: (Some(1000), 1000),
+            ..Default::default()
+        };
+        assert!(!swap_join_order(&options, &schema));
+
+        options.args.strategy_hint = Some(JoinStrategyHint::Broadcast);
+        assert!(swap_join_order(&options, &schema));
+        assert!(join_hint_fallback_reason(&options, &schema).is_none());
+
+        // The size of the right side is unknown, so it can't be broadcast.
+        options.rows_right = (None, usize::MAX);
+        assert!(!swap_join_order(&options, &schema));
+        assert!(join_hint_fallback_reason(&options, &schema).is_some());
+
+        options.args.strategy_hint = Some(JoinStrategyHint::SortMerge);
+        assert!(join_hint_fallback_reason(&options, &schema).is_some());
+        options.args.strategy_hint = Some(JoinStrategyHint::Hash);
+        assert!(join_hint_fallback_reason(&options, &schema).is_none());
+    }
+}
@@ -104,11 +104,14 @@ pub(super) fn push_operators_single_thread(
             },
             Some(op) => {
                 let op = op.get_mut();
-                match op.execute(ec, &chunk)? {
-                    OperatorResult::Finished(chunk) => {
-                        must_flush.store(op.must_flush(), Ordering::Relaxed);
-                        in_process.push((op_i + 1, chunk))
-                    },
+                let result = op.execute(ec, &chunk)?;
+                // An operator that holds back its input must be flushed, even if it didn't emit
+                // anything yet or another operator in the pipeline doesn't need a flush.
+                if op.must_flush() {
+                    must_flush.store(true, Ordering::Relaxed);
+                }
+                match result {
+                    OperatorResult::Finished(chunk) => in_process.push((op_i + 1, chunk)),
                     OperatorResult::HaveMoreOutPut(output_chunk) => {
                         // Push the next operator call with the same chunk on the stack
                         in_process.push((op_i, chunk));
@@ -2,6 +2,10 @@ mod config;
 mod convert;
 mod dispatcher;
 
+#[cfg(feature = "arg_where")]
+pub use convert::arg_where_condition;
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+pub use convert::distinct_flag_key;
 pub use convert::{
     create_pipeline, get_dummy_operator, get_operator, get_sink, swap_join_order, CallBacks,
 };
@@ -23,6 +27,8 @@ pub(crate) const PARTITION_SIZE: usize = 64;
 
 // env vars
 pub(crate) static FORCE_OOC: &str = "POLARS_FORCE_OOC";
+// Memory budget in bytes of a streaming query.
+pub(crate) static MEMORY_LIMIT: &str = "POLARS_STREAMING_MEMORY_LIMIT";
 
 /// ideal chunk size we strive to have
 /// scale the chunk size depending on the number of
@@ -4,6 +4,7 @@ use crate::prelude::ColumnName;
 
 pub static MAP_LIST_NAME: &str = "map_list";
 pub static CSE_REPLACED: &str = "__POLARS_CSER_";
+pub const ELEMENT_INDEX_NAME: &str = "__POLARS_ELEMENT_INDEX";
 pub const LEN: &str = "len";
 pub const LITERAL_NAME: &str = "literal";
 pub const UNLIMITED_CACHE: u32 = u32::MAX;
@@ -402,6 +402,7 @@ impl DslPlan {
                         SinkType::File { .. } => "SINK (FILE)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (CLOUD)",
+                        SinkType::Split { .. } => "SINK (SPLIT)",
                     },
                 };
                 self.write_dot(acc_str, prev_node, current_node, id_map)?;
@@ -46,6 +46,7 @@ impl Rem for Expr {
 impl Neg for Expr {
     type Output = Expr;
 
+    /// Negate numeric values. On boolean columns this is a logical not, like [`Expr::not`].
     fn neg(self) -> Self::Output {
         self.map_private(FunctionExpr::Negate)
     }
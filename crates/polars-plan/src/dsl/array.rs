@@ -192,4 +192,29 @@ impl ArrayNameSpace {
             false,
         )
     }
+
+    /// Compute the dot product of every sub-array with the matching sub-array of `other`.
+    ///
+    /// `other` may also be a single array (e.g. a literal), which is compared against every
+    /// row. A null element makes the result of its row null, unless `nulls_as_zero` is set.
+    pub fn dot<E: Into<Expr>>(self, other: E, nulls_as_zero: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Dot(nulls_as_zero)),
+            &[other.into()],
+            false,
+            false,
+        )
+    }
+
+    /// Compute the cosine similarity of every sub-array with the matching sub-array of `other`.
+    ///
+    /// See [`ArrayNameSpace::dot`] for the broadcasting and null semantics.
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E, nulls_as_zero: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity(nulls_as_zero)),
+            &[other.into()],
+            false,
+            false,
+        )
+    }
 }
@@ -33,6 +33,42 @@ impl BinaryNameSpace {
         )
     }
 
+    /// Get the size of the binary values in bytes.
+    pub fn size(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Size))
+    }
+
+    /// Slice the binary values.
+    ///
+    /// A negative `offset` counts from the end of the values and a null `length` slices until the
+    /// end. Slices that are out of bounds are clamped.
+    pub fn slice(self, offset: Expr, length: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::BinaryExpr(BinaryFunction::Slice),
+            &[offset, length],
+            false,
+            false,
+        )
+    }
+
+    /// Concatenate the binary values with the values of `others`, elementwise. The result is null
+    /// if any of the values is null.
+    pub fn concat<E: AsRef<[Expr]>>(self, others: E) -> Expr {
+        let mut input = vec![self.0];
+        input.extend_from_slice(others.as_ref());
+
+        Expr::Function {
+            input,
+            function: FunctionExpr::BinaryExpr(BinaryFunction::ConcatBinary),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::ElementWise,
+                input_wildcard_expansion: true,
+                ..Default::default()
+            },
+        }
+    }
+
     #[cfg(feature = "binary_encoding")]
     pub fn hex_decode(self, strict: bool) -> Expr {
         self.0
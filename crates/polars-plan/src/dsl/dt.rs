@@ -275,9 +275,15 @@ impl DateLikeNameSpace {
     }
 
     /// Combine an existing Date/Datetime with a Time, creating a new Datetime value.
-    pub fn combine(self, time: Expr, tu: TimeUnit) -> Expr {
+    ///
+    /// The result is in `time_zone` if given, otherwise in the time zone of the input. The
+    /// result is null where either input is null.
+    pub fn combine(self, time: Expr, tu: TimeUnit, time_zone: Option<TimeZone>) -> Expr {
         self.0.map_many_private(
-            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu)),
+            FunctionExpr::TemporalExpr(TemporalFunction::Combine {
+                time_unit: tu,
+                time_zone,
+            }),
             &[time],
             false,
             false,
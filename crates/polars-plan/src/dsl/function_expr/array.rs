@@ -30,6 +30,8 @@ pub enum ArrayFunction {
     #[cfg(feature = "array_count")]
     CountMatches,
     Shift,
+    Dot(bool),
+    CosineSimilarity(bool),
 }
 
 impl ArrayFunction {
@@ -56,6 +58,9 @@ impl ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => mapper.with_dtype(IDX_DTYPE),
             Shift => mapper.with_same_dtype(),
+            Dot(_) | CosineSimilarity(_) => {
+                mapper.try_map_dtypes(|dtypes| map_array_similarity_dtype(self, dtypes))
+            },
         }
     }
 }
@@ -68,6 +73,28 @@ fn map_array_dtype_to_list_dtype(datatype: &DataType) -> PolarsResult<DataType>
     }
 }
 
+fn map_array_similarity_dtype(
+    func: &ArrayFunction,
+    dtypes: &[&DataType],
+) -> PolarsResult<DataType> {
+    match dtypes {
+        [DataType::Array(lhs, lhs_width), DataType::Array(rhs, rhs_width)] => {
+            polars_ensure!(
+                lhs_width == rhs_width,
+                ShapeMismatch: "`{}` got arrays of width {} and {}", func, lhs_width, rhs_width
+            );
+            match (lhs.as_ref(), rhs.as_ref()) {
+                (DataType::Float32, DataType::Float32) => Ok(DataType::Float32),
+                _ => Ok(DataType::Float64),
+            }
+        },
+        [lhs, rhs] => polars_bail!(
+            SchemaMismatch: "`{}` expected two `Array` inputs, got `{}` and `{}`", func, lhs, rhs
+        ),
+        _ => polars_bail!(ComputeError: "`{}` expected two inputs", func),
+    }
+}
+
 impl Display for ArrayFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use ArrayFunction::*;
@@ -96,6 +123,8 @@ impl Display for ArrayFunction {
             #[cfg(feature = "array_count")]
             CountMatches => "count_matches",
             Shift => "shift",
+            Dot(_) => "dot",
+            CosineSimilarity(_) => "cosine_similarity",
         };
         write!(f, "arr.{name}")
     }
@@ -129,6 +158,8 @@ impl From<ArrayFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "array_count")]
             CountMatches => map_as_slice!(count_matches),
             Shift => map_as_slice!(shift),
+            Dot(nulls_as_zero) => map_as_slice!(dot, nulls_as_zero),
+            CosineSimilarity(nulls_as_zero) => map_as_slice!(cosine_similarity, nulls_as_zero),
         }
     }
 }
@@ -243,3 +274,15 @@ pub(super) fn shift(s: &[Series]) -> PolarsResult<Series> {
 
     ca.array_shift(n)
 }
+
+pub(super) fn dot(s: &[Series], nulls_as_zero: bool) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let other = s[1].array()?;
+    ca.array_dot(other, nulls_as_zero)
+}
+
+pub(super) fn cosine_similarity(s: &[Series], nulls_as_zero: bool) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let other = s[1].array()?;
+    ca.array_cosine_similarity(other, nulls_as_zero)
+}
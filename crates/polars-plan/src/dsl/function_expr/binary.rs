@@ -2,9 +2,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::*;
-#[cfg(feature = "binary_encoding")]
-use crate::map;
-use crate::map_as_slice;
+use crate::{map, map_as_slice};
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -12,6 +10,9 @@ pub enum BinaryFunction {
     Contains,
     StartsWith,
     EndsWith,
+    Size,
+    Slice,
+    ConcatBinary,
     #[cfg(feature = "binary_encoding")]
     HexDecode(bool),
     #[cfg(feature = "binary_encoding")]
@@ -28,6 +29,8 @@ impl BinaryFunction {
         match self {
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
+            Size => mapper.with_dtype(DataType::UInt32),
+            Slice | ConcatBinary => mapper.with_dtype(DataType::Binary),
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
@@ -43,6 +46,9 @@ impl Display for BinaryFunction {
             Contains { .. } => "contains",
             StartsWith => "starts_with",
             EndsWith => "ends_with",
+            Size => "size",
+            Slice => "slice",
+            ConcatBinary => "concat",
             #[cfg(feature = "binary_encoding")]
             HexDecode(_) => "hex_decode",
             #[cfg(feature = "binary_encoding")]
@@ -69,6 +75,9 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             StartsWith => {
                 map_as_slice!(starts_with)
             },
+            Size => map!(size),
+            Slice => map_as_slice!(slice),
+            ConcatBinary => map_as_slice!(concat),
             #[cfg(feature = "binary_encoding")]
             HexDecode(strict) => map!(hex_decode, strict),
             #[cfg(feature = "binary_encoding")]
@@ -107,6 +116,27 @@ pub(super) fn starts_with(s: &[Series]) -> PolarsResult<Series> {
         .into_series())
 }
 
+pub(super) fn size(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.size_bytes().into_series())
+}
+
+pub(super) fn slice(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].binary()?;
+    let offset = &s[1];
+    let length = &s[2];
+    Ok(ca.bin_slice(offset, length)?.into_series())
+}
+
+pub(super) fn concat(s: &[Series]) -> PolarsResult<Series> {
+    let bin_series: Vec<_> = s
+        .iter()
+        .map(|s| s.cast(&DataType::Binary))
+        .collect::<PolarsResult<_>>()?;
+    let cas: Vec<_> = bin_series.iter().map(|s| s.binary().unwrap()).collect();
+    Ok(polars_ops::chunked_array::hor_bin_concat(&cas)?.into_series())
+}
+
 #[cfg(feature = "binary_encoding")]
 pub(super) fn hex_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
     let ca = s.binary()?;
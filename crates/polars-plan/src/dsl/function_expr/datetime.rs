@@ -59,7 +59,10 @@ pub enum TemporalFunction {
     Round(String),
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>, NonExistent),
-    Combine(TimeUnit),
+    Combine {
+        time_unit: TimeUnit,
+        time_zone: Option<TimeZone>,
+    },
     DatetimeFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
@@ -119,9 +122,15 @@ impl TemporalFunction {
                 "datetime",
                 DataType::Datetime(*time_unit, time_zone.clone()),
             )),
-            Combine(tu) => mapper.try_map_dtype(|dt| match dt {
-                DataType::Datetime(_, tz) => Ok(DataType::Datetime(*tu, tz.clone())),
-                DataType::Date => Ok(DataType::Datetime(*tu, None)),
+            Combine {
+                time_unit,
+                time_zone,
+            } => mapper.try_map_dtype(|dt| match dt {
+                DataType::Datetime(_, tz) => Ok(DataType::Datetime(
+                    *time_unit,
+                    time_zone.clone().or_else(|| tz.clone()),
+                )),
+                DataType::Date => Ok(DataType::Datetime(*time_unit, time_zone.clone())),
                 dtype => {
                     polars_bail!(ComputeError: "expected Date or Datetime, got {}", dtype)
                 },
@@ -181,7 +190,7 @@ impl Display for TemporalFunction {
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_, _) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
-            Combine(_) => "combine",
+            Combine { .. } => "combine",
         };
         write!(f, "dt.{s}")
     }
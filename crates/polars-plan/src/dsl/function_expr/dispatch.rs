@@ -5,8 +5,8 @@ pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
 }
 
 #[cfg(feature = "approx_unique")]
-pub(super) fn approx_n_unique(s: &Series) -> PolarsResult<Series> {
-    polars_ops::prelude::approx_n_unique(s)
+pub(super) fn approx_n_unique(s: &Series, precision: u8) -> PolarsResult<Series> {
+    polars_ops::prelude::approx_n_unique_with_precision(s, precision)
 }
 
 #[cfg(feature = "diff")]
@@ -14,6 +14,11 @@ pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsRes
     polars_ops::prelude::diff(s, n, null_behavior)
 }
 
+#[cfg(feature = "diff")]
+pub(super) fn diff_by(s: &[Series]) -> PolarsResult<Series> {
+    polars_ops::prelude::diff_by(&s[0], &s[1])
+}
+
 #[cfg(feature = "pct_change")]
 pub(super) fn pct_change(s: &[Series]) -> PolarsResult<Series> {
     polars_ops::prelude::pct_change(&s[0], &s[1])
@@ -69,6 +74,18 @@ pub(super) fn repeat_by(s: &[Series]) -> PolarsResult<Series> {
     polars_ops::chunked_array::repeat_by(s, by.idx()?).map(|ok| ok.into_series())
 }
 
+#[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+pub(super) fn repeat_fixed(s: &Series, n: usize) -> PolarsResult<Series> {
+    polars_ops::chunked_array::repeat_fixed(s, n).map(|ok| ok.into_series())
+}
+
+/// Convert a group to an array, it must have exactly `width` values. In a group-by the
+/// groups are converted at once, see `ApplyExpr`.
+#[cfg(feature = "dtype-array")]
+pub(super) fn implode_to_array(s: &Series, width: usize) -> PolarsResult<Series> {
+    polars_ops::chunked_array::lists_to_array(&s.implode()?, width)
+}
+
 pub(super) fn backward_fill(s: &Series, limit: FillNullLimit) -> PolarsResult<Series> {
     s.fill_null(FillNullStrategy::Backward(limit))
 }
@@ -77,6 +94,41 @@ pub(super) fn forward_fill(s: &Series, limit: FillNullLimit) -> PolarsResult<Ser
     s.fill_null(FillNullStrategy::Forward(limit))
 }
 
+/// Forward or backward fill `s[0]`, with at most `s[1]` consecutive values filled.
+///
+/// The limit is evaluated per group in a group-by or window context, a null limit doesn't
+/// limit the fill. Errors get the failing group attached by the group-wise evaluation.
+pub(super) fn fill_with_limit(s: &[Series], backward: bool) -> PolarsResult<Series> {
+    let (values, limit) = (&s[0], &s[1]);
+    polars_ensure!(
+        limit.len() == 1,
+        ComputeError: "the fill limit of '{}' must be a single value, got {} values",
+        values.name(), limit.len()
+    );
+    polars_ensure!(
+        limit.dtype().is_integer() || limit.dtype() == &DataType::Null,
+        InvalidOperation: "the fill limit of '{}' must be an integer, got {}",
+        values.name(), limit.dtype()
+    );
+    let limit = match limit.cast(&DataType::Int64)?.i64()?.get(0) {
+        Some(limit) => {
+            polars_ensure!(
+                limit >= 0,
+                InvalidOperation: "the fill limit of '{}' must be non-negative, got {}",
+                values.name(), limit
+            );
+            Some(IdxSize::try_from(limit).unwrap_or(IdxSize::MAX))
+        },
+        None => None,
+    };
+    let strategy = if backward {
+        FillNullStrategy::Backward(limit)
+    } else {
+        FillNullStrategy::Forward(limit)
+    };
+    values.fill_null(strategy)
+}
+
 pub(super) fn max_horizontal(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     polars_ops::prelude::max_horizontal(s)
 }
@@ -97,6 +149,25 @@ pub(super) fn drop_nulls(s: &Series) -> PolarsResult<Series> {
     Ok(s.drop_nulls())
 }
 
+#[cfg(feature = "dtype-struct")]
+pub(super) fn drop_nulls_struct(s: &Series, any: bool) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    if ca.total_null_count() == 0 {
+        return Ok(s.clone());
+    }
+    let mask = if any {
+        let is_valid = ca.fields().iter().map(|s| s.is_not_null());
+        match is_valid.reduce(|acc, is_valid| acc & is_valid) {
+            Some(mask) => mask,
+            None => return Ok(s.clone()),
+        }
+    } else {
+        // A struct row is null if all of its fields are null.
+        s.is_not_null()
+    };
+    s.filter(&mask)
+}
+
 #[cfg(feature = "mode")]
 pub(super) fn mode(s: &Series) -> PolarsResult<Series> {
     mode::mode(s)
@@ -148,7 +219,11 @@ pub(super) fn fill_null_with_strategy(
     s: &Series,
     strategy: FillNullStrategy,
 ) -> PolarsResult<Series> {
-    s.fill_null(strategy)
+    match strategy {
+        #[cfg(feature = "interpolate")]
+        FillNullStrategy::Interpolate(method) => interpolate(s, method),
+        _ => s.fill_null(strategy),
+    }
 }
 
 pub(super) fn gather_every(s: &Series, n: usize, offset: usize) -> PolarsResult<Series> {
@@ -161,11 +236,15 @@ pub(super) fn reinterpret(s: &Series, signed: bool) -> PolarsResult<Series> {
     polars_ops::series::reinterpret(s, signed)
 }
 
+/// Arithmetic negation for numeric columns and logical not for boolean columns.
 pub(super) fn negate(s: &Series) -> PolarsResult<Series> {
-    polars_ops::series::negate(s)
+    match s.dtype() {
+        DataType::Boolean => Ok((!s.bool()?).into_series()),
+        _ => polars_ops::series::negate(s),
+    }
 }
 
-pub(super) fn extend_constant(s: &[Series]) -> PolarsResult<Series> {
+pub(super) fn extend_constant(s: &[Series], prepend: bool) -> PolarsResult<Series> {
     let value = &s[1];
     let n = &s[2];
     polars_ensure!(value.len() == 1 && n.len() == 1, ComputeError: "value and n should have unit length.");
@@ -173,9 +252,28 @@ pub(super) fn extend_constant(s: &[Series]) -> PolarsResult<Series> {
     let v = value.get(0)?;
     let s = &s[0];
     match n.u64()?.get(0) {
+        Some(n) if prepend => {
+            let mut out = Series::new_empty(s.name(), s.dtype()).extend_constant(v, n as usize)?;
+            out.append(s)?;
+            Ok(out)
+        },
         Some(n) => s.extend_constant(v, n as usize),
         None => {
             polars_bail!(ComputeError: "n can not be None for extend_constant.")
         },
     }
 }
+
+#[cfg(feature = "top_k")]
+pub(super) fn top_k_by(
+    s: &[Series],
+    sort_options: SortMultipleOptions,
+    return_indices: bool,
+) -> PolarsResult<Series> {
+    let (s, by) = s.split_at(2);
+    if return_indices {
+        Ok(polars_ops::prelude::arg_top_k_by(s, by, sort_options)?.into_series())
+    } else {
+        polars_ops::prelude::top_k_by(s, by, sort_options)
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+/// Serialize every row of a nested Series to a compact JSON string, null rows stay null.
+pub(super) fn json_encode(s: &Series, options: JsonEncodeOptions) -> PolarsResult<Series> {
+    let s = if has_temporal(s.dtype()) {
+        encode_temporals(s, options.temporal)?
+    } else {
+        s.clone()
+    };
+    let dtype = s.dtype().to_arrow(true);
+
+    let iter = s.chunks().iter().map(|arr| {
+        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
+        polars_json::json::write::serialize_to_utf8(arr.as_ref())
+            .with_validity(arr.validity().cloned())
+    });
+
+    Ok(StringChunked::from_chunk_iter(s.name(), iter).into_series())
+}
+
+fn has_temporal(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(inner) => has_temporal(inner),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, _) => has_temporal(inner),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => fields.iter().any(|fld| has_temporal(fld.data_type())),
+        dt => dt.is_temporal(),
+    }
+}
+
+/// Replace the temporal values that the JSON writer doesn't write as requested.
+///
+/// The writer writes dates, times, durations and datetimes with a time zone as ISO 8601 strings,
+/// but separates the date and the time of naive datetimes with a space.
+fn encode_temporals(s: &Series, temporal: JsonTemporalEncoding) -> PolarsResult<Series> {
+    let f = |s: Series| encode_temporals(&s, temporal);
+    let out = match (s.dtype(), temporal) {
+        (DataType::List(inner), _) if has_temporal(inner) => {
+            s.list()?.apply_to_inner(&f)?.into_series()
+        },
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(inner, _), _) if has_temporal(inner) => {
+            s.array()?.apply_to_inner(&f)?.into_series()
+        },
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(_), _) => s
+            .struct_()?
+            .try_apply_fields(|s| encode_temporals(s, temporal))?
+            .into_series(),
+        (dt, JsonTemporalEncoding::Epoch) if dt.is_temporal() => s.to_physical_repr().into_owned(),
+        #[cfg(feature = "dtype-datetime")]
+        (DataType::Datetime(_, None), JsonTemporalEncoding::Iso) => s
+            .datetime()?
+            .to_string("%Y-%m-%dT%H:%M:%S%.f")?
+            .into_series(),
+        _ => s.clone(),
+    };
+    Ok(out)
+}
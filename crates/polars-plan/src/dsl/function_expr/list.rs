@@ -21,6 +21,16 @@ pub enum ListFunction {
     },
     Slice,
     Shift,
+    PadToLength {
+        length: usize,
+        side: PadSide,
+        as_array: bool,
+    },
+    Truncate {
+        length: usize,
+        side: PadSide,
+        as_array: bool,
+    },
     Get(bool),
     #[cfg(feature = "list_gather")]
     Gather(bool),
@@ -56,6 +66,8 @@ pub enum ListFunction {
     Join(bool),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
+    #[cfg(feature = "json")]
+    JsonEncode(JsonEncodeOptions),
 }
 
 impl ListFunction {
@@ -71,6 +83,18 @@ impl ListFunction {
             Sample { .. } => mapper.with_same_dtype(),
             Slice => mapper.with_same_dtype(),
             Shift => mapper.with_same_dtype(),
+            PadToLength {
+                length, as_array, ..
+            }
+            | Truncate {
+                length, as_array, ..
+            } => {
+                if *as_array {
+                    mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *length))
+                } else {
+                    mapper.with_same_dtype()
+                }
+            },
             Get(_) => mapper.map_to_list_and_array_inner_dtype(),
             #[cfg(feature = "list_gather")]
             Gather(_) => mapper.with_same_dtype(),
@@ -102,6 +126,8 @@ impl ListFunction {
             Join(_) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width)),
+            #[cfg(feature = "json")]
+            JsonEncode(_) => mapper.with_dtype(DataType::String),
             NUnique => mapper.with_dtype(IDX_DTYPE),
         }
     }
@@ -116,6 +142,11 @@ fn map_list_dtype_to_array_dtype(datatype: &DataType, width: usize) -> PolarsRes
     }
 }
 
+#[cfg(not(feature = "dtype-array"))]
+fn map_list_dtype_to_array_dtype(_datatype: &DataType, _width: usize) -> PolarsResult<DataType> {
+    polars_bail!(InvalidOperation: "activate 'dtype-array' feature to output fixed size arrays")
+}
+
 impl Display for ListFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         use ListFunction::*;
@@ -136,6 +167,8 @@ impl Display for ListFunction {
             },
             Slice => "slice",
             Shift => "shift",
+            PadToLength { .. } => "pad_to_length",
+            Truncate { .. } => "truncate",
             Get(_) => "get",
             #[cfg(feature = "list_gather")]
             Gather(_) => "gather",
@@ -174,6 +207,8 @@ impl Display for ListFunction {
             Join(_) => "join",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
+            #[cfg(feature = "json")]
+            JsonEncode(_) => "json_encode",
         };
         write!(f, "list.{name}")
     }
@@ -203,6 +238,16 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
             Slice => wrap!(slice),
             Shift => map_as_slice!(shift),
+            PadToLength {
+                length,
+                side,
+                as_array,
+            } => map_as_slice!(pad_to_length, length, side, as_array),
+            Truncate {
+                length,
+                side,
+                as_array,
+            } => map!(truncate, length, side, as_array),
             Get(null_on_oob) => wrap!(get, null_on_oob),
             #[cfg(feature = "list_gather")]
             Gather(null_on_oob) => map_as_slice!(gather, null_on_oob),
@@ -234,6 +279,8 @@ impl From<ListFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => map!(to_array, width),
+            #[cfg(feature = "json")]
+            JsonEncode(options) => map!(json_encode, options),
             NUnique => map!(n_unique),
         }
     }
@@ -391,6 +438,26 @@ pub(super) fn slice(args: &mut [Series]) -> PolarsResult<Option<Series>> {
     Ok(Some(out.into_series()))
 }
 
+pub(super) fn pad_to_length(
+    s: &[Series],
+    length: usize,
+    side: PadSide,
+    as_array: bool,
+) -> PolarsResult<Series> {
+    let list = s[0].list()?;
+    let fill = &s[1];
+    list_pad_to_length(list, length, side, fill, as_array)
+}
+
+pub(super) fn truncate(
+    s: &Series,
+    length: usize,
+    side: PadSide,
+    as_array: bool,
+) -> PolarsResult<Series> {
+    list_truncate(s.list()?, length, side, as_array)
+}
+
 pub(super) fn concat(s: &mut [Series]) -> PolarsResult<Option<Series>> {
     let mut first = std::mem::take(&mut s[0]);
     let other = &s[1..];
@@ -621,6 +688,12 @@ pub(super) fn to_array(s: &Series, width: usize) -> PolarsResult<Series> {
     s.cast(&array_dtype)
 }
 
+#[cfg(feature = "json")]
+pub(super) fn json_encode(s: &Series, options: JsonEncodeOptions) -> PolarsResult<Series> {
+    s.list()?;
+    json::json_encode(s, options)
+}
+
 pub(super) fn n_unique(s: &Series) -> PolarsResult<Series> {
     Ok(s.list()?.lst_n_unique()?.into_series())
 }
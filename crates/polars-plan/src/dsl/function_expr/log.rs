@@ -10,14 +10,34 @@ pub(super) fn entropy(s: &Series, base: f64, normalize: bool) -> PolarsResult<Se
     }
 }
 
-pub(super) fn log(s: &Series, base: f64) -> PolarsResult<Series> {
-    Ok(s.log(base))
+/// Apply `f` to the values of a float Series, reusing its buffers if they aren't shared.
+///
+/// Other data types are passed to `fallback`.
+fn apply_float_owned(
+    s: Series,
+    f32: impl Fn(f32) -> f32 + Copy,
+    f64: impl Fn(f64) -> f64 + Copy,
+    fallback: impl FnOnce(&Series) -> Series,
+) -> PolarsResult<Series> {
+    match s.dtype() {
+        DataType::Float32 => s.apply_values_in_place::<Float32Type, _>(f32),
+        DataType::Float64 => s.apply_values_in_place::<Float64Type, _>(f64),
+        _ => Ok(fallback(&s)),
+    }
+}
+
+pub(super) fn log(s: Series, base: f64) -> PolarsResult<Series> {
+    apply_float_owned(s, |v| v.log(base as f32), |v| v.log(base), |s| s.log(base))
+}
+
+pub(super) fn log1p(s: Series) -> PolarsResult<Series> {
+    apply_float_owned(s, |v| v.ln_1p(), |v| v.ln_1p(), |s| s.log1p())
 }
 
-pub(super) fn log1p(s: &Series) -> PolarsResult<Series> {
-    Ok(s.log1p())
+pub(super) fn exp(s: Series) -> PolarsResult<Series> {
+    apply_float_owned(s, |v| v.exp(), |v| v.exp(), |s| s.exp())
 }
 
-pub(super) fn exp(s: &Series) -> PolarsResult<Series> {
-    Ok(s.exp())
+pub(super) fn expm1(s: Series) -> PolarsResult<Series> {
+    apply_float_owned(s, |v| v.exp_m1(), |v| v.exp_m1(), |s| s.expm1())
 }
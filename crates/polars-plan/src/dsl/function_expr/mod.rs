@@ -30,6 +30,8 @@ mod ewm_by;
 mod fill_null;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "json")]
+mod json;
 mod list;
 #[cfg(feature = "log")]
 mod log;
@@ -138,6 +140,8 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "row_hash")]
+    HashHex(u64, u64, u64, u64),
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "search_sorted")]
@@ -160,6 +164,10 @@ pub enum FunctionExpr {
     Shift,
     DropNans,
     DropNulls,
+    #[cfg(feature = "dtype-struct")]
+    DropNullsStruct {
+        any: bool,
+    },
     #[cfg(feature = "mode")]
     Mode,
     #[cfg(feature = "moment")]
@@ -169,6 +177,10 @@ pub enum FunctionExpr {
     Reshape(Vec<i64>),
     #[cfg(feature = "repeat_by")]
     RepeatBy,
+    #[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+    RepeatFixed(usize),
+    #[cfg(feature = "dtype-array")]
+    ImplodeToArray(usize),
     ArgUnique,
     #[cfg(feature = "rank")]
     Rank {
@@ -184,6 +196,11 @@ pub enum FunctionExpr {
     AsStruct,
     #[cfg(feature = "top_k")]
     TopK(bool),
+    #[cfg(feature = "top_k")]
+    TopKBy {
+        sort_options: SortMultipleOptions,
+        return_indices: bool,
+    },
     #[cfg(feature = "cum_agg")]
     CumCount {
         reverse: bool,
@@ -213,11 +230,16 @@ pub enum FunctionExpr {
     #[cfg(feature = "unique_counts")]
     UniqueCounts,
     #[cfg(feature = "approx_unique")]
-    ApproxNUnique,
+    ApproxNUnique {
+        /// The number of hash bits used to index the registers of the HyperLogLog sketch.
+        precision: u8,
+    },
     Coalesce,
     ShrinkType,
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
+    #[cfg(feature = "diff")]
+    DiffBy,
     #[cfg(feature = "pct_change")]
     PctChange,
     #[cfg(feature = "interpolate")]
@@ -235,6 +257,8 @@ pub enum FunctionExpr {
     Log1p,
     #[cfg(feature = "log")]
     Exp,
+    #[cfg(feature = "log")]
+    Expm1,
     Unique(bool),
     #[cfg(feature = "round_series")]
     Round {
@@ -245,6 +269,11 @@ pub enum FunctionExpr {
         digits: i32,
     },
     #[cfg(feature = "round_series")]
+    RoundToMultiple {
+        step: f64,
+        mode: RoundMode,
+    },
+    #[cfg(feature = "round_series")]
     Floor,
     #[cfg(feature = "round_series")]
     Ceil,
@@ -268,6 +297,8 @@ pub enum FunctionExpr {
         labels: Option<Vec<String>>,
         left_closed: bool,
         include_breaks: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        index_only: bool,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -276,6 +307,8 @@ pub enum FunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        index_only: bool,
     },
     #[cfg(feature = "rle")]
     RLE,
@@ -305,6 +338,10 @@ pub enum FunctionExpr {
     ForwardFill {
         limit: FillNullLimit,
     },
+    /// Backward fill with the limit given by the second input.
+    BackwardFillWithLimit,
+    /// Forward fill with the limit given by the second input.
+    ForwardFillWithLimit,
     MaxHorizontal,
     MinHorizontal,
     SumHorizontal,
@@ -336,7 +373,10 @@ pub enum FunctionExpr {
     },
     #[cfg(feature = "reinterpret")]
     Reinterpret(bool),
-    ExtendConstant,
+    ExtendConstant {
+        #[cfg_attr(feature = "serde", serde(default))]
+        prepend: bool,
+    },
 }
 
 impl Hash for FunctionExpr {
@@ -377,6 +417,8 @@ impl Hash for FunctionExpr {
             Fused(f) => f.hash(state),
             #[cfg(feature = "diff")]
             Diff(_, null_behavior) => null_behavior.hash(state),
+            #[cfg(feature = "diff")]
+            DiffBy => {},
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "ffi_plugin")]
@@ -391,6 +433,9 @@ impl Hash for FunctionExpr {
             },
             MaxHorizontal | MinHorizontal | SumHorizontal | MeanHorizontal | DropNans
             | DropNulls | Reverse | ArgUnique | Shift | ShiftAndFill => {},
+            BackwardFillWithLimit | ForwardFillWithLimit => {},
+            #[cfg(feature = "dtype-struct")]
+            DropNullsStruct { any } => any.hash(state),
             #[cfg(feature = "mode")]
             Mode => {},
             #[cfg(feature = "abs")]
@@ -409,6 +454,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "row_hash")]
+            HashHex(a, b, c, d) => (a, b, c, d).hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -433,6 +480,14 @@ impl Hash for FunctionExpr {
             },
             #[cfg(feature = "top_k")]
             TopK(a) => a.hash(state),
+            #[cfg(feature = "top_k")]
+            TopKBy {
+                sort_options,
+                return_indices,
+            } => {
+                sort_options.hash(state);
+                return_indices.hash(state);
+            },
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => reverse.hash(state),
             #[cfg(feature = "cum_agg")]
@@ -451,7 +506,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "unique_counts")]
             UniqueCounts => {},
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => {},
+            ApproxNUnique { precision } => precision.hash(state),
             Coalesce => {},
             ShrinkType => {},
             #[cfg(feature = "pct_change")]
@@ -467,10 +522,17 @@ impl Hash for FunctionExpr {
             Log1p => {},
             #[cfg(feature = "log")]
             Exp => {},
+            #[cfg(feature = "log")]
+            Expm1 => {},
             Unique(a) => a.hash(state),
             #[cfg(feature = "round_series")]
             Round { decimals } => decimals.hash(state),
             #[cfg(feature = "round_series")]
+            RoundToMultiple { step, mode } => {
+                step.to_bits().hash(state);
+                mode.hash(state);
+            },
+            #[cfg(feature = "round_series")]
             FunctionExpr::RoundSF { digits } => digits.hash(state),
             #[cfg(feature = "round_series")]
             FunctionExpr::Floor => {},
@@ -489,18 +551,24 @@ impl Hash for FunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                index_only,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                index_only.hash(state);
             },
             Reshape(dims) => {
                 dims.hash(state);
             },
             #[cfg(feature = "repeat_by")]
             RepeatBy => {},
+            #[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+            RepeatFixed(n) => n.hash(state),
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(width) => width.hash(state),
             #[cfg(feature = "cutqcut")]
             QCut {
                 probs,
@@ -508,6 +576,7 @@ impl Hash for FunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                index_only,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -515,6 +584,7 @@ impl Hash for FunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                index_only.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
@@ -550,7 +620,7 @@ impl Hash for FunctionExpr {
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
-            ExtendConstant => {},
+            ExtendConstant { prepend } => prepend.hash(state),
         }
     }
 }
@@ -584,6 +654,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "row_hash")]
+            HashHex(_, _, _, _) => "hash_hex",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "search_sorted")]
@@ -604,6 +676,8 @@ impl Display for FunctionExpr {
             ShiftAndFill => "shift_and_fill",
             DropNans => "drop_nans",
             DropNulls => "drop_nulls",
+            #[cfg(feature = "dtype-struct")]
+            DropNullsStruct { .. } => "drop_nulls_struct",
             #[cfg(feature = "mode")]
             Mode => "mode",
             #[cfg(feature = "moment")]
@@ -630,6 +704,8 @@ impl Display for FunctionExpr {
                     "top_k"
                 }
             },
+            #[cfg(feature = "top_k")]
+            TopKBy { .. } => "top_k_by",
             Shift => "shift",
             #[cfg(feature = "cum_agg")]
             CumCount { .. } => "cum_count",
@@ -647,11 +723,13 @@ impl Display for FunctionExpr {
             UniqueCounts => "unique_counts",
             Reverse => "reverse",
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => "approx_n_unique",
+            ApproxNUnique { .. } => "approx_n_unique",
             Coalesce => "coalesce",
             ShrinkType => "shrink_dtype",
             #[cfg(feature = "diff")]
             Diff(_, _) => "diff",
+            #[cfg(feature = "diff")]
+            DiffBy => "diff_by",
             #[cfg(feature = "pct_change")]
             PctChange => "pct_change",
             #[cfg(feature = "interpolate")]
@@ -664,6 +742,8 @@ impl Display for FunctionExpr {
             Log1p => "log1p",
             #[cfg(feature = "log")]
             Exp => "exp",
+            #[cfg(feature = "log")]
+            Expm1 => "expm1",
             Unique(stable) => {
                 if *stable {
                     "unique_stable"
@@ -674,6 +754,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "round_series")]
             Round { .. } => "round",
             #[cfg(feature = "round_series")]
+            RoundToMultiple { .. } => "round_to_multiple",
+            #[cfg(feature = "round_series")]
             RoundSF { .. } => "round_sig_figs",
             #[cfg(feature = "round_series")]
             Floor => "floor",
@@ -697,6 +779,10 @@ impl Display for FunctionExpr {
             Reshape(_) => "reshape",
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
+            #[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+            RepeatFixed(_) => "repeat_fixed",
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(_) => "implode_to_array",
             #[cfg(feature = "rle")]
             RLE => "rle",
             #[cfg(feature = "rle")]
@@ -709,6 +795,8 @@ impl Display for FunctionExpr {
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
             BackwardFill { .. } => "backward_fill",
             ForwardFill { .. } => "forward_fill",
+            BackwardFillWithLimit => "backward_fill_with_limit",
+            ForwardFillWithLimit => "forward_fill_with_limit",
             MaxHorizontal => "max_horizontal",
             MinHorizontal => "min_horizontal",
             SumHorizontal => "sum_horizontal",
@@ -729,7 +817,8 @@ impl Display for FunctionExpr {
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
-            ExtendConstant => "extend_constant",
+            ExtendConstant { prepend: false } => "extend_constant",
+            ExtendConstant { prepend: true } => "prepend_constant",
         };
         write!(f, "{s}")
     }
@@ -858,6 +947,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "row_hash")]
+            HashHex(k0, k1, k2, k3) => {
+                map!(row_hash::row_hash_hex, k0, k1, k2, k3)
+            },
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -912,6 +1005,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     StdBy(options) => map_as_slice!(rolling::rolling_std_by, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew(window_size, bias) => map!(rolling::rolling_skew, window_size, bias),
+                    CorrCov {
+                        corr_cov_options,
+                        is_corr,
+                    } => map_as_slice!(rolling::rolling_corr_cov, corr_cov_options, is_corr),
                 }
             },
             #[cfg(feature = "hist")]
@@ -932,6 +1029,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
             DropNans => map_owned!(nan::drop_nans),
             DropNulls => map!(dispatch::drop_nulls),
+            #[cfg(feature = "dtype-struct")]
+            DropNullsStruct { any } => map!(dispatch::drop_nulls_struct, any),
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 map_as_slice!(clip::clip, has_min, has_max)
@@ -953,6 +1052,13 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             TopK(descending) => {
                 map_as_slice!(top_k, descending)
             },
+            #[cfg(feature = "top_k")]
+            TopKBy {
+                sort_options,
+                return_indices,
+            } => {
+                map_as_slice!(dispatch::top_k_by, sort_options.clone(), return_indices)
+            },
             Shift => map_as_slice!(shift_and_fill::shift),
             #[cfg(feature = "cum_agg")]
             CumCount { reverse } => map!(cum::cum_count, reverse),
@@ -970,11 +1076,13 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             UniqueCounts => map!(dispatch::unique_counts),
             Reverse => map!(dispatch::reverse),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => map!(dispatch::approx_n_unique),
+            ApproxNUnique { precision } => map!(dispatch::approx_n_unique, precision),
             Coalesce => map_as_slice!(fill_null::coalesce),
             ShrinkType => map_owned!(shrink_type::shrink),
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
+            #[cfg(feature = "diff")]
+            DiffBy => map_as_slice!(dispatch::diff_by),
             #[cfg(feature = "pct_change")]
             PctChange => map_as_slice!(dispatch::pct_change),
             #[cfg(feature = "interpolate")]
@@ -984,15 +1092,19 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
             #[cfg(feature = "log")]
-            Log { base } => map!(log::log, base),
+            Log { base } => map_owned!(log::log, base),
             #[cfg(feature = "log")]
-            Log1p => map!(log::log1p),
+            Log1p => map_owned!(log::log1p),
             #[cfg(feature = "log")]
-            Exp => map!(log::exp),
+            Exp => map_owned!(log::exp),
+            #[cfg(feature = "log")]
+            Expm1 => map_owned!(log::expm1),
             Unique(stable) => map!(unique::unique, stable),
             #[cfg(feature = "round_series")]
             Round { decimals } => map!(round::round, decimals),
             #[cfg(feature = "round_series")]
+            RoundToMultiple { step, mode } => map!(round::round_to_multiple, step, mode),
+            #[cfg(feature = "round_series")]
             RoundSF { digits } => map!(round::round_sig_figs, digits),
             #[cfg(feature = "round_series")]
             Floor => map!(round::floor),
@@ -1011,6 +1123,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             PeakMax => map!(peaks::peak_max),
             #[cfg(feature = "repeat_by")]
             RepeatBy => map_as_slice!(dispatch::repeat_by),
+            #[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+            RepeatFixed(n) => map!(dispatch::repeat_fixed, n),
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(width) => map!(dispatch::implode_to_array, width),
             Reshape(dims) => map!(dispatch::reshape, dims.clone()),
             #[cfg(feature = "cutqcut")]
             Cut {
@@ -1018,12 +1134,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 labels,
                 left_closed,
                 include_breaks,
+                index_only,
             } => map!(
                 cut,
                 breaks.clone(),
                 labels.clone(),
                 left_closed,
-                include_breaks
+                include_breaks,
+                index_only
             ),
             #[cfg(feature = "cutqcut")]
             QCut {
@@ -1032,13 +1150,15 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                index_only,
             } => map!(
                 qcut,
                 probs.clone(),
                 labels.clone(),
                 left_closed,
                 allow_duplicates,
-                include_breaks
+                include_breaks,
+                index_only
             ),
             #[cfg(feature = "rle")]
             RLE => map!(rle),
@@ -1054,11 +1174,24 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                         is_fraction,
                         with_replacement,
                         shuffle,
+                        clamp_to_len,
                     } => {
+                        let seeds = random::Seeds::new(seed);
                         if is_fraction {
-                            map_as_slice!(random::sample_frac, with_replacement, shuffle, seed)
+                            map_as_slice!(
+                                random::sample_frac,
+                                with_replacement,
+                                shuffle,
+                                seeds.next()
+                            )
                         } else {
-                            map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
+                            map_as_slice!(
+                                random::sample_n,
+                                with_replacement,
+                                shuffle,
+                                clamp_to_len,
+                                seeds.next()
+                            )
                         }
                     },
                 }
@@ -1079,6 +1212,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
             BackwardFill { limit } => map!(dispatch::backward_fill, limit),
             ForwardFill { limit } => map!(dispatch::forward_fill, limit),
+            BackwardFillWithLimit => map_as_slice!(dispatch::fill_with_limit, true),
+            ForwardFillWithLimit => map_as_slice!(dispatch::fill_with_limit, false),
             MaxHorizontal => wrap!(dispatch::max_horizontal),
             MinHorizontal => wrap!(dispatch::min_horizontal),
             SumHorizontal => wrap!(dispatch::sum_horizontal),
@@ -1102,7 +1237,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
-            ExtendConstant => map_as_slice!(dispatch::extend_constant),
+            ExtendConstant { prepend } => map_as_slice!(dispatch::extend_constant, prepend),
         }
     }
 }
@@ -1,5 +1,8 @@
 use super::*;
 
+/// Filter out the NaN values of a float column.
+///
+/// Other dtypes can't hold NaN values, so they are returned unchanged.
 pub(super) fn drop_nans(s: Series) -> PolarsResult<Series> {
     match s.dtype() {
         DataType::Float32 => {
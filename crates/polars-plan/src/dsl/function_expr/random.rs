@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use polars_core::prelude::DataType::Float64;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -14,6 +16,9 @@ pub enum RandomMethod {
         is_fraction: bool,
         with_replacement: bool,
         shuffle: bool,
+        /// Sample at most the length of the input instead of raising when `n` exceeds it.
+        #[cfg_attr(feature = "serde", serde(default))]
+        clamp_to_len: bool,
     },
 }
 
@@ -23,6 +28,31 @@ impl Hash for RandomMethod {
     }
 }
 
+/// The seeds of the calls of a sampling function.
+///
+/// In the group_by context the function is called once per group, in group order. Every
+/// call after the first mixes its index into the seed, so groups of the same size don't
+/// all sample the same positions, while the result stays reproducible for a given seed.
+pub(super) struct Seeds {
+    seed: Option<u64>,
+    calls: AtomicU64,
+}
+
+impl Seeds {
+    pub(super) fn new(seed: Option<u64>) -> Self {
+        Self {
+            seed,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    pub(super) fn next(&self) -> Option<u64> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        self.seed
+            .map(|seed| seed ^ call.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+}
+
 pub(super) fn shuffle(s: &Series, seed: Option<u64>) -> PolarsResult<Series> {
     Ok(s.shuffle(seed))
 }
@@ -54,21 +84,47 @@ pub(super) fn sample_n(
     s: &[Series],
     with_replacement: bool,
     shuffle: bool,
+    clamp_to_len: bool,
     seed: Option<u64>,
 ) -> PolarsResult<Series> {
     let src = &s[0];
     let n_s = &s[1];
 
+    // In the group_by context `n` can be a per-group aggregation, so this is the value for
+    // the current group.
     polars_ensure!(
         n_s.len() == 1,
         ComputeError: "Sample size must be a single value."
     );
+    // Integer-valued floats are accepted, e.g. the result of `len() * 0.5`.
+    polars_ensure!(
+        n_s.dtype().is_numeric(),
+        ComputeError: "Sample size must be an integer, got '{}'.", n_s.dtype()
+    );
 
-    let n_s = n_s.cast(&IDX_DTYPE)?;
-    let n = n_s.idx()?;
+    let n = n_s.get(0)?;
+    if n.is_null() {
+        return Ok(Series::new_empty(src.name(), src.dtype()));
+    }
+    let n = if n_s.dtype().is_float() {
+        let n = n.extract::<f64>().unwrap();
+        polars_ensure!(
+            n.fract() == 0.0,
+            ComputeError: "Sample size must be an integer, got {}.", n
+        );
+        n as i64
+    } else {
+        n.extract::<i64>()
+            .ok_or_else(|| polars_err!(ComputeError: "could not parse sample size '{}'.", n))?
+    };
+    polars_ensure!(
+        n >= 0,
+        ComputeError: "Sample size must be non-negative, got {}.", n
+    );
 
-    match n.get(0) {
-        Some(n) => src.sample_n(n as usize, with_replacement, shuffle, seed),
-        None => Ok(Series::new_empty(src.name(), src.dtype())),
+    let mut n = n as usize;
+    if clamp_to_len && !with_replacement {
+        n = n.min(src.len());
     }
+    src.sample_n(n, with_replacement, shuffle, seed)
 }
@@ -1,8 +1,12 @@
 #[cfg(feature = "timezones")]
 use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::*;
+#[cfg(feature = "dtype-duration")]
+use polars_core::utils::try_get_supertype;
 use polars_time::{datetime_range_impl, ClosedWindow, Duration};
 
+#[cfg(feature = "dtype-duration")]
+use super::utils::numeric_ranges_impl_broadcast;
 use super::utils::{
     ensure_range_bounds_contain_exactly_one_value, temporal_ranges_impl_broadcast,
     temporal_series_to_i64_scalar,
@@ -215,6 +219,86 @@ pub(super) fn datetime_ranges(
     out.cast(&to_type)
 }
 
+/// Create a column of datetime ranges where the step of every range is given by a Duration
+/// column.
+#[cfg(feature = "dtype-duration")]
+pub(super) fn datetime_ranges_by_step(s: &[Series], closed: ClosedWindow) -> PolarsResult<Series> {
+    let dtype = temporal_ranges_dtype(s[0].dtype(), s[1].dtype(), s[2].dtype())?;
+    let DataType::Datetime(tu, _) = dtype else {
+        unreachable!()
+    };
+
+    let start = s[0].cast(&dtype)?.to_physical_repr().into_owned();
+    let end = s[1].cast(&dtype)?.to_physical_repr().into_owned();
+    let step = s[2]
+        .cast(&DataType::Duration(tu))?
+        .to_physical_repr()
+        .into_owned();
+    let start = start.i64().unwrap();
+    let end = end.i64().unwrap();
+    let step = step.i64().unwrap();
+
+    let len = std::cmp::max(start.len(), end.len());
+    let mut builder = ListPrimitiveChunkedBuilder::<Int64Type>::new(
+        start.name(),
+        len,
+        len * CAPACITY_FACTOR,
+        DataType::Int64,
+    );
+
+    let include_end = matches!(closed, ClosedWindow::Both | ClosedWindow::Right);
+    let range_impl =
+        |start: i64, end: i64, step: i64, builder: &mut ListPrimitiveChunkedBuilder<Int64Type>| {
+            polars_ensure!(step > 0, ComputeError: "`step` must be positive, got {}", step);
+            let first = match closed {
+                ClosedWindow::Both | ClosedWindow::Left => Some(start),
+                ClosedWindow::Right | ClosedWindow::None => start.checked_add(step),
+            };
+            // Compute the length up front, so that no value can overflow.
+            let n = match first {
+                Some(first) if first <= end => {
+                    let n = (end as i128 - first as i128) / step as i128 + 1;
+                    let last = first as i128 + (n - 1) * step as i128;
+                    if last == end as i128 && !include_end {
+                        n - 1
+                    } else {
+                        n
+                    }
+                },
+                _ => 0,
+            };
+            // `i * step` can overflow even if the values don't, so compute the values in i128.
+            let first = first.unwrap_or_default() as i128;
+            builder.append_iter_values(
+                (0..n as i64).map(|i| (first + i as i128 * step as i128) as i64),
+            );
+            Ok(())
+        };
+
+    let out = numeric_ranges_impl_broadcast(start, end, step, range_impl, &mut builder)?;
+    out.cast(&DataType::List(Box::new(dtype)))
+}
+
+/// The output dtype of the datetime ranges with a Duration step.
+///
+/// Datetimes keep their time unit and time zone, dates become naive datetimes in the time unit
+/// of the step.
+#[cfg(feature = "dtype-duration")]
+pub(super) fn temporal_ranges_dtype(
+    start: &DataType,
+    end: &DataType,
+    step: &DataType,
+) -> PolarsResult<DataType> {
+    let DataType::Duration(step_tu) = step else {
+        polars_bail!(InvalidOperation: "`step` must be of type Duration, got {}", step)
+    };
+    match try_get_supertype(start, end)? {
+        DataType::Date => Ok(DataType::Datetime(*step_tu, None)),
+        dt @ DataType::Datetime(_, _) => Ok(dt),
+        dt => polars_bail!(InvalidOperation: "expected a temporal datatype, got {}", dt),
+    }
+}
+
 impl<'a> FieldsMapper<'a> {
     pub(super) fn map_to_datetime_range_dtype(
         &self,
@@ -56,6 +56,11 @@ pub enum RangeFunction {
         time_unit: Option<TimeUnit>,
         time_zone: Option<TimeZone>,
     },
+    /// Datetime ranges with a step of Duration dtype that can differ per row.
+    #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+    DatetimeRangesByStep {
+        closed: ClosedWindow,
+    },
     #[cfg(feature = "dtype-time")]
     TimeRange {
         interval: Duration,
@@ -128,6 +133,16 @@ impl RangeFunction {
                     mapper.map_to_datetime_range_dtype(time_unit.as_ref(), time_zone.as_deref())?;
                 mapper.with_dtype(DataType::List(Box::new(inner_dtype)))
             },
+            #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+            DatetimeRangesByStep { .. } => {
+                let args = mapper.args();
+                let inner_dtype = datetime_range::temporal_ranges_dtype(
+                    args[0].data_type(),
+                    args[1].data_type(),
+                    args[2].data_type(),
+                )?;
+                mapper.with_dtype(DataType::List(Box::new(inner_dtype)))
+            },
             #[cfg(feature = "dtype-time")]
             TimeRange { .. } => mapper.with_dtype(DataType::Time),
             #[cfg(feature = "dtype-time")]
@@ -150,6 +165,8 @@ impl Display for RangeFunction {
             DatetimeRange { .. } => "datetime_range",
             #[cfg(feature = "dtype-datetime")]
             DatetimeRanges { .. } => "datetime_ranges",
+            #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+            DatetimeRangesByStep { .. } => "datetime_ranges_by_step",
             #[cfg(feature = "dtype-time")]
             TimeRange { .. } => "time_range",
             #[cfg(feature = "dtype-time")]
@@ -229,6 +246,10 @@ impl From<RangeFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     time_zone.clone()
                 )
             },
+            #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+            DatetimeRangesByStep { closed } => {
+                map_as_slice!(datetime_range::datetime_ranges_by_step, closed)
+            },
             #[cfg(feature = "dtype-time")]
             TimeRange { interval, closed } => {
                 map_as_slice!(time_range::time_range, interval, closed)
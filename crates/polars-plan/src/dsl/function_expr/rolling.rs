@@ -21,6 +21,11 @@ pub enum RollingFunction {
     StdBy(RollingOptions),
     #[cfg(feature = "moment")]
     Skew(usize, bool),
+    CorrCov {
+        corr_cov_options: RollingCovOptions,
+        // Whether is Corr or Cov
+        is_corr: bool,
+    },
 }
 
 impl Display for RollingFunction {
@@ -44,6 +49,13 @@ impl Display for RollingFunction {
             StdBy(_) => "rolling_std_by",
             #[cfg(feature = "moment")]
             Skew(..) => "rolling_skew",
+            CorrCov { is_corr, .. } => {
+                if *is_corr {
+                    "rolling_corr"
+                } else {
+                    "rolling_cov"
+                }
+            },
         };
 
         write!(f, "{name}")
@@ -61,11 +73,35 @@ impl Hash for RollingFunction {
                 window_size.hash(state);
                 bias.hash(state)
             },
+            CorrCov {
+                corr_cov_options,
+                is_corr,
+            } => {
+                corr_cov_options.hash(state);
+                is_corr.hash(state)
+            },
             _ => {},
         }
     }
 }
 
+impl RollingFunction {
+    /// Check the options of a fixed window, so that invalid options error when the schema is
+    /// resolved instead of when the data is processed.
+    pub(super) fn check_options(&self) -> PolarsResult<()> {
+        use RollingFunction::*;
+        match self {
+            Min(options) | Max(options) | Mean(options) | Sum(options) | Quantile(options)
+            | Var(options) | Std(options) => {
+                let options: RollingOptionsImpl = options.clone().try_into()?;
+                let _: RollingOptionsFixedWindow = options.try_into()?;
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
 fn convert<'a>(
     f: impl Fn(RollingOptionsImpl) -> PolarsResult<Series> + 'a,
     ss: &'a [Series],
@@ -117,6 +153,8 @@ fn convert<'a>(
             window_size: options.window_size,
             min_periods: options.min_periods,
             weights: None,
+            normalize_weights: false,
+            precise: options.precise,
             center: options.center,
             by: Some(by_values),
             tu: Some(tu),
@@ -193,3 +231,19 @@ pub(super) fn rolling_std_by(s: &[Series], options: RollingOptions) -> PolarsRes
 pub(super) fn rolling_skew(s: &Series, window_size: usize, bias: bool) -> PolarsResult<Series> {
     s.rolling_skew(window_size, bias)
 }
+
+pub(super) fn rolling_corr_cov(
+    s: &[Series],
+    options: RollingCovOptions,
+    is_corr: bool,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::rolling_corr_cov(
+        &s[0],
+        &s[1],
+        options.window_size as usize,
+        options.min_periods as usize,
+        options.ddof,
+        options.spearman,
+        is_corr,
+    )
+}
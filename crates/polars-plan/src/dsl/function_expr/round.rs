@@ -8,6 +8,10 @@ pub(super) fn round_sig_figs(s: &Series, digits: i32) -> PolarsResult<Series> {
     s.round_sig_figs(digits)
 }
 
+pub(super) fn round_to_multiple(s: &Series, step: f64, mode: RoundMode) -> PolarsResult<Series> {
+    s.round_to_multiple(step, mode)
+}
+
 pub(super) fn floor(s: &Series) -> PolarsResult<Series> {
     s.floor()
 }
@@ -1,6 +1,20 @@
+use std::fmt::Write;
+
 use super::*;
 
 pub(super) fn row_hash(s: &Series, k0: u64, k1: u64, k2: u64, k3: u64) -> PolarsResult<Series> {
     Ok(s.hash(ahash::RandomState::with_seeds(k0, k1, k2, k3))
         .into_series())
 }
+
+pub(super) fn row_hash_hex(s: &Series, k0: u64, k1: u64, k2: u64, k3: u64) -> PolarsResult<Series> {
+    let hashes = s.hash(ahash::RandomState::with_seeds(k0, k1, k2, k3));
+    let mut builder = StringChunkedBuilder::new(hashes.name(), hashes.len());
+    let mut buf = String::with_capacity(16);
+    for hash in hashes.into_no_null_iter() {
+        buf.clear();
+        write!(buf, "{hash:016x}").unwrap();
+        builder.append_value(&buf);
+    }
+    Ok(builder.finish().into_series())
+}
@@ -36,7 +36,7 @@ impl FunctionExpr {
             },
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
-            Negate => mapper.with_same_dtype(),
+            Negate => mapper.map_dtype(polars_ops::series::negate_dtype),
             NullCount => mapper.with_dtype(IDX_DTYPE),
             Pow(pow_function) => match pow_function {
                 PowFunction::Generic => mapper.pow_dtype(),
@@ -45,6 +45,8 @@ impl FunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "row_hash")]
+            HashHex(..) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "search_sorted")]
@@ -63,6 +65,7 @@ impl FunctionExpr {
             #[cfg(feature = "rolling_window")]
             RollingExpr(rolling_func, ..) => {
                 use RollingFunction::*;
+                rolling_func.check_options()?;
                 match rolling_func {
                     Min(_) | MinBy(_) | Max(_) | MaxBy(_) | Sum(_) | SumBy(_) => {
                         mapper.with_same_dtype()
@@ -71,11 +74,14 @@ impl FunctionExpr {
                     | Std(_) | StdBy(_) => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
                     Skew(..) => mapper.map_to_float_dtype(),
+                    CorrCov { .. } => mapper.with_dtype(DataType::Float64),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
             DropNans => mapper.with_same_dtype(),
             DropNulls => mapper.with_same_dtype(),
+            #[cfg(feature = "dtype-struct")]
+            DropNullsStruct { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "mode")]
@@ -97,6 +103,14 @@ impl FunctionExpr {
             )),
             #[cfg(feature = "top_k")]
             TopK(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "top_k")]
+            TopKBy { return_indices, .. } => {
+                if *return_indices {
+                    mapper.with_dtype(IDX_DTYPE)
+                } else {
+                    mapper.with_same_dtype()
+                }
+            },
             #[cfg(feature = "dtype-struct")]
             ValueCounts { .. } => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
@@ -118,7 +132,7 @@ impl FunctionExpr {
             #[cfg(feature = "cum_agg")]
             CumMax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
-            ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            ApproxNUnique { .. } => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "hist")]
             Hist {
                 include_category,
@@ -155,6 +169,8 @@ impl FunctionExpr {
                 DataType::UInt8 => DataType::Int16,
                 dt => dt.clone(),
             }),
+            #[cfg(feature = "diff")]
+            DiffBy => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "pct_change")]
             PctChange => mapper.map_dtype(|dt| match dt {
                 DataType::Float64 | DataType::Float32 => dt.clone(),
@@ -162,7 +178,9 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(),
+                InterpolationMethod::Linear | InterpolationMethod::Pchip => {
+                    mapper.map_numeric_to_float_dtype()
+                },
                 InterpolationMethod::Nearest => mapper.with_same_dtype(),
             },
             ShrinkType => {
@@ -189,10 +207,12 @@ impl FunctionExpr {
                 })
             },
             #[cfg(feature = "log")]
-            Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
+            Entropy { .. } | Log { .. } | Log1p | Exp | Expm1 => mapper.map_to_float_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
+            #[cfg(feature = "round_series")]
+            RoundToMultiple { .. } => mapper.map_to_float_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
@@ -205,27 +225,50 @@ impl FunctionExpr {
             PeakMax => mapper.with_same_dtype(),
             #[cfg(feature = "cutqcut")]
             Cut {
-                include_breaks: false,
+                include_breaks,
+                index_only,
                 ..
-            } => mapper.with_dtype(DataType::Categorical(None, Default::default())),
-            #[cfg(feature = "cutqcut")]
-            Cut {
-                include_breaks: true,
+            }
+            | QCut {
+                include_breaks,
+                index_only,
                 ..
             } => {
-                let name = fields[0].name();
-                let name_bin = format!("{}_bin", name);
-                let struct_dt = DataType::Struct(vec![
-                    Field::new("brk", DataType::Float64),
-                    Field::new(
-                        name_bin.as_str(),
-                        DataType::Categorical(None, Default::default()),
-                    ),
-                ]);
-                mapper.with_dtype(struct_dt)
+                let bin_dtype = if *index_only {
+                    DataType::UInt32
+                } else {
+                    DataType::Categorical(None, Default::default())
+                };
+                if *include_breaks {
+                    let name = fields[0].name();
+                    let name_bin = format!("{}_bin", name);
+                    let struct_dt = DataType::Struct(vec![
+                        Field::new("brk", DataType::Float64),
+                        Field::new(name_bin.as_str(), bin_dtype),
+                    ]);
+                    mapper.with_dtype(struct_dt)
+                } else {
+                    mapper.with_dtype(bin_dtype)
+                }
             },
             #[cfg(feature = "repeat_by")]
             RepeatBy => mapper.map_dtype(|dt| DataType::List(dt.clone().into())),
+            #[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+            RepeatFixed(n) => {
+                polars_ensure!(
+                    *n > 0,
+                    InvalidOperation: "`repeat_fixed` cannot create arrays of width 0; use `repeat_by` to create empty lists"
+                );
+                mapper.map_dtype(|dt| DataType::Array(Box::new(dt.clone()), *n))
+            },
+            #[cfg(feature = "dtype-array")]
+            ImplodeToArray(width) => {
+                polars_ensure!(
+                    *width > 0,
+                    InvalidOperation: "`implode_to_array` cannot create arrays of width 0"
+                );
+                mapper.map_dtype(|dt| DataType::Array(Box::new(dt.clone()), *width))
+            },
             Reshape(dims) => mapper.map_dtype(|dt| {
                 let dtype = dt.inner_dtype().unwrap_or(dt).clone();
                 if dims.len() == 1 {
@@ -234,27 +277,6 @@ impl FunctionExpr {
                     DataType::List(Box::new(dtype))
                 }
             }),
-            #[cfg(feature = "cutqcut")]
-            QCut {
-                include_breaks: false,
-                ..
-            } => mapper.with_dtype(DataType::Categorical(None, Default::default())),
-            #[cfg(feature = "cutqcut")]
-            QCut {
-                include_breaks: true,
-                ..
-            } => {
-                let name = fields[0].name();
-                let name_bin = format!("{}_bin", name);
-                let struct_dt = DataType::Struct(vec![
-                    Field::new("brk", DataType::Float64),
-                    Field::new(
-                        name_bin.as_str(),
-                        DataType::Categorical(None, Default::default()),
-                    ),
-                ]);
-                mapper.with_dtype(struct_dt)
-            },
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
@@ -276,6 +298,7 @@ impl FunctionExpr {
             } => unsafe { plugin::plugin_field(fields, lib, symbol.as_ref(), kwargs) },
             BackwardFill { .. } => mapper.with_same_dtype(),
             ForwardFill { .. } => mapper.with_same_dtype(),
+            BackwardFillWithLimit | ForwardFillWithLimit => mapper.with_same_dtype(),
             MaxHorizontal => mapper.map_to_supertype(),
             MinHorizontal => mapper.map_to_supertype(),
             SumHorizontal => {
@@ -296,6 +319,9 @@ impl FunctionExpr {
             EwmVar { .. } => mapper.map_to_float_dtype(),
             #[cfg(feature = "replace")]
             Replace { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
+            FillNullWithStrategy(FillNullStrategy::Interpolate(
+                InterpolationMethod::Linear | InterpolationMethod::Pchip,
+            )) => mapper.map_numeric_to_float_dtype(),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
@@ -307,7 +333,7 @@ impl FunctionExpr {
                 };
                 mapper.with_dtype(dt)
             },
-            ExtendConstant => mapper.with_same_dtype(),
+            ExtendConstant { .. } => mapper.with_same_dtype(),
         }
     }
 
@@ -349,6 +375,11 @@ impl<'a> FieldsMapper<'a> {
         self.fields.len()
     }
 
+    /// The fields of all inputs.
+    pub fn args(&self) -> &[Field] {
+        self.fields
+    }
+
     /// Map a single field with a potentially failing mapper function.
     pub fn try_map_field(
         &self,
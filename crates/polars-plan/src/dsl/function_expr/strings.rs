@@ -107,8 +107,14 @@ pub enum StringFunction {
     },
     #[cfg(feature = "dtype-struct")]
     SplitN(usize),
+    SplitNList {
+        n: usize,
+        inclusive: bool,
+    },
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
+    #[cfg(feature = "temporal")]
+    StrptimeFormats(DataType, Vec<String>, StrptimeOptions),
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
@@ -158,7 +164,11 @@ impl StringFunction {
             Reverse => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
-            Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "temporal")]
+            StrptimeFormats(dtype, _, _) => mapper.with_dtype(dtype.clone()),
+            Split(_) | SplitNList { .. } => {
+                mapper.with_dtype(DataType::List(Box::new(DataType::String)))
+            },
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
@@ -258,8 +268,17 @@ impl Display for StringFunction {
             },
             #[cfg(feature = "dtype-struct")]
             SplitN(_) => "splitn",
+            SplitNList { inclusive, .. } => {
+                if *inclusive {
+                    "splitn_list_inclusive"
+                } else {
+                    "splitn_list"
+                }
+            },
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "temporal")]
+            StrptimeFormats(_, _, _) => "strptime",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -322,6 +341,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             Strptime(dtype, options) => {
                 map_as_slice!(strings::strptime, dtype.clone(), &options)
             },
+            #[cfg(feature = "temporal")]
+            StrptimeFormats(dtype, formats, options) => {
+                map_as_slice!(strings::strptime_formats, dtype.clone(), &formats, &options)
+            },
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
@@ -329,6 +352,7 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
             SplitN(n) => map_as_slice!(strings::splitn, n),
+            SplitNList { n, inclusive } => map_as_slice!(strings::splitn_list, n, inclusive),
             #[cfg(feature = "concat_str")]
             ConcatVertical {
                 delimiter,
@@ -592,6 +616,79 @@ pub(super) fn strptime(
     }
 }
 
+#[cfg(feature = "temporal")]
+pub(super) fn strptime_formats(
+    s: &[Series],
+    dtype: DataType,
+    formats: &[String],
+    options: &StrptimeOptions,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        !formats.is_empty(),
+        ComputeError: "strptime with multiple formats requires at least one format"
+    );
+    polars_ensure!(
+        options.exact,
+        ComputeError: "non-exact parsing is not supported with multiple formats"
+    );
+    let ca = s[0].str()?;
+    let formats = formats.iter().map(|fmt| fmt.as_str()).collect::<Vec<_>>();
+
+    let out = match dtype {
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => ca.as_date_formats(&formats, options.cache)?.into_series(),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(time_unit, time_zone) => {
+            let ambiguous = s[1].str()?;
+            #[cfg(feature = "timezones")]
+            let tz_aware = {
+                let n_tz_aware = formats
+                    .iter()
+                    .filter(|fmt| TZ_AWARE_RE.is_match(fmt))
+                    .count();
+                polars_ensure!(
+                    n_tz_aware == 0 || n_tz_aware == formats.len(),
+                    ComputeError: "cannot mix time-zone-aware and naive formats in strptime: {:?}",
+                    formats
+                );
+                n_tz_aware > 0
+            };
+            #[cfg(not(feature = "timezones"))]
+            let tz_aware = false;
+            if let (Some(tz), true) = (&time_zone, tz_aware) {
+                polars_ensure!(
+                    tz == "UTC",
+                    ComputeError:
+                    "if using strftime/to_datetime with a time-zone-aware format, the output will be in UTC. Please either drop the time zone from the function call, or set it to UTC. \
+                    If you are trying to convert the output to a different time zone, please use `convert_time_zone`."
+                );
+            }
+            ca.as_datetime_formats(
+                &formats,
+                time_unit,
+                options.cache,
+                tz_aware,
+                time_zone.as_ref(),
+                ambiguous,
+            )?
+            .into_series()
+        },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => ca.as_time_formats(&formats, options.cache)?.into_series(),
+        dt => polars_bail!(ComputeError: "not implemented for dtype {}", dt),
+    };
+
+    if options.strict && ca.null_count() != out.null_count() {
+        let failures = s[0].filter(&(!s[0].is_null() & out.is_null()))?;
+        polars_bail!(
+            ComputeError:
+            "{} out of {} values in column '{}' matched none of the formats {:?}: {}",
+            failures.len(), ca.len(), ca.name(), formats, failures.fmt_list(),
+        );
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "dtype-struct")]
 pub(super) fn split_exact(s: &[Series], n: usize, inclusive: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
@@ -612,6 +709,17 @@ pub(super) fn splitn(s: &[Series], n: usize) -> PolarsResult<Series> {
     ca.splitn(by, n).map(|ca| ca.into_series())
 }
 
+pub(super) fn splitn_list(s: &[Series], n: usize, inclusive: bool) -> PolarsResult<Series> {
+    let ca = s[0].str()?;
+    let by = s[1].str()?;
+
+    if inclusive {
+        Ok(ca.splitn_list_inclusive(by, n).into_series())
+    } else {
+        Ok(ca.splitn_list(by, n).into_series())
+    }
+}
+
 pub(super) fn split(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
     let ca = s[0].str()?;
     let by = s[1].str()?;
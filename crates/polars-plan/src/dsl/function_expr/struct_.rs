@@ -1,7 +1,7 @@
 use polars_core::utils::slice_offsets;
 
 use super::*;
-use crate::map;
+use crate::{map, map_as_slice};
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,7 +12,12 @@ pub enum StructFunction {
     PrefixFields(Arc<str>),
     SuffixFields(Arc<str>),
     #[cfg(feature = "json")]
-    JsonEncode,
+    JsonEncode(JsonEncodeOptions),
+    WithFields,
+    FillNull {
+        strategy: FillNullStrategy,
+        fill_null_rows: bool,
+    },
 }
 
 impl StructFunction {
@@ -89,7 +94,28 @@ impl StructFunction {
                 _ => polars_bail!(op = "suffix_fields", got = dt, expected = "Struct"),
             }),
             #[cfg(feature = "json")]
-            JsonEncode => mapper.with_dtype(DataType::String),
+            JsonEncode(_) => mapper.with_dtype(DataType::String),
+            WithFields => {
+                let args = mapper.args();
+                let struct_ = &args[0];
+                if let DataType::Struct(fields) = struct_.data_type() {
+                    let mut fields = fields.clone();
+                    for new in &args[1..] {
+                        match fields.iter_mut().find(|fld| fld.name() == new.name()) {
+                            Some(fld) => *fld = new.clone(),
+                            None => fields.push(new.clone()),
+                        }
+                    }
+                    Ok(Field::new(struct_.name(), DataType::Struct(fields)))
+                } else {
+                    polars_bail!(
+                        op = "with_fields",
+                        got = struct_.data_type(),
+                        expected = "Struct"
+                    )
+                }
+            },
+            FillNull { .. } => mapper.with_same_dtype(),
         }
     }
 }
@@ -104,7 +130,9 @@ impl Display for StructFunction {
             PrefixFields(_) => write!(f, "name.prefix_fields"),
             SuffixFields(_) => write!(f, "name.suffixFields"),
             #[cfg(feature = "json")]
-            JsonEncode => write!(f, "struct.to_json"),
+            JsonEncode(_) => write!(f, "struct.to_json"),
+            WithFields => write!(f, "struct.with_fields"),
+            FillNull { .. } => write!(f, "struct.fill_null"),
         }
     }
 }
@@ -119,7 +147,12 @@ impl From<StructFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             PrefixFields(prefix) => map!(struct_::prefix_fields, prefix.clone()),
             SuffixFields(suffix) => map!(struct_::suffix_fields, suffix.clone()),
             #[cfg(feature = "json")]
-            JsonEncode => map!(struct_::to_json),
+            JsonEncode(options) => map!(struct_::to_json, options),
+            WithFields => map_as_slice!(struct_::with_fields),
+            FillNull {
+                strategy,
+                fill_null_rows,
+            } => map!(struct_::fill_null, strategy, fill_null_rows),
         }
     }
 }
@@ -174,15 +207,50 @@ pub(super) fn suffix_fields(s: &Series, suffix: Arc<str>) -> PolarsResult<Series
     StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
 }
 
-#[cfg(feature = "json")]
-pub(super) fn to_json(s: &Series) -> PolarsResult<Series> {
-    let ca = s.struct_()?;
-    let dtype = ca.dtype().to_arrow(true);
+pub(super) fn with_fields(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].struct_()?;
+    let len = ca.len();
+    // Rows where the struct is null stay null.
+    let is_valid = (ca.null_count() > 0).then(|| s[0].is_not_null());
+
+    let mut fields = ca.fields().to_vec();
+    for new in &s[1..] {
+        let new = if new.len() == 1 && len != 1 {
+            new.new_from_index(0, len)
+        } else {
+            polars_ensure!(
+                new.len() == len,
+                ShapeMismatch: "field '{}' of `struct.with_fields` has length {}, expected {}",
+                new.name(), new.len(), len
+            );
+            new.clone()
+        };
+        let new = match &is_valid {
+            Some(is_valid) => {
+                new.zip_with(is_valid, &Series::full_null(new.name(), len, new.dtype()))?
+            },
+            None => new,
+        };
+        match fields.iter_mut().find(|fld| fld.name() == new.name()) {
+            Some(fld) => *fld = new,
+            None => fields.push(new),
+        }
+    }
+    StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+}
 
-    let iter = ca.chunks().iter().map(|arr| {
-        let arr = arrow::compute::cast::cast_unchecked(arr.as_ref(), &dtype).unwrap();
-        polars_json::json::write::serialize_to_utf8(arr.as_ref())
-    });
+pub(super) fn fill_null(
+    s: &Series,
+    strategy: FillNullStrategy,
+    fill_null_rows: bool,
+) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    ca.fill_null_fields(strategy, fill_null_rows)
+        .map(|ca| ca.into_series())
+}
 
-    Ok(StringChunked::from_chunk_iter(ca.name(), iter).into_series())
+#[cfg(feature = "json")]
+pub(super) fn to_json(s: &Series, options: JsonEncodeOptions) -> PolarsResult<Series> {
+    s.struct_()?;
+    json::json_encode(s, options)
 }
@@ -62,7 +62,10 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ReplaceTimeZone(tz, non_existent) => {
                 map_as_slice!(dispatch::replace_time_zone, tz.as_deref(), non_existent)
             },
-            Combine(tu) => map_as_slice!(temporal::combine, tu),
+            Combine {
+                time_unit,
+                time_zone,
+            } => map_as_slice!(temporal::combine, time_unit, time_zone.as_deref()),
             DatetimeFunction {
                 time_unit,
                 time_zone,
@@ -294,17 +297,19 @@ pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
+pub(super) fn combine(s: &[Series], tu: TimeUnit, time_zone: Option<&str>) -> PolarsResult<Series> {
     let date = &s[0];
     let time = &s[1];
 
     let tz = match date.dtype() {
         DataType::Date => None,
-        DataType::Datetime(_, tz) => tz.as_ref(),
+        DataType::Datetime(_, tz) => tz.as_deref(),
         _dtype => {
             polars_bail!(ComputeError: format!("expected Date or Datetime, got {}", _dtype))
         },
     };
+    // An explicit time zone takes precedence over the one of the input.
+    let tz = time_zone.or(tz);
 
     let date = date.cast(&DataType::Date)?;
     let datetime = date.cast(&DataType::Datetime(tu, None)).unwrap();
@@ -320,6 +325,10 @@ pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
             NonExistent::Raise,
         )?
         .into()),
-        _ => Ok(result_naive),
+        #[cfg(not(feature = "timezones"))]
+        Some(_) => polars_bail!(
+            ComputeError: "cannot combine into a time zone aware datetime; activate the 'timezones' feature"
+        ),
+        None => Ok(result_naive),
     }
 }
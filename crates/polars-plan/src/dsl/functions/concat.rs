@@ -69,6 +69,21 @@ pub fn concat_list<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult
     })
 }
 
+/// Concat lists entries like [`concat_list`], if `ignore_nulls` is set null values are
+/// dropped from the resulting lists.
+#[cfg(feature = "list_drop_nulls")]
+pub fn concat_to_list<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+    s: E,
+    ignore_nulls: bool,
+) -> PolarsResult<Expr> {
+    let out = concat_list(s)?;
+    Ok(if ignore_nulls {
+        out.list().drop_nulls()
+    } else {
+        out
+    })
+}
+
 pub fn concat_expr<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
     s: E,
     rechunk: bool,
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "rolling_window")]
+use crate::dsl::function_expr::RollingFunction;
 
 /// Compute the covariance between two columns.
 pub fn cov(a: Expr, b: Expr, ddof: u8) -> Expr {
@@ -71,59 +73,34 @@ pub fn spearman_rank_corr(a: Expr, b: Expr, ddof: u8, propagate_nans: bool) -> E
 }
 
 #[cfg(feature = "rolling_window")]
-pub fn rolling_corr(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
-    // see: https://github.com/pandas-dev/pandas/blob/v1.5.1/pandas/core/window/rolling.py#L1780-L1804
-    let rolling_options = RollingOptions {
-        window_size: Duration::new(options.window_size as i64),
-        min_periods: options.min_periods as usize,
-        ..Default::default()
-    };
-
-    let mean_x_y = (x.clone() * y.clone()).rolling_mean(rolling_options.clone());
-    let mean_x = x.clone().rolling_mean(rolling_options.clone());
-    let mean_y = y.clone().rolling_mean(rolling_options.clone());
-    let var_x = x.clone().rolling_var(rolling_options.clone());
-    let var_y = y.clone().rolling_var(rolling_options);
-
-    let rolling_options_count = RollingOptions {
-        window_size: Duration::new(options.window_size as i64),
-        min_periods: 0,
-        ..Default::default()
-    };
-    let ddof = options.ddof as f64;
-    let count_x_y = (x + y)
-        .is_not_null()
-        .cast(DataType::Float64)
-        .rolling_sum(rolling_options_count);
-    let numerator = (mean_x_y - mean_x * mean_y) * (count_x_y.clone() / (count_x_y - lit(ddof)));
-    let denominator = (var_x * var_y).pow(lit(0.5));
+fn rolling_corr_cov(x: Expr, y: Expr, options: RollingCovOptions, is_corr: bool) -> Expr {
+    Expr::Function {
+        input: vec![x, y],
+        function: FunctionExpr::RollingExpr(RollingFunction::CorrCov {
+            corr_cov_options: options,
+            is_corr,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            ..Default::default()
+        },
+    }
+}
 
-    numerator / denominator
+/// Compute the rolling correlation between two columns.
+///
+/// Only rows in which both columns are valid count towards `min_periods`; windows with fewer
+/// valid pairs produce a null. Set `spearman` to rank the values within each window first.
+#[cfg(feature = "rolling_window")]
+pub fn rolling_corr(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
+    rolling_corr_cov(x, y, options, true)
 }
 
+/// Compute the rolling covariance between two columns.
+///
+/// Only rows in which both columns are valid count towards `min_periods`; windows with fewer
+/// valid pairs produce a null.
 #[cfg(feature = "rolling_window")]
 pub fn rolling_cov(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
-    // see: https://github.com/pandas-dev/pandas/blob/91111fd99898d9dcaa6bf6bedb662db4108da6e6/pandas/core/window/rolling.py#L1700
-    let rolling_options = RollingOptions {
-        window_size: Duration::new(options.window_size as i64),
-        min_periods: options.min_periods as usize,
-        ..Default::default()
-    };
-
-    let mean_x_y = (x.clone() * y.clone()).rolling_mean(rolling_options.clone());
-    let mean_x = x.clone().rolling_mean(rolling_options.clone());
-    let mean_y = y.clone().rolling_mean(rolling_options);
-    let rolling_options_count = RollingOptions {
-        window_size: Duration::new(options.window_size as i64),
-        min_periods: 0,
-        ..Default::default()
-    };
-    let count_x_y = (x + y)
-        .is_not_null()
-        .cast(DataType::Float64)
-        .rolling_sum(rolling_options_count);
-
-    let ddof = options.ddof as f64;
-
-    (mean_x_y - mean_x * mean_y) * (count_x_y.clone() / (count_x_y - lit(ddof)))
+    rolling_corr_cov(x, y, options, false)
 }
@@ -15,6 +15,9 @@ pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -
 
 #[cfg(feature = "arg_where")]
 /// Get the indices where `condition` evaluates `true`.
+///
+/// In a `group_by` context the indices are relative to the start of each group. Use
+/// `LazyFrame::filtered_indices` to get the global row numbers of a whole frame.
 pub fn arg_where<E: Into<Expr>>(condition: E) -> Expr {
     let condition = condition.into();
     Expr::Function {
@@ -151,6 +151,22 @@ pub fn datetime_ranges(
     }
 }
 
+/// Create a column of datetime ranges from a `start` and `end` expression, where every row
+/// steps by the value of the `step` expression of Duration dtype.
+#[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+pub fn datetime_ranges_by_step(start: Expr, end: Expr, step: Expr, closed: ClosedWindow) -> Expr {
+    let input = vec![start, end, step];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::Range(RangeFunction::DatetimeRangesByStep { closed }),
+        options: FunctionOptions {
+            allow_rename: true,
+            ..Default::default()
+        },
+    }
+}
+
 /// Generate a time range.
 #[cfg(feature = "dtype-time")]
 pub fn time_range(start: Expr, end: Expr, interval: Duration, closed: ClosedWindow) -> Expr {
@@ -4,6 +4,19 @@ use super::*;
 /// as `lit(value)` already represents a column containing only `value` whose length is automatically set to the correct
 /// number of rows.
 pub fn repeat<E: Into<Expr>>(value: E, n: Expr) -> Expr {
+    let value = value.into();
+
+    // Fast path: a scalar literal repeated a literal number of times doesn't have to evaluate
+    // and broadcast `n` for every group.
+    if let (Expr::Literal(lv), Some(n)) = (&value, literal_count(&n)) {
+        if lv.projects_as_scalar() {
+            let function = move |s: Series| Ok(Some(s.new_from_index(0, n)));
+            return value
+                .apply(function, GetOutput::same_type())
+                .alias("repeat");
+        }
+    }
+
     let function = |s: Series, n: Series| {
         polars_ensure!(
             n.dtype().is_integer(),
@@ -15,5 +28,20 @@ pub fn repeat<E: Into<Expr>>(value: E, n: Expr) -> Expr {
         )?;
         Ok(Some(s.new_from_index(0, n)))
     };
-    apply_binary(value.into(), n, function, GetOutput::same_type()).alias("repeat")
+    apply_binary(value, n, function, GetOutput::same_type()).alias("repeat")
+}
+
+/// Get `n` if it is a non-negative integer literal.
+fn literal_count(n: &Expr) -> Option<usize> {
+    match n {
+        Expr::Literal(lv) => {
+            let av = lv.to_any_value()?;
+            if av.dtype().is_integer() {
+                av.extract::<usize>()
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::constants::ELEMENT_INDEX_NAME;
 
 /// Create a Column Expression based on a column name.
 ///
@@ -31,6 +32,14 @@ pub fn col(name: &str) -> Expr {
     }
 }
 
+/// The 0-based position of an element within its list.
+///
+/// This is only valid in the expression passed to `list.eval`. In nested evaluations it
+/// refers to the innermost list.
+pub fn element_index() -> Expr {
+    Expr::Column(ColumnName::from(ELEMENT_INDEX_NAME))
+}
+
 /// Selects all columns. Shorthand for `col("*")`.
 pub fn all() -> Expr {
     Expr::Wildcard
@@ -196,6 +196,9 @@ impl ListNameSpace {
     }
 
     /// Join all string items in a sublist and place a separator between them.
+    ///
+    /// If `ignore_nulls` is `false`, a sublist that contains a null yields null; otherwise
+    /// the nulls are skipped. An empty sublist yields an empty string.
     /// # Error
     /// This errors if inner type of list `!= DataType::String`.
     pub fn join(self, separator: Expr, ignore_nulls: bool) -> Expr {
@@ -259,6 +262,36 @@ impl ListNameSpace {
         self.slice(lit(0i64) - n.clone().cast(DataType::Int64), n)
     }
 
+    /// Pad every sublist with `fill` at the given `side` until it has `length` elements.
+    ///
+    /// Sublists that are already long enough are left untouched. If `as_array` is set, the output
+    /// is an `Array` of width `length` and longer sublists raise an error.
+    pub fn pad_to_length(self, length: usize, fill: Expr, side: PadSide, as_array: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ListExpr(ListFunction::PadToLength {
+                length,
+                side,
+                as_array,
+            }),
+            &[fill],
+            false,
+            false,
+        )
+    }
+
+    /// Truncate every sublist to at most `length` elements, removing elements at the given `side`.
+    ///
+    /// If `as_array` is set, the output is an `Array` of width `length` and shorter sublists
+    /// raise an error.
+    pub fn truncate(self, length: usize, side: PadSide, as_array: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::Truncate {
+                length,
+                side,
+                as_array,
+            }))
+    }
+
     #[cfg(feature = "dtype-array")]
     /// Convert a List column into an Array column with the same inner data type.
     pub fn to_array(self, width: usize) -> Expr {
@@ -266,6 +299,22 @@ impl ListNameSpace {
             .map_private(FunctionExpr::ListExpr(ListFunction::ToArray(width)))
     }
 
+    #[cfg(feature = "json")]
+    /// Serialize every sublist to a compact JSON string, null sublists stay null.
+    ///
+    /// Binary values are written as base64 strings and temporal values as ISO 8601 strings.
+    pub fn json_encode(self) -> Expr {
+        self.json_encode_with_options(Default::default())
+    }
+
+    #[cfg(feature = "json")]
+    /// Serialize every sublist to a compact JSON string, temporal values are written as set in
+    /// `options`.
+    pub fn json_encode_with_options(self, options: JsonEncodeOptions) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::JsonEncode(options)))
+    }
+
     #[cfg(feature = "list_to_struct")]
     #[allow(clippy::wrong_self_convention)]
     /// Convert this `List` to a `Series` of type `Struct`. The width will be determined according to
@@ -0,0 +1,64 @@
+use polars_core::utils::any_values_to_supertype;
+
+use super::*;
+
+/// The number of non-null outputs the output data type of [`Expr::map_elements`] is inferred from.
+const INFER_LENGTH: usize = 100;
+
+impl Expr {
+    /// Apply a closure to every non-null element. Null elements stay null.
+    ///
+    /// If `return_dtype` is `None`, the output data type can't be known before the closure runs,
+    /// so it is unknown in the schema. It is inferred at execution from the supertype of the first
+    /// 100 non-null outputs, and an error is raised if a later output doesn't fit that data type.
+    /// As the inference runs per batch of data, different groups or chunks can end up with
+    /// different data types; pass `return_dtype` if that matters.
+    pub fn map_elements<F>(self, function: F, return_dtype: Option<DataType>) -> Self
+    where
+        F: Fn(AnyValue) -> PolarsResult<AnyValue> + 'static + Send + Sync,
+    {
+        let output_type = GetOutput::from_type(
+            return_dtype
+                .clone()
+                .unwrap_or_else(|| DataType::Unknown(Default::default())),
+        );
+        self.map(
+            move |s| map_elements_impl(&s, &function, return_dtype.as_ref()).map(Some),
+            output_type,
+        )
+        .with_fmt("map_elements")
+    }
+}
+
+fn map_elements_impl<F>(
+    s: &Series,
+    function: &F,
+    return_dtype: Option<&DataType>,
+) -> PolarsResult<Series>
+where
+    F: Fn(AnyValue) -> PolarsResult<AnyValue>,
+{
+    let s = s.rechunk();
+    let values = s
+        .iter()
+        .map(|av| {
+            if av.is_null() {
+                Ok(AnyValue::Null)
+            } else {
+                function(av)?.into_static()
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let dtype = match return_dtype {
+        Some(dtype) => dtype.clone(),
+        None => {
+            any_values_to_supertype(values.iter().filter(|av| !av.is_null()).take(INFER_LENGTH))?
+        },
+    };
+    Series::from_any_values_and_dtype(s.name(), &values, &dtype, true).map_err(|err| {
+        polars_err!(
+            ComputeError: "the outputs of `map_elements` don't fit the data type {}: {}", dtype, err
+        )
+    })
+}
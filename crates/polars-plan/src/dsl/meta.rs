@@ -28,6 +28,26 @@ impl MetaNameSpace {
         expr_to_leaf_column_names(&self.0)
     }
 
+    /// Get the distinct column names this expression reads, in order of first appearance.
+    ///
+    /// Unlike [`root_names`](Self::root_names) every name is returned once, which makes
+    /// this suitable to determine the minimal input schema of an expression.
+    pub fn free_columns(&self) -> Vec<Arc<str>> {
+        let mut seen = PlHashSet::new();
+        expr_to_leaf_column_names_iter(&self.0)
+            .filter(|name| seen.insert(name.clone()))
+            .collect()
+    }
+
+    /// Get a rough estimate of the cost of evaluating this expression.
+    ///
+    /// This is a heuristic: it sums a fixed weight per node of the expression tree and doesn't
+    /// look at the data. Column projections and literals are cheap, sorts, aggregations, window
+    /// functions and regex projections are expensive. Only compare estimates with each other.
+    pub fn estimated_cost(&self) -> u64 {
+        self.0.into_iter().map(node_cost).sum()
+    }
+
     /// A projection that only takes a column or a column + alias.
     pub fn is_simple_projection(&self) -> bool {
         let mut arena = Arena::with_capacity(8);
@@ -134,3 +154,49 @@ impl MetaNameSpace {
         Ok(visitor)
     }
 }
+
+mod cost {
+    pub(super) const PROJECTION: u64 = 1;
+    pub(super) const ELEMENT_WISE: u64 = 2;
+    pub(super) const SELECTION: u64 = 4;
+    pub(super) const GROUP_WISE: u64 = 8;
+    pub(super) const REGEX: u64 = 8;
+    pub(super) const UDF: u64 = 16;
+    pub(super) const SORT: u64 = 16;
+    pub(super) const WINDOW: u64 = 16;
+    pub(super) const SUB_PLAN: u64 = 64;
+}
+
+/// The weight of a single node of an expression, without its inputs.
+fn node_cost(e: &Expr) -> u64 {
+    let function_cost = |options: &FunctionOptions| match options.collect_groups {
+        ApplyOptions::ElementWise => cost::ELEMENT_WISE,
+        ApplyOptions::GroupWise | ApplyOptions::ApplyList => cost::GROUP_WISE,
+    };
+
+    match e {
+        Expr::Column(name) if is_regex_projection(name) => cost::REGEX,
+        Expr::Alias(_, _)
+        | Expr::Column(_)
+        | Expr::Columns(_)
+        | Expr::DtypeColumn(_)
+        | Expr::Literal(_)
+        | Expr::Wildcard
+        | Expr::Exclude(_, _)
+        | Expr::KeepName(_)
+        | Expr::Len
+        | Expr::Nth(_)
+        | Expr::RenameAlias { .. }
+        | Expr::Selector(_) => cost::PROJECTION,
+        Expr::BinaryExpr { .. } | Expr::Cast { .. } | Expr::Ternary { .. } => cost::ELEMENT_WISE,
+        Expr::Gather { .. } | Expr::Filter { .. } | Expr::Slice { .. } | Expr::Explode(_) => {
+            cost::SELECTION
+        },
+        Expr::Agg(_) => cost::GROUP_WISE,
+        Expr::Function { options, .. } => function_cost(options),
+        Expr::AnonymousFunction { options, .. } => cost::UDF + function_cost(options),
+        Expr::Sort { .. } | Expr::SortBy { .. } => cost::SORT,
+        Expr::Window { .. } => cost::WINDOW,
+        Expr::SubPlan(_, _) => cost::SUB_PLAN,
+    }
+}
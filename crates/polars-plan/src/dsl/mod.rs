@@ -23,6 +23,8 @@ mod from;
 pub(crate) mod function_expr;
 pub mod functions;
 mod list;
+#[cfg(feature = "map_elements")]
+mod map_elements;
 #[cfg(feature = "meta")]
 mod meta;
 mod name;
@@ -187,6 +189,8 @@ impl Expr {
     }
 
     /// Drop NaN values.
+    ///
+    /// This is a no-op for columns that are not of a float dtype.
     pub fn drop_nans(self) -> Self {
         self.apply_private(FunctionExpr::DropNans)
     }
@@ -211,6 +215,21 @@ impl Expr {
         AggExpr::Implode(Arc::new(self)).into()
     }
 
+    /// Aggregate every group into an array of `width` values, the inverse of exploding an
+    /// `Array` column. Raises an error if a group doesn't have exactly `width` values.
+    #[cfg(feature = "dtype-array")]
+    pub fn implode_to_array(self, width: usize) -> Self {
+        Expr::Function {
+            input: vec![self],
+            function: FunctionExpr::ImplodeToArray(width),
+            options: FunctionOptions {
+                collect_groups: ApplyOptions::GroupWise,
+                returns_scalar: true,
+                ..Default::default()
+            },
+        }
+    }
+
     /// Compute the quantile per group.
     pub fn quantile(self, quantile: Expr, interpol: QuantileInterpolOptions) -> Self {
         AggExpr::Quantile {
@@ -222,6 +241,9 @@ impl Expr {
     }
 
     /// Get the group indexes of the group by operation.
+    ///
+    /// The offsets and values of the resulting list are the flat `(offsets, indices)` buffers
+    /// of `GroupsProxy::to_offsets_and_indices`, so they can be handed over FFI as is.
     pub fn agg_groups(self) -> Self {
         AggExpr::AggGroups(Arc::new(self)).into()
     }
@@ -297,6 +319,22 @@ impl Expr {
         self.apply_private(FunctionExpr::ArgUnique)
     }
 
+    /// Get the indices where this boolean expression is `true`.
+    ///
+    /// On the streaming engine a `select` of only this expression runs in a sink.
+    #[cfg(feature = "arg_where")]
+    pub fn arg_true(self) -> Self {
+        arg_where(self)
+    }
+
+    /// Get the indices where this boolean expression is `false`.
+    ///
+    /// Null values are neither `true` nor `false` and are never part of the output.
+    #[cfg(feature = "arg_where")]
+    pub fn arg_false(self) -> Self {
+        arg_where(self.not())
+    }
+
     /// Get the index value that has the minimum value.
     pub fn arg_min(self) -> Self {
         let options = FunctionOptions {
@@ -460,6 +498,33 @@ impl Expr {
         self.apply_many_private(FunctionExpr::TopK(true), &[k], false, false)
     }
 
+    /// Returns the elements of the `k` rows with the largest values in `by`.
+    ///
+    /// With `maintain_order` set in the `sort_options`, the earlier rows win when rows tie at the
+    /// `k`-th position. If `return_indices` is set, the indices of the rows are returned instead
+    /// of the elements; in a group-by context they are relative to the group. If `k` exceeds the
+    /// number of rows, all rows are returned.
+    #[cfg(feature = "top_k")]
+    pub fn top_k_by<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        k: Expr,
+        by: E,
+        sort_options: SortMultipleOptions,
+        return_indices: bool,
+    ) -> Self {
+        let mut args = vec![k];
+        args.extend(by.as_ref().iter().map(|e| e.clone().into()));
+        self.apply_many_private(
+            FunctionExpr::TopKBy {
+                sort_options,
+                return_indices,
+            },
+            &args,
+            false,
+            false,
+        )
+    }
+
     /// Reverse column
     pub fn reverse(self) -> Self {
         self.apply_private(FunctionExpr::Reverse)
@@ -777,6 +842,32 @@ impl Expr {
         self.apply_private(FunctionExpr::ForwardFill { limit })
     }
 
+    /// Fill missing value with next non-null, filling at most `limit` consecutive values.
+    ///
+    /// In a group-by or window context the limit is evaluated per group, so every group can
+    /// have its own limit. The limit must evaluate to a single non-negative integer, a null
+    /// limit doesn't limit the fill.
+    pub fn backward_fill_with_limit<E: Into<Expr>>(self, limit: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::BackwardFillWithLimit,
+            &[limit.into()],
+            false,
+            false,
+        )
+    }
+
+    /// Fill missing value with previous non-null, filling at most `limit` consecutive values.
+    ///
+    /// See [`Expr::backward_fill_with_limit`] for how the limit is evaluated.
+    pub fn forward_fill_with_limit<E: Into<Expr>>(self, limit: E) -> Self {
+        self.apply_many_private(
+            FunctionExpr::ForwardFillWithLimit,
+            &[limit.into()],
+            false,
+            false,
+        )
+    }
+
     /// Round underlying floating point array to given decimal numbers.
     #[cfg(feature = "round_series")]
     pub fn round(self, decimals: u32) -> Self {
@@ -789,6 +880,13 @@ impl Expr {
         self.map_private(FunctionExpr::RoundSF { digits })
     }
 
+    /// Round to the nearest multiple of `step`, e.g. to the nearest `0.25` or `5`.
+    /// Ties are broken according to `mode`. Integers are rounded as `Float64`.
+    #[cfg(feature = "round_series")]
+    pub fn round_to_multiple(self, step: f64, mode: RoundMode) -> Self {
+        self.map_private(FunctionExpr::RoundToMultiple { step, mode })
+    }
+
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
     #[cfg(feature = "round_series")]
     pub fn floor(self) -> Self {
@@ -1009,7 +1107,18 @@ impl Expr {
     /// Get the approximate count of unique values.
     #[cfg(feature = "approx_unique")]
     pub fn approx_n_unique(self) -> Self {
-        self.apply_private(FunctionExpr::ApproxNUnique)
+        self.approx_n_unique_with_precision(polars_ops::prelude::HLL_DEFAULT_PRECISION)
+    }
+
+    /// Get the approximate count of unique values with a HyperLogLog sketch of
+    /// `2^precision` registers.
+    ///
+    /// The precision must be between 4 and 18. Higher precisions use more memory but are
+    /// more accurate; the relative standard error is about `1.04 / sqrt(2^precision)`, i.e.
+    /// 0.81% for the default precision of 14.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique_with_precision(self, precision: u8) -> Self {
+        self.apply_private(FunctionExpr::ApproxNUnique { precision })
             .with_function_options(|mut options| {
                 options.returns_scalar = true;
                 options
@@ -1135,6 +1244,13 @@ impl Expr {
         self.repeat_by_impl(by.into())
     }
 
+    #[cfg(all(feature = "repeat_by", feature = "dtype-array"))]
+    /// Repeat every value of the column `n` times.
+    /// This yields an `Expr` of dtype `Array` with width `n`.
+    pub fn repeat_fixed(self, n: usize) -> Expr {
+        self.map_private(FunctionExpr::RepeatFixed(n))
+    }
+
     #[cfg(feature = "is_first_distinct")]
     #[allow(clippy::wrong_self_convention)]
     /// Get a mask of the first unique value.
@@ -1397,18 +1513,23 @@ impl Expr {
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories.
+    ///
+    /// With `index_only` the zero-based index of the bin is returned as `UInt32` instead of a
+    /// categorical label. Values below the first break are in bin 0.
     pub fn cut(
         self,
         breaks: Vec<f64>,
         labels: Option<Vec<String>>,
         left_closed: bool,
         include_breaks: bool,
+        index_only: bool,
     ) -> Expr {
         self.apply_private(FunctionExpr::Cut {
             breaks,
             labels,
             left_closed,
             include_breaks,
+            index_only,
         })
         .with_function_options(|mut opt| {
             opt.pass_name_to_apply = true;
@@ -1418,6 +1539,8 @@ impl Expr {
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories based on their quantiles.
+    ///
+    /// See [`Expr::cut`] for `index_only`.
     pub fn qcut(
         self,
         probs: Vec<f64>,
@@ -1425,6 +1548,7 @@ impl Expr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        index_only: bool,
     ) -> Expr {
         self.apply_private(FunctionExpr::QCut {
             probs,
@@ -1432,6 +1556,7 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            index_only,
         })
         .with_function_options(|mut opt| {
             opt.pass_name_to_apply = true;
@@ -1441,6 +1566,8 @@ impl Expr {
 
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories using uniform quantile probabilities.
+    ///
+    /// See [`Expr::cut`] for `index_only`.
     pub fn qcut_uniform(
         self,
         n_bins: usize,
@@ -1448,6 +1575,7 @@ impl Expr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        index_only: bool,
     ) -> Expr {
         let probs = (1..n_bins).map(|b| b as f64 / n_bins as f64).collect();
         self.apply_private(FunctionExpr::QCut {
@@ -1456,6 +1584,7 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            index_only,
         })
         .with_function_options(|mut opt| {
             opt.pass_name_to_apply = true;
@@ -1481,6 +1610,16 @@ impl Expr {
         self.apply_private(FunctionExpr::Diff(n, null_behavior))
     }
 
+    #[cfg(feature = "diff")]
+    /// Divide the difference between consecutive values by the difference between the
+    /// consecutive values of `by`, the rate of change of an irregularly sampled series.
+    ///
+    /// The output is `Float64` and its first value is null. A zero difference in `by` gives
+    /// an infinite value, or NaN if the values didn't change either.
+    pub fn diff_by(self, by: Expr) -> Expr {
+        self.apply_many_private(FunctionExpr::DiffBy, &[by], false, false)
+    }
+
     #[cfg(feature = "pct_change")]
     /// Computes percentage change between values.
     pub fn pct_change(self, n: Expr) -> Expr {
@@ -1642,6 +1781,14 @@ impl Expr {
         self.map_private(FunctionExpr::Exp)
     }
 
+    #[cfg(feature = "log")]
+    /// Calculate the exponential of all elements minus one in the input array.
+    ///
+    /// Unlike `exp() - 1`, this keeps its precision for elements close to zero.
+    pub fn expm1(self) -> Self {
+        self.map_private(FunctionExpr::Expm1)
+    }
+
     #[cfg(feature = "log")]
     /// Compute the entropy as `-sum(pk * log(pk)`.
     /// where `pk` are discrete probabilities.
@@ -1677,10 +1824,22 @@ impl Expr {
         self.map_private(FunctionExpr::Hash(k0, k1, k2, k3))
     }
 
+    #[cfg(feature = "row_hash")]
+    /// Compute the hash of every element as a 16 character lowercase hex string.
+    ///
+    /// The string encodes the same value as [`Expr::hash`] with the same seeds.
+    pub fn hash_hex(self, k0: u64, k1: u64, k2: u64, k3: u64) -> Expr {
+        self.map_private(FunctionExpr::HashHex(k0, k1, k2, k3))
+    }
+
     pub fn to_physical(self) -> Expr {
         self.map_private(FunctionExpr::ToPhysical)
     }
 
+    /// Take every `n`-th value, starting at `offset`.
+    ///
+    /// In a group-by context the rows are counted per group, so every group starts at its own
+    /// `offset`.
     pub fn gather_every(self, n: usize, offset: usize) -> Expr {
         self.apply_private(FunctionExpr::GatherEvery { n, offset })
     }
@@ -1691,7 +1850,22 @@ impl Expr {
     }
 
     pub fn extend_constant(self, value: Expr, n: Expr) -> Expr {
-        self.apply_many_private(FunctionExpr::ExtendConstant, &[value, n], false, false)
+        self.apply_many_private(
+            FunctionExpr::ExtendConstant { prepend: false },
+            &[value, n],
+            false,
+            false,
+        )
+    }
+
+    /// Put `n` copies of `value` in front of the Series.
+    pub fn prepend_constant(self, value: Expr, n: Expr) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::ExtendConstant { prepend: true },
+            &[value, n],
+            false,
+            false,
+        )
     }
 
     #[cfg(feature = "strings")]
@@ -9,8 +9,12 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RollingCovOptions {
     pub window_size: IdxSize,
+    /// The minimum number of rows in the window in which both inputs are valid.
     pub min_periods: IdxSize,
     pub ddof: u8,
+    /// Rank the values within each window before computing the correlation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spearman: bool,
 }
 
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
@@ -97,3 +101,22 @@ pub enum WindowMapping {
     /// warning: this can be memory intensive
     Join,
 }
+
+/// How temporal values are written by `json_encode`.
+#[cfg(feature = "json")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonTemporalEncoding {
+    /// ISO 8601 strings, e.g. `"2024-01-31T12:30:00"` or `"P1DT2H"`.
+    #[default]
+    Iso,
+    /// The physical integer, e.g. the number of days since the epoch for dates.
+    Epoch,
+}
+
+#[cfg(feature = "json")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonEncodeOptions {
+    pub temporal: JsonTemporalEncoding,
+}
@@ -8,12 +8,36 @@ impl Expr {
         })
     }
 
+    /// Sample `n` values. In the group_by context `n` can be an aggregation, so that every
+    /// group samples its own number of values. A float `n` must be integer-valued.
     pub fn sample_n(
         self,
         n: Expr,
         with_replacement: bool,
         shuffle: bool,
         seed: Option<u64>,
+    ) -> Self {
+        self.sample_n_impl(n, with_replacement, shuffle, false, seed)
+    }
+
+    /// Sample `n` values, or all values if the input is shorter than `n`.
+    pub fn sample_n_clamped(
+        self,
+        n: Expr,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        self.sample_n_impl(n, with_replacement, shuffle, true, seed)
+    }
+
+    fn sample_n_impl(
+        self,
+        n: Expr,
+        with_replacement: bool,
+        shuffle: bool,
+        clamp_to_len: bool,
+        seed: Option<u64>,
     ) -> Self {
         self.apply_many_private(
             FunctionExpr::Random {
@@ -21,6 +45,7 @@ impl Expr {
                     is_fraction: false,
                     with_replacement,
                     shuffle,
+                    clamp_to_len,
                 },
                 seed,
             },
@@ -43,6 +68,7 @@ impl Expr {
                     is_fraction: true,
                     with_replacement,
                     shuffle,
+                    clamp_to_len: false,
                 },
                 seed,
             },
@@ -250,6 +250,27 @@ impl StringNameSpace {
         )
     }
 
+    /// Convert a String column into a Date/Datetime/Time column, trying each of `formats`
+    /// in order on every value.
+    ///
+    /// The format of `options` is ignored. With `strict`, values that match none of the
+    /// formats raise an error; otherwise they become null.
+    #[cfg(feature = "temporal")]
+    pub fn strptime_formats(
+        self,
+        dtype: DataType,
+        formats: Vec<String>,
+        options: StrptimeOptions,
+        ambiguous: Expr,
+    ) -> Expr {
+        self.0.map_many_private(
+            StringFunction::StrptimeFormats(dtype, formats, options).into(),
+            &[ambiguous],
+            false,
+            false,
+        )
+    }
+
     /// Convert a String column into a Date column.
     #[cfg(feature = "dtype-date")]
     pub fn to_date(self, options: StrptimeOptions) -> Expr {
@@ -368,6 +389,18 @@ impl StringNameSpace {
             .map_many_private(StringFunction::SplitN(n).into(), &[by], false, false)
     }
 
+    /// Split by a given substring into at most `n` parts. If there are more possible splits,
+    /// the last part keeps the remainder of the string intact. The resulting dtype is
+    /// `List<String>`.
+    pub fn splitn_list(self, by: Expr, n: usize, inclusive: bool) -> Expr {
+        self.0.map_many_private(
+            StringFunction::SplitNList { n, inclusive }.into(),
+            &[by],
+            false,
+            false,
+        )
+    }
+
     #[cfg(feature = "regex")]
     /// Replace values that match a regex `pat` with a `value`.
     pub fn replace(self, pat: Expr, value: Expr, literal: bool) -> Expr {
@@ -35,9 +35,63 @@ impl StructNameSpace {
             )))
     }
 
+    /// Serialize every struct to a compact JSON object, null structs stay null.
+    ///
+    /// Binary values are written as base64 strings and temporal values as ISO 8601 strings.
     #[cfg(feature = "json")]
     pub fn json_encode(self) -> Expr {
+        self.json_encode_with_options(Default::default())
+    }
+
+    /// Serialize every struct to a compact JSON object, temporal values are written as set in
+    /// `options`.
+    #[cfg(feature = "json")]
+    pub fn json_encode_with_options(self, options: JsonEncodeOptions) -> Expr {
         self.0
-            .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode))
+            .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode(
+                options,
+            )))
+    }
+
+    /// Add or replace fields of the [`StructChunked`].
+    ///
+    /// Every expression becomes a field named after its output name, replacing the existing field
+    /// with that name or being appended otherwise. Untouched fields are reused as is and rows
+    /// where the struct is null stay null. For example, to fill the nulls of a single field:
+    ///
+    /// ```ignore
+    /// col("s").struct_().with_fields(vec![
+    ///     col("s").struct_().field_by_name("x").fill_null(lit(0)),
+    /// ])
+    /// ```
+    pub fn with_fields(self, fields: Vec<Expr>) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::StructExpr(StructFunction::WithFields),
+            &fields,
+            false,
+            false,
+        )
+    }
+
+    /// Fill the null values of every field with `strategy`.
+    ///
+    /// Fields of a dtype the strategy doesn't apply to are left untouched and nested structs are
+    /// filled recursively. Rows where the struct itself is null stay null, unless
+    /// `fill_null_rows` is set.
+    pub fn fill_null(self, strategy: FillNullStrategy, fill_null_rows: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::FillNull {
+                strategy,
+                fill_null_rows,
+            }))
+    }
+
+    /// Drop the rows with null values.
+    ///
+    /// A struct row is null when all of its fields are null. With `any` every row that has at
+    /// least one null field is dropped, otherwise only the null rows themselves are dropped. Null
+    /// rows are therefore dropped in both cases.
+    pub fn drop_nulls(self, any: bool) -> Expr {
+        self.0.apply_private(FunctionExpr::DropNullsStruct { any })
     }
 }
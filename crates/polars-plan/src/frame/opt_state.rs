@@ -29,6 +29,9 @@ pub struct OptState {
     pub fast_projection: bool,
     /// Try to estimate the number of rows so that joins can determine which side to keep in memory.
     pub row_estimate: bool,
+    /// Memory budget in bytes of the streaming engine. Takes precedence over the
+    /// `POLARS_STREAMING_MEMORY_LIMIT` env var.
+    pub streaming_memory_limit: Option<usize>,
 }
 
 impl Default for OptState {
@@ -49,6 +52,7 @@ impl Default for OptState {
             fast_projection: true,
             eager: false,
             row_estimate: true,
+            streaming_memory_limit: None,
         }
     }
 }
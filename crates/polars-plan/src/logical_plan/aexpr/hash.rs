@@ -1,9 +1,10 @@
 use std::hash::{Hash, Hasher};
 
+use polars_core::prelude::*;
 use polars_utils::arena::{Arena, Node};
 
 use crate::logical_plan::ArenaExprIter;
-use crate::prelude::AExpr;
+use crate::prelude::{AAggExpr, AExpr, LiteralValue};
 
 impl Hash for AExpr {
     // This hashes the variant, not the whole expression
@@ -43,3 +44,141 @@ pub(crate) fn traverse_and_hash_aexpr<H: Hasher>(
         ae.hash(state);
     }
 }
+
+/// Like [`traverse_and_hash_aexpr`], but only hashes data that is stable across processes,
+/// compilers and feature sets, for a plan fingerprint. It also hashes what is needed to tell
+/// plans apart, such as the target data types of casts and the values of literals.
+pub(crate) fn traverse_and_hash_aexpr_stable<H: Hasher>(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    state: &mut H,
+) {
+    for (_, ae) in expr_arena.iter(node) {
+        ae.hash_stable(state);
+    }
+}
+
+impl AExpr {
+    /// Hash the variant by its name instead of its discriminant, as the latter depends on
+    /// the enabled features. The same holds for the function, which is why it is hashed by
+    /// its `Debug` representation, which includes its arguments.
+    fn hash_stable<H: Hasher>(&self, state: &mut H) {
+        <&'static str>::from(self).hash(state);
+
+        match self {
+            AExpr::Column(name) => name.hash(state),
+            AExpr::Alias(_, name) => name.hash(state),
+            AExpr::Nth(v) => v.hash(state),
+            AExpr::Literal(lv) => hash_literal_stable(lv, state),
+            AExpr::Function {
+                options, function, ..
+            } => {
+                options.hash(state);
+                format!("{function:?}").hash(state)
+            },
+            AExpr::AnonymousFunction { options, .. } => {
+                options.hash(state);
+            },
+            AExpr::Agg(agg) => {
+                <&'static str>::from(agg).hash(state);
+                match agg {
+                    AAggExpr::Min { propagate_nans, .. } | AAggExpr::Max { propagate_nans, .. } => {
+                        propagate_nans.hash(state)
+                    },
+                    AAggExpr::Quantile { interpol, .. } => interpol.hash(state),
+                    AAggExpr::Count(_, include_nulls) => include_nulls.hash(state),
+                    AAggExpr::Std(_, ddof) | AAggExpr::Var(_, ddof) => ddof.hash(state),
+                    _ => {},
+                }
+            },
+            AExpr::Sort { options, .. } => options.hash(state),
+            AExpr::SortBy { sort_options, .. } => sort_options.hash(state),
+            AExpr::Gather { returns_scalar, .. } => returns_scalar.hash(state),
+            AExpr::Cast {
+                data_type, strict, ..
+            } => {
+                data_type.hash_stable(state);
+                strict.hash(state)
+            },
+            AExpr::Window { options, .. } => options.hash(state),
+            AExpr::BinaryExpr { op, .. } => op.hash(state),
+            _ => {},
+        }
+    }
+}
+
+fn hash_literal_stable<H: Hasher>(lv: &LiteralValue, state: &mut H) {
+    lv.get_datatype().hash_stable(state);
+    match lv {
+        LiteralValue::Series(s) => hash_values_stable(s, state),
+        LiteralValue::Range { low, high, .. } => {
+            low.hash(state);
+            high.hash(state);
+        },
+        _ => {
+            if let Some(av) = lv.to_any_value() {
+                hash_any_value_stable(&av, state)
+            }
+        },
+    }
+}
+
+fn hash_values_stable<H: Hasher>(s: &Series, state: &mut H) {
+    s.len().hash(state);
+    s.iter().for_each(|av| hash_any_value_stable(&av, state))
+}
+
+/// Hash the physical value. Unlike [`AnyValue::hash_impl`] this doesn't hash the
+/// discriminant; the data type is hashed separately by the caller.
+fn hash_any_value_stable<H: Hasher>(av: &AnyValue, state: &mut H) {
+    use AnyValue::*;
+    if av.is_null() {
+        state.write_u8(0);
+        return;
+    }
+    state.write_u8(1);
+    match av {
+        Boolean(v) => v.hash(state),
+        Int8(v) => v.hash(state),
+        Int16(v) => v.hash(state),
+        Int32(v) => v.hash(state),
+        Int64(v) => v.hash(state),
+        UInt8(v) => v.hash(state),
+        UInt16(v) => v.hash(state),
+        UInt32(v) => v.hash(state),
+        UInt64(v) => v.hash(state),
+        Float32(v) => v.to_bits().hash(state),
+        Float64(v) => v.to_bits().hash(state),
+        String(v) => v.hash(state),
+        StringOwned(v) => v.as_str().hash(state),
+        Binary(v) => v.hash(state),
+        BinaryOwned(v) => v.as_slice().hash(state),
+        #[cfg(feature = "dtype-date")]
+        Date(v) => v.hash(state),
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(v, _, _) => v.hash(state),
+        #[cfg(feature = "dtype-duration")]
+        Duration(v, _) => v.hash(state),
+        #[cfg(feature = "dtype-time")]
+        Time(v) => v.hash(state),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(v, _) => v.hash(state),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(..) | Enum(..) => av.get_str().hash(state),
+        List(s) => hash_values_stable(s, state),
+        #[cfg(feature = "dtype-array")]
+        Array(s, _) => hash_values_stable(s, state),
+        #[cfg(feature = "dtype-struct")]
+        Struct(..) | StructOwned(_) => {
+            if let Ok(StructOwned(payload)) = av.clone().into_static() {
+                payload
+                    .0
+                    .iter()
+                    .for_each(|av| hash_any_value_stable(av, state))
+            }
+        },
+        // Objects have no stable representation.
+        #[allow(unreachable_patterns)]
+        _ => {},
+    }
+}
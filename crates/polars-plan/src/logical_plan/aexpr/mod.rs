@@ -6,7 +6,7 @@ mod utils;
 use std::hash::{Hash, Hasher};
 
 #[cfg(feature = "cse")]
-pub(super) use hash::traverse_and_hash_aexpr;
+pub(super) use hash::{traverse_and_hash_aexpr, traverse_and_hash_aexpr_stable};
 use polars_core::prelude::*;
 use polars_core::utils::{get_time_units, try_get_supertype};
 use polars_utils::arena::{Arena, Node};
@@ -122,7 +122,7 @@ impl From<AAggExpr> for GroupByMethod {
 }
 
 // AExpr representation of Nodes which are allocated in an Arena
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, IntoStaticStr)]
 pub enum AExpr {
     Explode(Node),
     Alias(Node, ColumnName),
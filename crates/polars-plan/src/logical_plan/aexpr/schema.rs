@@ -1,6 +1,7 @@
 use recursive::recursive;
 
 use super::*;
+use crate::constants::ELEMENT_INDEX_NAME;
 
 fn float_type(field: &mut Field) {
     if (field.dtype.is_numeric() || field.dtype == DataType::Boolean)
@@ -41,9 +42,15 @@ impl AExpr {
                 arena.get(*expr).get_type(schema, ctxt, arena)?,
             )),
             Column(name) => {
-                let field = schema
-                    .get_field(name)
-                    .ok_or_else(|| PolarsError::ColumnNotFound(name.to_string().into()));
+                let field = schema.get_field(name).ok_or_else(|| {
+                    if name.as_ref() == ELEMENT_INDEX_NAME {
+                        polars_err!(
+                            InvalidOperation: "`element_index` can only be used inside `list.eval`"
+                        )
+                    } else {
+                        PolarsError::ColumnNotFound(name.to_string().into())
+                    }
+                });
 
                 match ctxt {
                     Context::Default => field,
@@ -38,6 +38,7 @@ impl IR {
                 SinkType::File { .. } => "sink (file)",
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud { .. } => "sink (cloud)",
+                SinkType::Split { .. } => "sink (split)",
             },
             SimpleProjection { .. } => "simple_projection",
             Invalid => "invalid",
@@ -6,6 +6,9 @@ use polars_core::prelude::*;
 pub use super::options::AnonymousScanOptions;
 use crate::dsl::Expr;
 
+/// The batches produced by [`AnonymousScan::scan_batched`].
+pub type AnonymousScanBatches = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send + Sync>;
+
 pub struct AnonymousScanArgs {
     pub n_rows: Option<usize>,
     pub with_columns: Option<Arc<Vec<String>>>,
@@ -42,6 +45,19 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// specify if the scan provider can produce its data in batches with
+    /// [`scan_batched`](Self::scan_batched), which allows it to be a source of the
+    /// streaming engine
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
+    /// Creates an iterator over the data in batches, every batch becomes a morsel of the
+    /// streaming engine. The batches must respect `n_rows` and `with_columns`.
+    fn scan_batched(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<AnonymousScanBatches> {
+        polars_bail!(ComputeError: "this anonymous scan can't produce batches");
+    }
 }
 
 impl<F> AnonymousScan for F
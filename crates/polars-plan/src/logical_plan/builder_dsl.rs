@@ -66,6 +66,8 @@ impl DslBuilder {
                 enabled: false,
                 ..Default::default()
             },
+            on_error: Default::default(),
+            per_file_limit: None,
         };
 
         Ok(DslPlan::Scan {
@@ -96,7 +98,10 @@ impl DslBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        uuid_as_string: bool,
         hive_options: HiveOptions,
+        on_error: ScanErrorPolicy,
+        per_file_limit: Option<usize>,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
 
@@ -108,6 +113,8 @@ impl DslBuilder {
             row_index,
             file_counter: Default::default(),
             hive_options,
+            on_error,
+            per_file_limit,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -119,6 +126,7 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    uuid_as_string,
                 },
                 cloud_options,
                 metadata: None,
@@ -154,6 +162,8 @@ impl DslBuilder {
                     enabled: false,
                     ..Default::default()
                 },
+                on_error: Default::default(),
+                per_file_limit: None,
             },
             predicate: None,
             scan_type: FileScan::Ipc {
@@ -181,6 +191,7 @@ impl DslBuilder {
         comment_prefix: Option<CommentPrefix>,
         quote_char: Option<u8>,
         eol_char: u8,
+        record_separator: Option<Vec<u8>>,
         null_values: Option<NullValues>,
         infer_schema_length: Option<usize>,
         rechunk: bool,
@@ -209,6 +220,8 @@ impl DslBuilder {
                 enabled: false,
                 ..Default::default()
             },
+            on_error: Default::default(),
+            per_file_limit: None,
         };
         Ok(DslPlan::Scan {
             paths,
@@ -225,6 +238,7 @@ impl DslBuilder {
                     comment_prefix,
                     quote_char,
                     eol_char,
+                    record_separator,
                     null_values,
                     encoding,
                     try_parse_dates,
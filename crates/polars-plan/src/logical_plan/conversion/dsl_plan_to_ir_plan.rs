@@ -1,3 +1,5 @@
+use smartstring::alias::String as SmartString;
+
 use super::stack_opt::ConversionOpt;
 use super::*;
 use crate::logical_plan::expr_expansion::{is_regex_projection, rewrite_projections};
@@ -11,7 +13,7 @@ fn expand_expressions(
 ) -> PolarsResult<Vec<ExprIR>> {
     let schema = lp_arena.get(input).schema(lp_arena);
     let exprs = rewrite_projections(exprs, &schema, &[])?;
-    Ok(to_expr_irs(exprs, expr_arena))
+    to_expr_irs(exprs, expr_arena)
 }
 
 fn empty_df() -> IR {
@@ -93,13 +95,17 @@ pub fn to_alp_impl(
                 match &mut scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet {
+                        options,
                         cloud_options,
                         metadata,
-                        ..
                     } => {
-                        let (file_info, md) =
-                            scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+                        let (file_info, md) = scans::parquet_file_info(
+                            &paths,
+                            &file_options,
+                            options,
+                            cloud_options.as_ref(),
+                        )
+                        .map_err(|e| e.context(failed_here!(parquet scan)))?;
                         *metadata = md;
                         file_info
                     },
@@ -136,7 +142,9 @@ pub fn to_alp_impl(
                 file_info,
                 paths,
                 output_schema: None,
-                predicate: predicate.map(|expr| to_expr_ir(expr, expr_arena)),
+                predicate: predicate
+                    .map(|expr| try_to_expr_ir(expr, expr_arena))
+                    .transpose()?,
                 scan_type,
                 file_options,
             }
@@ -175,9 +183,9 @@ pub fn to_alp_impl(
                 .map_err(|e| e.context(failed_input!(filter)))?;
             let predicate = expand_filter(predicate, input, lp_arena)
                 .map_err(|e| e.context(failed_here!(filter)))?;
-            let predicate = to_expr_ir(predicate, expr_arena);
+            let predicate = try_to_expr_ir(predicate, expr_arena)?;
 
-            convert.push_scratch(predicate.node(), expr_arena);
+            convert.push_scratch(&predicate);
 
             let lp = IR::Filter { input, predicate };
             return run_conversion(lp, lp_arena, expr_arena, convert, "filter");
@@ -198,7 +206,9 @@ pub fn to_alp_impl(
             schema,
             output_schema,
             projection,
-            selection: selection.map(|expr| to_expr_ir(expr, expr_arena)),
+            selection: selection
+                .map(|expr| try_to_expr_ir(expr, expr_arena))
+                .transpose()?,
         },
         DslPlan::Select {
             expr,
@@ -216,8 +226,8 @@ pub fn to_alp_impl(
             }
 
             let schema = Arc::new(schema);
-            let eirs = to_expr_irs(exprs, expr_arena);
-            convert.fill_scratch(&eirs, expr_arena);
+            let eirs = to_expr_irs(exprs, expr_arena)?;
+            convert.fill_scratch(&eirs);
             let expr = eirs.into();
 
             let lp = IR::Select {
@@ -240,7 +250,7 @@ pub fn to_alp_impl(
             let by_column = expand_expressions(input, by_column, lp_arena, expr_arena)
                 .map_err(|e| e.context(failed_here!(sort)))?;
 
-            convert.fill_scratch(&by_column, expr_arena);
+            convert.fill_scratch(&by_column);
             let lp = IR::Sort {
                 input,
                 by_column,
@@ -284,8 +294,8 @@ pub fn to_alp_impl(
                 (None, schema)
             };
 
-            convert.fill_scratch(&keys, expr_arena);
-            convert.fill_scratch(&aggs, expr_arena);
+            convert.fill_scratch(&keys);
+            convert.fill_scratch(&aggs);
 
             let lp = IR::GroupBy {
                 input,
@@ -327,11 +337,11 @@ pub fn to_alp_impl(
                 det_join_schema(&schema_left, &schema_right, &left_on, &right_on, &options)
                     .map_err(|e| e.context(failed_here!(join schema resolving)))?;
 
-            let left_on = to_expr_irs_ignore_alias(left_on, expr_arena);
-            let right_on = to_expr_irs_ignore_alias(right_on, expr_arena);
+            let left_on = to_expr_irs_ignore_alias(left_on, expr_arena)?;
+            let right_on = to_expr_irs_ignore_alias(right_on, expr_arena)?;
 
-            convert.fill_scratch(&left_on, expr_arena);
-            convert.fill_scratch(&right_on, expr_arena);
+            convert.fill_scratch(&left_on);
+            convert.fill_scratch(&right_on);
 
             let lp = IR::Join {
                 input_left,
@@ -348,19 +358,31 @@ pub fn to_alp_impl(
             exprs,
             options,
         } => {
-            let input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
+            let mut input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
                 .map_err(|e| e.context(failed_input!(with_columns)))?;
-            let (exprs, schema) = resolve_with_columns(exprs, input, lp_arena, expr_arena)
-                .map_err(|e| e.context(failed_here!(with_columns)))?;
-
-            convert.fill_scratch(&exprs, expr_arena);
-            let lp = IR::HStack {
-                input,
-                exprs,
-                schema,
-                options,
+            let stages = if options.sequential_resolution {
+                let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                split_sequential_with_columns(exprs, &input_schema)
+                    .map_err(|e| e.context(failed_here!(with_columns)))?
+            } else {
+                vec![exprs]
             };
-            return run_conversion(lp, lp_arena, expr_arena, convert, "with_columns");
+
+            // Every stage only depends on columns of its input, so it becomes its own `HStack`.
+            for exprs in stages {
+                let (exprs, schema) = resolve_with_columns(exprs, input, lp_arena, expr_arena)
+                    .map_err(|e| e.context(failed_here!(with_columns)))?;
+
+                convert.fill_scratch(&exprs);
+                let lp = IR::HStack {
+                    input,
+                    exprs,
+                    schema,
+                    options,
+                };
+                input = run_conversion(lp, lp_arena, expr_arena, convert, "with_columns")?;
+            }
+            return Ok(input);
         },
         DslPlan::Distinct { input, options } => {
             let input = to_alp_impl(owned(input), expr_arena, lp_arena, convert)
@@ -388,7 +410,7 @@ pub fn to_alp_impl(
                     let (exprs, schema) = resolve_with_columns(exprs, input, lp_arena, expr_arena)
                         .map_err(|e| e.context(failed_here!(fill_nan)))?;
 
-                    convert.fill_scratch(&exprs, expr_arena);
+                    convert.fill_scratch(&exprs);
 
                     let lp = IR::HStack {
                         input,
@@ -487,10 +509,10 @@ pub fn to_alp_impl(
                         &input_schema,
                         Context::Default,
                     )?);
-                    let eirs = to_expr_irs(exprs, expr_arena);
+                    let eirs = to_expr_irs(exprs, expr_arena)?;
                     let expr: ProjectionExprs = eirs.into();
 
-                    convert.fill_scratch(&expr, expr_arena);
+                    convert.fill_scratch(&expr);
 
                     let lp = IR::Select {
                         input,
@@ -628,11 +650,84 @@ fn resolve_with_columns(
         arena.clear();
     }
 
-    let eirs = to_expr_irs(exprs, expr_arena);
+    let eirs = to_expr_irs(exprs, expr_arena)?;
     let exprs = eirs.into();
     Ok((exprs, Arc::new(new_schema)))
 }
 
+/// Split the expressions of a sequentially resolved `with_columns` into a minimal chain of
+/// stages. Expressions are resolved left-to-right against the schema extended by the earlier
+/// expressions, and a new stage is only started when an expression refers to (or overwrites)
+/// a column created in the current stage.
+fn split_sequential_with_columns(
+    exprs: Vec<Expr>,
+    input_schema: &Schema,
+) -> PolarsResult<Vec<Vec<Expr>>> {
+    let mut schema = input_schema.clone();
+    // The index of the expression in this call that (last) defined a column.
+    let mut defined_by: PlHashMap<SmartString, usize> = PlHashMap::new();
+
+    let mut stages = vec![];
+    let mut stage = vec![];
+    let mut stage_names = PlHashSet::new();
+
+    let mut arena = Arena::with_capacity(8);
+    for (i, e) in exprs.into_iter().enumerate() {
+        let (expanded, _) = prepare_projection(vec![e.clone()], &schema).map_err(|err| {
+            let defined_earlier = expr_to_leaf_column_names_iter(&e)
+                .filter_map(|name| {
+                    defined_by
+                        .get(name.as_ref())
+                        .map(|idx| format!("'{name}' (defined by expression {idx})"))
+                })
+                .collect::<Vec<_>>();
+            if defined_earlier.is_empty() {
+                err
+            } else {
+                err.context(
+                    format!(
+                        "expression {i} of `with_columns` refers to {}",
+                        defined_earlier.join(", ")
+                    )
+                    .into(),
+                )
+            }
+        })?;
+
+        for e in expanded {
+            let field = e.to_field_amortized(&schema, Context::Default, &mut arena)?;
+            arena.clear();
+            let name = field.name();
+
+            if let Some(idx) = defined_by.get(name) {
+                polars_warn!(
+                    "the name '{}' is created by expressions {} and {} of the same `with_columns`; the last one is kept",
+                    name,
+                    idx,
+                    i
+                );
+            }
+
+            let depends_on_stage =
+                expr_to_leaf_column_names_iter(&e).any(|leaf| stage_names.contains(leaf.as_ref()));
+            if depends_on_stage || stage_names.contains(name) {
+                stages.push(std::mem::take(&mut stage));
+                stage_names.clear();
+            }
+
+            stage_names.insert(name.clone());
+            defined_by.insert(name.clone(), i);
+            schema.with_column(name.clone(), field.data_type().clone());
+            stage.push(e);
+        }
+    }
+    if !stage.is_empty() {
+        stages.push(stage);
+    }
+
+    Ok(stages)
+}
+
 fn resolve_group_by(
     input: Node,
     keys: Vec<Expr>,
@@ -680,8 +775,8 @@ fn resolve_group_by(
             polars_ensure!(names.insert(name.clone()), duplicate = name)
         }
     }
-    let aggs = to_expr_irs(aggs, expr_arena);
-    let keys = keys.convert(|e| to_expr_ir(e.clone(), expr_arena));
+    let aggs = to_expr_irs(aggs, expr_arena)?;
+    let keys = to_expr_irs(keys, expr_arena)?;
 
     Ok((keys, aggs, Arc::new(schema)))
 }
@@ -6,8 +6,19 @@ pub fn to_expr_ir(expr: Expr, arena: &mut Arena<AExpr>) -> ExprIR {
     ExprIR::new(node, state.output_name)
 }
 
-pub(super) fn to_expr_irs(input: Vec<Expr>, arena: &mut Arena<AExpr>) -> Vec<ExprIR> {
-    input.convert_owned(|e| to_expr_ir(e, arena))
+/// [`to_expr_ir`], but bails once the expression has more nodes than `POLARS_MAX_EXPR_NODES`.
+pub(super) fn try_to_expr_ir(expr: Expr, arena: &mut Arena<AExpr>) -> PolarsResult<ExprIR> {
+    let mut state = ConversionState::with_node_limit();
+    let node = to_aexpr_impl(expr, arena, &mut state);
+    state.check_node_limit()?;
+    Ok(ExprIR::new(node, state.output_name))
+}
+
+pub(super) fn to_expr_irs(input: Vec<Expr>, arena: &mut Arena<AExpr>) -> PolarsResult<Vec<ExprIR>> {
+    input
+        .into_iter()
+        .map(|e| try_to_expr_ir(e, arena))
+        .collect()
 }
 
 pub fn to_expr_ir_ignore_alias(expr: Expr, arena: &mut Arena<AExpr>) -> ExprIR {
@@ -17,8 +28,22 @@ pub fn to_expr_ir_ignore_alias(expr: Expr, arena: &mut Arena<AExpr>) -> ExprIR {
     ExprIR::new(node, state.output_name)
 }
 
-pub(super) fn to_expr_irs_ignore_alias(input: Vec<Expr>, arena: &mut Arena<AExpr>) -> Vec<ExprIR> {
-    input.convert_owned(|e| to_expr_ir_ignore_alias(e, arena))
+fn try_to_expr_ir_ignore_alias(expr: Expr, arena: &mut Arena<AExpr>) -> PolarsResult<ExprIR> {
+    let mut state = ConversionState::with_node_limit();
+    state.ignore_alias = true;
+    let node = to_aexpr_impl_materialized_lit(expr, arena, &mut state);
+    state.check_node_limit()?;
+    Ok(ExprIR::new(node, state.output_name))
+}
+
+pub(super) fn to_expr_irs_ignore_alias(
+    input: Vec<Expr>,
+    arena: &mut Arena<AExpr>,
+) -> PolarsResult<Vec<ExprIR>> {
+    input
+        .into_iter()
+        .map(|e| try_to_expr_ir_ignore_alias(e, arena))
+        .collect()
 }
 
 /// converts expression to AExpr and adds it to the arena, which uses an arena (Vec) for allocation
@@ -33,13 +58,39 @@ pub fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
     )
 }
 
-#[derive(Default)]
+/// [`to_aexpr`], but bails once the expression has more nodes than `POLARS_MAX_EXPR_NODES`.
+pub(crate) fn try_to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> PolarsResult<Node> {
+    let mut state = ConversionState {
+        prune_alias: false,
+        ..ConversionState::with_node_limit()
+    };
+    let node = to_aexpr_impl_materialized_lit(expr, arena, &mut state);
+    state.check_node_limit()?;
+    Ok(node)
+}
+
 struct ConversionState {
     output_name: OutputName,
     /// Remove alias from the expressions and set as [`OutputName`].
     prune_alias: bool,
     /// If an `alias` is encountered prune and ignore it.
     ignore_alias: bool,
+    /// Number of nodes converted so far, including the inputs of functions.
+    nodes: usize,
+    /// Stop converting once `nodes` exceeds this.
+    max_nodes: usize,
+}
+
+impl Default for ConversionState {
+    fn default() -> Self {
+        Self {
+            output_name: OutputName::None,
+            prune_alias: false,
+            ignore_alias: false,
+            nodes: 0,
+            max_nodes: usize::MAX,
+        }
+    }
 }
 
 impl ConversionState {
@@ -49,6 +100,45 @@ impl ConversionState {
             ..Default::default()
         }
     }
+
+    fn with_node_limit() -> Self {
+        Self {
+            max_nodes: polars_core::config::get_max_expr_nodes(),
+            ..Self::new()
+        }
+    }
+
+    fn check_node_limit(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.nodes <= self.max_nodes,
+            ComputeError: "expression '{}' has more than {} nodes (conversion stopped after {} nodes); \
+            the limit can be raised with POLARS_MAX_EXPR_NODES",
+            self.output_name.get().map(|name| name.as_ref()).unwrap_or("<unnamed>"),
+            self.max_nodes,
+            self.nodes
+        );
+        Ok(())
+    }
+}
+
+/// Convert the inputs of a function. Every input gets its own output name, but they count
+/// towards the node limit of the expression they are part of.
+fn to_function_inputs(
+    input: Vec<Expr>,
+    arena: &mut Arena<AExpr>,
+    state: &mut ConversionState,
+) -> Vec<ExprIR> {
+    input.convert_owned(|e| {
+        let mut input_state = ConversionState {
+            prune_alias: true,
+            nodes: state.nodes,
+            max_nodes: state.max_nodes,
+            ..Default::default()
+        };
+        let node = to_aexpr_impl(e, arena, &mut input_state);
+        state.nodes = input_state.nodes;
+        ExprIR::new(node, input_state.output_name)
+    })
 }
 
 fn to_aexprs(input: Vec<Expr>, arena: &mut Arena<AExpr>, state: &mut ConversionState) -> Vec<Node> {
@@ -107,6 +197,11 @@ fn to_aexpr_impl_materialized_lit(
 /// Converts expression to AExpr and adds it to the arena, which uses an arena (Vec) for allocation.
 #[recursive]
 fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionState) -> Node {
+    state.nodes += 1;
+    if state.nodes > state.max_nodes {
+        // The caller raises an error, don't convert the rest of the expression.
+        return arena.add(AExpr::Len);
+    }
     let owned = Arc::unwrap_or_clone;
     let v = match expr {
         Expr::Explode(expr) => AExpr::Explode(to_aexpr_impl(owned(expr), arena, state)),
@@ -265,7 +360,7 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
             output_type,
             options,
         } => {
-            let e = to_expr_irs(input, arena);
+            let e = to_function_inputs(input, arena, state);
             set_function_output_name(&e, state, || Cow::Borrowed(options.fmt_str));
             AExpr::AnonymousFunction {
                 input: e,
@@ -300,7 +395,7 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
                 _ => {},
             }
 
-            let e = to_expr_irs(input, arena);
+            let e = to_function_inputs(input, arena, state);
 
             if state.output_name.is_none() {
                 // Handles special case functions like `struct.field`.
@@ -352,3 +447,30 @@ fn to_aexpr_impl(expr: Expr, arena: &mut Arena<AExpr>, state: &mut ConversionSta
     };
     arena.add(v)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_expr_ir_with_max_nodes(expr: Expr, max_nodes: usize) -> PolarsResult<ExprIR> {
+        let mut state = ConversionState {
+            max_nodes,
+            ..ConversionState::new()
+        };
+        let node = to_aexpr_impl(expr, &mut Arena::new(), &mut state);
+        state.check_node_limit()?;
+        Ok(ExprIR::new(node, state.output_name))
+    }
+
+    #[test]
+    fn test_max_expr_nodes() {
+        let deep = |n: i32| (0..n).fold(col("a"), |acc, _| acc + lit(1)).alias("deep");
+
+        let err = to_expr_ir_with_max_nodes(deep(1500), 1000)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'deep'"), "{err}");
+        assert!(err.contains("more than 1000 nodes"), "{err}");
+        assert!(to_expr_ir_with_max_nodes(deep(100), 1000).is_ok());
+    }
+}
@@ -1,5 +1,5 @@
 use std::io::Read;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[cfg(feature = "cloud")]
 use polars_io::pl_async::get_runtime;
@@ -28,11 +28,53 @@ fn prepare_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef
 pub(super) fn parquet_file_info(
     paths: &[PathBuf],
     file_options: &FileScanOptions,
+    options: &ParquetOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetaDataRef>)> {
     let path = get_path(paths)?;
 
-    let (schema, reader_schema, num_rows, metadata) = if is_cloud_url(path) {
+    let (path, (schema, reader_schema, num_rows, metadata)) =
+        match parquet_schema(path, file_options, options, cloud_options) {
+            Ok(info) => (path, info),
+            // Files that can't be read are skipped by the scan, so take the schema of the first
+            // file that can. The metadata is only passed on for the first file.
+            Err(err) if file_options.on_error == ScanErrorPolicy::SkipFile => paths[1..]
+                .iter()
+                .find_map(|path| {
+                    let (schema, reader_schema, num_rows, _) =
+                        parquet_schema(path, file_options, options, cloud_options).ok()?;
+                    Some((path, (schema, reader_schema, num_rows, None)))
+                })
+                .ok_or(err)?,
+            Err(err) => return Err(err),
+        };
+
+    let mut file_info = FileInfo::new(
+        schema,
+        Some(reader_schema),
+        (num_rows, num_rows.unwrap_or(0)),
+    );
+
+    if file_options.hive_options.enabled {
+        file_info.init_hive_partitions(path.as_path(), file_options.hive_options.schema.clone())?
+    }
+
+    Ok((file_info, metadata))
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_schema(
+    path: &Path,
+    file_options: &FileScanOptions,
+    options: &ParquetOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<(
+    SchemaRef,
+    arrow::datatypes::ArrowSchemaRef,
+    Option<usize>,
+    Option<FileMetaDataRef>,
+)> {
+    if is_cloud_url(path) {
         #[cfg(not(feature = "cloud"))]
         panic!("One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled.");
 
@@ -40,8 +82,9 @@ pub(super) fn parquet_file_info(
         {
             let uri = path.to_string_lossy();
             get_runtime().block_on(async {
-                let mut reader =
-                    ParquetAsyncReader::from_uri(&uri, cloud_options, None, None).await?;
+                let mut reader = ParquetAsyncReader::from_uri(&uri, cloud_options, None, None)
+                    .await?
+                    .uuid_as_string(options.uuid_as_string);
                 let reader_schema = reader.schema().await?;
                 let num_rows = reader.num_rows().await?;
                 let metadata = reader.get_metadata().await?.clone();
@@ -49,32 +92,20 @@ pub(super) fn parquet_file_info(
                 let schema =
                     prepare_schema((&reader_schema).into(), file_options.row_index.as_ref());
                 PolarsResult::Ok((schema, reader_schema, Some(num_rows), Some(metadata)))
-            })?
+            })
         }
     } else {
         let file = polars_utils::open_file(path)?;
-        let mut reader = ParquetReader::new(file);
+        let mut reader = ParquetReader::new(file).uuid_as_string(options.uuid_as_string);
         let reader_schema = reader.schema()?;
         let schema = prepare_schema((&reader_schema).into(), file_options.row_index.as_ref());
-        (
+        Ok((
             schema,
             reader_schema,
             Some(reader.num_rows()?),
             Some(reader.get_metadata()?.clone()),
-        )
-    };
-
-    let mut file_info = FileInfo::new(
-        schema,
-        Some(reader_schema),
-        (num_rows, num_rows.unwrap_or(0)),
-    );
-
-    if file_options.hive_options.enabled {
-        file_info.init_hive_partitions(path.as_path(), file_options.hive_options.schema.clone())?
+        ))
     }
-
-    Ok((file_info, metadata))
 }
 
 // TODO! return metadata arced
@@ -159,6 +190,7 @@ pub(super) fn csv_file_info(
         csv_options.comment_prefix.as_ref(),
         csv_options.quote_char,
         csv_options.eol_char,
+        csv_options.record_separator.as_deref(),
         csv_options.null_values.as_ref(),
         csv_options.try_parse_dates,
         csv_options.raise_if_empty,
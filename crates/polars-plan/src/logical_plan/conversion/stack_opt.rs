@@ -1,9 +1,10 @@
-use std::borrow::Borrow;
-
 use super::*;
+use crate::logical_plan::optimizer::with_expr_context;
 
 pub(super) struct ConversionOpt {
     scratch: Vec<Node>,
+    // the expressions of the current plan node, errors name the one they occur in
+    roots: Vec<ExprIR>,
     simplify: Option<SimplifyExprRule>,
     coerce: Option<TypeCoercionRule>,
 }
@@ -24,23 +25,18 @@ impl ConversionOpt {
 
         ConversionOpt {
             scratch: Vec::with_capacity(8),
+            roots: Vec::with_capacity(4),
             simplify,
             coerce,
         }
     }
 
-    pub(super) fn push_scratch(&mut self, expr: Node, expr_arena: &Arena<AExpr>) {
-        self.scratch.push(expr);
-        // traverse all subexpressions and add to the stack
-        let expr = unsafe { expr_arena.get_unchecked(expr) };
-        expr.nodes(&mut self.scratch);
+    pub(super) fn push_scratch(&mut self, expr: &ExprIR) {
+        self.roots.push(expr.clone());
     }
 
-    pub(super) fn fill_scratch<N: Borrow<Node>>(&mut self, exprs: &[N], expr_arena: &Arena<AExpr>) {
-        for e in exprs {
-            let node = *e.borrow();
-            self.push_scratch(node, expr_arena);
-        }
+    pub(super) fn fill_scratch(&mut self, exprs: &[ExprIR]) {
+        self.roots.extend_from_slice(exprs);
     }
 
     pub(super) fn coerce_types(
@@ -51,32 +47,41 @@ impl ConversionOpt {
     ) -> PolarsResult<()> {
         // Different from the stack-opt in the optimizer phase, this does a single pass until fixed point per expression.
 
-        // process the expressions on the stack and apply optimizations.
-        while let Some(current_expr_node) = self.scratch.pop() {
-            {
-                let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
-                if expr.is_leaf() {
-                    continue;
-                }
-            }
-            if let Some(rule) = &mut self.simplify {
-                while let Some(x) =
-                    rule.optimize_expr(expr_arena, current_expr_node, lp_arena, current_node)?
+        while let Some(expr_ir) = self.roots.pop() {
+            self.scratch.push(expr_ir.node());
+            // add the direct subexpressions as well, so they are visited before the root
+            let expr = unsafe { expr_arena.get_unchecked(expr_ir.node()) };
+            expr.nodes(&mut self.scratch);
+
+            // process the expressions on the stack and apply optimizations.
+            while let Some(current_expr_node) = self.scratch.pop() {
                 {
-                    expr_arena.replace(current_expr_node, x);
+                    let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
+                    if expr.is_leaf() {
+                        continue;
+                    }
                 }
-            }
-            if let Some(rule) = &mut self.coerce {
-                while let Some(x) =
-                    rule.optimize_expr(expr_arena, current_expr_node, lp_arena, current_node)?
-                {
-                    expr_arena.replace(current_expr_node, x);
+                if let Some(rule) = &mut self.simplify {
+                    while let Some(x) = rule
+                        .optimize_expr(expr_arena, current_expr_node, lp_arena, current_node)
+                        .map_err(|e| with_expr_context(e, &expr_ir, expr_arena))?
+                    {
+                        expr_arena.replace(current_expr_node, x);
+                    }
+                }
+                if let Some(rule) = &mut self.coerce {
+                    while let Some(x) = rule
+                        .optimize_expr(expr_arena, current_expr_node, lp_arena, current_node)
+                        .map_err(|e| with_expr_context(e, &expr_ir, expr_arena))?
+                    {
+                        expr_arena.replace(current_expr_node, x);
+                    }
                 }
-            }
 
-            let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
-            // traverse subexpressions and add to the stack
-            expr.nodes(&mut self.scratch)
+                let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
+                // traverse subexpressions and add to the stack
+                expr.nodes(&mut self.scratch)
+            }
         }
 
         Ok(())
@@ -28,6 +28,13 @@ impl OutputName {
     pub(crate) fn is_none(&self) -> bool {
         matches!(self, OutputName::None)
     }
+
+    pub(crate) fn get(&self) -> Option<&ColumnName> {
+        match self {
+            OutputName::None => None,
+            name => Some(name.unwrap()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -105,6 +105,22 @@ impl Hash for FileScan {
 }
 
 impl FileScan {
+    /// Hash the scan options, but not the cloud options as those contain credentials
+    /// that aren't stable over time. The variant is hashed by name, as its discriminant
+    /// depends on the enabled features.
+    pub(crate) fn hash_without_cloud_options<H: Hasher>(&self, state: &mut H) {
+        <&'static str>::from(self).hash(state);
+        match self {
+            #[cfg(feature = "csv")]
+            FileScan::Csv { options } => options.hash(state),
+            #[cfg(feature = "parquet")]
+            FileScan::Parquet { options, .. } => options.hash(state),
+            #[cfg(feature = "ipc")]
+            FileScan::Ipc { options, .. } => options.hash(state),
+            FileScan::Anonymous { options, .. } => options.hash(state),
+        }
+    }
+
     pub(crate) fn remove_metadata(&mut self) {
         match self {
             #[cfg(feature = "parquet")]
@@ -133,11 +149,10 @@ impl FileScan {
             #[cfg(feature = "csv")]
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
-            Self::Ipc { .. } => false,
+            Self::Ipc { .. } => true,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
-            #[allow(unreachable_patterns)]
-            _ => false,
+            Self::Anonymous { function, .. } => function.allows_streaming(),
         }
     }
 }
@@ -203,6 +203,9 @@ impl DslPlan {
             } => {
                 let how = &options.args.how;
                 write!(f, "{:indent$}{how} JOIN:", "")?;
+                if let Some(hint) = &options.args.strategy_hint {
+                    write!(f, "\n{:indent$}STRATEGY HINT: {hint}", "")?;
+                }
                 write!(f, "\n{:indent$}LEFT PLAN ON: {left_on:?}", "")?;
                 input_left._format(f, sub_indent)?;
                 write!(f, "\n{:indent$}RIGHT PLAN ON: {right_on:?}", "")?;
@@ -243,6 +246,7 @@ impl DslPlan {
                     SinkType::File { .. } => "SINK (file)",
                     #[cfg(feature = "cloud")]
                     SinkType::Cloud { .. } => "SINK (cloud)",
+                    SinkType::Split { .. } => "SINK (split)",
                 };
                 write!(f, "{:indent$}{name}", "")?;
                 input._format(f, sub_indent)
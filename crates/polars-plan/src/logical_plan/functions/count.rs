@@ -31,6 +31,7 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
                         options.quote_char,
                         options.comment_prefix.as_ref(),
                         options.eol_char,
+                        options.record_separator.as_deref(),
                         options.has_header,
                     )
                 })
@@ -1,7 +1,11 @@
 use polars_core::prelude::*;
 use polars_ops::prelude::*;
 
-pub(super) fn merge_sorted(df: &DataFrame, column: &str) -> PolarsResult<DataFrame> {
+pub(super) fn merge_sorted(
+    df: &DataFrame,
+    columns: &[Arc<str>],
+    descending: &[bool],
+) -> PolarsResult<DataFrame> {
     // SAFETY:
     // the dtype is known
     let (left_cols, right_cols) = unsafe {
@@ -32,7 +36,7 @@ pub(super) fn merge_sorted(df: &DataFrame, column: &str) -> PolarsResult<DataFra
     let left = unsafe { DataFrame::new_no_checks(left_cols) };
     let right = unsafe { DataFrame::new_no_checks(right_cols) };
 
-    let lhs = left.column(column)?;
-    let rhs = right.column(column)?;
-    _merge_sorted_dfs(&left, &right, lhs, rhs, true)
+    let lhs = left.select_series(columns)?;
+    let rhs = right.select_series(columns)?;
+    _merge_sorted_dfs_by(&left, &right, &lhs, &rhs, descending, true)
 }
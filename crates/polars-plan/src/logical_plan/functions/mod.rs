@@ -18,6 +18,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use smartstring::alias::String as SmartString;
+use strum_macros::IntoStaticStr;
 
 #[cfg(feature = "python")]
 use crate::dsl::python_udf::PythonFunction;
@@ -25,7 +26,7 @@ use crate::dsl::python_udf::PythonFunction;
 use crate::logical_plan::functions::merge_sorted::merge_sorted;
 use crate::prelude::*;
 
-#[derive(Clone)]
+#[derive(Clone, IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FunctionNode {
     #[cfg(feature = "python")]
@@ -73,8 +74,9 @@ pub enum FunctionNode {
     // two DataFrames
     #[cfg(feature = "merge_sorted")]
     MergeSorted {
-        // sorted column that serves as the key
-        column: Arc<str>,
+        // sorted columns that serve as the keys
+        columns: Arc<[Arc<str>]>,
+        descending: Arc<[bool]>,
     },
     Rename {
         existing: Arc<[SmartString]>,
@@ -127,7 +129,16 @@ impl PartialEq for FunctionNode {
             (Melt { args: l, .. }, Melt { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
             #[cfg(feature = "merge_sorted")]
-            (MergeSorted { column: l }, MergeSorted { column: r }) => l == r,
+            (
+                MergeSorted {
+                    columns: columns_l,
+                    descending: descending_l,
+                },
+                MergeSorted {
+                    columns: columns_r,
+                    descending: descending_r,
+                },
+            ) => columns_l == columns_r && descending_l == descending_r,
             _ => false,
         }
     }
@@ -136,6 +147,20 @@ impl PartialEq for FunctionNode {
 impl Hash for FunctionNode {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
+        self.hash_fields(state, false)
+    }
+}
+
+impl FunctionNode {
+    /// Hash the variant by name, as its discriminant depends on the enabled features, and
+    /// leave out the cloud options of scans. Used for stable plan fingerprints.
+    #[cfg(feature = "cse")]
+    pub(crate) fn hash_stable<H: Hasher>(&self, state: &mut H) {
+        <&'static str>::from(self).hash(state);
+        self.hash_fields(state, true)
+    }
+
+    fn hash_fields<H: Hasher>(&self, state: &mut H, stable: bool) {
         match self {
             #[cfg(feature = "python")]
             FunctionNode::OpaquePython { .. } => {},
@@ -146,14 +171,24 @@ impl Hash for FunctionNode {
                 alias,
             } => {
                 paths.hash(state);
-                scan_type.hash(state);
+                if stable {
+                    scan_type.hash_without_cloud_options(state);
+                } else {
+                    scan_type.hash(state);
+                }
                 alias.hash(state);
             },
             FunctionNode::Pipeline { .. } => {},
             FunctionNode::Unnest { columns } => columns.hash(state),
             FunctionNode::Rechunk => {},
             #[cfg(feature = "merge_sorted")]
-            FunctionNode::MergeSorted { column } => column.hash(state),
+            FunctionNode::MergeSorted {
+                columns,
+                descending,
+            } => {
+                columns.hash(state);
+                descending.hash(state);
+            },
             FunctionNode::Rename {
                 existing,
                 new,
@@ -244,7 +279,7 @@ impl FunctionNode {
             Unnest { columns } => Cow::Borrowed(columns.as_ref()),
             Explode { columns, .. } => Cow::Borrowed(columns.as_ref()),
             #[cfg(feature = "merge_sorted")]
-            MergeSorted { column, .. } => Cow::Owned(vec![column.clone()]),
+            MergeSorted { columns, .. } => Cow::Borrowed(columns.as_ref()),
             _ => Cow::Borrowed(&[]),
         }
     }
@@ -268,7 +303,10 @@ impl FunctionNode {
                 Ok(df)
             },
             #[cfg(feature = "merge_sorted")]
-            MergeSorted { column } => merge_sorted(&df, column.as_ref()),
+            MergeSorted {
+                columns,
+                descending,
+            } => merge_sorted(&df, columns.as_ref(), descending.as_ref()),
             Unnest { columns: _columns } => {
                 #[cfg(feature = "dtype-struct")]
                 {
@@ -260,16 +260,22 @@ impl OptimizationRule for SimplifyBooleanRule {
 fn eval_negate(ae: &AExpr) -> Option<AExpr> {
     let out = match ae {
         AExpr::Literal(lv) => match lv {
+            // Leave overflowing literals to the kernel, which raises.
             #[cfg(feature = "dtype-i8")]
-            LiteralValue::Int8(v) => LiteralValue::Int8(-*v),
+            LiteralValue::Int8(v) => LiteralValue::Int8(v.checked_neg()?),
             #[cfg(feature = "dtype-i16")]
-            LiteralValue::Int16(v) => LiteralValue::Int16(-*v),
-            LiteralValue::Int32(v) => LiteralValue::Int32(-*v),
-            LiteralValue::Int64(v) => LiteralValue::Int64(-*v),
+            LiteralValue::Int16(v) => LiteralValue::Int16(v.checked_neg()?),
+            LiteralValue::Int32(v) => LiteralValue::Int32(v.checked_neg()?),
+            LiteralValue::Int64(v) => LiteralValue::Int64(v.checked_neg()?),
+            // Unsigned literals are upcast, like columns in `negate_dtype`.
+            #[cfg(feature = "dtype-u16")]
+            LiteralValue::UInt16(v) => LiteralValue::Int32(-(*v as i32)),
+            LiteralValue::UInt32(v) => LiteralValue::Int64(-(*v as i64)),
             LiteralValue::Float32(v) => LiteralValue::Float32(-*v),
             LiteralValue::Float64(v) => LiteralValue::Float64(-*v),
             LiteralValue::Float(v) => LiteralValue::Float(-*v),
-            LiteralValue::Int(v) => LiteralValue::Int(-*v),
+            LiteralValue::Int(v) => LiteralValue::Int(v.checked_neg()?),
+            LiteralValue::Boolean(v) => LiteralValue::Boolean(!*v),
             _ => return None,
         },
         _ => return None,
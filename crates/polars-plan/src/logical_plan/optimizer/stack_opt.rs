@@ -1,8 +1,9 @@
-use polars_core::prelude::PolarsResult;
+use polars_core::prelude::{PolarsError, PolarsResult};
 
 use crate::logical_plan::aexpr::AExpr;
 use crate::logical_plan::alp::IR;
-use crate::prelude::{Arena, Node};
+use crate::logical_plan::expr_ir::OutputName;
+use crate::prelude::{node_to_expr, Arena, ExprIR, Node};
 
 /// Optimizer that uses a stack and memory arenas in favor of recursion
 pub struct StackOptimizer {}
@@ -49,32 +50,35 @@ impl StackOptimizer {
 
                 while let Some(expr_ir) = scratch.pop() {
                     exprs.push(expr_ir.node());
-                }
 
-                // process the expressions on the stack and apply optimizations.
-                while let Some(current_expr_node) = exprs.pop() {
-                    {
-                        let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
-                        if expr.is_leaf() {
-                            continue;
+                    // process the expressions on the stack and apply optimizations.
+                    while let Some(current_expr_node) = exprs.pop() {
+                        {
+                            let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
+                            if expr.is_leaf() {
+                                continue;
+                            }
                         }
-                    }
-                    for rule in rules.iter_mut() {
-                        // keep iterating over same rule
-                        while let Some(x) = rule.optimize_expr(
-                            expr_arena,
-                            current_expr_node,
-                            lp_arena,
-                            current_node,
-                        )? {
-                            expr_arena.replace(current_expr_node, x);
-                            changed = true;
+                        for rule in rules.iter_mut() {
+                            // keep iterating over same rule
+                            while let Some(x) = rule
+                                .optimize_expr(
+                                    expr_arena,
+                                    current_expr_node,
+                                    lp_arena,
+                                    current_node,
+                                )
+                                .map_err(|e| with_expr_context(e, &expr_ir, expr_arena))?
+                            {
+                                expr_arena.replace(current_expr_node, x);
+                                changed = true;
+                            }
                         }
-                    }
 
-                    let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
-                    // traverse subexpressions and add to the stack
-                    expr.nodes(&mut exprs)
+                        let expr = unsafe { expr_arena.get_unchecked(current_expr_node) };
+                        // traverse subexpressions and add to the stack
+                        expr.nodes(&mut exprs)
+                    }
                 }
             }
         }
@@ -82,6 +86,23 @@ impl StackOptimizer {
     }
 }
 
+/// Point to the user expression in which an optimization rule failed, as rules only see the
+/// (sub)expression they are currently rewriting.
+pub(crate) fn with_expr_context(
+    err: PolarsError,
+    expr_ir: &ExprIR,
+    expr_arena: &Arena<AExpr>,
+) -> PolarsError {
+    let expr = node_to_expr(expr_ir.node(), expr_arena);
+    let msg = match expr_ir.output_name_inner() {
+        OutputName::None => format!("in expression {:?}", expr),
+        OutputName::LiteralLhs(name) | OutputName::ColumnLhs(name) | OutputName::Alias(name) => {
+            format!("in expression '{}' ({:?})", name, expr)
+        },
+    };
+    err.context(msg.into())
+}
+
 pub trait OptimizationRule {
     ///  Optimize (subplan) in LogicalPlan
     ///
@@ -31,6 +31,23 @@ pub struct FileScanOptions {
     pub rechunk: bool,
     pub file_counter: FileCount,
     pub hive_options: HiveOptions,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub on_error: ScanErrorPolicy,
+    /// Read at most this many rows from every file of the scan. Unlike `n_rows`, which limits
+    /// the total over all files, this caps the files independently of each other.
+    pub per_file_limit: Option<usize>,
+}
+
+/// What to do when a single file of a (multi-file) scan can't be read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScanErrorPolicy {
+    /// Abort the query with the error of the file.
+    #[default]
+    Raise,
+    /// Skip the file and record its error, so that the query can continue with the remaining
+    /// files. Errors that are not caused by reading a file still abort the query.
+    SkipFile,
 }
 
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
@@ -237,10 +254,31 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    /// Route every row to one of two files by the boolean column `predicate` of the input,
+    /// which isn't written itself.
+    Split {
+        predicate: Arc<str>,
+        nulls: SplitNulls,
+        on_true: Box<FileSinkOptions>,
+        on_false: Box<FileSinkOptions>,
+    },
 }
 
+/// Where a split routes the rows for which the predicate is null.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug)]
+pub enum SplitNulls {
+    /// Route the rows to the output of the rows that don't match the predicate.
+    #[default]
+    False,
+    /// Route the rows to the output of the rows that match the predicate.
+    True,
+    /// Don't route the rows to either output.
+    Drop,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct FileSinkOptions {
     pub path: Arc<PathBuf>,
     pub file_type: FileType,
@@ -264,6 +302,10 @@ pub enum FileType {
 pub struct ProjectionOptions {
     pub run_parallel: bool,
     pub duplicate_check: bool,
+    /// Resolve the expressions of a `with_columns` left-to-right, so that they can refer to
+    /// columns created by earlier expressions in the same call.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sequential_resolution: bool,
 }
 
 impl Default for ProjectionOptions {
@@ -271,6 +313,7 @@ impl Default for ProjectionOptions {
         Self {
             run_parallel: true,
             duplicate_check: true,
+            sequential_resolution: false,
         }
     }
 }
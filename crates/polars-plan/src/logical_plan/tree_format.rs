@@ -254,7 +254,13 @@ impl<'a> TreeFmtNode<'a> {
                     ..
                 },
             ) => ND(
-                wh(h, &format!("{} JOIN", options.args.how)),
+                wh(
+                    h,
+                    &match &options.args.strategy_hint {
+                        Some(hint) => format!("{} JOIN [{hint} hint]", options.args.how),
+                        None => format!("{} JOIN", options.args.how),
+                    },
+                ),
                 left_on
                     .iter()
                     .map(|expr| NE(Some("left on:".to_string()), expr))
@@ -301,6 +307,7 @@ impl<'a> TreeFmtNode<'a> {
                         SinkType::File { .. } => "SINK (file)",
                         #[cfg(feature = "cloud")]
                         SinkType::Cloud { .. } => "SINK (cloud)",
+                        SinkType::Split { .. } => "SINK (split)",
                     },
                 ),
                 vec![NL(None, input)],
@@ -1,12 +1,13 @@
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use polars_utils::arena::Arena;
+use polars_utils::arena::{Arena, Node};
+use polars_utils::hashing::StableHasher;
 
 use super::*;
 use crate::logical_plan::{AExpr, IR};
-use crate::prelude::aexpr::traverse_and_hash_aexpr;
-use crate::prelude::ExprIR;
+use crate::prelude::aexpr::{traverse_and_hash_aexpr, traverse_and_hash_aexpr_stable};
+use crate::prelude::{ExprIR, JoinOptions, JoinType, SinkType, UnionOptions};
 
 impl IRNode {
     pub(crate) fn hashable_and_cmp<'a>(
@@ -19,6 +20,7 @@ impl IRNode {
             lp_arena,
             expr_arena,
             ignore_cache: false,
+            stable: false,
         }
     }
 }
@@ -28,6 +30,7 @@ pub(crate) struct HashableEqLP<'a> {
     lp_arena: &'a Arena<IR>,
     expr_arena: &'a Arena<AExpr>,
     ignore_cache: bool,
+    stable: bool,
 }
 
 impl HashableEqLP<'_> {
@@ -37,17 +40,34 @@ impl HashableEqLP<'_> {
         self.ignore_cache = true;
         self
     }
-}
 
-fn hash_option_expr<H: Hasher>(expr: &Option<ExprIR>, expr_arena: &Arena<AExpr>, state: &mut H) {
-    if let Some(e) = expr {
-        e.traverse_and_hash(expr_arena, state)
+    /// Only hash data that is stable across processes, see [`plan_fingerprint`].
+    fn stable(mut self) -> Self {
+        self.stable = true;
+        self
+    }
+
+    fn hash_expr<H: Hasher>(&self, expr: &ExprIR, state: &mut H) {
+        if self.stable {
+            traverse_and_hash_aexpr_stable(expr.node(), self.expr_arena, state);
+            if let Some(alias) = expr.get_alias() {
+                alias.hash(state)
+            }
+        } else {
+            expr.traverse_and_hash(self.expr_arena, state)
+        }
+    }
+
+    fn hash_option_expr<H: Hasher>(&self, expr: &Option<ExprIR>, state: &mut H) {
+        if let Some(e) = expr {
+            self.hash_expr(e, state)
+        }
     }
-}
 
-fn hash_exprs<H: Hasher>(exprs: &[ExprIR], expr_arena: &Arena<AExpr>, state: &mut H) {
-    for e in exprs {
-        e.traverse_and_hash(expr_arena, state);
+    fn hash_exprs<H: Hasher>(&self, exprs: &[ExprIR], state: &mut H) {
+        for e in exprs {
+            self.hash_expr(e, state);
+        }
     }
 }
 
@@ -55,7 +75,12 @@ impl Hash for HashableEqLP<'_> {
     // This hashes the variant, not the whole plan
     fn hash<H: Hasher>(&self, state: &mut H) {
         let alp = self.node.to_alp(self.lp_arena);
-        std::mem::discriminant(alp).hash(state);
+        if self.stable {
+            // The discriminant depends on the enabled features.
+            alp.name().hash(state);
+        } else {
+            std::mem::discriminant(alp).hash(state);
+        }
         match alp {
             #[cfg(feature = "python")]
             IR::PythonScan { .. } => {},
@@ -71,7 +96,7 @@ impl Hash for HashableEqLP<'_> {
                 input: _,
                 predicate,
             } => {
-                predicate.traverse_and_hash(self.expr_arena, state);
+                self.hash_expr(predicate, state);
             },
             IR::Scan {
                 paths,
@@ -82,9 +107,13 @@ impl Hash for HashableEqLP<'_> {
                 file_options,
             } => {
                 // We don't have to traverse the schema, hive partitions etc. as they are derivative from the paths.
-                scan_type.hash(state);
+                if self.stable {
+                    scan_type.hash_without_cloud_options(state);
+                } else {
+                    scan_type.hash(state);
+                }
                 paths.hash(state);
-                hash_option_expr(predicate, self.expr_arena, state);
+                self.hash_option_expr(predicate, state);
                 file_options.hash(state);
             },
             IR::DataFrameScan {
@@ -94,9 +123,16 @@ impl Hash for HashableEqLP<'_> {
                 projection,
                 selection,
             } => {
-                (Arc::as_ptr(df) as usize).hash(state);
+                if self.stable {
+                    // The address of the frame differs between processes, so in-memory
+                    // data is only identified by its shape.
+                    df.schema_hash().hash(state);
+                    df.height().hash(state);
+                } else {
+                    (Arc::as_ptr(df) as usize).hash(state);
+                }
                 projection.hash(state);
-                hash_option_expr(selection, self.expr_arena, state);
+                self.hash_option_expr(selection, state);
             },
             IR::SimpleProjection {
                 columns,
@@ -112,7 +148,7 @@ impl Hash for HashableEqLP<'_> {
                 schema: _,
                 options,
             } => {
-                hash_exprs(expr.default_exprs(), self.expr_arena, state);
+                self.hash_exprs(expr.default_exprs(), state);
                 options.hash(state);
             },
             IR::Sort {
@@ -121,7 +157,7 @@ impl Hash for HashableEqLP<'_> {
                 slice,
                 sort_options,
             } => {
-                hash_exprs(by_column, self.expr_arena, state);
+                self.hash_exprs(by_column, state);
                 slice.hash(state);
                 sort_options.hash(state);
             },
@@ -134,8 +170,8 @@ impl Hash for HashableEqLP<'_> {
                 maintain_order,
                 options,
             } => {
-                hash_exprs(keys, self.expr_arena, state);
-                hash_exprs(aggs, self.expr_arena, state);
+                self.hash_exprs(keys, state);
+                self.hash_exprs(aggs, state);
                 apply.is_none().hash(state);
                 maintain_order.hash(state);
                 options.hash(state);
@@ -148,9 +184,13 @@ impl Hash for HashableEqLP<'_> {
                 right_on,
                 options,
             } => {
-                hash_exprs(left_on, self.expr_arena, state);
-                hash_exprs(right_on, self.expr_arena, state);
-                options.hash(state);
+                self.hash_exprs(left_on, state);
+                self.hash_exprs(right_on, state);
+                if self.stable {
+                    hash_join_options_stable(options, state);
+                } else {
+                    options.hash(state);
+                }
             },
             IR::HStack {
                 input: _,
@@ -158,16 +198,39 @@ impl Hash for HashableEqLP<'_> {
                 schema: _,
                 options,
             } => {
-                hash_exprs(exprs.default_exprs(), self.expr_arena, state);
+                self.hash_exprs(exprs.default_exprs(), state);
                 options.hash(state);
             },
             IR::Distinct { input: _, options } => {
                 options.hash(state);
             },
             IR::MapFunction { input: _, function } => {
-                function.hash(state);
+                if self.stable {
+                    function.hash_stable(state);
+                } else {
+                    function.hash(state);
+                }
+            },
+            IR::Union { inputs: _, options } => {
+                if self.stable {
+                    // The row counts are estimated from file metadata.
+                    let UnionOptions {
+                        slice,
+                        parallel,
+                        rows: _,
+                        from_partitioned_ds,
+                        flattened_by_opt,
+                        rechunk,
+                    } = options;
+                    slice.hash(state);
+                    parallel.hash(state);
+                    from_partitioned_ds.hash(state);
+                    flattened_by_opt.hash(state);
+                    rechunk.hash(state);
+                } else {
+                    options.hash(state)
+                }
             },
-            IR::Union { inputs: _, options } => options.hash(state),
             IR::HConcat {
                 inputs: _,
                 schema: _,
@@ -181,18 +244,29 @@ impl Hash for HashableEqLP<'_> {
                 schema: _,
             } => {
                 for node in contexts {
-                    traverse_and_hash_aexpr(*node, self.expr_arena, state);
+                    if self.stable {
+                        traverse_and_hash_aexpr_stable(*node, self.expr_arena, state);
+                    } else {
+                        traverse_and_hash_aexpr(*node, self.expr_arena, state);
+                    }
                 }
             },
             IR::Sink { input: _, payload } => {
-                payload.hash(state);
+                if self.stable {
+                    hash_sink_stable(payload, state);
+                } else {
+                    payload.hash(state);
+                }
             },
             IR::Cache {
                 input: _,
                 id,
                 cache_hits,
             } => {
-                id.hash(state);
+                // The id is derived from an address.
+                if !self.stable {
+                    id.hash(state);
+                }
                 cache_hits.hash(state);
             },
             IR::Invalid => unreachable!(),
@@ -518,3 +592,113 @@ impl PartialEq for HashableEqLP<'_> {
         }
     }
 }
+
+/// Hash the join options without the row estimates, which are derived from file metadata.
+/// The join type is hashed by name, as its discriminant depends on the enabled features.
+fn hash_join_options_stable<H: Hasher>(options: &JoinOptions, state: &mut H) {
+    let JoinOptions {
+        allow_parallel,
+        force_parallel,
+        args,
+        rows_left: _,
+        rows_right: _,
+    } = options;
+    allow_parallel.hash(state);
+    force_parallel.hash(state);
+
+    args.how.to_string().hash(state);
+    match &args.how {
+        JoinType::Outer { coalesce } => coalesce.hash(state),
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(options) => format!("{options:?}").hash(state),
+        _ => {},
+    }
+    args.validation.hash(state);
+    args.suffix.hash(state);
+    args.slice.hash(state);
+    args.join_nulls.hash(state);
+    args.strategy_hint.map(|hint| hint.to_string()).hash(state);
+}
+
+/// Hash the sink without its cloud options. The file type is hashed by its `Debug`
+/// representation, as its discriminant depends on the enabled features.
+fn hash_sink_stable<H: Hasher>(payload: &SinkType, state: &mut H) {
+    match payload {
+        SinkType::Memory => "memory".hash(state),
+        SinkType::File { path, file_type } => {
+            "file".hash(state);
+            path.hash(state);
+            format!("{file_type:?}").hash(state);
+        },
+        #[cfg(feature = "cloud")]
+        SinkType::Cloud {
+            uri,
+            file_type,
+            cloud_options: _,
+        } => {
+            "cloud".hash(state);
+            uri.hash(state);
+            format!("{file_type:?}").hash(state);
+        },
+        SinkType::Split {
+            predicate,
+            nulls,
+            on_true,
+            on_false,
+        } => {
+            "split".hash(state);
+            predicate.hash(state);
+            nulls.hash(state);
+            for output in [on_true, on_false] {
+                output.path.hash(state);
+                format!("{:?}", output.file_type).hash(state);
+            }
+        },
+    }
+}
+
+/// Version of the [`plan_fingerprint`] algorithm.
+///
+/// This is part of every fingerprint and is bumped whenever the fingerprint of an
+/// existing plan changes, e.g. because the hashing of plan nodes or expressions
+/// changes or new optimizations rewrite plans differently.
+pub const PLAN_FINGERPRINT_VERSION: u32 = 2;
+
+/// Compute a fingerprint of the (optimized) plan rooted at `root`.
+///
+/// The fingerprint covers the structure of the plan, every node's options and
+/// expressions, including literal values, and the identity of the scanned sources
+/// (their paths and scan options). It is computed with a
+/// [`StableHasher`](polars_utils::hashing::StableHasher), so it is equal across
+/// processes and machines for the same plan and Polars version.
+///
+/// It purposely excludes data that isn't stable or isn't part of the query:
+/// - file metadata gathered while planning, such as sizes and modification times,
+/// - cloud options, which includes credentials,
+/// - cache ids, as they are derived from memory addresses,
+/// - the contents of in-memory frames; those are identified by their schema and
+///   height only, so their content should be fingerprinted separately.
+///
+/// User defined functions are only identified by their options and name.
+///
+/// Fingerprints only change together with [`PLAN_FINGERPRINT_VERSION`].
+pub fn plan_fingerprint(root: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> u64 {
+    let mut state = StableHasher::default();
+    PLAN_FINGERPRINT_VERSION.hash(&mut state);
+
+    let mut stack = vec![root];
+    let mut inputs: Vec<Node> = vec![];
+    while let Some(node) = stack.pop() {
+        IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .stable()
+            .hash(&mut state);
+
+        // Hashing the number of inputs makes the pre-order traversal unambiguous.
+        inputs.clear();
+        lp_arena.get(node).copy_inputs(&mut inputs);
+        inputs.len().hash(&mut state);
+        stack.extend(inputs.iter().rev());
+    }
+    state.finish()
+}
@@ -8,6 +8,8 @@ mod lp;
 mod visitors;
 
 pub use expr::*;
+#[cfg(feature = "cse")]
+pub use hash::{plan_fingerprint, PLAN_FINGERPRINT_VERSION};
 pub use lp::*;
 pub use visitors::*;
 
@@ -240,6 +240,8 @@ unsafe fn encode_array(encoder: &Encoder, field: &EncodingField, out: &mut RowsE
                 ArrowDataType::Utf8View => {
                     panic!("should be binview")
                 },
+                // All values are null and equal, so they don't need any bytes.
+                ArrowDataType::Null => {},
                 ArrowDataType::Dictionary(_, _, _) => {
                     let array = array
                         .as_any()
@@ -277,6 +279,7 @@ pub fn encoded_size(data_type: &ArrowDataType) -> usize {
         Float32 => f32::ENCODED_LEN,
         Float64 => f64::ENCODED_LEN,
         Boolean => bool::ENCODED_LEN,
+        Null => 0,
         dt => unimplemented!("{dt:?}"),
     }
 }
@@ -3,6 +3,7 @@ use std::collections::BTreeSet;
 
 use polars_core::prelude::*;
 use polars_error::to_compute_err;
+use polars_io::cloud::CloudOptions;
 use polars_lazy::prelude::*;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
@@ -25,6 +26,7 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     aliases: RefCell<PlHashMap<String, String>>,
+    cloud_options: Option<CloudOptions>,
 }
 
 impl Default for SQLContext {
@@ -34,6 +36,7 @@ impl Default for SQLContext {
             table_map: Default::default(),
             cte_map: Default::default(),
             aliases: Default::default(),
+            cloud_options: None,
         }
     }
 }
@@ -81,6 +84,12 @@ impl SQLContext {
         self.table_map.remove(&name.to_owned());
     }
 
+    /// Set the [`CloudOptions`] used by table functions such as `read_parquet` when they scan
+    /// cloud paths.
+    pub fn set_cloud_options(&mut self, cloud_options: Option<CloudOptions>) {
+        self.cloud_options = cloud_options;
+    }
+
     /// Execute a SQL query, returning a [`LazyFrame`].
     /// ```rust
     /// # use polars_sql::SQLContext;
@@ -653,7 +662,12 @@ impl SQLContext {
     ) -> PolarsResult<(String, LazyFrame)> {
         let tbl_fn = name.0.first().unwrap().value.as_str();
         let read_fn = tbl_fn.parse::<PolarsTableFunctions>()?;
-        let (tbl_name, lf) = read_fn.execute(args)?;
+        let (tbl_name, lf) = read_fn
+            .execute(args, self.cloud_options.as_ref())
+            .map_err(|e| {
+                let args = args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                e.context(format!("in FROM clause: {}({})", tbl_fn, args.join(", ")).into())
+            })?;
         #[allow(clippy::useless_asref)]
         let tbl_name = alias
             .as_ref()
@@ -1,17 +1,13 @@
 use std::str::FromStr;
 
-#[cfg(any(
-    feature = "csv",
-    feature = "parquet",
-    feature = "ipc",
-    feature = "json"
-))]
-use polars_core::prelude::polars_ensure;
-use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, InitHashMaps, PlHashMap, PolarsError, PolarsResult,
+};
+use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_lazy::prelude::LazyCsvReader;
 use polars_lazy::prelude::LazyFrame;
-use sqlparser::ast::{FunctionArg, FunctionArgExpr};
+use sqlparser::ast::{Expr as SQLExpr, FunctionArg, FunctionArgExpr, Value as SQLValue};
 
 /// Table functions that are supported by Polars
 #[allow(clippy::enum_variant_names)]
@@ -19,25 +15,31 @@ pub(crate) enum PolarsTableFunctions {
     /// SQL 'read_csv' function
     /// ```sql
     /// SELECT * FROM read_csv('path/to/file.csv')
+    /// SELECT * FROM read_csv('path/to/*.csv', delimiter => ';', has_header => false)
     /// ```
+    /// Named arguments: `has_header`, `delimiter`, `infer_schema_length`, `n_rows`.
     #[cfg(feature = "csv")]
     ReadCsv,
     /// SQL 'read_parquet' function
     /// ```sql
     /// SELECT * FROM read_parquet('path/to/file.parquet')
+    /// SELECT * FROM read_parquet('s3://bucket/path/*.parquet', hive_partitioning => true)
     /// ```
+    /// Named arguments: `hive_partitioning`, `n_rows`.
     #[cfg(feature = "parquet")]
     ReadParquet,
     /// SQL 'read_ipc' function
     /// ```sql
     /// SELECT * FROM read_ipc('path/to/file.ipc')
     /// ```
+    /// Named arguments: `n_rows`.
     #[cfg(feature = "ipc")]
     ReadIpc,
     /// SQL 'read_json' function. *Only ndjson is currently supported.*
     /// ```sql
     /// SELECT * FROM read_json('path/to/file.json')
     /// ```
+    /// Named arguments: `infer_schema_length`, `n_rows`.
     #[cfg(feature = "json")]
     ReadJson,
 }
@@ -62,15 +64,21 @@ impl FromStr for PolarsTableFunctions {
 }
 
 impl PolarsTableFunctions {
+    /// Execute the table function. `cloud_options` are used by the readers that support cloud
+    /// storage.
     #[allow(unused_variables, unreachable_patterns)]
-    pub(crate) fn execute(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+    pub(crate) fn execute(
+        &self,
+        args: &[FunctionArg],
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<(String, LazyFrame)> {
         match self {
             #[cfg(feature = "csv")]
             PolarsTableFunctions::ReadCsv => self.read_csv(args),
             #[cfg(feature = "parquet")]
-            PolarsTableFunctions::ReadParquet => self.read_parquet(args),
+            PolarsTableFunctions::ReadParquet => self.read_parquet(args, cloud_options),
             #[cfg(feature = "ipc")]
-            PolarsTableFunctions::ReadIpc => self.read_ipc(args),
+            PolarsTableFunctions::ReadIpc => self.read_ipc(args, cloud_options),
             #[cfg(feature = "json")]
             PolarsTableFunctions::ReadJson => self.read_ndjson(args),
             _ => unreachable!(),
@@ -83,25 +91,64 @@ impl PolarsTableFunctions {
 
         use polars_lazy::frame::LazyFileListReader;
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyCsvReader::new(&path).finish()?;
+        let options = NamedArgs::new(
+            "read_csv",
+            &args[1..],
+            &["delimiter", "has_header", "infer_schema_length", "n_rows"],
+        )?;
+        let mut reader = LazyCsvReader::new(&path)
+            .with_n_rows(options.get_usize("n_rows")?)
+            .with_infer_schema_length(options.get_usize("infer_schema_length")?.or(Some(100)));
+        if let Some(has_header) = options.get_bool("has_header")? {
+            reader = reader.has_header(has_header);
+        }
+        if let Some(delimiter) = options.get_char("delimiter")? {
+            reader = reader.with_separator(delimiter);
+        }
+        let lf = reader.finish()?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "parquet")]
-    fn read_parquet(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+    fn read_parquet(
+        &self,
+        args: &[FunctionArg],
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<(String, LazyFrame)> {
         polars_ensure!(!args.is_empty(), ComputeError: "read_parquet expected a path");
 
+        use polars_lazy::prelude::ScanArgsParquet;
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_parquet(&path, Default::default())?;
+        let options = NamedArgs::new("read_parquet", &args[1..], &["hive_partitioning", "n_rows"])?;
+        let mut scan_args = ScanArgsParquet {
+            n_rows: options.get_usize("n_rows")?,
+            cloud_options: cloud_options.cloned(),
+            ..Default::default()
+        };
+        if let Some(enabled) = options.get_bool("hive_partitioning")? {
+            scan_args.hive_options.enabled = enabled;
+        }
+        let lf = LazyFrame::scan_parquet(&path, scan_args)?;
         Ok((path, lf))
     }
 
     #[cfg(feature = "ipc")]
-    fn read_ipc(&self, args: &[FunctionArg]) -> PolarsResult<(String, LazyFrame)> {
+    fn read_ipc(
+        &self,
+        args: &[FunctionArg],
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<(String, LazyFrame)> {
         polars_ensure!(!args.is_empty(), ComputeError: "read_ipc expected a path");
 
+        use polars_lazy::prelude::ScanArgsIpc;
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+        let options = NamedArgs::new("read_ipc", &args[1..], &["n_rows"])?;
+        let scan_args = ScanArgsIpc {
+            n_rows: options.get_usize("n_rows")?,
+            cloud_options: cloud_options.cloned(),
+            ..Default::default()
+        };
+        let lf = LazyFrame::scan_ipc(&path, scan_args)?;
         Ok((path, lf))
     }
     #[cfg(feature = "json")]
@@ -112,13 +159,16 @@ impl PolarsTableFunctions {
         use polars_lazy::prelude::LazyJsonLineReader;
 
         let path = self.get_file_path_from_arg(&args[0])?;
-        let lf = LazyJsonLineReader::new(path.clone()).finish()?;
+        let options = NamedArgs::new("read_json", &args[1..], &["infer_schema_length", "n_rows"])?;
+        let lf = LazyJsonLineReader::new(path.clone())
+            .with_n_rows(options.get_usize("n_rows")?)
+            .with_infer_schema_length(options.get_usize("infer_schema_length")?.or(Some(100)))
+            .finish()?;
         Ok((path, lf))
     }
 
     #[allow(dead_code)]
     fn get_file_path_from_arg(&self, arg: &FunctionArg) -> PolarsResult<String> {
-        use sqlparser::ast::{Expr as SQLExpr, Value as SQLValue};
         match arg {
             FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Value(
                 SQLValue::SingleQuotedString(s),
@@ -131,6 +181,105 @@ impl PolarsTableFunctions {
     }
 }
 
+/// The `name => value` arguments that follow the path of a table function.
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+struct NamedArgs<'a> {
+    function: &'static str,
+    args: PlHashMap<&'a str, &'a SQLExpr>,
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+impl<'a> NamedArgs<'a> {
+    fn new(
+        function: &'static str,
+        args: &'a [FunctionArg],
+        allowed: &[&str],
+    ) -> PolarsResult<Self> {
+        let mut out = PlHashMap::with_capacity(args.len());
+        for arg in args {
+            match arg {
+                FunctionArg::Named {
+                    name,
+                    arg: FunctionArgExpr::Expr(expr),
+                } => {
+                    let name = name.value.as_str();
+                    polars_ensure!(
+                        allowed.contains(&name),
+                        ComputeError: "{} does not support the argument '{}'; supported arguments are {:?}",
+                        function, name, allowed
+                    );
+                    polars_ensure!(
+                        out.insert(name, expr).is_none(),
+                        ComputeError: "{} got the argument '{}' more than once", function, name
+                    );
+                },
+                _ => polars_bail!(
+                    ComputeError:
+                    "{} only accepts named arguments after the path, e.g. `name => value`; received: {}",
+                    function, arg
+                ),
+            }
+        }
+        Ok(Self {
+            function,
+            args: out,
+        })
+    }
+
+    fn invalid(&self, name: &str, expected: &str) -> PolarsError {
+        polars_err!(
+            ComputeError: "{} expected {} for the argument '{}'; received: {}",
+            self.function, expected, name, self.args[name]
+        )
+    }
+
+    #[cfg(any(feature = "csv", feature = "parquet"))]
+    fn get_bool(&self, name: &str) -> PolarsResult<Option<bool>> {
+        self.args
+            .get(name)
+            .map(|expr| match expr {
+                SQLExpr::Value(SQLValue::Boolean(b)) => Ok(*b),
+                _ => Err(self.invalid(name, "a boolean")),
+            })
+            .transpose()
+    }
+
+    fn get_usize(&self, name: &str) -> PolarsResult<Option<usize>> {
+        self.args
+            .get(name)
+            .map(|expr| match expr {
+                SQLExpr::Value(SQLValue::Number(n, _)) => n
+                    .parse::<usize>()
+                    .map_err(|_| self.invalid(name, "a non-negative integer")),
+                _ => Err(self.invalid(name, "a non-negative integer")),
+            })
+            .transpose()
+    }
+
+    #[cfg(feature = "csv")]
+    fn get_char(&self, name: &str) -> PolarsResult<Option<u8>> {
+        self.args
+            .get(name)
+            .map(|expr| match expr {
+                SQLExpr::Value(SQLValue::SingleQuotedString(s)) if s.len() == 1 => {
+                    Ok(s.as_bytes()[0])
+                },
+                _ => Err(self.invalid(name, "a single character string")),
+            })
+            .transpose()
+    }
+}
+
 impl PolarsTableFunctions {
     // list sql names of all table functions
     pub(crate) fn keywords() -> &'static [&'static str] {
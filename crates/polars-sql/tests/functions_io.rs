@@ -1,8 +1,8 @@
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_core::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_lazy::prelude::*;
-#[cfg(any(feature = "csv", feature = "ipc"))]
+#[cfg(any(feature = "csv", feature = "ipc", feature = "parquet"))]
 use polars_sql::*;
 
 #[test]
@@ -109,3 +109,94 @@ fn read_ipc_tbl() {
     assert_eq!(df_2.height(), 27);
     assert_eq!(df_2.width(), 4);
 }
+
+#[cfg(any(feature = "csv", feature = "parquet"))]
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("polars-sql-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_named_args() {
+    let dir = temp_dir("csv-named-args");
+    let path = dir.join("data.csv");
+    std::fs::write(&path, "1;a\n2;b\n3;c\n").unwrap();
+
+    let mut context = SQLContext::new();
+    let sql = format!(
+        "SELECT * FROM read_csv('{}', delimiter => ';', has_header => false, n_rows => 2)",
+        path.display()
+    );
+    let df_sql = context.execute(&sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "column_1" => [1i64, 2],
+        "column_2" => ["a", "b"],
+    }
+    .unwrap();
+    assert!(df_sql.equals(&expected));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn read_csv_tbl_func_invalid_args() {
+    let mut context = SQLContext::new();
+    let err = context
+        .execute("SELECT * FROM read_csv('../../examples/datasets/foods1.csv', sep => ';')")
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(err.contains("does not support the argument 'sep'"), "{err}");
+    assert!(err.contains("in FROM clause: read_csv"), "{err}");
+
+    let err = context
+        .execute("SELECT * FROM read_csv('../../examples/datasets/foods1.csv', delimiter => ';;')")
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(err.contains("expected a single character string"), "{err}");
+
+    // Scan errors are reported with the table function call.
+    let err = context
+        .execute("SELECT * FROM read_csv('/does/not/exist/*.csv')")
+        .err()
+        .unwrap()
+        .to_string();
+    assert!(err.contains("read_csv('/does/not/exist/*.csv')"), "{err}");
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn read_parquet_tbl_func_named_args() {
+    use polars_io::prelude::ParquetWriter;
+
+    let dir = temp_dir("parquet-hive");
+    for (part, values) in [(1, [1i32, 2]), (2, [3, 4])] {
+        let part_dir = dir.join(format!("part={part}"));
+        std::fs::create_dir_all(&part_dir).unwrap();
+        let mut df = df! { "value" => values }.unwrap();
+        let file = std::fs::File::create(part_dir.join("data.parquet")).unwrap();
+        ParquetWriter::new(file).finish(&mut df).unwrap();
+    }
+
+    let mut context = SQLContext::new();
+    let sql = format!(
+        "SELECT * FROM read_parquet('{}/**/*.parquet', hive_partitioning => true) ORDER BY value",
+        dir.display()
+    );
+    let df_sql = context.execute(&sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.get_column_names(), &["value", "part"]);
+    assert_eq!(df_sql.height(), 4);
+
+    let sql = format!(
+        "SELECT * FROM read_parquet('{}/**/*.parquet', hive_partitioning => false, n_rows => 3)",
+        dir.display()
+    );
+    let df_sql = context.execute(&sql).unwrap().collect().unwrap();
+    assert_eq!(df_sql.get_column_names(), &["value"]);
+    assert_eq!(df_sql.height(), 3);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
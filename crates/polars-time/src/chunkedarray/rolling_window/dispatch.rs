@@ -96,6 +96,15 @@ where
     Series::try_from((ca.name(), arr))
 }
 
+/// Pass the `precise` option to the sum kernels, which `rolling_mean` uses as well.
+#[cfg(feature = "rolling_window")]
+fn with_sum_params(mut options: RollingOptionsImpl) -> RollingOptionsImpl {
+    if options.precise {
+        options.fn_params = Some(Arc::new(rolling::RollingSumParams { precise: true }));
+    }
+    options
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series.
     ///
@@ -107,7 +116,7 @@ pub trait SeriesOpsTime: AsSeries {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
             rolling_agg(
                 ca,
-                options,
+                with_sum_params(options),
                 &rolling::no_nulls::rolling_mean,
                 &rolling::nulls::rolling_mean,
                 Some(&super::rolling_kernels::no_nulls::rolling_mean),
@@ -119,6 +128,10 @@ pub trait SeriesOpsTime: AsSeries {
     fn rolling_sum(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
         let mut s = self.as_series().clone();
         if options.weights.is_some() {
+            // With weights that sum to one, the weighted sum is the weighted mean.
+            if options.normalize_weights {
+                return self.rolling_mean(options);
+            }
             s = s.to_float()?;
         }
 
@@ -126,7 +139,7 @@ pub trait SeriesOpsTime: AsSeries {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
             rolling_agg(
                 ca,
-                options,
+                with_sum_params(options),
                 &rolling::no_nulls::rolling_sum,
                 &rolling::nulls::rolling_sum,
                 Some(&super::rolling_kernels::no_nulls::rolling_sum),
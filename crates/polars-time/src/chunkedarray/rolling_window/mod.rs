@@ -20,6 +20,16 @@ pub struct RollingOptions {
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
     pub weights: Option<Vec<f64>>,
+    /// Scale the weights of the valid values in every window to sum to one.
+    ///
+    /// The weighted mean, variance and standard deviation don't depend on the scale of the
+    /// weights, so this only changes the weighted sum, which becomes the weighted mean.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub normalize_weights: bool,
+    /// Sum float windows with compensated summation in `rolling_sum` and `rolling_mean`. This
+    /// keeps long inputs with mixed magnitudes accurate at the cost of some speed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub precise: bool,
     /// Set the labels at the center of the window.
     pub center: bool,
     /// Compute the rolling aggregates with a window defined by a time column
@@ -39,6 +49,8 @@ impl Default for RollingOptions {
             window_size: Duration::parse("3i"),
             min_periods: 1,
             weights: None,
+            normalize_weights: false,
+            precise: false,
             center: false,
             by: None,
             closed_window: None,
@@ -54,6 +66,8 @@ impl PartialEq for RollingOptions {
         self.window_size == other.window_size
             && self.min_periods == other.min_periods
             && self.weights == other.weights
+            && self.normalize_weights == other.normalize_weights
+            && self.precise == other.precise
             && self.center == other.center
             && self.by == other.by
             && self.closed_window == other.closed_window
@@ -71,6 +85,10 @@ pub struct RollingOptionsImpl<'a> {
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
     pub weights: Option<Vec<f64>>,
+    /// Scale the weights of the valid values in every window to sum to one.
+    pub normalize_weights: bool,
+    /// Sum float windows with compensated summation.
+    pub precise: bool,
     /// Set the labels at the center of the window.
     pub center: bool,
     pub by: Option<&'a [i64]>,
@@ -99,6 +117,8 @@ impl TryFrom<RollingOptions> for RollingOptionsImpl<'static> {
             window_size,
             min_periods: options.min_periods,
             weights: options.weights,
+            normalize_weights: options.normalize_weights,
+            precise: options.precise,
             center: options.center,
             by: None,
             tu: None,
@@ -115,6 +135,8 @@ impl Default for RollingOptionsImpl<'static> {
             window_size: Duration::parse("3i"),
             min_periods: 1,
             weights: None,
+            normalize_weights: false,
+            precise: false,
             center: false,
             by: None,
             tu: None,
@@ -140,12 +162,14 @@ impl<'a> TryFrom<RollingOptionsImpl<'a>> for RollingOptionsFixedWindow {
         );
         let window_size = window_size.nanoseconds() as usize;
         check_input(window_size, options.min_periods)?;
+        check_weights(options.weights.as_deref(), window_size)?;
 
         Ok(RollingOptionsFixedWindow {
             window_size,
             min_periods: options.min_periods,
             weights: options.weights,
             center: options.center,
+            precise: options.precise,
             fn_params: options.fn_params,
         })
     }
@@ -159,3 +183,15 @@ fn check_input(window_size: usize, min_periods: usize) -> PolarsResult<()> {
     );
     Ok(())
 }
+
+/// utility
+fn check_weights(weights: Option<&[f64]>, window_size: usize) -> PolarsResult<()> {
+    if let Some(weights) = weights {
+        polars_ensure!(
+            weights.len() == window_size,
+            InvalidOperation: "the number of `weights` ({}) should equal `window_size` ({})",
+            weights.len(), window_size
+        );
+    }
+    Ok(())
+}
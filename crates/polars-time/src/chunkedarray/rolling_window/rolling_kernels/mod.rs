@@ -1,6 +1,6 @@
 pub(super) mod no_nulls;
 use std::fmt::Debug;
-use std::ops::{AddAssign, Mul, SubAssign};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
 use arrow::array::{ArrayRef, PrimitiveArray};
 use arrow::trusted_len::TrustedLen;
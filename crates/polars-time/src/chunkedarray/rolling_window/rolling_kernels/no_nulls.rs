@@ -103,17 +103,28 @@ pub(crate) fn rolling_sum<T>(
     min_periods: usize,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
-    _params: DynArgs,
+    params: DynArgs,
 ) -> PolarsResult<ArrayRef>
 where
-    T: NativeType + std::iter::Sum + NumCast + Mul<Output = T> + AddAssign + SubAssign + IsFloat,
+    T: NativeType
+        + std::iter::Sum
+        + NumCast
+        + Mul<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + IsFloat,
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
         Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
-    rolling_apply_agg_window::<no_nulls::SumWindow<_>, _, _>(values, offset_iter, min_periods, None)
+    rolling_apply_agg_window::<no_nulls::SumWindow<_>, _, _>(
+        values,
+        offset_iter,
+        min_periods,
+        params,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -125,7 +136,7 @@ pub(crate) fn rolling_mean<T>(
     min_periods: usize,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
-    _params: DynArgs,
+    params: DynArgs,
 ) -> PolarsResult<ArrayRef>
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
@@ -139,7 +150,7 @@ where
         values,
         offset_iter,
         min_periods,
-        None,
+        params,
     )
 }
 
@@ -352,6 +352,176 @@ pub trait StringMethods: AsString {
             }
         }
     }
+
+    #[cfg(feature = "dtype-date")]
+    /// Parse string values to a [`DateChunked`] with the first of `formats` that matches.
+    ///
+    /// The formats are tried in order for every value, in a single pass over the data.
+    /// Values that match none of them become null.
+    fn as_date_formats(&self, formats: &[&str], use_cache: bool) -> PolarsResult<DateChunked> {
+        let string_ca = self.as_string();
+        let formats = CompiledFormats::new(formats)?;
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let mut strptime_cache = StrpTimeState::default();
+        let mut convert = FastCachedFunc::new(
+            |s: &str| {
+                formats
+                    .parse(
+                        s,
+                        &mut strptime_cache,
+                        |s, fmt| NaiveDate::parse_from_str(s, fmt).ok(),
+                        |ndt| ndt.date(),
+                    )
+                    .map(naive_date_to_date)
+            },
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        let ca = string_ca.apply_generic(|opt_s| convert.eval(opt_s?, use_cache));
+        Ok(ca.with_name(string_ca.name()).into())
+    }
+
+    #[cfg(feature = "dtype-datetime")]
+    /// Parse string values to a [`DatetimeChunked`] with the first of `formats` that matches.
+    ///
+    /// The formats are tried in order for every value, in a single pass over the data.
+    /// Values that match none of them become null. Either all or none of the formats must
+    /// be time-zone-aware, as set by `tz_aware`.
+    fn as_datetime_formats(
+        &self,
+        formats: &[&str],
+        tu: TimeUnit,
+        use_cache: bool,
+        tz_aware: bool,
+        tz: Option<&TimeZone>,
+        ambiguous: &StringChunked,
+    ) -> PolarsResult<DatetimeChunked> {
+        let string_ca = self.as_string();
+        let formats = CompiledFormats::new(formats)?;
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let func = match tu {
+            TimeUnit::Nanoseconds => datetime_to_timestamp_ns,
+            TimeUnit::Microseconds => datetime_to_timestamp_us,
+            TimeUnit::Milliseconds => datetime_to_timestamp_ms,
+        };
+
+        if tz_aware {
+            #[cfg(feature = "timezones")]
+            {
+                let mut convert = FastCachedFunc::new(
+                    |s: &str| {
+                        formats.formats.iter().find_map(|fmt| {
+                            let dt = DateTime::parse_from_str(s, fmt).ok()?;
+                            Some(func(dt.naive_utc()))
+                        })
+                    },
+                    (string_ca.len() as f64).sqrt() as usize,
+                );
+                Ok(string_ca
+                    .apply_generic(|opt_s| convert.eval(opt_s?, use_cache))
+                    .with_name(string_ca.name())
+                    .into_datetime(tu, Some("UTC".to_string())))
+            }
+            #[cfg(not(feature = "timezones"))]
+            {
+                panic!("activate 'timezones' feature")
+            }
+        } else {
+            let transform = match tu {
+                TimeUnit::Nanoseconds => infer::transform_datetime_ns,
+                TimeUnit::Microseconds => infer::transform_datetime_us,
+                TimeUnit::Milliseconds => infer::transform_datetime_ms,
+            };
+            let mut strptime_cache = StrpTimeState::default();
+            let mut convert = FastCachedFunc::new(
+                |s: &str| formats.parse(s, &mut strptime_cache, transform, func),
+                (string_ca.len() as f64).sqrt() as usize,
+            );
+            let dt = string_ca
+                .apply_generic(|opt_s| convert.eval(opt_s?, use_cache))
+                .with_name(string_ca.name())
+                .into_datetime(tu, None);
+            match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => polars_ops::prelude::replace_time_zone(
+                    &dt,
+                    Some(tz),
+                    ambiguous,
+                    NonExistent::Raise,
+                ),
+                _ => Ok(dt),
+            }
+        }
+    }
+
+    #[cfg(feature = "dtype-time")]
+    /// Parse string values to a [`TimeChunked`] with the first of `formats` that matches.
+    ///
+    /// Values that match none of the formats become null.
+    fn as_time_formats(&self, formats: &[&str], use_cache: bool) -> PolarsResult<TimeChunked> {
+        let string_ca = self.as_string();
+        let use_cache = use_cache && string_ca.len() > 50;
+
+        let mut convert = FastCachedFunc::new(
+            |s: &str| {
+                formats.iter().find_map(|fmt| {
+                    let naive_time = NaiveTime::parse_from_str(s, fmt).ok()?;
+                    Some(time_to_time64ns(&naive_time))
+                })
+            },
+            (string_ca.len() as f64).sqrt() as usize,
+        );
+        let ca = string_ca.apply_generic(|opt_s| convert.eval(opt_s?, use_cache));
+        Ok(ca.with_name(string_ca.name()).into())
+    }
+}
+
+/// Format strings that are compiled once and then tried in order on every value.
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+struct CompiledFormats {
+    formats: Vec<String>,
+    /// Lengths for the fast parser, `None` if a format needs chrono.
+    fmt_lens: Vec<Option<u16>>,
+}
+
+#[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+impl CompiledFormats {
+    fn new(formats: &[&str]) -> PolarsResult<Self> {
+        let formats = formats
+            .iter()
+            .map(|fmt| strptime::compile_fmt(fmt))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let fmt_lens = formats
+            .iter()
+            .map(|fmt| strptime::fmt_len(fmt.as_bytes()))
+            .collect();
+        Ok(Self { formats, fmt_lens })
+    }
+
+    /// Parse `s` with the first matching format. The fast parser is tried first for every
+    /// format, `fallback` parses with chrono and `convert` maps a fast parser result.
+    fn parse<T>(
+        &self,
+        s: &str,
+        strptime_cache: &mut StrpTimeState,
+        fallback: impl Fn(&str, &str) -> Option<T>,
+        convert: impl Fn(NaiveDateTime) -> T,
+    ) -> Option<T> {
+        self.formats
+            .iter()
+            .zip(&self.fmt_lens)
+            .find_map(|(fmt, fmt_len)| {
+                // SAFETY: fmt_len is correct, it was computed with this `fmt` str.
+                let fast = fmt_len.and_then(|fmt_len| unsafe {
+                    strptime_cache.parse(s.as_bytes(), fmt.as_bytes(), fmt_len)
+                });
+                match fast {
+                    Some(ndt) => Some(convert(ndt)),
+                    None => fallback(s, fmt),
+                }
+            })
+    }
 }
 
 pub trait AsString {
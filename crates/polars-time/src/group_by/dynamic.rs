@@ -86,6 +86,13 @@ fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Integer index columns reuse the temporal window logic by interpreting the values as nanosecond
+/// timestamps, so that `every`, `period` and `offset` are expressed in index units.
+fn integer_index_to_datetime(time: &Series) -> PolarsResult<Series> {
+    time.strict_cast(&DataType::Int64)?
+        .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+}
+
 const LB_NAME: &str = "_lower_boundary";
 const UP_NAME: &str = "_upper_boundary";
 
@@ -152,9 +159,9 @@ impl Wrap<&DataFrame> {
                 TimeUnit::Milliseconds,
                 None,
             ),
-            UInt32 | UInt64 | Int32 => {
+            Int32 | Int64 | UInt32 | UInt64 => {
                 let time_type_dt = Datetime(TimeUnit::Nanoseconds, None);
-                let dt = time.cast(&Int64).unwrap().cast(&time_type_dt).unwrap();
+                let dt = integer_index_to_datetime(&time)?;
                 let (out, by, gt) = self.impl_rolling(
                     dt,
                     group_by,
@@ -166,20 +173,6 @@ impl Wrap<&DataFrame> {
                 let out = out.cast(&Int64).unwrap().cast(time_type).unwrap();
                 return Ok((out, by, gt));
             },
-            Int64 => {
-                let time_type = Datetime(TimeUnit::Nanoseconds, None);
-                let dt = time.cast(&time_type).unwrap();
-                let (out, by, gt) = self.impl_rolling(
-                    dt,
-                    group_by,
-                    options,
-                    TimeUnit::Nanoseconds,
-                    None,
-                    &time_type,
-                )?;
-                let out = out.cast(&Int64).unwrap();
-                return Ok((out, by, gt));
-            },
             dt => polars_bail!(
                 ComputeError:
                 "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64, UInt32, UInt64 }}, got {}",
@@ -221,45 +214,36 @@ impl Wrap<&DataFrame> {
                 time.cast(&Datetime(TimeUnit::Milliseconds, None))?,
                 TimeUnit::Milliseconds,
             ),
-            Int32 => {
-                let time_type = Datetime(TimeUnit::Nanoseconds, None);
-                let dt = time.cast(&Int64).unwrap().cast(&time_type).unwrap();
-                let (out, mut keys, gt) = self.impl_group_by_dynamic(
-                    dt,
-                    group_by,
-                    options,
-                    TimeUnit::Nanoseconds,
-                    &time_type,
-                )?;
-                let out = out.cast(&Int64).unwrap().cast(&Int32).unwrap();
-                for k in &mut keys {
-                    if k.name() == UP_NAME || k.name() == LB_NAME {
-                        *k = k.cast(&Int64).unwrap().cast(&Int32).unwrap()
-                    }
-                }
-                return Ok((out, keys, gt));
-            },
-            Int64 => {
-                let time_type = Datetime(TimeUnit::Nanoseconds, None);
-                let dt = time.cast(&time_type).unwrap();
+            Int32 | Int64 | UInt32 | UInt64 => {
+                polars_ensure!(
+                    options.start_by.weekday().is_none(),
+                    InvalidOperation: "`start_by={:?}` is only supported for temporal index columns, got {}",
+                    options.start_by, time_type
+                );
+                polars_ensure!(
+                    !(time_type.is_unsigned_integer() && options.offset.negative() && !options.offset.is_zero()),
+                    InvalidOperation: "a negative `offset` is not supported for an index column of dtype {}", time_type
+                );
+                let time_type_dt = Datetime(TimeUnit::Nanoseconds, None);
+                let dt = integer_index_to_datetime(&time)?;
                 let (out, mut keys, gt) = self.impl_group_by_dynamic(
                     dt,
                     group_by,
                     options,
                     TimeUnit::Nanoseconds,
-                    &time_type,
+                    &time_type_dt,
                 )?;
-                let out = out.cast(&Int64).unwrap();
+                let out = out.cast(&Int64).unwrap().cast(time_type).unwrap();
                 for k in &mut keys {
                     if k.name() == UP_NAME || k.name() == LB_NAME {
-                        *k = k.cast(&Int64).unwrap()
+                        *k = k.cast(&Int64).unwrap().cast(time_type).unwrap()
                     }
                 }
                 return Ok((out, keys, gt));
             },
             dt => polars_bail!(
                 ComputeError:
-                "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64 }}, got {}",
+                "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64, UInt32, UInt64 }}, got {}",
                 dt
             ),
         };
@@ -113,3 +113,96 @@ impl<T: DirtyHash> DirtyHash for Option<T> {
         self.as_ref().map(|s| s.dirty_hash()).unwrap_or(0)
     }
 }
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// A [`Hasher`] whose output only depends on the bytes written to it.
+///
+/// Unlike the randomly seeded hashers used for hash tables, this produces the same
+/// output across processes, machines and releases, which makes it suitable for
+/// fingerprints that are persisted. Integers are written in little endian and
+/// `usize`/`isize` are widened to 64 bits, so the output is platform independent.
+///
+/// This is 64-bit FNV-1a, it is neither fast on long inputs nor collision resistant
+/// against adversarial inputs.
+#[derive(Clone, Copy, Debug)]
+pub struct StableHasher {
+    state: u64,
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+macro_rules! impl_stable_write {
+    ($($method:ident: $T:ty),*) => {
+        $(
+            #[inline]
+            fn $method(&mut self, i: $T) {
+                self.write(&i.to_le_bytes())
+            }
+        )*
+    };
+}
+
+impl Hasher for StableHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.state ^= *b as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    impl_stable_write!(
+        write_u16: u16,
+        write_u32: u32,
+        write_u64: u64,
+        write_u128: u128,
+        write_i16: i16,
+        write_i32: i32,
+        write_i64: i64,
+        write_i128: i128
+    );
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stable_hasher() {
+        // Known FNV-1a test vector.
+        let mut h = StableHasher::default();
+        h.write(b"a");
+        assert_eq!(h.finish(), 0xaf63dc4c8601ec8c);
+
+        // Integers are little endian and pointer sized integers are widened.
+        let mut a = StableHasher::default();
+        a.write_usize(1);
+        let mut b = StableHasher::default();
+        b.write_u64(1);
+        assert_eq!(a.finish(), b.finish());
+        assert_eq!(a.finish(), 0x89cd31291d2aefa4);
+    }
+}
@@ -206,6 +206,8 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `xlsx` - Write Excel xlsx files
+//!     - `adbc` - Read query results through an ADBC database driver
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -262,6 +264,7 @@
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
 //!     - `list_count` - Count elements in lists.
 //!     - `list_eval` - Apply expressions over list elements.
+//!     - `map_elements` - Apply a closure to every element of an expression.
 //!     - `list_sets` - Compute UNION, INTERSECTION, and DIFFERENCE on list types.
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
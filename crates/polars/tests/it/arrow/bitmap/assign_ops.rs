@@ -76,3 +76,57 @@ proptest! {
         assert_eq!(b, not_b);
     }
 }
+
+/// Returns two equally long slices of the same random bits at independent offsets, as a
+/// [`MutableBitmap`] and a sliced [`Bitmap`].
+fn offset_pair_strategy() -> impl Strategy<Value = (MutableBitmap, Bitmap)> {
+    prop::collection::vec(any::<bool>(), 1..1000)
+        .prop_flat_map(|vec| {
+            let len = vec.len();
+            (Just(vec), 0..len, 0..len)
+        })
+        .prop_flat_map(|(vec, lhs_offset, rhs_offset)| {
+            let max_len = vec.len() - lhs_offset.max(rhs_offset);
+            (Just(vec), Just(lhs_offset), Just(rhs_offset), 0..=max_len)
+        })
+        .prop_map(|(vec, lhs_offset, rhs_offset, len)| {
+            let lhs = MutableBitmap::from(&vec[lhs_offset..lhs_offset + len]);
+            let rhs = Bitmap::from(&vec).sliced(rhs_offset, len);
+            (lhs, rhs)
+        })
+}
+
+proptest! {
+    /// Asserts that the in-place operations equal their functional counterparts
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn in_place_ops((lhs, rhs) in offset_pair_strategy()) {
+        let lhs_bitmap: Bitmap = lhs.clone().into();
+
+        let mut and = lhs.clone();
+        and.and_assign(&rhs);
+        assert_eq!(Bitmap::from(and), &lhs_bitmap & &rhs);
+
+        let mut or = lhs.clone();
+        or.or_assign(&rhs);
+        assert_eq!(Bitmap::from(or), &lhs_bitmap | &rhs);
+
+        let mut xor = lhs;
+        xor.xor_assign(&rhs);
+        assert_eq!(Bitmap::from(xor), &lhs_bitmap ^ &rhs);
+    }
+}
+
+#[test]
+fn in_place_ops_fast_paths() {
+    let rhs = Bitmap::from_iter(std::iter::repeat(true).take(70)).sliced(3, 65);
+    let mut b = MutableBitmap::from_iter((0..65).map(|i| i % 3 == 0));
+    b.and_assign(&rhs);
+    assert_eq!(b, MutableBitmap::from_iter((0..65).map(|i| i % 3 == 0)));
+    b.or_assign(&rhs);
+    assert_eq!(b, MutableBitmap::from_len_set(65));
+
+    let rhs = Bitmap::new_zeroed(65);
+    b.and_assign(&rhs);
+    assert_eq!(b, MutableBitmap::from_len_zeroed(65));
+}
@@ -68,3 +68,83 @@ fn from_arrow() {
     let back = NullBuffer::from(bitmap);
     assert_eq!(nulls, back);
 }
+
+#[test]
+fn intersects_with_prefix() {
+    let mut lhs = vec![false; 200];
+    let mut rhs = vec![false; 200];
+    lhs[150] = true;
+    rhs[150] = true;
+    let lhs = Bitmap::from_iter(lhs);
+    let rhs = Bitmap::from_iter(rhs);
+
+    // Overlap only beyond the prefix.
+    assert!(!lhs.intersects_with_prefix(&rhs, 150));
+    // Overlap within the prefix.
+    assert!(lhs.intersects_with_prefix(&rhs, 151));
+    assert!(lhs.intersects_with_prefix(&rhs, 1000));
+
+    // Respects offsets on both sides.
+    let lhs = lhs.sliced(3, 190);
+    let rhs = rhs.sliced(10, 190);
+    assert!(!lhs.intersects_with_prefix(&rhs, 190));
+    let rhs = Bitmap::from_iter((0..200).map(|i| i == 147)).sliced(0, 190);
+    assert!(!lhs.intersects_with_prefix(&rhs, 147));
+    assert!(lhs.intersects_with_prefix(&rhs, 148));
+}
+
+#[test]
+fn resize() {
+    let values = (0..40).map(|i| i % 3 != 0).collect::<Vec<_>>();
+    let bitmap = Bitmap::from_iter(values.iter().copied()).sliced(5, 30);
+    let expected = &values[5..35];
+    assert_eq!(
+        bitmap.unset_bits(),
+        expected.iter().filter(|v| !**v).count()
+    );
+
+    // Truncate.
+    let truncated = bitmap.clone().resize(17);
+    assert_eq!(truncated.len(), 17);
+    assert!(truncated.iter().eq(expected[..17].iter().copied()));
+    assert_eq!(
+        truncated.unset_bits(),
+        expected[..17].iter().filter(|v| !**v).count()
+    );
+
+    // Extend.
+    let extended = bitmap.clone().resize(45);
+    assert_eq!(extended.len(), 45);
+    assert_eq!(extended.get_bit(29), expected[29]);
+    assert!(!extended.get_bit(30));
+    assert!(extended.iter().take(30).eq(expected.iter().copied()));
+    assert!(extended.iter().skip(30).all(|v| !v));
+    assert_eq!(extended.lazy_unset_bits(), Some(bitmap.unset_bits() + 15));
+    assert_eq!(
+        extended.unset_bits(),
+        extended.iter().filter(|v| !*v).count()
+    );
+
+    assert_eq!(bitmap.clone().resize(30), bitmap);
+}
+
+#[test]
+fn prefix_popcount() {
+    let values = (0..300)
+        .map(|i| i % 3 == 0 || i % 7 == 0)
+        .collect::<Vec<_>>();
+    let bitmap = Bitmap::from(values.as_slice()).sliced(5, 290);
+    let prefix = bitmap.prefix_popcount();
+    assert_eq!(prefix.len(), 290);
+
+    for k in 0..=bitmap.len() {
+        let expected = bitmap.iter().take(k).filter(|v| *v).count();
+        assert_eq!(prefix.rank(k), expected);
+        assert_eq!(prefix.rank(k), k - bitmap.null_count_range(0, k));
+        assert_eq!(prefix.rank_zeros(k), k - expected);
+    }
+
+    let prefix = Bitmap::new().prefix_popcount();
+    assert!(prefix.is_empty());
+    assert_eq!(prefix.rank(0), 0);
+}
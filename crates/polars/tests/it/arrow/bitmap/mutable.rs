@@ -208,6 +208,22 @@ fn extend_offset() {
     }
 }
 
+#[test]
+fn extend_from_slice_of_bools() {
+    let values = (0..515).map(|i| i % 7 == 0).collect::<Vec<_>>();
+
+    let mut b = MutableBitmap::new();
+    b.push(true);
+    b.extend_from_slice_of_bools(&values);
+    b.extend_from_slice_of_bools(&[]);
+
+    assert_eq!(b.len(), 516);
+    let b: Bitmap = b.into();
+    let mut iter = b.iter();
+    assert!(iter.next().unwrap());
+    assert!(iter.eq(values.iter().copied()));
+}
+
 #[test]
 fn set() {
     let mut bitmap = MutableBitmap::from_len_zeroed(12);
@@ -0,0 +1,109 @@
+use polars_core::functions::*;
+
+use super::*;
+
+#[cfg(all(feature = "diagonal_concat", feature = "dtype-struct"))]
+fn struct_series(name: &str, fields: &[Series]) -> Series {
+    StructChunked::new(name, fields).unwrap().into_series()
+}
+
+#[test]
+fn test_concat_df_vertical_relaxed() -> PolarsResult<()> {
+    let a = df![
+        "int" => [1i32, 2],
+        "float" => [1.5f32, 2.5],
+    ]?;
+    let b = df![
+        "int" => [3i64],
+        "float" => [3.5f64],
+    ]?;
+
+    let out = concat_df_vertical_relaxed(&[a.clone(), b])?;
+    let expected = df![
+        "int" => [1i64, 2, 3],
+        "float" => [1.5f64, 2.5, 3.5],
+    ]?;
+    assert!(out.equals(&expected));
+    assert_eq!(out.schema(), expected.schema());
+
+    // The column names must match.
+    let c = df!["other" => [1i32], "float" => [1.0f32]]?;
+    assert!(concat_df_vertical_relaxed(&[a.clone(), c]).is_err());
+
+    let d = df![
+        "int" => [true],
+        "float" => Series::new("float", ["x"]).cast(&DataType::Binary)?,
+    ]?;
+    let err = concat_df_vertical_relaxed(&[a, d]).unwrap_err().to_string();
+    assert!(err.contains("'float'"), "{err}");
+    assert!(err.contains("f32") && err.contains("binary"), "{err}");
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "diagonal_concat", feature = "dtype-struct"))]
+fn test_concat_df_diagonal_relaxed_nested_structs() -> PolarsResult<()> {
+    let a = DataFrame::new(vec![
+        Series::new("a", [1i32, 2]),
+        struct_series(
+            "s",
+            &[
+                Series::new("x", [1i32, 2]),
+                struct_series("inner", &[Series::new("p", [10i32, 20])]),
+            ],
+        ),
+    ])?;
+    let b = DataFrame::new(vec![
+        struct_series(
+            "s",
+            &[
+                Series::new("x", [3.5f64]),
+                Series::new("y", ["y"]),
+                struct_series(
+                    "inner",
+                    &[Series::new("p", [30i64]), Series::new("q", [true])],
+                ),
+            ],
+        ),
+        Series::new("a", [3.5f64]),
+        Series::new("c", ["c"]),
+    ])?;
+
+    let out = concat_df_diagonal_relaxed(&[a, b])?;
+    assert_eq!(out.get_column_names(), &["a", "s", "c"]);
+    assert_eq!(out.column("a")?.dtype(), &DataType::Float64);
+    let inner = DataType::Struct(vec![
+        Field::new("p", DataType::Int64),
+        Field::new("q", DataType::Boolean),
+    ]);
+    assert_eq!(
+        out.column("s")?.dtype(),
+        &DataType::Struct(vec![
+            Field::new("x", DataType::Float64),
+            Field::new("y", DataType::String),
+            Field::new("inner", inner),
+        ])
+    );
+
+    let s = out.column("s")?.struct_()?;
+    assert_eq!(
+        Vec::from(s.field_by_name("x")?.f64()?),
+        &[Some(1.0), Some(2.0), Some(3.5)]
+    );
+    assert_eq!(
+        Vec::from(s.field_by_name("y")?.str()?),
+        &[None, None, Some("y")]
+    );
+    let inner = s.field_by_name("inner")?;
+    let inner = inner.struct_()?;
+    assert_eq!(
+        Vec::from(inner.field_by_name("p")?.i64()?),
+        &[Some(10), Some(20), Some(30)]
+    );
+    assert_eq!(
+        Vec::from(inner.field_by_name("q")?.bool()?),
+        &[None, None, Some(true)]
+    );
+    assert_eq!(Vec::from(out.column("c")?.str()?), &[None, None, Some("c")]);
+    Ok(())
+}
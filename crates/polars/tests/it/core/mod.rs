@@ -1,3 +1,4 @@
+mod concat;
 mod date_like;
 mod group_by;
 mod joins;
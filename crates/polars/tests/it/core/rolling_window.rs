@@ -192,6 +192,127 @@ fn test_rolling_map() {
         Vec::from(out),
         &[None, None, Some(3.0), None, None, None, None,]
     );
+
+    // The windows at the start are shorter than the weights.
+    let out = ca
+        .rolling_map(
+            &|s| s.sum_as_series().unwrap(),
+            RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                weights: Some(vec![1.0, 2.0, 3.0]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(out.f64().unwrap()),
+        &[
+            Some(0.0),
+            Some(2.0),
+            Some(8.0),
+            Some(5.0),
+            Some(2.0),
+            Some(15.0),
+            Some(28.0)
+        ]
+    );
+}
+
+#[test]
+fn test_rolling_apply_indexed() {
+    let ca = Int32Chunked::new(
+        "foo",
+        &[Some(0), Some(1), Some(2), None, Some(4), Some(5), Some(6)],
+    );
+    let start_index = |start: usize, _: &Series| Some(start as i32);
+
+    let out = ca
+        .rolling_apply_indexed(
+            start_index,
+            RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out),
+        &[
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4)
+        ]
+    );
+
+    // `min_periods` suppresses the early windows and the windows with a null.
+    let out = ca
+        .rolling_apply_indexed(
+            start_index,
+            RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out),
+        &[None, None, Some(0), None, None, None, Some(4)]
+    );
+
+    let out = ca
+        .rolling_apply_indexed(
+            start_index,
+            RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 2,
+                center: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out),
+        &[
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5)
+        ]
+    );
+
+    // The window is passed as well.
+    let out = ca
+        .rolling_apply_indexed(
+            |start, s| Some(start as i32 * 100 + s.len() as i32),
+            RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        Vec::from(&out),
+        &[
+            Some(1),
+            Some(2),
+            Some(102),
+            Some(202),
+            Some(302),
+            Some(402),
+            Some(502)
+        ]
+    );
 }
 
 #[test]
@@ -280,3 +401,74 @@ fn test_rolling_var() {
         out, exp_res
     );
 }
+
+#[test]
+fn test_rolling_weights_with_nulls() -> PolarsResult<()> {
+    let s =
+        Float64Chunked::new("", &[Some(1.0), Some(4.0), None, Some(8.0), Some(5.0)]).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(3),
+        min_periods: 1,
+        weights: Some(vec![1.0, 2.0, 1.0]),
+        ..Default::default()
+    };
+
+    // A null drops its weight.
+    let out = s.rolling_sum(options.clone())?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[Some(1.0), Some(9.0), Some(9.0), Some(12.0), Some(21.0)]
+    );
+    let mean = s.rolling_mean(options.clone())?;
+    assert_eq!(
+        Vec::from(mean.f64()?),
+        &[Some(1.0), Some(3.0), Some(3.0), Some(6.0), Some(7.0)]
+    );
+    let var = s.rolling_var(options.clone())?;
+    let expected = [None, Some(4.5), Some(4.5), Some(8.0), Some(4.5)];
+    assert!(var
+        .f64()?
+        .into_iter()
+        .zip(expected)
+        .all(|(a, b)| match (a, b) {
+            (None, None) => true,
+            (Some(a), Some(b)) => (a - b).abs() < 1e-12,
+            _ => false,
+        }));
+
+    // Normalized weights turn the weighted sum into the weighted mean.
+    let normalized = RollingOptionsImpl {
+        normalize_weights: true,
+        ..options.clone()
+    };
+    assert!(s.rolling_sum(normalized.clone())?.equals_missing(&mean));
+    assert!(s.rolling_mean(normalized.clone())?.equals_missing(&mean));
+    assert!(s.rolling_var(normalized)?.equals_missing(&var));
+
+    let wrong_length = RollingOptionsImpl {
+        weights: Some(vec![1.0, 2.0]),
+        ..options
+    };
+    assert!(s.rolling_var(wrong_length).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rolling_sum_precise() -> PolarsResult<()> {
+    // The ones that are added next to the large value are lost to rounding.
+    let mut values = vec![1e16];
+    values.extend(std::iter::repeat(1.0).take(9));
+    let s = Series::new("", values);
+    let options = RollingOptionsImpl {
+        window_size: Duration::new(2),
+        min_periods: 1,
+        precise: true,
+        ..Default::default()
+    };
+
+    let out = s.rolling_sum(options.clone())?;
+    assert!(out.f64()?.into_no_null_iter().skip(2).all(|v| v == 2.0));
+    let out = s.rolling_mean(options)?;
+    assert!(out.f64()?.into_no_null_iter().skip(2).all(|v| v == 1.0));
+    Ok(())
+}
@@ -33,3 +33,44 @@ fn test_construct_list_of_null_series() {
     assert_eq!(s.null_count(), 0);
     assert_eq!(s.field().name(), "a");
 }
+
+#[test]
+fn test_apply_values_in_place() -> PolarsResult<()> {
+    let values_ptr = |ca: &Float64Chunked| ca.downcast_iter().next().unwrap().values().as_ptr();
+
+    let ca = Float64Chunked::from_slice_options("a", &[Some(1.0), None, Some(3.0)]);
+    let ptr = values_ptr(&ca);
+    let out = ca.apply_values_in_place(|v| v * 2.0);
+    assert_eq!(values_ptr(&out), ptr);
+    assert_eq!(Vec::from(&out), &[Some(2.0), None, Some(6.0)]);
+    assert_eq!(out.is_sorted_flag(), IsSorted::Not);
+
+    // The buffer is shared with `ca`, so the values are written to a new one.
+    let mut ca = Float64Chunked::from_slice("a", &[1.0, 2.0]);
+    ca.set_sorted_flag(IsSorted::Ascending);
+    let out = ca.clone().apply_values_in_place(|v| -v);
+    assert_ne!(values_ptr(&out), values_ptr(&ca));
+    assert_eq!(Vec::from(&ca), &[Some(1.0), Some(2.0)]);
+    assert_eq!(Vec::from(&out), &[Some(-1.0), Some(-2.0)]);
+    assert_eq!(out.is_sorted_flag(), IsSorted::Not);
+
+    let s = Series::new("a", &[1i32, 2, 3]);
+    let ptr = s.i32()?.cont_slice()?.as_ptr();
+    let out = s.apply_values_in_place::<Int32Type, _>(|v| v + 1)?;
+    assert_eq!(out.i32()?.cont_slice()?.as_ptr(), ptr);
+    assert_eq!(out, Series::new("a", &[2i32, 3, 4]));
+
+    let s = Series::new("a", &[1.0f32, 2.0]);
+    let out = s
+        .clone()
+        .apply_values_in_place::<Float32Type, _>(|v| v / 2.0)?;
+    assert_ne!(
+        out.f32()?.cont_slice()?.as_ptr(),
+        s.f32()?.cont_slice()?.as_ptr()
+    );
+    assert_eq!(s, Series::new("a", &[1.0f32, 2.0]));
+    assert_eq!(out, Series::new("a", &[0.5f32, 1.0]));
+
+    assert!(s.apply_values_in_place::<Float64Type, _>(|v| v).is_err());
+    Ok(())
+}
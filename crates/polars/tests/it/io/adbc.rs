@@ -0,0 +1,109 @@
+use polars::io::adbc::{AdbcOptions, AdbcReader, UnsupportedTypePolicy};
+use polars::prelude::*;
+
+// More rows than fit in a single batch of the SQLite driver.
+const QUERY: &str = "WITH RECURSIVE t(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM t WHERE i < 5000)
+    SELECT i, i * 2 AS j, 'row ' || i AS s FROM t";
+
+/// The ignored tests run against the SQLite driver, `POLARS_ADBC_SQLITE_DRIVER` must point to
+/// it.
+fn sqlite_options() -> AdbcOptions {
+    let driver = std::env::var("POLARS_ADBC_SQLITE_DRIVER")
+        .expect("POLARS_ADBC_SQLITE_DRIVER must point to the ADBC SQLite driver");
+    AdbcOptions::new(driver).with_database_option("uri", ":memory:")
+}
+
+#[test]
+#[ignore = "needs POLARS_ADBC_SQLITE_DRIVER"]
+fn test_adbc_read() -> PolarsResult<()> {
+    let options = sqlite_options();
+    let reader = AdbcReader::new(&options)?;
+
+    let df = reader.read("SELECT 1 AS a, 'x' AS b, NULL AS c UNION ALL SELECT 2, NULL, NULL")?;
+    assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+    let a = df.column("a")?.i64()?.into_iter().collect::<Vec<_>>();
+    assert_eq!(a, &[Some(1), Some(2)]);
+    assert_eq!(df.column("b")?.str()?.get(1), None);
+
+    let mut batches = reader.execute(QUERY)?;
+    let schema = batches.schema().clone();
+    let mut n_batches = 0;
+    let mut height = 0;
+    for df in batches.by_ref() {
+        let df = df?;
+        assert_eq!(df.schema(), *schema);
+        n_batches += 1;
+        height += df.height();
+    }
+    assert!(n_batches > 1);
+    assert_eq!(height, 5000);
+    Ok(())
+}
+
+#[test]
+fn test_adbc_missing_driver() {
+    let options = AdbcOptions::new("/does/not/exist/libadbc_driver.so");
+    assert!(AdbcReader::new(&options).is_err());
+}
+
+#[test]
+#[ignore = "needs POLARS_ADBC_SQLITE_DRIVER"]
+fn test_adbc_errors() {
+    let options = sqlite_options();
+    let reader = AdbcReader::new(&options).unwrap();
+    assert!(reader.read("SELECT * FROM does_not_exist").is_err());
+
+    // A nul byte can't be passed to the driver.
+    let options = options.with_database_option("uri", "a\0b");
+    assert!(AdbcReader::new(&options).is_err());
+}
+
+#[test]
+#[ignore = "needs POLARS_ADBC_SQLITE_DRIVER"]
+fn test_adbc_unsupported_type_policy() -> PolarsResult<()> {
+    let options = sqlite_options();
+    // SQLite only produces supported types, so the policy doesn't change the result.
+    for policy in [
+        UnsupportedTypePolicy::Raise,
+        UnsupportedTypePolicy::Drop,
+        UnsupportedTypePolicy::Null,
+    ] {
+        let reader = AdbcReader::new(&options.clone().with_unsupported_types(policy))?;
+        let df = reader.read("SELECT 1 AS a")?;
+        assert_eq!(df.shape(), (1, 1));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+#[ignore = "needs POLARS_ADBC_SQLITE_DRIVER"]
+fn test_scan_adbc() -> PolarsResult<()> {
+    let options = sqlite_options();
+    let lf = LazyFrame::scan_adbc(QUERY, &options, Default::default())?;
+    assert_eq!(
+        lf.schema()?
+            .iter_names()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>(),
+        &["i", "j", "s"]
+    );
+
+    let q = lf.select([col("j").sum()]);
+    let expected = df![
+        "j" => [5000i64 * 5001],
+    ]?;
+    assert!(q.clone().collect()?.equals(&expected));
+    assert!(q.with_streaming(true).collect()?.equals(&expected));
+
+    let args = ScanArgsAnonymous {
+        n_rows: Some(1500),
+        ..Default::default()
+    };
+    let lf = LazyFrame::scan_adbc(QUERY, &options, args)?.select([col("s")]);
+    let out = lf.clone().collect()?;
+    assert_eq!(out.shape(), (1500, 1));
+    assert_eq!(out.column("s")?.str()?.get(1499), Some("row 1500"));
+    assert!(lf.with_streaming(true).collect()?.equals(&out));
+    Ok(())
+}
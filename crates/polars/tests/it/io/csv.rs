@@ -1274,3 +1274,84 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+fn test_multi_byte_record_separator() -> PolarsResult<()> {
+    use std::io::Write;
+
+    let mut csv = b"id,text".to_vec();
+    let mut texts = vec![];
+    for i in 0..5000i64 {
+        csv.extend_from_slice(b"||");
+        let text = match i % 3 {
+            // The separator inside a quoted field doesn't end the record.
+            0 => {
+                let text = format!("x||{i}");
+                write!(csv, "{i},\"{text}\"")?;
+                text
+            },
+            // Neither does a regular line ending.
+            1 => {
+                let text = format!("line\nbreak {i}");
+                write!(csv, "{i},{text}")?;
+                text
+            },
+            _ => {
+                let text = format!("plain {i}");
+                write!(csv, "{i},{text}")?;
+                text
+            },
+        };
+        texts.push(text);
+    }
+    let expected = df![
+        "id" => (0..5000i64).collect::<Vec<_>>(),
+        "text" => texts,
+    ]?;
+
+    let path = std::env::temp_dir().join("polars_test_multi_byte_record_separator.csv");
+    std::fs::write(&path, &csv)?;
+    let reader = || -> PolarsResult<_> {
+        Ok(CsvReader::from_path(&path)?
+            .with_record_separator(Some(b"||".to_vec()))
+            // Small chunks, so that record boundaries are searched all over the file.
+            .with_chunk_size(7)
+            .with_n_threads(Some(4)))
+    };
+
+    let df = reader()?.finish()?;
+    assert!(df.equals(&expected));
+
+    let mut mmap_reader = reader()?;
+    let mut batched = mmap_reader.batched_borrowed_mmap()?;
+    let mut batches = vec![];
+    while let Some(dfs) = batched.next_batches(3)? {
+        batches.extend(dfs);
+    }
+    assert!(batches.len() > 1);
+    assert!(concat_df(&batches)?.equals(&expected));
+
+    let mut read_reader = reader()?;
+    let mut batched = read_reader.batched_borrowed_read()?;
+    let mut batches = vec![];
+    while let Some(dfs) = batched.next_batches(3)? {
+        batches.extend(dfs);
+    }
+    assert!(concat_df(&batches)?.equals(&expected));
+
+    // Every byte value may occur in the data.
+    let mut csv = b"id,bytes||0,\"".to_vec();
+    for byte in 0..=255u8 {
+        csv.push(byte);
+        if byte == b'"' {
+            csv.push(byte);
+        }
+    }
+    csv.extend_from_slice(b"\"||1,x");
+    std::fs::write(&path, &csv)?;
+    let df = reader()?.with_encoding(CsvEncoding::LossyUtf8).finish()?;
+    assert_eq!(df.column("id")?.i64()?.to_vec(), &[Some(0), Some(1)]);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
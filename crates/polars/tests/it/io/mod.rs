@@ -1,3 +1,5 @@
+#[cfg(feature = "adbc")]
+mod adbc;
 mod csv;
 
 #[cfg(feature = "json")]
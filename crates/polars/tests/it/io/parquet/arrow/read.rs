@@ -160,3 +160,120 @@ fn read_int96_timestamps() -> PolarsResult<()> {
 
     Ok(())
 }
+
+/// A parquet file with a single `UUID` column `id` of a value and a null.
+fn uuid_parquet_file() -> PolarsResult<Vec<u8>> {
+    use polars_parquet::parquet::schema::types::{PhysicalType, PrimitiveLogicalType};
+    use polars_parquet::parquet::schema::Repetition;
+
+    let values = FixedSizeBinaryArray::from_iter(
+        [
+            Some([
+                0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+                0xee, 0xff,
+            ]),
+            None,
+        ],
+        16,
+    );
+    let parquet_type = ParquetType::try_from_primitive(
+        "id".to_string(),
+        PhysicalType::FixedLenByteArray(16),
+        Repetition::Optional,
+        None,
+        Some(PrimitiveLogicalType::Uuid),
+        None,
+    )?;
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+
+    let mut writer = polars_parquet::parquet::write::FileWriter::new(
+        Cursor::new(vec![]),
+        SchemaDescriptor::new("root".to_string(), vec![parquet_type.clone()]),
+        polars_parquet::parquet::write::WriteOptions {
+            write_statistics: options.write_statistics,
+            version: options.version,
+        },
+        None,
+    );
+    writer.write(row_group_iter(
+        RecordBatch::new(vec![values.boxed()]),
+        vec![vec![Encoding::Plain]],
+        vec![parquet_type],
+        options,
+    ))?;
+    writer.end(None)?;
+    Ok(writer.into_inner().into_inner())
+}
+
+#[test]
+fn read_uuid_as_string() -> PolarsResult<()> {
+    use polars_parquet::read::schema::{infer_schema_with_options, SchemaInferenceOptions};
+
+    let mut reader = Cursor::new(uuid_parquet_file()?);
+    let metadata = read_metadata(&mut reader)?;
+
+    // Without the option the column is read as plain binary.
+    let schema = infer_schema(&metadata)?;
+    assert_eq!(
+        schema.fields[0].data_type(),
+        &ArrowDataType::FixedSizeBinary(16)
+    );
+
+    let schema = infer_schema_with_options(
+        &metadata,
+        &Some(SchemaInferenceOptions {
+            uuid_as_string: true,
+            ..Default::default()
+        }),
+    )?;
+    assert_eq!(schema.fields[0].data_type(), &ArrowDataType::Utf8View);
+
+    let chunks = FileReader::new(reader, metadata.row_groups, schema, None, None, None)
+        .collect::<PolarsResult<Vec<_>>>()?;
+    assert_eq!(
+        chunks[0].arrays()[0].as_ref(),
+        &Utf8ViewArray::from_slice([Some("00112233-4455-6677-8899-aabbccddeeff"), None])
+            as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn scan_uuid_as_string_multiple_files() -> PolarsResult<()> {
+    use polars::prelude::{DataType, LazyFrame, ScanArgsParquet};
+
+    let dir = std::env::temp_dir().join("polars_test_scan_uuid_as_string_multiple_files");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    let data = uuid_parquet_file()?;
+    for name in ["a.parquet", "b.parquet", "c.parquet"] {
+        std::fs::write(dir.join(name), &data)?;
+    }
+
+    let glob = format!("{}/*.parquet", dir.display());
+    for streaming in [false, true] {
+        let args = ScanArgsParquet {
+            uuid_as_string: true,
+            ..Default::default()
+        };
+        let df = LazyFrame::scan_parquet(&glob, args)?
+            .with_streaming(streaming)
+            .collect()?;
+        // Every file is read with the option, not just the one the schema is taken from.
+        let id = df.column("id")?;
+        assert_eq!(id.dtype(), &DataType::String);
+        assert_eq!(
+            Vec::from(id.str()?),
+            [Some("00112233-4455-6677-8899-aabbccddeeff"), None].repeat(3)
+        );
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
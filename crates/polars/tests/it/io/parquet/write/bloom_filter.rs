@@ -0,0 +1,179 @@
+use std::io::Cursor;
+
+use polars::io::parquet::write::{BloomFilterOptions, ParquetWriter};
+use polars_core::df;
+use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native, is_in_set, read};
+use polars_parquet::parquet::metadata::FileMetaData;
+use polars_parquet::read::read_metadata;
+
+const FPP: f64 = 0.01;
+
+/// Reads the bloom filter of every row group of the column at `column`.
+fn read_bloom_filters(data: &[u8], metadata: &FileMetaData, column: usize) -> Vec<Vec<u8>> {
+    let mut reader = Cursor::new(data);
+    metadata
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            let mut bitset = vec![];
+            read(&row_group.columns()[column], &mut reader, &mut bitset).unwrap();
+            bitset
+        })
+        .collect()
+}
+
+/// The fraction of `hashes` that are (falsely) found in `bitset`.
+fn false_positive_rate(bitset: &[u8], hashes: impl Iterator<Item = u64>) -> f64 {
+    let (mut n, mut positive) = (0, 0);
+    for hash in hashes {
+        n += 1;
+        positive += is_in_set(bitset, hash) as usize;
+    }
+    positive as f64 / n as f64
+}
+
+#[test]
+fn test_write_bloom_filters() -> PolarsResult<()> {
+    let n = 10_000;
+    let mut df = df![
+        "int" => (0..n).collect::<Vec<i64>>(),
+        "uint" => (0..n as u32).map(|i| i * 3).collect::<Vec<_>>(),
+        "str" => (0..n).map(|i| format!("value {i}")).collect::<Vec<_>>(),
+        "float" => (0..n).map(|i| i as f64).collect::<Vec<_>>(),
+    ]?;
+
+    let mut data = vec![];
+    ParquetWriter::new(&mut data)
+        .with_row_group_size(Some(n as usize / 2))
+        .with_bloom_filter(Some(BloomFilterOptions::new(
+            vec!["int".into(), "uint".into(), "str".into()],
+            FPP,
+        )))
+        .finish(&mut df)?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let int = read_bloom_filters(&data, &metadata, 0);
+    let uint = read_bloom_filters(&data, &metadata, 1);
+    let str = read_bloom_filters(&data, &metadata, 2);
+    let float = read_bloom_filters(&data, &metadata, 3);
+    for (i, row_group) in metadata.row_groups.iter().enumerate() {
+        let start = i as i64 * row_group.num_rows() as i64;
+        let values = start..start + row_group.num_rows() as i64;
+        // Values outside of the row group, including the values of the other row group.
+        let absent = (0..2 * n).filter(|v| !values.contains(v));
+
+        // No false negatives.
+        assert!(values.clone().all(|v| is_in_set(&int[i], hash_native(v))));
+        assert!(values
+            .clone()
+            .all(|v| is_in_set(&uint[i], hash_native((v * 3) as i32))));
+        assert!(values
+            .clone()
+            .all(|v| is_in_set(&str[i], hash_byte(format!("value {v}")))));
+
+        // The false positive rate is close to the requested one.
+        let rate = false_positive_rate(&int[i], absent.clone().map(hash_native));
+        assert!(rate < 2.0 * FPP, "{rate}");
+        let rate = false_positive_rate(&str[i], absent.map(|v| hash_byte(format!("value {v}"))));
+        assert!(rate < 2.0 * FPP, "{rate}");
+
+        // Columns without a bloom filter don't get one.
+        assert!(float[i].is_empty());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_write_bloom_filters_invalid() {
+    let mut df = df![
+        "int" => [1, 2, 3],
+        "bool" => [true, false, true],
+    ]
+    .unwrap();
+    for (columns, fpp) in [
+        (vec!["bool"], FPP),
+        (vec!["missing"], FPP),
+        (vec!["int"], 1.5),
+    ] {
+        let options = BloomFilterOptions::new(columns.into_iter().map(Into::into).collect(), fpp);
+        let result = ParquetWriter::new(vec![])
+            .with_bloom_filter(Some(options))
+            .finish(&mut df);
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "streaming"))]
+fn test_sink_bloom_filters() -> PolarsResult<()> {
+    use polars::prelude::{IntoLazy, ParquetWriteOptions};
+
+    let n = 10_000;
+    let df = df![
+        "int" => (0..n).collect::<Vec<i64>>(),
+    ]?;
+    let path = std::env::temp_dir().join("polars_test_sink_bloom_filters.parquet");
+    let options = ParquetWriteOptions {
+        bloom_filter: Some(BloomFilterOptions::new(vec!["int".into()], FPP)),
+        ..Default::default()
+    };
+    df.lazy().sink_parquet(path.clone(), options)?;
+
+    let data = std::fs::read(&path)?;
+    std::fs::remove_file(&path)?;
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let int = read_bloom_filters(&data, &metadata, 0);
+    assert!(int.iter().all(|bitset| !bitset.is_empty()));
+    // Every value is found in the filter of at least one row group.
+    assert!((0..n).all(|v| int.iter().any(|bitset| is_in_set(bitset, hash_native(v)))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn test_file_sink_bloom_filters() -> PolarsResult<()> {
+    use futures::SinkExt;
+    use polars_parquet::write::{
+        transverse, CompressionOptions, Encoding, FileSink, Version, WriteOptions,
+    };
+
+    let n = 1_000;
+    let df = df![
+        "int" => (0..n).collect::<Vec<i64>>(),
+    ]?;
+    let schema = df.schema().to_arrow(false);
+    let encodings = schema
+        .fields
+        .iter()
+        .map(|field| transverse(&field.data_type, |_| Encoding::Plain))
+        .collect();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_pagesize_limit: None,
+    };
+
+    let mut data = vec![];
+    futures::executor::block_on(async {
+        let mut sink = FileSink::try_new(
+            futures::io::Cursor::new(&mut data),
+            schema,
+            encodings,
+            options,
+        )?
+        .with_bloom_filters(vec![Some(FPP)])?;
+        for batch in df.iter_chunks(false) {
+            sink.feed(batch).await?;
+        }
+        sink.close().await
+    })?;
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let int = read_bloom_filters(&data, &metadata, 0);
+    assert_eq!(int.len(), 1);
+    assert!((0..n).all(|v| is_in_set(&int[0], hash_native(v))));
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod binary;
+mod bloom_filter;
 mod indexes;
 mod primitive;
 mod sidecar;
@@ -0,0 +1,121 @@
+use super::*;
+
+const LONG: &[u8] = b"too long to be inlined";
+
+fn binary_df() -> PolarsResult<DataFrame> {
+    DataFrame::new(vec![
+        Series::new(
+            "a",
+            &[
+                Some(b"ab".as_slice()),
+                Some(LONG),
+                None,
+                Some(b"xyz".as_slice()),
+            ],
+        ),
+        Series::new(
+            "b",
+            &[
+                Some(b"cd".as_slice()),
+                Some(b"!".as_slice()),
+                Some(b"ef".as_slice()),
+                None,
+            ],
+        ),
+    ])
+}
+
+fn binary_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    Ok(df
+        .column(name)?
+        .binary()?
+        .into_iter()
+        .map(|v| v.map(<[u8]>::to_vec))
+        .collect())
+}
+
+#[test]
+fn test_binary_size() -> PolarsResult<()> {
+    let out = binary_df()?
+        .lazy()
+        .select([col("a").binary().size()])
+        .collect()?;
+    let size = out.column("a")?;
+    assert_eq!(size.dtype(), &DataType::UInt32);
+    assert_eq!(
+        Vec::from(size.u32()?),
+        &[Some(2), Some(LONG.len() as u32), None, Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_binary_slice() -> PolarsResult<()> {
+    let out = binary_df()?
+        .lazy()
+        .select([
+            col("a").binary().slice(lit(1), lit(NULL)).alias("tail"),
+            col("a").binary().slice(lit(-3), lit(2)).alias("negative"),
+            col("a").binary().slice(lit(10), lit(100)).alias("clamped"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        binary_values(&out, "tail")?,
+        &[
+            Some(b"b".to_vec()),
+            Some(LONG[1..].to_vec()),
+            None,
+            Some(b"yz".to_vec())
+        ]
+    );
+    // The part of the slice before the start of "ab" is cut from the length.
+    assert_eq!(
+        binary_values(&out, "negative")?,
+        &[
+            Some(b"a".to_vec()),
+            Some(LONG[LONG.len() - 3..LONG.len() - 1].to_vec()),
+            None,
+            Some(b"xy".to_vec())
+        ]
+    );
+    assert_eq!(
+        binary_values(&out, "clamped")?,
+        &[Some(vec![]), Some(LONG[10..].to_vec()), None, Some(vec![])]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_binary_concat() -> PolarsResult<()> {
+    let out = binary_df()?
+        .lazy()
+        .select([
+            col("a").binary().concat([col("b")]),
+            col("b")
+                .binary()
+                .concat([col("*"), lit(b"-".to_vec())])
+                .alias("wildcard"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        binary_values(&out, "a")?,
+        &[
+            Some(b"abcd".to_vec()),
+            Some([LONG, b"!".as_slice()].concat()),
+            None,
+            None
+        ]
+    );
+    assert_eq!(
+        binary_values(&out, "wildcard")?,
+        &[
+            Some(b"cdabcd-".to_vec()),
+            Some([b"!".as_slice(), LONG, b"!-"].concat()),
+            None,
+            None
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,290 @@
+use super::*;
+
+fn ragged_lists() -> PolarsResult<DataFrame> {
+    let s = Series::new(
+        "a",
+        [
+            Some(Series::new("", &[1i32, 2, 3, 4])),
+            Some(Series::new("", &[1i32])),
+            None,
+            Some(Series::new("", &[1i32, 2])),
+        ],
+    );
+    DataFrame::new(vec![s])
+}
+
+fn list_values(s: &Series) -> Vec<Option<Vec<Option<i32>>>> {
+    s.list()
+        .unwrap()
+        .into_iter()
+        .map(|opt_s| opt_s.map(|s| s.i32().unwrap().into_iter().collect()))
+        .collect()
+}
+
+#[test]
+fn test_list_pad_to_length() -> PolarsResult<()> {
+    let df = ragged_lists()?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a")
+                .list()
+                .pad_to_length(2, lit(0i32), PadSide::Right, false)
+                .alias("right"),
+            col("a")
+                .list()
+                .pad_to_length(2, lit(0i32), PadSide::Left, false)
+                .alias("left"),
+        ])
+        .collect()?;
+
+    let expected = vec![
+        Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+        Some(vec![Some(1), Some(0)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+    ];
+    assert_eq!(list_values(out.column("right")?), expected);
+    let expected = vec![
+        Some(vec![Some(1), Some(2), Some(3), Some(4)]),
+        Some(vec![Some(0), Some(1)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+    ];
+    assert_eq!(list_values(out.column("left")?), expected);
+
+    // Sublists longer than the target width cannot become an array.
+    let out = df
+        .lazy()
+        .select([col("a")
+            .list()
+            .pad_to_length(2, lit(0i32), PadSide::Right, true)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_list_slice() -> PolarsResult<()> {
+    let df = ragged_lists()?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").list().slice(lit(1), lit(2)).alias("positive"),
+            col("a").list().slice(lit(-2), lit(NULL)).alias("tail"),
+            col("a").list().slice(lit(-3), lit(2)).alias("negative"),
+            col("a").list().slice(lit(5), lit(1)).alias("out_of_range"),
+        ])
+        .collect()?;
+
+    let expected = vec![
+        Some(vec![Some(2), Some(3)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(2)]),
+    ];
+    assert_eq!(list_values(out.column("positive")?), expected);
+    let expected = vec![
+        Some(vec![Some(3), Some(4)]),
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+    ];
+    assert_eq!(list_values(out.column("tail")?), expected);
+    // A negative offset past the start is clamped, which also shortens the slice.
+    let expected = vec![
+        Some(vec![Some(2), Some(3)]),
+        Some(vec![]),
+        None,
+        Some(vec![Some(1)]),
+    ];
+    assert_eq!(list_values(out.column("negative")?), expected);
+    let expected = vec![Some(vec![]), Some(vec![]), None, Some(vec![])];
+    assert_eq!(list_values(out.column("out_of_range")?), expected);
+    Ok(())
+}
+
+#[test]
+fn test_list_truncate() -> PolarsResult<()> {
+    let df = ragged_lists()?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .list()
+                .truncate(2, PadSide::Right, false)
+                .alias("right"),
+            col("a")
+                .list()
+                .truncate(2, PadSide::Left, false)
+                .alias("left"),
+        ])
+        .collect()?;
+
+    let expected = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+    ];
+    assert_eq!(list_values(out.column("right")?), expected);
+    let expected = vec![
+        Some(vec![Some(3), Some(4)]),
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![Some(1), Some(2)]),
+    ];
+    assert_eq!(list_values(out.column("left")?), expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-array")]
+fn test_list_truncate_pad_to_array() -> PolarsResult<()> {
+    let df = ragged_lists()?;
+
+    let out = df
+        .lazy()
+        .select([col("a")
+            .list()
+            .truncate(3, PadSide::Right, false)
+            .list()
+            .pad_to_length(3, lit(NULL), PadSide::Right, true)])
+        .collect()?;
+
+    let s = out.column("a")?;
+    assert_eq!(s.dtype(), &DataType::Array(Box::new(DataType::Int32), 3));
+    let ca = s.array()?;
+    assert_eq!(ca.null_count(), 1);
+    let values = ca
+        .into_iter()
+        .map(|opt_s| opt_s.map(|s| s.i32().unwrap().into_iter().collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![Some(1), None, None]),
+            None,
+            Some(vec![Some(1), Some(2), None]),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_list_join() -> PolarsResult<()> {
+    let s = Series::new(
+        "tags",
+        [
+            Some(Series::new("", &[Some("a"), Some("b"), Some("c")])),
+            Some(Series::new("", &[Some("a"), None, Some("c")])),
+            Some(Series::new("", Vec::<Option<&str>>::new())),
+            None,
+        ],
+    );
+    let df = DataFrame::new(vec![s])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("tags")
+                .list()
+                .join(lit(", "), false)
+                .alias("keep_nulls"),
+            col("tags")
+                .list()
+                .join(lit(", "), true)
+                .alias("ignore_nulls"),
+        ])
+        .collect()?;
+
+    let values = |name| -> PolarsResult<Vec<Option<String>>> {
+        Ok(out
+            .column(name)?
+            .str()?
+            .into_iter()
+            .map(|opt_s| opt_s.map(str::to_string))
+            .collect())
+    };
+    assert_eq!(
+        values("keep_nulls")?,
+        [Some("a, b, c".into()), None, Some("".into()), None]
+    );
+    assert_eq!(
+        values("ignore_nulls")?,
+        [
+            Some("a, b, c".into()),
+            Some("a, c".into()),
+            Some("".into()),
+            None
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "json",
+    feature = "extract_jsonpath",
+    feature = "dtype-datetime"
+))]
+fn test_list_json_encode() -> PolarsResult<()> {
+    let df = ragged_lists()?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").list().json_encode()])
+        .collect()?;
+    let json = out.column("a")?.str()?;
+    assert_eq!(
+        Vec::from(json),
+        &[Some("[1,2,3,4]"), Some("[1]"), None, Some("[1,2]")]
+    );
+    let out = out
+        .lazy()
+        .select([col("a")
+            .str()
+            .json_decode(Some(DataType::List(Box::new(DataType::Int32))), None)])
+        .collect()?;
+    assert!(out.column("a")?.equals_missing(df.column("a")?));
+
+    let dt = Series::new("", &[0i64, 1_500, -86_400_000])
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    let df = DataFrame::new(vec![Series::new("a", [Some(dt), None])])?;
+    let dtype = df.column("a")?.dtype().clone();
+    let encode = |temporal| {
+        df.clone()
+            .lazy()
+            .select([col("a")
+                .list()
+                .json_encode_with_options(JsonEncodeOptions { temporal })])
+            .collect()
+    };
+
+    let out = encode(JsonTemporalEncoding::Iso)?;
+    assert_eq!(
+        out.column("a")?.str()?.get(0),
+        Some(r#"["1970-01-01T00:00:00","1970-01-01T00:00:01.500","1969-12-31T00:00:00"]"#)
+    );
+    let out = out
+        .lazy()
+        .select([col("a").str().json_decode(Some(dtype.clone()), None)])
+        .collect()?;
+    assert!(out.column("a")?.equals_missing(df.column("a")?));
+
+    let out = encode(JsonTemporalEncoding::Epoch)?;
+    assert_eq!(out.column("a")?.str()?.get(0), Some("[0,1500,-86400000]"));
+    let out = out
+        .lazy()
+        .select([col("a").str().json_decode(Some(dtype), None)])
+        .collect()?;
+    assert!(out.column("a")?.equals_missing(df.column("a")?));
+    Ok(())
+}
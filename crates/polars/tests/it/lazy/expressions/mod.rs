@@ -1,10 +1,14 @@
 mod apply;
 mod arity;
+mod binary;
 mod expand;
 mod filter;
 #[cfg(feature = "is_in")]
 mod is_in;
+mod list;
 mod slice;
+#[cfg(feature = "dtype-struct")]
+mod struct_;
 mod window;
 
 use super::*;
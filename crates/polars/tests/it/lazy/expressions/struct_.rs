@@ -0,0 +1,141 @@
+use super::*;
+
+/// Row 1 is a null struct, the other rows have nulls in some of the fields.
+fn struct_with_nulls() -> PolarsResult<DataFrame> {
+    let x = Series::new("x", &[Some(1i32), None, None, Some(4)]);
+    let y = Series::new("y", &[None, None, Some("b"), None]);
+    let z = Series::new("z", &[Some(1.5f64), None, None, None]);
+    let s = StructChunked::new("s", &[x, y, z])?.into_series();
+    DataFrame::new(vec![s])
+}
+
+fn field(df: &DataFrame, name: &str) -> PolarsResult<Series> {
+    df.column("s")?.struct_()?.field_by_name(name)
+}
+
+#[test]
+fn test_struct_with_fields_fill_null() -> PolarsResult<()> {
+    let df = struct_with_nulls()?;
+    let y_before = field(&df, "y")?;
+
+    let out = df
+        .lazy()
+        .select([col("s").struct_().with_fields(vec![
+            col("s").struct_().field_by_name("x").fill_null(lit(0i32)),
+            lit(1i32).alias("w"),
+        ])])
+        .collect()?;
+
+    let x = field(&out, "x")?;
+    assert_eq!(Vec::from(x.i32()?), &[Some(1), None, Some(0), Some(4)]);
+    let w = field(&out, "w")?;
+    assert_eq!(Vec::from(w.i32()?), &[Some(1), None, Some(1), Some(1)]);
+    // The untouched field is reused.
+    let y = field(&out, "y")?;
+    assert!(y.equals_missing(&y_before));
+    assert_eq!(out.column("s")?.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_struct_fill_null_strategy() -> PolarsResult<()> {
+    let df = struct_with_nulls()?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s")
+            .struct_()
+            .fill_null(FillNullStrategy::Forward(None), false)])
+        .collect()?;
+    let x = field(&out, "x")?;
+    assert_eq!(Vec::from(x.i32()?), &[Some(1), None, Some(1), Some(4)]);
+    let y = field(&out, "y")?;
+    assert_eq!(Vec::from(y.str()?), &[None, None, Some("b"), Some("b")]);
+    assert_eq!(out.column("s")?.null_count(), 1);
+
+    // Strategies that don't apply to a field's dtype leave that field untouched.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").struct_().fill_null(FillNullStrategy::Mean, false)])
+        .collect()?;
+    let x = field(&out, "x")?;
+    assert_eq!(Vec::from(x.i32()?), &[Some(1), None, Some(2), Some(4)]);
+    let y = field(&out, "y")?;
+    assert_eq!(Vec::from(y.str()?), &[None, None, Some("b"), None]);
+    let z = field(&out, "z")?;
+    assert_eq!(
+        Vec::from(z.f64()?),
+        &[Some(1.5), None, Some(1.5), Some(1.5)]
+    );
+
+    // Null rows can be filled as well.
+    let out = df
+        .lazy()
+        .select([col("s").struct_().fill_null(FillNullStrategy::Zero, true)])
+        .collect()?;
+    let x = field(&out, "x")?;
+    assert_eq!(Vec::from(x.i32()?), &[Some(1), Some(0), Some(0), Some(4)]);
+    assert_eq!(out.column("s")?.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_struct_drop_nulls() -> PolarsResult<()> {
+    // Row 1 has a single null field, row 2 is a null struct.
+    let x = Series::new("x", &[Some(1i32), None, None, Some(4)]);
+    let y = Series::new("y", &[Some("a"), Some("b"), None, Some("d")]);
+    let s = StructChunked::new("s", &[x, y])?.into_series();
+    let df = DataFrame::new(vec![s])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").struct_().drop_nulls(true)])
+        .collect()?;
+    let x = field(&out, "x")?;
+    assert_eq!(Vec::from(x.i32()?), &[Some(1), Some(4)]);
+
+    let out = df
+        .lazy()
+        .select([col("s").struct_().drop_nulls(false)])
+        .collect()?;
+    let x = field(&out, "x")?;
+    assert_eq!(Vec::from(x.i32()?), &[Some(1), None, Some(4)]);
+    let y = field(&out, "y")?;
+    assert_eq!(Vec::from(y.str()?), &[Some("a"), Some("b"), Some("d")]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "json", feature = "extract_jsonpath"))]
+fn test_struct_json_encode() -> PolarsResult<()> {
+    let df = struct_with_nulls()?;
+    let dtype = df.column("s")?.dtype().clone();
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("s").struct_().json_encode()])
+        .collect()?;
+    let json = out.column("s")?.str()?;
+    assert_eq!(json.get(0), Some(r#"{"x":1,"y":null,"z":1.5}"#));
+    assert_eq!(json.get(2), Some(r#"{"x":null,"y":"b","z":null}"#));
+
+    let out = out
+        .lazy()
+        .select([col("s").str().json_decode(Some(dtype), None)])
+        .collect()?;
+    assert!(out.column("s")?.equals_missing(df.column("s")?));
+
+    // Binary values are written as base64.
+    let b = Series::new("b", &[Some(b"polars".to_vec()), None]);
+    let df = DataFrame::new(vec![StructChunked::new("s", &[b])?.into_series()])?;
+    let out = df
+        .lazy()
+        .select([col("s").struct_().json_encode()])
+        .collect()?;
+    assert_eq!(out.column("s")?.str()?.get(0), Some(r#"{"b":"cG9sYXJz"}"#));
+    Ok(())
+}
@@ -394,3 +394,87 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+fn test_window_fill_with_dynamic_limit() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "a", "a", "b", "b", "b", "b", "b"],
+        "v" => [Some(1), None, None, None, Some(5), Some(1), None, None, None, Some(5)],
+        "limit" => [1, 1, 1, 1, 1, 2, 2, 2, 2, 2],
+    ]?;
+
+    // Split the frame and fill every group with its own static limit.
+    let manual = |backward: bool| -> PolarsResult<Series> {
+        let mut out = Series::new_empty("v", &DataType::Int32);
+        for (offset, limit) in [(0, 1), (5, 2)] {
+            let v = df.column("v")?.slice(offset, 5);
+            let strategy = if backward {
+                FillNullStrategy::Backward(Some(limit))
+            } else {
+                FillNullStrategy::Forward(Some(limit))
+            };
+            out.append(&v.fill_null(strategy)?)?;
+        }
+        Ok(out)
+    };
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("v")
+                .forward_fill_with_limit(col("limit").first())
+                .over([col("g")])
+                .alias("forward"),
+            col("v")
+                .backward_fill_with_limit(col("limit").first())
+                .over([col("g")])
+                .alias("backward"),
+        ])
+        .collect()?;
+    assert!(out
+        .column("forward")?
+        .equals_missing(&manual(false)?.with_name("forward")));
+    assert!(out
+        .column("backward")?
+        .equals_missing(&manual(true)?.with_name("backward")));
+
+    // The same limits in an aggregation.
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("v").forward_fill_with_limit(col("limit").first())])
+        .explode([col("v")])
+        .collect()?;
+    assert!(out.column("v")?.equals_missing(&manual(false)?));
+
+    // A null limit doesn't limit the fill.
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("v").forward_fill_with_limit(lit(NULL))])
+        .collect()?;
+    assert_eq!(out.column("v")?.null_count(), 0);
+
+    // Negative and non-integer limits are rejected, naming the group.
+    for (limit, group) in [
+        (
+            col("limit").first() - lit(2),
+            "in group 0, which starts at row 0",
+        ),
+        (
+            lit(1) - col("limit").first(),
+            "in group 1, which starts at row 5",
+        ),
+        (lit(1.5), "in group 0, which starts at row 0"),
+    ] {
+        let q = df
+            .clone()
+            .lazy()
+            .select([col("v").forward_fill_with_limit(limit).over([col("g")])]);
+        let err = q.collect().unwrap_err().to_string();
+        assert!(err.contains(group), "{err}");
+    }
+    Ok(())
+}
@@ -26,3 +26,174 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(all(feature = "diagonal_concat", feature = "dtype-struct"))]
+fn test_concat_lf_diagonal_relaxed_nested_structs() -> PolarsResult<()> {
+    let a = df![
+        "a" => [1i32, 2],
+        "x" => [1i32, 2],
+    ]?
+    .lazy()
+    .select([col("a"), as_struct(vec![col("x")]).alias("s")]);
+    let b = df![
+        "x" => [3.5f64],
+        "y" => ["y"],
+        "c" => ["c"],
+    ]?
+    .lazy()
+    .select([as_struct(vec![col("x"), col("y")]).alias("s"), col("c")]);
+
+    let args = UnionArgs {
+        to_supertypes: true,
+        ..Default::default()
+    };
+    let out = concat_lf_diagonal([a.clone(), b.clone()], args)?.collect()?;
+    assert_eq!(out.get_column_names(), &["a", "s", "c"]);
+    assert_eq!(
+        out.column("s")?.dtype(),
+        &DataType::Struct(vec![
+            Field::new("x", DataType::Float64),
+            Field::new("y", DataType::String),
+        ])
+    );
+    let x = out.column("s")?.struct_()?.field_by_name("x")?;
+    assert_eq!(Vec::from(x.f64()?), &[Some(1.0), Some(2.0), Some(3.5)]);
+
+    // Irreconcilable data types point to the column.
+    let c = df!["a" => [true]]?
+        .lazy()
+        .select([col("a").cast(DataType::Binary)]);
+    let err = concat_lf_diagonal([a, c], args)
+        .and_then(|lf| lf.collect())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("'a'"), "{err}");
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "range",
+    feature = "dtype-datetime",
+    feature = "dtype-duration"
+))]
+fn test_datetime_ranges_by_step() -> PolarsResult<()> {
+    let h = 3_600_000i64;
+    let df = df![
+        "start" => [Some(0i64), Some(0), None, Some(2 * h), Some(i64::MAX - 1)],
+        "end" => [3 * h, h, h, h, i64::MAX],
+        // The steps are in microseconds, the bounds in milliseconds.
+        "step" => [1000 * h, 500 * h, 1000 * h, 1000 * h, 1000 * h],
+    ]?
+    .lazy()
+    .select([
+        col("start").cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+        col("end").cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+        col("step").cast(DataType::Duration(TimeUnit::Microseconds)),
+    ]);
+
+    let ranges = |closed| -> PolarsResult<Vec<Option<Vec<i64>>>> {
+        let q = df.clone().select([datetime_ranges_by_step(
+            col("start"),
+            col("end"),
+            col("step"),
+            closed,
+        )]);
+        let dtype = DataType::List(Box::new(DataType::Datetime(TimeUnit::Milliseconds, None)));
+        assert_eq!(q.schema()?.get("start"), Some(&dtype));
+
+        let out = q.collect()?;
+        let out = out.column("start")?;
+        assert_eq!(out.dtype(), &dtype);
+        Ok(out
+            .list()?
+            .into_iter()
+            .map(|opt_s| {
+                opt_s.map(|s| {
+                    s.to_physical_repr()
+                        .i64()
+                        .unwrap()
+                        .into_no_null_iter()
+                        .collect()
+                })
+            })
+            .collect())
+    };
+
+    let max = i64::MAX - 1;
+    assert_eq!(
+        ranges(ClosedWindow::Both)?,
+        [
+            Some(vec![0, h, 2 * h, 3 * h]),
+            Some(vec![0, h / 2, h]),
+            None,
+            Some(vec![]),
+            Some(vec![max]),
+        ]
+    );
+    assert_eq!(
+        ranges(ClosedWindow::Left)?,
+        [
+            Some(vec![0, h, 2 * h]),
+            Some(vec![0, h / 2]),
+            None,
+            Some(vec![]),
+            Some(vec![max]),
+        ]
+    );
+    // Stepping past the end of the last range doesn't overflow.
+    assert_eq!(
+        ranges(ClosedWindow::None)?,
+        [
+            Some(vec![h, 2 * h]),
+            Some(vec![h / 2]),
+            None,
+            Some(vec![]),
+            Some(vec![]),
+        ]
+    );
+
+    for step in [0i64, -1000 * h] {
+        let q = df.clone().select([datetime_ranges_by_step(
+            col("start"),
+            col("end"),
+            lit(step).cast(DataType::Duration(TimeUnit::Microseconds)),
+            ClosedWindow::Both,
+        )]);
+        assert!(q.collect().is_err());
+    }
+    let q = df.clone().select([datetime_ranges_by_step(
+        col("start"),
+        col("end"),
+        lit(1i64),
+        ClosedWindow::Both,
+    )]);
+    assert!(q.schema().is_err());
+
+    // The offsets from the start overflow, but the values don't.
+    let step = 1i64 << 62;
+    let dtype = DataType::Datetime(TimeUnit::Milliseconds, None);
+    let out = df![
+        "start" => [i64::MIN + 1],
+        "end" => [i64::MAX],
+    ]?
+    .lazy()
+    .select([datetime_ranges_by_step(
+        col("start").cast(dtype.clone()),
+        col("end").cast(dtype),
+        lit(step).cast(DataType::Duration(TimeUnit::Milliseconds)),
+        ClosedWindow::Both,
+    )])
+    .collect()?;
+    let out = out
+        .column("start")?
+        .explode()?
+        .to_physical_repr()
+        .into_owned();
+    assert_eq!(
+        Vec::from(out.i64()?),
+        [i64::MIN + 1, i64::MIN + 1 + step, 1, 1 + step].map(Some)
+    );
+    Ok(())
+}
@@ -61,3 +61,184 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn int_index_df(dtype: &DataType) -> PolarsResult<DataFrame> {
+    let mut idx = Series::new("idx", &[0i64, 1, 2, 3, 4, 5, 6, 7, 8, 9]).cast(dtype)?;
+    idx.set_sorted_flag(polars::series::IsSorted::Ascending);
+    df![
+        "idx" => idx,
+        "a" => [1i32, 1, 1, 1, 1, 1, 1, 1, 1, 1]
+    ]
+}
+
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn int_dynamic_options(every: &str, period: &str, offset: &str) -> DynamicGroupOptions {
+    DynamicGroupOptions {
+        every: Duration::parse(every),
+        period: Duration::parse(period),
+        offset: Duration::parse(offset),
+        closed_window: ClosedWindow::Left,
+        label: Label::Left,
+        include_boundaries: true,
+        start_by: StartBy::WindowBound,
+        ..Default::default()
+    }
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_integer_index() -> PolarsResult<()> {
+    for dtype in [
+        DataType::Int32,
+        DataType::Int64,
+        DataType::UInt32,
+        DataType::UInt64,
+    ] {
+        let out = int_index_df(&dtype)?
+            .lazy()
+            .group_by_dynamic(col("idx"), [], int_dynamic_options("3i", "3i", "0i"))
+            .agg([col("a").sum()])
+            .collect()?;
+
+        let values = |name: &str| -> PolarsResult<Vec<i64>> {
+            let s = out.column(name)?;
+            assert_eq!(s.dtype(), &dtype);
+            Ok(s.cast(&DataType::Int64)?
+                .i64()?
+                .into_no_null_iter()
+                .collect())
+        };
+        assert_eq!(values("idx")?, &[0, 3, 6, 9]);
+        assert_eq!(values("_lower_boundary")?, &[0, 3, 6, 9]);
+        assert_eq!(values("_upper_boundary")?, &[3, 6, 9, 12]);
+        let a = out
+            .column("a")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(a, &[3, 3, 3, 1]);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_integer_index_closed_label() -> PolarsResult<()> {
+    let options = DynamicGroupOptions {
+        closed_window: ClosedWindow::Both,
+        label: Label::Right,
+        ..int_dynamic_options("4i", "4i", "0i")
+    };
+    let out = int_index_df(&DataType::Int64)?
+        .lazy()
+        .group_by_dynamic(col("idx"), [], options)
+        .agg([col("a").sum()])
+        .collect()?;
+
+    let idx = out
+        .column("idx")?
+        .i64()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(idx, &[4, 8, 12]);
+    let a = out
+        .column("a")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(a, &[5, 5, 2]);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_integer_index_negative_offset() -> PolarsResult<()> {
+    let out = int_index_df(&DataType::Int32)?
+        .lazy()
+        .group_by_dynamic(col("idx"), [], int_dynamic_options("5i", "5i", "-2i"))
+        .agg([col("a").sum()])
+        .collect()?;
+
+    let idx = out
+        .column("idx")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(idx, &[-2, 3, 8]);
+    let a = out
+        .column("a")?
+        .i32()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    assert_eq!(a, &[3, 5, 2]);
+
+    // Window starts below zero cannot be represented by an unsigned index.
+    let out = int_index_df(&DataType::UInt32)?
+        .lazy()
+        .group_by_dynamic(col("idx"), [], int_dynamic_options("5i", "5i", "-2i"))
+        .agg([col("a").sum()])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_group_by_dynamic_integer_index_temporal_options() -> PolarsResult<()> {
+    let options = DynamicGroupOptions {
+        start_by: StartBy::Monday,
+        ..int_dynamic_options("3i", "3i", "0i")
+    };
+    let out = int_index_df(&DataType::Int64)?
+        .lazy()
+        .group_by_dynamic(col("idx"), [], options)
+        .agg([col("a").sum()])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+
+    // Calendar durations have no meaning for an integer index.
+    let out = int_index_df(&DataType::Int64)?
+        .lazy()
+        .group_by_dynamic(col("idx"), [], int_dynamic_options("1d", "1d", "0i"))
+        .agg([col("a").sum()])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "temporal", feature = "dynamic_group_by"))]
+fn test_rolling_integer_index() -> PolarsResult<()> {
+    for dtype in [
+        DataType::Int32,
+        DataType::Int64,
+        DataType::UInt32,
+        DataType::UInt64,
+    ] {
+        let out = int_index_df(&dtype)?
+            .lazy()
+            .rolling(
+                col("idx"),
+                [],
+                RollingGroupOptions {
+                    index_column: "idx".into(),
+                    period: Duration::parse("3i"),
+                    offset: Duration::parse("-3i"),
+                    closed_window: ClosedWindow::Right,
+                    ..Default::default()
+                },
+            )
+            .agg([col("a").sum()])
+            .collect()?;
+
+        assert_eq!(out.column("idx")?.dtype(), &dtype);
+        let a = out
+            .column("a")?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(a, &[1, 2, 3, 3, 3, 3, 3, 3, 3, 3]);
+    }
+    Ok(())
+}
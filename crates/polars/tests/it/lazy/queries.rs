@@ -272,3 +272,95 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "log")]
+fn test_expm1() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1e-10), Some(-3e-12), None],
+        "b" => [Some(0i32), None, Some(2)],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").log1p().expm1().alias("roundtrip"),
+            col("a").expm1().alias("expm1"),
+            (col("a").exp() - lit(1.0)).alias("naive"),
+            col("b").expm1(),
+        ])
+        .collect()?;
+
+    let a = [Some(1e-10), Some(-3e-12), None];
+    let roundtrip = out.column("roundtrip")?.f64()?;
+    let expm1 = out.column("expm1")?.f64()?;
+    for (i, expected) in a.into_iter().enumerate() {
+        let Some(x) = expected else {
+            assert_eq!(roundtrip.get(i), None);
+            assert_eq!(expm1.get(i), None);
+            continue;
+        };
+        assert!((roundtrip.get(i).unwrap() - x).abs() <= x.abs() * 1e-15);
+        // For tiny x, exp(x) - 1 ~= x + x^2 / 2.
+        let precise = x + x * x / 2.0;
+        assert!((expm1.get(i).unwrap() - precise).abs() <= precise.abs() * 1e-15);
+    }
+    // `exp(x) - 1` loses most digits for tiny x.
+    let naive = out.column("naive")?.f64()?.get(0).unwrap();
+    assert!((naive - 1e-10).abs() > 1e-10 * 1e-9);
+
+    assert_eq!(
+        Vec::from(out.column("b")?.f64()?),
+        &[Some(0.0), None, Some(2f64.exp_m1())]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extend_constant_prepend() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1i16), None, Some(3)],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").extend_constant(lit(7), lit(3)).alias("append"),
+            col("a").prepend_constant(lit(7), lit(3)).alias("prepend"),
+        ])
+        .collect()?;
+
+    let append = out.column("append")?;
+    assert_eq!(append.dtype(), &DataType::Int16);
+    assert_eq!(
+        Vec::from(append.i16()?),
+        &[Some(1), None, Some(3), Some(7), Some(7), Some(7)]
+    );
+    let prepend = out.column("prepend")?;
+    assert_eq!(prepend.dtype(), &DataType::Int16);
+    assert_eq!(
+        Vec::from(prepend.i16()?),
+        &[Some(7), Some(7), Some(7), Some(1), None, Some(3)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "diff")]
+fn test_diff_by() -> PolarsResult<()> {
+    let t = [0i64, 1, 3, 3, 7, 8];
+    let v = [1i32, 2, 6, 8, 8, 4];
+    let df = df![
+        "t" => t,
+        "v" => v,
+    ]?;
+    let out = df.lazy().select([col("v").diff_by(col("t"))]).collect()?;
+
+    // The fourth row has a zero gap in `t`.
+    let expected = (0..t.len())
+        .map(|i| (i > 0).then(|| (v[i] - v[i - 1]) as f64 / (t[i] - t[i - 1]) as f64))
+        .collect::<Vec<_>>();
+    assert_eq!(expected[3], Some(f64::INFINITY));
+    let out = out.column("v")?;
+    assert_eq!(out.dtype(), &DataType::Float64);
+    assert_eq!(Vec::from(out.f64()?), expected);
+    Ok(())
+}
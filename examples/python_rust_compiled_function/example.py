@@ -1,5 +1,5 @@
 import polars as pl
-from my_polars_functions import hamming_distance
+from my_polars_functions import grouped_mean, grouped_mean_by, hamming_distance
 
 a = pl.Series("a", ["foo", "bar"])
 b = pl.Series("b", ["fooy", "ham"])
@@ -17,3 +17,13 @@ print(
         pl.map(["a", "b"], lambda series: hamming_distance(series[0], series[1]))
     )
 )
+
+# pass the groups of a group by to a custom grouped kernel
+df = pl.DataFrame({"g": ["a", "b", "a", "c", "b", "a"], "x": [1.0, 2, 3, 4, None, 6]})
+groups = df.group_by("g", maintain_order=True).agg(
+    pl.col("g").agg_groups().alias("groups"), pl.col("x").mean()
+)
+expected = groups["x"]
+print("grouped mean: ", grouped_mean(df["x"], groups["groups"]))
+assert grouped_mean(df["x"], groups["groups"]).series_equal(expected.rename(""))
+assert grouped_mean_by(df["x"], df["g"]).series_equal(expected.rename(""))
@@ -43,8 +43,77 @@ fn hamming_distance_strs(a: Option<&str>, b: Option<&str>) -> Option<u32> {
     }
 }
 
+#[pyfunction]
+fn grouped_mean(values: &PyAny, groups: &PyAny) -> PyResult<PyObject> {
+    let values = ffi::py_series_to_rust_series(values)?;
+    let groups = ffi::py_series_to_rust_series(groups)?;
+
+    let out = grouped_mean_from_list(&values, &groups)
+        .map_err(|e| PyValueError::new_err(format!("Something went wrong: {:?}", e)))?;
+    ffi::rust_series_to_py_series(&out.into_series())
+}
+
+#[pyfunction]
+fn grouped_mean_by(values: &PyAny, keys: &PyAny) -> PyResult<PyObject> {
+    let values = ffi::py_series_to_rust_series(values)?;
+    let keys = ffi::py_series_to_rust_series(keys)?;
+
+    let out = grouped_mean_by_impl(&values, &keys)
+        .map_err(|e| PyValueError::new_err(format!("Something went wrong: {:?}", e)))?;
+    ffi::rust_series_to_py_series(&out.into_series())
+}
+
+/// The groups of an `agg_groups()` column are a list array, its offsets and values are the
+/// flat group buffers, so they are passed to the kernel without copying.
+fn grouped_mean_from_list(values: &Series, groups: &Series) -> PolarsResult<Float64Chunked> {
+    let groups = groups.list()?.rechunk();
+    let arr = groups.downcast_iter().next().unwrap();
+    let offsets = arr
+        .offsets()
+        .iter()
+        .map(|o| *o as IdxSize)
+        .collect::<Vec<_>>();
+    let indices = arr
+        .values()
+        .as_any()
+        .downcast_ref::<IdxArr>()
+        .ok_or_else(|| polars_err!(ComputeError: "expected the output of `agg_groups`"))?;
+    grouped_mean_impl(values, &offsets, indices.values())
+}
+
+/// Group by `keys` on the eager API and pass the flat group buffers to the kernel.
+fn grouped_mean_by_impl(values: &Series, keys: &Series) -> PolarsResult<Float64Chunked> {
+    let df = DataFrame::new(vec![keys.clone()])?;
+    let gb = df.group_by_stable([keys.name()])?;
+    let (offsets, indices) = gb.get_groups().to_offsets_and_indices();
+    grouped_mean_impl(values, &offsets, &indices)
+}
+
+/// A custom grouped kernel: the mean of the non-null values of every group. The row indices
+/// of group `i` are `indices[offsets[i]..offsets[i + 1]]`.
+fn grouped_mean_impl(
+    values: &Series,
+    offsets: &[IdxSize],
+    indices: &[IdxSize],
+) -> PolarsResult<Float64Chunked> {
+    let values = values.cast(&DataType::Float64)?;
+    let values = values.f64()?.rechunk();
+    Ok(offsets
+        .windows(2)
+        .map(|w| {
+            let (sum, count) = indices[w[0] as usize..w[1] as usize]
+                .iter()
+                .filter_map(|&i| values.get(i as usize))
+                .fold((0.0, 0), |(sum, count), v| (sum + v, count + 1));
+            (count > 0).then(|| sum / count as f64)
+        })
+        .collect())
+}
+
 #[pymodule]
 fn my_polars_functions(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(hamming_distance)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(grouped_mean)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(grouped_mean_by)).unwrap();
     Ok(())
 }
@@ -134,6 +134,7 @@ from polars.functions import (
     datetime_ranges,
     duration,
     element,
+    element_index,
     exclude,
     first,
     fold,
@@ -212,6 +213,7 @@ from polars.meta import (
     thread_pool_size,
     threadpool_size,
 )
+from polars.schema import Schema
 from polars.series import Series
 from polars.sql import SQLContext
 from polars.string_cache import (
@@ -255,6 +257,7 @@ __all__ = [
     "LazyFrame",
     "Series",
     "InProcessQuery",
+    "Schema",
     # polars.datatypes
     "Array",
     "Binary",
@@ -338,6 +341,7 @@ __all__ = [
     "datetime_range",
     "datetime_ranges",
     "element",
+    "element_index",
     "ones",
     "repeat",
     "time_range",
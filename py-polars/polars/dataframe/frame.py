@@ -2854,6 +2854,8 @@ class DataFrame:
         statistics: bool = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter_columns: Sequence[str] | None = None,
+        bloom_filter_fpp: float = 0.01,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
     ) -> None:
@@ -2883,6 +2885,14 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        bloom_filter_columns
+            Write a split block bloom filter for every row group of these columns.
+            Readers can use the filters to skip row groups that don't contain a
+            value. The columns must have an integer, float, date, string or binary
+            type.
+        bloom_filter_fpp
+            The false positive probability the bloom filters are sized for, between
+            0 and 1.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -2976,6 +2986,8 @@ class DataFrame:
                 statistics,
                 row_group_size,
                 data_page_size,
+                bloom_filter_columns,
+                bloom_filter_fpp,
             )
 
     @deprecate_renamed_parameter("if_exists", "if_table_exists", version="0.20.0")
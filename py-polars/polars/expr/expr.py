@@ -1527,6 +1527,9 @@ class Expr:
         A NaN value is not the same as a null value.
         To drop null values, use :func:`drop_nulls`.
 
+        Only float columns can contain NaN values; for columns of any other data type
+        this is a no-op.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1.0, None, 3.0, float("nan")]})
@@ -3713,6 +3716,7 @@ class Expr:
         labels: Sequence[str] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        index_only: bool = False,
     ) -> Self:
         """
         Bin continuous values into discrete categories.
@@ -3734,12 +3738,17 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        index_only
+            Return the zero-based index of the bin as :class:`UInt32` instead of its
+            category. `labels` are ignored. Combined with `include_breaks`, the index
+            is stored in the category field of the struct.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` (:class:`UInt32` if
+            `index_only` is set) if `include_breaks` is set to `False` (default),
+            otherwise an expression of data type :class:`Struct`.
 
         See Also
         --------
@@ -3785,7 +3794,7 @@ class Expr:
         └─────┴──────┴────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.cut(breaks, labels, left_closed, include_breaks)
+            self._pyexpr.cut(breaks, labels, left_closed, include_breaks, index_only)
         )
 
     @unstable()
@@ -3797,6 +3806,7 @@ class Expr:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        index_only: bool = False,
     ) -> Self:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -3823,12 +3833,17 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        index_only
+            Return the zero-based index of the bin as :class:`UInt32` instead of its
+            category. `labels` are ignored. Combined with `include_breaks`, the index
+            is stored in the category field of the struct.
 
         Returns
         -------
         Expr
-            Expression of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise an expression of data type :class:`Struct`.
+            Expression of data type :class:`Categorical` (:class:`UInt32` if
+            `index_only` is set) if `include_breaks` is set to `False` (default),
+            otherwise an expression of data type :class:`Struct`.
 
         See Also
         --------
@@ -3896,11 +3911,21 @@ class Expr:
         """
         if isinstance(quantiles, int):
             pyexpr = self._pyexpr.qcut_uniform(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                index_only,
             )
         else:
             pyexpr = self._pyexpr.qcut(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                index_only,
             )
 
         return self._from_pyexpr(pyexpr)
@@ -4612,6 +4637,9 @@ class Expr:
         """
         Take every nth value in the Series and return as a new Series.
 
+        In a group by context the rows are counted per group, so every group starts
+        at its own `offset`.
+
         Parameters
         ----------
         n
@@ -5271,6 +5299,9 @@ class Expr:
         """
         Method equivalent of unary minus operator `-expr`.
 
+        Unsigned integers are upcast to the next wider signed integer type. Raises
+        if the result doesn't fit, e.g. for the minimum value of a signed integer.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [-1, 0, 2, None]})
@@ -5748,8 +5779,9 @@ class Expr:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
-            Interpolation method.
+        method : {'linear', 'nearest', 'pchip'}
+            Interpolation method. 'pchip' fills the gaps with a monotone piecewise cubic
+            curve that doesn't overshoot its neighboring values.
 
         Examples
         --------
@@ -6525,7 +6557,9 @@ class Expr:
         center: bool = False,
         by: str | None = None,
         closed: ClosedInterval | None = None,
+        normalize_weights: bool = False,
         warn_if_unsorted: bool = True,
+        precise: bool = False,
     ) -> Self:
         """
         Apply a rolling sum (moving sum) over the values in this array.
@@ -6593,8 +6627,14 @@ class Expr:
         closed : {'left', 'right', 'both', 'none'}
             Define which sides of the temporal interval are closed (inclusive); only
             applicable if `by` has been set (in which case, it defaults to `'right'`).
+        normalize_weights
+            Scale the weights of the non-null values in every window to sum to one,
+            which turns the weighted sum into the weighted mean.
         warn_if_unsorted
             Warn if data is not known to be sorted by `by` column (if passed).
+        precise
+            Sum float windows with compensated (Kahan) summation, which keeps long
+            columns with values of mixed magnitudes accurate at some cost in speed.
 
         Notes
         -----
@@ -6748,7 +6788,15 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_sum(
-                window_size, weights, min_periods, center, by, closed, warn_if_unsorted
+                window_size,
+                weights,
+                min_periods,
+                center,
+                by,
+                closed,
+                normalize_weights,
+                warn_if_unsorted,
+                precise,
             )
         )
 
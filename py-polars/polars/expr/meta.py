@@ -187,6 +187,42 @@ class ExprMetaNameSpace:
         """
         return self._pyexpr.meta_root_names()
 
+    def free_columns(self) -> list[str]:
+        """
+        Get the distinct column names this expression reads.
+
+        Unlike :meth:`root_names`, every name is returned once, in order of first
+        appearance. This is the minimal set of columns needed to evaluate the
+        expression.
+
+        Examples
+        --------
+        >>> e = pl.col("a") + pl.col("a") * pl.col("b")
+        >>> e.meta.root_names()
+        ['a', 'a', 'b']
+        >>> e.meta.free_columns()
+        ['a', 'b']
+        """
+        return self._pyexpr.meta_free_columns()
+
+    def estimated_cost(self) -> int:
+        """
+        Get a rough estimate of the cost of evaluating this expression.
+
+        The estimate is a heuristic that doesn't look at the data. Every node of the
+        expression tree gets a fixed weight: column projections and literals are
+        cheap, while sorts, aggregations, window functions and regex projections are
+        expensive. The estimates are only meaningful relative to each other.
+
+        Examples
+        --------
+        >>> cheap = pl.col("a") + 1
+        >>> expensive = pl.col("a").sort().sum().over("b")
+        >>> cheap.meta.estimated_cost() < expensive.meta.estimated_cost()
+        True
+        """
+        return self._pyexpr.meta_estimated_cost()
+
     def undo_aliases(self) -> Expr:
         """
         Undo any renaming operation like `alias` or `name.keep`.
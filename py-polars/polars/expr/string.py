@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import warnings
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Sequence
 
 import polars._reexport as pl
 from polars import functions as F
@@ -208,7 +208,7 @@ class ExprStringNameSpace:
     def strptime(
         self,
         dtype: PolarsTemporalType,
-        format: str | None = None,
+        format: str | Sequence[str] | None = None,
         *,
         strict: bool = True,
         exact: bool = True,
@@ -228,11 +228,14 @@ class ExprStringNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for the full specification. Example: `"%Y-%m-%d %H:%M:%S"`.
             If set to None (default), the format is inferred from the data.
+            If a sequence of formats is given, they are tried in order on every value
+            and the first one that matches is used.
         strict
             Raise an error if any conversion fails.
         exact
             Require an exact format match. If False, allow the format to match anywhere
-            in the target string. Conversion to the Time type is always exact.
+            in the target string. Conversion to the Time type is always exact, as is
+            parsing with multiple formats.
 
             .. note::
                 Using `exact=False` introduces a performance penalty - cleaning your
@@ -286,14 +289,7 @@ class ExprStringNameSpace:
         ...         "Sun Jul  8 00:34:60 2001",
         ...     ],
         ... )
-        >>> s.to_frame().select(
-        ...     pl.coalesce(
-        ...         pl.col("date").str.strptime(pl.Date, "%F", strict=False),
-        ...         pl.col("date").str.strptime(pl.Date, "%F %T", strict=False),
-        ...         pl.col("date").str.strptime(pl.Date, "%D", strict=False),
-        ...         pl.col("date").str.strptime(pl.Date, "%c", strict=False),
-        ...     )
-        ... ).to_series()
+        >>> s.str.strptime(pl.Date, ["%F", "%F %T", "%D", "%c"])
         shape: (4,)
         Series: 'date' [date]
         [
@@ -303,6 +299,15 @@ class ExprStringNameSpace:
                 2001-07-08
         ]
         """
+        if format is not None and not isinstance(format, str):
+            return self._strptime_formats(
+                dtype,
+                format,
+                strict=strict,
+                exact=exact,
+                cache=cache,
+                ambiguous=rename_use_earliest_to_ambiguous(use_earliest, ambiguous),
+            )
         if dtype == Date:
             return self.to_date(format, strict=strict, exact=exact, cache=cache)
         elif dtype == Datetime:
@@ -324,6 +329,41 @@ class ExprStringNameSpace:
             msg = "`dtype` must be of type {Date, Datetime, Time}"
             raise ValueError(msg)
 
+    def _strptime_formats(
+        self,
+        dtype: PolarsTemporalType,
+        formats: Sequence[str],
+        *,
+        strict: bool,
+        exact: bool,
+        cache: bool,
+        ambiguous: Ambiguous | Expr,
+    ) -> Expr:
+        if not exact:
+            msg = "`exact=False` is not supported when parsing with multiple formats"
+            raise ValueError(msg)
+        formats = list(formats)
+        if not formats:
+            msg = "at least one format is required"
+            raise ValueError(msg)
+        for fmt in formats:
+            _validate_format_argument(fmt)
+
+        if dtype == Datetime:
+            time_unit = dtype.time_unit or "us"  # type: ignore[union-attr]
+            dtype = Datetime(time_unit, dtype.time_zone)  # type: ignore[union-attr]
+        elif dtype not in (Date, Time):
+            msg = "`dtype` must be of type {Date, Datetime, Time}"
+            raise ValueError(msg)
+
+        if not isinstance(ambiguous, pl.Expr):
+            ambiguous = F.lit(ambiguous)
+        return wrap_expr(
+            self._pyexpr.str_strptime_formats(
+                dtype, formats, strict, cache, ambiguous._pyexpr
+            )
+        )
+
     def to_decimal(
         self,
         inference_length: int = 100,
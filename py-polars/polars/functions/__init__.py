@@ -47,6 +47,7 @@ from polars.functions.lazy import (
     cumfold,
     cumreduce,
     element,
+    element_index,
     exclude,
     first,
     fold,
@@ -114,6 +115,7 @@ __all__ = [
     "datetime_range",
     "datetime_ranges",
     "element",
+    "element_index",
     "ones",
     "repeat",
     "time_range",
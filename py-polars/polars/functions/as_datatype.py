@@ -396,7 +396,11 @@ def duration(
     )
 
 
-def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
+def concat_list(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+    ignore_nulls: bool = False,
+) -> Expr:
     """
     Horizontally concatenate columns into a single list column.
 
@@ -411,6 +415,8 @@ def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) ->
     *more_exprs
         Additional columns to concatenate into a single list column, specified as
         positional arguments.
+    ignore_nulls
+        Drop null values from the resulting lists.
 
     Examples
     --------
@@ -435,7 +441,7 @@ def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) ->
     └───────────────────┘
     """
     exprs = parse_as_list_of_expressions(exprs, *more_exprs)
-    return wrap_expr(plr.concat_list(exprs))
+    return wrap_expr(plr.concat_list(exprs, ignore_nulls))
 
 
 @overload
@@ -89,6 +89,36 @@ def element() -> Expr:
     return F.col("")
 
 
+def element_index() -> Expr:
+    """
+    The position of an element being evaluated in an `eval` expression.
+
+    The index is 0-based and counts from the start of the list the element belongs
+    to. In a nested `eval` it refers to the innermost list. Using this expression
+    outside of `list.eval` raises an error.
+
+    Examples
+    --------
+    Weigh the elements of each list by their position
+
+    >>> df = pl.DataFrame({"a": [[1, 2, 3], [], [4, 5]]})
+    >>> df.with_columns(
+    ...     pl.col("a").list.eval(pl.element() * pl.element_index()).alias("weighted")
+    ... )
+    shape: (3, 2)
+    ┌───────────┬───────────┐
+    │ a         ┆ weighted  │
+    │ ---       ┆ ---       │
+    │ list[i64] ┆ list[i64] │
+    ╞═══════════╪═══════════╡
+    │ [1, 2, 3] ┆ [0, 2, 6] │
+    │ []        ┆ []        │
+    │ [4, 5]    ┆ [0, 5]    │
+    └───────────┴───────────┘
+    """
+    return wrap_expr(plr.element_index())
+
+
 @deprecate_parameter_as_positional("column", version="0.20.4")
 def count(*columns: str) -> Expr:
     """
@@ -2141,6 +2171,7 @@ def rolling_corr(
     window_size: int,
     min_periods: int | None = None,
     ddof: int = 1,
+    spearman: bool = False,
 ) -> Expr:
     """
     Compute the rolling correlation between two columns/ expressions.
@@ -2166,6 +2197,9 @@ def rolling_corr(
     ddof
         Delta degrees of freedom.  The divisor used in calculations
         is `N - ddof`, where `N` represents the number of elements.
+    spearman
+        Rank the values within each window and compute the Spearman rank correlation
+        instead of the Pearson correlation.
     """
     if min_periods is None:
         min_periods = window_size
@@ -2174,7 +2208,9 @@ def rolling_corr(
     if isinstance(b, str):
         b = F.col(b)
     return wrap_expr(
-        plr.rolling_corr(a._pyexpr, b._pyexpr, window_size, min_periods, ddof)
+        plr.rolling_corr(
+            a._pyexpr, b._pyexpr, window_size, min_periods, ddof, spearman
+        )
     )
 
 
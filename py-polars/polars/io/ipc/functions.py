@@ -2,10 +2,11 @@ from __future__ import annotations
 
 import contextlib
 from pathlib import Path
-from typing import IO, TYPE_CHECKING, Any, Sequence
+from typing import IO, TYPE_CHECKING, Any, Callable, Sequence
 
 import polars._reexport as pl
 from polars._utils.deprecation import deprecate_renamed_parameter
+from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import (
     is_str_sequence,
     normalize_filepath,
@@ -312,6 +313,7 @@ def scan_ipc(
     storage_options: dict[str, Any] | None = None,
     memory_map: bool = True,
     retries: int = 0,
+    credential_provider: Callable[[], dict[str, Any]] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from an Arrow IPC (Feather v2) file or multiple files via glob patterns.
@@ -344,8 +346,26 @@ def scan_ipc(
         Only uncompressed IPC files can be memory mapped.
     retries
         Number of retries if accessing a cloud instance fails.
+    credential_provider
+        Function that returns AWS credentials as a dictionary with the keys
+        `aws_access_key_id`, `aws_secret_access_key` and optionally
+        `aws_session_token` and `expiry`, the latter in seconds since the Unix
+        epoch. It is called again when the credentials are about to expire, which
+        keeps long running queries working with short lived credentials.
+        The file is not opened with `fsspec` if this is set.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     """
+    if credential_provider is not None:
+        msg = (
+            "The `credential_provider` parameter of `scan_ipc` is considered"
+            " unstable."
+        )
+        issue_unstable_warning(msg)
+
     if isinstance(source, (str, Path)):
         can_use_fsspec = True
         source = normalize_filepath(source)
@@ -356,7 +376,11 @@ def scan_ipc(
         source = None  # type: ignore[assignment]
 
     # try fsspec scanner
-    if can_use_fsspec and not is_local_file(source):  # type: ignore[arg-type]
+    if (
+        can_use_fsspec
+        and credential_provider is None
+        and not is_local_file(source)  # type: ignore[arg-type]
+    ):
         scan = _scan_ipc_fsspec(source, storage_options)  # type: ignore[arg-type]
         if n_rows:
             scan = scan.head(n_rows)
@@ -374,5 +398,6 @@ def scan_ipc(
         memory_map=memory_map,
         cloud_options=storage_options,
         retries=retries,
+        credential_provider=credential_provider,
     )
     return wrap_ldf(pylf)
@@ -3,7 +3,7 @@ from __future__ import annotations
 import contextlib
 import io
 from pathlib import Path
-from typing import IO, TYPE_CHECKING, Any, Sequence
+from typing import IO, TYPE_CHECKING, Any, Callable, Sequence
 
 from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.unstable import issue_unstable_warning
@@ -29,7 +29,7 @@ with contextlib.suppress(ImportError):
 
 if TYPE_CHECKING:
     from polars import DataFrame, DataType, LazyFrame
-    from polars.type_aliases import ParallelStrategy, SchemaDict
+    from polars.type_aliases import ParallelStrategy, ScanErrorPolicy, SchemaDict
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -49,6 +49,7 @@ def read_parquet(
     low_memory: bool = False,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    credential_provider: Callable[[], dict[str, Any]] | None = None,
     use_pyarrow: bool = False,
     pyarrow_options: dict[str, Any] | None = None,
     memory_map: bool = True,
@@ -112,6 +113,13 @@ def read_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    credential_provider
+        Function that returns AWS credentials as a dictionary, see
+        :func:`scan_parquet`. Not used when `use_pyarrow=True`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     use_pyarrow
         Use PyArrow instead of the Rust-native Parquet reader. The PyArrow reader is
         more stable.
@@ -191,6 +199,7 @@ def read_parquet(
         cache=False,
         storage_options=storage_options,
         retries=retries,
+        credential_provider=credential_provider,
     )
 
     if columns is not None:
@@ -299,6 +308,9 @@ def scan_parquet(
     cache: bool = True,
     storage_options: dict[str, Any] | None = None,
     retries: int = 0,
+    credential_provider: Callable[[], dict[str, Any]] | None = None,
+    on_error: ScanErrorPolicy = "raise",
+    uuid_as_string: bool = False,
 ) -> LazyFrame:
     """
     Lazily read from a local or cloud-hosted parquet file (or files).
@@ -357,6 +369,26 @@ def scan_parquet(
         from environment variables.
     retries
         Number of retries if accessing a cloud instance fails.
+    credential_provider
+        Function that returns AWS credentials as a dictionary with the keys
+        `aws_access_key_id`, `aws_secret_access_key` and optionally
+        `aws_session_token` and `expiry`, the latter in seconds since the Unix
+        epoch. It is called again when the credentials are about to expire, which
+        keeps long running queries working with short lived credentials.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    on_error : {'raise', 'skip_file'}
+        What to do when one of the files can't be read. 'raise' aborts the query,
+        'skip_file' leaves the file out of the result and continues with the
+        remaining files, and the schema is taken from the first file that can be
+        read. :meth:`LazyFrame.collect_with_scan_errors` returns the skipped files
+        with their errors. Errors that are not caused by reading a file still abort
+        the query.
+    uuid_as_string
+        Read columns with the parquet `UUID` logical type as strings in their
+        canonical hyphenated form instead of as binary values.
 
     See Also
     --------
@@ -383,6 +415,12 @@ def scan_parquet(
     if hive_schema is not None:
         msg = "The `hive_schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
+    if credential_provider is not None:
+        msg = (
+            "The `credential_provider` parameter of `scan_parquet` is considered"
+            " unstable."
+        )
+        issue_unstable_warning(msg)
 
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source)
@@ -403,6 +441,9 @@ def scan_parquet(
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
         retries=retries,
+        credential_provider=credential_provider,
+        on_error=on_error,
+        uuid_as_string=uuid_as_string,
     )
 
 
@@ -421,6 +462,9 @@ def _scan_parquet_impl(
     hive_partitioning: bool = True,
     hive_schema: SchemaDict | None = None,
     retries: int = 0,
+    credential_provider: Callable[[], dict[str, Any]] | None = None,
+    on_error: ScanErrorPolicy = "raise",
+    uuid_as_string: bool = False,
 ) -> LazyFrame:
     if isinstance(source, list):
         sources = source
@@ -463,5 +507,8 @@ def _scan_parquet_impl(
         hive_partitioning=hive_partitioning,
         hive_schema=hive_schema,
         retries=retries,
+        credential_provider=credential_provider,
+        on_error=on_error,
+        uuid_as_string=uuid_as_string,
     )
     return wrap_ldf(pylf)
@@ -1809,6 +1809,35 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return wrap_df(ldf.collect())
 
+    @unstable()
+    def collect_with_scan_errors(self) -> tuple[DataFrame, list[tuple[str, str]]]:
+        """
+        Collect into a DataFrame, together with the files that scans skipped.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Scans created with `on_error="skip_file"` leave out the files that can't be
+        read instead of aborting the query. This returns the resulting DataFrame and
+        the path and error message of every skipped file.
+
+        Returns
+        -------
+        tuple of (DataFrame, list of (path, error))
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet(
+        ...     ["data/a.parquet", "data/broken.parquet"], on_error="skip_file"
+        ... )  # doctest: +SKIP
+        >>> df, errors = lf.collect_with_scan_errors()  # doctest: +SKIP
+        >>> errors  # doctest: +SKIP
+        [('data/broken.parquet', 'parquet: File out of specification: ...')]
+        """
+        df, errors = self._ldf.collect_with_scan_errors()
+        return wrap_df(df), errors
+
     @overload
     def collect_async(
         self,
@@ -1985,6 +2014,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
         maintain_order: bool = True,
+        bloom_filter_columns: Sequence[str] | None = None,
+        bloom_filter_fpp: float = 0.01,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
         projection_pushdown: bool = True,
@@ -2030,6 +2061,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
+        bloom_filter_columns
+            Write a split block bloom filter for every row group of these columns.
+            Readers can use the filters to skip row groups that don't contain a
+            value. The columns must have an integer, float, date, string or binary
+            type.
+        bloom_filter_fpp
+            The false positive probability the bloom filters are sized for, between
+            0 and 1.
         type_coercion
             Do type coercion optimization.
         predicate_pushdown
@@ -2069,6 +2108,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
             maintain_order=maintain_order,
+            bloom_filter_columns=bloom_filter_columns,
+            bloom_filter_fpp=bloom_filter_fpp,
         )
 
     @unstable()
@@ -3016,8 +3057,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order
             Ensure that the order of the groups is consistent with the input data.
             This is slower than a default group by.
-            Setting this to `True` blocks the possibility
-            to run on the streaming engine.
+            On the streaming engine all groups are materialized and sorted before
+            they are returned.
         **named_by
             Additional columns to group by, specified as keyword arguments.
             The columns will be renamed to the keyword used.
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import contextlib
+from collections import OrderedDict
+from typing import TYPE_CHECKING, Any
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from polars.type_aliases import PolarsDataType
+
+    BaseSchema = OrderedDict[str, PolarsDataType]
+else:
+    BaseSchema = OrderedDict
+
+
+class Schema(BaseSchema):
+    """
+    Ordered mapping of column names to their data type.
+
+    Examples
+    --------
+    >>> schema = pl.Schema({"foo": pl.Int8(), "bar": pl.String()})
+    >>> schema
+    Schema({'foo': Int8, 'bar': String})
+    """
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}({dict(self)!r})"
+
+    @classmethod
+    def from_typed_dict(cls, typed: Any) -> Schema:
+        """
+        Create a schema from the type annotations of a class.
+
+        Works for `TypedDict` classes, dataclasses and `NamedTuple` classes. Nested
+        classes of these kinds become `Struct`, `list[T]` becomes `List` and
+        `Optional[T]` maps to the dtype of `T`.
+
+        Parameters
+        ----------
+        typed
+            The annotated class.
+
+        Raises
+        ------
+        TypeError
+            If an annotation can not be mapped unambiguously to a Polars data type,
+            or if a class refers to itself. The message contains the path of the
+            annotation, e.g. `a.b[]` for the items of list field `b` in struct
+            field `a`.
+
+        Examples
+        --------
+        >>> from typing import List, Optional, TypedDict
+        >>> class Point(TypedDict):
+        ...     x: float
+        ...     y: float
+        >>> class Shape(TypedDict):
+        ...     name: str
+        ...     points: Optional[List[Point]]
+        >>> pl.Schema.from_typed_dict(Shape)
+        Schema({'name': String, 'points': List(Struct({'x': Float64, 'y': Float64}))})
+        """
+        return cls(plr.schema_from_python_type(typed))
@@ -1774,6 +1774,9 @@ class Series:
         A NaN value is not the same as a null value.
         To drop null values, use :func:`drop_nulls`.
 
+        Only float Series can contain NaN values; for a Series of any other data type
+        this is a no-op.
+
         Examples
         --------
         >>> s = pl.Series([1.0, None, 3.0, float("nan")])
@@ -2151,6 +2154,7 @@ class Series:
         *,
         left_closed: bool = ...,
         include_breaks: bool = ...,
+        index_only: bool = ...,
         as_series: Literal[True] = ...,
     ) -> Series: ...
 
@@ -2164,6 +2168,7 @@ class Series:
         *,
         left_closed: bool = ...,
         include_breaks: bool = ...,
+        index_only: bool = ...,
         as_series: Literal[False],
     ) -> DataFrame: ...
 
@@ -2177,6 +2182,7 @@ class Series:
         *,
         left_closed: bool = ...,
         include_breaks: bool = ...,
+        index_only: bool = ...,
         as_series: bool,
     ) -> Series | DataFrame: ...
 
@@ -2192,6 +2198,7 @@ class Series:
         *,
         left_closed: bool = False,
         include_breaks: bool = False,
+        index_only: bool = False,
         as_series: bool = True,
     ) -> Series | DataFrame:
         """
@@ -2228,6 +2235,10 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        index_only
+            Return the zero-based index of the bin as :class:`UInt32` instead of its
+            category. `labels` are ignored. Combined with `include_breaks`, the index
+            is stored in the category field of the struct.
         as_series
             If set to `False`, return a DataFrame containing the original values,
             the breakpoints, and the categories.
@@ -2240,8 +2251,9 @@ class Series:
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` (:class:`UInt32` if `index_only`
+            is set) if `include_breaks` is set to `False` (default), otherwise a Series
+            of data type :class:`Struct`.
 
         See Also
         --------
@@ -2310,6 +2322,7 @@ class Series:
                         labels=labels,
                         left_closed=left_closed,
                         include_breaks=True,  # always include breaks
+                        index_only=index_only,
                     )
                     .alias(temp_name)
                 )
@@ -2325,6 +2338,7 @@ class Series:
                     labels=labels,
                     left_closed=left_closed,
                     include_breaks=include_breaks,
+                    index_only=index_only,
                 )
             )
             .to_series()
@@ -2344,6 +2358,7 @@ class Series:
         left_closed: bool = ...,
         allow_duplicates: bool = ...,
         include_breaks: bool = ...,
+        index_only: bool = ...,
         break_point_label: str = ...,
         category_label: str = ...,
         as_series: Literal[True] = ...,
@@ -2358,6 +2373,7 @@ class Series:
         left_closed: bool = ...,
         allow_duplicates: bool = ...,
         include_breaks: bool = ...,
+        index_only: bool = ...,
         break_point_label: str = ...,
         category_label: str = ...,
         as_series: Literal[False],
@@ -2372,6 +2388,7 @@ class Series:
         left_closed: bool = ...,
         allow_duplicates: bool = ...,
         include_breaks: bool = ...,
+        index_only: bool = ...,
         break_point_label: str = ...,
         category_label: str = ...,
         as_series: bool,
@@ -2386,6 +2403,7 @@ class Series:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        index_only: bool = False,
         break_point_label: str = "break_point",
         category_label: str = "category",
         as_series: bool = True,
@@ -2415,6 +2433,10 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        index_only
+            Return the zero-based index of the bin as :class:`UInt32` instead of its
+            category. `labels` are ignored. Combined with `include_breaks`, the index
+            is stored in the category field of the struct.
         break_point_label
             Name of the breakpoint column. Only used if `include_breaks` is set to
             `True`.
@@ -2441,8 +2463,9 @@ class Series:
         Returns
         -------
         Series
-            Series of data type :class:`Categorical` if `include_breaks` is set to
-            `False` (default), otherwise a Series of data type :class:`Struct`.
+            Series of data type :class:`Categorical` (:class:`UInt32` if `index_only`
+            is set) if `include_breaks` is set to `False` (default), otherwise a Series
+            of data type :class:`Struct`.
 
         See Also
         --------
@@ -2526,6 +2549,7 @@ class Series:
                         left_closed=left_closed,
                         allow_duplicates=allow_duplicates,
                         include_breaks=True,  # always include breaks
+                        index_only=index_only,
                     )
                     .alias(temp_name)
                 )
@@ -2542,6 +2566,7 @@ class Series:
                     left_closed=left_closed,
                     allow_duplicates=allow_duplicates,
                     include_breaks=include_breaks,
+                    index_only=index_only,
                 )
             )
             .to_series()
@@ -5633,6 +5658,8 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        normalize_weights: bool = False,
+        precise: bool = False,
     ) -> Series:
         """
         Apply a rolling sum (moving sum) over the values in this array.
@@ -5663,6 +5690,12 @@ class Series:
             - 1, if `window_size` is a dynamic temporal size
         center
             Set the labels at the center of the window
+        normalize_weights
+            Scale the weights of the non-null values in every window to sum to one,
+            which turns the weighted sum into the weighted mean.
+        precise
+            Sum float windows with compensated (Kahan) summation, which keeps long
+            columns with values of mixed magnitudes accurate at some cost in speed.
 
         Examples
         --------
@@ -5682,7 +5715,12 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_sum(
-                    window_size, weights, min_periods, center=center
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    normalize_weights=normalize_weights,
+                    precise=precise,
                 )
             )
             .to_series()
@@ -6229,8 +6267,9 @@ class Series:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
-            Interpolation method.
+        method : {'linear', 'nearest', 'pchip'}
+            Interpolation method. 'pchip' fills the gaps with a monotone piecewise cubic
+            curve that doesn't overshoot its neighboring values.
 
         Examples
         --------
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Sequence
 
 from polars._utils.deprecation import (
     deprecate_renamed_function,
@@ -192,7 +192,7 @@ class StringNameSpace:
     def strptime(
         self,
         dtype: PolarsTemporalType,
-        format: str | None = None,
+        format: str | Sequence[str] | None = None,
         *,
         strict: bool = True,
         exact: bool = True,
@@ -212,11 +212,14 @@ class StringNameSpace:
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for the full specification. Example: `"%Y-%m-%d %H:%M:%S"`.
             If set to None (default), the format is inferred from the data.
+            If a sequence of formats is given, they are tried in order on every value
+            and the first one that matches is used.
         strict
             Raise an error if any conversion fails.
         exact
             Require an exact format match. If False, allow the format to match anywhere
-            in the target string. Conversion to the Time type is always exact.
+            in the target string. Conversion to the Time type is always exact, as is
+            parsing with multiple formats.
 
             .. note::
                 Using `exact=False` introduces a performance penalty - cleaning your
@@ -108,6 +108,7 @@ PivotAgg: TypeAlias = Literal[
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
+ScanErrorPolicy: TypeAlias = Literal["raise", "skip_file"]
 SizeUnit: TypeAlias = Literal[
     "b",
     "kb",
@@ -139,7 +140,7 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
-InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
+InterpolationMethod: TypeAlias = Literal["linear", "nearest", "pchip"]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "outer", "semi", "anti", "cross", "outer_coalesce"
 ]  # JoinType
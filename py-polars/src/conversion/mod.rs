@@ -1,5 +1,6 @@
 pub(crate) mod any_value;
 pub(crate) mod chunked_array;
+pub(crate) mod python_type;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 
@@ -11,6 +12,8 @@ use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(feature = "cloud")]
+use polars::io::cloud::{AwsCredentials, CredentialProviderFunction};
 use polars::series::ops::NullBehavior;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
@@ -18,7 +21,7 @@ use polars_core::utils::materialize_dyn_int;
 use polars_lazy::prelude::*;
 use polars_utils::total_ord::{TotalEq, TotalHash};
 use pyo3::basic::CompareOp;
-use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
@@ -582,10 +585,11 @@ impl FromPyObject<'_> for Wrap<InterpolationMethod> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "pchip" => InterpolationMethod::Pchip,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
-                )))
+                "interpolation `method` must be one of {{'linear', 'nearest', 'pchip'}}, got {v}",
+            )))
             },
         };
         Ok(Wrap(parsed))
@@ -811,6 +815,22 @@ impl FromPyObject<'_> for Wrap<ParallelStrategy> {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl FromPyObject<'_> for Wrap<ScanErrorPolicy> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "raise" => ScanErrorPolicy::Raise,
+            "skip_file" => ScanErrorPolicy::SkipFile,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`on_error` must be one of {{'raise', 'skip_file'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<IndexOrder> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1019,6 +1039,41 @@ pub(crate) fn parse_cloud_options(uri: &str, kv: Vec<(String, String)>) -> PyRes
     Ok(out)
 }
 
+/// Wrap a Python callable that returns a dict of AWS credentials. The dict holds the
+/// keys `aws_access_key_id`, `aws_secret_access_key` and optionally `aws_session_token`
+/// and `expiry`, the latter in seconds since the Unix epoch.
+#[cfg(feature = "cloud")]
+pub(crate) fn parse_credential_provider(func: PyObject) -> CredentialProviderFunction {
+    CredentialProviderFunction::new(move || {
+        Python::with_gil(|py| {
+            let out = func.call0(py)?;
+            let out = out.downcast_bound::<PyDict>(py)?;
+            let get = |key: &str| {
+                out.get_item(key)
+                    .map(|value| value.filter(|value| !value.is_none()))
+            };
+            let required = |key: &str| -> PyResult<String> {
+                get(key)?
+                    .ok_or_else(|| {
+                        PyKeyError::new_err(format!("credential provider did not return '{key}'"))
+                    })?
+                    .extract()
+            };
+            let expiry = get("expiry")?
+                .map(|secs| secs.extract::<f64>())
+                .transpose()?
+                .map(|secs| std::time::UNIX_EPOCH + std::time::Duration::from_secs_f64(secs));
+            Ok(AwsCredentials {
+                key_id: required("aws_access_key_id")?,
+                secret_key: required("aws_secret_access_key")?,
+                session_token: get("aws_session_token")?.map(|v| v.extract()).transpose()?,
+                expiry,
+            })
+        })
+        .map_err(|e: PyErr| polars_err!(ComputeError: "credential provider failed: {}", e))
+    })
+}
+
 #[cfg(feature = "list_sets")]
 impl FromPyObject<'_> for Wrap<SetOperation> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
@@ -0,0 +1,193 @@
+use polars::datatypes::{DataType, Field, TimeUnit};
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+/// Convert a Python type annotation to a [`DataType`].
+///
+/// `path` locates the annotation in the type being converted, e.g. `a.b[]` for the item type
+/// of the list field `b` of the struct field `a`. It is only used in error messages.
+pub(crate) fn py_type_to_dtype(tp: &Bound<'_, PyAny>, path: &str) -> PyResult<DataType> {
+    to_dtype(tp, path, &mut vec![])
+}
+
+/// Convert the annotated fields of a `TypedDict`, dataclass or `NamedTuple` to [`Field`]s, in
+/// definition order.
+pub(crate) fn py_type_to_fields(cls: &Bound<'_, PyAny>, path: &str) -> PyResult<Vec<Field>> {
+    if !is_record_type(cls)? {
+        return Err(unsupported(
+            cls,
+            path,
+            "expected a TypedDict, dataclass or NamedTuple",
+        ));
+    }
+    to_fields(cls, path, &mut vec![])
+}
+
+/// `visiting` holds the record types that are being converted, so that a type which
+/// (indirectly) contains itself raises instead of recursing forever.
+fn to_dtype(
+    tp: &Bound<'_, PyAny>,
+    path: &str,
+    visiting: &mut Vec<Py<PyAny>>,
+) -> PyResult<DataType> {
+    let py = tp.py();
+    let typing = py.import_bound(intern!(py, "typing"))?;
+    let builtins = py.import_bound(intern!(py, "builtins"))?;
+
+    let origin = typing.call_method1(intern!(py, "get_origin"), (tp,))?;
+    if !origin.is_none() {
+        let args = typing.call_method1(intern!(py, "get_args"), (tp,))?;
+        let args = args.downcast::<PyTuple>()?;
+        return if is_union(&origin)? {
+            // `Optional[T]`, `Union[T, None]` and `T | None`; every dtype is nullable.
+            let mut members = args.iter().filter(|arg| !is_none_type(arg));
+            match (members.next(), members.next()) {
+                (Some(inner), None) => to_dtype(&inner, path, visiting),
+                _ => Err(unsupported(
+                    tp,
+                    path,
+                    "unions of multiple types are ambiguous",
+                )),
+            }
+        } else if origin.is(&builtins.getattr(intern!(py, "list"))?) && args.len() == 1 {
+            let inner = to_dtype(&args.get_item(0)?, &format!("{path}[]"), visiting)?;
+            Ok(DataType::List(Box::new(inner)))
+        } else {
+            Err(unsupported(tp, path, "unsupported generic type"))
+        };
+    }
+
+    if is_none_type(tp) {
+        return Ok(DataType::Null);
+    }
+
+    let datetime = py.import_bound(intern!(py, "datetime"))?;
+    let decimal = py.import_bound(intern!(py, "decimal"))?;
+    let scalars = [
+        (&builtins, "bool", DataType::Boolean),
+        (&builtins, "int", DataType::Int64),
+        (&builtins, "float", DataType::Float64),
+        (&builtins, "str", DataType::String),
+        (&builtins, "bytes", DataType::Binary),
+        // `datetime` is a subclass of `date`, but identity checks don't mix them up.
+        (
+            &datetime,
+            "datetime",
+            DataType::Datetime(TimeUnit::Microseconds, None),
+        ),
+        (&datetime, "date", DataType::Date),
+        (&datetime, "time", DataType::Time),
+        (
+            &datetime,
+            "timedelta",
+            DataType::Duration(TimeUnit::Microseconds),
+        ),
+        (&decimal, "Decimal", DataType::Decimal(None, None)),
+    ];
+    for (module, name, dtype) in scalars {
+        if tp.is(&module.getattr(name)?) {
+            return Ok(dtype);
+        }
+    }
+
+    if is_record_type(tp)? {
+        if visiting.iter().any(|cls| tp.is(cls)) {
+            return Err(unsupported(tp, path, "recursive types are not supported"));
+        }
+        visiting.push(tp.clone().unbind());
+        let fields = to_fields(tp, path, visiting);
+        visiting.pop();
+        return Ok(DataType::Struct(fields?));
+    }
+
+    if tp.is_instance_of::<PyType>() && tp.hasattr(intern!(py, "__annotations__"))? {
+        return Err(unsupported(
+            tp,
+            path,
+            "only TypedDicts, dataclasses and NamedTuples are converted to a struct",
+        ));
+    }
+    Err(unsupported(tp, path, "unknown type"))
+}
+
+fn to_fields(
+    cls: &Bound<'_, PyAny>,
+    path: &str,
+    visiting: &mut Vec<Py<PyAny>>,
+) -> PyResult<Vec<Field>> {
+    let py = cls.py();
+    let typing = py.import_bound(intern!(py, "typing"))?;
+    let hints = typing.call_method1(intern!(py, "get_type_hints"), (cls,))?;
+    let hints = hints.downcast::<PyDict>()?;
+
+    hints
+        .iter()
+        .map(|(name, tp)| {
+            let name = name.extract::<String>()?;
+            let field_path = if path.is_empty() {
+                name.clone()
+            } else {
+                format!("{path}.{name}")
+            };
+            let dtype = to_dtype(&tp, &field_path, visiting)?;
+            Ok(Field::new(&name, dtype))
+        })
+        .collect()
+}
+
+/// Whether `tp` is a class whose annotations describe its fields: a `TypedDict`, a
+/// dataclass or a `NamedTuple`.
+fn is_record_type(tp: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = tp.py();
+    let Ok(cls) = tp.downcast::<PyType>() else {
+        return Ok(false);
+    };
+    let dataclasses = py.import_bound(intern!(py, "dataclasses"))?;
+    if dataclasses
+        .call_method1(intern!(py, "is_dataclass"), (cls,))?
+        .is_truthy()?
+    {
+        return Ok(true);
+    }
+    // `TypedDict` classes are plain `dict` subclasses at runtime, marked by `__total__`.
+    let is_typed_dict = cls.is_subclass_of::<PyDict>()? && cls.hasattr(intern!(py, "__total__"))?;
+    let is_named_tuple = cls.is_subclass_of::<PyTuple>()? && cls.hasattr(intern!(py, "_fields"))?;
+    Ok(is_typed_dict || is_named_tuple)
+}
+
+fn is_none_type(tp: &Bound<'_, PyAny>) -> bool {
+    let py = tp.py();
+    tp.is_none() || tp.is(&py.None().into_bound(py).get_type())
+}
+
+fn is_union(origin: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = origin.py();
+    let typing = py.import_bound(intern!(py, "typing"))?;
+    if origin.is(&typing.getattr(intern!(py, "Union"))?) {
+        return Ok(true);
+    }
+    // `X | Y` syntax, Python 3.10+.
+    let types = py.import_bound(intern!(py, "types"))?;
+    match types.getattr(intern!(py, "UnionType")) {
+        Ok(union_type) => Ok(origin.is(&union_type)),
+        Err(_) => Ok(false),
+    }
+}
+
+fn unsupported(tp: &Bound<'_, PyAny>, path: &str, reason: &str) -> PyErr {
+    let repr = tp
+        .repr()
+        .map(|r| r.to_string())
+        .unwrap_or_else(|_| "<unknown>".to_string());
+    if path.is_empty() {
+        PyTypeError::new_err(format!(
+            "cannot convert annotation {repr} to a Polars data type: {reason}"
+        ))
+    } else {
+        PyTypeError::new_err(format!(
+            "cannot convert annotation {repr} at '{path}' to a Polars data type: {reason}"
+        ))
+    }
+}
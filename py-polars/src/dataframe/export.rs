@@ -40,21 +40,32 @@ impl PyDataFrame {
     pub fn row_tuples(&self) -> PyObject {
         Python::with_gil(|py| {
             let df = &self.df;
+            // Extract the values column by column, so that every column is downcast once.
+            let mut columns = df
+                .get_columns()
+                .iter()
+                .map(|s| match s.dtype() {
+                    DataType::Null | DataType::Object(_, _) => None,
+                    _ => Some(s.extract_any_values(0..df.height()).into_iter()),
+                })
+                .collect::<Vec<_>>();
             PyList::new_bound(
                 py,
                 (0..df.height()).map(|idx| {
                     PyTuple::new_bound(
                         py,
-                        self.df.get_columns().iter().map(|s| match s.dtype() {
-                            DataType::Null => py.None(),
-                            DataType::Object(_, _) => {
-                                let obj: Option<&ObjectValue> =
-                                    s.get_object(idx).map(|any| any.into());
-                                obj.to_object(py)
-                            },
-                            // SAFETY: we are in bounds.
-                            _ => unsafe { Wrap(s.get_unchecked(idx)).into_py(py) },
-                        }),
+                        df.get_columns()
+                            .iter()
+                            .zip(columns.iter_mut())
+                            .map(|(s, values)| match s.dtype() {
+                                DataType::Null => py.None(),
+                                DataType::Object(_, _) => {
+                                    let obj: Option<&ObjectValue> =
+                                        s.get_object(idx).map(|any| any.into());
+                                    obj.to_object(py)
+                                },
+                                _ => Wrap(values.as_mut().unwrap().next().unwrap()).into_py(py),
+                            }),
                     )
                 }),
             )
@@ -395,7 +395,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, bloom_filter_columns, bloom_filter_fpp))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -405,8 +405,12 @@ impl PyDataFrame {
         statistics: bool,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        bloom_filter_columns: Option<Vec<String>>,
+        bloom_filter_fpp: f64,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let bloom_filter =
+            bloom_filter_columns.map(|columns| BloomFilterOptions::new(columns, bloom_filter_fpp));
 
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let f = std::fs::File::create(&*s)?;
@@ -416,6 +420,7 @@ impl PyDataFrame {
                     .with_statistics(statistics)
                     .with_row_group_size(row_group_size)
                     .with_data_page_size(data_page_size)
+                    .with_bloom_filter(bloom_filter)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
             })?;
@@ -426,6 +431,7 @@ impl PyDataFrame {
                 .with_statistics(statistics)
                 .with_row_group_size(row_group_size)
                 .with_data_page_size(data_page_size)
+                .with_bloom_filter(bloom_filter)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
         }
@@ -98,7 +98,7 @@ impl PyExpr {
         self.inner
             .clone()
             .dt()
-            .combine(time.inner, time_unit.0)
+            .combine(time.inner, time_unit.0, None)
             .into()
     }
     fn dt_millennium(&self) -> Self {
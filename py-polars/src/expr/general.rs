@@ -178,7 +178,7 @@ impl PyExpr {
             .into()
     }
 
-    #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
+    #[pyo3(signature = (breaks, labels, left_closed, include_breaks, index_only))]
     #[cfg(feature = "cutqcut")]
     fn cut(
         &self,
@@ -186,13 +186,14 @@ impl PyExpr {
         labels: Option<Vec<String>>,
         left_closed: bool,
         include_breaks: bool,
+        index_only: bool,
     ) -> Self {
         self.inner
             .clone()
-            .cut(breaks, labels, left_closed, include_breaks)
+            .cut(breaks, labels, left_closed, include_breaks, index_only)
             .into()
     }
-    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks, index_only))]
     #[cfg(feature = "cutqcut")]
     fn qcut(
         &self,
@@ -201,13 +202,21 @@ impl PyExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        index_only: bool,
     ) -> Self {
         self.inner
             .clone()
-            .qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
+            .qcut(
+                probs,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                index_only,
+            )
             .into()
     }
-    #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks, index_only))]
     #[cfg(feature = "cutqcut")]
     fn qcut_uniform(
         &self,
@@ -216,6 +225,7 @@ impl PyExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        index_only: bool,
     ) -> Self {
         self.inner
             .clone()
@@ -225,6 +235,7 @@ impl PyExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                index_only,
             )
             .into()
     }
@@ -28,6 +28,20 @@ impl PyExpr {
             .collect()
     }
 
+    fn meta_free_columns(&self) -> Vec<String> {
+        self.inner
+            .clone()
+            .meta()
+            .free_columns()
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    fn meta_estimated_cost(&self) -> u64 {
+        self.inner.clone().meta().estimated_cost()
+    }
+
     fn meta_output_name(&self) -> PyResult<String> {
         let name = self
             .inner
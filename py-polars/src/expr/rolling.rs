@@ -10,7 +10,7 @@ use crate::{PyExpr, PySeries};
 
 #[pymethods]
 impl PyExpr {
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed, warn_if_unsorted))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed, normalize_weights, warn_if_unsorted, precise))]
     fn rolling_sum(
         &self,
         window_size: &str,
@@ -19,16 +19,20 @@ impl PyExpr {
         center: bool,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
+        normalize_weights: bool,
         warn_if_unsorted: bool,
+        precise: bool,
     ) -> Self {
         let options = RollingOptions {
             window_size: Duration::parse(window_size),
             weights,
+            normalize_weights,
             min_periods,
             center,
             by,
             closed_window: closed.map(|c| c.0),
             warn_if_unsorted,
+            precise,
             ..Default::default()
         };
         self.inner.clone().rolling_sum(options).into()
@@ -55,6 +55,28 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (dtype, formats, strict, cache, ambiguous))]
+    fn str_strptime_formats(
+        &self,
+        dtype: Wrap<DataType>,
+        formats: Vec<String>,
+        strict: bool,
+        cache: bool,
+        ambiguous: Self,
+    ) -> Self {
+        let options = StrptimeOptions {
+            format: None,
+            strict,
+            exact: true,
+            cache,
+        };
+        self.inner
+            .clone()
+            .str()
+            .strptime_formats(dtype.0, formats, options, ambiguous.inner)
+            .into()
+    }
+
     #[pyo3(signature = (format, strict, cache))]
     fn str_to_time(&self, format: Option<String>, strict: bool, cache: bool) -> Self {
         let options = StrptimeOptions {
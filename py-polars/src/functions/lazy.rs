@@ -23,6 +23,7 @@ pub fn rolling_corr(
     window_size: IdxSize,
     min_periods: IdxSize,
     ddof: u8,
+    spearman: bool,
 ) -> PyExpr {
     dsl::rolling_corr(
         x.inner,
@@ -31,6 +32,7 @@ pub fn rolling_corr(
             min_periods,
             window_size,
             ddof,
+            spearman,
         },
     )
     .into()
@@ -51,6 +53,7 @@ pub fn rolling_cov(
             min_periods,
             window_size,
             ddof,
+            spearman: false,
         },
     )
     .into()
@@ -179,9 +182,9 @@ pub fn concat_lf(
 }
 
 #[pyfunction]
-pub fn concat_list(s: Vec<PyExpr>) -> PyResult<PyExpr> {
+pub fn concat_list(s: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExpr> {
     let s = s.into_iter().map(|e| e.inner).collect::<Vec<_>>();
-    let expr = dsl::concat_list(s).map_err(PyPolarsErr::from)?;
+    let expr = dsl::concat_to_list(s, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(expr.into())
 }
 
@@ -364,6 +367,11 @@ pub fn duration(
     dsl::duration(args).into()
 }
 
+#[pyfunction]
+pub fn element_index() -> PyExpr {
+    dsl::element_index().into()
+}
+
 #[pyfunction]
 pub fn first() -> PyExpr {
     dsl::first().into()
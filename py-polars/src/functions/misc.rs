@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use polars_plan::prelude::*;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
+use crate::conversion::python_type::{py_type_to_dtype, py_type_to_fields};
 use crate::conversion::Wrap;
 use crate::expr::ToExprs;
 use crate::prelude::DataType;
@@ -14,6 +16,21 @@ pub fn dtype_str_repr(dtype: Wrap<DataType>) -> PyResult<String> {
     Ok(dtype.to_string())
 }
 
+#[pyfunction]
+pub fn dtype_from_python_type(py: Python, tp: &Bound<PyAny>) -> PyResult<PyObject> {
+    let dtype = py_type_to_dtype(tp, "")?;
+    Ok(Wrap(dtype).to_object(py))
+}
+
+#[pyfunction]
+pub fn schema_from_python_type(py: Python, cls: &Bound<PyAny>) -> PyResult<PyObject> {
+    let schema = PyDict::new_bound(py);
+    for field in py_type_to_fields(cls, "")? {
+        schema.set_item(field.name().as_str(), Wrap(field.data_type().clone()))?;
+    }
+    Ok(schema.into())
+}
+
 #[cfg(feature = "ffi_plugin")]
 #[pyfunction]
 pub fn register_plugin_function(
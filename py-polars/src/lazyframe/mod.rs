@@ -245,7 +245,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (path, paths, n_rows, cache, parallel, rechunk, row_index,
-        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries)
+        low_memory, cloud_options, use_statistics, hive_partitioning, hive_schema, retries,
+        credential_provider, on_error, uuid_as_string)
     )]
     fn new_from_parquet(
         path: Option<PathBuf>,
@@ -261,6 +262,9 @@ impl PyLazyFrame {
         hive_partitioning: bool,
         hive_schema: Option<Wrap<Schema>>,
         retries: usize,
+        credential_provider: Option<PyObject>,
+        on_error: Wrap<ScanErrorPolicy>,
+        uuid_as_string: bool,
     ) -> PyResult<Self> {
         let parallel = parallel.0;
         let hive_schema = hive_schema.map(|s| Arc::new(s.0));
@@ -286,6 +290,13 @@ impl PyLazyFrame {
                         options
                     });
         }
+        if let Some(func) = credential_provider {
+            cloud_options = Some(
+                cloud_options
+                    .unwrap_or_default()
+                    .with_credential_provider(parse_credential_provider(func)),
+            );
+        }
         let row_index = row_index.map(|(name, offset)| RowIndex { name, offset });
         let hive_options = HiveOptions {
             enabled: hive_partitioning,
@@ -301,7 +312,10 @@ impl PyLazyFrame {
             low_memory,
             cloud_options,
             use_statistics,
+            uuid_as_string,
             hive_options,
+            on_error: on_error.0,
+            per_file_limit: None,
         };
 
         let lf = if path.is_some() {
@@ -315,7 +329,9 @@ impl PyLazyFrame {
 
     #[cfg(feature = "ipc")]
     #[staticmethod]
-    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, cloud_options, retries))]
+    #[pyo3(signature = (path, paths, n_rows, cache, rechunk, row_index, memory_map, cloud_options, retries,
+        credential_provider)
+    )]
     fn new_from_ipc(
         path: Option<PathBuf>,
         paths: Vec<PathBuf>,
@@ -326,6 +342,7 @@ impl PyLazyFrame {
         memory_map: bool,
         cloud_options: Option<Vec<(String, String)>>,
         retries: usize,
+        credential_provider: Option<PyObject>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex { name, offset });
 
@@ -352,6 +369,13 @@ impl PyLazyFrame {
                             options
                         });
             }
+            if let Some(func) = credential_provider {
+                cloud_options = Some(
+                    cloud_options
+                        .unwrap_or_default()
+                        .with_credential_provider(parse_credential_provider(func)),
+                );
+            }
             cloud_options
         };
 
@@ -570,6 +594,21 @@ impl PyLazyFrame {
         Ok(df.into())
     }
 
+    fn collect_with_scan_errors(
+        &self,
+        py: Python,
+    ) -> PyResult<(PyDataFrame, Vec<(String, String)>)> {
+        let (df, errors) = py.allow_threads(|| {
+            let ldf = self.ldf.clone();
+            ldf.collect_with_scan_errors().map_err(PyPolarsErr::from)
+        })?;
+        let errors = errors
+            .into_iter()
+            .map(|(path, err)| (path.to_string_lossy().into_owned(), err.to_string()))
+            .collect();
+        Ok((df.into(), errors))
+    }
+
     #[pyo3(signature = (lambda,))]
     fn collect_with_callback(&self, lambda: PyObject) {
         let ldf = self.ldf.clone();
@@ -595,7 +634,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order, bloom_filter_columns, bloom_filter_fpp))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -606,8 +645,12 @@ impl PyLazyFrame {
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
         maintain_order: bool,
+        bloom_filter_columns: Option<Vec<String>>,
+        bloom_filter_fpp: f64,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let bloom_filter =
+            bloom_filter_columns.map(|columns| BloomFilterOptions::new(columns, bloom_filter_fpp));
 
         let options = ParquetWriteOptions {
             compression,
@@ -615,6 +658,7 @@ impl PyLazyFrame {
             row_group_size,
             data_pagesize_limit,
             maintain_order,
+            bloom_filter,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -193,6 +193,8 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::duration))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::element_index))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::first)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::fold)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::last)).unwrap();
@@ -265,6 +267,10 @@ fn polars(py: Python, m: &PyModule) -> PyResult<()> {
     // Functions - misc
     m.add_wrapped(wrap_pyfunction!(functions::dtype_str_repr))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::dtype_from_python_type))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::schema_from_python_type))
+        .unwrap();
     #[cfg(feature = "object")]
     m.add_wrapped(wrap_pyfunction!(on_startup::__register_startup_deps))
         .unwrap();
@@ -127,3 +127,26 @@ def test_groupby_h2oai_q10(groupby_data: pl.DataFrame) -> None:
         )
         .collect()
     )
+
+
+def test_groupby_null_count(groupby_data: pl.DataFrame) -> None:
+    (
+        groupby_data.lazy()
+        .group_by("id3")
+        .agg(
+            pl.all().exclude("id3").null_count().name.suffix("_null_count"),
+        )
+        .collect()
+    )
+
+
+def test_groupby_null_count_sorted(groupby_data: pl.DataFrame) -> None:
+    (
+        groupby_data.lazy()
+        .sort("id3")
+        .group_by("id3")
+        .agg(
+            pl.all().exclude("id3").is_null().sum().name.suffix("_null_count"),
+        )
+        .collect()
+    )
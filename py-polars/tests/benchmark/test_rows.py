@@ -0,0 +1,35 @@
+"""Benchmark tests for extracting the values of a DataFrame row by row."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+
+@pytest.fixture(scope="module")
+def chunked_data(groupby_data: pl.DataFrame) -> pl.DataFrame:
+    # Many chunks, so the values are extracted across chunk boundaries.
+    return pl.concat(
+        [groupby_data.slice(i, 1_000) for i in range(0, 10_000, 1_000)], rechunk=False
+    )
+
+
+def test_rows(groupby_data: pl.DataFrame) -> None:
+    groupby_data.rows()
+
+
+def test_rows_chunked(chunked_data: pl.DataFrame) -> None:
+    assert chunked_data.n_chunks() == 10
+    chunked_data.rows()
+
+
+def test_rows_categorical(groupby_data: pl.DataFrame) -> None:
+    groupby_data.select(pl.col("id1", "id2", "id3").cast(pl.Categorical)).rows()
+
+
+def test_format(groupby_data: pl.DataFrame) -> None:
+    with pl.Config(tbl_rows=1_000):
+        str(groupby_data)
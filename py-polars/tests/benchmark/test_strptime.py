@@ -0,0 +1,38 @@
+"""Benchmark tests for parsing strings with multiple formats."""
+
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+pytestmark = pytest.mark.benchmark()
+
+FORMATS = ["%Y-%m-%d %H:%M:%S", "%m/%d/%Y %H:%M", "%s"]
+
+
+@pytest.fixture(scope="module")
+def mixed_dates() -> pl.Series:
+    n = 1_000_000
+    ts = pl.Series(range(n)) * 9_973 + 1_500_000_000
+    dt = (ts * 1000).cast(pl.Datetime("ms"))
+    return pl.select(
+        pl.when(pl.int_range(n) % 3 == 0)
+        .then(dt.dt.strftime(FORMATS[0]))
+        .when(pl.int_range(n) % 3 == 1)
+        .then(dt.dt.strftime(FORMATS[1]))
+        .otherwise(ts.cast(pl.String))
+    ).to_series()
+
+
+def test_strptime_formats_single_pass(mixed_dates: pl.Series) -> None:
+    mixed_dates.str.strptime(pl.Datetime, FORMATS)
+
+
+def test_strptime_formats_chained(mixed_dates: pl.Series) -> None:
+    pl.select(
+        pl.coalesce(
+            pl.lit(mixed_dates).str.strptime(pl.Datetime, fmt, strict=False)
+            for fmt in FORMATS
+        )
+    )
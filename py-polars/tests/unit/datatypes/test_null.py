@@ -1,12 +1,15 @@
 from __future__ import annotations
 
-from typing import Any
+from typing import TYPE_CHECKING, Any
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars.type_aliases import JoinStrategy
+
 
 def test_null_index() -> None:
     df = pl.DataFrame({"a": [[1, 2], [3, 4], [5, 6]], "b": [[1, 2], [1, 2], [4, 5]]})
@@ -77,3 +80,106 @@ def test_null_hash_rows_14100() -> None:
     assert df.hash_rows().dtype == pl.UInt64
     assert df["b"].hash().dtype == pl.UInt64
     assert df.select([pl.col("b").hash().alias("foo")])["foo"].dtype == pl.UInt64
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+@pytest.mark.parametrize("join_nulls", [False, True])
+@pytest.mark.parametrize("how", ["inner", "left"])
+def test_null_dtype_join_keys(
+    how: JoinStrategy, join_nulls: bool, streaming: bool
+) -> None:
+    left = pl.DataFrame(
+        {"a": [1, 2, 3], "n": [None, None, None]},
+        schema={"a": pl.Int64, "n": pl.Null},
+    )
+    right = pl.DataFrame(
+        {"a": [1, 2, 4], "n": [None, None, None], "b": ["x", "y", "z"]},
+        schema={"a": pl.Int64, "n": pl.Null, "b": pl.String},
+    )
+
+    # A Null column alone: either every pair of rows matches or none does.
+    out = (
+        left.lazy()
+        .join(right.lazy(), on="n", how=how, join_nulls=join_nulls)
+        .collect(streaming=streaming)
+    )
+    assert out.schema["n"] == pl.Null
+    if join_nulls:
+        assert out.height == 9
+        assert out["b"].null_count() == 0
+    elif how == "inner":
+        assert out.height == 0
+    else:
+        assert out.height == 3
+        assert out["b"].null_count() == 3
+
+    # Combined with a normal key the Null column is a degenerate extra key.
+    out = (
+        left.lazy()
+        .join(right.lazy(), on=["a", "n"], how=how, join_nulls=join_nulls)
+        .collect(streaming=streaming)
+        .sort("a")
+    )
+    if join_nulls:
+        expected_b = ["x", "y"] if how == "inner" else ["x", "y", None]
+    else:
+        expected_b = [] if how == "inner" else [None, None, None]
+    assert out["b"].to_list() == expected_b
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_null_dtype_sort(streaming: bool) -> None:
+    df = pl.DataFrame(
+        {"n": [None, None, None], "a": [3, 1, 2]},
+        schema={"n": pl.Null, "a": pl.Int64},
+    )
+
+    def sort(*args: Any, **kwargs: Any) -> pl.DataFrame:
+        return df.lazy().sort(*args, **kwargs).collect(streaming=streaming)
+
+    # Sorting by a Null column alone keeps the input order.
+    assert sort("n")["a"].to_list() == [3, 1, 2]
+    assert sort("n", descending=True)["a"].to_list() == [3, 1, 2]
+    assert sort("n", maintain_order=True)["a"].to_list() == [3, 1, 2]
+
+    # Combined with other keys the Null column never breaks ties.
+    assert sort("n", "a")["a"].to_list() == [1, 2, 3]
+    assert sort("a", "n")["a"].to_list() == [1, 2, 3]
+    assert sort("n", "a", descending=[True, False])["a"].to_list() == [1, 2, 3]
+    assert sort("n", "a", nulls_last=True)["a"].to_list() == [1, 2, 3]
+    assert df.select(pl.col("n").sort()).schema["n"] == pl.Null
+    assert df.select(pl.col("a").sort_by("n"))["a"].to_list() == [3, 1, 2]
+
+
+@pytest.mark.parametrize("streaming", [False, True])
+def test_null_dtype_group_by_keys(streaming: bool) -> None:
+    df = pl.DataFrame(
+        {"n": [None, None, None, None], "a": [1, 1, 2, 2], "v": [1, 2, 3, 4]},
+        schema={"n": pl.Null, "a": pl.Int64, "v": pl.Int64},
+    )
+
+    # A Null key alone forms a single group.
+    out = df.lazy().group_by("n").agg(pl.sum("v")).collect(streaming=streaming)
+    expected = pl.DataFrame(
+        {"n": [None], "v": [10]}, schema={"n": pl.Null, "v": pl.Int64}
+    )
+    assert_frame_equal(out, expected)
+
+    # Combined with a normal key it doesn't split any groups.
+    out = (
+        df.lazy()
+        .group_by("n", "a")
+        .agg(pl.sum("v"))
+        .collect(streaming=streaming)
+        .sort("a")
+    )
+    expected = pl.DataFrame(
+        {"n": [None, None], "a": [1, 2], "v": [3, 7]},
+        schema={"n": pl.Null, "a": pl.Int64, "v": pl.Int64},
+    )
+    assert_frame_equal(out, expected)
+
+    # No rows, no groups.
+    out = df.clear().lazy().group_by("n").agg(pl.sum("v")).collect(streaming=streaming)
+    assert out.height == 0
+    assert out.schema["n"] == pl.Null
@@ -127,6 +127,16 @@ def test_concat_list_with_lit() -> None:
     assert result.to_dict(as_series=False) == expected
 
 
+def test_concat_list_ignore_nulls() -> None:
+    df = pl.DataFrame({"a": [1, None, 3], "b": [4, 5, None], "c": [7, None, 9]})
+
+    result = df.select(pl.concat_list("a", "b", "c"))
+    assert result.to_series().to_list() == [[1, 4, 7], [None, 5, None], [3, None, 9]]
+
+    result = df.select(pl.concat_list("a", "b", "c", ignore_nulls=True))
+    assert result.to_series().to_list() == [[1, 4, 7], [5], [3, 9]]
+
+
 def test_concat_list_empty_raises() -> None:
     with pytest.raises(pl.ComputeError):
         pl.DataFrame({"a": [1, 2, 3]}).with_columns(pl.concat_list([]))
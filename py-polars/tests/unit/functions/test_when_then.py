@@ -596,3 +596,52 @@ def test_when_then_parametric(
             assert_frame_equal(ref, ans)
         else:
             assert ref["if_true"].to_list() == ans["if_true"].to_list()
+
+
+@pytest.mark.parametrize(
+    ("dtype", "vals"),
+    [
+        pytest.param(pl.List(pl.Int64), [[0], [1, 2], []], id="List"),
+        pytest.param(
+            pl.Struct({"foo": pl.Int32, "bar": pl.List(pl.String)}),
+            [{"foo": 0, "bar": ["1"]}, {"foo": None, "bar": None}, {"foo": 1}],
+            id="Struct",
+        ),
+    ],
+)
+@pytest.mark.parametrize(
+    "mask",
+    [
+        [True, True, False, None, True, True],
+        [None, None, True, True, False, False],
+        [True, False, None, True, False, True],
+    ],
+)
+def test_when_then_nested_chunked(
+    dtype: pl.DataType, vals: list[Any], mask: list[bool | None]
+) -> None:
+    if_true = [*vals, None, *reversed(vals[1:])]
+    if_false = [None, *vals[::-1], *vals[:2]]
+    ref = pl.DataFrame(
+        {"if_true": [t if m else f for m, t, f in zip(mask, if_true, if_false)]},
+        schema={"if_true": dtype},
+    )
+
+    df = pl.concat(
+        [
+            pl.DataFrame(
+                {
+                    "mask": mask[i : i + 2],
+                    "if_true": if_true[i : i + 2],
+                    "if_false": if_false[i : i + 2],
+                },
+                schema={"mask": pl.Boolean, "if_true": dtype, "if_false": dtype},
+            )
+            for i in range(0, 6, 2)
+        ],
+        rechunk=False,
+    )
+    assert df.n_chunks() == 3
+
+    ans = df.select(pl.when("mask").then("if_true").otherwise("if_false"))
+    assert_frame_equal(ref, ans)
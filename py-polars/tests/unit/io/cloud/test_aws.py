@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import multiprocessing
+import time
 from typing import TYPE_CHECKING, Any, Callable, Iterator
 
 import boto3
@@ -95,3 +96,34 @@ def test_lazy_count_s3(s3: str) -> None:
     assert "FAST COUNT(*)" in lf.explain()
     expected = pl.DataFrame({"len": [54]}, schema={"len": pl.UInt32})
     assert_frame_equal(lf.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("function", "source", "height"),
+    [
+        (pl.scan_ipc, "s3://bucket/foods1.ipc", 27),
+        (pl.scan_parquet, "s3://bucket/foods*.parquet", 54),
+    ],
+)
+def test_scan_s3_credential_provider(
+    s3: str, function: Callable[..., pl.LazyFrame], source: str, height: int
+) -> None:
+    calls = 0
+
+    def credential_provider() -> dict[str, Any]:
+        nonlocal calls
+        calls += 1
+        # Expire immediately, so that every request refreshes the credentials.
+        return {
+            "aws_access_key_id": "accesskey",
+            "aws_secret_access_key": "secretkey",
+            "expiry": time.time(),
+        }
+
+    lf = function(
+        source,
+        storage_options={"endpoint_url": s3},
+        credential_provider=credential_provider,
+    )
+    assert lf.collect().shape == (height, 4)
+    assert calls > 1
@@ -432,3 +432,25 @@ def test_scan_deadlock_rayon_spawn_from_async_15172(
     t.join(5)
 
     assert results[0].equals(df)
+
+
+@pytest.mark.write_disk()
+@pytest.mark.parametrize("force_async", [False, True])
+def test_scan_parquet_skip_file(
+    force_async: bool, monkeypatch: Any, tmp_path: Path
+) -> None:
+    if force_async:
+        monkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+    pl.DataFrame({"a": [1, 2]}).write_parquet(tmp_path / "a.parquet")
+    (tmp_path / "b.parquet").write_bytes(b"PAR1 this is not a parquet file")
+    pl.DataFrame({"a": [3]}).write_parquet(tmp_path / "c.parquet")
+
+    source = tmp_path / "*.parquet"
+    with pytest.raises(pl.PolarsError):
+        pl.scan_parquet(source).collect()
+
+    out = pl.scan_parquet(source, on_error="skip_file").collect()
+    assert out["a"].to_list() == [1, 2, 3]
+
+    with pytest.raises(ValueError, match="on_error"):
+        pl.scan_parquet(source, on_error="ignore").collect()  # type: ignore[arg-type]
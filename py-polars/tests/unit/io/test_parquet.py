@@ -855,3 +855,23 @@ def test_max_statistic_parquet_writer(tmp_path: Path) -> None:
     result = pl.scan_parquet(f).filter(pl.col("int") > n - 3).collect()
     expected = pl.DataFrame({"int": [149998, 149999]})
     assert_frame_equal(result, expected)
+
+
+def test_write_parquet_bloom_filter() -> None:
+    df = pl.DataFrame({"int": [1, 2, 3], "str": ["a", "b", None], "bool": [True] * 3})
+    f = io.BytesIO()
+    df.write_parquet(f, bloom_filter_columns=["int", "str"], bloom_filter_fpp=0.05)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    with pytest.raises(pl.InvalidOperationError, match="bloom filters"):
+        df.write_parquet(io.BytesIO(), bloom_filter_columns=["bool"])
+
+
+@pytest.mark.write_disk()
+def test_sink_parquet_bloom_filter(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df = pl.DataFrame({"int": [1, 2, 3]})
+    f = tmp_path / "bloom_filter.parquet"
+    df.lazy().sink_parquet(f, bloom_filter_columns=["int"])
+    assert_frame_equal(pl.read_parquet(f), df)
@@ -910,3 +910,46 @@ def test_list_eval_err_raise_15653() -> None:
     df = pl.DataFrame({"foo": [[]]})
     with pytest.raises(pl.StructFieldNotFoundError):
         df.with_columns(bar=pl.col("foo").list.eval(pl.element().struct.field("baz")))
+
+
+@pytest.mark.parametrize("parallel", [False, True])
+def test_list_eval_element_index(parallel: bool) -> None:
+    idx_type = pl.get_index_type()
+    s = pl.Series("a", [[1, 2, 3], [], [4, 5]])
+
+    out = s.list.eval(pl.element_index(), parallel=parallel)
+    expected = pl.Series("a", [[0, 1, 2], [], [0, 1]], dtype=pl.List(idx_type))
+    assert_series_equal(out, expected)
+
+    out = s.list.eval(pl.element() * pl.element_index(), parallel=parallel)
+    assert out.to_list() == [[0, 2, 6], [], [0, 5]]
+
+    # Null lists take the per-sublist code path.
+    s = pl.Series("a", [[1, 2], None, [], [3]])
+    out = s.list.eval(pl.element_index(), parallel=parallel)
+    expected = pl.Series("a", [[0, 1], None, [], [0]], dtype=pl.List(idx_type))
+    assert_series_equal(out, expected)
+
+    # Sliced lists don't start at the beginning of their values.
+    s = pl.Series("a", [[1, 2], [3, 4, 5], [6]]).slice(1)
+    out = s.list.eval(pl.element_index(), parallel=parallel)
+    expected = pl.Series("a", [[0, 1, 2], [0]], dtype=pl.List(idx_type))
+    assert_series_equal(out, expected)
+
+
+def test_list_eval_element_index_nested() -> None:
+    s = pl.Series("a", [[[1, 2], [3]], [[4, 5, 6]], []])
+
+    out = s.list.eval(pl.element().list.eval(pl.element_index()))
+    assert out.to_list() == [[[0, 1], [0]], [[0, 1, 2]], []]
+
+    out = s.list.eval(pl.element_index())
+    assert out.to_list() == [[0, 1], [0], []]
+
+
+def test_list_eval_element_index_outside_eval() -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(pl.InvalidOperationError, match="element_index"):
+        df.select(pl.element_index())
+    with pytest.raises(pl.InvalidOperationError, match="element_index"):
+        df.select(pl.col("a") + pl.element_index())
@@ -52,6 +52,26 @@ def test_root_and_output_names() -> None:
     )
 
 
+def test_free_columns() -> None:
+    e = pl.col("a") + pl.col("a") * pl.col("b")
+    assert e.meta.root_names() == ["a", "a", "b"]
+    assert e.meta.free_columns() == ["a", "b"]
+
+    e = pl.col("b").filter(pl.col("a") > pl.col("b")).over("a")
+    assert e.meta.free_columns() == ["b", "a"]
+
+    assert pl.lit(1).meta.free_columns() == []
+
+
+def test_estimated_cost() -> None:
+    arithmetic = pl.col("a") * 2 + pl.col("b")
+    sorts = pl.col("a").sort_by("b").sort().head(3).sum().over("c")
+    assert arithmetic.meta.estimated_cost() < sorts.meta.estimated_cost()
+
+    assert pl.col("a").meta.estimated_cost() < pl.col("^a.*$").meta.estimated_cost()
+    assert pl.col("a").meta.estimated_cost() < (pl.col("a") + 1).meta.estimated_cost()
+
+
 def test_undo_aliases() -> None:
     e = pl.col("foo").alias("bar")
     assert e.meta.undo_aliases().meta == pl.col("foo")
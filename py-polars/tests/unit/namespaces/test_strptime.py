@@ -719,3 +719,38 @@ def test_to_datetime_out_of_range_13401(time_unit: TimeUnit) -> None:
         s.str.to_datetime("%Y-%B-%d %H:%M:%S", strict=False, time_unit=time_unit).item()
         is None
     )
+
+
+def test_strptime_multiple_formats() -> None:
+    s = pl.Series("date", ["2021-03-15", "03/16/2021", "17 Mar 2021", None, "foo"])
+    formats = ["%Y-%m-%d", "%m/%d/%Y", "%d %b %Y"]
+
+    result = s.str.strptime(pl.Date, formats, strict=False)
+    expected = pl.Series(
+        "date", [date(2021, 3, 15), date(2021, 3, 16), date(2021, 3, 17), None, None]
+    )
+    assert_series_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="1 out of 5 values .* none of the formats"):
+        s.str.strptime(pl.Date, formats)
+
+
+def test_strptime_multiple_formats_datetime() -> None:
+    s = pl.Series(["2021-03-15 10:00", "15/03/2021 11:30:15", "1615804200"])
+    formats = ["%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M:%S", "%s"]
+    result = s.str.strptime(pl.Datetime, formats)
+    expected = pl.Series(
+        [
+            datetime(2021, 3, 15, 10),
+            datetime(2021, 3, 15, 11, 30, 15),
+            datetime(2021, 3, 15, 10, 30),
+        ],
+        dtype=pl.Datetime("us"),
+    )
+    assert_series_equal(result, expected)
+
+
+def test_strptime_multiple_formats_mixed_tz_aware() -> None:
+    s = pl.Series(["2021-03-15 10:00+01:00", "2021-03-15 10:00"])
+    with pytest.raises(ComputeError, match="cannot mix time-zone-aware and naive"):
+        s.str.strptime(pl.Datetime, ["%Y-%m-%d %H:%M%#z", "%Y-%m-%d %H:%M"])
@@ -41,20 +41,63 @@ def test_neg_duration() -> None:
     assert_frame_equal(result, expected)
 
 
-def test_neg_overflow_wrapping() -> None:
-    df = pl.DataFrame({"a": [-128]}, schema={"a": pl.Int8})
+@pytest.mark.parametrize(
+    ("dtype", "min_value", "max_value"),
+    [
+        (pl.Int8, -(2**7), 2**7 - 1),
+        (pl.Int16, -(2**15), 2**15 - 1),
+        (pl.Int32, -(2**31), 2**31 - 1),
+        (pl.Int64, -(2**63), 2**63 - 1),
+    ],
+)
+def test_neg_signed_extremes(
+    dtype: pl.PolarsDataType, min_value: int, max_value: int
+) -> None:
+    df = pl.DataFrame({"a": [min_value + 1, max_value, None]}, schema={"a": dtype})
     result = df.select(-pl.col("a"))
-    assert_frame_equal(result, df)
+    expected = pl.DataFrame({"a": [max_value, -max_value, None]}, schema={"a": dtype})
+    assert_frame_equal(result, expected)
+
+    df = pl.DataFrame({"a": [0, min_value]}, schema={"a": dtype})
+    with pytest.raises(pl.ComputeError, match="`neg` overflowed"):
+        df.select(-pl.col("a"))
 
 
-def test_neg_unsigned_int() -> None:
-    df = pl.DataFrame({"a": [1, 2, 3]}, schema={"a": pl.UInt8})
-    with pytest.raises(
-        pl.InvalidOperationError, match="`neg` operation not supported for dtype `u8`"
-    ):
+@pytest.mark.parametrize(
+    ("dtype", "max_value", "expected_dtype"),
+    [
+        (pl.UInt8, 2**8 - 1, pl.Int16),
+        (pl.UInt16, 2**16 - 1, pl.Int32),
+        (pl.UInt32, 2**32 - 1, pl.Int64),
+        (pl.UInt64, 2**63, pl.Int64),
+    ],
+)
+def test_neg_unsigned_upcast(
+    dtype: pl.PolarsDataType, max_value: int, expected_dtype: pl.PolarsDataType
+) -> None:
+    lf = pl.LazyFrame({"a": [0, 1, max_value, None]}, schema={"a": dtype})
+    result = lf.select(-pl.col("a"))
+    assert result.schema == {"a": expected_dtype}
+    expected = pl.LazyFrame(
+        {"a": [0, -1, -max_value, None]}, schema={"a": expected_dtype}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_neg_unsigned_overflow() -> None:
+    df = pl.DataFrame({"a": [1, 2**63 + 1]}, schema={"a": pl.UInt64})
+    with pytest.raises(pl.ComputeError, match="`neg` overflowed"):
         df.select(-pl.col("a"))
 
 
+def test_neg_literal() -> None:
+    result = pl.select(-pl.lit(5, dtype=pl.UInt32))
+    assert_frame_equal(result, pl.DataFrame({"literal": [-5]}))
+
+    with pytest.raises(pl.ComputeError, match="`neg` overflowed"):
+        pl.select(-pl.lit(-128, dtype=pl.Int8))
+
+
 def test_neg_non_numeric() -> None:
     df = pl.DataFrame({"a": ["p", "q", "r"]})
     with pytest.raises(
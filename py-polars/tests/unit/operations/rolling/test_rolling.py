@@ -574,6 +574,26 @@ def test_rolling_cov_corr() -> None:
     assert res["corr"][:2] == [None] * 2
 
 
+def test_rolling_corr_min_periods_counts_valid_pairs() -> None:
+    df = pl.DataFrame(
+        {"x": [1, 2, None, 4, 5, 6], "y": [2, 4, 6, None, 10, 9]},
+    )
+    res = df.select(
+        pl.rolling_corr("x", "y", window_size=3, min_periods=2).alias("corr")
+    )
+    assert res["corr"].to_list() == [None, 1.0, 1.0, None, None, -1.0]
+
+
+def test_rolling_corr_spearman() -> None:
+    df = pl.DataFrame({"x": [1, 2, 3, 4, None], "y": [1, 4, 9, 100, 3]})
+    res = df.select(
+        pl.rolling_corr("x", "y", window_size=4).alias("pearson"),
+        pl.rolling_corr("x", "y", window_size=4, spearman=True).alias("spearman"),
+    )
+    assert res["pearson"][3] < 1.0
+    assert res["spearman"].to_list() == [None, None, None, 1.0, None]
+
+
 @pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
 def test_rolling_empty_window_9406(time_unit: TimeUnit) -> None:
     datecol = pl.Series(
@@ -606,6 +626,84 @@ def test_rolling_empty_window_9406(time_unit: TimeUnit) -> None:
     )
 
 
+def _weighted_references(
+    values: list[float | None], weights: list[float], min_periods: int
+) -> tuple[list[float | None], list[float | None]]:
+    # The weights line up with the start of every window, nulls drop their weight.
+    means: list[float | None] = []
+    variances: list[float | None] = []
+    for i in range(len(values)):
+        window = values[max(0, i + 1 - len(weights)) : i + 1]
+        valid = [(v, w) for v, w in zip(window, weights) if v is not None]
+        x = np.array([v for v, _ in valid])
+        w = np.array([w for _, w in valid])
+        if len(valid) < min_periods or w.sum() == 0:
+            means.append(None)
+            variances.append(None)
+            continue
+        means.append(float(np.average(x, weights=w)))
+        # The denominator of `np.cov` for reliability weights.
+        if w.sum() - (w**2).sum() / w.sum() <= 0:
+            variances.append(None)
+        else:
+            variances.append(float(np.cov(x, aweights=w)))
+    return means, variances
+
+
+@pytest.mark.parametrize(
+    "weights",
+    [
+        [1.0, 2.0, 3.0],
+        [0.0, 1.0, 1.0],
+        [0.5, 0.0, 0.5],
+        [2.0, 2.0, 2.0],
+        [0.0, 0.0, 1.0],
+    ],
+)
+@pytest.mark.parametrize(
+    "values",
+    [[1.0, 4.0, 2.0, 8.0, 5.0, 7.0, 3.0], [1.0, 4.0, None, 8.0, 5.0, None, None, 3.0]],
+)
+def test_rolling_weighted_mean_var_std(
+    values: list[float | None], weights: list[float]
+) -> None:
+    s = pl.Series("a", values, dtype=pl.Float64)
+    means, variances = _weighted_references(values, weights, min_periods=2)
+
+    assert_series_equal(
+        s.rolling_mean(3, weights, min_periods=2),
+        pl.Series("a", means, dtype=pl.Float64),
+    )
+    assert_series_equal(
+        s.rolling_var(3, weights, min_periods=2),
+        pl.Series("a", variances, dtype=pl.Float64),
+    )
+    assert_series_equal(
+        s.rolling_std(3, weights, min_periods=2),
+        pl.Series("a", variances, dtype=pl.Float64).sqrt(),
+    )
+
+
+def test_rolling_sum_normalize_weights() -> None:
+    s = pl.Series("a", [1.0, 4.0, None, 8.0, 5.0, 7.0], dtype=pl.Float64)
+    weights = [1.0, 2.0, 3.0]
+
+    expected = s.rolling_mean(3, weights, min_periods=2)
+    assert_series_equal(
+        s.rolling_sum(3, weights, min_periods=2, normalize_weights=True), expected
+    )
+    out = s.to_frame().select(
+        pl.col("a").rolling_sum(3, weights, min_periods=2, normalize_weights=True)
+    )
+    assert_series_equal(out.to_series(), expected)
+
+
+def test_rolling_weights_length() -> None:
+    lf = pl.LazyFrame({"a": [1.0, 2.0, 3.0]})
+    with pytest.raises(InvalidOperationError, match="`weights`"):
+        lf.select(pl.col("a").rolling_var(3, weights=[1.0, 2.0])).schema
+
+
 def test_rolling_weighted_quantile_10031() -> None:
     assert_series_equal(
         pl.Series([1, 2]).rolling_median(window_size=2, weights=[0, 1]),
@@ -74,10 +74,40 @@ def test_abs_duration() -> None:
     assert_frame_equal(result, expected)
 
 
-def test_abs_overflow_wrapping() -> None:
-    df = pl.DataFrame({"a": [-128]}, schema={"a": pl.Int8})
+@pytest.mark.parametrize(
+    ("dtype", "min_value", "max_value"),
+    [
+        (pl.Int8, -(2**7), 2**7 - 1),
+        (pl.Int16, -(2**15), 2**15 - 1),
+        (pl.Int32, -(2**31), 2**31 - 1),
+        (pl.Int64, -(2**63), 2**63 - 1),
+    ],
+)
+def test_abs_signed_extremes(
+    dtype: pl.PolarsDataType, min_value: int, max_value: int
+) -> None:
+    df = pl.DataFrame({"a": [min_value + 1, max_value, None]}, schema={"a": dtype})
     result = df.select(pl.col("a").abs())
-    assert_frame_equal(result, df)
+    expected = pl.DataFrame({"a": [max_value, max_value, None]}, schema={"a": dtype})
+    assert_frame_equal(result, expected)
+
+    df = pl.DataFrame({"a": [0, min_value]}, schema={"a": dtype})
+    with pytest.raises(pl.ComputeError, match="`abs` overflowed"):
+        df.select(pl.col("a").abs())
+
+
+@pytest.mark.parametrize(
+    ("dtype", "max_value"),
+    [
+        (pl.UInt8, 2**8 - 1),
+        (pl.UInt16, 2**16 - 1),
+        (pl.UInt32, 2**32 - 1),
+        (pl.UInt64, 2**64 - 1),
+    ],
+)
+def test_abs_unsigned_extremes(dtype: pl.PolarsDataType, max_value: int) -> None:
+    df = pl.DataFrame({"a": [0, max_value, None]}, schema={"a": dtype})
+    assert_frame_equal(df.select(pl.col("a").abs()), df)
 
 
 def test_abs_unsigned_int() -> None:
@@ -54,6 +54,15 @@ def test_cut_include_breaks() -> None:
     assert_series_equal(out, expected, categorical_as_str=True)
 
 
+def test_cut_index_only() -> None:
+    s = pl.Series("a", [-2, -1, 0, 1, 2, None])
+
+    result = s.cut([-1, 1], labels=["a", "b", "c"], index_only=True)
+
+    expected = pl.Series("a", [0, 0, 1, 1, 2, None], dtype=pl.UInt32)
+    assert_series_equal(result, expected)
+
+
 # https://github.com/pola-rs/polars/issues/11255
 def test_cut_include_breaks_lazy_schema() -> None:
     lf = pl.LazyFrame({"a": [-2, -1, 0, 1, 2]})
@@ -4,7 +4,7 @@ import pytest
 
 import polars as pl
 import polars.selectors as cs
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_drop_explode_6641() -> None:
@@ -127,6 +127,31 @@ def test_drop_without_parameters() -> None:
     assert_frame_equal(df.lazy().drop(*[]), df.lazy())
 
 
+@pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
+def test_drop_nans_float(dtype: pl.DataType) -> None:
+    s = pl.Series("a", [float("nan"), 1.0, None, float("nan"), 2.0], dtype=dtype)
+    expected = pl.Series("a", [1.0, None, 2.0], dtype=dtype)
+    assert_series_equal(s.drop_nans(), expected)
+    assert_series_equal(pl.select(pl.lit(s).drop_nans()).to_series(), expected)
+
+
+@pytest.mark.parametrize(
+    "s",
+    [
+        pl.Series("a", [1, None, 3], dtype=pl.Int64),
+        pl.Series("a", ["x", None, "nan"], dtype=pl.String),
+        pl.Series("a", [True, None, False], dtype=pl.Boolean),
+    ],
+)
+def test_drop_nans_non_float_noop(s: pl.Series) -> None:
+    assert_series_equal(s.drop_nans(), s)
+    df = pl.DataFrame({"g": [1, 1, 2], "a": s})
+    assert_frame_equal(df.select(pl.col("a").drop_nans()), df.select("a"))
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").drop_nans())
+    expected = df.group_by("g", maintain_order=True).agg(pl.col("a"))
+    assert_frame_equal(out, expected)
+
+
 def test_drop_keyword_deprecated() -> None:
     df = pl.DataFrame({"a": [1, 2], "b": [3, 4]})
     expected = df.select("b")
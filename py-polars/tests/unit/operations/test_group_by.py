@@ -1060,3 +1060,24 @@ def test_boolean_min_max_agg() -> None:
         schema=schema,
     )
     assert_frame_equal(result, expected)
+
+
+def test_group_by_gather_every() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a"] * 7 + ["b"] * 4,
+            "x": list(range(11)),
+        }
+    )
+    result = df.group_by("g", maintain_order=True).agg(
+        every_3=pl.col("x").gather_every(3),
+        every_2_offset=pl.col("x").gather_every(2, offset=1),
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "b"],
+            "every_3": [[0, 3, 6], [7, 10]],
+            "every_2_offset": [[1, 3, 5], [8, 10]],
+        }
+    )
+    assert_frame_equal(result, expected)
@@ -48,6 +48,16 @@ def test_qcut_n() -> None:
     assert_series_equal(out, expected, categorical_as_str=True)
 
 
+def test_qcut_index_only() -> None:
+    s = pl.Series("a", [-2, -1, 0, 1, 2])
+
+    result = s.qcut([0.25, 0.75], index_only=True)
+    assert_series_equal(result, pl.Series("a", [0, 0, 1, 1, 2], dtype=pl.UInt32))
+
+    result = s.qcut(2, left_closed=True, index_only=True)
+    assert_series_equal(result, pl.Series("a", [0, 0, 1, 1, 1], dtype=pl.UInt32))
+
+
 def test_qcut_include_breaks() -> None:
     s = pl.int_range(-2, 3, eager=True).alias("a")
 
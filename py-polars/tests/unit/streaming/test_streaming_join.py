@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Literal
+from typing import TYPE_CHECKING, Literal
 
 import numpy as np
 import pandas as pd
@@ -9,6 +9,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars.type_aliases import JoinStrategy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -248,3 +251,36 @@ def test_streaming_join_and_union() -> None:
     out = q.collect(streaming=True)
     assert_frame_equal(out, q.collect(streaming=False))
     assert out.to_series().to_list() == [1, 2, 1, 2, 4, 8, 1, 2]
+
+
+@pytest.mark.parametrize("join_nulls", [False, True])
+@pytest.mark.parametrize("how", ["inner", "left"])
+@pytest.mark.parametrize("swap", [False, True])
+@pytest.mark.parametrize("on", ["a", ["a", "b"]])
+def test_streaming_join_nulls_matches_in_memory(
+    on: str | list[str], join_nulls: bool, how: JoinStrategy, swap: bool
+) -> None:
+    small = pl.LazyFrame(
+        {
+            "a": [None, 1, 2, None, 3],
+            "b": [1, None, 2, None, 3],
+            "idx_small": range(5),
+        }
+    )
+    large = pl.LazyFrame(
+        {
+            "a": [None, 2, 1, None, 4, 1, None, 3],
+            "b": [1, 2, None, None, 4, 1, 1, None],
+            "idx_large": range(8),
+        }
+    )
+    # The smaller table is the build side of an inner join, so swapping the inputs
+    # exercises both the build and the probe side.
+    left, right = (large, small) if swap else (small, large)
+
+    q = left.join(right, on=on, how=how, join_nulls=join_nulls)
+    sort_by = ["idx_small", "idx_large"]
+    assert_frame_equal(
+        q.collect(streaming=True).sort(sort_by, nulls_last=True),
+        q.collect(streaming=False).sort(sort_by, nulls_last=True),
+    )
@@ -1,8 +1,20 @@
 from __future__ import annotations
 
 from collections import OrderedDict
-from datetime import date, timedelta
-from typing import TYPE_CHECKING, Any, Iterator, Mapping
+from dataclasses import dataclass
+from datetime import date, datetime, timedelta
+from decimal import Decimal
+from typing import (
+    TYPE_CHECKING,
+    Any,
+    Iterator,
+    List,
+    Mapping,
+    NamedTuple,
+    Optional,
+    TypedDict,
+    Union,
+)
 
 import pytest
 
@@ -690,3 +702,113 @@ def test_resolved_names_15442() -> None:
     right = 1000
     in_x = (left < center.struct.field("x")) & (center.struct.field("x") <= right)
     assert df.lazy().filter(in_x).collect().shape == (1, 2)
+
+
+class Point(TypedDict):
+    x: float
+    y: Optional[float]
+
+
+class Shape(TypedDict):
+    name: str
+    closed: bool
+    points: Optional[List[Optional[Point]]]
+
+
+@dataclass
+class Measurement:
+    id: int
+    taken: datetime
+    day: date
+    duration: timedelta
+    value: Decimal
+    shapes: List[Shape]
+    raw: Optional[bytes] = None
+
+
+class BadPoint(TypedDict):
+    x: float
+    y: Union[int, str]
+
+
+class BadShape(TypedDict):
+    points: List[BadPoint]
+
+
+def test_schema_from_typed_dict() -> None:
+    point = pl.Struct({"x": pl.Float64, "y": pl.Float64})
+    shape = pl.Struct(
+        {"name": pl.String, "closed": pl.Boolean, "points": pl.List(point)}
+    )
+
+    schema = pl.Schema.from_typed_dict(Shape)
+    assert isinstance(schema, pl.Schema)
+    assert list(schema.items()) == [
+        ("name", pl.String),
+        ("closed", pl.Boolean),
+        ("points", pl.List(point)),
+    ]
+
+    schema = pl.Schema.from_typed_dict(Measurement)
+    assert list(schema.items()) == [
+        ("id", pl.Int64),
+        ("taken", pl.Datetime("us")),
+        ("day", pl.Date),
+        ("duration", pl.Duration("us")),
+        ("value", pl.Decimal),
+        ("shapes", pl.List(shape)),
+        ("raw", pl.Binary),
+    ]
+
+    df = pl.DataFrame(
+        [{"name": "a", "closed": True, "points": [{"x": 1.0, "y": None}, None]}],
+        schema=pl.Schema.from_typed_dict(Shape),
+    )
+    assert df.schema == {
+        "name": pl.String,
+        "closed": pl.Boolean,
+        "points": pl.List(point),
+    }
+
+
+def test_schema_from_typed_dict_errors() -> None:
+    with pytest.raises(TypeError, match=r"at 'points\[\]\.y'.*ambiguous"):
+        pl.Schema.from_typed_dict(BadShape)
+
+    class Unknown(TypedDict):
+        value: complex
+
+    with pytest.raises(TypeError, match="at 'value'.*unknown type"):
+        pl.Schema.from_typed_dict(Unknown)
+
+
+class Pair(NamedTuple):
+    left: int
+    right: Optional[str]
+
+
+class Node(TypedDict):
+    value: int
+    children: List[Node]
+
+
+class PlainClass:
+    value: int
+
+
+def test_schema_from_named_tuple() -> None:
+    assert pl.Schema.from_typed_dict(Pair) == {"left": pl.Int64, "right": pl.String}
+
+
+def test_schema_from_typed_dict_unsupported_classes() -> None:
+    with pytest.raises(TypeError, match=r"at 'children\[\]'.*recursive"):
+        pl.Schema.from_typed_dict(Node)
+
+    with pytest.raises(TypeError, match="TypedDict, dataclass or NamedTuple"):
+        pl.Schema.from_typed_dict(PlainClass)
+
+    class Wrapper(TypedDict):
+        inner: PlainClass
+
+    with pytest.raises(TypeError, match="at 'inner'.*only TypedDicts"):
+        pl.Schema.from_typed_dict(Wrapper)